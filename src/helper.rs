@@ -1,5 +1,7 @@
 pub mod address;
+pub mod auto_filter;
 pub mod binary;
+pub mod calculation;
 pub mod color;
 pub mod const_str;
 pub mod coordinate;
@@ -8,7 +10,11 @@ pub mod date;
 pub mod formula;
 pub mod html;
 pub mod number_format;
+pub mod ods;
+pub mod outline;
+pub mod pivot;
 pub mod range;
+pub mod sort;
 pub mod string_helper;
 pub mod time_zone;
 pub mod utils;
@@ -11,9 +11,24 @@ pub fn join_address(sheet_name: &str, address: &str) -> String {
     if sheet_name == "" {
         return address.to_string();
     }
+    let need_quote = sheet_name.starts_with(|c: char| c.is_ascii_digit())
+        || sheet_name
+            .chars()
+            .any(|c| !(c.is_alphanumeric() || c == '_' || c == '.'));
+    if need_quote {
+        return format!("'{}'!{}", sheet_name.replace('\'', "''"), address);
+    }
     format!("{}!{}", sheet_name, address)
 }
 
+#[test]
+fn join_address_test() {
+    assert_eq!(join_address("", "A1"), "A1");
+    assert_eq!(join_address("Sheet1", "A1:B2"), "Sheet1!A1:B2");
+    assert_eq!(join_address("My Sheet", "A1"), "'My Sheet'!A1");
+    assert_eq!(join_address("2024", "A1"), "'2024'!A1");
+}
+
 #[test]
 fn split_address_test() {
     assert_eq!(split_address("A1"), ("", "A1"));
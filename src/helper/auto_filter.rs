@@ -0,0 +1,412 @@
+//! Evaluation of the criteria used by `Worksheet::apply_auto_filter`.
+
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Timelike;
+
+use crate::helper::date::excel_to_date_time_object;
+use crate::helper::sort::is_same_color;
+use crate::structs::Cell;
+use crate::structs::CellRawValue;
+use crate::structs::CellValue;
+use crate::structs::ColorFilter;
+use crate::structs::CustomFilter;
+use crate::structs::CustomFilters;
+use crate::structs::DateGroupItem;
+use crate::structs::DateTimeGroupingValues;
+use crate::structs::DynamicFilter;
+use crate::structs::DynamicFilterValues;
+use crate::structs::FilterColumn;
+use crate::structs::FilterOperatorValues;
+use crate::structs::Filters;
+use crate::structs::Top10;
+
+/// Serial of 9999-12-31, the last date Excel can display.
+const MAX_DATE_SERIAL: f64 = 2958465f64;
+
+/// Decide which cells of a column are shown.
+/// # Arguments
+/// * `filter_column` - criteria of the column.
+/// * `cells` - cells of the column without the header row.
+/// * `today` - date the dynamic date filters are relative to.
+/// # Return value
+/// * `Vec<bool>` - true for each shown cell.
+pub(crate) fn evaluate(
+    filter_column: &FilterColumn,
+    cells: &[Option<&Cell>],
+    today: NaiveDate,
+) -> Vec<bool> {
+    let numbers: Vec<Option<f64>> = cells.iter().map(|v| get_number(*v)).collect();
+
+    if let Some(filters) = filter_column.get_filters() {
+        return cells
+            .iter()
+            .zip(&numbers)
+            .map(|(cell, number)| is_match_filters(filters, *cell, *number))
+            .collect();
+    }
+    if let Some(custom_filters) = filter_column.get_custom_filters() {
+        return cells
+            .iter()
+            .zip(&numbers)
+            .map(|(cell, number)| is_match_custom_filters(custom_filters, *cell, *number))
+            .collect();
+    }
+    if let Some(top10) = filter_column.get_top10() {
+        return match get_top10_threshold(top10, &numbers) {
+            Some(threshold) => numbers
+                .iter()
+                .map(|v| match (v, top10.get_top()) {
+                    (Some(v), true) => *v >= threshold,
+                    (Some(v), false) => *v <= threshold,
+                    (None, _) => false,
+                })
+                .collect(),
+            None => vec![false; cells.len()],
+        };
+    }
+    if let Some(dynamic_filter) = filter_column.get_dynamic_filter() {
+        return is_match_dynamic_filter(dynamic_filter, &numbers, today);
+    }
+    if let Some(color_filter) = filter_column.get_color_filter() {
+        return cells
+            .iter()
+            .map(|cell| is_match_color_filter(color_filter, *cell))
+            .collect();
+    }
+    vec![true; cells.len()]
+}
+
+fn get_number(cell: Option<&Cell>) -> Option<f64> {
+    let value = match cell?.get_raw_value() {
+        CellRawValue::Lazy(v) => CellValue::guess_typed_data(v),
+        v => v.clone(),
+    };
+    match value {
+        CellRawValue::Numeric(v) => Some(v),
+        _ => None,
+    }
+}
+
+#[inline]
+fn get_text(cell: Option<&Cell>) -> String {
+    cell.map_or(String::new(), |v| v.get_formatted_value())
+}
+
+/// Serials outside the range of Excel dates are not dates.
+#[inline]
+fn to_date_time(number: f64) -> Option<NaiveDateTime> {
+    if !(0f64..=MAX_DATE_SERIAL).contains(&number) {
+        return None;
+    }
+    Some(excel_to_date_time_object(&number, None))
+}
+
+fn is_match_filters(filters: &Filters, cell: Option<&Cell>, number: Option<f64>) -> bool {
+    let text = get_text(cell);
+    if text.is_empty() {
+        return *filters.get_blank();
+    }
+    if filters
+        .get_filter_list()
+        .iter()
+        .any(|v| v.get_val().eq_ignore_ascii_case(text.trim()))
+    {
+        return true;
+    }
+    match number.and_then(to_date_time) {
+        Some(v) => filters
+            .get_date_group_item_list()
+            .iter()
+            .any(|item| is_match_date_group_item(item, &v)),
+        None => false,
+    }
+}
+
+fn is_match_date_group_item(item: &DateGroupItem, date_time: &NaiveDateTime) -> bool {
+    let parts = [
+        (date_time.year() as u32, *item.get_year() as u32),
+        (date_time.month(), *item.get_month() as u32),
+        (date_time.day(), *item.get_day() as u32),
+        (date_time.hour(), *item.get_hour() as u32),
+        (date_time.minute(), *item.get_minute() as u32),
+        (date_time.second(), *item.get_second() as u32),
+    ];
+    let count = match item.get_date_time_grouping() {
+        DateTimeGroupingValues::Year => 1,
+        DateTimeGroupingValues::Month => 2,
+        DateTimeGroupingValues::Day => 3,
+        DateTimeGroupingValues::Hour => 4,
+        DateTimeGroupingValues::Minute => 5,
+        DateTimeGroupingValues::Second => 6,
+    };
+    parts[..count].iter().all(|(a, b)| a == b)
+}
+
+fn is_match_custom_filters(
+    custom_filters: &CustomFilters,
+    cell: Option<&Cell>,
+    number: Option<f64>,
+) -> bool {
+    let list = custom_filters.get_custom_filter_list();
+    if list.is_empty() {
+        return true;
+    }
+    let text = get_text(cell);
+    let mut results = list
+        .iter()
+        .map(|v| is_match_custom_filter(v, &text, number));
+    match custom_filters.get_and() {
+        true => results.all(|v| v),
+        false => results.any(|v| v),
+    }
+}
+
+fn is_match_custom_filter(custom_filter: &CustomFilter, text: &str, number: Option<f64>) -> bool {
+    let operator = custom_filter.get_operator();
+    let val = custom_filter.get_val();
+
+    // numbers are compared by value, everything else by text.
+    let ordering = match (number, val.trim().parse::<f64>()) {
+        (Some(a), Ok(b)) => Some(a.total_cmp(&b)),
+        (Some(_), Err(_)) | (None, Ok(_)) if operator_is_ordering(operator) => None,
+        _ => {
+            if matches!(
+                operator,
+                FilterOperatorValues::Equal | FilterOperatorValues::NotEqual
+            ) {
+                let is_match = is_match_wildcard(&val.to_lowercase(), &text.to_lowercase());
+                return match operator {
+                    FilterOperatorValues::Equal => is_match,
+                    _ => !is_match,
+                };
+            }
+            Some(text.to_lowercase().cmp(&val.to_lowercase()))
+        }
+    };
+    let ordering = match ordering {
+        Some(v) => v,
+        None => return false,
+    };
+    match operator {
+        FilterOperatorValues::Equal => ordering.is_eq(),
+        FilterOperatorValues::NotEqual => ordering.is_ne(),
+        FilterOperatorValues::GreaterThan => ordering.is_gt(),
+        FilterOperatorValues::GreaterThanOrEqual => ordering.is_ge(),
+        FilterOperatorValues::LessThan => ordering.is_lt(),
+        FilterOperatorValues::LessThanOrEqual => ordering.is_le(),
+    }
+}
+
+#[inline]
+fn operator_is_ordering(operator: &FilterOperatorValues) -> bool {
+    !matches!(
+        operator,
+        FilterOperatorValues::Equal | FilterOperatorValues::NotEqual
+    )
+}
+
+/// Match text against a pattern where `*` is any text, `?` is any character and `~` escapes them.
+fn is_match_wildcard(pattern: &str, text: &str) -> bool {
+    fn is_match(pattern: &[char], text: &[char]) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some('*') => (0..=text.len()).any(|i| is_match(&pattern[1..], &text[i..])),
+            Some('?') => !text.is_empty() && is_match(&pattern[1..], &text[1..]),
+            Some('~') if pattern.len() > 1 => {
+                text.first() == Some(&pattern[1]) && is_match(&pattern[2..], &text[1..])
+            }
+            Some(c) => text.first() == Some(c) && is_match(&pattern[1..], &text[1..]),
+        }
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    is_match(&pattern, &text)
+}
+
+/// The smallest shown value for a top filter, the largest for a bottom filter.
+fn get_top10_threshold(top10: &Top10, numbers: &[Option<f64>]) -> Option<f64> {
+    let mut values: Vec<f64> = numbers.iter().flatten().copied().collect();
+    if values.is_empty() {
+        return None;
+    }
+    match top10.get_top() {
+        true => values.sort_by(|a, b| b.total_cmp(a)),
+        false => values.sort_by(|a, b| a.total_cmp(b)),
+    }
+    let count = match top10.get_percent() {
+        true => (values.len() as f64 * top10.get_val() / 100f64).floor() as usize,
+        false => *top10.get_val() as usize,
+    };
+    let count = count.clamp(1, values.len());
+    Some(values[count - 1])
+}
+
+fn is_match_dynamic_filter(
+    dynamic_filter: &DynamicFilter,
+    numbers: &[Option<f64>],
+    today: NaiveDate,
+) -> Vec<bool> {
+    let r#type = dynamic_filter.get_type();
+    match r#type {
+        DynamicFilterValues::Null => return vec![true; numbers.len()],
+        DynamicFilterValues::AboveAverage | DynamicFilterValues::BelowAverage => {
+            let values: Vec<f64> = numbers.iter().flatten().copied().collect();
+            if values.is_empty() {
+                return vec![false; numbers.len()];
+            }
+            let average = values.iter().sum::<f64>() / values.len() as f64;
+            return numbers
+                .iter()
+                .map(|v| match (v, r#type) {
+                    (Some(v), DynamicFilterValues::AboveAverage) => *v > average,
+                    (Some(v), _) => *v < average,
+                    (None, _) => false,
+                })
+                .collect();
+        }
+        _ => {}
+    }
+
+    numbers
+        .iter()
+        .map(|v| match v.and_then(to_date_time) {
+            Some(v) => is_match_date_period(r#type, v.date(), today),
+            None => false,
+        })
+        .collect()
+}
+
+fn is_match_date_period(r#type: &DynamicFilterValues, date: NaiveDate, today: NaiveDate) -> bool {
+    // the week starts on sunday.
+    let week_start = today - Duration::days(today.weekday().num_days_from_sunday() as i64);
+    let month_start = |year: i32, month: i32| {
+        let (year, month) = (
+            year + (month - 1).div_euclid(12),
+            (month - 1).rem_euclid(12) + 1,
+        );
+        NaiveDate::from_ymd_opt(year, month as u32, 1).unwrap()
+    };
+    let year = today.year();
+    let month = today.month() as i32;
+    let quarter_month = (month - 1) / 3 * 3 + 1;
+
+    let (start, end) = match r#type {
+        DynamicFilterValues::Yesterday => (today - Duration::days(1), today),
+        DynamicFilterValues::Today => (today, today + Duration::days(1)),
+        DynamicFilterValues::Tomorrow => (today + Duration::days(1), today + Duration::days(2)),
+        DynamicFilterValues::LastWeek => (week_start - Duration::days(7), week_start),
+        DynamicFilterValues::ThisWeek => (week_start, week_start + Duration::days(7)),
+        DynamicFilterValues::NextWeek => (
+            week_start + Duration::days(7),
+            week_start + Duration::days(14),
+        ),
+        DynamicFilterValues::LastMonth => (month_start(year, month - 1), month_start(year, month)),
+        DynamicFilterValues::ThisMonth => (month_start(year, month), month_start(year, month + 1)),
+        DynamicFilterValues::NextMonth => {
+            (month_start(year, month + 1), month_start(year, month + 2))
+        }
+        DynamicFilterValues::LastQuarter => (
+            month_start(year, quarter_month - 3),
+            month_start(year, quarter_month),
+        ),
+        DynamicFilterValues::ThisQuarter => (
+            month_start(year, quarter_month),
+            month_start(year, quarter_month + 3),
+        ),
+        DynamicFilterValues::NextQuarter => (
+            month_start(year, quarter_month + 3),
+            month_start(year, quarter_month + 6),
+        ),
+        DynamicFilterValues::LastYear => (month_start(year - 1, 1), month_start(year, 1)),
+        DynamicFilterValues::ThisYear => (month_start(year, 1), month_start(year + 1, 1)),
+        DynamicFilterValues::NextYear => (month_start(year + 1, 1), month_start(year + 2, 1)),
+        DynamicFilterValues::YearToDate => (month_start(year, 1), today + Duration::days(1)),
+        // periods of any year.
+        DynamicFilterValues::Quarter1 => return (1..=3).contains(&date.month()),
+        DynamicFilterValues::Quarter2 => return (4..=6).contains(&date.month()),
+        DynamicFilterValues::Quarter3 => return (7..=9).contains(&date.month()),
+        DynamicFilterValues::Quarter4 => return (10..=12).contains(&date.month()),
+        DynamicFilterValues::Month1 => return date.month() == 1,
+        DynamicFilterValues::Month2 => return date.month() == 2,
+        DynamicFilterValues::Month3 => return date.month() == 3,
+        DynamicFilterValues::Month4 => return date.month() == 4,
+        DynamicFilterValues::Month5 => return date.month() == 5,
+        DynamicFilterValues::Month6 => return date.month() == 6,
+        DynamicFilterValues::Month7 => return date.month() == 7,
+        DynamicFilterValues::Month8 => return date.month() == 8,
+        DynamicFilterValues::Month9 => return date.month() == 9,
+        DynamicFilterValues::Month10 => return date.month() == 10,
+        DynamicFilterValues::Month11 => return date.month() == 11,
+        DynamicFilterValues::Month12 => return date.month() == 12,
+        DynamicFilterValues::Null
+        | DynamicFilterValues::AboveAverage
+        | DynamicFilterValues::BelowAverage => return true,
+    };
+    start <= date && date < end
+}
+
+fn is_match_color_filter(color_filter: &ColorFilter, cell: Option<&Cell>) -> bool {
+    let target_style = color_filter.get_style();
+    match color_filter.get_cell_color() {
+        true => {
+            let target = target_style.and_then(|v| {
+                let pattern_fill = v.get_fill()?.get_pattern_fill()?;
+                pattern_fill
+                    .get_foreground_color()
+                    .or(pattern_fill.get_background_color())
+            });
+            let color = cell.and_then(|v| v.get_style().get_background_color());
+            is_same_color(color, target)
+        }
+        false => {
+            let target = target_style
+                .and_then(|v| v.get_font())
+                .map(|v| v.get_color());
+            let color = cell
+                .and_then(|v| v.get_style().get_font())
+                .map(|v| v.get_color());
+            is_same_color(color, target)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcard() {
+        assert!(is_match_wildcard("a*", "apple"));
+        assert!(is_match_wildcard("*pl*", "apple"));
+        assert!(is_match_wildcard("a???e", "apple"));
+        assert!(!is_match_wildcard("a??e", "apple"));
+        assert!(is_match_wildcard("a~*", "a*"));
+        assert!(!is_match_wildcard("a~*", "ab"));
+    }
+
+    #[test]
+    fn date_period() {
+        // 2024-05-15 is a wednesday.
+        let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let is_match = |t, d| is_match_date_period(&t, d, today);
+        assert!(is_match(DynamicFilterValues::Today, date(2024, 5, 15)));
+        assert!(is_match(DynamicFilterValues::ThisWeek, date(2024, 5, 12)));
+        assert!(!is_match(DynamicFilterValues::ThisWeek, date(2024, 5, 19)));
+        assert!(is_match(DynamicFilterValues::LastMonth, date(2024, 4, 30)));
+        assert!(is_match(DynamicFilterValues::LastQuarter, date(2024, 1, 1)));
+        assert!(is_match(
+            DynamicFilterValues::NextQuarter,
+            date(2024, 9, 30)
+        ));
+        assert!(is_match(DynamicFilterValues::YearToDate, date(2024, 1, 1)));
+        assert!(!is_match(
+            DynamicFilterValues::YearToDate,
+            date(2024, 5, 16)
+        ));
+        assert!(is_match(DynamicFilterValues::Month5, date(2019, 5, 1)));
+    }
+}
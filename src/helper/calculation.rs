@@ -0,0 +1,10 @@
+mod dependency;
+mod evaluator;
+mod functions;
+mod parser;
+mod value;
+
+pub(crate) use self::dependency::*;
+pub(crate) use self::evaluator::*;
+pub use self::parser::*;
+pub use self::value::*;
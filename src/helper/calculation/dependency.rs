@@ -0,0 +1,213 @@
+use super::evaluator::*;
+use crate::helper::address::*;
+use crate::helper::formula::*;
+use crate::structs::Address;
+use crate::structs::CellFormulaValues;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+type CellKey = (usize, u32, u32);
+
+/// Ranges wider than this are not indexed column by column.
+const COLUMN_INDEX_LIMIT: u32 = 64;
+
+/// Precedents of every formula cell, built from the formula tokens,
+/// with a reverse index to look up the dependents of a cell.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct DependencyGraph {
+    precedents: HashMap<CellKey, Vec<RangeReference>>,
+    /// Formula cells by the single cell they refer to.
+    cell_dependents: HashMap<CellKey, Vec<CellKey>>,
+    /// Formula cells by each column of the ranges they refer to, with the row span.
+    column_dependents: HashMap<(usize, u32), Vec<(u32, u32, CellKey)>>,
+    /// Formula cells referring to ranges wider than `COLUMN_INDEX_LIMIT`, by sheet.
+    wide_dependents: HashMap<usize, Vec<(RangeReference, CellKey)>>,
+}
+impl DependencyGraph {
+    pub(crate) fn new(calc: &Calculator) -> Self {
+        let mut graph = Self::default();
+        for sheet_index in 0..calc.get_worksheet_count() {
+            for cell in calc
+                .get_worksheet(sheet_index)
+                .get_cell_collection_crate()
+                .iter_cells_sorted_by_row_column()
+            {
+                let formula = match cell.get_formula_obj() {
+                    Some(v) if v.get_formula_type() != &CellFormulaValues::DataTable => {
+                        v.get_text()
+                    }
+                    _ => continue,
+                };
+                let mut references: Vec<RangeReference> = Vec::new();
+                collect_references(calc, sheet_index, formula, 0, &mut references);
+                let key = (
+                    sheet_index,
+                    *cell.get_coordinate().get_col_num(),
+                    *cell.get_coordinate().get_row_num(),
+                );
+                for reference in &references {
+                    graph.add_dependent(reference, key);
+                }
+                graph.precedents.insert(key, references);
+            }
+        }
+        graph
+    }
+
+    fn add_dependent(&mut self, reference: &RangeReference, key: CellKey) {
+        if reference.is_single_cell() {
+            self.cell_dependents
+                .entry((
+                    reference.sheet_index,
+                    reference.col_start,
+                    reference.row_start,
+                ))
+                .or_default()
+                .push(key);
+            return;
+        }
+        if reference.get_col_count() > COLUMN_INDEX_LIMIT {
+            self.wide_dependents
+                .entry(reference.sheet_index)
+                .or_default()
+                .push((reference.clone(), key));
+            return;
+        }
+        for col in reference.col_start..=reference.col_end {
+            self.column_dependents
+                .entry((reference.sheet_index, col))
+                .or_default()
+                .push((reference.row_start, reference.row_end, key));
+        }
+    }
+
+    /// Whether a cell holds a formula.
+    #[inline]
+    pub(crate) fn has_formula(&self, sheet_index: usize, col: u32, row: u32) -> bool {
+        self.precedents.contains_key(&(sheet_index, col, row))
+    }
+
+    /// Cells and ranges the formula of a cell refers to.
+    #[inline]
+    pub(crate) fn get_precedents(
+        &self,
+        sheet_index: usize,
+        col: u32,
+        row: u32,
+    ) -> &[RangeReference] {
+        self.precedents
+            .get(&(sheet_index, col, row))
+            .map(|v| v.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Formula cells referring to a cell directly, sorted by sheet, row and column.
+    pub(crate) fn get_dependents(&self, sheet_index: usize, col: u32, row: u32) -> Vec<CellKey> {
+        let mut result: Vec<CellKey> = Vec::new();
+        if let Some(v) = self.cell_dependents.get(&(sheet_index, col, row)) {
+            result.extend(v);
+        }
+        if let Some(v) = self.column_dependents.get(&(sheet_index, col)) {
+            result.extend(
+                v.iter()
+                    .filter(|(row_start, row_end, _)| *row_start <= row && row <= *row_end)
+                    .map(|(_, _, key)| *key),
+            );
+        }
+        if let Some(v) = self.wide_dependents.get(&sheet_index) {
+            result.extend(
+                v.iter()
+                    .filter(|(reference, _)| reference.contains(sheet_index, col, row))
+                    .map(|(_, key)| *key),
+            );
+        }
+        result.sort_by_key(|(sheet_index, col, row)| (*sheet_index, *row, *col));
+        result.dedup();
+        result
+    }
+
+    /// Formula cells affected by a change of a cell, directly or indirectly.
+    pub(crate) fn get_all_dependents(
+        &self,
+        sheet_index: usize,
+        col: u32,
+        row: u32,
+    ) -> HashSet<CellKey> {
+        let mut result: HashSet<CellKey> = HashSet::new();
+        let mut queue: VecDeque<CellKey> = VecDeque::new();
+        queue.push_back((sheet_index, col, row));
+        while let Some((sheet_index, col, row)) = queue.pop_front() {
+            for key in self.get_dependents(sheet_index, col, row) {
+                if result.insert(key) {
+                    queue.push_back(key);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Resolve the references of a formula. Defined names are replaced with what they refer to.
+pub(crate) fn collect_references(
+    calc: &Calculator,
+    sheet_index: usize,
+    formula: &str,
+    depth: u32,
+    result: &mut Vec<RangeReference>,
+) {
+    if depth > MAX_NAME_DEPTH {
+        return;
+    }
+    for token in parse_to_tokens(format!("={}", formula)) {
+        if token.get_token_type() != &FormulaTokenTypes::Operand
+            || token.get_token_sub_type() != &FormulaTokenSubTypes::Range
+        {
+            continue;
+        }
+        let (sheet_name, range) = split_address(token.get_value());
+        let target_index = match sheet_name.is_empty() {
+            true => Some(sheet_index),
+            false => calc.find_sheet_index(sheet_name),
+        };
+        let target_index = match target_index {
+            Some(v) => v,
+            None => continue,
+        };
+        if let Some((col_start, row_start, col_end, row_end)) = parse_range_reference(range) {
+            result.push(RangeReference {
+                sheet_index: target_index,
+                col_start,
+                row_start,
+                col_end,
+                row_end,
+            });
+            continue;
+        }
+        let defined_name = match calc.find_defined_name(target_index, range) {
+            Some(v) => v,
+            None => continue,
+        };
+        let addresses = defined_name.get_address_obj();
+        if addresses.is_empty() {
+            collect_references(
+                calc,
+                target_index,
+                &defined_name.get_address(),
+                depth + 1,
+                result,
+            );
+            continue;
+        }
+        for address in addresses {
+            let value = join_address(address.get_sheet_name(), &address.get_range().get_range());
+            collect_references(calc, target_index, &value, depth + 1, result);
+        }
+    }
+}
+
+/// Convert a reference into an Address holding the sheet name.
+pub(crate) fn to_address(calc: &Calculator, reference: &RangeReference) -> Address {
+    let mut address = Address::default();
+    address.set_sheet_name(calc.get_worksheet(reference.sheet_index).get_name());
+    address.get_range_mut().set_range(reference.get_range());
+    address
+}
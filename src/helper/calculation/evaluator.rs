@@ -0,0 +1,803 @@
+use super::dependency::*;
+use super::functions::*;
+use super::parser::*;
+use super::value::*;
+use crate::helper::address::*;
+use crate::helper::coordinate::*;
+use crate::structs::CellErrorType;
+use crate::structs::CellFormulaValues;
+use crate::structs::DefinedName;
+use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
+use std::collections::{HashMap, HashSet};
+
+pub(crate) const MAX_COLUMN: u32 = 16384;
+pub(crate) const MAX_ROW: u32 = 1048576;
+pub(crate) const MAX_NAME_DEPTH: u32 = 32;
+
+/// The cell in which a formula is evaluated.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Position {
+    pub(crate) sheet_index: usize,
+    pub(crate) col: u32,
+    pub(crate) row: u32,
+}
+
+/// Rectangular area of a worksheet.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RangeReference {
+    pub(crate) sheet_index: usize,
+    pub(crate) col_start: u32,
+    pub(crate) row_start: u32,
+    pub(crate) col_end: u32,
+    pub(crate) row_end: u32,
+}
+impl RangeReference {
+    #[inline]
+    pub(crate) fn is_single_cell(&self) -> bool {
+        self.col_start == self.col_end && self.row_start == self.row_end
+    }
+
+    #[inline]
+    pub(crate) fn get_row_count(&self) -> u32 {
+        self.row_end - self.row_start + 1
+    }
+
+    #[inline]
+    pub(crate) fn get_col_count(&self) -> u32 {
+        self.col_end - self.col_start + 1
+    }
+
+    #[inline]
+    pub(crate) fn contains(&self, sheet_index: usize, col: u32, row: u32) -> bool {
+        self.sheet_index == sheet_index
+            && self.col_start <= col
+            && col <= self.col_end
+            && self.row_start <= row
+            && row <= self.row_end
+    }
+
+    /// Range in A1 notation. Whole columns and rows are written as `A:B` and `1:2`.
+    pub(crate) fn get_range(&self) -> String {
+        let col_start = string_from_column_index(&self.col_start);
+        let col_end = string_from_column_index(&self.col_end);
+        if self.row_start == 1 && self.row_end == MAX_ROW {
+            return format!("{}:{}", col_start, col_end);
+        }
+        if self.col_start == 1 && self.col_end == MAX_COLUMN {
+            return format!("{}:{}", self.row_start, self.row_end);
+        }
+        if self.is_single_cell() {
+            return format!("{}{}", col_start, self.row_start);
+        }
+        format!(
+            "{}{}:{}{}",
+            col_start, self.row_start, col_end, self.row_end
+        )
+    }
+}
+
+/// Result of evaluating an expression before it is turned into a value.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Operand {
+    Value(CalculatedValue),
+    Reference(RangeReference),
+}
+impl Operand {
+    #[inline]
+    pub(crate) fn error(error: CellErrorType) -> Self {
+        Self::Value(CalculatedValue::Error(error))
+    }
+}
+
+/// Parse the range part of a reference. ex) `A1`, `$A$1:B2`, `A:C`, `1:3`
+/// # Return value
+/// * `(col_start, row_start, col_end, row_end)`
+pub(crate) fn parse_range_reference(range: &str) -> Option<(u32, u32, u32, u32)> {
+    fn split_part(part: &str) -> (Option<u32>, Option<u32>) {
+        let part = part.replace('$', "");
+        let alpha: String = part
+            .chars()
+            .take_while(|c| c.is_ascii_alphabetic())
+            .collect();
+        let digit = &part[alpha.len()..];
+        if !digit.chars().all(|c| c.is_ascii_digit()) || alpha.len() > 3 {
+            return (None, None);
+        }
+        let col = match alpha.is_empty() {
+            true => None,
+            false => Some(column_index_from_string(alpha.to_uppercase())),
+        };
+        let row = match digit.is_empty() {
+            true => None,
+            false => digit.parse::<u32>().ok(),
+        };
+        (col, row)
+    }
+
+    let parts: Vec<&str> = range.split(':').collect();
+    let (col_start, row_start, col_end, row_end) = match parts.as_slice() {
+        [cell] => match split_part(cell) {
+            (Some(col), Some(row)) => (col, row, col, row),
+            _ => return None,
+        },
+        [start, end] => match (split_part(start), split_part(end)) {
+            ((Some(c1), Some(r1)), (Some(c2), Some(r2))) => (c1, r1, c2, r2),
+            ((Some(c1), None), (Some(c2), None)) => (c1, 1, c2, MAX_ROW),
+            ((None, Some(r1)), (None, Some(r2))) => (1, r1, MAX_COLUMN, r2),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if col_start == 0
+        || row_start == 0
+        || col_end == 0
+        || row_end == 0
+        || col_start.max(col_end) > MAX_COLUMN
+        || row_start.max(row_end) > MAX_ROW
+    {
+        return None;
+    }
+    Some((
+        col_start.min(col_end),
+        row_start.min(row_end),
+        col_start.max(col_end),
+        row_start.max(row_end),
+    ))
+}
+
+/// Formula evaluator over one or more worksheets.
+/// Results are memoized so every formula cell is calculated at most once.
+pub(crate) struct Calculator<'a> {
+    worksheets: Vec<&'a Worksheet>,
+    defined_names: Vec<&'a DefinedName>,
+    results: HashMap<(usize, u32, u32), CalculatedValue>,
+    in_progress: HashSet<(usize, u32, u32)>,
+    name_depth: u32,
+    targets: Option<HashSet<(usize, u32, u32)>>,
+}
+impl<'a> Calculator<'a> {
+    pub(crate) fn from_spreadsheet(spreadsheet: &'a Spreadsheet) -> Self {
+        Self {
+            worksheets: spreadsheet.get_sheet_collection_no_check().iter().collect(),
+            defined_names: spreadsheet.get_defined_names().iter().collect(),
+            results: HashMap::new(),
+            in_progress: HashSet::new(),
+            name_depth: 0,
+            targets: None,
+        }
+    }
+
+    pub(crate) fn from_worksheet(worksheet: &'a Worksheet) -> Self {
+        Self {
+            worksheets: vec![worksheet],
+            defined_names: Vec::new(),
+            results: HashMap::new(),
+            in_progress: HashSet::new(),
+            name_depth: 0,
+            targets: None,
+        }
+    }
+
+    /// Restrict recalculation to the given formula cells.
+    /// Other formula cells keep their cached value.
+    #[inline]
+    pub(crate) fn set_targets(&mut self, value: HashSet<(usize, u32, u32)>) -> &mut Self {
+        self.targets = Some(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn get_worksheet_count(&self) -> usize {
+        self.worksheets.len()
+    }
+
+    #[inline]
+    pub(crate) fn get_worksheet(&self, sheet_index: usize) -> &'a Worksheet {
+        self.worksheets[sheet_index]
+    }
+
+    #[inline]
+    pub(crate) fn get_results(&self) -> &HashMap<(usize, u32, u32), CalculatedValue> {
+        &self.results
+    }
+
+    #[inline]
+    pub(crate) fn into_results(self) -> HashMap<(usize, u32, u32), CalculatedValue> {
+        self.results
+    }
+
+    /// Calculate every formula cell of every worksheet.
+    pub(crate) fn calculate_all(&mut self) {
+        for sheet_index in 0..self.worksheets.len() {
+            self.calculate_worksheet(sheet_index);
+        }
+    }
+
+    /// Calculate every formula cell of a worksheet.
+    pub(crate) fn calculate_worksheet(&mut self, sheet_index: usize) {
+        let worksheet = self.worksheets[sheet_index];
+        for cell in worksheet
+            .get_cell_collection_crate()
+            .iter_cells_sorted_by_row_column()
+        {
+            if cell.is_formula() {
+                self.calculate_cell(
+                    sheet_index,
+                    *cell.get_coordinate().get_col_num(),
+                    *cell.get_coordinate().get_row_num(),
+                );
+            }
+        }
+    }
+
+    /// Calculate a cell. Cells without formula return their stored value.
+    pub(crate) fn calculate_cell(
+        &mut self,
+        sheet_index: usize,
+        col: u32,
+        row: u32,
+    ) -> CalculatedValue {
+        let key = (sheet_index, col, row);
+        if let Some(v) = self.results.get(&key) {
+            return v.clone();
+        }
+        let cell = match self.worksheets[sheet_index].get_cell((col, row)) {
+            Some(v) => v,
+            None => return CalculatedValue::Empty,
+        };
+        if self.get_formula(key).is_none() {
+            return CalculatedValue::from_raw_value(cell.get_raw_value());
+        }
+        if self.in_progress.contains(&key) {
+            // Circular reference. Excel evaluates it as zero.
+            return CalculatedValue::Number(0f64);
+        }
+        self.calculate_with_precedents(key);
+        self.results
+            .get(&key)
+            .cloned()
+            .unwrap_or(CalculatedValue::Number(0f64))
+    }
+
+    /// Formula of a cell that has to be calculated.
+    fn get_formula(&self, key: (usize, u32, u32)) -> Option<&'a str> {
+        if let Some(targets) = &self.targets {
+            if !targets.contains(&key) {
+                return None;
+            }
+        }
+        let (sheet_index, col, row) = key;
+        match self.worksheets[sheet_index]
+            .get_cell((col, row))?
+            .get_formula_obj()
+        {
+            Some(v)
+                if v.get_formula_type() != &CellFormulaValues::DataTable
+                    && !v.get_text().is_empty() =>
+            {
+                Some(v.get_text())
+            }
+            _ => None,
+        }
+    }
+
+    /// Calculate a formula cell after the formula cells it refers to.
+    /// The precedents are walked with an explicit stack, so long chains of
+    /// references do not deepen the call stack while evaluating.
+    fn calculate_with_precedents(&mut self, key: (usize, u32, u32)) {
+        let mut stack: Vec<((usize, u32, u32), bool)> = vec![(key, false)];
+        while let Some((key, is_visited)) = stack.pop() {
+            if self.results.contains_key(&key) {
+                continue;
+            }
+            let formula = match self.get_formula(key) {
+                Some(v) => v,
+                None => continue,
+            };
+            if is_visited {
+                self.in_progress.remove(&key);
+                self.calculate_cell_formula(key, formula);
+                continue;
+            }
+            if !self.in_progress.insert(key) {
+                // Circular reference. It is resolved while evaluating.
+                continue;
+            }
+            stack.push((key, true));
+            let mut references: Vec<RangeReference> = Vec::new();
+            collect_references(self, key.0, formula, 0, &mut references);
+            for reference in references {
+                let reference = self.clamp_reference(&reference);
+                for (col, row) in self.get_range_coordinates(&reference) {
+                    let precedent = (reference.sheet_index, col, row);
+                    if !self.results.contains_key(&precedent)
+                        && !self.in_progress.contains(&precedent)
+                        && self.get_formula(precedent).is_some()
+                    {
+                        stack.push((precedent, false));
+                    }
+                }
+            }
+        }
+    }
+
+    fn calculate_cell_formula(&mut self, key: (usize, u32, u32), formula: &str) {
+        let (sheet_index, col, row) = key;
+        self.in_progress.insert(key);
+        let position = Position {
+            sheet_index,
+            col,
+            row,
+        };
+        let result = self.calculate_formula(&position, formula);
+        self.in_progress.remove(&key);
+        self.results.insert(key, result);
+    }
+
+    /// Evaluate a formula as if it was entered in the given cell.
+    pub(crate) fn calculate_formula(
+        &mut self,
+        position: &Position,
+        formula: &str,
+    ) -> CalculatedValue {
+        match parse_formula(formula) {
+            Ok(expression) => {
+                let operand = self.evaluate(position, &expression);
+                self.operand_to_scalar(position, operand)
+            }
+            Err(e) => CalculatedValue::Error(e),
+        }
+    }
+
+    /// Value stored in a cell, calculating it first when it holds a formula.
+    pub(crate) fn get_cell_value(
+        &mut self,
+        sheet_index: usize,
+        col: u32,
+        row: u32,
+    ) -> CalculatedValue {
+        match self.worksheets[sheet_index].get_cell((col, row)) {
+            Some(cell) if cell.is_formula() => self.calculate_cell(sheet_index, col, row),
+            Some(cell) => CalculatedValue::from_raw_value(cell.get_raw_value()),
+            None => CalculatedValue::Empty,
+        }
+    }
+
+    pub(crate) fn evaluate(
+        &mut self,
+        position: &Position,
+        expression: &FormulaExpression,
+    ) -> Operand {
+        match expression {
+            FormulaExpression::Number(v) => Operand::Value(CalculatedValue::Number(*v)),
+            FormulaExpression::Text(v) => Operand::Value(CalculatedValue::Text(v.clone())),
+            FormulaExpression::Bool(v) => Operand::Value(CalculatedValue::Bool(*v)),
+            FormulaExpression::Error(v) => Operand::error(v.clone()),
+            FormulaExpression::Empty => Operand::Value(CalculatedValue::Empty),
+            FormulaExpression::Reference(v) => self.resolve_reference(position, v),
+            FormulaExpression::Array(rows) => {
+                let mut result: Vec<Vec<CalculatedValue>> = Vec::new();
+                for row in rows {
+                    let mut values: Vec<CalculatedValue> = Vec::new();
+                    for expression in row {
+                        values.push(self.evaluate_scalar(position, expression));
+                    }
+                    result.push(values);
+                }
+                Operand::Value(CalculatedValue::Array(result))
+            }
+            FormulaExpression::Prefix(operator, operand) => {
+                let value = self.evaluate_value(position, operand);
+                let result = map_value(&value, &|v| match v.to_number() {
+                    Ok(n) if operator == "-" => CalculatedValue::Number(-n),
+                    Ok(n) => CalculatedValue::Number(n),
+                    Err(e) => CalculatedValue::Error(e),
+                });
+                Operand::Value(result)
+            }
+            FormulaExpression::Postfix(_, operand) => {
+                let value = self.evaluate_value(position, operand);
+                let result = map_value(&value, &|v| match v.to_number() {
+                    Ok(n) => CalculatedValue::Number(n / 100f64),
+                    Err(e) => CalculatedValue::Error(e),
+                });
+                Operand::Value(result)
+            }
+            FormulaExpression::Infix(operator, left, right) => {
+                if operator == " " {
+                    return self.intersect(position, left, right);
+                }
+                let left = self.evaluate_value(position, left);
+                let right = self.evaluate_value(position, right);
+                Operand::Value(zip_value(&left, &right, &|l, r| {
+                    binary_operation(operator, l, r)
+                }))
+            }
+            FormulaExpression::Function(name, arguments) => {
+                call_function(self, position, name, arguments)
+            }
+        }
+    }
+
+    /// Evaluate an expression. Multi-cell references become arrays.
+    pub(crate) fn evaluate_value(
+        &mut self,
+        position: &Position,
+        expression: &FormulaExpression,
+    ) -> CalculatedValue {
+        let operand = self.evaluate(position, expression);
+        self.operand_to_value(operand)
+    }
+
+    /// Evaluate an expression into a single value.
+    /// Multi-cell references are reduced by implicit intersection.
+    pub(crate) fn evaluate_scalar(
+        &mut self,
+        position: &Position,
+        expression: &FormulaExpression,
+    ) -> CalculatedValue {
+        let operand = self.evaluate(position, expression);
+        self.operand_to_scalar(position, operand)
+    }
+
+    pub(crate) fn operand_to_value(&mut self, operand: Operand) -> CalculatedValue {
+        match operand {
+            Operand::Value(v) => v,
+            Operand::Reference(r) if r.is_single_cell() => {
+                self.get_cell_value(r.sheet_index, r.col_start, r.row_start)
+            }
+            Operand::Reference(r) => CalculatedValue::Array(self.get_range_matrix(&r)),
+        }
+    }
+
+    pub(crate) fn operand_to_scalar(
+        &mut self,
+        position: &Position,
+        operand: Operand,
+    ) -> CalculatedValue {
+        match operand {
+            Operand::Value(v) => v.to_scalar(),
+            Operand::Reference(r) => {
+                if r.is_single_cell() {
+                    return self.get_cell_value(r.sheet_index, r.col_start, r.row_start);
+                }
+                // implicit intersection
+                if r.col_start == r.col_end
+                    && r.row_start <= position.row
+                    && position.row <= r.row_end
+                {
+                    return self.get_cell_value(r.sheet_index, r.col_start, position.row);
+                }
+                if r.row_start == r.row_end
+                    && r.col_start <= position.col
+                    && position.col <= r.col_end
+                {
+                    return self.get_cell_value(r.sheet_index, position.col, r.row_start);
+                }
+                CalculatedValue::Error(CellErrorType::Value)
+            }
+        }
+    }
+
+    /// Trim whole-column and whole-row references to the used area of the sheet.
+    pub(crate) fn clamp_reference(&self, reference: &RangeReference) -> RangeReference {
+        let (max_col, max_row) =
+            self.worksheets[reference.sheet_index].get_highest_column_and_row();
+        let mut result = reference.clone();
+        if result.row_start == 1 && result.row_end == MAX_ROW {
+            result.row_end = max_row.max(1);
+        }
+        if result.col_start == 1 && result.col_end == MAX_COLUMN {
+            result.col_end = max_col.max(1);
+        }
+        result
+    }
+
+    /// Values of a range in row-major order.
+    pub(crate) fn get_range_matrix(
+        &mut self,
+        reference: &RangeReference,
+    ) -> Vec<Vec<CalculatedValue>> {
+        let reference = self.clamp_reference(reference);
+        let mut result: Vec<Vec<CalculatedValue>> =
+            Vec::with_capacity(reference.get_row_count() as usize);
+        for row in reference.row_start..=reference.row_end {
+            let mut values: Vec<CalculatedValue> =
+                Vec::with_capacity(reference.get_col_count() as usize);
+            for col in reference.col_start..=reference.col_end {
+                values.push(self.get_cell_value(reference.sheet_index, col, row));
+            }
+            result.push(values);
+        }
+        result
+    }
+
+    /// Values of the existing cells of a range in row-major order.
+    /// Blank cells are skipped, so it is cheap even for whole-column references.
+    pub(crate) fn get_range_values(&mut self, reference: &RangeReference) -> Vec<CalculatedValue> {
+        let worksheet = self.worksheets[reference.sheet_index];
+        let coordinates: Vec<(u32, u32)> = worksheet
+            .get_cell_collection_crate()
+            .iter_coordinates_by_range_sorted_by_row(
+                reference.row_start,
+                reference.row_end,
+                reference.col_start,
+                reference.col_end,
+            )
+            .collect();
+        let mut result: Vec<CalculatedValue> = Vec::with_capacity(coordinates.len());
+        for (col, row) in coordinates {
+            let value = self.get_cell_value(reference.sheet_index, col, row);
+            if !value.is_empty() {
+                result.push(value);
+            }
+        }
+        result
+    }
+
+    /// Coordinates `(col, row)` of the existing cells of a range in row-major order.
+    pub(crate) fn get_range_coordinates(&self, reference: &RangeReference) -> Vec<(u32, u32)> {
+        self.worksheets[reference.sheet_index]
+            .get_cell_collection_crate()
+            .iter_coordinates_by_range_sorted_by_row(
+                reference.row_start,
+                reference.row_end,
+                reference.col_start,
+                reference.col_end,
+            )
+            .collect()
+    }
+
+    pub(crate) fn find_sheet_index(&self, sheet_name: &str) -> Option<usize> {
+        let sheet_name = sheet_name.to_lowercase();
+        self.worksheets
+            .iter()
+            .position(|v| v.get_name().to_lowercase() == sheet_name)
+    }
+
+    pub(crate) fn resolve_reference(&mut self, position: &Position, value: &str) -> Operand {
+        let (sheet_name, range) = split_address(value);
+        let sheet_index = if sheet_name.is_empty() {
+            Some(position.sheet_index)
+        } else {
+            self.find_sheet_index(sheet_name)
+        };
+        if let Some((col_start, row_start, col_end, row_end)) = parse_range_reference(range) {
+            return match sheet_index {
+                Some(sheet_index) => Operand::Reference(RangeReference {
+                    sheet_index,
+                    col_start,
+                    row_start,
+                    col_end,
+                    row_end,
+                }),
+                None => Operand::error(CellErrorType::Ref),
+            };
+        }
+        match sheet_index {
+            Some(sheet_index) => {
+                let position = Position {
+                    sheet_index,
+                    col: position.col,
+                    row: position.row,
+                };
+                self.resolve_defined_name(&position, range)
+            }
+            None => Operand::error(CellErrorType::Ref),
+        }
+    }
+
+    pub(crate) fn find_defined_name(
+        &self,
+        sheet_index: usize,
+        name: &str,
+    ) -> Option<&'a DefinedName> {
+        let name = name.to_lowercase();
+        let worksheet = self.worksheets[sheet_index];
+        if let Some(v) = worksheet
+            .get_defined_names()
+            .iter()
+            .find(|v| v.get_name().to_lowercase() == name)
+        {
+            return Some(v);
+        }
+        if let Some(v) = self.defined_names.iter().find(|v| {
+            v.has_local_sheet_id()
+                && *v.get_local_sheet_id() as usize == sheet_index
+                && v.get_name().to_lowercase() == name
+        }) {
+            return Some(v);
+        }
+        self.defined_names
+            .iter()
+            .find(|v| !v.has_local_sheet_id() && v.get_name().to_lowercase() == name)
+            .copied()
+    }
+
+    fn resolve_defined_name(&mut self, position: &Position, name: &str) -> Operand {
+        let defined_name = match self.find_defined_name(position.sheet_index, name) {
+            Some(v) => v,
+            None => return Operand::error(CellErrorType::Name),
+        };
+        if self.name_depth > MAX_NAME_DEPTH {
+            return Operand::error(CellErrorType::Name);
+        }
+        self.name_depth += 1;
+        let result = match defined_name.get_address_obj().first() {
+            Some(address) => {
+                let value =
+                    join_address(address.get_sheet_name(), &address.get_range().get_range());
+                self.resolve_reference(position, &value)
+            }
+            None => match parse_formula(&defined_name.get_address()) {
+                Ok(expression) => self.evaluate(position, &expression),
+                Err(e) => Operand::error(e),
+            },
+        };
+        self.name_depth -= 1;
+        result
+    }
+
+    fn intersect(
+        &mut self,
+        position: &Position,
+        left: &FormulaExpression,
+        right: &FormulaExpression,
+    ) -> Operand {
+        let left = self.evaluate(position, left);
+        let right = self.evaluate(position, right);
+        match (left, right) {
+            (Operand::Reference(l), Operand::Reference(r)) => {
+                let col_start = l.col_start.max(r.col_start);
+                let row_start = l.row_start.max(r.row_start);
+                let col_end = l.col_end.min(r.col_end);
+                let row_end = l.row_end.min(r.row_end);
+                if l.sheet_index != r.sheet_index || col_start > col_end || row_start > row_end {
+                    return Operand::error(CellErrorType::Null);
+                }
+                Operand::Reference(RangeReference {
+                    sheet_index: l.sheet_index,
+                    col_start,
+                    row_start,
+                    col_end,
+                    row_end,
+                })
+            }
+            _ => Operand::error(CellErrorType::Value),
+        }
+    }
+}
+
+/// Apply a function to a value or to every element of an array.
+pub(crate) fn map_value(
+    value: &CalculatedValue,
+    f: &dyn Fn(&CalculatedValue) -> CalculatedValue,
+) -> CalculatedValue {
+    match value {
+        CalculatedValue::Array(rows) => CalculatedValue::Array(
+            rows.iter()
+                .map(|row| row.iter().map(|v| f(v)).collect())
+                .collect(),
+        ),
+        _ => f(value),
+    }
+}
+
+/// Apply a binary function element-wise, broadcasting single values, rows and columns.
+pub(crate) fn zip_value(
+    left: &CalculatedValue,
+    right: &CalculatedValue,
+    f: &dyn Fn(&CalculatedValue, &CalculatedValue) -> CalculatedValue,
+) -> CalculatedValue {
+    fn dimension(value: &CalculatedValue) -> (usize, usize) {
+        match value {
+            CalculatedValue::Array(rows) => {
+                (rows.len(), rows.first().map(|r| r.len()).unwrap_or(0))
+            }
+            _ => (1, 1),
+        }
+    }
+    fn element(value: &CalculatedValue, row: usize, col: usize) -> CalculatedValue {
+        match value {
+            CalculatedValue::Array(rows) => {
+                let row = if rows.len() == 1 { 0 } else { row };
+                match rows.get(row) {
+                    Some(r) => {
+                        let col = if r.len() == 1 { 0 } else { col };
+                        r.get(col)
+                            .cloned()
+                            .unwrap_or(CalculatedValue::Error(CellErrorType::NA))
+                    }
+                    None => CalculatedValue::Error(CellErrorType::NA),
+                }
+            }
+            _ => value.clone(),
+        }
+    }
+
+    match (left, right) {
+        (CalculatedValue::Array(_), _) | (_, CalculatedValue::Array(_)) => {
+            let (left_rows, left_cols) = dimension(left);
+            let (right_rows, right_cols) = dimension(right);
+            let rows = left_rows.max(right_rows);
+            let cols = left_cols.max(right_cols);
+            let mut result: Vec<Vec<CalculatedValue>> = Vec::with_capacity(rows);
+            for row in 0..rows {
+                let mut values: Vec<CalculatedValue> = Vec::with_capacity(cols);
+                for col in 0..cols {
+                    values.push(f(&element(left, row, col), &element(right, row, col)));
+                }
+                result.push(values);
+            }
+            CalculatedValue::Array(result)
+        }
+        _ => f(left, right),
+    }
+}
+
+pub(crate) fn binary_operation(
+    operator: &str,
+    left: &CalculatedValue,
+    right: &CalculatedValue,
+) -> CalculatedValue {
+    if let CalculatedValue::Error(e) = left {
+        return CalculatedValue::Error(e.clone());
+    }
+    if let CalculatedValue::Error(e) = right {
+        return CalculatedValue::Error(e.clone());
+    }
+    match operator {
+        "&" => match (left.to_text(), right.to_text()) {
+            (Ok(l), Ok(r)) => CalculatedValue::Text(format!("{}{}", l, r)),
+            (Err(e), _) | (_, Err(e)) => CalculatedValue::Error(e),
+        },
+        "=" | "<>" | "<" | ">" | "<=" | ">=" => {
+            let ordering = left.compare(right);
+            let result = match operator {
+                "=" => ordering.is_eq(),
+                "<>" => ordering.is_ne(),
+                "<" => ordering.is_lt(),
+                ">" => ordering.is_gt(),
+                "<=" => ordering.is_le(),
+                _ => ordering.is_ge(),
+            };
+            CalculatedValue::Bool(result)
+        }
+        _ => {
+            let (l, r) = match (left.to_number(), right.to_number()) {
+                (Ok(l), Ok(r)) => (l, r),
+                (Err(e), _) | (_, Err(e)) => return CalculatedValue::Error(e),
+            };
+            let result = match operator {
+                "+" => l + r,
+                "-" => l - r,
+                "*" => l * r,
+                "/" => {
+                    if r == 0f64 {
+                        return CalculatedValue::Error(CellErrorType::Div0);
+                    }
+                    l / r
+                }
+                "^" => {
+                    if l == 0f64 && r == 0f64 {
+                        return CalculatedValue::Error(CellErrorType::Num);
+                    }
+                    l.powf(r)
+                }
+                _ => return CalculatedValue::Error(CellErrorType::Value),
+            };
+            number_result(result)
+        }
+    }
+}
+
+/// Wrap a number, turning NaN and infinity into `#NUM!`.
+#[inline]
+pub(crate) fn number_result(value: f64) -> CalculatedValue {
+    if value.is_finite() {
+        CalculatedValue::Number(value)
+    } else {
+        CalculatedValue::Error(CellErrorType::Num)
+    }
+}
@@ -0,0 +1,1649 @@
+use super::evaluator::*;
+use super::parser::*;
+use super::value::*;
+use crate::helper::date::*;
+use crate::helper::number_format::*;
+use crate::structs::CellErrorType;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, Timelike};
+use regex::Regex;
+use std::cmp::Ordering;
+
+type Matrix = Vec<Vec<CalculatedValue>>;
+
+/// Evaluate a built-in worksheet function.
+pub(crate) fn call_function(
+    calc: &mut Calculator,
+    position: &Position,
+    name: &str,
+    args: &[FormulaExpression],
+) -> Operand {
+    let name = name
+        .trim_start_matches("_XLFN.")
+        .trim_start_matches("_XLWS.");
+
+    // functions that may return a reference or evaluate their arguments lazily
+    let result = match name {
+        "IF" => function_if(calc, position, args),
+        "IFERROR" => function_iferror(calc, position, args, false),
+        "IFNA" => function_iferror(calc, position, args, true),
+        "IFS" => function_ifs(calc, position, args),
+        "SWITCH" => function_switch(calc, position, args),
+        "CHOOSE" => function_choose(calc, position, args),
+        "INDEX" => function_index(calc, position, args),
+        "OFFSET" => function_offset(calc, position, args),
+        "INDIRECT" => function_indirect(calc, position, args),
+        _ => {
+            return match call_value_function(calc, position, name, args) {
+                Ok(v) => Operand::Value(v),
+                Err(e) => Operand::error(e),
+            }
+        }
+    };
+    match result {
+        Ok(v) => v,
+        Err(e) => Operand::error(e),
+    }
+}
+
+fn call_value_function(
+    calc: &mut Calculator,
+    position: &Position,
+    name: &str,
+    args: &[FormulaExpression],
+) -> Result<CalculatedValue, CellErrorType> {
+    let value = match name {
+        // math
+        "SUM" => CalculatedValue::Number(collect_numbers(calc, position, args)?.iter().sum()),
+        "PRODUCT" => {
+            let numbers = collect_numbers(calc, position, args)?;
+            match numbers.is_empty() {
+                true => CalculatedValue::Number(0f64),
+                false => number_result(numbers.iter().product()),
+            }
+        }
+        "SUMSQ" => CalculatedValue::Number(
+            collect_numbers(calc, position, args)?
+                .iter()
+                .map(|v| v * v)
+                .sum(),
+        ),
+        "AVERAGE" => number_result(average(&collect_numbers(calc, position, args)?)?),
+        "MIN" => CalculatedValue::Number(minimum(&collect_numbers(calc, position, args)?)),
+        "MAX" => CalculatedValue::Number(maximum(&collect_numbers(calc, position, args)?)),
+        "MEDIAN" => CalculatedValue::Number(median(collect_numbers(calc, position, args)?)?),
+        "STDEV" | "STDEV.S" => {
+            number_result(variance(&collect_numbers(calc, position, args)?, true)?.sqrt())
+        }
+        "STDEVP" | "STDEV.P" => {
+            number_result(variance(&collect_numbers(calc, position, args)?, false)?.sqrt())
+        }
+        "VAR" | "VAR.S" => number_result(variance(&collect_numbers(calc, position, args)?, true)?),
+        "VARP" | "VAR.P" => {
+            number_result(variance(&collect_numbers(calc, position, args)?, false)?)
+        }
+        "LARGE" | "SMALL" => {
+            arity(args, 2, 2)?;
+            let mut numbers = collect_numbers(calc, position, &args[0..1])?;
+            let k = number_arg(calc, position, &args[1])?.ceil();
+            if k < 1f64 || k as usize > numbers.len() {
+                return Err(CellErrorType::Num);
+            }
+            numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            if name == "LARGE" {
+                numbers.reverse();
+            }
+            CalculatedValue::Number(numbers[k as usize - 1])
+        }
+        "COUNT" => CalculatedValue::Number(count_values(calc, position, args, false) as f64),
+        "COUNTA" => CalculatedValue::Number(count_values(calc, position, args, true) as f64),
+        "COUNTBLANK" => {
+            arity(args, 1, 1)?;
+            match calc.evaluate(position, &args[0]) {
+                Operand::Reference(r) => {
+                    let total = r.get_row_count() as f64 * r.get_col_count() as f64;
+                    let filled = calc
+                        .get_range_values(&r)
+                        .iter()
+                        .filter(|v| !is_blank(v))
+                        .count();
+                    CalculatedValue::Number(total - filled as f64)
+                }
+                _ => return Err(CellErrorType::Value),
+            }
+        }
+        "SUBTOTAL" => function_subtotal(calc, position, args)?,
+        "SUMPRODUCT" => function_sumproduct(calc, position, args)?,
+        "ABS" => CalculatedValue::Number(number_arg1(calc, position, args)?.abs()),
+        "SIGN" => {
+            let n = number_arg1(calc, position, args)?;
+            CalculatedValue::Number(if n > 0f64 {
+                1f64
+            } else if n < 0f64 {
+                -1f64
+            } else {
+                0f64
+            })
+        }
+        "INT" => CalculatedValue::Number(number_arg1(calc, position, args)?.floor()),
+        "TRUNC" | "ROUND" | "ROUNDUP" | "ROUNDDOWN" => {
+            arity(args, 1, 2)?;
+            let n = number_arg(calc, position, &args[0])?;
+            let digits = optional_number_arg(calc, position, args, 1, 0f64)?.trunc() as i32;
+            let mode = match name {
+                "ROUND" => RoundMode::HalfUp,
+                "ROUNDUP" => RoundMode::Up,
+                _ => RoundMode::Down,
+            };
+            CalculatedValue::Number(round_number(n, digits, mode))
+        }
+        "MOD" => {
+            arity(args, 2, 2)?;
+            let n = number_arg(calc, position, &args[0])?;
+            let d = number_arg(calc, position, &args[1])?;
+            if d == 0f64 {
+                return Err(CellErrorType::Div0);
+            }
+            number_result(n - d * (n / d).floor())
+        }
+        "POWER" => {
+            arity(args, 2, 2)?;
+            let n = calc.evaluate_scalar(position, &args[0]);
+            let p = calc.evaluate_scalar(position, &args[1]);
+            binary_operation("^", &n, &p)
+        }
+        "SQRT" => {
+            let n = number_arg1(calc, position, args)?;
+            if n < 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            CalculatedValue::Number(n.sqrt())
+        }
+        "EXP" => number_result(number_arg1(calc, position, args)?.exp()),
+        "LN" => {
+            let n = number_arg1(calc, position, args)?;
+            if n <= 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            CalculatedValue::Number(n.ln())
+        }
+        "LOG" | "LOG10" => {
+            arity(args, 1, if name == "LOG" { 2 } else { 1 })?;
+            let n = number_arg(calc, position, &args[0])?;
+            let base = optional_number_arg(calc, position, args, 1, 10f64)?;
+            if n <= 0f64 || base <= 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            if base == 1f64 {
+                return Err(CellErrorType::Div0);
+            }
+            number_result(n.log(base))
+        }
+        "PI" => {
+            arity(args, 0, 0)?;
+            CalculatedValue::Number(std::f64::consts::PI)
+        }
+        "CEILING" | "FLOOR" | "CEILING.MATH" | "FLOOR.MATH" => {
+            arity(args, 1, 2)?;
+            let n = number_arg(calc, position, &args[0])?;
+            let significance = optional_number_arg(calc, position, args, 1, 1f64)?;
+            if significance == 0f64 {
+                return Ok(CalculatedValue::Number(0f64));
+            }
+            if n > 0f64 && significance < 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            let quotient = round_number(n / significance, 10, RoundMode::HalfUp);
+            let result = match name.starts_with("CEILING") {
+                true => quotient.ceil() * significance,
+                false => quotient.floor() * significance,
+            };
+            number_result(result)
+        }
+
+        // logical
+        "TRUE" => CalculatedValue::Bool(true),
+        "FALSE" => CalculatedValue::Bool(false),
+        "NOT" => CalculatedValue::Bool(!bool_arg1(calc, position, args)?),
+        "AND" | "OR" | "XOR" => {
+            let values = collect_bools(calc, position, args)?;
+            if values.is_empty() {
+                return Err(CellErrorType::Value);
+            }
+            CalculatedValue::Bool(match name {
+                "AND" => values.iter().all(|v| *v),
+                "OR" => values.iter().any(|v| *v),
+                _ => values.iter().filter(|v| **v).count() % 2 == 1,
+            })
+        }
+
+        // text
+        "CONCATENATE" => {
+            let mut result = String::new();
+            for arg in args {
+                result.push_str(&calc.evaluate_scalar(position, arg).to_text()?);
+            }
+            CalculatedValue::Text(result)
+        }
+        "CONCAT" => {
+            let mut result = String::new();
+            for value in collect_all_values(calc, position, args) {
+                result.push_str(&value.to_text()?);
+            }
+            CalculatedValue::Text(result)
+        }
+        "TEXTJOIN" => {
+            if args.len() < 3 {
+                return Err(CellErrorType::Value);
+            }
+            let delimiter = text_arg(calc, position, &args[0])?;
+            let ignore_empty = bool_arg(calc, position, &args[1])?;
+            let mut list: Vec<String> = Vec::new();
+            for value in collect_all_values(calc, position, &args[2..]) {
+                let text = value.to_text()?;
+                if ignore_empty && text.is_empty() {
+                    continue;
+                }
+                list.push(text);
+            }
+            CalculatedValue::Text(list.join(&delimiter))
+        }
+        "LEN" => CalculatedValue::Number(text_arg1(calc, position, args)?.chars().count() as f64),
+        "LEFT" | "RIGHT" => {
+            arity(args, 1, 2)?;
+            let text = text_arg(calc, position, &args[0])?;
+            let count = optional_number_arg(calc, position, args, 1, 1f64)?;
+            if count < 0f64 {
+                return Err(CellErrorType::Value);
+            }
+            let count = count as usize;
+            let chars: Vec<char> = text.chars().collect();
+            let result: String = match name {
+                "LEFT" => chars.iter().take(count).collect(),
+                _ => chars[chars.len().saturating_sub(count)..].iter().collect(),
+            };
+            CalculatedValue::Text(result)
+        }
+        "MID" => {
+            arity(args, 3, 3)?;
+            let text = text_arg(calc, position, &args[0])?;
+            let start = number_arg(calc, position, &args[1])?;
+            let count = number_arg(calc, position, &args[2])?;
+            if start < 1f64 || count < 0f64 {
+                return Err(CellErrorType::Value);
+            }
+            CalculatedValue::Text(
+                text.chars()
+                    .skip(start as usize - 1)
+                    .take(count as usize)
+                    .collect(),
+            )
+        }
+        "UPPER" => CalculatedValue::Text(text_arg1(calc, position, args)?.to_uppercase()),
+        "LOWER" => CalculatedValue::Text(text_arg1(calc, position, args)?.to_lowercase()),
+        "PROPER" => {
+            let text = text_arg1(calc, position, args)?;
+            let mut result = String::with_capacity(text.len());
+            let mut is_previous_letter = false;
+            for c in text.chars() {
+                if is_previous_letter {
+                    result.extend(c.to_lowercase());
+                } else {
+                    result.extend(c.to_uppercase());
+                }
+                is_previous_letter = c.is_alphabetic();
+            }
+            CalculatedValue::Text(result)
+        }
+        "TRIM" => CalculatedValue::Text(
+            text_arg1(calc, position, args)?
+                .split(' ')
+                .filter(|v| !v.is_empty())
+                .collect::<Vec<&str>>()
+                .join(" "),
+        ),
+        "REPT" => {
+            arity(args, 2, 2)?;
+            let text = text_arg(calc, position, &args[0])?;
+            let count = number_arg(calc, position, &args[1])?;
+            if count < 0f64 {
+                return Err(CellErrorType::Value);
+            }
+            CalculatedValue::Text(text.repeat(count as usize))
+        }
+        "EXACT" => {
+            arity(args, 2, 2)?;
+            let left = text_arg(calc, position, &args[0])?;
+            let right = text_arg(calc, position, &args[1])?;
+            CalculatedValue::Bool(left == right)
+        }
+        "SUBSTITUTE" => {
+            arity(args, 3, 4)?;
+            let text = text_arg(calc, position, &args[0])?;
+            let old_text = text_arg(calc, position, &args[1])?;
+            let new_text = text_arg(calc, position, &args[2])?;
+            if old_text.is_empty() {
+                return Ok(CalculatedValue::Text(text));
+            }
+            match args.get(3) {
+                Some(arg) => {
+                    let instance = number_arg(calc, position, arg)?;
+                    if instance < 1f64 {
+                        return Err(CellErrorType::Value);
+                    }
+                    match text.match_indices(&old_text).nth(instance as usize - 1) {
+                        Some((index, _)) => CalculatedValue::Text(format!(
+                            "{}{}{}",
+                            &text[..index],
+                            new_text,
+                            &text[index + old_text.len()..]
+                        )),
+                        None => CalculatedValue::Text(text),
+                    }
+                }
+                None => CalculatedValue::Text(text.replace(&old_text, &new_text)),
+            }
+        }
+        "FIND" | "SEARCH" => {
+            arity(args, 2, 3)?;
+            let find_text = text_arg(calc, position, &args[0])?;
+            let within_text = text_arg(calc, position, &args[1])?;
+            let start = optional_number_arg(calc, position, args, 2, 1f64)?;
+            let chars: Vec<char> = within_text.chars().collect();
+            if start < 1f64 || start as usize > chars.len() + 1 {
+                return Err(CellErrorType::Value);
+            }
+            let offset = start as usize - 1;
+            let haystack: String = chars[offset..].iter().collect();
+            let found = match name {
+                "FIND" => haystack
+                    .find(&find_text)
+                    .map(|i| haystack[..i].chars().count()),
+                _ => wildcard_regex(&find_text, false)
+                    .find(&haystack)
+                    .map(|m| haystack[..m.start()].chars().count()),
+            };
+            match found {
+                Some(i) => CalculatedValue::Number((offset + i + 1) as f64),
+                None => return Err(CellErrorType::Value),
+            }
+        }
+        "TEXT" => {
+            arity(args, 2, 2)?;
+            let value = calc.evaluate_scalar(position, &args[0]);
+            let format = text_arg(calc, position, &args[1])?;
+            let value = match value {
+                CalculatedValue::Number(n) => n.to_string(),
+                CalculatedValue::Error(e) => return Err(e),
+                v => v.to_text()?,
+            };
+            CalculatedValue::Text(to_formatted_string(value, format))
+        }
+        "VALUE" => {
+            arity(args, 1, 1)?;
+            match calc.evaluate_scalar(position, &args[0]) {
+                CalculatedValue::Number(n) => CalculatedValue::Number(n),
+                CalculatedValue::Empty => CalculatedValue::Number(0f64),
+                CalculatedValue::Text(v) => match parse_number_text(&v) {
+                    Some(n) => CalculatedValue::Number(n),
+                    None => return Err(CellErrorType::Value),
+                },
+                CalculatedValue::Error(e) => return Err(e),
+                _ => return Err(CellErrorType::Value),
+            }
+        }
+
+        // information
+        "ISBLANK" | "ISNUMBER" | "ISTEXT" | "ISNONTEXT" | "ISLOGICAL" | "ISERROR" | "ISERR"
+        | "ISNA" => {
+            arity(args, 1, 1)?;
+            let value = calc.evaluate_scalar(position, &args[0]);
+            CalculatedValue::Bool(match name {
+                "ISBLANK" => value.is_empty(),
+                "ISNUMBER" => matches!(value, CalculatedValue::Number(_)),
+                "ISTEXT" => matches!(value, CalculatedValue::Text(_)),
+                "ISNONTEXT" => !matches!(value, CalculatedValue::Text(_)),
+                "ISLOGICAL" => matches!(value, CalculatedValue::Bool(_)),
+                "ISERROR" => value.is_error(),
+                "ISERR" => value.is_error() && value.get_error() != Some(&CellErrorType::NA),
+                _ => value.get_error() == Some(&CellErrorType::NA),
+            })
+        }
+        "ISEVEN" | "ISODD" => {
+            let n = number_arg1(calc, position, args)?.trunc() as i64;
+            CalculatedValue::Bool((n % 2 == 0) == (name == "ISEVEN"))
+        }
+        "NA" => return Err(CellErrorType::NA),
+
+        // lookup and reference
+        "VLOOKUP" | "HLOOKUP" => function_lookup(calc, position, args, name == "HLOOKUP")?,
+        "MATCH" => function_match(calc, position, args)?,
+        "XLOOKUP" => function_xlookup(calc, position, args)?,
+        "ROW" | "COLUMN" => {
+            arity(args, 0, 1)?;
+            let is_row = name == "ROW";
+            match args.first() {
+                None | Some(FormulaExpression::Empty) => CalculatedValue::Number(if is_row {
+                    position.row as f64
+                } else {
+                    position.col as f64
+                }),
+                Some(arg) => match calc.evaluate(position, arg) {
+                    Operand::Reference(r) => CalculatedValue::Number(if is_row {
+                        r.row_start as f64
+                    } else {
+                        r.col_start as f64
+                    }),
+                    _ => return Err(CellErrorType::Value),
+                },
+            }
+        }
+        "ROWS" | "COLUMNS" => {
+            arity(args, 1, 1)?;
+            let (rows, cols) = match calc.evaluate(position, &args[0]) {
+                Operand::Reference(r) => (r.get_row_count() as usize, r.get_col_count() as usize),
+                Operand::Value(CalculatedValue::Array(v)) => {
+                    (v.len(), v.first().map(|r| r.len()).unwrap_or(0))
+                }
+                Operand::Value(CalculatedValue::Error(e)) => return Err(e),
+                Operand::Value(_) => (1, 1),
+            };
+            CalculatedValue::Number(if name == "ROWS" { rows } else { cols } as f64)
+        }
+
+        // conditional aggregation
+        "COUNTIF" => {
+            arity(args, 2, 2)?;
+            let (_, _, matched) = match_criteria(calc, position, &[(&args[0], &args[1])])?;
+            CalculatedValue::Number(matched.len() as f64)
+        }
+        "COUNTIFS" => {
+            let pairs = criteria_pairs(args)?;
+            let (_, _, matched) = match_criteria(calc, position, &pairs)?;
+            CalculatedValue::Number(matched.len() as f64)
+        }
+        "SUMIF" | "AVERAGEIF" => {
+            arity(args, 2, 3)?;
+            let (rows, cols, matched) = match_criteria(calc, position, &[(&args[0], &args[1])])?;
+            let target = match args.get(2) {
+                Some(v) if v != &FormulaExpression::Empty => v,
+                _ => &args[0],
+            };
+            let numbers = matched_numbers(calc, position, target, rows, cols, &matched)?;
+            match name {
+                "SUMIF" => CalculatedValue::Number(numbers.iter().sum()),
+                _ => CalculatedValue::Number(average(&numbers)?),
+            }
+        }
+        "SUMIFS" | "AVERAGEIFS" | "MAXIFS" | "MINIFS" => {
+            if args.len() < 3 {
+                return Err(CellErrorType::Value);
+            }
+            let pairs = criteria_pairs(&args[1..])?;
+            let (rows, cols, matched) = match_criteria(calc, position, &pairs)?;
+            let numbers = matched_numbers(calc, position, &args[0], rows, cols, &matched)?;
+            match name {
+                "SUMIFS" => CalculatedValue::Number(numbers.iter().sum()),
+                "AVERAGEIFS" => CalculatedValue::Number(average(&numbers)?),
+                "MAXIFS" => CalculatedValue::Number(maximum(&numbers)),
+                _ => CalculatedValue::Number(minimum(&numbers)),
+            }
+        }
+
+        // date and time
+        "DATE" => {
+            arity(args, 3, 3)?;
+            let year = number_arg(calc, position, &args[0])?.trunc() as i32;
+            let month = number_arg(calc, position, &args[1])?.trunc() as i32;
+            let day = number_arg(calc, position, &args[2])?.trunc() as i64;
+            let year = if (0..1900).contains(&year) {
+                year + 1900
+            } else {
+                year
+            };
+            let total_month = year * 12 + month - 1;
+            let first_day = NaiveDate::from_ymd_opt(
+                total_month.div_euclid(12),
+                total_month.rem_euclid(12) as u32 + 1,
+                1,
+            )
+            .ok_or(CellErrorType::Num)?;
+            let serial = date_to_serial(&(first_day + Duration::days(day - 1)));
+            if serial < 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            CalculatedValue::Number(serial)
+        }
+        "TIME" => {
+            arity(args, 3, 3)?;
+            let hour = number_arg(calc, position, &args[0])?.trunc();
+            let minute = number_arg(calc, position, &args[1])?.trunc();
+            let second = number_arg(calc, position, &args[2])?.trunc();
+            let seconds = hour * 3600f64 + minute * 60f64 + second;
+            if seconds < 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            CalculatedValue::Number((seconds / 86400f64).fract())
+        }
+        "YEAR" | "MONTH" | "DAY" | "HOUR" | "MINUTE" | "SECOND" | "WEEKDAY" => {
+            arity(args, 1, 2)?;
+            let serial = number_arg(calc, position, &args[0])?;
+            if serial < 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            let datetime = serial_to_date_time(serial);
+            CalculatedValue::Number(match name {
+                "YEAR" => datetime.year() as f64,
+                "MONTH" => datetime.month() as f64,
+                "DAY" => datetime.day() as f64,
+                "HOUR" => datetime.hour() as f64,
+                "MINUTE" => datetime.minute() as f64,
+                "SECOND" => datetime.second() as f64,
+                _ => {
+                    let return_type = optional_number_arg(calc, position, args, 1, 1f64)? as u32;
+                    let from_sunday = datetime.weekday().num_days_from_sunday() as f64;
+                    let from_monday = datetime.weekday().num_days_from_monday() as f64;
+                    match return_type {
+                        1 => from_sunday + 1f64,
+                        2 => from_monday + 1f64,
+                        3 => from_monday,
+                        _ => return Err(CellErrorType::Num),
+                    }
+                }
+            })
+        }
+        "EDATE" | "EOMONTH" => {
+            arity(args, 2, 2)?;
+            let serial = number_arg(calc, position, &args[0])?;
+            let months = number_arg(calc, position, &args[1])?.trunc() as i32;
+            let date = serial_to_date_time(serial).date();
+            let total_month = date.year() * 12 + date.month0() as i32 + months;
+            let year = total_month.div_euclid(12);
+            let month = total_month.rem_euclid(12) as u32 + 1;
+            let last_day = last_day_of_month(year, month).ok_or(CellErrorType::Num)?;
+            let result = match name {
+                "EDATE" => NaiveDate::from_ymd_opt(year, month, date.day().min(last_day)),
+                _ => NaiveDate::from_ymd_opt(year, month, last_day),
+            }
+            .ok_or(CellErrorType::Num)?;
+            CalculatedValue::Number(date_to_serial(&result))
+        }
+        "DAYS" => {
+            arity(args, 2, 2)?;
+            let end = number_arg(calc, position, &args[0])?.trunc();
+            let start = number_arg(calc, position, &args[1])?.trunc();
+            CalculatedValue::Number(end - start)
+        }
+        "TODAY" => {
+            arity(args, 0, 0)?;
+            CalculatedValue::Number(date_to_serial(&Local::now().date_naive()))
+        }
+        "NOW" => {
+            arity(args, 0, 0)?;
+            let now = Local::now().naive_local();
+            let time = now.num_seconds_from_midnight() as f64 / 86400f64;
+            CalculatedValue::Number(date_to_serial(&now.date()) + time)
+        }
+
+        _ => return Err(CellErrorType::Name),
+    };
+    Ok(value)
+}
+
+// ************************
+// argument helpers
+// ************************
+#[inline]
+fn arity(args: &[FormulaExpression], min: usize, max: usize) -> Result<(), CellErrorType> {
+    if args.len() < min || args.len() > max {
+        return Err(CellErrorType::Value);
+    }
+    Ok(())
+}
+
+#[inline]
+fn number_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    arg: &FormulaExpression,
+) -> Result<f64, CellErrorType> {
+    calc.evaluate_scalar(position, arg).to_number()
+}
+
+#[inline]
+fn number_arg1(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<f64, CellErrorType> {
+    arity(args, 1, 1)?;
+    number_arg(calc, position, &args[0])
+}
+
+fn optional_number_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+    index: usize,
+    default: f64,
+) -> Result<f64, CellErrorType> {
+    match args.get(index) {
+        Some(FormulaExpression::Empty) | None => Ok(default),
+        Some(arg) => number_arg(calc, position, arg),
+    }
+}
+
+#[inline]
+fn text_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    arg: &FormulaExpression,
+) -> Result<String, CellErrorType> {
+    calc.evaluate_scalar(position, arg).to_text()
+}
+
+#[inline]
+fn text_arg1(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<String, CellErrorType> {
+    arity(args, 1, 1)?;
+    text_arg(calc, position, &args[0])
+}
+
+#[inline]
+fn bool_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    arg: &FormulaExpression,
+) -> Result<bool, CellErrorType> {
+    calc.evaluate_scalar(position, arg).to_bool()
+}
+
+#[inline]
+fn bool_arg1(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<bool, CellErrorType> {
+    arity(args, 1, 1)?;
+    bool_arg(calc, position, &args[0])
+}
+
+/// Evaluate an argument into a matrix. Single values become a 1x1 matrix.
+fn matrix_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    arg: &FormulaExpression,
+) -> Result<Matrix, CellErrorType> {
+    match calc.evaluate(position, arg) {
+        Operand::Reference(r) => Ok(calc.get_range_matrix(&r)),
+        Operand::Value(CalculatedValue::Array(v)) => Ok(v),
+        Operand::Value(CalculatedValue::Error(e)) => Err(e),
+        Operand::Value(v) => Ok(vec![vec![v]]),
+    }
+}
+
+/// Evaluate an argument into a matrix of the given size, anchored at its top-left cell.
+fn sized_matrix_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    arg: &FormulaExpression,
+    rows: usize,
+    cols: usize,
+) -> Result<Matrix, CellErrorType> {
+    match calc.evaluate(position, arg) {
+        Operand::Reference(r) => {
+            let reference = RangeReference {
+                sheet_index: r.sheet_index,
+                col_start: r.col_start,
+                row_start: r.row_start,
+                col_end: r.col_start + cols.max(1) as u32 - 1,
+                row_end: r.row_start + rows.max(1) as u32 - 1,
+            };
+            Ok(calc.get_range_matrix(&reference))
+        }
+        Operand::Value(CalculatedValue::Array(v)) => {
+            if v.len() != rows || v.first().map(|r| r.len()).unwrap_or(0) != cols {
+                return Err(CellErrorType::Value);
+            }
+            Ok(v)
+        }
+        Operand::Value(CalculatedValue::Error(e)) => Err(e),
+        Operand::Value(v) => Ok(vec![vec![v]]),
+    }
+}
+
+/// Numbers of the arguments, following the rules of SUM.
+/// Texts, logical values and blanks in references or arrays are ignored,
+/// values given directly are converted.
+fn collect_numbers(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Vec<f64>, CellErrorType> {
+    let mut result: Vec<f64> = Vec::new();
+    for arg in args {
+        let values = match calc.evaluate(position, arg) {
+            Operand::Reference(r) => calc.get_range_values(&r),
+            Operand::Value(CalculatedValue::Array(v)) => v.into_iter().flatten().collect(),
+            Operand::Value(CalculatedValue::Empty) => continue,
+            Operand::Value(v) => {
+                result.push(v.to_number()?);
+                continue;
+            }
+        };
+        for value in values {
+            match value {
+                CalculatedValue::Number(n) => result.push(n),
+                CalculatedValue::Error(e) => return Err(e),
+                _ => {}
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Every value of the arguments, with references and arrays flattened.
+fn collect_all_values(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Vec<CalculatedValue> {
+    let mut result: Vec<CalculatedValue> = Vec::new();
+    for arg in args {
+        match calc.evaluate(position, arg) {
+            Operand::Reference(r) => {
+                result.extend(calc.get_range_matrix(&r).into_iter().flatten());
+            }
+            Operand::Value(CalculatedValue::Array(v)) => result.extend(v.into_iter().flatten()),
+            Operand::Value(v) => result.push(v),
+        }
+    }
+    result
+}
+
+fn collect_bools(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Vec<bool>, CellErrorType> {
+    let mut result: Vec<bool> = Vec::new();
+    for arg in args {
+        let values = match calc.evaluate(position, arg) {
+            Operand::Reference(r) => calc.get_range_values(&r),
+            Operand::Value(CalculatedValue::Array(v)) => v.into_iter().flatten().collect(),
+            Operand::Value(v) => {
+                result.push(v.to_bool()?);
+                continue;
+            }
+        };
+        for value in values {
+            match value {
+                CalculatedValue::Number(n) => result.push(n != 0f64),
+                CalculatedValue::Bool(b) => result.push(b),
+                CalculatedValue::Error(e) => return Err(e),
+                _ => {}
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn count_values(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+    count_all: bool,
+) -> usize {
+    let mut result = 0;
+    for arg in args {
+        let values = match calc.evaluate(position, arg) {
+            Operand::Reference(r) => calc.get_range_values(&r),
+            Operand::Value(CalculatedValue::Array(v)) => v.into_iter().flatten().collect(),
+            Operand::Value(v) => {
+                let is_count = match count_all {
+                    true => !v.is_empty(),
+                    false => !v.is_error() && v.to_number().is_ok(),
+                };
+                if is_count {
+                    result += 1;
+                }
+                continue;
+            }
+        };
+        result += values
+            .iter()
+            .filter(|v| match count_all {
+                true => !v.is_empty(),
+                false => matches!(v, CalculatedValue::Number(_)),
+            })
+            .count();
+    }
+    result
+}
+
+#[inline]
+fn is_blank(value: &CalculatedValue) -> bool {
+    match value {
+        CalculatedValue::Empty => true,
+        CalculatedValue::Text(v) => v.is_empty(),
+        _ => false,
+    }
+}
+
+// ************************
+// statistics
+// ************************
+fn average(numbers: &[f64]) -> Result<f64, CellErrorType> {
+    if numbers.is_empty() {
+        return Err(CellErrorType::Div0);
+    }
+    Ok(numbers.iter().sum::<f64>() / numbers.len() as f64)
+}
+
+#[inline]
+fn minimum(numbers: &[f64]) -> f64 {
+    match numbers.is_empty() {
+        true => 0f64,
+        false => numbers.iter().cloned().fold(f64::INFINITY, f64::min),
+    }
+}
+
+#[inline]
+fn maximum(numbers: &[f64]) -> f64 {
+    match numbers.is_empty() {
+        true => 0f64,
+        false => numbers.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+    }
+}
+
+fn median(mut numbers: Vec<f64>) -> Result<f64, CellErrorType> {
+    if numbers.is_empty() {
+        return Err(CellErrorType::Num);
+    }
+    numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let middle = numbers.len() / 2;
+    match numbers.len() % 2 {
+        0 => Ok((numbers[middle - 1] + numbers[middle]) / 2f64),
+        _ => Ok(numbers[middle]),
+    }
+}
+
+fn variance(numbers: &[f64], is_sample: bool) -> Result<f64, CellErrorType> {
+    let count = numbers.len();
+    if count == 0 || (is_sample && count == 1) {
+        return Err(CellErrorType::Div0);
+    }
+    let mean = numbers.iter().sum::<f64>() / count as f64;
+    let sum_of_squares: f64 = numbers.iter().map(|v| (v - mean) * (v - mean)).sum();
+    let divisor = if is_sample { count - 1 } else { count };
+    Ok(sum_of_squares / divisor as f64)
+}
+
+#[derive(PartialEq)]
+enum RoundMode {
+    HalfUp,
+    Up,
+    Down,
+}
+
+fn round_number(value: f64, digits: i32, mode: RoundMode) -> f64 {
+    let factor = 10f64.powi(digits);
+    // Drop the binary representation noise beyond 15 significant digits first,
+    // so that 2.675 is rounded like the decimal number Excel shows.
+    let scaled = format!("{:.14e}", value * factor)
+        .parse::<f64>()
+        .unwrap_or(value * factor);
+    let result = match mode {
+        RoundMode::HalfUp => scaled.round(),
+        RoundMode::Up => scaled.abs().ceil() * scaled.signum(),
+        RoundMode::Down => scaled.trunc(),
+    };
+    result / factor
+}
+
+fn function_subtotal(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<CalculatedValue, CellErrorType> {
+    if args.len() < 2 {
+        return Err(CellErrorType::Value);
+    }
+    let function_num = number_arg(calc, position, &args[0])?.trunc() as u32;
+    let ignore_hidden = function_num > 100;
+    let mut numbers: Vec<f64> = Vec::new();
+    let mut count_all = 0usize;
+    for arg in &args[1..] {
+        let reference = match calc.evaluate(position, arg) {
+            Operand::Reference(r) => r,
+            Operand::Value(CalculatedValue::Error(e)) => return Err(e),
+            Operand::Value(_) => return Err(CellErrorType::Value),
+        };
+        let worksheet = calc.get_worksheet(reference.sheet_index);
+        for (col, row) in calc.get_range_coordinates(&reference) {
+            if ignore_hidden {
+                if let Some(v) = worksheet.get_row_dimension(&row) {
+                    if *v.get_hidden() {
+                        continue;
+                    }
+                }
+            }
+            // nested subtotals are ignored to avoid double counting
+            if let Some(cell) = worksheet.get_cell((col, row)) {
+                if cell.get_formula().to_uppercase().contains("SUBTOTAL(") {
+                    continue;
+                }
+            }
+            match calc.get_cell_value(reference.sheet_index, col, row) {
+                CalculatedValue::Number(n) => {
+                    numbers.push(n);
+                    count_all += 1;
+                }
+                CalculatedValue::Error(e) => return Err(e),
+                CalculatedValue::Empty => {}
+                _ => count_all += 1,
+            }
+        }
+    }
+    let result = match function_num % 100 {
+        1 => average(&numbers)?,
+        2 => numbers.len() as f64,
+        3 => count_all as f64,
+        4 => maximum(&numbers),
+        5 => minimum(&numbers),
+        6 => numbers.iter().product(),
+        7 => variance(&numbers, true)?.sqrt(),
+        8 => variance(&numbers, false)?.sqrt(),
+        9 => numbers.iter().sum(),
+        10 => variance(&numbers, true)?,
+        11 => variance(&numbers, false)?,
+        _ => return Err(CellErrorType::Value),
+    };
+    Ok(number_result(result))
+}
+
+fn function_sumproduct(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<CalculatedValue, CellErrorType> {
+    if args.is_empty() {
+        return Err(CellErrorType::Value);
+    }
+    let mut result: Option<Vec<f64>> = None;
+    let mut dimension = (0, 0);
+    for arg in args {
+        let matrix = matrix_arg(calc, position, arg)?;
+        let size = (matrix.len(), matrix.first().map(|r| r.len()).unwrap_or(0));
+        let numbers: Vec<f64> = matrix
+            .into_iter()
+            .flatten()
+            .map(|v| match v {
+                CalculatedValue::Number(n) => Ok(n),
+                CalculatedValue::Error(e) => Err(e),
+                _ => Ok(0f64),
+            })
+            .collect::<Result<Vec<f64>, CellErrorType>>()?;
+        result = match result {
+            None => {
+                dimension = size;
+                Some(numbers)
+            }
+            Some(v) => {
+                if dimension != size {
+                    return Err(CellErrorType::Value);
+                }
+                Some(v.iter().zip(numbers.iter()).map(|(a, b)| a * b).collect())
+            }
+        };
+    }
+    Ok(CalculatedValue::Number(
+        result.unwrap_or_default().iter().sum(),
+    ))
+}
+
+// ************************
+// logical
+// ************************
+fn function_if(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    arity(args, 2, 3)?;
+    let condition = bool_arg(calc, position, &args[0])?;
+    match (condition, args.get(2)) {
+        (true, _) => Ok(calc.evaluate(position, &args[1])),
+        (false, Some(v)) => Ok(calc.evaluate(position, v)),
+        (false, None) => Ok(Operand::Value(CalculatedValue::Bool(false))),
+    }
+}
+
+fn function_iferror(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+    only_na: bool,
+) -> Result<Operand, CellErrorType> {
+    arity(args, 2, 2)?;
+    let is_target = |v: &CalculatedValue| match v.get_error() {
+        Some(e) => !only_na || e == &CellErrorType::NA,
+        None => false,
+    };
+    let operand = calc.evaluate(position, &args[0]);
+    let value = match &operand {
+        Operand::Reference(r) if !r.is_single_cell() => return Ok(operand),
+        _ => calc.operand_to_value(operand.clone()),
+    };
+    match value {
+        CalculatedValue::Array(_) => {
+            let alternative = calc.evaluate_scalar(position, &args[1]);
+            Ok(Operand::Value(map_value(&value, &|v| match is_target(v) {
+                true => alternative.clone(),
+                false => v.clone(),
+            })))
+        }
+        v if is_target(&v) => Ok(calc.evaluate(position, &args[1])),
+        _ => Ok(operand),
+    }
+}
+
+fn function_ifs(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    if args.is_empty() || args.len() % 2 != 0 {
+        return Err(CellErrorType::Value);
+    }
+    for pair in args.chunks(2) {
+        if bool_arg(calc, position, &pair[0])? {
+            return Ok(calc.evaluate(position, &pair[1]));
+        }
+    }
+    Err(CellErrorType::NA)
+}
+
+fn function_switch(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    if args.len() < 3 {
+        return Err(CellErrorType::Value);
+    }
+    let value = calc.evaluate_scalar(position, &args[0]);
+    if let CalculatedValue::Error(e) = value {
+        return Err(e);
+    }
+    let rest = &args[1..];
+    for pair in rest.chunks(2) {
+        if pair.len() == 1 {
+            return Ok(calc.evaluate(position, &pair[0]));
+        }
+        let case = calc.evaluate_scalar(position, &pair[0]);
+        if value.compare(&case) == Ordering::Equal {
+            return Ok(calc.evaluate(position, &pair[1]));
+        }
+    }
+    Err(CellErrorType::NA)
+}
+
+// ************************
+// lookup and reference
+// ************************
+fn function_choose(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    if args.len() < 2 {
+        return Err(CellErrorType::Value);
+    }
+    let index = number_arg(calc, position, &args[0])?.trunc();
+    if index < 1f64 || index as usize >= args.len() {
+        return Err(CellErrorType::Value);
+    }
+    Ok(calc.evaluate(position, &args[index as usize]))
+}
+
+fn function_index(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    arity(args, 2, 3)?;
+    let source = calc.evaluate(position, &args[0]);
+    let (rows, cols) = match &source {
+        Operand::Reference(r) => (r.get_row_count() as usize, r.get_col_count() as usize),
+        Operand::Value(CalculatedValue::Array(v)) => {
+            (v.len(), v.first().map(|r| r.len()).unwrap_or(0))
+        }
+        Operand::Value(CalculatedValue::Error(e)) => return Err(e.clone()),
+        Operand::Value(_) => (1, 1),
+    };
+    let first = number_arg(calc, position, &args[1])?.trunc() as i64;
+    let second = match args.get(2) {
+        Some(FormulaExpression::Empty) | None => None,
+        Some(v) => Some(number_arg(calc, position, v)?.trunc() as i64),
+    };
+    // a single index addresses the only row or column of a vector
+    let (row, col) = match second {
+        Some(col) => (first, col),
+        None if rows == 1 => (1, first),
+        None => (first, if cols == 1 { 1 } else { 0 }),
+    };
+    if row < 0 || col < 0 || row as usize > rows || col as usize > cols {
+        return Err(CellErrorType::Ref);
+    }
+    match source {
+        Operand::Reference(r) => {
+            let (row_start, row_end) = match row {
+                0 => (r.row_start, r.row_end),
+                _ => (r.row_start + row as u32 - 1, r.row_start + row as u32 - 1),
+            };
+            let (col_start, col_end) = match col {
+                0 => (r.col_start, r.col_end),
+                _ => (r.col_start + col as u32 - 1, r.col_start + col as u32 - 1),
+            };
+            Ok(Operand::Reference(RangeReference {
+                sheet_index: r.sheet_index,
+                col_start,
+                row_start,
+                col_end,
+                row_end,
+            }))
+        }
+        Operand::Value(CalculatedValue::Array(v)) => {
+            if row == 0 || col == 0 {
+                return Err(CellErrorType::Value);
+            }
+            Ok(Operand::Value(
+                v[row as usize - 1][col as usize - 1].clone(),
+            ))
+        }
+        other => Ok(other),
+    }
+}
+
+fn function_offset(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    arity(args, 3, 5)?;
+    let reference = match calc.evaluate(position, &args[0]) {
+        Operand::Reference(r) => r,
+        Operand::Value(CalculatedValue::Error(e)) => return Err(e),
+        Operand::Value(_) => return Err(CellErrorType::Value),
+    };
+    let rows = number_arg(calc, position, &args[1])?.trunc() as i64;
+    let cols = number_arg(calc, position, &args[2])?.trunc() as i64;
+    let height = optional_number_arg(calc, position, args, 3, reference.get_row_count() as f64)?
+        .trunc() as i64;
+    let width = optional_number_arg(calc, position, args, 4, reference.get_col_count() as f64)?
+        .trunc() as i64;
+    let row_start = reference.row_start as i64 + rows;
+    let col_start = reference.col_start as i64 + cols;
+    let row_end = row_start + height - 1;
+    let col_end = col_start + width - 1;
+    if height < 1
+        || width < 1
+        || row_start < 1
+        || col_start < 1
+        || row_end > MAX_ROW as i64
+        || col_end > MAX_COLUMN as i64
+    {
+        return Err(CellErrorType::Ref);
+    }
+    Ok(Operand::Reference(RangeReference {
+        sheet_index: reference.sheet_index,
+        col_start: col_start as u32,
+        row_start: row_start as u32,
+        col_end: col_end as u32,
+        row_end: row_end as u32,
+    }))
+}
+
+fn function_indirect(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<Operand, CellErrorType> {
+    arity(args, 1, 2)?;
+    let text = text_arg(calc, position, &args[0])?;
+    match calc.evaluate(position, &FormulaExpression::Reference(text)) {
+        Operand::Reference(r) => Ok(Operand::Reference(r)),
+        _ => Err(CellErrorType::Ref),
+    }
+}
+
+/// Equality used by exact lookups. Texts are compared case-insensitively and may contain wildcards.
+fn lookup_equal(lookup: &CalculatedValue, value: &CalculatedValue, use_wildcard: bool) -> bool {
+    match (lookup, value) {
+        (CalculatedValue::Text(l), CalculatedValue::Text(v)) => {
+            if use_wildcard && (l.contains('*') || l.contains('?')) {
+                return wildcard_regex(l, true).is_match(v);
+            }
+            l.to_lowercase() == v.to_lowercase()
+        }
+        (CalculatedValue::Number(l), CalculatedValue::Number(v)) => l == v,
+        (CalculatedValue::Bool(l), CalculatedValue::Bool(v)) => l == v,
+        _ => false,
+    }
+}
+
+#[inline]
+fn is_same_kind(left: &CalculatedValue, right: &CalculatedValue) -> bool {
+    std::mem::discriminant(left) == std::mem::discriminant(right)
+}
+
+/// Position of `lookup` in `values` (0-based).
+/// `match_type` 0 is exact, 1 is the largest value less than or equal to `lookup`,
+/// -1 is the smallest value greater than or equal to `lookup`.
+fn find_position(
+    lookup: &CalculatedValue,
+    values: &[CalculatedValue],
+    match_type: i32,
+) -> Option<usize> {
+    if match_type == 0 {
+        return values.iter().position(|v| lookup_equal(lookup, v, true));
+    }
+    let mut result: Option<usize> = None;
+    for (i, value) in values.iter().enumerate() {
+        if !is_same_kind(lookup, value) {
+            continue;
+        }
+        let ordering = value.compare(lookup);
+        match match_type {
+            1 => {
+                if ordering == Ordering::Greater {
+                    break;
+                }
+                result = Some(i);
+            }
+            _ => {
+                if ordering == Ordering::Less {
+                    break;
+                }
+                result = Some(i);
+            }
+        }
+    }
+    result
+}
+
+fn function_lookup(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+    is_horizontal: bool,
+) -> Result<CalculatedValue, CellErrorType> {
+    arity(args, 3, 4)?;
+    let lookup = calc.evaluate_scalar(position, &args[0]);
+    if let CalculatedValue::Error(e) = lookup {
+        return Err(e);
+    }
+    let mut table = matrix_arg(calc, position, &args[1])?;
+    if is_horizontal {
+        table = transpose(table);
+    }
+    let index = number_arg(calc, position, &args[2])?.trunc();
+    let is_approximate = match args.get(3) {
+        Some(FormulaExpression::Empty) => false,
+        Some(v) => bool_arg(calc, position, v)?,
+        None => true,
+    };
+    if index < 1f64 {
+        return Err(CellErrorType::Value);
+    }
+    let width = table.first().map(|r| r.len()).unwrap_or(0);
+    if index as usize > width {
+        return Err(CellErrorType::Ref);
+    }
+    let keys: Vec<CalculatedValue> = table.iter().map(|r| r[0].clone()).collect();
+    let match_type = if is_approximate { 1 } else { 0 };
+    match find_position(&lookup, &keys, match_type) {
+        Some(i) => Ok(table[i][index as usize - 1].clone()),
+        None => Err(CellErrorType::NA),
+    }
+}
+
+fn function_match(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<CalculatedValue, CellErrorType> {
+    arity(args, 2, 3)?;
+    let lookup = calc.evaluate_scalar(position, &args[0]);
+    if let CalculatedValue::Error(e) = lookup {
+        return Err(e);
+    }
+    let values = vector_arg(calc, position, &args[1])?;
+    let match_type = optional_number_arg(calc, position, args, 2, 1f64)?;
+    let match_type = if match_type > 0f64 {
+        1
+    } else if match_type < 0f64 {
+        -1
+    } else {
+        0
+    };
+    match find_position(&lookup, &values, match_type) {
+        Some(i) => Ok(CalculatedValue::Number((i + 1) as f64)),
+        None => Err(CellErrorType::NA),
+    }
+}
+
+fn function_xlookup(
+    calc: &mut Calculator,
+    position: &Position,
+    args: &[FormulaExpression],
+) -> Result<CalculatedValue, CellErrorType> {
+    arity(args, 3, 6)?;
+    let lookup = calc.evaluate_scalar(position, &args[0]);
+    if let CalculatedValue::Error(e) = lookup {
+        return Err(e);
+    }
+    let lookup_matrix = matrix_arg(calc, position, &args[1])?;
+    let return_matrix = matrix_arg(calc, position, &args[2])?;
+    let match_mode = optional_number_arg(calc, position, args, 4, 0f64)?.trunc() as i32;
+    let is_vertical = lookup_matrix.first().map(|r| r.len()).unwrap_or(0) == 1;
+    let values: Vec<CalculatedValue> = match is_vertical {
+        true => lookup_matrix.into_iter().map(|mut r| r.remove(0)).collect(),
+        false => lookup_matrix.into_iter().next().unwrap_or_default(),
+    };
+    let found = match match_mode {
+        0 => values.iter().position(|v| lookup_equal(&lookup, v, false)),
+        2 => values.iter().position(|v| lookup_equal(&lookup, v, true)),
+        -1 | 1 => {
+            let exact = values.iter().position(|v| lookup_equal(&lookup, v, false));
+            exact.or_else(|| {
+                let wanted = if match_mode == -1 {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+                values
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| is_same_kind(&lookup, v) && v.compare(&lookup) == wanted)
+                    .fold(
+                        None,
+                        |best: Option<(usize, &CalculatedValue)>, (i, v)| match best {
+                            Some((_, b)) if v.compare(b) != wanted.reverse() => best,
+                            _ => Some((i, v)),
+                        },
+                    )
+                    .map(|(i, _)| i)
+            })
+        }
+        _ => return Err(CellErrorType::Value),
+    };
+    match found {
+        Some(i) => {
+            let value = match is_vertical {
+                true => return_matrix.get(i).and_then(|r| r.first()),
+                false => return_matrix.first().and_then(|r| r.get(i)),
+            };
+            value.cloned().ok_or(CellErrorType::Value)
+        }
+        None => match args.get(3) {
+            Some(FormulaExpression::Empty) | None => Err(CellErrorType::NA),
+            Some(v) => Ok(calc.evaluate_scalar(position, v)),
+        },
+    }
+}
+
+/// Evaluate a one-dimensional range or array.
+fn vector_arg(
+    calc: &mut Calculator,
+    position: &Position,
+    arg: &FormulaExpression,
+) -> Result<Vec<CalculatedValue>, CellErrorType> {
+    let matrix = matrix_arg(calc, position, arg)?;
+    let width = matrix.first().map(|r| r.len()).unwrap_or(0);
+    if matrix.len() == 1 {
+        return Ok(matrix.into_iter().next().unwrap_or_default());
+    }
+    if width == 1 {
+        return Ok(matrix.into_iter().map(|mut r| r.remove(0)).collect());
+    }
+    Err(CellErrorType::NA)
+}
+
+fn transpose(matrix: Matrix) -> Matrix {
+    let width = matrix.first().map(|r| r.len()).unwrap_or(0);
+    (0..width)
+        .map(|col| matrix.iter().map(|row| row[col].clone()).collect())
+        .collect()
+}
+
+// ************************
+// criteria
+// ************************
+/// Condition used by COUNTIF, SUMIF and friends. ex) `">=10"`, `"app*"`, `"<>"`
+pub(crate) struct Criteria {
+    operator: &'static str,
+    value: CalculatedValue,
+    pattern: Option<Regex>,
+}
+impl Criteria {
+    pub(crate) fn new(criteria: &CalculatedValue) -> Self {
+        let text = match criteria.to_scalar() {
+            CalculatedValue::Text(v) => v,
+            CalculatedValue::Empty => String::new(),
+            v => {
+                return Self {
+                    operator: "=",
+                    value: v,
+                    pattern: None,
+                }
+            }
+        };
+        let operator = ["<=", ">=", "<>", "<", ">", "="]
+            .into_iter()
+            .find(|v| text.starts_with(v))
+            .unwrap_or("=");
+        let rest = text.strip_prefix(operator).unwrap_or(&text);
+        let value = match rest {
+            "" => CalculatedValue::Empty,
+            _ => match parse_number_text(rest) {
+                Some(n) => CalculatedValue::Number(n),
+                None => match CellValueKind::from_text(rest) {
+                    Some(v) => v,
+                    None => CalculatedValue::Text(rest.to_string()),
+                },
+            },
+        };
+        let pattern = match (&value, operator) {
+            (CalculatedValue::Text(v), "=" | "<>") if v.contains('*') || v.contains('?') => {
+                Some(wildcard_regex(v, true))
+            }
+            _ => None,
+        };
+        Self {
+            operator,
+            value,
+            pattern,
+        }
+    }
+
+    pub(crate) fn is_match(&self, value: &CalculatedValue) -> bool {
+        let ordering = match (&self.value, value) {
+            (CalculatedValue::Empty, _) => {
+                let equal = is_blank(value);
+                return match self.operator {
+                    "=" => equal,
+                    "<>" => !equal,
+                    _ => false,
+                };
+            }
+            (CalculatedValue::Number(c), _) => {
+                let number = match value {
+                    CalculatedValue::Number(n) => Some(*n),
+                    CalculatedValue::Text(t) => parse_number_text(t),
+                    _ => None,
+                };
+                match number {
+                    Some(n) => n.partial_cmp(c).unwrap_or(Ordering::Equal),
+                    None => return self.operator == "<>",
+                }
+            }
+            (CalculatedValue::Text(c), CalculatedValue::Text(t)) => {
+                if let Some(pattern) = &self.pattern {
+                    let is_match = pattern.is_match(t);
+                    return if self.operator == "=" {
+                        is_match
+                    } else {
+                        !is_match
+                    };
+                }
+                t.to_lowercase().cmp(&c.to_lowercase())
+            }
+            (c, v) if is_same_kind(c, v) => v.compare(c),
+            _ => return self.operator == "<>",
+        };
+        match self.operator {
+            "=" => ordering.is_eq(),
+            "<>" => ordering.is_ne(),
+            "<" => ordering.is_lt(),
+            ">" => ordering.is_gt(),
+            "<=" => ordering.is_le(),
+            _ => ordering.is_ge(),
+        }
+    }
+}
+
+struct CellValueKind;
+impl CellValueKind {
+    /// Logical values and errors written as text in a criteria.
+    fn from_text(value: &str) -> Option<CalculatedValue> {
+        match value.to_uppercase().as_str() {
+            "TRUE" => Some(CalculatedValue::Bool(true)),
+            "FALSE" => Some(CalculatedValue::Bool(false)),
+            v => v.parse::<CellErrorType>().ok().map(CalculatedValue::Error),
+        }
+    }
+}
+
+/// Build a regex from an Excel wildcard pattern (`*`, `?` and `~` escape).
+pub(crate) fn wildcard_regex(pattern: &str, is_whole: bool) -> Regex {
+    let mut result = String::from("(?is)");
+    if is_whole {
+        result.push('^');
+    }
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '~' => match chars.next() {
+                Some(n) => result.push_str(&regex::escape(&n.to_string())),
+                None => result.push_str(&regex::escape("~")),
+            },
+            '*' => result.push_str(".*"),
+            '?' => result.push('.'),
+            _ => result.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    if is_whole {
+        result.push('$');
+    }
+    Regex::new(&result).unwrap()
+}
+
+fn criteria_pairs(
+    args: &[FormulaExpression],
+) -> Result<Vec<(&FormulaExpression, &FormulaExpression)>, CellErrorType> {
+    if args.is_empty() || args.len() % 2 != 0 {
+        return Err(CellErrorType::Value);
+    }
+    Ok(args.chunks(2).map(|v| (&v[0], &v[1])).collect())
+}
+
+/// Positions `(row, col)` where every criteria range meets its criteria.
+/// Returns the size of the first range as well.
+fn match_criteria(
+    calc: &mut Calculator,
+    position: &Position,
+    pairs: &[(&FormulaExpression, &FormulaExpression)],
+) -> Result<(usize, usize, Vec<(usize, usize)>), CellErrorType> {
+    let mut rows = 0;
+    let mut cols = 0;
+    let mut matched: Option<Vec<(usize, usize)>> = None;
+    for (index, (range, criteria)) in pairs.iter().enumerate() {
+        let matrix = match index {
+            0 => matrix_arg(calc, position, range)?,
+            _ => sized_matrix_arg(calc, position, range, rows, cols)?,
+        };
+        if index == 0 {
+            rows = matrix.len();
+            cols = matrix.first().map(|r| r.len()).unwrap_or(0);
+        }
+        let criteria = Criteria::new(&calc.evaluate_scalar(position, criteria));
+        let is_match = |r: usize, c: usize| {
+            matrix
+                .get(r)
+                .and_then(|v| v.get(c))
+                .map(|v| criteria.is_match(v))
+                .unwrap_or(false)
+        };
+        matched = Some(match matched {
+            None => (0..rows)
+                .flat_map(|r| (0..cols).map(move |c| (r, c)))
+                .filter(|(r, c)| is_match(*r, *c))
+                .collect(),
+            Some(v) => v.into_iter().filter(|(r, c)| is_match(*r, *c)).collect(),
+        });
+    }
+    Ok((rows, cols, matched.unwrap_or_default()))
+}
+
+fn matched_numbers(
+    calc: &mut Calculator,
+    position: &Position,
+    target: &FormulaExpression,
+    rows: usize,
+    cols: usize,
+    matched: &[(usize, usize)],
+) -> Result<Vec<f64>, CellErrorType> {
+    let matrix = sized_matrix_arg(calc, position, target, rows, cols)?;
+    let mut result: Vec<f64> = Vec::new();
+    for (row, col) in matched {
+        match matrix.get(*row).and_then(|v| v.get(*col)) {
+            Some(CalculatedValue::Number(n)) => result.push(*n),
+            Some(CalculatedValue::Error(e)) => return Err(e.clone()),
+            _ => {}
+        }
+    }
+    Ok(result)
+}
+
+// ************************
+// date and time
+// ************************
+/// Convert a date into a serial number of the 1900 date system.
+pub(crate) fn date_to_serial(date: &NaiveDate) -> f64 {
+    let base_date = NaiveDate::from_ymd_opt(1899, 12, 30).unwrap();
+    let days = (*date - base_date).num_days();
+    // Excel treats 1900 as a leap year, so dates before March 1900 are off by one.
+    if days < 61 {
+        (days - 1) as f64
+    } else {
+        days as f64
+    }
+}
+
+#[inline]
+pub(crate) fn serial_to_date_time(serial: f64) -> NaiveDateTime {
+    excel_to_date_time_object(&serial, None)
+}
+
+fn last_day_of_month(year: i32, month: u32) -> Option<u32> {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1).map(|v| (v - Duration::days(1)).day())
+}
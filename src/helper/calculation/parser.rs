@@ -0,0 +1,299 @@
+use crate::helper::formula::*;
+use crate::structs::CellErrorType;
+use std::str::FromStr;
+
+/// Abstract syntax tree of a formula.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormulaExpression {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(CellErrorType),
+    /// Cell reference, range reference or defined name. ex) `A1`, `Sheet1!$A$1:$B$2`, `MyName`
+    Reference(String),
+    Array(Vec<Vec<FormulaExpression>>),
+    Prefix(String, Box<FormulaExpression>),
+    Postfix(String, Box<FormulaExpression>),
+    Infix(String, Box<FormulaExpression>, Box<FormulaExpression>),
+    Function(String, Vec<FormulaExpression>),
+    /// Omitted function argument. ex) the second argument of `IF(A1,,1)`
+    Empty,
+}
+
+/// Parse a formula into an expression tree.
+/// # Arguments
+/// * `formula` - formula text. The leading `=` is optional. ex) `"SUM(A1:A3)*2"`
+/// # Return value
+/// * `Result<FormulaExpression, CellErrorType>` - Err is returned when the formula is malformed.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::calculation::*;
+/// let expression = parse_formula("=1+2*3").unwrap();
+/// ```
+pub fn parse_formula(formula: &str) -> Result<FormulaExpression, CellErrorType> {
+    let formula = if formula.starts_with('=') {
+        formula.to_string()
+    } else {
+        format!("={}", formula)
+    };
+    let tokens = parse_to_tokens(formula);
+    parse_formula_tokens(&tokens)
+}
+
+pub(crate) fn parse_formula_tokens(
+    tokens: &[FormulaToken],
+) -> Result<FormulaExpression, CellErrorType> {
+    if tokens.is_empty() {
+        return Err(CellErrorType::Value);
+    }
+    let mut parser = Parser { tokens, index: 0 };
+    let result = parser.parse_expression(0)?;
+    if parser.index < tokens.len() {
+        return Err(CellErrorType::Value);
+    }
+    Ok(result)
+}
+
+struct Parser<'a> {
+    tokens: &'a [FormulaToken],
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn peek(&self) -> Option<&'a FormulaToken> {
+        self.tokens.get(self.index)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a FormulaToken> {
+        let token = self.tokens.get(self.index);
+        self.index += 1;
+        token
+    }
+
+    fn infix_precedence(token: &FormulaToken) -> Option<u8> {
+        if token.get_token_type() != &FormulaTokenTypes::OperatorInfix {
+            return None;
+        }
+        match token.get_token_sub_type() {
+            FormulaTokenSubTypes::Intersection => return Some(7),
+            FormulaTokenSubTypes::Union => return None,
+            _ => {}
+        }
+        match token.get_value() {
+            "=" | "<>" | "<" | ">" | "<=" | ">=" => Some(1),
+            "&" => Some(2),
+            "+" | "-" => Some(3),
+            "*" | "/" => Some(4),
+            "^" => Some(5),
+            _ => None,
+        }
+    }
+
+    fn parse_expression(&mut self, min_precedence: u8) -> Result<FormulaExpression, CellErrorType> {
+        let mut left = self.parse_unary()?;
+        while let Some(token) = self.peek() {
+            let precedence = match Self::infix_precedence(token) {
+                Some(v) if v >= min_precedence => v,
+                _ => break,
+            };
+            self.index += 1;
+            let operator = match token.get_token_sub_type() {
+                FormulaTokenSubTypes::Intersection => " ".to_string(),
+                _ => token.get_value().to_string(),
+            };
+            let right = self.parse_expression(precedence + 1)?;
+            left = FormulaExpression::Infix(operator, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<FormulaExpression, CellErrorType> {
+        if let Some(token) = self.peek() {
+            if token.get_token_type() == &FormulaTokenTypes::OperatorPrefix {
+                self.index += 1;
+                let operand = self.parse_unary()?;
+                return Ok(FormulaExpression::Prefix(
+                    token.get_value().to_string(),
+                    Box::new(operand),
+                ));
+            }
+        }
+        let mut result = self.parse_primary()?;
+        while let Some(token) = self.peek() {
+            if token.get_token_type() != &FormulaTokenTypes::OperatorPostfix {
+                break;
+            }
+            self.index += 1;
+            result = FormulaExpression::Postfix(token.get_value().to_string(), Box::new(result));
+        }
+        Ok(result)
+    }
+
+    fn parse_primary(&mut self) -> Result<FormulaExpression, CellErrorType> {
+        let token = self.next().ok_or(CellErrorType::Value)?;
+        match token.get_token_type() {
+            FormulaTokenTypes::Operand => Ok(Self::parse_operand(token)),
+            FormulaTokenTypes::Function
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Start =>
+            {
+                let name = token.get_value().to_uppercase();
+                let arguments = self.parse_arguments()?;
+                if name == "ARRAY" {
+                    return Self::to_array(arguments);
+                }
+                Ok(FormulaExpression::Function(name, arguments))
+            }
+            FormulaTokenTypes::Subexpression
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Start =>
+            {
+                let result = self.parse_expression(0)?;
+                match self.next() {
+                    Some(v)
+                        if v.get_token_type() == &FormulaTokenTypes::Subexpression
+                            && v.get_token_sub_type() == &FormulaTokenSubTypes::Stop =>
+                    {
+                        Ok(result)
+                    }
+                    _ => Err(CellErrorType::Value),
+                }
+            }
+            _ => Err(CellErrorType::Value),
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<FormulaExpression>, CellErrorType> {
+        let mut arguments: Vec<FormulaExpression> = Vec::new();
+        let mut expect_argument = true;
+        loop {
+            let token = self.peek().ok_or(CellErrorType::Value)?;
+            if token.get_token_type() == &FormulaTokenTypes::Function
+                && token.get_token_sub_type() == &FormulaTokenSubTypes::Stop
+            {
+                self.index += 1;
+                if expect_argument && !arguments.is_empty() {
+                    arguments.push(FormulaExpression::Empty);
+                }
+                return Ok(arguments);
+            }
+            if Self::is_separator(token) {
+                self.index += 1;
+                if expect_argument {
+                    arguments.push(FormulaExpression::Empty);
+                }
+                expect_argument = true;
+                continue;
+            }
+            if !expect_argument {
+                return Err(CellErrorType::Value);
+            }
+            arguments.push(self.parse_expression(0)?);
+            expect_argument = false;
+        }
+    }
+
+    #[inline]
+    fn is_separator(token: &FormulaToken) -> bool {
+        token.get_token_type() == &FormulaTokenTypes::Argument
+            || (token.get_token_type() == &FormulaTokenTypes::OperatorInfix
+                && token.get_token_sub_type() == &FormulaTokenSubTypes::Union)
+    }
+
+    fn parse_operand(token: &FormulaToken) -> FormulaExpression {
+        let value = token.get_value();
+        match token.get_token_sub_type() {
+            FormulaTokenSubTypes::Text => FormulaExpression::Text(value.to_string()),
+            FormulaTokenSubTypes::Number => match value.parse::<f64>() {
+                Ok(v) => FormulaExpression::Number(v),
+                Err(_) => FormulaExpression::Error(CellErrorType::Value),
+            },
+            FormulaTokenSubTypes::Logical => {
+                FormulaExpression::Bool(value.to_uppercase() == "TRUE")
+            }
+            FormulaTokenSubTypes::Error => match CellErrorType::from_str(value) {
+                Ok(v) => FormulaExpression::Error(v),
+                Err(_) => FormulaExpression::Error(CellErrorType::Value),
+            },
+            _ => FormulaExpression::Reference(value.to_string()),
+        }
+    }
+
+    fn to_array(rows: Vec<FormulaExpression>) -> Result<FormulaExpression, CellErrorType> {
+        let mut result: Vec<Vec<FormulaExpression>> = Vec::new();
+        for row in rows {
+            match row {
+                FormulaExpression::Function(name, values) if name == "ARRAYROW" => {
+                    result.push(values);
+                }
+                _ => return Err(CellErrorType::Value),
+            }
+        }
+        Ok(FormulaExpression::Array(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_formula("=1+2*3").unwrap(),
+            FormulaExpression::Infix(
+                "+".into(),
+                Box::new(FormulaExpression::Number(1.0)),
+                Box::new(FormulaExpression::Infix(
+                    "*".into(),
+                    Box::new(FormulaExpression::Number(2.0)),
+                    Box::new(FormulaExpression::Number(3.0)),
+                )),
+            )
+        );
+
+        assert_eq!(
+            parse_formula("SUM(A1:B2,'My Sheet'!C3)").unwrap(),
+            FormulaExpression::Function(
+                "SUM".into(),
+                vec![
+                    FormulaExpression::Reference("A1:B2".into()),
+                    FormulaExpression::Reference("'My Sheet'!C3".into()),
+                ],
+            )
+        );
+
+        assert_eq!(
+            parse_formula("=IF(A1,,\"x\")").unwrap(),
+            FormulaExpression::Function(
+                "IF".into(),
+                vec![
+                    FormulaExpression::Reference("A1".into()),
+                    FormulaExpression::Empty,
+                    FormulaExpression::Text("x".into()),
+                ],
+            )
+        );
+
+        assert_eq!(
+            parse_formula("={1,2;3,4}").unwrap(),
+            FormulaExpression::Array(vec![
+                vec![
+                    FormulaExpression::Number(1.0),
+                    FormulaExpression::Number(2.0)
+                ],
+                vec![
+                    FormulaExpression::Number(3.0),
+                    FormulaExpression::Number(4.0)
+                ],
+            ])
+        );
+
+        assert_eq!(
+            parse_formula("=NOW()").unwrap(),
+            FormulaExpression::Function("NOW".into(), vec![])
+        );
+
+        assert!(parse_formula("=(1+2").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::structs::CellErrorType;
+use crate::structs::CellRawValue;
+use crate::structs::CellValue;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Value produced by evaluating a formula.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CalculatedValue {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(CellErrorType),
+    Empty,
+    Array(Vec<Vec<CalculatedValue>>),
+}
+impl Default for CalculatedValue {
+    #[inline]
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+impl fmt::Display for CalculatedValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(v) => write!(f, "{}", number_to_text(*v)),
+            Self::Text(v) => write!(f, "{}", v),
+            Self::Bool(v) => write!(f, "{}", if *v { "TRUE" } else { "FALSE" }),
+            Self::Error(v) => write!(f, "{}", v),
+            Self::Empty => write!(f, ""),
+            Self::Array(v) => match v.first().and_then(|r| r.first()) {
+                Some(v) => write!(f, "{}", v),
+                None => write!(f, ""),
+            },
+        }
+    }
+}
+impl CalculatedValue {
+    pub(crate) fn from_raw_value(value: &CellRawValue) -> Self {
+        match value {
+            CellRawValue::String(v) => Self::Text(v.to_string()),
+            CellRawValue::RichText(v) => Self::Text(v.get_text().to_string()),
+            CellRawValue::Lazy(v) => Self::from_raw_value(&CellValue::guess_typed_data(v)),
+            CellRawValue::Numeric(v) => Self::Number(*v),
+            CellRawValue::Bool(v) => Self::Bool(*v),
+            CellRawValue::Error(v) => Self::Error(v.clone()),
+            CellRawValue::Empty => Self::Empty,
+        }
+    }
+
+    /// Convert into the value cached in a formula cell.
+    pub(crate) fn to_raw_value(&self) -> CellRawValue {
+        match self {
+            Self::Number(v) => {
+                if v.is_finite() {
+                    CellRawValue::Numeric(*v)
+                } else {
+                    CellRawValue::Error(CellErrorType::Num)
+                }
+            }
+            Self::Text(v) => CellRawValue::String(v.clone().into_boxed_str()),
+            Self::Bool(v) => CellRawValue::Bool(*v),
+            Self::Error(v) => CellRawValue::Error(v.clone()),
+            // A formula that refers to a blank cell results in zero.
+            Self::Empty => CellRawValue::Numeric(0f64),
+            Self::Array(v) => match v.first().and_then(|r| r.first()) {
+                Some(v) => v.to_raw_value(),
+                None => CellRawValue::Error(CellErrorType::Value),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error(_))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    #[inline]
+    pub fn get_error(&self) -> Option<&CellErrorType> {
+        match self {
+            Self::Error(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Top-left value of an array, otherwise the value itself.
+    pub fn to_scalar(&self) -> Self {
+        match self {
+            Self::Array(v) => v
+                .first()
+                .and_then(|r| r.first())
+                .cloned()
+                .unwrap_or(Self::Error(CellErrorType::Value)),
+            _ => self.clone(),
+        }
+    }
+
+    /// Coerce into a number like Excel does for arithmetic.
+    pub fn to_number(&self) -> Result<f64, CellErrorType> {
+        match self.to_scalar() {
+            Self::Number(v) => Ok(v),
+            Self::Bool(v) => Ok(if v { 1f64 } else { 0f64 }),
+            Self::Empty => Ok(0f64),
+            Self::Text(v) => parse_number_text(&v).ok_or(CellErrorType::Value),
+            Self::Error(e) => Err(e),
+            Self::Array(_) => Err(CellErrorType::Value),
+        }
+    }
+
+    /// Coerce into a text like Excel does for concatenation.
+    pub fn to_text(&self) -> Result<String, CellErrorType> {
+        match self.to_scalar() {
+            Self::Error(e) => Err(e),
+            v => Ok(v.to_string()),
+        }
+    }
+
+    /// Coerce into a logical value like Excel does for conditions.
+    pub fn to_bool(&self) -> Result<bool, CellErrorType> {
+        match self.to_scalar() {
+            Self::Number(v) => Ok(v != 0f64),
+            Self::Bool(v) => Ok(v),
+            Self::Empty => Ok(false),
+            Self::Text(v) => match v.to_uppercase().as_str() {
+                "TRUE" => Ok(true),
+                "FALSE" => Ok(false),
+                _ => Err(CellErrorType::Value),
+            },
+            Self::Error(e) => Err(e),
+            Self::Array(_) => Err(CellErrorType::Value),
+        }
+    }
+
+    /// Compare two values using Excel's ordering.
+    /// Numbers sort before texts, texts before logical values.
+    /// Text comparison is case-insensitive.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        fn rank(value: &CalculatedValue) -> u8 {
+            match value {
+                CalculatedValue::Number(_) | CalculatedValue::Empty => 0,
+                CalculatedValue::Text(_) => 1,
+                CalculatedValue::Bool(_) => 2,
+                _ => 3,
+            }
+        }
+        let left = match (self.to_scalar(), other) {
+            (Self::Empty, Self::Text(_)) => Self::Text(String::new()),
+            (Self::Empty, Self::Bool(_)) => Self::Bool(false),
+            (v, _) => v,
+        };
+        let right = match (other.to_scalar(), &left) {
+            (Self::Empty, Self::Text(_)) => Self::Text(String::new()),
+            (Self::Empty, Self::Bool(_)) => Self::Bool(false),
+            (v, _) => v,
+        };
+        match (&left, &right) {
+            (Self::Number(_) | Self::Empty, Self::Number(_) | Self::Empty) => {
+                let l = left.to_number().unwrap_or(0f64);
+                let r = right.to_number().unwrap_or(0f64);
+                l.partial_cmp(&r).unwrap_or(Ordering::Equal)
+            }
+            (Self::Text(l), Self::Text(r)) => l.to_lowercase().cmp(&r.to_lowercase()),
+            (Self::Bool(l), Self::Bool(r)) => l.cmp(r),
+            _ => rank(&left).cmp(&rank(&right)),
+        }
+    }
+}
+
+/// Parse a text as a number. Surrounding spaces, percent signs and thousands separators are allowed.
+pub(crate) fn parse_number_text(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Some(v) = value.strip_suffix('%') {
+        return parse_number_text(v).map(|v| v / 100f64);
+    }
+    let value = value.replace(',', "");
+    match value.parse::<f64>() {
+        Ok(v) if v.is_finite() => Some(v),
+        _ => None,
+    }
+}
+
+/// Render a number the way the General format shows it (15 significant digits).
+pub(crate) fn number_to_text(value: f64) -> String {
+    if value == value.trunc() && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+    let rounded = format!("{:.14e}", value).parse::<f64>().unwrap_or(value);
+    format!("{}", rounded)
+}
@@ -2,6 +2,8 @@ pub(crate) const CERTIFICATE_NS: &str =
     "http://schemas.microsoft.com/office/2006/keyEncryptor/certificate";
 pub(crate) const CHART_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+pub(crate) const CHART_EX_NS: &str =
+    "http://schemas.microsoft.com/office/2014/relationships/chartEx";
 pub(crate) const COMMENTS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
 pub(crate) const CONTYPES_NS: &str = "http://schemas.openxmlformats.org/package/2006/content-types";
@@ -18,6 +20,12 @@ pub(crate) const DRAWING_CHART_NS: &str =
 pub(crate) const DRAWING_MAIN_NS: &str = "http://schemas.microsoft.com/office/drawing/2010/main";
 pub(crate) const DRAWINGML_CHART_NS: &str =
     "http://schemas.openxmlformats.org/drawingml/2006/chart";
+pub(crate) const DRAWINGML_CHARTEX_NS: &str =
+    "http://schemas.microsoft.com/office/drawing/2014/chartex";
+pub(crate) const DRAWINGML_CHARTEX_CX1_NS: &str =
+    "http://schemas.microsoft.com/office/drawing/2015/9/8/chartex";
+pub(crate) const DRAWINGML_CHARTEX_CX2_NS: &str =
+    "http://schemas.microsoft.com/office/drawing/2015/10/21/chartex";
 pub(crate) const DRAWINGML_MAIN_NS: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
 pub(crate) const DRAWINGS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/drawing";
@@ -42,8 +50,14 @@ pub(crate) const REL_OFC_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
 pub(crate) const PRINTER_SETTINGS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/printerSettings";
+pub(crate) const PERSON_NS: &str =
+    "http://schemas.microsoft.com/office/2017/10/relationships/person";
 pub(crate) const PIVOT_CACHE_DEF_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheDefinition";
+pub(crate) const PIVOT_CACHE_RECORDS_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheRecords";
+pub(crate) const PIVOT_TABLE_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotTable";
 pub(crate) const STYLES_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles";
 pub(crate) const SHARED_STRINGS_NS: &str =
@@ -59,6 +73,10 @@ pub(crate) const SHEETML_AC_NS: &str =
     "http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac";
 pub(crate) const TABLE_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/table";
+pub(crate) const THREADED_COMMENT_NS: &str =
+    "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment";
+pub(crate) const THREADED_COMMENTS_MAIN_NS: &str =
+    "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments";
 pub(crate) const THEME_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme";
 pub(crate) const VBA_PROJECT_NS: &str =
@@ -85,12 +103,20 @@ pub(crate) const COREPROPS_REL: &str =
 
 pub(crate) const CHART_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
+pub(crate) const CHART_EX_TYPE: &str = "application/vnd.ms-office.chartex+xml";
 pub(crate) const COMMENTS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml";
 pub(crate) const CORE_PROPS_TYPE: &str =
     "application/vnd.openxmlformats-package.core-properties+xml";
 pub(crate) const DRAWING_TYPE: &str = "application/vnd.openxmlformats-officedocument.drawing+xml";
 pub(crate) const OLE_OBJECT_TYPE: &str = "application/vnd.openxmlformats-officedocument.oleObject";
+pub(crate) const PERSON_TYPE: &str = "application/vnd.ms-excel.person+xml";
+pub(crate) const PIVOT_CACHE_DEF_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheDefinition+xml";
+pub(crate) const PIVOT_CACHE_RECORDS_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheRecords+xml";
+pub(crate) const PIVOT_TABLE_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotTable+xml";
 pub(crate) const PRNTR_SETTINGS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.printerSettings";
 pub(crate) const REL_TYPE: &str = "application/vnd.openxmlformats-package.relationships+xml";
@@ -103,6 +129,7 @@ pub(crate) const STYLES_TYPE: &str =
 pub(crate) const THEME_TYPE: &str = "application/vnd.openxmlformats-officedocument.theme+xml";
 pub(crate) const TABLE_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml";
+pub(crate) const THREADED_COMMENTS_TYPE: &str = "application/vnd.ms-excel.threadedcomments+xml";
 pub(crate) const VBA_TYPE: &str = "application/vnd.ms-office.vbaProject";
 pub(crate) const VML_DRAWING_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.vmlDrawing";
@@ -127,9 +154,14 @@ pub(crate) const PKG_SHEET: &str = "xl/worksheets/sheet";
 pub(crate) const PKG_SHEET_RELS: &str = "xl/worksheets/_rels/sheet";
 pub(crate) const PKG_STYLES: &str = "xl/styles.xml";
 pub(crate) const PKG_TABLES: &str = "xl/tables";
+pub(crate) const PKG_PERSONS: &str = "xl/persons/person.xml";
+pub(crate) const PKG_PIVOT_CACHE: &str = "xl/pivotCache";
+pub(crate) const PKG_PIVOT_TABLES: &str = "xl/pivotTables";
 pub(crate) const PKG_THEME: &str = "xl/theme/theme1.xml";
+pub(crate) const PKG_THREADED_COMMENTS: &str = "xl/threadedComments";
 pub(crate) const PKG_WORKBOOK: &str = "xl/workbook.xml";
 pub(crate) const PKG_WORKBOOK_RELS: &str = "xl/_rels/workbook.xml.rels";
+pub(crate) const XLSB_WORKBOOK: &str = "xl/workbook.bin";
 pub(crate) const PKG_VBA_PROJECT: &str = "xl/vbaProject.bin";
 pub(crate) const PKG_VML_DRAWING_RELS: &str = "xl/drawings/_rels/vmlDrawing";
 
@@ -138,3 +170,22 @@ pub(crate) const ARC_CORE: &str = "docProps/core.xml";
 pub(crate) const ARC_CUSTOM: &str = "docProps/custom.xml";
 
 pub(crate) const CONTENT_TYPES: &str = "[Content_Types].xml";
+
+// OpenDocument Spreadsheet
+pub(crate) const ODS_MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+pub(crate) const ODS_FO_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0";
+pub(crate) const ODS_MANIFEST_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0";
+pub(crate) const ODS_META_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:meta:1.0";
+pub(crate) const ODS_NUMBER_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0";
+pub(crate) const ODS_OF_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:of:1.2";
+pub(crate) const ODS_OFFICE_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:office:1.0";
+pub(crate) const ODS_STYLE_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:style:1.0";
+pub(crate) const ODS_SVG_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0";
+pub(crate) const ODS_TABLE_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:table:1.0";
+pub(crate) const ODS_TEXT_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:text:1.0";
+pub(crate) const ODS_VERSION: &str = "1.2";
+pub(crate) const ODS_MIMETYPE_FILE: &str = "mimetype";
+pub(crate) const ODS_CONTENT: &str = "content.xml";
+pub(crate) const ODS_STYLES: &str = "styles.xml";
+pub(crate) const ODS_META: &str = "meta.xml";
+pub(crate) const ODS_MANIFEST: &str = "META-INF/manifest.xml";
@@ -1,17 +1,25 @@
 use super::const_str::*;
+use crate::reader::driver::*;
 use crate::structs::SheetProtection;
 use crate::structs::WorkbookProtection;
 use crate::writer::driver::*;
-use aes::cipher::{block_padding::NoPadding, BlockEncryptMut, KeyIvInit};
+use crate::XlsxError;
+use aes::cipher::{
+    block_padding::NoPadding, generic_array::GenericArray, BlockDecrypt, BlockDecryptMut,
+    BlockEncryptMut, KeyIvInit,
+};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use byteorder::{ByteOrder, LittleEndian};
 use cfb;
 use hmac::{Hmac, Mac};
 use quick_xml::events::{BytesDecl, Event};
 use quick_xml::Writer;
-use sha2::{Digest, Sha512};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::io;
+use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 
@@ -26,6 +34,9 @@ const BLOCK_KEYS_DATA_INTEGRITY_HMAC_VALUE: &[u8] =
 const BLOCK_KEYS_KEY: &[u8] = &[0x14, 0x6e, 0x0b, 0xe7, 0xab, 0xac, 0xd0, 0xd6];
 const BLOCK_VERIFIER_HASH_INPUT: &[u8] = &[0xfe, 0xa7, 0xd2, 0x76, 0x3b, 0x4b, 0x9e, 0x79];
 const BLOCK_VERIFIER_HASH_VALUE: &[u8] = &[0xd7, 0xaa, 0x0f, 0x6d, 0x30, 0x61, 0x34, 0x4e];
+const CFB_SIGNATURE: &[u8] = &[0xd0, 0xcf, 0x11, 0xe0, 0xa1, 0xb1, 0x1a, 0xe1];
+const STANDARD_SPIN_COUNT: u32 = 50000;
+const STANDARD_VERIFIER_HASH_SIZE: usize = 20; // SHA-1
 
 pub fn encrypt_sheet_protection(password: &str, sheet_protection: &mut SheetProtection) {
     let key_salt_value = gen_random_16();
@@ -256,6 +267,275 @@ pub fn encrypt<P: AsRef<Path>>(filepath: &P, data: &[u8], password: &str) {
     }
 }
 
+/// Check whether the data starts with the signature of a compound file,
+/// the container of password-protected workbooks.
+pub fn is_encrypted_file<R: io::Read + io::Seek>(reader: &mut R) -> Result<bool, XlsxError> {
+    let position = reader.stream_position()?;
+    let mut signature = [0u8; 8];
+    let result = match reader.read_exact(&mut signature) {
+        Ok(_) => signature == CFB_SIGNATURE,
+        Err(_) => false,
+    };
+    reader.seek(io::SeekFrom::Start(position))?;
+    Ok(result)
+}
+
+/// Decrypt a workbook saved with "Encrypt with Password".
+/// Both Agile encryption and Standard encryption are supported.
+/// Agile encrypted files are checked against their `dataIntegrity` HMAC.
+/// Standard encryption has no integrity data, so tampering with those files is not detected.
+/// # Arguments
+/// * `reader` - compound file holding the `EncryptionInfo` and `EncryptedPackage` streams.
+/// * `password` - password of the file.
+/// # Return value
+/// * `Result` - OK is the decrypted xlsx package. Err is `XlsxError::WrongPassword` when the password does not match,
+///   and `XlsxError::Decryption` when the data integrity check fails.
+pub fn decrypt<R: io::Read + io::Seek>(reader: R, password: &str) -> Result<Vec<u8>, XlsxError> {
+    let mut comp = cfb::CompoundFile::open(reader)
+        .map_err(|_| XlsxError::Decryption("the file is not encrypted".into()))?;
+    let encryption_info = read_stream(&mut comp, "EncryptionInfo")?;
+    let encrypted_package = read_stream(&mut comp, "EncryptedPackage")?;
+    if encryption_info.len() < 8 || encrypted_package.len() < PACKAGE_OFFSET {
+        return Err(XlsxError::Decryption("invalid encrypted file".into()));
+    }
+    let major = LittleEndian::read_u16(&encryption_info[0..2]);
+    let minor = LittleEndian::read_u16(&encryption_info[2..4]);
+    match (major, minor) {
+        (4, 4) => decrypt_agile(&encryption_info, &encrypted_package, password),
+        (2..=4, 2) => decrypt_standard(&encryption_info, &encrypted_package, password),
+        _ => Err(XlsxError::Decryption(format!(
+            "encryption version {}.{} is not supported",
+            major, minor
+        ))),
+    }
+}
+
+fn read_stream<R: io::Read + io::Seek>(
+    comp: &mut cfb::CompoundFile<R>,
+    name: &str,
+) -> Result<Vec<u8>, XlsxError> {
+    let mut stream = comp
+        .open_stream(name)
+        .map_err(|_| XlsxError::Decryption(format!("{} stream is not found", name)))?;
+    let mut result = Vec::new();
+    stream.read_to_end(&mut result)?;
+    Ok(result)
+}
+
+// Agile encryption. The parameters are described in an XML document.
+fn decrypt_agile(
+    encryption_info: &[u8],
+    encrypted_package: &[u8],
+    password: &str,
+) -> Result<Vec<u8>, XlsxError> {
+    let mut key_data: HashMap<String, String> = HashMap::new();
+    let mut encrypted_key: HashMap<String, String> = HashMap::new();
+    let mut data_integrity: HashMap<String, String> = HashMap::new();
+    let mut reader = quick_xml::Reader::from_reader(&encryption_info[8..]);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(ref e) | Event::Empty(ref e) => match e.local_name().into_inner() {
+                b"keyData" => key_data = get_attributes(e),
+                b"dataIntegrity" => data_integrity = get_attributes(e),
+                // the password key encryptor, certificate ones have no spin count
+                b"encryptedKey" if get_attribute(e, b"spinCount").is_some() => {
+                    encrypted_key = get_attributes(e)
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let invalid = |name: &str| XlsxError::Decryption(format!("invalid {} in EncryptionInfo", name));
+    let get_number = |map: &HashMap<String, String>, name: &str| {
+        map.get(name)
+            .and_then(|v| v.parse::<usize>().ok())
+            .ok_or_else(|| invalid(name))
+    };
+    let get_binary = |map: &HashMap<String, String>, name: &str| {
+        map.get(name)
+            .and_then(|v| STANDARD.decode(v).ok())
+            .ok_or_else(|| invalid(name))
+    };
+    let get_text = |map: &HashMap<String, String>, name: &str| {
+        map.get(name).cloned().ok_or_else(|| invalid(name))
+    };
+
+    let package_salt_value = get_binary(&key_data, "saltValue")?;
+    let package_block_size = get_number(&key_data, "blockSize")?;
+    let package_key_bits = get_number(&key_data, "keyBits")?;
+    let package_hash_algorithm = get_text(&key_data, "hashAlgorithm")?;
+    let package_hash_size = get_number(&key_data, "hashSize")?;
+    let key_spin_count = get_number(&encrypted_key, "spinCount")?;
+    let key_salt_value = get_binary(&encrypted_key, "saltValue")?;
+    let key_key_bits = get_number(&encrypted_key, "keyBits")?;
+    let key_hash_size = get_number(&encrypted_key, "hashSize")?;
+    let key_hash_algorithm = get_text(&encrypted_key, "hashAlgorithm")?;
+    let encrypted_verifier_hash_input = get_binary(&encrypted_key, "encryptedVerifierHashInput")?;
+    let encrypted_verifier_hash_value = get_binary(&encrypted_key, "encryptedVerifierHashValue")?;
+    let encrypted_key_value = get_binary(&encrypted_key, "encryptedKeyValue")?;
+    for key_bits in [package_key_bits, key_key_bits] {
+        if !matches!(key_bits, 128 | 192 | 256) {
+            return Err(XlsxError::Decryption(format!(
+                "key size {} is not supported",
+                key_bits
+            )));
+        }
+    }
+    hash(&package_hash_algorithm, vec![]).map_err(XlsxError::Decryption)?;
+    hash(&key_hash_algorithm, vec![]).map_err(XlsxError::Decryption)?;
+
+    // verify the password
+    let decrypt_key_value = |block_key: &[u8], value: &[u8]| {
+        let key = convert_password_to_key(
+            password,
+            &key_hash_algorithm,
+            &key_salt_value,
+            &key_spin_count,
+            &key_key_bits,
+            block_key,
+        );
+        decrypt_cbc(&key, &key_salt_value, value).map_err(XlsxError::Decryption)
+    };
+    let verifier_hash_input =
+        decrypt_key_value(BLOCK_VERIFIER_HASH_INPUT, &encrypted_verifier_hash_input)?;
+    let verifier_hash_value =
+        decrypt_key_value(BLOCK_VERIFIER_HASH_VALUE, &encrypted_verifier_hash_value)?;
+    let salt_size = key_salt_value.len().min(verifier_hash_input.len());
+    let calculated_hash = hash(&key_hash_algorithm, vec![&verifier_hash_input[..salt_size]])
+        .map_err(XlsxError::Decryption)?;
+    let hash_size = key_hash_size.min(calculated_hash.len());
+    if verifier_hash_value.len() < hash_size
+        || calculated_hash[..hash_size] != verifier_hash_value[..hash_size]
+    {
+        return Err(XlsxError::WrongPassword);
+    }
+
+    // decrypt the package
+    let mut package_key = decrypt_key_value(BLOCK_KEYS_KEY, &encrypted_key_value)?;
+    package_key.truncate(package_key_bits / 8);
+
+    // verify the data integrity, the HMAC of the whole EncryptedPackage stream
+    if !data_integrity.is_empty() {
+        let decrypt_hmac = |block_key: &[u8], name: &str| {
+            let iv = create_iv(
+                &package_hash_algorithm,
+                &package_salt_value,
+                &package_block_size,
+                block_key,
+            );
+            let mut value = decrypt_cbc(&package_key, &iv, &get_binary(&data_integrity, name)?)
+                .map_err(XlsxError::Decryption)?;
+            value.truncate(package_hash_size);
+            Ok::<Vec<u8>, XlsxError>(value)
+        };
+        let hmac_key = decrypt_hmac(BLOCK_KEYS_DATA_INTEGRITY_HMAC_KEY, "encryptedHmacKey")?;
+        let hmac_value = decrypt_hmac(BLOCK_KEYS_DATA_INTEGRITY_HMAC_VALUE, "encryptedHmacValue")?;
+        let calculated_hmac = hmac(&package_hash_algorithm, &hmac_key, vec![encrypted_package])
+            .map_err(XlsxError::Decryption)?;
+        if calculated_hmac != hmac_value {
+            return Err(XlsxError::Decryption(
+                "data integrity check failed, the file has been tampered with".into(),
+            ));
+        }
+    }
+
+    Ok(crypt_package(
+        &false,
+        "AES",
+        "ChainingModeCBC",
+        &package_hash_algorithm,
+        &package_block_size,
+        &package_salt_value,
+        &package_key,
+        encrypted_package,
+    ))
+}
+
+// Standard encryption. The parameters are stored in a binary EncryptionHeader and EncryptionVerifier.
+fn decrypt_standard(
+    encryption_info: &[u8],
+    encrypted_package: &[u8],
+    password: &str,
+) -> Result<Vec<u8>, XlsxError> {
+    let invalid = || XlsxError::Decryption("invalid EncryptionInfo".into());
+    let read_u32 = |offset: usize| {
+        encryption_info
+            .get(offset..offset + 4)
+            .map(LittleEndian::read_u32)
+            .ok_or_else(invalid)
+    };
+
+    // EncryptionHeader
+    let header_size = read_u32(8)? as usize;
+    let header_offset = 12;
+    let algorithm_id = read_u32(header_offset + 8)?;
+    let key_bits = match read_u32(header_offset + 16)? {
+        0 => 128,
+        v => v as usize,
+    };
+    if !matches!(algorithm_id, 0x660E | 0x660F | 0x6610) || !matches!(key_bits, 128 | 192 | 256) {
+        return Err(XlsxError::Decryption(
+            "only AES is supported for standard encryption".into(),
+        ));
+    }
+
+    // EncryptionVerifier
+    let verifier_offset = header_offset + header_size;
+    let salt_size = read_u32(verifier_offset)? as usize;
+    let salt_offset = verifier_offset + 4;
+    let salt_value = encryption_info
+        .get(salt_offset..salt_offset + salt_size)
+        .ok_or_else(invalid)?;
+    let verifier_offset = salt_offset + salt_size;
+    let encrypted_verifier = encryption_info
+        .get(verifier_offset..verifier_offset + 16)
+        .ok_or_else(invalid)?;
+    let verifier_hash_offset = verifier_offset + 16 + 4;
+    let encrypted_verifier_hash = encryption_info
+        .get(verifier_hash_offset..verifier_hash_offset + 32)
+        .ok_or_else(invalid)?;
+
+    // verify the password
+    let key = convert_password_to_standard_key(password, salt_value, &key_bits);
+    let verifier = decrypt_ecb(&key, encrypted_verifier).map_err(XlsxError::Decryption)?;
+    let verifier_hash =
+        decrypt_ecb(&key, encrypted_verifier_hash).map_err(XlsxError::Decryption)?;
+    let calculated_hash = hash("SHA1", vec![&verifier]).map_err(XlsxError::Decryption)?;
+    if calculated_hash[..STANDARD_VERIFIER_HASH_SIZE]
+        != verifier_hash[..STANDARD_VERIFIER_HASH_SIZE]
+    {
+        return Err(XlsxError::WrongPassword);
+    }
+
+    // decrypt the package
+    let length = LittleEndian::read_u64(&encrypted_package[0..PACKAGE_OFFSET]) as usize;
+    let mut output =
+        decrypt_ecb(&key, &encrypted_package[PACKAGE_OFFSET..]).map_err(XlsxError::Decryption)?;
+    if output.len() < length {
+        return Err(XlsxError::Decryption("invalid EncryptedPackage".into()));
+    }
+    output.truncate(length);
+    Ok(output)
+}
+
+#[inline]
+fn get_attributes(e: &quick_xml::events::BytesStart<'_>) -> HashMap<String, String> {
+    e.attributes()
+        .with_checks(false)
+        .filter_map(|attr| attr.ok())
+        .filter_map(|attr| {
+            let key = String::from_utf8(attr.key.local_name().into_inner().to_vec()).ok()?;
+            let value = get_attribute_value(&attr).ok()?;
+            Some((key, value))
+        })
+        .collect()
+}
+
 // Encrypt/decrypt the package
 #[allow(clippy::too_many_arguments)]
 fn crypt_package(
@@ -270,20 +550,24 @@ fn crypt_package(
 ) -> Vec<u8> {
     // The first 8 bytes is supposed to be the length, but it seems like it is really the length - 4..
     let mut output_chunks: Vec<Vec<u8>> = Vec::new();
-    let offset = if encrypt == &true { 0 } else { PACKAGE_OFFSET };
+    let data = if encrypt == &true {
+        input
+    } else {
+        &input[PACKAGE_OFFSET..]
+    };
 
     // The package is encoded in chunks. Encrypt/decrypt each and concat.
     let mut i: usize = 0;
     let mut end = 0;
-    while end < input.len() {
+    while end < data.len() {
         let start = end;
         end = start + PACKAGE_ENCRYPTION_CHUNK_SIZE;
-        if end > input.len() {
-            end = input.len();
+        if end > data.len() {
+            end = data.len();
         };
 
         // Grab the next chunk
-        let mut input_chunk = buffer_slice(input, start + offset, end + offset);
+        let mut input_chunk = buffer_slice(data, start, end);
 
         // Pad the chunk if it is not an integer multiple of the block size
         let remainder = input_chunk.len() % block_size;
@@ -325,8 +609,8 @@ fn crypt_package(
         ]);
     } else {
         // Truncate the buffer to the size in the prefix
-        let length = buffer_read_u_int32_le(input, &0);
-        output = output[0..length as usize].to_vec();
+        let length = buffer_read_u_int32_le(input, &0) as usize;
+        output.truncate(length);
     }
 
     output
@@ -358,13 +642,16 @@ fn create_iv(
 
 // Encrypt/decrypt input
 fn crypt(
-    _encrypt: &bool,
+    encrypt: &bool,
     _cipher_algorithm: &str,
     _cipher_chaining: &str,
     key: &[u8],
     iv: &[u8],
     input: &[u8],
 ) -> Result<Vec<u8>, String> {
+    if !encrypt {
+        return decrypt_cbc(key, iv, input);
+    }
     let mut buf = [0u8; 4096];
     let pt_len = input.len();
     buf[..pt_len].copy_from_slice(input);
@@ -380,20 +667,61 @@ fn crypt(
     Ok(ct.to_vec())
 }
 
-fn hmac(algorithm: &str, key: &[u8], buffers: Vec<&[u8]>) -> Result<Vec<u8>, String> {
-    let mut mac = match algorithm {
-        "SHA512" => {
-            type HmacSha512 = Hmac<Sha512>;
-            HmacSha512::new_from_slice(key).unwrap()
-        }
-        _ => {
-            return Err(format!("algorithm {} not supported!", algorithm));
+fn decrypt_cbc(key: &[u8], iv: &[u8], input: &[u8]) -> Result<Vec<u8>, String> {
+    fn run<C>(key: &[u8], iv: &[u8], buf: &mut [u8]) -> Result<Vec<u8>, String>
+    where
+        C: BlockDecryptMut + KeyIvInit,
+    {
+        C::new_from_slices(key, iv)
+            .map_err(|_| "invalid key or iv length!".to_string())?
+            .decrypt_padded_mut::<NoPadding>(buf)
+            .map(|v| v.to_vec())
+            .map_err(|_| "invalid data length!".to_string())
+    }
+    let mut buf = input[..input.len() - input.len() % 16].to_vec();
+    match key.len() * 8 {
+        128 => run::<cbc::Decryptor<aes::Aes128>>(key, iv, &mut buf),
+        192 => run::<cbc::Decryptor<aes::Aes192>>(key, iv, &mut buf),
+        256 => run::<cbc::Decryptor<aes::Aes256>>(key, iv, &mut buf),
+        _ => Err("key size not supported!".to_string()),
+    }
+}
+
+fn decrypt_ecb(key: &[u8], input: &[u8]) -> Result<Vec<u8>, String> {
+    fn run<C>(key: &[u8], buf: &mut [u8]) -> Result<(), String>
+    where
+        C: BlockDecrypt + aes::cipher::KeyInit,
+    {
+        let cipher = C::new_from_slice(key).map_err(|_| "invalid key length!".to_string())?;
+        for block in buf.chunks_exact_mut(16) {
+            cipher.decrypt_block(GenericArray::from_mut_slice(block));
         }
-    };
-    mac.update(&buffer_concat(buffers));
+        Ok(())
+    }
+    let mut buf = input[..input.len() - input.len() % 16].to_vec();
+    match key.len() * 8 {
+        128 => run::<aes::Aes128>(key, &mut buf)?,
+        192 => run::<aes::Aes192>(key, &mut buf)?,
+        256 => run::<aes::Aes256>(key, &mut buf)?,
+        _ => return Err("key size not supported!".to_string()),
+    }
+    Ok(buf)
+}
 
-    let result = mac.finalize();
-    Ok(result.into_bytes()[..].to_vec())
+fn hmac(algorithm: &str, key: &[u8], buffers: Vec<&[u8]>) -> Result<Vec<u8>, String> {
+    fn run<M: Mac + hmac::digest::KeyInit>(key: &[u8], buffer: &[u8]) -> Vec<u8> {
+        let mut mac = <M as Mac>::new_from_slice(key).unwrap();
+        mac.update(buffer);
+        mac.finalize().into_bytes()[..].to_vec()
+    }
+    let buffer = buffer_concat(buffers);
+    match algorithm {
+        "SHA512" | "SHA-512" => Ok(run::<Hmac<Sha512>>(key, &buffer)),
+        "SHA384" | "SHA-384" => Ok(run::<Hmac<Sha384>>(key, &buffer)),
+        "SHA256" | "SHA-256" => Ok(run::<Hmac<Sha256>>(key, &buffer)),
+        "SHA1" | "SHA-1" => Ok(run::<Hmac<Sha1>>(key, &buffer)),
+        _ => Err(format!("algorithm {} not supported!", algorithm)),
+    }
 }
 
 fn convert_password_to_key(
@@ -405,13 +733,7 @@ fn convert_password_to_key(
     block_key: &[u8],
 ) -> Vec<u8> {
     // Password must be in unicode buffer
-    let mut password_buffer: Vec<u8> = Vec::new();
-    let v: Vec<u16> = password.encode_utf16().collect();
-    for a in v {
-        let d = a.to_le_bytes();
-        password_buffer.push(d[0]);
-        password_buffer.push(d[1]);
-    }
+    let password_buffer = convert_password_to_buffer(password);
 
     // Generate the initial hash
     let mut key = hash(hash_algorithm, vec![salt_value, &password_buffer]).unwrap();
@@ -438,6 +760,42 @@ fn convert_password_to_key(
     }
 }
 
+// Derive the key of standard encryption (SHA-1, 50000 spins).
+fn convert_password_to_standard_key(
+    password: &str,
+    salt_value: &[u8],
+    key_bits: &usize,
+) -> Vec<u8> {
+    let password_buffer = convert_password_to_buffer(password);
+    let mut key = hash("SHA1", vec![salt_value, &password_buffer]).unwrap();
+    for i in 0..STANDARD_SPIN_COUNT {
+        let iterator = create_uint32_le_buffer(&i, None);
+        key = hash("SHA1", vec![&iterator, &key]).unwrap();
+    }
+    let block_key = create_uint32_le_buffer(&0, None);
+    key = hash("SHA1", vec![&key, &block_key]).unwrap();
+
+    // Expand the hash with the HMAC-like padding to get to length of keyBits
+    let mut buffer1 = buffer_alloc(0x36, 64);
+    let mut buffer2 = buffer_alloc(0x5c, 64);
+    for (i, byte) in key.iter().enumerate() {
+        buffer1[i] ^= byte;
+        buffer2[i] ^= byte;
+    }
+    let derived = buffer_concat(vec![
+        &hash("SHA1", vec![&buffer1]).unwrap(),
+        &hash("SHA1", vec![&buffer2]).unwrap(),
+    ]);
+    buffer_slice(&derived, 0, key_bits / 8)
+}
+
+fn convert_password_to_buffer(password: &str) -> Vec<u8> {
+    password
+        .encode_utf16()
+        .flat_map(|v| v.to_le_bytes())
+        .collect()
+}
+
 fn convert_password_to_hash(
     password: &str,
     hash_algorithm: &str,
@@ -445,13 +803,7 @@ fn convert_password_to_hash(
     spin_count: &usize,
 ) -> Vec<u8> {
     // Password must be in unicode buffer
-    let mut password_buffer: Vec<u8> = Vec::new();
-    let v: Vec<u16> = password.encode_utf16().collect();
-    for a in v {
-        let d = a.to_le_bytes();
-        password_buffer.push(d[0]);
-        password_buffer.push(d[1]);
-    }
+    let password_buffer = convert_password_to_buffer(password);
 
     // Generate the initial hash
     let mut key = hash(hash_algorithm, vec![salt_value, &password_buffer]).unwrap();
@@ -467,14 +819,14 @@ fn convert_password_to_hash(
 
 // Calculate a hash of the concatenated buffers with the given algorithm.
 fn hash(algorithm: &str, buffers: Vec<&[u8]>) -> Result<Vec<u8>, String> {
-    let mut digest = match algorithm {
-        "SHA512" | "SHA-512" => Sha512::new(),
-        _ => {
-            return Err(format!("algorithm {} not supported!", algorithm));
-        }
-    };
-    digest.update(&buffer_concat(buffers)[..]);
-    Ok(digest.finalize().to_vec())
+    let buffer = buffer_concat(buffers);
+    match algorithm {
+        "SHA512" | "SHA-512" => Ok(Sha512::digest(&buffer).to_vec()),
+        "SHA384" | "SHA-384" => Ok(Sha384::digest(&buffer).to_vec()),
+        "SHA256" | "SHA-256" => Ok(Sha256::digest(&buffer).to_vec()),
+        "SHA1" | "SHA-1" => Ok(Sha1::digest(&buffer).to_vec()),
+        _ => Err(format!("algorithm {} not supported!", algorithm)),
+    }
 }
 
 #[inline]
@@ -3,6 +3,7 @@ use crate::helper::coordinate::*;
 use crate::helper::coordinate::*;
 use crate::helper::range::*;
 use crate::structs::StringValue;
+use crate::structs::Table;
 use fancy_regex::{Captures, Regex};
 
 /** PARTLY BASED ON: */
@@ -114,7 +115,7 @@ pub const ERRORS: &'static [&'static str] = &[
 const COMPARATORS_MULTI: &'static [&'static str] = &[">=", "<=", "<>"];
 
 lazy_static! {
-    pub static ref SCIENTIFIC_REGEX: Regex = Regex::new(r#"/^[1-9]{1}(\\.\\d+)?E{1}$/"#).unwrap();
+    pub static ref SCIENTIFIC_REGEX: Regex = Regex::new(r#"^[1-9]{1}(\.\d+)?E{1}$"#).unwrap();
 }
 
 pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken> {
@@ -134,6 +135,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
     let mut in_string = false;
     let mut in_path = false;
     let mut in_range = false;
+    let mut range_depth = 0;
     let mut in_error = false;
     let mut next_token: Option<FormulaToken> = None;
 
@@ -180,6 +182,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                     index += 1;
                 } else {
                     in_path = false;
+                    value = format!("{}{}", value, self::QUOTE_SINGLE);
                 }
             } else {
                 value = format!("{}{}", value, formula.chars().nth(index).unwrap());
@@ -189,15 +192,24 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
             continue;
         }
 
-        // bracked strings (R1C1 range index or linked workbook name)
-        // no embeds (changed to "()" by Excel)
+        // bracked strings (R1C1 range index, linked workbook name or structured reference)
+        // structured references nest brackets and escape special characters with "'"
         // end does not mark a token
         if in_range {
-            if formula.chars().nth(index).unwrap() == self::BRACKET_CLOSE {
-                in_range = false;
+            let chr = formula.chars().nth(index).unwrap();
+            if chr == self::QUOTE_SINGLE && (index + 1) < formula_length {
+                value = format!("{}{}", value, chr);
+                index += 1;
+            } else if chr == self::BRACKET_OPEN {
+                range_depth += 1;
+            } else if chr == self::BRACKET_CLOSE {
+                range_depth -= 1;
+                if range_depth == 0 {
+                    in_range = false;
+                }
             }
             value = format!("{}{}", value, formula.chars().nth(index).unwrap());
-            index;
+            index += 1;
 
             continue;
         }
@@ -223,10 +235,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
         // scientific notation check
         if self::OPERATORS_SN.contains(formula.chars().nth(index).unwrap()) {
             if value.len() > 1 {
-                if SCIENTIFIC_REGEX
-                    .is_match(&formula.chars().nth(index).unwrap().to_string())
-                    .unwrap_or(false)
-                {
+                if SCIENTIFIC_REGEX.is_match(&value).unwrap_or(false) {
                     value = format!("{}{}", value, formula.chars().nth(index).unwrap());
                     index += 1;
 
@@ -262,7 +271,8 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 tokens1.push(obj);
                 value = String::new();
             }
-            in_string = true;
+            in_path = true;
+            value = format!("{}{}", value, self::QUOTE_SINGLE);
             index += 1;
 
             continue;
@@ -270,6 +280,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
 
         if formula.chars().nth(index).unwrap() == self::BRACKET_OPEN {
             in_range = true;
+            range_depth = 1;
             value = format!("{}{}", value, self::BRACKET_OPEN);
             index += 1;
 
@@ -331,7 +342,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 value = String::new();
             }
 
-            let mut obj = stack.pop().unwrap();
+            let mut obj = stack.pop().unwrap_or_default();
             obj.set_value("");
             obj.set_token_sub_type(FormulaTokenSubTypes::Stop);
             tokens1.push(obj);
@@ -362,12 +373,12 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 value = String::new();
             }
 
-            let mut obj = stack.pop().unwrap();
+            let mut obj = stack.pop().unwrap_or_default();
             obj.set_value("");
             obj.set_token_sub_type(FormulaTokenSubTypes::Stop);
             tokens1.push(obj);
 
-            let mut obj = stack.pop().unwrap();
+            let mut obj = stack.pop().unwrap_or_default();
             obj.set_value("");
             obj.set_token_sub_type(FormulaTokenSubTypes::Stop);
             tokens1.push(obj);
@@ -391,8 +402,8 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
             obj.set_token_type(FormulaTokenTypes::Whitespace);
             tokens1.push(obj);
             index += 1;
-            while ((formula.chars().nth(index).unwrap() == self::WHITESPACE)
-                && (index < formula_length))
+            while ((index < formula_length)
+                && (formula.chars().nth(index).unwrap() == self::WHITESPACE))
             {
                 index += 1;
             }
@@ -493,7 +504,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 value = String::new();
             }
 
-            let mut obj = stack.pop().unwrap();
+            let mut obj = stack.pop().unwrap_or_default();
             obj.set_value("");
             obj.set_token_sub_type(FormulaTokenSubTypes::Stop);
             stack.push(obj.clone());
@@ -525,7 +536,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 value = String::new();
             }
 
-            let mut obj = stack.pop().unwrap();
+            let mut obj = stack.pop().unwrap_or_default();
             obj.set_value("");
             obj.set_token_sub_type(FormulaTokenSubTypes::Stop);
             tokens1.push(obj);
@@ -786,6 +797,7 @@ pub fn adjustment_formula_coordinate(
     for token in token_list.into_iter() {
         if token.get_token_type() == &FormulaTokenTypes::Operand
             && token.get_token_sub_type() == &FormulaTokenSubTypes::Range
+            && !is_structured_reference(token.get_value())
         {
             let (sheet_name, range) = split_address(token.get_value());
             let mut coordinate_list_new: Vec<String> = Vec::new();
@@ -851,6 +863,7 @@ pub fn adjustment_insert_formula_coordinate(
     for token in token_list.into_iter() {
         if token.get_token_type() == &FormulaTokenTypes::Operand
             && token.get_token_sub_type() == &FormulaTokenSubTypes::Range
+            && !is_structured_reference(token.get_value())
         {
             let (sheet_name, range) = split_address(token.get_value());
             if ignore_worksheet
@@ -912,6 +925,7 @@ pub fn adjustment_remove_formula_coordinate(
     for token in token_list.into_iter() {
         if token.get_token_type() == &FormulaTokenTypes::Operand
             && token.get_token_sub_type() == &FormulaTokenSubTypes::Range
+            && !is_structured_reference(token.get_value())
         {
             let (sheet_name, range) = split_address(token.get_value());
             if ignore_worksheet
@@ -960,6 +974,315 @@ pub fn adjustment_remove_formula_coordinate(
     render(token_list.as_ref())
 }
 
+/// Special item specifier of a structured reference. ex) `#Totals`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StructuredReferenceItem {
+    All,
+    Data,
+    Headers,
+    Totals,
+    ThisRow,
+}
+impl StructuredReferenceItem {
+    fn from_specifier(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("#All") {
+            Some(Self::All)
+        } else if value.eq_ignore_ascii_case("#Data") {
+            Some(Self::Data)
+        } else if value.eq_ignore_ascii_case("#Headers") {
+            Some(Self::Headers)
+        } else if value.eq_ignore_ascii_case("#Totals") {
+            Some(Self::Totals)
+        } else if value.eq_ignore_ascii_case("#This Row") {
+            Some(Self::ThisRow)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reference to a table by name. ex) `Table1[Amount]`, `[@Amount]`, `Table1[[#Totals],[Amount]]`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StructuredReference {
+    table_name: String,
+    items: Vec<StructuredReferenceItem>,
+    first_column: Option<String>,
+    last_column: Option<String>,
+}
+impl StructuredReference {
+    /// Name of the table. Empty when the reference points to the table holding the formula.
+    #[inline]
+    pub fn get_table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    #[inline]
+    pub fn set_table_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.table_name = value.into();
+        self
+    }
+
+    /// Special items. `#Data` is implied when empty.
+    #[inline]
+    pub fn get_items(&self) -> &[StructuredReferenceItem] {
+        &self.items
+    }
+
+    #[inline]
+    pub fn set_items(&mut self, value: Vec<StructuredReferenceItem>) -> &mut Self {
+        self.items = value;
+        self
+    }
+
+    /// First column of the reference. All columns are referenced when `None`.
+    #[inline]
+    pub fn get_first_column(&self) -> Option<&str> {
+        self.first_column.as_deref()
+    }
+
+    #[inline]
+    pub fn set_first_column<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.first_column = Some(value.into());
+        self
+    }
+
+    /// Last column of a column range. ex) `Total` for `Table1[[Amount]:[Total]]`
+    #[inline]
+    pub fn get_last_column(&self) -> Option<&str> {
+        self.last_column.as_deref().or(self.first_column.as_deref())
+    }
+
+    #[inline]
+    pub fn set_last_column<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.last_column = Some(value.into());
+        self
+    }
+
+    /// Get the cells referenced in the table.
+    /// # Arguments
+    /// * `table` - Table the reference points to.
+    /// * `row_num` - Row of the formula. Only used by `#This Row`.
+    /// # Return value
+    /// * `Option<((u32, u32), (u32, u32))>` - Column and row numbers of the first and last cells.
+    /// `None` when the reference does not resolve in the table.
+    pub fn get_coordinates(
+        &self,
+        table: &Table,
+        row_num: &u32,
+    ) -> Option<((u32, u32), (u32, u32))> {
+        let (start, end) = table.get_area();
+        let totals_count = *table.get_totals_row_count();
+        let header_count = table.get_header_row_count();
+        let header_row = *start.get_row_num();
+        let data_start = header_row + header_count;
+        let data_end = end.get_row_num().checked_sub(totals_count)?;
+
+        let (row_start, row_end) = if self.items.contains(&StructuredReferenceItem::ThisRow) {
+            if self.items.len() > 1 || row_num < &data_start || row_num > &data_end {
+                return None;
+            }
+            (*row_num, *row_num)
+        } else {
+            // header, data and totals sections in sheet order
+            let mut sections = [false; 3];
+            if self.items.is_empty() {
+                sections[1] = true;
+            }
+            for item in &self.items {
+                match item {
+                    StructuredReferenceItem::All => {
+                        sections = [header_count > 0, true, totals_count > 0]
+                    }
+                    StructuredReferenceItem::Headers if header_count > 0 => sections[0] = true,
+                    StructuredReferenceItem::Data => sections[1] = true,
+                    StructuredReferenceItem::Totals if totals_count > 0 => sections[2] = true,
+                    _ => return None,
+                }
+            }
+            if sections[0] && sections[2] && !sections[1] {
+                return None;
+            }
+            let row_start = match sections {
+                [true, _, _] => header_row,
+                [_, true, _] => data_start,
+                _ => *end.get_row_num(),
+            };
+            let row_end = match sections {
+                [_, _, true] => *end.get_row_num(),
+                [_, true, _] => data_end,
+                _ => header_row,
+            };
+            if row_start > row_end {
+                return None;
+            }
+            (row_start, row_end)
+        };
+
+        let find_column = |name: &str| {
+            table
+                .get_columns()
+                .iter()
+                .position(|v| v.get_name().eq_ignore_ascii_case(name))
+                .map(|v| start.get_col_num() + v as u32)
+        };
+        let (col_start, col_end) = match (self.get_first_column(), self.get_last_column()) {
+            (Some(first), Some(last)) => {
+                let first = find_column(first)?;
+                let last = find_column(last)?;
+                (first.min(last), first.max(last))
+            }
+            _ => (*start.get_col_num(), *end.get_col_num()),
+        };
+        Some(((col_start, row_start), (col_end, row_end)))
+    }
+
+    /// Get the range referenced in the table. ex) `"C2:C10"`
+    /// # Arguments
+    /// * `table` - Table the reference points to.
+    /// * `row_num` - Row of the formula. Only used by `#This Row`.
+    pub fn get_range(&self, table: &Table, row_num: &u32) -> Option<String> {
+        let ((col_start, row_start), (col_end, row_end)) = self.get_coordinates(table, row_num)?;
+        let coordinate_start = coordinate_from_index(&col_start, &row_start);
+        if col_start == col_end && row_start == row_end {
+            return Some(coordinate_start);
+        }
+        Some(format!(
+            "{}:{}",
+            coordinate_start,
+            coordinate_from_index(&col_end, &row_end)
+        ))
+    }
+}
+
+// Structured references follow the table and are not adjusted.
+#[inline]
+fn is_structured_reference(value: &str) -> bool {
+    value.ends_with(self::BRACKET_CLOSE) && parse_structured_reference(value).is_some()
+}
+
+/// Parse a structured reference.
+/// # Arguments
+/// * `value` - Reference. ex) `Table1[Amount]`, `[@Amount]`, `Table1[[#This Row],[Qty]:[Price]]`
+/// # Return value
+/// * `Option<StructuredReference>` - `None` when the value is not a structured reference.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::formula::*;
+/// let reference = parse_structured_reference("Table1[[#Totals],[Amount]]").unwrap();
+/// assert_eq!(reference.get_table_name(), "Table1");
+/// assert_eq!(reference.get_items(), &[StructuredReferenceItem::Totals]);
+/// assert_eq!(reference.get_first_column(), Some("Amount"));
+/// ```
+pub fn parse_structured_reference(value: &str) -> Option<StructuredReference> {
+    let value = value.trim();
+    let (table_name, spec) = value.split_once(self::BRACKET_OPEN)?;
+    let spec = spec.strip_suffix(self::BRACKET_CLOSE)?;
+    // "R" and "C" are reserved for R1C1 references
+    if table_name.contains(['!', '\'', ' '])
+        || table_name.eq_ignore_ascii_case("R")
+        || table_name.eq_ignore_ascii_case("C")
+    {
+        return None;
+    }
+
+    let mut result = StructuredReference::default();
+    result.set_table_name(table_name);
+    let spec = spec.trim();
+    if let Some(spec) = spec.strip_prefix('@') {
+        result.items.push(StructuredReferenceItem::ThisRow);
+        let spec = spec.trim();
+        if spec.starts_with(self::BRACKET_OPEN) {
+            parse_structured_reference_specifiers(spec, &mut result)?;
+            if result.items.len() > 1 {
+                return None;
+            }
+        } else if !spec.is_empty() {
+            result.set_first_column(unescape_structured_reference(spec)?);
+        }
+    } else if spec.starts_with(self::BRACKET_OPEN) {
+        parse_structured_reference_specifiers(spec, &mut result)?;
+    } else if spec.starts_with(self::ERROR_START) {
+        result
+            .items
+            .push(StructuredReferenceItem::from_specifier(spec)?);
+    } else if !spec.is_empty() {
+        result.set_first_column(unescape_structured_reference(spec)?);
+    }
+    Some(result)
+}
+
+// Reads specifiers such as `[#This Row],[Qty]:[Price]`.
+fn parse_structured_reference_specifiers(
+    value: &str,
+    result: &mut StructuredReference,
+) -> Option<()> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut separator = self::COMMA;
+    let mut chars = value.chars().peekable();
+    loop {
+        while chars.next_if(|v| v == &self::WHITESPACE).is_some() {}
+        if chars.next()? != self::BRACKET_OPEN {
+            return None;
+        }
+        let mut specifier = String::new();
+        loop {
+            match chars.next()? {
+                self::QUOTE_SINGLE => specifier.push(chars.next()?),
+                self::BRACKET_OPEN => return None,
+                self::BRACKET_CLOSE => break,
+                v => specifier.push(v),
+            }
+        }
+        if specifier.trim_start().starts_with(self::ERROR_START) {
+            if separator != self::COMMA || !columns.is_empty() {
+                return None;
+            }
+            result
+                .items
+                .push(StructuredReferenceItem::from_specifier(&specifier)?);
+        } else if separator == ':' {
+            if columns.len() != 1 {
+                return None;
+            }
+            columns.push(specifier);
+        } else if columns.is_empty() {
+            columns.push(specifier);
+        } else {
+            return None;
+        }
+
+        while chars.next_if(|v| v == &self::WHITESPACE).is_some() {}
+        match chars.next() {
+            None => break,
+            Some(v) if v == self::COMMA || v == ':' => separator = v,
+            Some(_) => return None,
+        }
+    }
+    let mut columns = columns.into_iter();
+    if let Some(v) = columns.next() {
+        result.set_first_column(v);
+    }
+    if let Some(v) = columns.next() {
+        result.set_last_column(v);
+    }
+    Some(())
+}
+
+// Removes the `'` escapes of a column name. ex) `Price'[USD']` -> `Price[USD]`
+fn unescape_structured_reference(value: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = value.chars();
+    while let Some(v) = chars.next() {
+        match v {
+            self::QUOTE_SINGLE => result.push(chars.next()?),
+            self::BRACKET_OPEN | self::BRACKET_CLOSE => return None,
+            _ => result.push(v),
+        }
+    }
+    Some(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -988,5 +1311,49 @@ mod tests {
             format!("={}", render(parse_to_tokens(formula).as_ref())),
             formula
         );
+
+        let formula = "=SUM(Table1[[#This Row],[Qty]:[Price]])*Table1[@Rate]";
+        assert_eq!(
+            format!("={}", render(parse_to_tokens(formula).as_ref())),
+            formula
+        );
+    }
+
+    #[test]
+    fn structured_reference() {
+        let reference = parse_structured_reference("Table1[Amount]").unwrap();
+        assert_eq!(reference.get_table_name(), "Table1");
+        assert!(reference.get_items().is_empty());
+        assert_eq!(reference.get_first_column(), Some("Amount"));
+        assert_eq!(reference.get_last_column(), Some("Amount"));
+
+        let reference = parse_structured_reference("[@Amount]").unwrap();
+        assert_eq!(reference.get_table_name(), "");
+        assert_eq!(reference.get_items(), &[StructuredReferenceItem::ThisRow]);
+        assert_eq!(reference.get_first_column(), Some("Amount"));
+
+        let reference = parse_structured_reference("Table1[#Totals]").unwrap();
+        assert_eq!(reference.get_items(), &[StructuredReferenceItem::Totals]);
+        assert_eq!(reference.get_first_column(), None);
+
+        let reference =
+            parse_structured_reference("Table1[[#Headers],[#Data],[Qty]:[Unit Price]]").unwrap();
+        assert_eq!(
+            reference.get_items(),
+            &[
+                StructuredReferenceItem::Headers,
+                StructuredReferenceItem::Data
+            ]
+        );
+        assert_eq!(reference.get_first_column(), Some("Qty"));
+        assert_eq!(reference.get_last_column(), Some("Unit Price"));
+
+        let reference = parse_structured_reference("Table1[@[Price '[USD']]]").unwrap();
+        assert_eq!(reference.get_first_column(), Some("Price [USD]"));
+
+        assert!(parse_structured_reference("A1:B2").is_none());
+        assert!(parse_structured_reference("R[1]").is_none());
+        assert!(parse_structured_reference("Table1[[A],[B]]").is_none());
+        assert!(parse_structured_reference("Table1[#Unknown]").is_none());
     }
 }
@@ -1,3 +1,4 @@
+use crate::structs::drawing::Theme;
 use crate::structs::Color;
 use crate::structs::Font;
 use crate::structs::RichText;
@@ -6,7 +7,9 @@ use crate::structs::UnderlineValues;
 use crate::structs::VerticalAlignmentRunValues;
 use chrono::format;
 use html_parser::{Dom, Element, Node};
+use quick_xml::escape::{escape, partial_escape, unescape};
 use std::collections::HashMap;
+use std::fmt::Write;
 use thin_vec::ThinVec;
 
 /// Generate rich text from html.
@@ -46,12 +49,149 @@ pub fn html_to_richtext_custom(
     method: &dyn AnalysisMethod,
 ) -> Result<RichText, html_parser::Error> {
     let dom = Dom::parse(html)?;
-    let data = read_node(&dom.children, &Vec::new());
+    let data = read_node(&dom.children, &Vec::new(), false);
     let result = make_rich_text(&data, method);
     Ok(result)
 }
 
-fn read_node(node_list: &Vec<Node>, parent_element: &[HfdElement]) -> ThinVec<HtmlFlatData> {
+/// Generate rich text from html whose text holds character entities,
+/// such as the html made by `richtext_to_html`.
+/// The entities (`&amp;`, `&lt;`, `&nbsp;`, ...) are turned back into characters.
+/// # Arguments
+/// * `html` - HTML String.
+/// # Return value
+/// * `Result<RichText, html_parser::Error>`
+/// # Examples
+/// ```
+/// let richtext = umya_spreadsheet::helper::html::html_to_richtext_with_entities("<b>Tom &amp; Jerry</b>").unwrap();
+/// ```
+pub fn html_to_richtext_with_entities(html: &str) -> Result<RichText, html_parser::Error> {
+    let dom = Dom::parse(html)?;
+    let data = read_node(&dom.children, &Vec::new(), true);
+    let result = make_rich_text(&data, &DataAnalysis::default());
+    Ok(result)
+}
+
+/// Generate html from rich text.
+/// Each run becomes a `<span>` whose inline style carries the font of the run.
+/// Theme colors are resolved with the default theme.
+/// The result can be read back with `html_to_richtext_with_entities`.
+/// # Arguments
+/// * `rich_text` - RichText.
+/// # Return value
+/// * `String`
+/// # Examples
+/// ```
+/// let richtext = umya_spreadsheet::helper::html::html_to_richtext("<b>bold</b> text").unwrap();
+/// let html = umya_spreadsheet::helper::html::richtext_to_html(&richtext);
+/// ```
+#[inline]
+pub fn richtext_to_html(rich_text: &RichText) -> String {
+    richtext_to_html_with_theme(rich_text, &Theme::get_default_value())
+}
+
+/// Generate html from rich text, resolving theme colors with the given theme.
+/// # Arguments
+/// * `rich_text` - RichText.
+/// * `theme` - Theme of the spreadsheet.
+/// # Return value
+/// * `String`
+/// # Examples
+/// ```
+/// let book = umya_spreadsheet::new_file();
+/// let richtext = umya_spreadsheet::helper::html::html_to_richtext("<b>bold</b> text").unwrap();
+/// let html = umya_spreadsheet::helper::html::richtext_to_html_with_theme(&richtext, book.get_theme());
+/// ```
+pub fn richtext_to_html_with_theme(rich_text: &RichText, theme: &Theme) -> String {
+    let mut result = String::new();
+    for element in rich_text.get_rich_text_elements() {
+        let text = text_to_html(element.get_text());
+        match element.get_run_properties() {
+            Some(font) => {
+                let css = font_to_css(font, theme);
+                if css.is_empty() {
+                    result.push_str(&text);
+                    continue;
+                }
+                write!(
+                    result,
+                    "<span style=\"{}\">{}</span>",
+                    partial_escape(css.join(";")).replace('"', "&quot;"),
+                    text
+                )
+                .unwrap();
+            }
+            None => result.push_str(&text),
+        }
+    }
+    result
+}
+
+/// CSS declarations of a font.
+pub(crate) fn font_to_css(font: &Font, theme: &Theme) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    if !font.get_name().is_empty() {
+        result.push(format!("font-family:'{}'", font.get_name()));
+    }
+    if *font.get_size() > 0f64 {
+        result.push(format!("font-size:{}pt", font.get_size()));
+    }
+    if *font.get_bold() {
+        result.push("font-weight:bold".to_string());
+    }
+    if *font.get_italic() {
+        result.push("font-style:italic".to_string());
+    }
+    let mut decoration: Vec<&str> = Vec::new();
+    match font.get_font_underline().get_val() {
+        UnderlineValues::Single | UnderlineValues::SingleAccounting => decoration.push("underline"),
+        UnderlineValues::Double | UnderlineValues::DoubleAccounting => {
+            decoration.push("underline double")
+        }
+        UnderlineValues::None => {}
+    }
+    if *font.get_strikethrough() {
+        decoration.push("line-through");
+    }
+    if !decoration.is_empty() {
+        result.push(format!("text-decoration:{}", decoration.join(" ")));
+    }
+    match font.get_vertical_text_alignment().get_val() {
+        VerticalAlignmentRunValues::Superscript => {
+            result.push("vertical-align:super".to_string());
+        }
+        VerticalAlignmentRunValues::Subscript => {
+            result.push("vertical-align:sub".to_string());
+        }
+        _ => {}
+    }
+    if let Some(v) = color_to_css(font.get_color(), theme) {
+        result.push(format!("color:{}", v));
+    }
+    result
+}
+
+/// Convert a color into `#RRGGBB`.
+pub(crate) fn color_to_css(color: &Color, theme: &Theme) -> Option<String> {
+    let argb = color.get_argb_with_theme(theme);
+    match argb.len() {
+        8 => Some(format!("#{}", &argb[2..])),
+        6 => Some(format!("#{}", argb)),
+        _ => None,
+    }
+}
+
+/// Escape text and turn line breaks into `<br>`.
+#[inline]
+pub(crate) fn text_to_html(value: &str) -> String {
+    escape(value).replace("\r\n", "\n").replace('\n', "<br>")
+}
+
+fn read_node(
+    node_list: &Vec<Node>,
+    parent_element: &[HfdElement],
+    is_unescape: bool,
+) -> ThinVec<HtmlFlatData> {
     let mut result: ThinVec<HtmlFlatData> = ThinVec::new();
 
     if node_list.is_empty() {
@@ -63,6 +203,11 @@ fn read_node(node_list: &Vec<Node>, parent_element: &[HfdElement]) -> ThinVec<Ht
 
     for node in node_list {
         match node {
+            Node::Text(text) if is_unescape => {
+                let text = text.replace("&nbsp;", "\u{a0}");
+                let text = unescape(&text).map_or_else(|_| text.clone(), |v| v.into_owned());
+                data.text = format!("{}{}", data.text, text);
+            }
             Node::Text(text) => {
                 data.text = format!("{}{}", data.text, text);
             }
@@ -94,7 +239,7 @@ fn read_node(node_list: &Vec<Node>, parent_element: &[HfdElement]) -> ThinVec<Ht
                 elm.classes = element.classes.clone().into();
                 data.element.push(elm);
 
-                let mut children = read_node(&element.children, &data.element);
+                let mut children = read_node(&element.children, &data.element, is_unescape);
                 result.append(&mut children);
 
                 data = HtmlFlatData::default();
@@ -118,7 +263,7 @@ fn make_rich_text(html_flat_data_list: &[HtmlFlatData], method: &dyn AnalysisMet
         let mut color: Option<String> = method.color(html_flat_data);
         let mut is_bold: bool = method.is_bold(html_flat_data);
         let mut is_italic: bool = method.is_italic(html_flat_data);
-        let mut is_underline: bool = method.is_underline(html_flat_data);
+        let mut underline: UnderlineValues = method.underline(html_flat_data);
         let mut is_superscript: bool = method.is_superscript(html_flat_data);
         let mut is_subscript: bool = method.is_subscript(html_flat_data);
         let mut is_strikethrough: bool = method.is_strikethrough(html_flat_data);
@@ -147,9 +292,8 @@ fn make_rich_text(html_flat_data_list: &[HtmlFlatData], method: &dyn AnalysisMet
         if is_italic {
             font.set_italic(is_italic);
         }
-        if is_underline {
-            font.get_font_underline_mut()
-                .set_val(UnderlineValues::Single);
+        if underline != UnderlineValues::None {
+            font.get_font_underline_mut().set_val(underline);
         }
         if is_superscript {
             font.get_vertical_text_alignment_mut()
@@ -200,6 +344,18 @@ impl HfdElement {
     pub fn contains_class(&self, class: &str) -> bool {
         self.classes.contains(&class.to_string())
     }
+
+    /// Value of a declaration in the inline `style` attribute.
+    pub fn get_style(&self, property: &str) -> Option<&str> {
+        self.attributes.get("style").and_then(|style| {
+            style.split(';').find_map(|declaration| {
+                let (key, value) = declaration.split_once(':')?;
+                key.trim()
+                    .eq_ignore_ascii_case(property)
+                    .then(|| value.trim())
+            })
+        })
+    }
 }
 
 pub trait AnalysisMethod {
@@ -210,6 +366,12 @@ pub trait AnalysisMethod {
     fn is_bold(&self, html_flat_data: &HtmlFlatData) -> bool;
     fn is_italic(&self, html_flat_data: &HtmlFlatData) -> bool;
     fn is_underline(&self, html_flat_data: &HtmlFlatData) -> bool;
+    fn underline(&self, html_flat_data: &HtmlFlatData) -> UnderlineValues {
+        match self.is_underline(html_flat_data) {
+            true => UnderlineValues::Single,
+            false => UnderlineValues::None,
+        }
+    }
     fn is_superscript(&self, html_flat_data: &HtmlFlatData) -> bool;
     fn is_subscript(&self, html_flat_data: &HtmlFlatData) -> bool;
     fn is_strikethrough(&self, html_flat_data: &HtmlFlatData) -> bool;
@@ -217,13 +379,39 @@ pub trait AnalysisMethod {
 
 #[derive(Clone, Default, Debug)]
 struct DataAnalysis {}
+impl DataAnalysis {
+    #[inline]
+    fn is_style(&self, html_flat_data: &HtmlFlatData, property: &str, values: &[&str]) -> bool {
+        html_flat_data.element.iter().any(|element| {
+            element
+                .get_style(property)
+                .map_or(false, |v| values.iter().any(|x| v.eq_ignore_ascii_case(x)))
+        })
+    }
+
+    #[inline]
+    fn is_decoration(&self, html_flat_data: &HtmlFlatData, line: &str) -> bool {
+        html_flat_data.element.iter().any(|element| {
+            element
+                .get_style("text-decoration")
+                .or_else(|| element.get_style("text-decoration-line"))
+                .map_or(false, |v| v.split_whitespace().any(|x| x == line))
+        })
+    }
+}
 impl AnalysisMethod for DataAnalysis {
     #[inline]
     fn font_name<'a>(&'a self, html_flat_data: &'a HtmlFlatData) -> Option<&str> {
-        html_flat_data
-            .element
-            .iter()
-            .find_map(|element| element.get_by_name_and_attribute("font", "face"))
+        html_flat_data.element.iter().find_map(|element| {
+            element
+                .get_by_name_and_attribute("font", "face")
+                .or_else(|| {
+                    element
+                        .get_style("font-family")
+                        .and_then(|v| v.split(',').next())
+                        .map(|v| v.trim().trim_matches(|c| c == '\'' || c == '"'))
+                })
+        })
     }
 
     #[inline]
@@ -232,6 +420,13 @@ impl AnalysisMethod for DataAnalysis {
             element
                 .get_by_name_and_attribute("font", "size")
                 .and_then(|v| v.parse::<f64>().ok())
+                .or_else(|| {
+                    let value = element.get_style("font-size")?;
+                    match value.strip_suffix("px") {
+                        Some(v) => v.trim().parse::<f64>().ok().map(|v| v * 0.75),
+                        None => value.trim_end_matches("pt").trim().parse::<f64>().ok(),
+                    }
+                })
         })
     }
 
@@ -240,7 +435,11 @@ impl AnalysisMethod for DataAnalysis {
         html_flat_data
             .element
             .iter()
-            .flat_map(|element| element.get_by_name_and_attribute("font", "color"))
+            .flat_map(|element| {
+                element
+                    .get_by_name_and_attribute("font", "color")
+                    .or_else(|| element.get_style("color"))
+            })
             .find_map(|v| {
                 let color = v.trim_start_matches('#').to_uppercase();
                 COLOR_MAP
@@ -262,32 +461,60 @@ impl AnalysisMethod for DataAnalysis {
 
     #[inline]
     fn is_bold(&self, html_flat_data: &HtmlFlatData) -> bool {
-        self.is_tag(html_flat_data, "b") || self.is_tag(html_flat_data, "strong")
+        self.is_tag(html_flat_data, "b")
+            || self.is_tag(html_flat_data, "strong")
+            || self.is_style(
+                html_flat_data,
+                "font-weight",
+                &["bold", "bolder", "600", "700", "800", "900"],
+            )
     }
 
     #[inline]
     fn is_italic(&self, html_flat_data: &HtmlFlatData) -> bool {
-        self.is_tag(html_flat_data, "i") || self.is_tag(html_flat_data, "em")
+        self.is_tag(html_flat_data, "i")
+            || self.is_tag(html_flat_data, "em")
+            || self.is_style(html_flat_data, "font-style", &["italic", "oblique"])
     }
 
     #[inline]
     fn is_underline(&self, html_flat_data: &HtmlFlatData) -> bool {
-        self.is_tag(html_flat_data, "u") || self.is_tag(html_flat_data, "ins")
+        self.is_tag(html_flat_data, "u")
+            || self.is_tag(html_flat_data, "ins")
+            || self.is_decoration(html_flat_data, "underline")
+    }
+
+    #[inline]
+    fn underline(&self, html_flat_data: &HtmlFlatData) -> UnderlineValues {
+        if !self.is_underline(html_flat_data) {
+            return UnderlineValues::None;
+        }
+        match self.is_decoration(html_flat_data, "double")
+            || self.is_style(html_flat_data, "text-decoration-style", &["double"])
+        {
+            true => UnderlineValues::Double,
+            false => UnderlineValues::Single,
+        }
     }
 
     #[inline]
     fn is_superscript(&self, html_flat_data: &HtmlFlatData) -> bool {
         self.is_tag(html_flat_data, "sup")
+            || self.is_style(html_flat_data, "vertical-align", &["super"])
     }
 
     #[inline]
     fn is_subscript(&self, html_flat_data: &HtmlFlatData) -> bool {
         self.is_tag(html_flat_data, "sub")
+            || self.is_style(html_flat_data, "vertical-align", &["sub"])
     }
 
     #[inline]
     fn is_strikethrough(&self, html_flat_data: &HtmlFlatData) -> bool {
         self.is_tag(html_flat_data, "del")
+            || self.is_tag(html_flat_data, "s")
+            || self.is_tag(html_flat_data, "strike")
+            || self.is_decoration(html_flat_data, "line-through")
     }
 }
 
@@ -816,3 +1043,65 @@ fn convert_test() {
     let html = r#"<font color="red">test</font><br><font class="test" color="green">TE<b>S</b>T<br/>TEST</font>"#;
     let result = html_to_richtext(html).unwrap();
 }
+
+#[test]
+fn richtext_to_html_test() {
+    let mut rich_text = RichText::default();
+
+    let mut element = TextElement::default();
+    element.set_text("bold & red\n");
+    let font = element.get_run_properties_mut();
+    font.set_name("Arial");
+    font.set_size(12.0);
+    font.set_bold(true);
+    font.get_color_mut().set_argb("FFFF0000");
+    rich_text.add_rich_text_elements(element);
+
+    let mut element = TextElement::default();
+    element.set_text("x");
+    let font = element.get_run_properties_mut();
+    font.set_italic(true);
+    font.set_strikethrough(true);
+    font.get_font_underline_mut()
+        .set_val(UnderlineValues::Double);
+    font.get_vertical_text_alignment_mut()
+        .set_val(VerticalAlignmentRunValues::Superscript);
+    rich_text.add_rich_text_elements(element);
+
+    let mut element = TextElement::default();
+    element.set_text("plain");
+    rich_text.add_rich_text_elements(element);
+
+    let html = richtext_to_html(&rich_text);
+    assert_eq!(
+        html,
+        "<span style=\"font-family:'Arial';font-size:12pt;font-weight:bold;color:#FF0000\">bold &amp; red<br></span>\
+         <span style=\"font-family:'Calibri';font-size:11pt;font-style:italic;text-decoration:underline double line-through;vertical-align:super;color:#000000\">x</span>\
+         plain"
+    );
+
+    let result = html_to_richtext_with_entities(&html).unwrap();
+    let elements = result.get_rich_text_elements();
+    assert_eq!(elements[0].get_text(), "bold & red\n");
+    let font = elements[0].get_run_properties().unwrap();
+    assert_eq!(font.get_name(), "Arial");
+    assert_eq!(font.get_size(), &12.0);
+    assert!(*font.get_bold());
+    assert_eq!(font.get_color().get_argb(), "FF0000");
+    let font = elements[1].get_run_properties().unwrap();
+    assert!(*font.get_italic());
+    assert!(*font.get_strikethrough());
+    assert_eq!(
+        font.get_font_underline().get_val(),
+        &UnderlineValues::Double
+    );
+    assert_eq!(
+        font.get_vertical_text_alignment().get_val(),
+        &VerticalAlignmentRunValues::Superscript
+    );
+    assert_eq!(elements.last().unwrap().get_text(), "plain");
+
+    // html_to_richtext keeps the entities as they are
+    let result = html_to_richtext("a &amp; b").unwrap();
+    assert_eq!(result.get_text(), "a &amp; b");
+}
@@ -133,8 +133,7 @@ fn format_straight_numeric_value(
         if right_value == "0" {
             right_value = right.to_string();
         } else if right.len() > right_value.len() {
-            let pow = 10i32.pow(right.len() as u32);
-            right_value = format!("{}", right_value.parse::<i32>().unwrap() * pow);
+            right_value = format!("{:0<width$}", right_value, width = right.len());
         } else {
             let mut right_value_conv: String = right_value.chars().take(right.len()).collect();
             let ajst_str: String = right_value.chars().skip(right.len()).take(1).collect();
@@ -0,0 +1,310 @@
+//! Conversion helpers for the OpenDocument Spreadsheet format.
+
+use fancy_regex::Regex;
+
+lazy_static! {
+    static ref REFERENCE_REGEX: Regex = Regex::new(
+        r#"^(?:('(?:[^']|'')+'|[A-Za-z_\p{L}][A-Za-z0-9_.\p{L}]*)!)?(\$?[A-Za-z]{1,3}\$?[0-9]+|\$?[A-Za-z]{1,3}(?=:)|\$?[0-9]+(?=:))(?::(\$?[A-Za-z]{1,3}\$?[0-9]+|\$?[A-Za-z]{1,3}|\$?[0-9]+))?(?![A-Za-z0-9_(.!])"#
+    )
+    .unwrap();
+}
+
+/// Convert a formula of this crate (without "=") into an OpenFormula attribute value.
+/// # Arguments
+/// * `formula` - formula text. (e.g. `SUM(A1:B2,Sheet2!C3)`)
+/// # Return value
+/// * `String` - formula for `table:formula`. (e.g. `of:=SUM([.A1:.B2];[$Sheet2.C3])`)
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::ods::to_ods_formula;
+/// assert_eq!(to_ods_formula("SUM(A1:B2)"), "of:=SUM([.A1:.B2])");
+/// ```
+pub fn to_ods_formula(formula: &str) -> String {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::from("of:=");
+    let mut index = 0;
+    let mut in_array = false;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '"' => {
+                let end = find_string_end(&chars, index);
+                result.extend(&chars[index..end]);
+                index = end;
+                continue;
+            }
+            '{' => in_array = true,
+            '}' => in_array = false,
+            _ => {}
+        }
+        let is_boundary = index == 0 || !is_name_char(chars[index - 1]);
+        if is_boundary && (c == '\'' || c == '$' || c.is_alphanumeric() || c == '_') {
+            let rest: String = chars[index..].iter().collect();
+            if let Ok(Some(captures)) = REFERENCE_REGEX.captures(&rest) {
+                let matched = captures.get(0).unwrap().as_str();
+                let sheet = captures.get(1).map(|v| v.as_str());
+                let first = captures.get(2).unwrap().as_str();
+                let last = captures.get(3).map(|v| v.as_str());
+                result.push('[');
+                result.push_str(&make_ods_reference(sheet, first, last));
+                result.push(']');
+                index += matched.chars().count();
+                continue;
+            }
+            // names and function names are copied as they are.
+            while index < chars.len() && is_name_char(chars[index]) {
+                result.push(chars[index]);
+                index += 1;
+            }
+            continue;
+        }
+        match c {
+            ',' => result.push(';'),
+            ';' if in_array => result.push('|'),
+            _ => result.push(c),
+        }
+        index += 1;
+    }
+    result
+}
+
+/// Convert an OpenFormula attribute value into a formula of this crate (without "=").
+/// # Arguments
+/// * `formula` - value of `table:formula`. (e.g. `of:=SUM([.A1:.B2];[$Sheet2.C3])`)
+/// # Return value
+/// * `String` - formula text. (e.g. `SUM(A1:B2,Sheet2!C3)`)
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::ods::from_ods_formula;
+/// assert_eq!(from_ods_formula("of:=SUM([.A1:.B2])"), "SUM(A1:B2)");
+/// ```
+pub fn from_ods_formula(formula: &str) -> String {
+    let formula = match formula.split_once(':') {
+        Some((prefix, v)) if matches!(prefix, "of" | "oooc" | "msoxl") => v,
+        _ => formula,
+    };
+    let formula = formula.strip_prefix('=').unwrap_or(formula);
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::new();
+    let mut index = 0;
+    let mut in_array = false;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '"' => {
+                let end = find_string_end(&chars, index);
+                result.extend(&chars[index..end]);
+                index = end;
+                continue;
+            }
+            '[' => {
+                let mut end = index + 1;
+                let mut in_quote = false;
+                while end < chars.len() && (in_quote || chars[end] != ']') {
+                    if chars[end] == '\'' {
+                        in_quote = !in_quote;
+                    }
+                    end += 1;
+                }
+                let reference: String = chars[index + 1..end.min(chars.len())].iter().collect();
+                result.push_str(&from_ods_reference(&reference));
+                index = end + 1;
+                continue;
+            }
+            '{' => in_array = true,
+            '}' => in_array = false,
+            _ => {}
+        }
+        match c {
+            ';' if in_array => result.push(','),
+            ';' => result.push(','),
+            '|' if in_array => result.push(';'),
+            '~' => result.push(','),
+            _ => result.push(c),
+        }
+        index += 1;
+    }
+    result
+}
+
+/// Convert a range address of this crate into an ODS cell range address.
+/// (e.g. `'Sheet 1'!$A$1:$B$2` to `$'Sheet 1'.$A$1:.$B$2`)
+pub fn to_ods_range_address(address: &str) -> Option<String> {
+    let captures = REFERENCE_REGEX.captures(address).ok()??;
+    if captures.get(0)?.as_str().len() != address.len() {
+        return None;
+    }
+    let sheet = captures.get(1).map(|v| v.as_str());
+    let first = captures.get(2)?.as_str();
+    let last = captures.get(3).map(|v| v.as_str());
+    Some(make_ods_reference(sheet, first, last))
+}
+
+/// Convert an ODS cell range address into a range address of this crate.
+/// (e.g. `$'Sheet 1'.$A$1:.$B$2` to `'Sheet 1'!$A$1:$B$2`)
+#[inline]
+pub fn from_ods_range_address(address: &str) -> String {
+    from_ods_reference(address)
+}
+
+/// Parse an ODS length (e.g. `2.5cm`, `0.889in`, `12pt`) into points.
+pub fn length_to_point(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let number = value[..unit_start].parse::<f64>().ok()?;
+    let rate = match &value[unit_start..] {
+        "pt" | "" => 1f64,
+        "in" => 72f64,
+        "cm" => 72f64 / 2.54,
+        "mm" => 72f64 / 25.4,
+        "pc" => 12f64,
+        "px" => 0.75,
+        _ => return None,
+    };
+    Some(number * rate)
+}
+
+/// Convert a column width (number of characters) into an ODS length.
+#[inline]
+pub fn column_width_to_length(width: &f64) -> String {
+    // 7 pixels per character and 5 pixels of padding at 96 dpi.
+    let pixel = width * 7f64 + 5f64;
+    format!("{:.4}in", pixel / 96f64)
+}
+
+/// Convert an ODS length into a column width (number of characters).
+#[inline]
+pub fn length_to_column_width(value: &str) -> Option<f64> {
+    let pixel = length_to_point(value)? / 0.75;
+    let width = ((pixel - 5f64) / 7f64).max(0f64);
+    Some((width * 100f64).round() / 100f64)
+}
+
+#[inline]
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '$' || c == '\'' || c == '!'
+}
+
+fn find_string_end(chars: &[char], start: usize) -> usize {
+    let mut index = start + 1;
+    while index < chars.len() {
+        if chars[index] == '"' {
+            if chars.get(index + 1) == Some(&'"') {
+                index += 2;
+                continue;
+            }
+            return index + 1;
+        }
+        index += 1;
+    }
+    chars.len()
+}
+
+fn make_ods_reference(sheet: Option<&str>, first: &str, last: Option<&str>) -> String {
+    let sheet = match sheet {
+        Some(v) => format!("${}", v),
+        None => String::new(),
+    };
+    match last {
+        Some(v) => format!("{}.{}:.{}", sheet, first, v),
+        None => format!("{}.{}", sheet, first),
+    }
+}
+
+fn from_ods_reference(reference: &str) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut in_quote = false;
+    for c in reference.chars() {
+        match c {
+            '\'' => {
+                in_quote = !in_quote;
+                current.push(c);
+            }
+            ':' if !in_quote => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+
+    let mut result: Vec<String> = Vec::with_capacity(parts.len());
+    for (index, part) in parts.iter().enumerate() {
+        // split the sheet name at the last dot outside quotes.
+        let mut split_at = None;
+        let mut in_quote = false;
+        for (position, c) in part.char_indices() {
+            match c {
+                '\'' => in_quote = !in_quote,
+                '.' if !in_quote => split_at = Some(position),
+                _ => {}
+            }
+        }
+        let (sheet, cell) = match split_at {
+            Some(v) => (&part[..v], &part[v + 1..]),
+            None => ("", part.as_str()),
+        };
+        let sheet = sheet.trim_start_matches('$');
+        match sheet.is_empty() || index > 0 {
+            true => result.push(cell.to_string()),
+            false => result.push(format!("{}!{}", quote_sheet_name(sheet), cell)),
+        }
+    }
+    result.join(":")
+}
+
+fn quote_sheet_name(value: &str) -> String {
+    if value.starts_with('\'') {
+        return value.to_string();
+    }
+    let is_plain = value
+        .chars()
+        .next()
+        .map(|c| c.is_alphabetic() || c == '_')
+        .unwrap_or(false)
+        && value.chars().all(|c| c.is_alphanumeric() || c == '_');
+    match is_plain {
+        true => value.to_string(),
+        false => format!("'{}'", value.replace('\'', "''")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formula() {
+        assert_eq!(
+            to_ods_formula("SUM(A1:B2,Sheet2!$C$3)&\"a,b\""),
+            "of:=SUM([.A1:.B2];[$Sheet2.$C$3])&\"a,b\""
+        );
+        assert_eq!(
+            to_ods_formula("'My Sheet'!A1+LOG10(A:A)"),
+            "of:=[$'My Sheet'.A1]+LOG10([.A:.A])"
+        );
+        assert_eq!(to_ods_formula("SUM({1,2;3,4})"), "of:=SUM({1;2|3;4})");
+        assert_eq!(to_ods_formula("MyName*2"), "of:=MyName*2");
+
+        assert_eq!(
+            from_ods_formula("of:=SUM([.A1:.B2];[$Sheet2.$C$3])&\"a;b\""),
+            "SUM(A1:B2,Sheet2!$C$3)&\"a;b\""
+        );
+        assert_eq!(
+            from_ods_formula("of:=[$'My Sheet'.A1]+1"),
+            "'My Sheet'!A1+1"
+        );
+        assert_eq!(from_ods_formula("of:=SUM({1;2|3;4})"), "SUM({1,2;3,4})");
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(length_to_point("1in"), Some(72f64));
+        assert_eq!(length_to_point("2.54cm").map(|v| v.round()), Some(72f64));
+        assert_eq!(
+            length_to_column_width(&column_width_to_length(&20f64)),
+            Some(20f64)
+        );
+    }
+}
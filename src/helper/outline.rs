@@ -0,0 +1,104 @@
+//! Outline groups used by `Worksheet::group_rows` and `Worksheet::group_columns`.
+
+/// Row or column holding the summary of a group.
+/// # Arguments
+/// * `start` - first row or column of the group.
+/// * `end` - last row or column of the group.
+/// * `summary_after` - the summary is below or right of the group.
+#[inline]
+pub(crate) fn get_summary_number(start: u32, end: u32, summary_after: bool) -> Option<u32> {
+    match summary_after {
+        true => Some(end + 1),
+        false => start.checked_sub(1).filter(|v| *v > 0),
+    }
+}
+
+/// Rows or columns shown when a group is expanded.
+/// Nested groups stay hidden when their summary is collapsed.
+/// # Arguments
+/// * `start` - first row or column of the group.
+/// * `end` - last row or column of the group.
+/// * `summary_after` - the summary is below or right of the group.
+/// * `level_of` - outline level of a row or column.
+/// * `collapsed_of` - whether a row or column is a collapsed summary.
+pub(crate) fn get_expanded_numbers(
+    start: u32,
+    end: u32,
+    summary_after: bool,
+    level_of: &dyn Fn(u32) -> u8,
+    collapsed_of: &dyn Fn(u32) -> bool,
+) -> Vec<u32> {
+    let level = (start..=end).map(level_of).min().unwrap_or(0);
+    let mut result = Vec::new();
+    collect_shown(
+        start,
+        end,
+        level,
+        summary_after,
+        level_of,
+        collapsed_of,
+        &mut result,
+    );
+    result
+}
+
+fn collect_shown(
+    start: u32,
+    end: u32,
+    level: u8,
+    summary_after: bool,
+    level_of: &dyn Fn(u32) -> u8,
+    collapsed_of: &dyn Fn(u32) -> bool,
+    result: &mut Vec<u32>,
+) {
+    let mut num = start;
+    while num <= end {
+        if level_of(num) <= level {
+            result.push(num);
+            num += 1;
+            continue;
+        }
+        // nested group.
+        let nested_start = num;
+        while num <= end && level_of(num) > level {
+            num += 1;
+        }
+        let nested_end = num - 1;
+        let is_collapsed =
+            get_summary_number(nested_start, nested_end, summary_after).map_or(false, collapsed_of);
+        if !is_collapsed {
+            collect_shown(
+                nested_start,
+                nested_end,
+                level + 1,
+                summary_after,
+                level_of,
+                collapsed_of,
+                result,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expanded_numbers() {
+        // 2..=7 is level 1, 3..=4 is a nested level 2 group summarized by 5.
+        let levels = [0, 0, 1, 2, 2, 1, 1, 1, 0];
+        let level_of = |num: u32| levels[num as usize];
+        let shown = get_expanded_numbers(2, 7, true, &level_of, &|num| num == 5);
+        assert_eq!(shown, vec![2, 5, 6, 7]);
+        let shown = get_expanded_numbers(2, 7, true, &level_of, &|_| false);
+        assert_eq!(shown, vec![2, 3, 4, 5, 6, 7]);
+        // summary above, the nested group is summarized by 2.
+        let shown = get_expanded_numbers(2, 7, false, &level_of, &|num| num == 2);
+        assert_eq!(shown, vec![2, 5, 6, 7]);
+
+        assert_eq!(get_summary_number(2, 7, true), Some(8));
+        assert_eq!(get_summary_number(2, 7, false), Some(1));
+        assert_eq!(get_summary_number(1, 7, false), None);
+    }
+}
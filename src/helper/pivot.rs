@@ -0,0 +1,1048 @@
+//! Generation of the pivot cache and the pivot table layout from the source data.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::helper::coordinate::*;
+use crate::helper::formula::*;
+use crate::helper::range::*;
+use crate::structs::Address;
+use crate::structs::CacheField;
+use crate::structs::CacheFields;
+use crate::structs::Cell;
+use crate::structs::CellRawValue;
+use crate::structs::ColumnItems;
+use crate::structs::DataConsolidateFunctionValues;
+use crate::structs::ItemValues;
+use crate::structs::MemberPropertyIndex;
+use crate::structs::PivotCacheRecord;
+use crate::structs::PivotCacheRecords;
+use crate::structs::PivotCacheValue;
+use crate::structs::PivotField;
+use crate::structs::PivotFields;
+use crate::structs::PivotItem;
+use crate::structs::PivotTableAxisValues;
+use crate::structs::PivotTableDefinition;
+use crate::structs::RowItem;
+use crate::structs::RowItems;
+use crate::structs::SharedItems;
+use crate::structs::Spreadsheet;
+use crate::structs::WorksheetSource;
+use crate::structs::XlsxError;
+
+/// Field index used in `rowFields` and `colFields` for the values.
+pub(crate) const DATA_FIELD_INDEX: i32 = -2;
+
+/// Source range of the pivot cache.
+/// A table name, optionally with columns (`"Table1[Column1]"`), or a defined name is resolved to its range.
+pub(crate) fn get_source_address(
+    spreadsheet: &Spreadsheet,
+    worksheet_source: &WorksheetSource,
+) -> Result<Address, XlsxError> {
+    let name = worksheet_source.get_name();
+    if name.is_empty() {
+        return Ok(worksheet_source.get_address().clone());
+    }
+
+    let mut reference = match name.contains('[') {
+        true => parse_structured_reference(name).ok_or_else(|| {
+            XlsxError::PivotTable(format!("invalid structured reference: {}", name))
+        })?,
+        false => {
+            let mut reference = StructuredReference::default();
+            reference.set_table_name(name);
+            reference
+        }
+    };
+    // the source always includes the header row
+    reference.set_items(vec![StructuredReferenceItem::All]);
+    for worksheet in spreadsheet.get_sheet_collection_no_check() {
+        for table in worksheet.get_tables() {
+            if !table
+                .get_name()
+                .eq_ignore_ascii_case(reference.get_table_name())
+                && !table
+                    .get_display_name()
+                    .eq_ignore_ascii_case(reference.get_table_name())
+            {
+                continue;
+            }
+            let range = reference.get_range(table, &0).ok_or_else(|| {
+                XlsxError::PivotTable(format!("table column not found: {}", name))
+            })?;
+            let mut address = Address::default();
+            address.set_sheet_name(worksheet.get_name());
+            address.get_range_mut().set_range(range);
+            return Ok(address);
+        }
+    }
+
+    let defined_name = spreadsheet
+        .get_defined_names()
+        .iter()
+        .chain(
+            spreadsheet
+                .get_sheet_collection_no_check()
+                .iter()
+                .flat_map(|v| v.get_defined_names()),
+        )
+        .find(|v| v.get_name().eq_ignore_ascii_case(name));
+    match defined_name {
+        Some(v) => {
+            let mut address = Address::default();
+            address.set_address(v.get_address());
+            Ok(address)
+        }
+        None => Err(XlsxError::PivotTable(format!(
+            "pivot source not found: {}",
+            name
+        ))),
+    }
+}
+
+/// Field names and data rows of the source range.
+pub(crate) fn read_source(
+    spreadsheet: &Spreadsheet,
+    source: &Address,
+) -> Result<(Vec<String>, Vec<Vec<PivotCacheValue>>), XlsxError> {
+    let sheet = spreadsheet
+        .get_sheet_by_name(source.get_sheet_name())
+        .ok_or_else(|| {
+            XlsxError::PivotTable(format!(
+                "pivot source sheet not found: {}",
+                source.get_sheet_name()
+            ))
+        })?;
+    let (row_start, row_end, col_start, col_end) =
+        get_start_and_end_point(&source.get_range().get_range());
+    if row_start == 0 || col_start == 0 {
+        return Err(XlsxError::PivotTable(format!(
+            "invalid pivot source range: {}",
+            source.get_address()
+        )));
+    }
+
+    let mut names: Vec<String> = Vec::new();
+    for col in col_start..=col_end {
+        let name = sheet.get_value((col, row_start));
+        if name.is_empty() {
+            return Err(XlsxError::PivotTable(format!(
+                "pivot source has no field name at {}",
+                coordinate_from_index(&col, &row_start)
+            )));
+        }
+        names.push(name);
+    }
+
+    let mut rows: Vec<Vec<PivotCacheValue>> = Vec::new();
+    for row in (row_start + 1)..=row_end {
+        rows.push(
+            (col_start..=col_end)
+                .map(|col| to_cache_value(sheet.get_cell((col, row))))
+                .collect(),
+        );
+    }
+    Ok((names, rows))
+}
+
+fn to_cache_value(cell: Option<&Cell>) -> PivotCacheValue {
+    let raw = match cell {
+        Some(v) => v.get_cell_value().get_raw_value(),
+        None => return PivotCacheValue::Missing,
+    };
+    match raw {
+        CellRawValue::Numeric(v) => PivotCacheValue::Number(*v),
+        CellRawValue::Bool(v) => PivotCacheValue::Boolean(*v),
+        CellRawValue::Error(_) => PivotCacheValue::Error(raw.to_string().into_boxed_str()),
+        CellRawValue::Empty => PivotCacheValue::Missing,
+        _ => {
+            let value = raw.to_string();
+            match value.is_empty() {
+                true => PivotCacheValue::Missing,
+                false => PivotCacheValue::String(value.into_boxed_str()),
+            }
+        }
+    }
+}
+
+/// Cache fields and records for the source data.
+/// Fields flagged in `axis` always get their distinct values listed as shared items,
+/// other fields only when they hold text.
+pub(crate) fn make_cache(
+    names: &[String],
+    rows: &[Vec<PivotCacheValue>],
+    axis: &[bool],
+) -> (CacheFields, PivotCacheRecords) {
+    let mut cache_fields = CacheFields::default();
+    let mut records: Vec<PivotCacheRecord> = vec![PivotCacheRecord::default(); rows.len()];
+    for (index, name) in names.iter().enumerate() {
+        let values: Vec<&PivotCacheValue> = rows.iter().map(|row| &row[index]).collect();
+        let (cache_field, record_values) =
+            make_cache_field(name, &values, axis.get(index).copied().unwrap_or(false));
+        for (record, value) in records.iter_mut().zip(record_values) {
+            record.add_value(value);
+        }
+        cache_fields.add_list_mut(cache_field);
+    }
+
+    let mut cache_records = PivotCacheRecords::default();
+    for record in records {
+        cache_records.add_list_mut(record);
+    }
+    (cache_fields, cache_records)
+}
+
+fn make_cache_field(
+    name: &str,
+    values: &[&PivotCacheValue],
+    is_axis: bool,
+) -> (CacheField, Vec<PivotCacheValue>) {
+    let mut has_string = false;
+    let mut has_number = false;
+    let mut has_other = false;
+    let mut has_blank = false;
+    let mut is_integer = true;
+    let mut min_value = f64::MAX;
+    let mut max_value = f64::MIN;
+    for value in values {
+        match value {
+            PivotCacheValue::String(_) => has_string = true,
+            PivotCacheValue::Number(v) => {
+                has_number = true;
+                is_integer &= v.fract() == 0f64;
+                min_value = min_value.min(*v);
+                max_value = max_value.max(*v);
+            }
+            PivotCacheValue::Missing => has_blank = true,
+            _ => has_other = true,
+        }
+    }
+
+    let mut shared_items = SharedItems::default();
+    if !has_string && !has_other && !has_blank && has_number {
+        shared_items.set_contains_semi_mixed_types(false);
+    }
+    if !has_string {
+        shared_items.set_contains_string(false);
+    }
+    if has_blank {
+        shared_items.set_contains_blank(true);
+    }
+    if [has_string, has_number, has_other]
+        .iter()
+        .filter(|v| **v)
+        .count()
+        > 1
+    {
+        shared_items.set_contains_mixed_types(true);
+    }
+    if has_number {
+        shared_items.set_contains_number(true);
+        if is_integer {
+            shared_items.set_contains_integer(true);
+        }
+        shared_items.set_min_value(min_value);
+        shared_items.set_max_value(max_value);
+    }
+
+    let record_values = if is_axis || has_string || has_other {
+        let mut index_map: HashMap<String, u32> = HashMap::new();
+        values
+            .iter()
+            .map(|value| {
+                let key = make_item_key(value);
+                let index = match index_map.get(&key) {
+                    Some(v) => *v,
+                    None => {
+                        let v = shared_items.get_items().len() as u32;
+                        shared_items.add_item((*value).clone());
+                        index_map.insert(key, v);
+                        v
+                    }
+                };
+                PivotCacheValue::Index(index)
+            })
+            .collect()
+    } else {
+        values.iter().map(|value| (*value).clone()).collect()
+    };
+
+    let mut cache_field = CacheField::default();
+    cache_field
+        .set_name(name)
+        .set_number_format_id(0)
+        .set_shared_items(shared_items);
+    (cache_field, record_values)
+}
+
+/// Items are grouped case-insensitively, as Excel does.
+fn make_item_key(value: &PivotCacheValue) -> String {
+    match value {
+        PivotCacheValue::String(v) => format!("s:{}", v.to_lowercase()),
+        PivotCacheValue::Number(v) => format!("n:{}", v),
+        _ => format!("{}:{}", value.get_tag(), value),
+    }
+}
+
+/// Display order of two shared items.
+pub(crate) fn compare_item(a: &PivotCacheValue, b: &PivotCacheValue) -> Ordering {
+    fn rank(value: &PivotCacheValue) -> u8 {
+        match value {
+            PivotCacheValue::Number(_) => 0,
+            PivotCacheValue::DateTime(_) => 1,
+            PivotCacheValue::String(_) => 2,
+            PivotCacheValue::Boolean(_) => 3,
+            PivotCacheValue::Error(_) => 4,
+            PivotCacheValue::Index(_) => 5,
+            PivotCacheValue::Missing => 6,
+        }
+    }
+    match (a, b) {
+        (PivotCacheValue::Number(x), PivotCacheValue::Number(y)) => {
+            x.partial_cmp(y).unwrap_or(Ordering::Equal)
+        }
+        (PivotCacheValue::String(x), PivotCacheValue::String(y)) => {
+            x.to_lowercase().cmp(&y.to_lowercase())
+        }
+        (PivotCacheValue::Boolean(x), PivotCacheValue::Boolean(y)) => x.cmp(y),
+        (PivotCacheValue::DateTime(x), PivotCacheValue::DateTime(y))
+        | (PivotCacheValue::Error(x), PivotCacheValue::Error(y)) => x.cmp(y),
+        (PivotCacheValue::Index(x), PivotCacheValue::Index(y)) => x.cmp(y),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// Caption of a data field. ex) `"Sum of Sales"`
+pub(crate) fn make_data_field_name(function: &DataConsolidateFunctionValues, name: &str) -> String {
+    let prefix = match function {
+        DataConsolidateFunctionValues::Average => "Average",
+        DataConsolidateFunctionValues::Count => "Count",
+        DataConsolidateFunctionValues::CountNumbers => "Count",
+        DataConsolidateFunctionValues::Maximum => "Max",
+        DataConsolidateFunctionValues::Minimum => "Min",
+        DataConsolidateFunctionValues::Product => "Product",
+        DataConsolidateFunctionValues::StandardDeviation => "StdDev",
+        DataConsolidateFunctionValues::StandardDeviationP => "StdDevp",
+        DataConsolidateFunctionValues::Sum => "Sum",
+        DataConsolidateFunctionValues::Variance => "Var",
+        DataConsolidateFunctionValues::VarianceP => "Varp",
+    };
+    format!("{} of {}", prefix, name)
+}
+
+/// Display order of the shared items of each field, and the position of each item in it.
+fn make_item_orders(cache_fields: &CacheFields) -> (Vec<Vec<u32>>, Vec<Vec<u32>>) {
+    let mut orders: Vec<Vec<u32>> = Vec::new();
+    let mut positions: Vec<Vec<u32>> = Vec::new();
+    for cache_field in cache_fields.get_list() {
+        let items = cache_field.get_shared_items().get_items();
+        let mut order: Vec<u32> = (0..items.len() as u32).collect();
+        order.sort_by(|a, b| compare_item(&items[*a as usize], &items[*b as usize]));
+        let mut position = vec![0u32; items.len()];
+        for (pos, index) in order.iter().enumerate() {
+            position[*index as usize] = pos as u32;
+        }
+        orders.push(order);
+        positions.push(position);
+    }
+    (orders, positions)
+}
+
+/// Rebuilds the pivot fields, the row and column items and the location
+/// of the definition from its row, column and data fields.
+pub(crate) fn make_layout(
+    definition: &mut PivotTableDefinition,
+    cache_fields: &CacheFields,
+    records: &PivotCacheRecords,
+) {
+    let (orders, positions) = make_item_orders(cache_fields);
+
+    let row_dims: Vec<i32> = definition
+        .get_row_fields()
+        .get_list()
+        .iter()
+        .map(|v| *v.get_data_field())
+        .collect();
+    let col_dims: Vec<i32> = definition
+        .get_column_fields()
+        .get_list()
+        .iter()
+        .map(|v| *v.get_data_field())
+        .collect();
+    let data_count = definition.get_data_fields().get_list().len() as u32;
+
+    // pivotFields
+    let mut pivot_fields = PivotFields::default();
+    for (index, order) in orders.iter().enumerate() {
+        let old_axis = definition
+            .get_pivot_fields()
+            .get_list()
+            .get(index)
+            .and_then(|v| v.get_axis().cloned());
+        let mut pivot_field = PivotField::default();
+        if row_dims.contains(&(index as i32)) {
+            pivot_field.set_axis(PivotTableAxisValues::AxisRow);
+        } else if col_dims.contains(&(index as i32)) {
+            pivot_field.set_axis(PivotTableAxisValues::AxisColumn);
+        } else if old_axis == Some(PivotTableAxisValues::AxisPage) {
+            pivot_field.set_axis(PivotTableAxisValues::AxisPage);
+        }
+        if definition
+            .get_data_fields()
+            .get_list()
+            .iter()
+            .any(|v| *v.get_fie_id() as usize == index)
+        {
+            pivot_field.set_data_field(true);
+        }
+        if pivot_field.get_axis().is_some() {
+            for x in order {
+                let mut item = PivotItem::default();
+                item.set_index(*x);
+                pivot_field.add_item(item);
+            }
+            let mut item = PivotItem::default();
+            item.set_item_type(ItemValues::Default);
+            pivot_field.add_item(item);
+        }
+        pivot_field.set_show_all(false);
+        pivot_fields.add_list_mut(pivot_field);
+    }
+    definition.set_pivot_fields(pivot_fields);
+
+    // rowItems, colItems
+    let row_tuples = make_tuples(&row_dims, records, &positions, data_count);
+    let col_tuples = make_tuples(&col_dims, records, &positions, data_count);
+    let row_items = make_row_items(&row_dims, &row_tuples, data_count);
+    let col_items = make_column_items(&col_dims, &col_tuples, data_count);
+
+    // location
+    let has_col_field = col_dims.iter().any(|v| *v != DATA_FIELD_INDEX);
+    let label_cols = u32::from(!row_dims.is_empty() || has_col_field);
+    let (first_header_row, first_data_row) = match (has_col_field, col_dims.is_empty()) {
+        (true, _) => (1, 1 + col_dims.len() as u32),
+        (false, true) => (1, 1),
+        (false, false) => (0, 1),
+    };
+    let value_cols = match data_count {
+        0 if !has_col_field => 0,
+        _ => col_items.get_list().len() as u32,
+    };
+    let width = label_cols + value_cols;
+    let height = first_data_row + row_items.get_list().len() as u32;
+    let start = definition
+        .get_location()
+        .get_reference()
+        .split(':')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let (col, row, ..) = index_from_coordinate(&start);
+    let col = col.unwrap_or(1);
+    let row = row.unwrap_or(1);
+    let width = width.max(1);
+    let reference = format!(
+        "{}:{}",
+        coordinate_from_index(&col, &row),
+        coordinate_from_index(&(col + width - 1), &(row + height - 1))
+    );
+    definition
+        .get_location_mut()
+        .set_reference(reference)
+        .set_first_header_row(first_header_row)
+        .set_first_data_row(first_data_row)
+        .set_first_data_col(label_cols);
+
+    definition.set_row_items(row_items);
+    definition.set_column_items(col_items);
+}
+
+/// Distinct combinations of item positions on an axis, in display order.
+fn make_tuples(
+    dims: &[i32],
+    records: &PivotCacheRecords,
+    positions: &[Vec<u32>],
+    data_count: u32,
+) -> Vec<Vec<u32>> {
+    if dims.is_empty() {
+        return Vec::new();
+    }
+    let mut tuples: BTreeSet<Vec<u32>> = BTreeSet::new();
+    for record in records.get_list() {
+        let tuple: Option<Vec<u32>> = dims
+            .iter()
+            .map(|dim| {
+                if *dim == DATA_FIELD_INDEX {
+                    return Some(0);
+                }
+                match record.get_values().get(*dim as usize) {
+                    Some(PivotCacheValue::Index(v)) => {
+                        positions.get(*dim as usize)?.get(*v as usize).copied()
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+        if let Some(v) = tuple {
+            tuples.insert(v);
+        }
+    }
+    if let Some(level) = dims.iter().position(|v| *v == DATA_FIELD_INDEX) {
+        let mut expanded: BTreeSet<Vec<u32>> = BTreeSet::new();
+        for tuple in tuples {
+            for data_index in 0..data_count {
+                let mut v = tuple.clone();
+                v[level] = data_index;
+                expanded.insert(v);
+            }
+        }
+        tuples = expanded;
+    }
+    tuples.into_iter().collect()
+}
+
+fn make_item(
+    repeated: usize,
+    indexes: &[u32],
+    data_index: u32,
+    item_type: Option<ItemValues>,
+) -> RowItem {
+    let mut item = RowItem::default();
+    if repeated > 0 {
+        item.set_repeated_item_count(repeated as u32);
+    }
+    if data_index > 0 {
+        item.set_index(data_index);
+    }
+    if let Some(v) = item_type {
+        item.set_item_type(v);
+    }
+    for index in indexes {
+        let mut x = MemberPropertyIndex::default();
+        x.set_val(*index as i32);
+        item.add_member_property_index(x);
+    }
+    item
+}
+
+fn common_prefix(prev: Option<&Vec<u32>>, tuple: &[u32]) -> usize {
+    match prev {
+        Some(v) => v.iter().zip(tuple).take_while(|(a, b)| a == b).count(),
+        None => 0,
+    }
+}
+
+/// Row items of the compact layout, where subtotals are shown on the item rows.
+fn make_row_items(dims: &[i32], tuples: &[Vec<u32>], data_count: u32) -> RowItems {
+    let mut row_items = RowItems::default();
+    if dims.is_empty() {
+        row_items.add_list_mut(RowItem::default());
+        return row_items;
+    }
+    let data_level = dims.iter().position(|v| *v == DATA_FIELD_INDEX);
+    let mut prev: Option<&Vec<u32>> = None;
+    for tuple in tuples {
+        for level in common_prefix(prev, tuple)..tuple.len() {
+            let data_index = match data_level {
+                Some(p) if p <= level => tuple[p],
+                _ => 0,
+            };
+            row_items.add_list_mut(make_item(level, &tuple[level..=level], data_index, None));
+        }
+        prev = Some(tuple);
+    }
+    if dims.iter().any(|v| *v != DATA_FIELD_INDEX) {
+        let grand_count = if data_level.is_some() { data_count } else { 1 };
+        for data_index in 0..grand_count {
+            row_items.add_list_mut(make_item(0, &[0], data_index, Some(ItemValues::Grand)));
+        }
+    }
+    row_items
+}
+
+/// Column items, with subtotals after the items of each outer field.
+fn make_column_items(dims: &[i32], tuples: &[Vec<u32>], data_count: u32) -> ColumnItems {
+    let mut col_items = ColumnItems::default();
+    if dims.is_empty() {
+        col_items.add_list_mut(RowItem::default());
+        return col_items;
+    }
+    let data_level = dims.iter().position(|v| *v == DATA_FIELD_INDEX);
+    let close_levels = |col_items: &mut ColumnItems, prev: &[u32], start: usize| {
+        for level in (start..dims.len() - 1).rev() {
+            let has_inner_field = dims[level + 1..].iter().any(|v| *v != DATA_FIELD_INDEX);
+            if dims[level] == DATA_FIELD_INDEX || !has_inner_field {
+                continue;
+            }
+            let data_indexes: Vec<u32> = match data_level {
+                Some(p) if p > level => (0..data_count).collect(),
+                Some(p) => vec![prev[p]],
+                None => vec![0],
+            };
+            for data_index in data_indexes {
+                col_items.add_list_mut(make_item(
+                    level,
+                    &prev[level..=level],
+                    data_index,
+                    Some(ItemValues::Default),
+                ));
+            }
+        }
+    };
+    let mut prev: Option<&Vec<u32>> = None;
+    for tuple in tuples {
+        let start = common_prefix(prev, tuple);
+        if let Some(v) = prev {
+            close_levels(&mut col_items, v, start);
+        }
+        let data_index = data_level.map(|p| tuple[p]).unwrap_or(0);
+        col_items.add_list_mut(make_item(start, &tuple[start..], data_index, None));
+        prev = Some(tuple);
+    }
+    if let Some(v) = prev {
+        close_levels(&mut col_items, v, 0);
+    }
+    if dims.iter().any(|v| *v != DATA_FIELD_INDEX) {
+        let grand_count = if data_level.is_some() { data_count } else { 1 };
+        for data_index in 0..grand_count {
+            col_items.add_list_mut(make_item(0, &[0], data_index, Some(ItemValues::Grand)));
+        }
+    }
+    col_items
+}
+
+/// Running aggregation of the values of a data field.
+#[derive(Clone, Debug)]
+struct Aggregate {
+    count: usize,
+    numbers: usize,
+    sum: f64,
+    product: f64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Default for Aggregate {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            numbers: 0,
+            sum: 0f64,
+            product: 1f64,
+            min: f64::MAX,
+            max: f64::MIN,
+            mean: 0f64,
+            m2: 0f64,
+        }
+    }
+}
+
+impl Aggregate {
+    fn add(&mut self, value: &PivotCacheValue) {
+        if value.is_missing() {
+            return;
+        }
+        self.count += 1;
+        if let Some(v) = value.get_number() {
+            self.numbers += 1;
+            self.sum += v;
+            self.product *= v;
+            self.min = self.min.min(v);
+            self.max = self.max.max(v);
+            let delta = v - self.mean;
+            self.mean += delta / self.numbers as f64;
+            self.m2 += delta * (v - self.mean);
+        }
+    }
+
+    fn get_result(&self, function: &DataConsolidateFunctionValues) -> Option<PivotCacheValue> {
+        if self.count == 0 {
+            return None;
+        }
+        let n = self.numbers as f64;
+        let div0 = || PivotCacheValue::Error("#DIV/0!".into());
+        let result = match function {
+            DataConsolidateFunctionValues::Count => PivotCacheValue::Number(self.count as f64),
+            DataConsolidateFunctionValues::CountNumbers => PivotCacheValue::Number(n),
+            DataConsolidateFunctionValues::Sum => PivotCacheValue::Number(self.sum),
+            _ if self.numbers == 0 => match function {
+                DataConsolidateFunctionValues::Maximum
+                | DataConsolidateFunctionValues::Minimum
+                | DataConsolidateFunctionValues::Product => PivotCacheValue::Number(0f64),
+                _ => div0(),
+            },
+            DataConsolidateFunctionValues::Average => PivotCacheValue::Number(self.sum / n),
+            DataConsolidateFunctionValues::Maximum => PivotCacheValue::Number(self.max),
+            DataConsolidateFunctionValues::Minimum => PivotCacheValue::Number(self.min),
+            DataConsolidateFunctionValues::Product => PivotCacheValue::Number(self.product),
+            DataConsolidateFunctionValues::StandardDeviationP => {
+                PivotCacheValue::Number((self.m2 / n).sqrt())
+            }
+            DataConsolidateFunctionValues::VarianceP => PivotCacheValue::Number(self.m2 / n),
+            _ if self.numbers < 2 => div0(),
+            DataConsolidateFunctionValues::StandardDeviation => {
+                PivotCacheValue::Number((self.m2 / (n - 1f64)).sqrt())
+            }
+            DataConsolidateFunctionValues::Variance => {
+                PivotCacheValue::Number(self.m2 / (n - 1f64))
+            }
+        };
+        Some(result)
+    }
+}
+
+/// One row or column item of the output, resolved against the layout.
+struct OutputItem {
+    /// Label and level of the label, if any.
+    label: Option<(usize, PivotCacheValue)>,
+    /// Positions of the items of the real fields the values are restricted to.
+    filter: Vec<u32>,
+    /// Index of the data field, if the item is bound to one.
+    data_index: Option<u32>,
+}
+
+fn make_output_items(
+    items: &[RowItem],
+    dims: &[i32],
+    orders: &[Vec<u32>],
+    cache_fields: &CacheFields,
+    data_names: &[String],
+) -> Vec<OutputItem> {
+    let data_level = dims.iter().position(|v| *v == DATA_FIELD_INDEX);
+    let item_label = |level: usize, pos: u32| -> PivotCacheValue {
+        let dim = dims[level];
+        if dim == DATA_FIELD_INDEX {
+            return PivotCacheValue::String(
+                data_names
+                    .get(pos as usize)
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_boxed_str(),
+            );
+        }
+        let value = orders
+            .get(dim as usize)
+            .and_then(|v| v.get(pos as usize))
+            .and_then(|v| {
+                cache_fields.get_list()[dim as usize]
+                    .get_shared_items()
+                    .get_items()
+                    .get(*v as usize)
+            })
+            .cloned()
+            .unwrap_or_default();
+        match value {
+            PivotCacheValue::Missing => PivotCacheValue::String("(blank)".into()),
+            v => v,
+        }
+    };
+    let make_filter = |path: &[u32], level: usize| -> Vec<u32> {
+        path.iter()
+            .zip(dims)
+            .take(level)
+            .filter(|(_, dim)| **dim != DATA_FIELD_INDEX)
+            .map(|(pos, _)| *pos)
+            .collect()
+    };
+    let data_name = |index: u32| data_names.get(index as usize).cloned().unwrap_or_default();
+
+    let mut result: Vec<OutputItem> = Vec::new();
+    let mut path: Vec<u32> = vec![0; dims.len()];
+    for item in items {
+        let level = *item.get_repeated_item_count() as usize;
+        let indexes: Vec<u32> = item
+            .get_member_property_index_list()
+            .iter()
+            .map(|v| *v.get_val() as u32)
+            .collect();
+        let item_type = match item.has_item_type() {
+            true => item.get_item_type().clone(),
+            false => ItemValues::Data,
+        };
+        let output_item = match item_type {
+            ItemValues::Grand => OutputItem {
+                label: Some((
+                    0,
+                    PivotCacheValue::String(
+                        match data_level {
+                            Some(_) => format!("Total {}", data_name(*item.get_index())),
+                            None => String::from("Grand Total"),
+                        }
+                        .into_boxed_str(),
+                    ),
+                )),
+                filter: Vec::new(),
+                data_index: Some(*item.get_index()),
+            },
+            ItemValues::Data if dims.is_empty() => OutputItem {
+                label: None,
+                filter: Vec::new(),
+                data_index: Some(*item.get_index()),
+            },
+            ItemValues::Data if level + indexes.len() <= dims.len() && !indexes.is_empty() => {
+                for (offset, pos) in indexes.iter().enumerate() {
+                    path[level + offset] = *pos;
+                }
+                let last = level + indexes.len() - 1;
+                let data_index = match data_level {
+                    Some(p) if p <= last => Some(path[p]),
+                    Some(_) => None,
+                    None => Some(0),
+                };
+                OutputItem {
+                    label: Some((last, item_label(last, path[last]))),
+                    filter: make_filter(&path, last + 1),
+                    data_index,
+                }
+            }
+            ItemValues::Data => continue,
+            _ if level < dims.len() => {
+                if let Some(pos) = indexes.first() {
+                    path[level] = *pos;
+                }
+                let label = item_label(level, path[level]);
+                let label = match data_level {
+                    Some(p) if p > level => format!("{} {}", label, data_name(*item.get_index())),
+                    _ => format!("{} Total", label),
+                };
+                OutputItem {
+                    label: Some((level, PivotCacheValue::String(label.into_boxed_str()))),
+                    filter: make_filter(&path, level + 1),
+                    data_index: Some(*item.get_index()),
+                }
+            }
+            _ => continue,
+        };
+        result.push(output_item);
+    }
+    result
+}
+
+/// Labels, values, subtotals and grand totals of the output range, as `(col, row, value)`.
+pub(crate) fn make_output(
+    definition: &PivotTableDefinition,
+    cache_fields: &CacheFields,
+    records: &PivotCacheRecords,
+) -> Vec<(u32, u32, PivotCacheValue)> {
+    let (orders, positions) = make_item_orders(cache_fields);
+    let row_dims: Vec<i32> = definition
+        .get_row_fields()
+        .get_list()
+        .iter()
+        .map(|v| *v.get_data_field())
+        .collect();
+    let col_dims: Vec<i32> = definition
+        .get_column_fields()
+        .get_list()
+        .iter()
+        .map(|v| *v.get_data_field())
+        .collect();
+    let data_fields = definition.get_data_fields().get_list();
+    let data_names: Vec<String> = data_fields
+        .iter()
+        .map(|v| v.get_name().to_string())
+        .collect();
+
+    let location = definition.get_location();
+    let start = location
+        .get_reference()
+        .split(':')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let (col0, row0, ..) = index_from_coordinate(&start);
+    let col0 = col0.unwrap_or(1);
+    let row0 = row0.unwrap_or(1);
+    let first_header_row = *location.get_first_header_row();
+    let first_data_row = *location.get_first_data_row();
+    let first_data_col = *location.get_first_data_col();
+    let has_col_field = col_dims.iter().any(|v| *v != DATA_FIELD_INDEX);
+
+    let mut cells: Vec<(u32, u32, PivotCacheValue)> = Vec::new();
+    let text = |v: &str| PivotCacheValue::String(v.into());
+
+    // headers
+    if has_col_field {
+        if data_names.len() == 1 {
+            cells.push((col0, row0, text(&data_names[0])));
+        }
+        cells.push((col0 + first_data_col, row0, text("Column Labels")));
+    } else if col_dims.is_empty() && !data_names.is_empty() {
+        cells.push((col0 + first_data_col, row0, text(&data_names[0])));
+    }
+    if !row_dims.is_empty() {
+        cells.push((col0, row0 + first_data_row.max(1) - 1, text("Row Labels")));
+    }
+
+    let row_items = make_output_items(
+        definition.get_row_items().get_list(),
+        &row_dims,
+        &orders,
+        cache_fields,
+        &data_names,
+    );
+    let col_items = make_output_items(
+        definition.get_column_items().get_list(),
+        &col_dims,
+        &orders,
+        cache_fields,
+        &data_names,
+    );
+    for (index, item) in row_items.iter().enumerate() {
+        if let Some((_, label)) = &item.label {
+            cells.push((col0, row0 + first_data_row + index as u32, label.clone()));
+        }
+    }
+    for (index, item) in col_items.iter().enumerate() {
+        if let Some((level, label)) = &item.label {
+            cells.push((
+                col0 + first_data_col + index as u32,
+                row0 + first_header_row + *level as u32,
+                label.clone(),
+            ));
+        }
+    }
+    if data_fields.is_empty() {
+        return cells;
+    }
+
+    // values
+    let real_dims = |dims: &[i32]| -> Vec<usize> {
+        dims.iter()
+            .filter(|v| **v != DATA_FIELD_INDEX)
+            .map(|v| *v as usize)
+            .collect()
+    };
+    let row_fields = real_dims(&row_dims);
+    let col_fields = real_dims(&col_dims);
+    let get_value = |record: &PivotCacheRecord, field: usize| -> PivotCacheValue {
+        match record.get_values().get(field) {
+            Some(PivotCacheValue::Index(v)) => cache_fields
+                .get_list()
+                .get(field)
+                .and_then(|f| f.get_shared_items().get_items().get(*v as usize))
+                .cloned()
+                .unwrap_or_default(),
+            Some(v) => v.clone(),
+            None => PivotCacheValue::Missing,
+        }
+    };
+    let get_path = |record: &PivotCacheRecord, fields: &[usize]| -> Option<Vec<u32>> {
+        fields
+            .iter()
+            .map(|field| match record.get_values().get(*field) {
+                Some(PivotCacheValue::Index(v)) => positions.get(*field)?.get(*v as usize).copied(),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let mut totals: HashMap<(Vec<u32>, Vec<u32>), Vec<Aggregate>> = HashMap::new();
+    for record in records.get_list() {
+        let (row_path, col_path) =
+            match (get_path(record, &row_fields), get_path(record, &col_fields)) {
+                (Some(r), Some(c)) => (r, c),
+                _ => continue,
+            };
+        let values: Vec<PivotCacheValue> = data_fields
+            .iter()
+            .map(|v| get_value(record, *v.get_fie_id() as usize))
+            .collect();
+        for row_len in 0..=row_path.len() {
+            for col_len in 0..=col_path.len() {
+                let aggregates = totals
+                    .entry((row_path[..row_len].to_vec(), col_path[..col_len].to_vec()))
+                    .or_insert_with(|| vec![Aggregate::default(); values.len()]);
+                for (aggregate, value) in aggregates.iter_mut().zip(&values) {
+                    aggregate.add(value);
+                }
+            }
+        }
+    }
+
+    let row_has_data = row_dims.contains(&DATA_FIELD_INDEX);
+    for (row_index, row_item) in row_items.iter().enumerate() {
+        for (col_index, col_item) in col_items.iter().enumerate() {
+            if row_item.data_index.is_none() || col_item.data_index.is_none() {
+                continue;
+            }
+            let data_index = match row_has_data {
+                true => row_item.data_index,
+                false => col_item.data_index,
+            }
+            .unwrap_or(0);
+            let Some(data_field) = data_fields.get(data_index as usize) else {
+                continue;
+            };
+            let key = (row_item.filter.clone(), col_item.filter.clone());
+            let value = totals
+                .get(&key)
+                .and_then(|v| v[data_index as usize].get_result(data_field.get_subtotal()));
+            if let Some(v) = value {
+                cells.push((
+                    col0 + first_data_col + col_index as u32,
+                    row0 + first_data_row + row_index as u32,
+                    v,
+                ));
+            }
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_cache_lists_axis_items() {
+        let names = vec!["Region".to_string(), "Sales".to_string()];
+        let rows = vec![
+            vec![
+                PivotCacheValue::String("West".into()),
+                PivotCacheValue::Number(10f64),
+            ],
+            vec![
+                PivotCacheValue::String("east".into()),
+                PivotCacheValue::Number(2.5),
+            ],
+            vec![
+                PivotCacheValue::String("West".into()),
+                PivotCacheValue::Missing,
+            ],
+        ];
+        let (cache_fields, records) = make_cache(&names, &rows, &[true, false]);
+
+        let region = cache_fields.get_list()[0].get_shared_items();
+        assert_eq!(region.get_items().len(), 2);
+        let sales = cache_fields.get_list()[1].get_shared_items();
+        assert!(sales.get_items().is_empty());
+        assert_eq!(sales.get_min_value(), &2.5);
+        assert_eq!(sales.get_max_value(), &10f64);
+        assert!(!sales.get_contains_integer());
+        assert!(sales.get_contains_blank());
+
+        assert_eq!(
+            records.get_list()[2].get_values(),
+            &[PivotCacheValue::Index(0), PivotCacheValue::Missing]
+        );
+    }
+
+    #[test]
+    fn compare_items() {
+        let east = PivotCacheValue::String("east".into());
+        let west = PivotCacheValue::String("West".into());
+        assert_eq!(compare_item(&east, &west), Ordering::Less);
+        assert_eq!(
+            compare_item(&PivotCacheValue::Number(2f64), &east),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_item(&PivotCacheValue::Missing, &east),
+            Ordering::Greater
+        );
+    }
+}
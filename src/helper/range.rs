@@ -67,6 +67,32 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
     (row_start, row_end, col_start, col_end)
 }
 
+/// Same as `get_start_and_end_point`, for ranges that may select whole columns or rows.
+/// # Returns
+/// `(row_start, row_end, col_start, col_end)`. The rows are 0 for "A:C", the columns are 0 for "2:5".
+/// Err when the range is not standard, e.g. "A1:B".
+pub fn get_start_and_end_point_checked(
+    range_str: &str,
+) -> Result<(u32, u32, u32, u32), &'static str> {
+    let upper_rng_str = range_str.to_uppercase();
+    let coordinate_collection: Vec<&str> = upper_rng_str.split(':').collect();
+    let (col_start, row_start, ..) = index_from_coordinate(coordinate_collection[0]);
+    let (col_end, row_end) = match coordinate_collection.len() {
+        1 => (col_start, row_start),
+        2 => {
+            let (col, row, ..) = index_from_coordinate(coordinate_collection[1]);
+            (col, row)
+        }
+        _ => return Err("Non-standard range."),
+    };
+    match (col_start, col_end, row_start, row_end) {
+        (Some(c1), Some(c2), Some(r1), Some(r2)) => Ok((r1, r2, c1, c2)),
+        (Some(c1), Some(c2), None, None) => Ok((0, 0, c1, c2)),
+        (None, None, Some(r1), Some(r2)) => Ok((r1, r2, 0, 0)),
+        _ => Err("Non-standard range."),
+    }
+}
+
 #[inline]
 pub fn get_split_range(range: &str) -> Vec<&str> {
     range.split(':').collect()
@@ -76,3 +102,25 @@ pub fn get_split_range(range: &str) -> Vec<&str> {
 pub fn get_join_range(coordinate_list: &[String]) -> String {
     coordinate_list.join(":")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_and_end_point() {
+        assert_eq!(get_start_and_end_point("B2:D5"), (2, 5, 2, 4));
+        assert_eq!(get_start_and_end_point("C3"), (3, 3, 3, 3));
+        assert_eq!(get_start_and_end_point("A1:B"), (1, 1, 1, 2));
+    }
+
+    #[test]
+    fn start_and_end_point_checked() {
+        assert_eq!(get_start_and_end_point_checked("B2:D5"), Ok((2, 5, 2, 4)));
+        assert_eq!(get_start_and_end_point_checked("B:D"), Ok((0, 0, 2, 4)));
+        assert_eq!(get_start_and_end_point_checked("2:5"), Ok((2, 5, 0, 0)));
+        assert_eq!(get_start_and_end_point_checked("C3"), Ok((3, 3, 3, 3)));
+        assert!(get_start_and_end_point_checked("A1:B").is_err());
+        assert!(get_start_and_end_point_checked("A:B2").is_err());
+    }
+}
@@ -0,0 +1,225 @@
+//! Ordering of cells used by `Worksheet::sort_range`.
+
+use std::cmp::Ordering;
+
+use crate::structs::Cell;
+use crate::structs::CellRawValue;
+use crate::structs::CellValue;
+use crate::structs::Color;
+use crate::structs::SortByValues;
+use crate::structs::SortKey;
+
+/// Value of a cell as seen by a sort key.
+#[derive(Clone, Debug)]
+pub(crate) enum SortValue {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error,
+    /// Whether the cell has the color of the key.
+    Color(bool),
+    Blank,
+}
+impl SortValue {
+    pub(crate) fn new(cell: Option<&Cell>, key: &SortKey) -> Self {
+        match key.get_sort_by() {
+            SortByValues::CellColor => {
+                let color = cell.and_then(|v| v.get_style().get_background_color());
+                Self::Color(is_same_color(color, key.get_color()))
+            }
+            SortByValues::FontColor => {
+                let color = cell
+                    .and_then(|v| v.get_style().get_font())
+                    .map(|v| v.get_color());
+                Self::Color(is_same_color(color, key.get_color()))
+            }
+            _ => match cell {
+                Some(v) => Self::from_raw_value(v.get_raw_value()),
+                None => Self::Blank,
+            },
+        }
+    }
+
+    fn from_raw_value(value: &CellRawValue) -> Self {
+        match value {
+            CellRawValue::Numeric(v) => Self::Number(*v),
+            CellRawValue::Bool(v) => Self::Bool(*v),
+            CellRawValue::Error(_) => Self::Error,
+            CellRawValue::String(v) if v.is_empty() => Self::Blank,
+            CellRawValue::String(v) => Self::Text(v.to_string()),
+            CellRawValue::RichText(v) => match v.get_text() {
+                text if text.is_empty() => Self::Blank,
+                text => Self::Text(text.to_string()),
+            },
+            CellRawValue::Lazy(v) => Self::from_raw_value(&CellValue::guess_typed_data(v)),
+            CellRawValue::Empty => Self::Blank,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_text(&self) -> bool {
+        matches!(self, Self::Text(_))
+    }
+
+    #[inline]
+    pub(crate) fn is_blank(&self) -> bool {
+        matches!(self, Self::Blank)
+    }
+
+    /// Ascending order is numbers, text, logical values, errors.
+    #[inline]
+    fn type_rank(&self) -> u8 {
+        match self {
+            Self::Number(_) => 0,
+            Self::Text(_) => 1,
+            Self::Bool(_) => 2,
+            Self::Error => 3,
+            Self::Color(_) => 4,
+            Self::Blank => 5,
+        }
+    }
+}
+
+/// Compare two values of a key.
+/// Blanks are placed last in both directions, like Excel does.
+pub(crate) fn compare(a: &SortValue, b: &SortValue, key: &SortKey) -> Ordering {
+    let result = match (a, b) {
+        (SortValue::Blank, SortValue::Blank) => return Ordering::Equal,
+        (SortValue::Blank, _) => return Ordering::Greater,
+        (_, SortValue::Blank) => return Ordering::Less,
+        // cells with the color come first.
+        (SortValue::Color(a), SortValue::Color(b)) => b.cmp(a),
+        _ => compare_value(a, b, key),
+    };
+    match key.get_descending() {
+        true => result.reverse(),
+        false => result,
+    }
+}
+
+fn compare_value(a: &SortValue, b: &SortValue, key: &SortKey) -> Ordering {
+    if let (SortValue::Text(a), SortValue::Text(b)) = (a, b) {
+        let list = key.get_custom_list();
+        let position = |value: &str| list.iter().position(|v| v.eq_ignore_ascii_case(value));
+        match (position(a), position(b)) {
+            (Some(a), Some(b)) => return a.cmp(&b),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            (None, None) => return compare_text(a, b, *key.get_case_sensitive()),
+        }
+    }
+    match (a, b) {
+        (SortValue::Number(a), SortValue::Number(b)) => a.total_cmp(b),
+        (SortValue::Bool(a), SortValue::Bool(b)) => a.cmp(b),
+        _ => a.type_rank().cmp(&b.type_rank()),
+    }
+}
+
+/// Text is compared without case first.
+/// When case sensitive, lowercase comes before uppercase for otherwise equal text.
+fn compare_text(a: &str, b: &str, case_sensitive: bool) -> Ordering {
+    let result = a.to_lowercase().cmp(&b.to_lowercase());
+    if result != Ordering::Equal || !case_sensitive {
+        return result;
+    }
+    let swap_case = |c: char| match c.is_lowercase() {
+        true => c.to_uppercase().next().unwrap_or(c),
+        false => c.to_lowercase().next().unwrap_or(c),
+    };
+    a.chars().map(swap_case).cmp(b.chars().map(swap_case))
+}
+
+#[inline]
+pub(crate) fn is_same_color(color: Option<&Color>, target: Option<&Color>) -> bool {
+    match (color, target) {
+        (Some(a), Some(b)) => {
+            a.get_argb() == b.get_argb() && a.get_theme_index() == b.get_theme_index()
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Guess whether the first row of a range is a header.
+/// It is when the row holds only text and the row below has values of another type or a lighter font.
+pub(crate) fn is_header_row(first: &[Option<&Cell>], second: &[Option<&Cell>]) -> bool {
+    let value = |cell: Option<&Cell>| match cell {
+        Some(v) => SortValue::from_raw_value(v.get_raw_value()),
+        None => SortValue::Blank,
+    };
+    let is_bold = |cell: Option<&Cell>| {
+        cell.and_then(|v| v.get_style().get_font())
+            .map_or(false, |v| *v.get_bold())
+    };
+
+    let first_values: Vec<SortValue> = first.iter().map(|v| value(*v)).collect();
+    if first_values.iter().all(|v| v.is_blank())
+        || first_values.iter().any(|v| !v.is_blank() && !v.is_text())
+    {
+        return false;
+    }
+    first
+        .iter()
+        .zip(second)
+        .zip(&first_values)
+        .any(|((a, b), a_value)| {
+            let b_value = value(*b);
+            (a_value.is_text() && !b_value.is_blank() && !b_value.is_text())
+                || (is_bold(*a) && !is_bold(*b))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_values() {
+        let key = SortKey::default();
+        let mut values = vec![
+            SortValue::Blank,
+            SortValue::Text("b".to_string()),
+            SortValue::Bool(true),
+            SortValue::Number(10.0),
+            SortValue::Error,
+            SortValue::Text("A".to_string()),
+            SortValue::Number(2.0),
+        ];
+        values.sort_by(|a, b| compare(a, b, &key));
+        assert_eq!(
+            format!("{:?}", values),
+            r#"[Number(2.0), Number(10.0), Text("A"), Text("b"), Bool(true), Error, Blank]"#
+        );
+
+        let mut key = SortKey::default();
+        key.set_descending(true);
+        values.sort_by(|a, b| compare(a, b, &key));
+        assert_eq!(
+            format!("{:?}", values),
+            r#"[Error, Bool(true), Text("b"), Text("A"), Number(10.0), Number(2.0), Blank]"#
+        );
+
+        let mut key = SortKey::default();
+        key.set_case_sensitive(true);
+        let mut values = vec![
+            SortValue::Text("Abc".to_string()),
+            SortValue::Text("abc".to_string()),
+        ];
+        values.sort_by(|a, b| compare(a, b, &key));
+        assert_eq!(format!("{:?}", values), r#"[Text("abc"), Text("Abc")]"#);
+
+        let mut key = SortKey::default();
+        key.set_custom_list(vec!["High", "Medium", "Low"]);
+        let mut values = vec![
+            SortValue::Text("low".to_string()),
+            SortValue::Text("Other".to_string()),
+            SortValue::Text("High".to_string()),
+            SortValue::Text("Medium".to_string()),
+        ];
+        values.sort_by(|a, b| compare(a, b, &key));
+        assert_eq!(
+            format!("{:?}", values),
+            r#"[Text("High"), Text("Medium"), Text("low"), Text("Other")]"#
+        );
+    }
+}
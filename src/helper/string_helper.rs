@@ -12,3 +12,20 @@ pub(crate) fn _get_decimal_separator() -> String {
 pub(crate) fn _get_thousands_separator() -> String {
     String::from(",")
 }
+
+/// Make a random GUID like `{8B1A4E5C-2F3D-4A6B-9C7E-1D2F3A4B5C6D}`.
+pub(crate) fn make_guid() -> String {
+    let mut buf = [0u8; 16];
+    getrandom::getrandom(&mut buf).unwrap();
+    buf[6] = (buf[6] & 0x0f) | 0x40;
+    buf[8] = (buf[8] & 0x3f) | 0x80;
+    let hex: String = buf.iter().map(|v| format!("{:02X}", v)).collect();
+    format!(
+        "{{{}-{}-{}-{}-{}}}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
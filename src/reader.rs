@@ -1,4 +1,8 @@
 //! file reader library.
 
+pub mod csv;
 pub(crate) mod driver;
+pub mod ods;
+pub mod xls;
+pub mod xlsb;
 pub mod xlsx;
@@ -0,0 +1,163 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use crate::structs::CsvReaderOption;
+use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
+use crate::structs::XlsxError;
+
+/// read csv from arbitrary reader into a new spreadsheet.
+/// The values are stored in the first worksheet "Sheet1".
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader<R: io::Read>(
+    reader: R,
+    option: &CsvReaderOption,
+) -> Result<Spreadsheet, XlsxError> {
+    let mut book = crate::new_file();
+    read_reader_to_worksheet(reader, book.get_sheet_mut(&0).unwrap(), option)?;
+    Ok(book)
+}
+
+/// read csv file into a new spreadsheet.
+/// The values are stored in the first worksheet "Sheet1".
+/// # Arguments
+/// * `path` - file path to read.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let path = std::path::Path::new("./tests/test_files/aaa.csv");
+/// let mut option = structs::CsvReaderOption::default();
+/// option.set_delimiter(';');
+/// let mut book = reader::csv::read(path, &option).unwrap();
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P, option: &CsvReaderOption) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(io::BufReader::new(file), option)
+}
+
+/// read csv from arbitrary reader into an existing worksheet.
+/// Values are written from cell A1; cells outside of the csv data are left untouched.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `worksheet` - worksheet to fill.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn read_reader_to_worksheet<R: io::Read>(
+    mut reader: R,
+    worksheet: &mut Worksheet,
+    option: &CsvReaderOption,
+) -> Result<(), XlsxError> {
+    let mut data: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    // encoding. a byte order mark takes precedence over the option.
+    let (encoding, bom_length) = match encoding_rs::Encoding::for_bom(&data) {
+        Some(v) => v,
+        None => (option.get_csv_encode_value().get_encoding(), 0),
+    };
+    let text = encoding.decode_without_bom_handling(&data[bom_length..]).0;
+
+    for (row_index, record) in parse_records(&text, option).into_iter().enumerate() {
+        for (column_index, value) in record.into_iter().enumerate() {
+            let value = match *option.get_do_trim() {
+                true => value.trim().to_string(),
+                false => value,
+            };
+            if value.is_empty() {
+                continue;
+            }
+            let cell = worksheet.get_cell_mut((column_index as u32 + 1, row_index as u32 + 1));
+            match *option.get_guess_type() {
+                true => cell.set_value(value),
+                false => cell.set_value_string(value),
+            };
+        }
+    }
+    Ok(())
+}
+
+/// read csv file into an existing worksheet.
+/// Values are written from cell A1; cells outside of the csv data are left untouched.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `worksheet` - worksheet to fill.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+#[inline]
+pub fn read_to_worksheet<P: AsRef<Path>>(
+    path: P,
+    worksheet: &mut Worksheet,
+    option: &CsvReaderOption,
+) -> Result<(), XlsxError> {
+    let file = File::open(path)?;
+    read_reader_to_worksheet(io::BufReader::new(file), worksheet, option)
+}
+
+/// Split the text into records and fields following RFC 4180.
+/// Quoted fields may contain delimiters, line breaks and doubled quotes.
+/// CRLF, LF and CR are all accepted as line breaks.
+fn parse_records(text: &str, option: &CsvReaderOption) -> Vec<Vec<String>> {
+    let delimiter = *option.get_delimiter();
+    let quote_char = *option.get_quote_char();
+
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut is_record_started = false;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == quote_char {
+                if chars.peek() == Some(&quote_char) {
+                    field.push(quote_char);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            _ if c == quote_char => {
+                in_quotes = true;
+                is_record_started = true;
+            }
+            _ if c == delimiter => {
+                record.push(std::mem::take(&mut field));
+                is_record_started = true;
+            }
+            '\r' | '\n' => {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+                is_record_started = false;
+            }
+            _ => {
+                field.push(c);
+                is_record_started = true;
+            }
+        }
+    }
+    if is_record_started || in_quotes {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
@@ -0,0 +1,82 @@
+//! Reader for the OpenDocument Spreadsheet format (.ods).
+
+use quick_xml::escape;
+use quick_xml::events::{BytesStart, BytesText};
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+use super::driver::get_attribute;
+use crate::helper::const_str::*;
+use crate::structs::Spreadsheet;
+use crate::XlsxError;
+
+mod content;
+mod number_format;
+mod styles;
+
+/// read ods spreadsheet from arbitrary reader.
+/// Cell values, formulas, cell styles (fonts, number formats, borders, fills, alignment),
+/// merged cells, column widths, row heights, sheet visibility and named ranges are loaded.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader<R: io::Read + io::Seek>(reader: R) -> Result<Spreadsheet, XlsxError> {
+    let mut arv = zip::read::ZipArchive::new(reader)?;
+
+    if let Ok(mut v) = arv.by_name(ODS_MIMETYPE_FILE) {
+        let mut mimetype = String::new();
+        v.read_to_string(&mut mimetype)?;
+        if mimetype.trim() != ODS_MIMETYPE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not an OpenDocument Spreadsheet",
+            )
+            .into());
+        }
+    }
+
+    let mut collection = styles::StyleCollection::default();
+    styles::read(&mut arv, ODS_STYLES, &mut collection)?;
+
+    let mut book = crate::new_file_empty_worksheet();
+    content::read(&mut arv, ODS_CONTENT, &mut collection, &mut book)?;
+    Ok(book)
+}
+
+/// read ods spreadsheet file.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.ods");
+/// let mut book = umya_spreadsheet::reader::ods::read(path).unwrap();
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(io::BufReader::new(file))
+}
+
+/// Get an attribute value with XML entities resolved.
+#[inline]
+pub(crate) fn get_attribute_unescaped(e: &BytesStart<'_>, key: &[u8]) -> Option<String> {
+    get_attribute(e, key).map(|v| match escape::unescape(&v) {
+        Ok(unescaped) => unescaped.into_owned(),
+        Err(_) => v,
+    })
+}
+
+/// Get a text node with XML entities resolved.
+/// Malformed entities are kept as they are.
+#[inline]
+pub(crate) fn get_text_unescaped(e: &BytesText<'_>) -> String {
+    match e.unescape() {
+        Ok(unescaped) => unescaped.into_owned(),
+        Err(_) => String::from_utf8_lossy(e).into_owned(),
+    }
+}
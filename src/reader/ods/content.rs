@@ -0,0 +1,543 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::io;
+
+use super::get_attribute_unescaped;
+use super::get_text_unescaped;
+use super::styles::StyleCollection;
+use super::XlsxError;
+use crate::helper::coordinate::string_from_column_index;
+use crate::helper::ods::*;
+use crate::structs::DefinedName;
+use crate::structs::Spreadsheet;
+use crate::structs::Style;
+
+/// Repeated rows and columns beyond this count are only read when they have content.
+const MAX_EMPTY_REPEAT: u32 = 1000;
+
+const ERROR_LIST: [&str; 8] = [
+    "#NULL!",
+    "#DIV/0!",
+    "#VALUE!",
+    "#REF!",
+    "#NAME?",
+    "#NUM!",
+    "#N/A",
+    "#GETTING_DATA",
+];
+
+/// A cell read from a `table:table-cell` element.
+#[derive(Clone, Debug, Default)]
+struct CellData {
+    style_name: Option<String>,
+    value_type: Option<String>,
+    value: Option<String>,
+    text: Option<String>,
+    formula: Option<String>,
+    span: Option<(u32, u32)>,
+}
+impl CellData {
+    #[inline]
+    fn has_content(&self) -> bool {
+        self.value_type.is_some() || self.formula.is_some() || self.span.is_some()
+    }
+}
+
+/// Columns and rows of the table being read.
+#[derive(Default)]
+struct TableState {
+    sheet_index: usize,
+    row: u32,
+    col: u32,
+    row_repeat: u32,
+    row_cells: Vec<(u32, u32, CellData)>,
+    row_default_style: Option<String>,
+    // (first column, count, style name, is hidden, default cell style name)
+    columns: Vec<(u32, u32, Option<String>, bool, Option<String>)>,
+    // (first row, count, style name, is hidden)
+    rows: Vec<(u32, u32, Option<String>, bool)>,
+    max_column: u32,
+    max_row: u32,
+}
+impl TableState {
+    fn get_column_default_style(&self, col: u32) -> Option<&str> {
+        self.columns
+            .iter()
+            .find(|(start, count, ..)| *start <= col && col < start + count)
+            .and_then(|v| v.4.as_deref())
+            .filter(|v| *v != "Default")
+    }
+}
+
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+    collection: &mut StyleCollection,
+    book: &mut Spreadsheet,
+) -> Result<(), XlsxError> {
+    let r = io::BufReader::new(arv.by_name(target)?);
+    let mut reader = Reader::from_reader(r);
+
+    let mut table: Option<TableState> = None;
+    let mut buf = Vec::new();
+    loop {
+        let ev = match reader.read_event_into(&mut buf) {
+            Ok(v) => v,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+        };
+        match ev {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_empty = matches!(ev, Event::Empty(_));
+                if collection.read_element(&mut reader, e, is_empty) {
+                    buf.clear();
+                    continue;
+                }
+                match e.name().into_inner() {
+                    b"table:table" => {
+                        let name = get_attribute_unescaped(e, b"table:name").unwrap_or_default();
+                        let sheet_index = book.get_sheet_collection_no_check().len();
+                        let worksheet = book
+                            .new_sheet(name.as_str())
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                        if let Some(v) = get_attribute_unescaped(e, b"table:style-name") {
+                            if collection.get_property(&v, "table:display") == Some("false") {
+                                worksheet.set_state_str("hidden");
+                            }
+                        }
+                        if let Some(v) = get_attribute_unescaped(e, b"table:print-ranges") {
+                            let address: Vec<String> =
+                                v.split_whitespace().map(from_ods_range_address).collect();
+                            let mut defined_name = DefinedName::default();
+                            defined_name.set_name("_xlnm.Print_Area");
+                            defined_name.set_address(address.join(","));
+                            defined_name.set_local_sheet_id(sheet_index as u32);
+                            worksheet.add_defined_names(defined_name);
+                        }
+                        table = Some(TableState {
+                            sheet_index,
+                            row: 1,
+                            col: 1,
+                            ..Default::default()
+                        });
+                    }
+                    b"table:table-column" => {
+                        if let Some(state) = table.as_mut() {
+                            let count = get_repeat(e, b"table:number-columns-repeated");
+                            let start = state.columns.last().map(|v| v.0 + v.1).unwrap_or(1);
+                            state.columns.push((
+                                start,
+                                count,
+                                get_attribute_unescaped(e, b"table:style-name"),
+                                is_collapsed(e),
+                                get_attribute_unescaped(e, b"table:default-cell-style-name"),
+                            ));
+                        }
+                    }
+                    b"table:table-row" => {
+                        if let Some(state) = table.as_mut() {
+                            state.col = 1;
+                            state.row_repeat = get_repeat(e, b"table:number-rows-repeated");
+                            state.row_cells.clear();
+                            state.row_default_style =
+                                get_attribute_unescaped(e, b"table:default-cell-style-name");
+                            state.rows.push((
+                                state.row,
+                                state.row_repeat,
+                                get_attribute_unescaped(e, b"table:style-name"),
+                                is_collapsed(e),
+                            ));
+                            if is_empty {
+                                state.row += state.row_repeat;
+                            }
+                        }
+                    }
+                    b"table:table-cell" | b"table:covered-table-cell" => {
+                        if let Some(state) = table.as_mut() {
+                            let is_covered = e.name().into_inner() == b"table:covered-table-cell";
+                            let count = get_repeat(e, b"table:number-columns-repeated");
+                            let mut data = read_cell_attributes(e);
+                            if !is_empty {
+                                let text = read_cell_text(&mut reader, e.name().into_inner());
+                                if data.value_type.as_deref() == Some("string") {
+                                    data.text = data.value.take().or(Some(text));
+                                } else if data.value_type.is_some() {
+                                    data.text = Some(text);
+                                }
+                            }
+                            if is_covered {
+                                data.span = None;
+                            }
+                            if data.style_name.is_none() {
+                                data.style_name = state
+                                    .get_column_default_style(state.col)
+                                    .or(state.row_default_style.as_deref())
+                                    .filter(|v| *v != "Default")
+                                    .map(|v| v.to_string());
+                            }
+                            let is_needed = data.has_content()
+                                || (data.style_name.is_some() && count <= MAX_EMPTY_REPEAT);
+                            if is_needed {
+                                state.row_cells.push((state.col, count, data));
+                            }
+                            state.col += count;
+                        }
+                    }
+                    b"table:named-range" | b"table:named-expression" => {
+                        let name = get_attribute_unescaped(e, b"table:name").unwrap_or_default();
+                        let address = match e.name().into_inner() {
+                            b"table:named-range" => {
+                                get_attribute_unescaped(e, b"table:cell-range-address").map(|v| {
+                                    v.split_whitespace()
+                                        .map(from_ods_range_address)
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                })
+                            }
+                            _ => get_attribute_unescaped(e, b"table:expression")
+                                .map(|v| from_ods_formula(&v)),
+                        };
+                        if let Some(address) = address {
+                            add_defined_name(
+                                book,
+                                name,
+                                address,
+                                table.as_ref().map(|v| v.sheet_index),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::End(ref e) => match e.name().into_inner() {
+                b"table:table-row" => {
+                    if let Some(state) = table.as_mut() {
+                        apply_row(state, collection, book);
+                        state.row += state.row_repeat;
+                    }
+                }
+                b"table:table" => {
+                    if let Some(state) = table.take() {
+                        apply_dimensions(&state, collection, book);
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(())
+}
+
+#[inline]
+fn get_repeat(e: &BytesStart, key: &[u8]) -> u32 {
+    get_attribute_unescaped(e, key)
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(1)
+        .max(1)
+}
+
+#[inline]
+fn is_collapsed(e: &BytesStart) -> bool {
+    matches!(
+        get_attribute_unescaped(e, b"table:visibility").as_deref(),
+        Some("collapse") | Some("filter")
+    )
+}
+
+fn read_cell_attributes(e: &BytesStart) -> CellData {
+    let value_type = get_attribute_unescaped(e, b"office:value-type");
+    let value = match value_type.as_deref() {
+        Some("float") | Some("percentage") | Some("currency") => {
+            get_attribute_unescaped(e, b"office:value")
+        }
+        Some("date") => get_attribute_unescaped(e, b"office:date-value"),
+        Some("time") => get_attribute_unescaped(e, b"office:time-value"),
+        Some("boolean") => get_attribute_unescaped(e, b"office:boolean-value"),
+        Some("string") => get_attribute_unescaped(e, b"office:string-value"),
+        _ => None,
+    };
+    let cols = get_repeat(e, b"table:number-columns-spanned");
+    let rows = get_repeat(e, b"table:number-rows-spanned");
+    CellData {
+        style_name: get_attribute_unescaped(e, b"table:style-name"),
+        value_type,
+        value,
+        text: None,
+        formula: get_attribute_unescaped(e, b"table:formula"),
+        span: match cols > 1 || rows > 1 {
+            true => Some((cols, rows)),
+            false => None,
+        },
+    }
+}
+
+/// Read the paragraphs of a cell until its end tag. Annotations are skipped.
+fn read_cell_text<R: io::BufRead>(reader: &mut Reader<R>, tag: &[u8]) -> String {
+    let tag = tag.to_vec();
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut paragraph_depth = 0;
+    let mut annotation_depth = 0;
+    let mut buf = Vec::new();
+    loop {
+        let ev = match reader.read_event_into(&mut buf) {
+            Ok(v) => v,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+        };
+        match ev {
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"office:annotation" => annotation_depth += 1,
+                b"text:p" | b"text:h" if annotation_depth == 0 => paragraph_depth += 1,
+                _ => {}
+            },
+            Event::Empty(ref e) if annotation_depth == 0 => match e.name().into_inner() {
+                b"text:s" => {
+                    let count = get_repeat(e, b"text:c");
+                    current.push_str(&" ".repeat(count as usize));
+                }
+                b"text:tab" => current.push('\t'),
+                b"text:line-break" => current.push('\n'),
+                b"text:p" | b"text:h" => paragraphs.push(String::new()),
+                _ => {}
+            },
+            Event::Text(ref e) if annotation_depth == 0 && paragraph_depth > 0 => {
+                current.push_str(&get_text_unescaped(e));
+            }
+            Event::End(ref e) => match e.name().into_inner() {
+                b"office:annotation" => annotation_depth -= 1,
+                b"text:p" | b"text:h" if annotation_depth == 0 => {
+                    paragraph_depth -= 1;
+                    if paragraph_depth == 0 {
+                        paragraphs.push(std::mem::take(&mut current));
+                    }
+                }
+                v if v == tag.as_slice() => break,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    paragraphs.join("\n")
+}
+
+/// Set the cells of the current row.
+fn apply_row(state: &mut TableState, collection: &mut StyleCollection, book: &mut Spreadsheet) {
+    if state.row_cells.is_empty() {
+        return;
+    }
+    let has_content = state.row_cells.iter().any(|(_, _, v)| v.has_content());
+    let row_count = match has_content {
+        true => state.row_repeat,
+        false => state.row_repeat.min(MAX_EMPTY_REPEAT),
+    };
+    let worksheet = book.get_sheet_mut(&state.sheet_index).unwrap();
+    for (col, col_count, data) in &state.row_cells {
+        let style = data
+            .style_name
+            .as_deref()
+            .and_then(|v| collection.get_style(v));
+        for row in state.row..state.row + row_count {
+            for col in *col..*col + col_count {
+                let cell = worksheet.get_cell_mut((col, row));
+                if let Some(v) = &style {
+                    cell.set_style(v.clone());
+                }
+                set_cell_value(cell, data);
+                if data.has_content() {
+                    state.max_column = state.max_column.max(col);
+                    state.max_row = state.max_row.max(row);
+                }
+                if let Some((cols, rows)) = data.span {
+                    worksheet.add_merge_cells(format!(
+                        "{}{}:{}{}",
+                        string_from_column_index(&col),
+                        row,
+                        string_from_column_index(&(col + cols - 1)),
+                        row + rows - 1
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn set_cell_value(cell: &mut crate::structs::Cell, data: &CellData) {
+    match data.value_type.as_deref() {
+        Some("float") | Some("percentage") | Some("currency") => {
+            if let Some(v) = data.value.as_deref().and_then(|v| v.parse::<f64>().ok()) {
+                cell.set_value_number(v);
+            }
+        }
+        Some("date") => {
+            if let Some(v) = data.value.as_deref().and_then(parse_date_value) {
+                cell.set_value_number(v);
+                set_default_format(
+                    cell.get_style_mut(),
+                    match v.fract() == 0f64 {
+                        true => "yyyy-mm-dd",
+                        false => "yyyy-mm-dd hh:mm:ss",
+                    },
+                );
+            }
+        }
+        Some("time") => {
+            if let Some(v) = data.value.as_deref().and_then(parse_time_value) {
+                cell.set_value_number(v);
+                set_default_format(cell.get_style_mut(), "hh:mm:ss");
+            }
+        }
+        Some("boolean") => {
+            let value = data.value.as_deref().unwrap_or("false");
+            cell.set_value_bool(value == "true" || value == "1");
+        }
+        Some("string") => {
+            let text = data.text.clone().unwrap_or_default();
+            match data.formula.is_some() && ERROR_LIST.contains(&text.as_str()) {
+                true => cell.set_error(text),
+                false => cell.set_value_string(text),
+            };
+        }
+        _ => {}
+    }
+    if let Some(v) = &data.formula {
+        cell.set_formula(from_ods_formula(v));
+    }
+}
+
+#[inline]
+fn set_default_format(style: &mut Style, format_code: &str) {
+    let has_format = style
+        .get_number_format()
+        .map(|v| v.get_format_code() != "General")
+        .unwrap_or(false);
+    if !has_format {
+        style.get_number_format_mut().set_format_code(format_code);
+    }
+}
+
+/// Apply the column widths and row heights of the table.
+fn apply_dimensions(state: &TableState, collection: &StyleCollection, book: &mut Spreadsheet) {
+    let worksheet = book.get_sheet_mut(&state.sheet_index).unwrap();
+    for (start, count, style_name, is_hidden, _) in &state.columns {
+        let width = style_name
+            .as_deref()
+            .and_then(|v| collection.get_property(v, "style:column-width"))
+            .and_then(length_to_column_width);
+        let end = match *count > MAX_EMPTY_REPEAT {
+            true => (start + count - 1).min(state.max_column),
+            false => start + count - 1,
+        };
+        for col in *start..=end {
+            if width.is_none() && !is_hidden {
+                continue;
+            }
+            let column = worksheet.get_column_dimension_by_number_mut(&col);
+            if let Some(v) = width {
+                column.set_width(v);
+            }
+            if *is_hidden {
+                column.set_hidden(true);
+            }
+        }
+    }
+    for (start, count, style_name, is_hidden) in &state.rows {
+        let style_name = style_name.as_deref().unwrap_or("");
+        let is_custom =
+            collection.get_property(style_name, "style:use-optimal-row-height") == Some("false");
+        let height = match is_custom {
+            true => collection
+                .get_property(style_name, "style:row-height")
+                .and_then(length_to_point),
+            false => None,
+        };
+        if height.is_none() && !is_hidden {
+            continue;
+        }
+        let end = match *count > MAX_EMPTY_REPEAT {
+            true => (start + count - 1).min(state.max_row),
+            false => start + count - 1,
+        };
+        for row in *start..=end {
+            let row_dimension = worksheet.get_row_dimension_mut(&row);
+            if let Some(v) = height {
+                row_dimension.set_height((v * 100f64).round() / 100f64);
+                row_dimension.set_custom_height(true);
+            }
+            if *is_hidden {
+                row_dimension.set_hidden(true);
+            }
+        }
+    }
+}
+
+fn add_defined_name(
+    book: &mut Spreadsheet,
+    name: String,
+    address: String,
+    local_sheet_index: Option<usize>,
+) {
+    let mut defined_name = DefinedName::default();
+    defined_name.set_name(name);
+    defined_name.set_address(address);
+    match local_sheet_index {
+        Some(v) => {
+            defined_name.set_local_sheet_id(v as u32);
+            book.get_sheet_mut(&v)
+                .unwrap()
+                .add_defined_names(defined_name);
+        }
+        None => {
+            let sheet_name = defined_name.get_sheet_name_crate();
+            match book.get_sheet_by_name_mut(&sheet_name) {
+                Some(v) => v.add_defined_names(defined_name),
+                None => book.add_defined_names(defined_name),
+            }
+        }
+    }
+}
+
+/// Convert `office:date-value` into a serial date.
+fn parse_date_value(value: &str) -> Option<f64> {
+    let base = NaiveDate::from_ymd_opt(1899, 12, 30)?.and_hms_opt(0, 0, 0)?;
+    let date_time = match value.contains('T') {
+        true => NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()?,
+        false => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?,
+    };
+    Some((date_time - base).num_milliseconds() as f64 / 86_400_000f64)
+}
+
+/// Convert `office:time-value` (e.g. `PT12H30M00S`) into a day fraction.
+fn parse_time_value(value: &str) -> Option<f64> {
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(v) => (-1f64, v),
+        None => (1f64, value),
+    };
+    let value = value.strip_prefix('P')?;
+    let mut seconds = 0f64;
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            'T' => {}
+            'D' | 'H' | 'M' | 'S' => {
+                let v = number.parse::<f64>().ok()?;
+                number.clear();
+                seconds += v * match c {
+                    'D' => 86_400f64,
+                    'H' => 3_600f64,
+                    'M' => 60f64,
+                    _ => 1f64,
+                };
+            }
+            _ => number.push(c),
+        }
+    }
+    Some(sign * seconds / 86_400f64)
+}
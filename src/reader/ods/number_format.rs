@@ -0,0 +1,160 @@
+//! Conversion of ODS number styles into format codes.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::get_attribute_unescaped;
+use super::get_text_unescaped;
+
+/// Read a number style element and return (style name, format code).
+pub(crate) fn read<R: std::io::BufRead>(
+    reader: &mut Reader<R>,
+    e: &BytesStart,
+) -> (String, String) {
+    let name = get_attribute_unescaped(e, b"style:name").unwrap_or_default();
+    let tag = e.name().into_inner().to_vec();
+    let is_date = matches!(tag.as_slice(), b"number:date-style" | b"number:time-style");
+    let is_elapsed = tag.as_slice() == b"number:time-style"
+        && get_attribute_unescaped(e, b"number:truncate-on-overflow").as_deref() == Some("false");
+
+    let mut code = String::new();
+    let mut text: Option<String> = None;
+    let mut buf = Vec::new();
+    loop {
+        let ev = match reader.read_event_into(&mut buf) {
+            Ok(v) => v,
+            Err(e) => panic!("Error at position {}: {e:?}", reader.buffer_position()),
+        };
+        match ev {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_long =
+                    get_attribute_unescaped(e, b"number:style").as_deref() == Some("long");
+                let get_usize = |key: &[u8]| {
+                    get_attribute_unescaped(e, key)
+                        .and_then(|v| v.parse::<usize>().ok())
+                        .unwrap_or(0)
+                };
+                match e.name().into_inner() {
+                    b"number:number" => {
+                        code.push_str(&make_number(
+                            get_usize(b"number:min-integer-digits"),
+                            get_usize(b"number:decimal-places"),
+                            get_attribute_unescaped(e, b"number:grouping").as_deref()
+                                == Some("true"),
+                        ));
+                    }
+                    b"number:scientific-number" => {
+                        code.push_str(&make_number(
+                            get_usize(b"number:min-integer-digits").max(1),
+                            get_usize(b"number:decimal-places"),
+                            false,
+                        ));
+                        code.push_str("E+");
+                        code.push_str(&"0".repeat(get_usize(b"number:min-exponent-digits").max(1)));
+                    }
+                    b"number:fraction" => {
+                        let integer = get_usize(b"number:min-integer-digits");
+                        code.push_str(&match integer {
+                            0 => "#".to_string(),
+                            v => "0".repeat(v),
+                        });
+                        code.push(' ');
+                        code.push_str(
+                            &"?".repeat(get_usize(b"number:min-numerator-digits").max(1)),
+                        );
+                        code.push('/');
+                        match get_attribute_unescaped(e, b"number:denominator-value") {
+                            Some(v) => code.push_str(&v),
+                            None => code.push_str(
+                                &"?".repeat(get_usize(b"number:min-denominator-digits").max(1)),
+                            ),
+                        }
+                    }
+                    b"number:year" => code.push_str(if is_long { "yyyy" } else { "yy" }),
+                    b"number:month" => {
+                        let is_textual = get_attribute_unescaped(e, b"number:textual").as_deref()
+                            == Some("true");
+                        code.push_str(match (is_textual, is_long) {
+                            (true, true) => "mmmm",
+                            (true, false) => "mmm",
+                            (false, true) => "mm",
+                            (false, false) => "m",
+                        });
+                    }
+                    b"number:day" => code.push_str(if is_long { "dd" } else { "d" }),
+                    b"number:day-of-week" => code.push_str(if is_long { "dddd" } else { "ddd" }),
+                    b"number:hours" => {
+                        let hours = if is_long { "hh" } else { "h" };
+                        match is_elapsed {
+                            true => code.push_str(&format!("[{}]", hours)),
+                            false => code.push_str(hours),
+                        }
+                    }
+                    b"number:minutes" => code.push_str(if is_long { "mm" } else { "m" }),
+                    b"number:seconds" => {
+                        code.push_str(if is_long { "ss" } else { "s" });
+                        let decimal = get_usize(b"number:decimal-places");
+                        if decimal > 0 {
+                            code.push('.');
+                            code.push_str(&"0".repeat(decimal));
+                        }
+                    }
+                    b"number:am-pm" => code.push_str("AM/PM"),
+                    b"number:text-content" => code.push('@'),
+                    b"number:text" | b"number:currency-symbol" => {
+                        if let Event::Start(_) = ev {
+                            text = Some(String::new());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(ref e) => {
+                if let Some(v) = text.as_mut() {
+                    v.push_str(&get_text_unescaped(e));
+                }
+            }
+            Event::End(ref e) => match e.name().into_inner() {
+                b"number:text" | b"number:currency-symbol" => {
+                    if let Some(v) = text.take() {
+                        code.push_str(&make_literal(&v, is_date));
+                    }
+                }
+                v if v == tag.as_slice() => break,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    if code.is_empty() {
+        code.push_str("General");
+    }
+    (name, code)
+}
+
+fn make_number(min_integer: usize, decimal_places: usize, grouping: bool) -> String {
+    let mut result = match (grouping, min_integer) {
+        (true, v) if v <= 3 => format!("#,{}{}", "#".repeat(3 - v), "0".repeat(v)),
+        (true, v) => format!("{},{}", "0".repeat(v - 3), "000"),
+        (false, 0) => "#".to_string(),
+        (false, v) => "0".repeat(v),
+    };
+    if decimal_places > 0 {
+        result.push('.');
+        result.push_str(&"0".repeat(decimal_places));
+    }
+    result
+}
+
+fn make_literal(value: &str, is_date: bool) -> String {
+    let is_plain = value.chars().all(|c| {
+        matches!(c, ' ' | '-' | '/' | ':' | '(' | ')' | '$' | '+' | '%')
+            || (is_date && matches!(c, '.' | ','))
+    });
+    match is_plain {
+        true => value.to_string(),
+        false => format!("\"{}\"", value.replace('"', "")),
+    }
+}
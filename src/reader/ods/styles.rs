@@ -0,0 +1,400 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::io;
+
+use super::get_attribute_unescaped;
+use super::number_format;
+use super::XlsxError;
+use crate::helper::ods::length_to_point;
+use crate::structs::Border;
+use crate::structs::HorizontalAlignmentValues;
+use crate::structs::PatternValues;
+use crate::structs::Style;
+use crate::structs::VerticalAlignmentValues;
+
+/// A style element with the attributes of its property elements.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RawStyle {
+    pub(crate) parent: Option<String>,
+    pub(crate) data_style_name: Option<String>,
+    pub(crate) properties: HashMap<String, String>,
+}
+
+/// Styles of styles.xml and the automatic styles of content.xml.
+#[derive(Debug, Default)]
+pub(crate) struct StyleCollection {
+    styles: HashMap<String, RawStyle>,
+    default_cell: RawStyle,
+    number_formats: HashMap<String, String>,
+    cache: HashMap<String, Option<Style>>,
+}
+impl StyleCollection {
+    #[inline]
+    pub(crate) fn get_raw_style(&self, name: &str) -> Option<&RawStyle> {
+        self.styles.get(name)
+    }
+
+    /// Get a property of a style. Parent styles are not looked up.
+    #[inline]
+    pub(crate) fn get_property(&self, name: &str, key: &str) -> Option<&str> {
+        self.styles
+            .get(name)?
+            .properties
+            .get(key)
+            .map(|v| v.as_str())
+    }
+
+    /// Get the cell style of the name.
+    pub(crate) fn get_style(&mut self, name: &str) -> Option<Style> {
+        if let Some(v) = self.cache.get(name) {
+            return v.clone();
+        }
+        let style = self.make_style(name);
+        self.cache.insert(name.to_string(), style.clone());
+        style
+    }
+
+    fn make_style(&self, name: &str) -> Option<Style> {
+        // collect the style and its parents.
+        let mut chain: Vec<&RawStyle> = Vec::new();
+        let mut current = Some(name.to_string());
+        while let Some(v) = current {
+            let raw = match self.styles.get(&v) {
+                Some(raw) => raw,
+                None => break,
+            };
+            if chain.len() > 16 {
+                break;
+            }
+            chain.push(raw);
+            current = raw.parent.clone();
+        }
+        if chain.is_empty() {
+            return None;
+        }
+        let mut properties = self.default_cell.properties.clone();
+        let mut data_style_name = self.default_cell.data_style_name.clone();
+        for raw in chain.iter().rev() {
+            properties.extend(raw.properties.clone());
+            if raw.data_style_name.is_some() {
+                data_style_name = raw.data_style_name.clone();
+            }
+        }
+        let format_code = data_style_name
+            .and_then(|v| self.number_formats.get(&v))
+            .map(|v| v.as_str());
+        Some(make_style(&properties, format_code))
+    }
+
+    /// Read a style element. Returns false if the element is not a style.
+    pub(crate) fn read_element<R: io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        is_empty: bool,
+    ) -> bool {
+        match e.name().into_inner() {
+            b"style:style" => {
+                let name = get_attribute_unescaped(e, b"style:name").unwrap_or_default();
+                let raw = RawStyle {
+                    parent: get_attribute_unescaped(e, b"style:parent-style-name"),
+                    data_style_name: get_attribute_unescaped(e, b"style:data-style-name"),
+                    properties: match is_empty {
+                        true => HashMap::new(),
+                        false => read_properties(reader, b"style:style"),
+                    },
+                };
+                self.cache.clear();
+                self.styles.insert(name, raw);
+                true
+            }
+            b"style:default-style" => {
+                let is_cell =
+                    get_attribute_unescaped(e, b"style:family").as_deref() == Some("table-cell");
+                let properties = match is_empty {
+                    true => HashMap::new(),
+                    false => read_properties(reader, b"style:default-style"),
+                };
+                if is_cell {
+                    self.default_cell.properties = properties;
+                    self.cache.clear();
+                }
+                true
+            }
+            b"number:number-style"
+            | b"number:percentage-style"
+            | b"number:currency-style"
+            | b"number:date-style"
+            | b"number:time-style"
+            | b"number:text-style"
+            | b"number:boolean-style" => {
+                let (name, code) = match is_empty {
+                    true => (
+                        get_attribute_unescaped(e, b"style:name").unwrap_or_default(),
+                        "General".to_string(),
+                    ),
+                    false => number_format::read(reader, e),
+                };
+                self.number_formats.insert(name, code);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Read the styles of styles.xml.
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+    collection: &mut StyleCollection,
+) -> Result<(), XlsxError> {
+    let r = io::BufReader::new(match arv.by_name(target) {
+        Ok(v) => v,
+        Err(zip::result::ZipError::FileNotFound) => {
+            return Ok(());
+        }
+        Err(e) => {
+            return Err(e.into());
+        }
+    });
+    let mut reader = Reader::from_reader(r);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                collection.read_element(&mut reader, e, false);
+            }
+            Ok(Event::Empty(ref e)) => {
+                collection.read_element(&mut reader, e, true);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(())
+}
+
+/// Collect the attributes of the property elements until the end tag.
+fn read_properties<R: io::BufRead>(reader: &mut Reader<R>, tag: &[u8]) -> HashMap<String, String> {
+    let mut result: HashMap<String, String> = HashMap::new();
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                if e.name().into_inner().ends_with(b"-properties") {
+                    for attr in e.attributes().with_checks(false).flatten() {
+                        let key = String::from_utf8_lossy(attr.key.into_inner()).to_string();
+                        let value = attr
+                            .unescape_value()
+                            .map(|v| v.to_string())
+                            .unwrap_or_default();
+                        result.insert(key, value);
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                if e.name().into_inner() == tag {
+                    break;
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            _ => (),
+        }
+        buf.clear();
+    }
+    result
+}
+
+fn make_style(properties: &HashMap<String, String>, format_code: Option<&str>) -> Style {
+    let mut style = Style::default();
+    let get = |key: &str| properties.get(key).map(|v| v.as_str());
+
+    if let Some(v) = format_code.filter(|v| *v != "General") {
+        style.get_number_format_mut().set_format_code(v);
+    }
+
+    if let Some(v) = get("fo:background-color").and_then(make_argb) {
+        let pattern_fill = style.get_fill_mut().get_pattern_fill_mut();
+        pattern_fill.set_pattern_type(PatternValues::Solid);
+        pattern_fill.get_foreground_color_mut().set_argb(v);
+    }
+
+    let border_list = [
+        ("fo:border-left", 0),
+        ("fo:border-right", 1),
+        ("fo:border-top", 2),
+        ("fo:border-bottom", 3),
+    ];
+    for (key, index) in border_list {
+        let value = match get(key).or_else(|| get("fo:border")) {
+            Some(v) => v,
+            None => continue,
+        };
+        let borders = style.get_borders_mut();
+        let border = match index {
+            0 => borders.get_left_mut(),
+            1 => borders.get_right_mut(),
+            2 => borders.get_top_mut(),
+            _ => borders.get_bottom_mut(),
+        };
+        set_border(border, value);
+    }
+
+    let horizontal = match get("fo:text-align") {
+        Some("start") | Some("left") => Some(HorizontalAlignmentValues::Left),
+        Some("center") => Some(HorizontalAlignmentValues::Center),
+        Some("end") | Some("right") => Some(HorizontalAlignmentValues::Right),
+        Some("justify") => Some(HorizontalAlignmentValues::Justify),
+        _ => None,
+    };
+    let vertical = match get("style:vertical-align") {
+        Some("top") => Some(VerticalAlignmentValues::Top),
+        Some("middle") => Some(VerticalAlignmentValues::Center),
+        Some("bottom") => Some(VerticalAlignmentValues::Bottom),
+        _ => None,
+    };
+    let is_wrap = get("fo:wrap-option") == Some("wrap");
+    let rotation = get("style:rotation-angle")
+        .and_then(|v| v.trim_end_matches("deg").parse::<f64>().ok())
+        .map(|v| v.round() as u32 % 360)
+        .and_then(|v| match v {
+            1..=90 => Some(v),
+            270..=359 => Some(90 + (360 - v)),
+            _ => None,
+        });
+    if horizontal.is_some() || vertical.is_some() || is_wrap || rotation.is_some() {
+        let alignment = style.get_alignment_mut();
+        if let Some(v) = horizontal {
+            alignment.set_horizontal(v);
+        }
+        if let Some(v) = vertical {
+            alignment.set_vertical(v);
+        }
+        if is_wrap {
+            alignment.set_wrap_text(true);
+        }
+        if let Some(v) = rotation {
+            alignment.set_text_rotation(v);
+        }
+    }
+
+    match get("style:cell-protect") {
+        Some("none") => {
+            style.get_protection_mut().set_locked(false);
+        }
+        Some("formula-hidden") | Some("hidden-and-protected") => {
+            style.get_protection_mut().set_hidden(true);
+        }
+        _ => {}
+    }
+
+    let has_font = [
+        "fo:font-family",
+        "style:font-name",
+        "fo:font-size",
+        "fo:font-weight",
+        "fo:font-style",
+        "fo:color",
+        "style:text-underline-style",
+        "style:text-line-through-style",
+    ]
+    .iter()
+    .any(|v| properties.contains_key(*v));
+    if has_font {
+        let font = style.get_font_mut();
+        if let Some(v) = get("fo:font-family").or_else(|| get("style:font-name")) {
+            font.set_name(v.trim_matches('\''));
+        }
+        if let Some(v) = get("fo:font-size").and_then(length_to_point) {
+            font.set_size(v);
+        }
+        if let Some(v) = get("fo:font-weight") {
+            font.set_bold(v == "bold" || v.parse::<u32>().map(|v| v >= 600).unwrap_or(false));
+        }
+        if let Some(v) = get("fo:font-style") {
+            font.set_italic(v == "italic" || v == "oblique");
+        }
+        if let Some(v) = get("fo:color").and_then(make_argb) {
+            font.get_color_mut().set_argb(v);
+        }
+        match get("style:text-underline-style") {
+            Some("none") | None => {}
+            Some(_) => {
+                match get("style:text-underline-type") {
+                    Some("double") => font.set_underline("double"),
+                    _ => font.set_underline("single"),
+                };
+            }
+        }
+        match get("style:text-line-through-style") {
+            Some("none") | None => {}
+            Some(_) => {
+                font.set_strikethrough(true);
+            }
+        }
+    }
+
+    style
+}
+
+fn set_border(border: &mut Border, value: &str) {
+    let mut width = 0f64;
+    let mut line = "solid";
+    let mut color: Option<String> = None;
+    for part in value.split_whitespace() {
+        if part.starts_with('#') {
+            color = make_argb(part);
+        } else if let Some(v) = length_to_point(part) {
+            width = v;
+        } else {
+            line = match part {
+                "thin" => {
+                    width = 0.74;
+                    line
+                }
+                "medium" => {
+                    width = 1.76;
+                    line
+                }
+                "thick" => {
+                    width = 2.49;
+                    line
+                }
+                v => v,
+            };
+        }
+    }
+    let border_style = match line {
+        "none" | "hidden" => return,
+        "double" => Border::BORDER_DOUBLE,
+        "dashed" if width > 1.2 => Border::BORDER_MEDIUMDASHED,
+        "dashed" => Border::BORDER_DASHED,
+        "dotted" => Border::BORDER_DOTTED,
+        "dash-dot" if width > 1.2 => Border::BORDER_MEDIUMDASHDOT,
+        "dash-dot" => Border::BORDER_DASHDOT,
+        "dash-dot-dot" if width > 1.2 => Border::BORDER_MEDIUMDASHDOTDOT,
+        "dash-dot-dot" => Border::BORDER_DASHDOTDOT,
+        _ if width < 0.5 => Border::BORDER_HAIR,
+        _ if width <= 1.2 => Border::BORDER_THIN,
+        _ if width <= 2.2 => Border::BORDER_MEDIUM,
+        _ => Border::BORDER_THICK,
+    };
+    border.set_border_style(border_style);
+    if let Some(v) = color {
+        border.get_color_mut().set_argb(v);
+    }
+}
+
+/// Convert `#RRGGBB` into ARGB.
+fn make_argb(value: &str) -> Option<String> {
+    let value = value.strip_prefix('#')?;
+    match value.len() == 6 && value.chars().all(|c| c.is_ascii_hexdigit()) {
+        true => Some(format!("FF{}", value.to_uppercase())),
+        false => None,
+    }
+}
@@ -0,0 +1,120 @@
+//! Reader for the Excel 97-2003 binary format (BIFF8).
+
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+use crate::structs::DefinedName;
+use crate::structs::Spreadsheet;
+use crate::XlsxError;
+
+pub(crate) mod biff;
+pub(crate) mod formula;
+pub(crate) mod style;
+mod workbook;
+mod worksheet;
+
+/// read xls spreadsheet from arbitrary reader.
+/// Cell values, formulas, shared strings, cell styles (fonts, number formats, borders, fills, alignment),
+/// merged cells, column widths, row heights and defined names are loaded.
+/// Charts, images and other drawing objects are ignored.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader<R: io::Read + io::Seek>(reader: R) -> Result<Spreadsheet, XlsxError> {
+    let mut comp = cfb::CompoundFile::open(reader)?;
+    let stream_name = match comp.exists("/Workbook") {
+        true => "/Workbook",
+        false if comp.exists("/Book") => {
+            return Err(biff::make_error(
+                "only BIFF8 (Excel 97-2003) files are supported",
+            ));
+        }
+        false => {
+            return Err(
+                io::Error::new(io::ErrorKind::NotFound, "Workbook stream not found").into(),
+            );
+        }
+    };
+    let mut stream = Vec::new();
+    comp.open_stream(stream_name)?.read_to_end(&mut stream)?;
+
+    let globals = workbook::read(&stream)?;
+    let styles = style::build_styles(&globals);
+
+    let mut book = crate::new_file_empty_worksheet();
+    // index of the worksheet in the book for each BOUNDSHEET record.
+    let mut sheet_indexes: Vec<Option<usize>> = Vec::with_capacity(globals.sheets.len());
+    for sheet in &globals.sheets {
+        if !sheet.is_worksheet {
+            sheet_indexes.push(None);
+            continue;
+        }
+        sheet_indexes.push(Some(book.get_sheet_collection_no_check().len()));
+        let worksheet = book
+            .new_sheet(sheet.name.as_str())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        match sheet.state {
+            1 => {
+                worksheet.set_state_str("hidden");
+            }
+            2 => {
+                worksheet.set_state_str("veryHidden");
+            }
+            _ => {}
+        }
+        worksheet::read(&stream, sheet.position, &globals, &styles, worksheet)?;
+    }
+
+    for name in &globals.names {
+        let address = match formula::decode(&name.rgce, &name.rgcb, (0, 0), &globals.context) {
+            Some(v) => v,
+            None => continue,
+        };
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(name.name.as_str());
+        defined_name.set_address(address);
+        if name.is_hidden {
+            defined_name.set_hidden(true);
+        }
+        let local_sheet_index = match name.sheet_index {
+            0 => None,
+            v => sheet_indexes.get(v as usize - 1).copied().flatten(),
+        };
+        match local_sheet_index {
+            Some(v) => {
+                defined_name.set_local_sheet_id(v as u32);
+                book.get_sheet_mut(&v)
+                    .unwrap()
+                    .add_defined_names(defined_name);
+            }
+            None => {
+                let sheet_name = defined_name.get_sheet_name_crate();
+                match book.get_sheet_by_name_mut(&sheet_name) {
+                    Some(v) => v.add_defined_names(defined_name),
+                    None => book.add_defined_names(defined_name),
+                }
+            }
+        }
+    }
+
+    Ok(book)
+}
+
+/// read xls spreadsheet file.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xls");
+/// let mut book = umya_spreadsheet::reader::xls::read(path).unwrap();
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(io::BufReader::new(file))
+}
@@ -0,0 +1,252 @@
+use super::XlsxError;
+
+// record types.
+pub(crate) const RECORD_FORMULA: u16 = 0x0006;
+pub(crate) const RECORD_EOF: u16 = 0x000A;
+pub(crate) const RECORD_EXTERNSHEET: u16 = 0x0017;
+pub(crate) const RECORD_NAME: u16 = 0x0018;
+pub(crate) const RECORD_EXTERNNAME: u16 = 0x0023;
+pub(crate) const RECORD_FILEPASS: u16 = 0x002F;
+pub(crate) const RECORD_FONT: u16 = 0x0031;
+pub(crate) const RECORD_CONTINUE: u16 = 0x003C;
+pub(crate) const RECORD_COLINFO: u16 = 0x007D;
+pub(crate) const RECORD_BOUNDSHEET: u16 = 0x0085;
+pub(crate) const RECORD_PALETTE: u16 = 0x0092;
+pub(crate) const RECORD_MULRK: u16 = 0x00BD;
+pub(crate) const RECORD_MULBLANK: u16 = 0x00BE;
+pub(crate) const RECORD_RSTRING: u16 = 0x00D6;
+pub(crate) const RECORD_XF: u16 = 0x00E0;
+pub(crate) const RECORD_MERGECELLS: u16 = 0x00E5;
+pub(crate) const RECORD_SST: u16 = 0x00FC;
+pub(crate) const RECORD_LABELSST: u16 = 0x00FD;
+pub(crate) const RECORD_SUPBOOK: u16 = 0x01AE;
+pub(crate) const RECORD_BLANK: u16 = 0x0201;
+pub(crate) const RECORD_NUMBER: u16 = 0x0203;
+pub(crate) const RECORD_LABEL: u16 = 0x0204;
+pub(crate) const RECORD_BOOLERR: u16 = 0x0205;
+pub(crate) const RECORD_STRING: u16 = 0x0207;
+pub(crate) const RECORD_ROW: u16 = 0x0208;
+pub(crate) const RECORD_ARRAY: u16 = 0x0221;
+pub(crate) const RECORD_RK: u16 = 0x027E;
+pub(crate) const RECORD_FORMAT: u16 = 0x041E;
+pub(crate) const RECORD_SHRFMLA: u16 = 0x04BC;
+pub(crate) const RECORD_BOF: u16 = 0x0809;
+
+pub(crate) const BIFF8_VERSION: u16 = 0x0600;
+
+/// A BIFF record with its CONTINUE records merged.
+pub(crate) struct Record {
+    pub(crate) record_type: u16,
+    pub(crate) data: Vec<u8>,
+    /// offsets in `data` where a CONTINUE record starts.
+    pub(crate) continues: Vec<usize>,
+}
+
+/// Iterate over the records of a substream starting at `offset`.
+pub(crate) struct RecordReader<'a> {
+    stream: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    #[inline]
+    pub(crate) fn new(stream: &'a [u8], offset: usize) -> Self {
+        Self {
+            stream,
+            pos: offset,
+        }
+    }
+
+    fn read_header(&self, pos: usize) -> Option<(u16, usize)> {
+        let header = self.stream.get(pos..pos + 4)?;
+        let record_type = u16::from_le_bytes([header[0], header[1]]);
+        let size = u16::from_le_bytes([header[2], header[3]]) as usize;
+        Some((record_type, size))
+    }
+}
+
+impl Iterator for RecordReader<'_> {
+    type Item = Result<Record, XlsxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (record_type, size) = self.read_header(self.pos)?;
+        let start = self.pos + 4;
+        let data = match self.stream.get(start..start + size) {
+            Some(v) => v.to_vec(),
+            None => return Some(Err(make_error("unexpected end of record"))),
+        };
+        self.pos = start + size;
+
+        let mut record = Record {
+            record_type,
+            data,
+            continues: Vec::new(),
+        };
+        while let Some((RECORD_CONTINUE, size)) = self.read_header(self.pos) {
+            let start = self.pos + 4;
+            let data = match self.stream.get(start..start + size) {
+                Some(v) => v,
+                None => return Some(Err(make_error("unexpected end of record"))),
+            };
+            record.continues.push(record.data.len());
+            record.data.extend_from_slice(data);
+            self.pos = start + size;
+        }
+        Some(Ok(record))
+    }
+}
+
+/// Cursor over the data of a record.
+pub(crate) struct ByteReader<'a> {
+    data: &'a [u8],
+    continues: &'a [usize],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    #[inline]
+    pub(crate) fn new(record: &'a Record) -> Self {
+        Self {
+            data: &record.data,
+            continues: &record.continues,
+            pos: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_slice(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            continues: &[],
+            pos: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    pub(crate) fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], XlsxError> {
+        let data = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| make_error("unexpected end of record"))?;
+        self.pos += len;
+        Ok(data)
+    }
+
+    #[inline]
+    pub(crate) fn skip(&mut self, len: usize) -> Result<(), XlsxError> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    #[inline]
+    pub(crate) fn read_u8(&mut self) -> Result<u8, XlsxError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    #[inline]
+    pub(crate) fn read_u16(&mut self) -> Result<u16, XlsxError> {
+        let v = self.read_bytes(2)?;
+        Ok(u16::from_le_bytes([v[0], v[1]]))
+    }
+
+    #[inline]
+    pub(crate) fn read_u32(&mut self) -> Result<u32, XlsxError> {
+        let v = self.read_bytes(4)?;
+        Ok(u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+    }
+
+    #[inline]
+    pub(crate) fn read_f64(&mut self) -> Result<f64, XlsxError> {
+        let v = self.read_bytes(8)?;
+        Ok(f64::from_le_bytes(v.try_into().unwrap()))
+    }
+
+    /// XLUnicodeString (16-bit character count).
+    #[inline]
+    pub(crate) fn read_unicode_string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.read_u16()? as usize;
+        let flags = self.read_u8()?;
+        self.read_chars(cch, flags & 0x01 != 0)
+    }
+
+    /// ShortXLUnicodeString (8-bit character count).
+    #[inline]
+    pub(crate) fn read_short_unicode_string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.read_u8()? as usize;
+        let flags = self.read_u8()?;
+        self.read_chars(cch, flags & 0x01 != 0)
+    }
+
+    /// XLUnicodeRichExtendedString. Formatting runs and phonetic data are skipped.
+    pub(crate) fn read_rich_extended_string(&mut self) -> Result<String, XlsxError> {
+        let cch = self.read_u16()? as usize;
+        let flags = self.read_u8()?;
+        let run_count = match flags & 0x08 != 0 {
+            true => self.read_u16()? as usize,
+            false => 0,
+        };
+        let ext_size = match flags & 0x04 != 0 {
+            true => self.read_u32()? as usize,
+            false => 0,
+        };
+        let value = self.read_chars(cch, flags & 0x01 != 0)?;
+        self.skip(run_count * 4 + ext_size)?;
+        Ok(value)
+    }
+
+    /// Read characters. A CONTINUE record inside the characters starts with a new option byte.
+    pub(crate) fn read_chars(
+        &mut self,
+        mut cch: usize,
+        mut is_high_byte: bool,
+    ) -> Result<String, XlsxError> {
+        let mut units: Vec<u16> = Vec::with_capacity(cch);
+        while cch > 0 {
+            if self.continues.contains(&self.pos) {
+                is_high_byte = self.read_u8()? & 0x01 != 0;
+            }
+            let boundary = self
+                .continues
+                .iter()
+                .find(|v| **v > self.pos)
+                .copied()
+                .unwrap_or(self.data.len());
+            let char_size = if is_high_byte { 2 } else { 1 };
+            let count = cch.min((boundary - self.pos) / char_size);
+            if count == 0 {
+                return Err(make_error("unexpected end of string"));
+            }
+            let bytes = self.read_bytes(count * char_size)?;
+            match is_high_byte {
+                true => units.extend(
+                    bytes
+                        .chunks_exact(2)
+                        .map(|v| u16::from_le_bytes([v[0], v[1]])),
+                ),
+                false => units.extend(bytes.iter().map(|v| *v as u16)),
+            }
+            cch -= count;
+        }
+        Ok(String::from_utf16_lossy(&units))
+    }
+}
+
+#[inline]
+pub(crate) fn make_error(message: &str) -> XlsxError {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message).into()
+}
+
+/// Decode an RK number.
+pub(crate) fn decode_rk(value: u32) -> f64 {
+    let number = match value & 0x02 != 0 {
+        true => ((value as i32) >> 2) as f64,
+        false => f64::from_bits(((value & 0xFFFF_FFFC) as u64) << 32),
+    };
+    match value & 0x01 != 0 {
+        true => number / 100f64,
+        false => number,
+    }
+}
@@ -0,0 +1,680 @@
+use super::biff::ByteReader;
+use crate::helper::coordinate::string_from_column_index;
+
+/// Workbook level data needed to turn parsed tokens back into formula text.
+#[derive(Default, Debug)]
+pub(crate) struct FormulaContext {
+    /// sheet reference text and supporting link index of each EXTERNSHEET entry.
+    pub(crate) extern_sheets: Vec<(Option<String>, usize)>,
+    /// whether each supporting link is the workbook itself.
+    pub(crate) self_supbooks: Vec<bool>,
+    /// names of the NAME records.
+    pub(crate) names: Vec<String>,
+    /// names of the EXTERNNAME records for each supporting link.
+    pub(crate) extern_names: Vec<Vec<String>>,
+}
+
+/// Decode a parsed formula (rgce) into formula text without the leading "=".
+/// # Arguments
+/// * `rgce` - formula tokens.
+/// * `rgcb` - extra data of the array constants.
+/// * `base` - cell the formula belongs to (0-based row and column), used by relative tokens of shared formulas.
+/// * `context` - workbook level data.
+/// # Return value
+/// * `Option<String>` - None if the formula holds a token that is not supported.
+pub(crate) fn decode(
+    rgce: &[u8],
+    rgcb: &[u8],
+    base: (u32, u32),
+    context: &FormulaContext,
+) -> Option<String> {
+    let mut reader = ByteReader::from_slice(rgce);
+    let mut extra = ByteReader::from_slice(rgcb);
+    let mut stack: Vec<String> = Vec::new();
+
+    while reader.remaining() > 0 {
+        let ptg = reader.read_u8().ok()?;
+        let token = match ptg < 0x20 {
+            true => ptg,
+            false => (ptg & 0x1F) | 0x20,
+        };
+        match token {
+            0x03..=0x11 => {
+                let right = stack.pop()?;
+                let left = stack.pop()?;
+                let operator = match token {
+                    0x03 => "+",
+                    0x04 => "-",
+                    0x05 => "*",
+                    0x06 => "/",
+                    0x07 => "^",
+                    0x08 => "&",
+                    0x09 => "<",
+                    0x0A => "<=",
+                    0x0B => "=",
+                    0x0C => ">=",
+                    0x0D => ">",
+                    0x0E => "<>",
+                    0x0F => " ",
+                    0x10 => ",",
+                    _ => ":",
+                };
+                stack.push(format!("{}{}{}", left, operator, right));
+            }
+            0x12 => {
+                let value = stack.pop()?;
+                stack.push(format!("+{}", value));
+            }
+            0x13 => {
+                let value = stack.pop()?;
+                stack.push(format!("-{}", value));
+            }
+            0x14 => {
+                let value = stack.pop()?;
+                stack.push(format!("{}%", value));
+            }
+            0x15 => {
+                let value = stack.pop()?;
+                stack.push(format!("({})", value));
+            }
+            0x16 => stack.push(String::new()),
+            0x17 => {
+                let value = reader.read_short_unicode_string().ok()?;
+                stack.push(format!("\"{}\"", value.replace('"', "\"\"")));
+            }
+            0x19 => {
+                let flags = reader.read_u8().ok()?;
+                let data = reader.read_u16().ok()?;
+                if flags & 0x04 != 0 {
+                    // jump table of CHOOSE.
+                    reader.skip((data as usize + 1) * 2).ok()?;
+                }
+                if flags & 0x10 != 0 {
+                    let value = stack.pop()?;
+                    stack.push(format!("SUM({})", value));
+                }
+            }
+            0x1C => {
+                let value = reader.read_u8().ok()?;
+                stack.push(error_text(value).to_string());
+            }
+            0x1D => {
+                let value = reader.read_u8().ok()?;
+                stack.push(bool_text(value != 0).to_string());
+            }
+            0x1E => {
+                let value = reader.read_u16().ok()?;
+                stack.push(value.to_string());
+            }
+            0x1F => {
+                let value = reader.read_f64().ok()?;
+                stack.push(value.to_string());
+            }
+            0x20 => {
+                reader.skip(7).ok()?;
+                stack.push(read_array(&mut extra)?);
+            }
+            0x21 => {
+                let index = reader.read_u16().ok()?;
+                let (name, argc) = get_function(index)?;
+                let argc = argc?;
+                push_function(&mut stack, name, argc as usize)?;
+            }
+            0x22 => {
+                let argc = (reader.read_u8().ok()? & 0x7F) as usize;
+                let index = reader.read_u16().ok()? & 0x7FFF;
+                if index == 255 {
+                    // add-in or future function, the name is the first argument.
+                    if stack.len() < argc || argc == 0 {
+                        return None;
+                    }
+                    // the "_xlfn." prefix is kept like in xlsx files.
+                    let args = stack.split_off(stack.len() - argc);
+                    stack.push(format!("{}({})", args[0], args[1..].join(",")));
+                } else {
+                    let (name, _) = get_function(index)?;
+                    push_function(&mut stack, name, argc)?;
+                }
+            }
+            0x23 => {
+                let index = reader.read_u32().ok()? as usize;
+                stack.push(context.names.get(index.checked_sub(1)?)?.clone());
+            }
+            0x24 => {
+                let row = reader.read_u16().ok()?;
+                let col = reader.read_u16().ok()?;
+                stack.push(cell_text(row as u32, col));
+            }
+            0x25 => {
+                let row_first = reader.read_u16().ok()?;
+                let row_last = reader.read_u16().ok()?;
+                let col_first = reader.read_u16().ok()?;
+                let col_last = reader.read_u16().ok()?;
+                stack.push(area_text(
+                    row_first as u32,
+                    row_last as u32,
+                    col_first,
+                    col_last,
+                ));
+            }
+            0x26 => {
+                reader.skip(6).ok()?;
+                // the cached area list of the sub expression.
+                let count = extra.read_u16().ok()? as usize;
+                extra.skip(count * 8).ok()?;
+            }
+            0x27 | 0x28 => reader.skip(6).ok()?,
+            0x29 => reader.skip(2).ok()?,
+            0x2A => {
+                reader.skip(4).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            0x2B => {
+                reader.skip(8).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            0x2C => {
+                let row = reader.read_u16().ok()?;
+                let col = reader.read_u16().ok()?;
+                let (row, col) = relative_cell(row, col, base);
+                stack.push(cell_text(row, col));
+            }
+            0x2D => {
+                let row_first = reader.read_u16().ok()?;
+                let row_last = reader.read_u16().ok()?;
+                let col_first = reader.read_u16().ok()?;
+                let col_last = reader.read_u16().ok()?;
+                let (row_first, col_first) = relative_cell(row_first, col_first, base);
+                let (row_last, col_last) = relative_cell(row_last, col_last, base);
+                stack.push(area_text(row_first, row_last, col_first, col_last));
+            }
+            0x39 => {
+                let ixti = reader.read_u16().ok()? as usize;
+                let index = reader.read_u16().ok()? as usize;
+                reader.skip(2).ok()?;
+                let (_, supbook) = context.extern_sheets.get(ixti)?;
+                let name = match context.self_supbooks.get(*supbook) {
+                    Some(true) => context.names.get(index.checked_sub(1)?)?,
+                    _ => context
+                        .extern_names
+                        .get(*supbook)?
+                        .get(index.checked_sub(1)?)?,
+                };
+                stack.push(name.clone());
+            }
+            0x3A => {
+                let sheet = extern_sheet_text(reader.read_u16().ok()?, context);
+                let row = reader.read_u16().ok()?;
+                let col = reader.read_u16().ok()?;
+                stack.push(match sheet {
+                    Some(v) => format!("{}!{}", v, cell_text(row as u32, col)),
+                    None => "#REF!".to_string(),
+                });
+            }
+            0x3B => {
+                let sheet = extern_sheet_text(reader.read_u16().ok()?, context);
+                let row_first = reader.read_u16().ok()?;
+                let row_last = reader.read_u16().ok()?;
+                let col_first = reader.read_u16().ok()?;
+                let col_last = reader.read_u16().ok()?;
+                stack.push(match sheet {
+                    Some(v) => format!(
+                        "{}!{}",
+                        v,
+                        area_text(row_first as u32, row_last as u32, col_first, col_last)
+                    ),
+                    None => "#REF!".to_string(),
+                });
+            }
+            0x3C => {
+                reader.skip(6).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            0x3D => {
+                reader.skip(10).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            _ => return None,
+        }
+    }
+
+    match stack.len() {
+        1 => stack.pop(),
+        _ => None,
+    }
+}
+
+/// Text of an error code.
+pub(crate) fn error_text(value: u8) -> &'static str {
+    match value {
+        0x00 => "#NULL!",
+        0x07 => "#DIV/0!",
+        0x0F => "#VALUE!",
+        0x17 => "#REF!",
+        0x1D => "#NAME?",
+        0x24 => "#NUM!",
+        0x2A => "#N/A",
+        _ => "#N/A",
+    }
+}
+
+#[inline]
+pub(crate) fn bool_text(value: bool) -> &'static str {
+    match value {
+        true => "TRUE",
+        false => "FALSE",
+    }
+}
+
+/// Quote a sheet name when it is not a plain identifier.
+pub(crate) fn quote_sheet_name(value: &str) -> String {
+    let is_plain = value
+        .chars()
+        .next()
+        .map(|c| c.is_alphabetic() || c == '_')
+        .unwrap_or(false)
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.');
+    match is_plain {
+        true => value.to_string(),
+        false => format!("'{}'", value.replace('\'', "''")),
+    }
+}
+
+pub(crate) fn push_function(stack: &mut Vec<String>, name: &str, argc: usize) -> Option<()> {
+    if stack.len() < argc {
+        return None;
+    }
+    let args = stack.split_off(stack.len() - argc);
+    stack.push(format!("{}({})", name, args.join(",")));
+    Some(())
+}
+
+fn extern_sheet_text(ixti: u16, context: &FormulaContext) -> Option<String> {
+    context
+        .extern_sheets
+        .get(ixti as usize)
+        .and_then(|(v, _)| v.clone())
+}
+
+fn relative_cell(row: u16, col: u16, base: (u32, u32)) -> (u32, u16) {
+    let row = match col & 0x8000 != 0 {
+        true => (base.0 as i64 + row as i16 as i64).rem_euclid(65536) as u32,
+        false => row as u32,
+    };
+    let col_value = match col & 0x4000 != 0 {
+        true => (base.1 as i64 + (col & 0xFF) as u8 as i8 as i64).rem_euclid(256) as u16,
+        false => col & 0xFF,
+    };
+    (row, (col & 0xC000) | col_value)
+}
+
+/// A1 text of a cell. The column field holds the relative flags in its high bits.
+fn cell_text(row: u32, col: u16) -> String {
+    let col_abs = match col & 0x4000 != 0 {
+        true => "",
+        false => "$",
+    };
+    let row_abs = match col & 0x8000 != 0 {
+        true => "",
+        false => "$",
+    };
+    format!(
+        "{}{}{}{}",
+        col_abs,
+        string_from_column_index(&((col & 0xFF) as u32 + 1)),
+        row_abs,
+        row + 1
+    )
+}
+
+fn area_text(row_first: u32, row_last: u32, col_first: u16, col_last: u16) -> String {
+    let is_whole_col = row_first == 0 && row_last == 0xFFFF;
+    let is_whole_row = (col_first & 0xFF) == 0 && (col_last & 0xFF) == 0xFF;
+    if is_whole_col {
+        let col_text = |col: u16| {
+            let abs = if col & 0x4000 != 0 { "" } else { "$" };
+            format!(
+                "{}{}",
+                abs,
+                string_from_column_index(&((col & 0xFF) as u32 + 1))
+            )
+        };
+        return format!("{}:{}", col_text(col_first), col_text(col_last));
+    }
+    if is_whole_row {
+        let row_text = |row: u32, col: u16| {
+            let abs = if col & 0x8000 != 0 { "" } else { "$" };
+            format!("{}{}", abs, row + 1)
+        };
+        return format!(
+            "{}:{}",
+            row_text(row_first, col_first),
+            row_text(row_last, col_last)
+        );
+    }
+    format!(
+        "{}:{}",
+        cell_text(row_first, col_first),
+        cell_text(row_last, col_last)
+    )
+}
+
+fn read_array(extra: &mut ByteReader) -> Option<String> {
+    let cols = extra.read_u8().ok()? as usize + 1;
+    let rows = extra.read_u16().ok()? as usize + 1;
+    let mut row_list: Vec<String> = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let mut col_list: Vec<String> = Vec::with_capacity(cols);
+        for _ in 0..cols {
+            let value = match extra.read_u8().ok()? {
+                0x00 => {
+                    extra.skip(8).ok()?;
+                    String::new()
+                }
+                0x01 => extra.read_f64().ok()?.to_string(),
+                0x02 => {
+                    let value = extra.read_unicode_string().ok()?;
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                }
+                0x04 => {
+                    let value = extra.read_u8().ok()?;
+                    extra.skip(7).ok()?;
+                    bool_text(value != 0).to_string()
+                }
+                0x10 => {
+                    let value = extra.read_u8().ok()?;
+                    extra.skip(7).ok()?;
+                    error_text(value).to_string()
+                }
+                _ => return None,
+            };
+            col_list.push(value);
+        }
+        row_list.push(col_list.join(","));
+    }
+    Some(format!("{{{}}}", row_list.join(";")))
+}
+
+/// Name and fixed argument count (None if variable) of a built-in function.
+pub(crate) fn get_function(index: u16) -> Option<(&'static str, Option<u8>)> {
+    FUNCTIONS
+        .iter()
+        .find(|(id, _, _)| *id == index)
+        .map(|(_, name, argc)| (*name, *argc))
+}
+
+const FUNCTIONS: &[(u16, &str, Option<u8>)] = &[
+    (0, "COUNT", None),
+    (1, "IF", None),
+    (2, "ISNA", Some(1)),
+    (3, "ISERROR", Some(1)),
+    (4, "SUM", None),
+    (5, "AVERAGE", None),
+    (6, "MIN", None),
+    (7, "MAX", None),
+    (8, "ROW", None),
+    (9, "COLUMN", None),
+    (10, "NA", Some(0)),
+    (11, "NPV", None),
+    (12, "STDEV", None),
+    (13, "DOLLAR", None),
+    (14, "FIXED", None),
+    (15, "SIN", Some(1)),
+    (16, "COS", Some(1)),
+    (17, "TAN", Some(1)),
+    (18, "ATAN", Some(1)),
+    (19, "PI", Some(0)),
+    (20, "SQRT", Some(1)),
+    (21, "EXP", Some(1)),
+    (22, "LN", Some(1)),
+    (23, "LOG10", Some(1)),
+    (24, "ABS", Some(1)),
+    (25, "INT", Some(1)),
+    (26, "SIGN", Some(1)),
+    (27, "ROUND", Some(2)),
+    (28, "LOOKUP", None),
+    (29, "INDEX", None),
+    (30, "REPT", Some(2)),
+    (31, "MID", Some(3)),
+    (32, "LEN", Some(1)),
+    (33, "VALUE", Some(1)),
+    (34, "TRUE", Some(0)),
+    (35, "FALSE", Some(0)),
+    (36, "AND", None),
+    (37, "OR", None),
+    (38, "NOT", Some(1)),
+    (39, "MOD", Some(2)),
+    (40, "DCOUNT", Some(3)),
+    (41, "DSUM", Some(3)),
+    (42, "DAVERAGE", Some(3)),
+    (43, "DMIN", Some(3)),
+    (44, "DMAX", Some(3)),
+    (45, "DSTDEV", Some(3)),
+    (46, "VAR", None),
+    (47, "DVAR", Some(3)),
+    (48, "TEXT", Some(2)),
+    (49, "LINEST", None),
+    (50, "TREND", None),
+    (51, "LOGEST", None),
+    (52, "GROWTH", None),
+    (56, "PV", None),
+    (57, "FV", None),
+    (58, "NPER", None),
+    (59, "PMT", None),
+    (60, "RATE", None),
+    (61, "MIRR", Some(3)),
+    (62, "IRR", None),
+    (63, "RAND", Some(0)),
+    (64, "MATCH", None),
+    (65, "DATE", Some(3)),
+    (66, "TIME", Some(3)),
+    (67, "DAY", Some(1)),
+    (68, "MONTH", Some(1)),
+    (69, "YEAR", Some(1)),
+    (70, "WEEKDAY", None),
+    (71, "HOUR", Some(1)),
+    (72, "MINUTE", Some(1)),
+    (73, "SECOND", Some(1)),
+    (74, "NOW", Some(0)),
+    (75, "AREAS", Some(1)),
+    (76, "ROWS", Some(1)),
+    (77, "COLUMNS", Some(1)),
+    (78, "OFFSET", None),
+    (82, "SEARCH", None),
+    (83, "TRANSPOSE", Some(1)),
+    (86, "TYPE", Some(1)),
+    (97, "ATAN2", Some(2)),
+    (98, "ASIN", Some(1)),
+    (99, "ACOS", Some(1)),
+    (100, "CHOOSE", None),
+    (101, "HLOOKUP", None),
+    (102, "VLOOKUP", None),
+    (105, "ISREF", Some(1)),
+    (109, "LOG", None),
+    (111, "CHAR", Some(1)),
+    (112, "LOWER", Some(1)),
+    (113, "UPPER", Some(1)),
+    (114, "PROPER", Some(1)),
+    (115, "LEFT", None),
+    (116, "RIGHT", None),
+    (117, "EXACT", Some(2)),
+    (118, "TRIM", Some(1)),
+    (119, "REPLACE", Some(4)),
+    (120, "SUBSTITUTE", None),
+    (121, "CODE", Some(1)),
+    (124, "FIND", None),
+    (125, "CELL", None),
+    (126, "ISERR", Some(1)),
+    (127, "ISTEXT", Some(1)),
+    (128, "ISNUMBER", Some(1)),
+    (129, "ISBLANK", Some(1)),
+    (130, "T", Some(1)),
+    (131, "N", Some(1)),
+    (140, "DATEVALUE", Some(1)),
+    (141, "TIMEVALUE", Some(1)),
+    (142, "SLN", Some(3)),
+    (143, "SYD", Some(4)),
+    (144, "DDB", None),
+    (148, "INDIRECT", None),
+    (162, "CLEAN", Some(1)),
+    (163, "MDETERM", Some(1)),
+    (164, "MINVERSE", Some(1)),
+    (165, "MMULT", Some(2)),
+    (167, "IPMT", None),
+    (168, "PPMT", None),
+    (169, "COUNTA", None),
+    (183, "PRODUCT", None),
+    (184, "FACT", Some(1)),
+    (189, "DPRODUCT", Some(3)),
+    (190, "ISNONTEXT", Some(1)),
+    (193, "STDEVP", None),
+    (194, "VARP", None),
+    (195, "DSTDEVP", Some(3)),
+    (196, "DVARP", Some(3)),
+    (197, "TRUNC", None),
+    (198, "ISLOGICAL", Some(1)),
+    (199, "DCOUNTA", Some(3)),
+    (204, "USDOLLAR", None),
+    (205, "FINDB", None),
+    (206, "SEARCHB", None),
+    (207, "REPLACEB", Some(4)),
+    (208, "LEFTB", None),
+    (209, "RIGHTB", None),
+    (210, "MIDB", Some(3)),
+    (211, "LENB", Some(1)),
+    (212, "ROUNDUP", Some(2)),
+    (213, "ROUNDDOWN", Some(2)),
+    (214, "ASC", Some(1)),
+    (215, "DBCS", Some(1)),
+    (216, "RANK", None),
+    (219, "ADDRESS", None),
+    (220, "DAYS360", None),
+    (221, "TODAY", Some(0)),
+    (222, "VDB", None),
+    (227, "MEDIAN", None),
+    (228, "SUMPRODUCT", None),
+    (229, "SINH", Some(1)),
+    (230, "COSH", Some(1)),
+    (231, "TANH", Some(1)),
+    (232, "ASINH", Some(1)),
+    (233, "ACOSH", Some(1)),
+    (234, "ATANH", Some(1)),
+    (235, "DGET", Some(3)),
+    (244, "INFO", Some(1)),
+    (247, "DB", None),
+    (252, "FREQUENCY", Some(2)),
+    (261, "ERROR.TYPE", Some(1)),
+    (269, "AVEDEV", None),
+    (270, "BETADIST", None),
+    (271, "GAMMALN", Some(1)),
+    (272, "BETAINV", None),
+    (273, "BINOMDIST", Some(4)),
+    (274, "CHIDIST", Some(2)),
+    (275, "CHIINV", Some(2)),
+    (276, "COMBIN", Some(2)),
+    (277, "CONFIDENCE", Some(3)),
+    (278, "CRITBINOM", Some(3)),
+    (279, "EVEN", Some(1)),
+    (280, "EXPONDIST", Some(3)),
+    (281, "FDIST", Some(3)),
+    (282, "FINV", Some(3)),
+    (283, "FISHER", Some(1)),
+    (284, "FISHERINV", Some(1)),
+    (285, "FLOOR", Some(2)),
+    (286, "GAMMADIST", Some(4)),
+    (287, "GAMMAINV", Some(3)),
+    (288, "CEILING", Some(2)),
+    (289, "HYPGEOMDIST", Some(4)),
+    (290, "LOGNORMDIST", Some(3)),
+    (291, "LOGINV", Some(3)),
+    (292, "NEGBINOMDIST", Some(3)),
+    (293, "NORMDIST", Some(4)),
+    (294, "NORMSDIST", Some(1)),
+    (295, "NORMINV", Some(3)),
+    (296, "NORMSINV", Some(1)),
+    (297, "STANDARDIZE", Some(3)),
+    (298, "ODD", Some(1)),
+    (299, "PERMUT", Some(2)),
+    (300, "POISSON", Some(3)),
+    (301, "TDIST", Some(3)),
+    (302, "WEIBULL", Some(4)),
+    (303, "SUMXMY2", Some(2)),
+    (304, "SUMX2MY2", Some(2)),
+    (305, "SUMX2PY2", Some(2)),
+    (306, "CHITEST", Some(2)),
+    (307, "CORREL", Some(2)),
+    (308, "COVAR", Some(2)),
+    (309, "FORECAST", Some(3)),
+    (310, "FTEST", Some(2)),
+    (311, "INTERCEPT", Some(2)),
+    (312, "PEARSON", Some(2)),
+    (313, "RSQ", Some(2)),
+    (314, "STEYX", Some(2)),
+    (315, "SLOPE", Some(2)),
+    (316, "TTEST", Some(4)),
+    (317, "PROB", None),
+    (318, "DEVSQ", None),
+    (319, "GEOMEAN", None),
+    (320, "HARMEAN", None),
+    (321, "SUMSQ", None),
+    (322, "KURT", None),
+    (323, "SKEW", None),
+    (324, "ZTEST", None),
+    (325, "LARGE", Some(2)),
+    (326, "SMALL", Some(2)),
+    (327, "QUARTILE", Some(2)),
+    (328, "PERCENTILE", Some(2)),
+    (329, "PERCENTRANK", None),
+    (330, "MODE", None),
+    (331, "TRIMMEAN", Some(2)),
+    (332, "TINV", Some(2)),
+    (336, "CONCATENATE", None),
+    (337, "POWER", Some(2)),
+    (342, "RADIANS", Some(1)),
+    (343, "DEGREES", Some(1)),
+    (344, "SUBTOTAL", None),
+    (345, "SUMIF", None),
+    (346, "COUNTIF", Some(2)),
+    (347, "COUNTBLANK", Some(1)),
+    (350, "ISPMT", Some(4)),
+    (351, "DATEDIF", Some(3)),
+    (352, "DATESTRING", Some(1)),
+    (353, "NUMBERSTRING", Some(2)),
+    (354, "ROMAN", None),
+    (358, "GETPIVOTDATA", None),
+    (359, "HYPERLINK", None),
+    (360, "PHONETIC", Some(1)),
+    (361, "AVERAGEA", None),
+    (362, "MAXA", None),
+    (363, "MINA", None),
+    (364, "STDEVPA", None),
+    (365, "VARPA", None),
+    (366, "STDEVA", None),
+    (367, "VARA", None),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn future_function() {
+        let context = FormulaContext {
+            extern_sheets: vec![(None, 0)],
+            self_supbooks: vec![false],
+            names: Vec::new(),
+            extern_names: vec![vec!["_xlfn.IFS".to_string()]],
+        };
+        // PtgNameX, PtgBool, PtgInt, PtgFuncVar(255)
+        let rgce = [
+            0x39, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1D, 0x01, 0x1E, 0x05, 0x00, 0x22, 0x03,
+            0xFF, 0x00,
+        ];
+        assert_eq!(
+            decode(&rgce, &[], (0, 0), &context).unwrap(),
+            "_xlfn.IFS(TRUE,5)"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use super::workbook::{FontRecord, Globals, XfRecord};
+use crate::structs::Border;
+use crate::structs::Color;
+use crate::structs::Font;
+use crate::structs::HorizontalAlignmentValues;
+use crate::structs::PatternValues;
+use crate::structs::Style;
+use crate::structs::VerticalAlignmentValues;
+use crate::structs::FILL_BUILT_IN_FORMAT_CODES;
+
+/// Build the style of each XF record.
+pub(crate) fn build_styles(globals: &Globals) -> Vec<Style> {
+    globals
+        .xfs
+        .iter()
+        .map(|xf| build_style(xf, globals))
+        .collect()
+}
+
+fn build_style(xf: &XfRecord, globals: &Globals) -> Style {
+    let mut style = Style::default();
+
+    // font index 4 is never written.
+    let font_index = match xf.font_index >= 4 {
+        true => xf.font_index as usize - 1,
+        false => xf.font_index as usize,
+    };
+    if let Some(font) = globals.fonts.get(font_index) {
+        style.set_font(build_font(font, globals));
+    }
+
+    let format_index = xf.format_index;
+    if let Some(code) = globals.formats.get(&format_index) {
+        style.get_number_format_mut().set_format_code(code.as_str());
+    } else if format_index != 0 && FILL_BUILT_IN_FORMAT_CODES.contains_key(&(format_index as u32)) {
+        style
+            .get_number_format_mut()
+            .set_number_format_id(format_index as u32);
+    }
+
+    let horizontal = match xf.alignment & 0x07 {
+        1 => Some("left"),
+        2 => Some("center"),
+        3 => Some("right"),
+        4 => Some("fill"),
+        5 => Some("justify"),
+        6 => Some("centerContinuous"),
+        7 => Some("distributed"),
+        _ => None,
+    };
+    let vertical = match (xf.alignment >> 4) & 0x07 {
+        0 => Some("top"),
+        1 => Some("center"),
+        3 => Some("justify"),
+        4 => Some("distributed"),
+        _ => None,
+    };
+    let is_wrap = xf.alignment & 0x08 != 0;
+    if horizontal.is_some() || vertical.is_some() || is_wrap || xf.rotation != 0 {
+        let alignment = style.get_alignment_mut();
+        if let Some(v) = horizontal {
+            alignment.set_horizontal(HorizontalAlignmentValues::from_str(v).unwrap());
+        }
+        if let Some(v) = vertical {
+            alignment.set_vertical(VerticalAlignmentValues::from_str(v).unwrap());
+        }
+        if is_wrap {
+            alignment.set_wrap_text(true);
+        }
+        if xf.rotation != 0 {
+            alignment.set_text_rotation(xf.rotation as u32);
+        }
+    }
+
+    let border_list = [
+        (xf.border1 & 0x0F, (xf.border1 >> 16) & 0x7F),
+        ((xf.border1 >> 4) & 0x0F, (xf.border1 >> 23) & 0x7F),
+        ((xf.border1 >> 8) & 0x0F, xf.border2 & 0x7F),
+        ((xf.border1 >> 12) & 0x0F, (xf.border2 >> 7) & 0x7F),
+    ];
+    if border_list.iter().any(|(v, _)| *v != 0) {
+        let borders = style.get_borders_mut();
+        for (index, (line, color)) in border_list.iter().enumerate() {
+            if *line == 0 {
+                continue;
+            }
+            let border = match index {
+                0 => borders.get_left_mut(),
+                1 => borders.get_right_mut(),
+                2 => borders.get_top_mut(),
+                _ => borders.get_bottom_mut(),
+            };
+            set_border(border, *line, *color as u16, globals);
+        }
+    }
+
+    let pattern = (xf.border2 >> 26) & 0x3F;
+    if pattern != 0 {
+        let pattern_fill = style.get_fill_mut().get_pattern_fill_mut();
+        if let Some(v) = pattern_name(pattern) {
+            pattern_fill.set_pattern_type(PatternValues::from_str(v).unwrap());
+        }
+        set_color(
+            pattern_fill.get_foreground_color_mut(),
+            xf.fill & 0x7F,
+            globals,
+        );
+        set_color(
+            pattern_fill.get_background_color_mut(),
+            (xf.fill >> 7) & 0x7F,
+            globals,
+        );
+    }
+
+    if !xf.is_locked || xf.is_hidden {
+        let protection = style.get_protection_mut();
+        protection.set_locked(xf.is_locked);
+        protection.set_hidden(xf.is_hidden);
+    }
+
+    style
+}
+
+fn build_font(record: &FontRecord, globals: &Globals) -> Font {
+    let mut font = Font::default();
+    font.set_name(record.name.as_str());
+    font.set_size(record.height as f64 / 20f64);
+    if record.family != 0 {
+        font.set_family(record.family as i32);
+    }
+    if record.charset != 0 {
+        font.set_charset(record.charset as i32);
+    }
+    if record.weight >= 700 {
+        font.set_bold(true);
+    }
+    if record.is_italic {
+        font.set_italic(true);
+    }
+    if record.is_strike {
+        font.set_strikethrough(true);
+    }
+    match record.underline {
+        0x01 => {
+            font.set_underline("single");
+        }
+        0x02 => {
+            font.set_underline("double");
+        }
+        0x21 => {
+            font.set_underline("singleAccounting");
+        }
+        0x22 => {
+            font.set_underline("doubleAccounting");
+        }
+        _ => {}
+    }
+    set_color(font.get_color_mut(), record.color, globals);
+    font
+}
+
+fn set_border(border: &mut Border, line: u32, color: u16, globals: &Globals) {
+    let style = match border_style(line) {
+        Some(v) => v,
+        None => return,
+    };
+    border.set_border_style(style);
+    set_color(border.get_color_mut(), color, globals);
+}
+
+/// Border style of a line style code.
+pub(crate) fn border_style(line: u32) -> Option<&'static str> {
+    let style = match line {
+        1 => Border::BORDER_THIN,
+        2 => Border::BORDER_MEDIUM,
+        3 => Border::BORDER_DASHED,
+        4 => Border::BORDER_DOTTED,
+        5 => Border::BORDER_THICK,
+        6 => Border::BORDER_DOUBLE,
+        7 => Border::BORDER_HAIR,
+        8 => Border::BORDER_MEDIUMDASHED,
+        9 => Border::BORDER_DASHDOT,
+        10 => Border::BORDER_MEDIUMDASHDOT,
+        11 => Border::BORDER_DASHDOTDOT,
+        12 => Border::BORDER_MEDIUMDASHDOTDOT,
+        13 => Border::BORDER_SLANTDASHDOT,
+        _ => return None,
+    };
+    Some(style)
+}
+
+/// Set a palette color. System colors (64 and above) are left as they are.
+fn set_color(color: &mut Color, index: u16, globals: &Globals) {
+    if let Some(v) = globals.palette.get(&index) {
+        color.set_argb(v.as_str());
+    } else if index < 64 {
+        color.set_indexed(index as u32);
+    }
+}
+
+/// Pattern type of a fill pattern code.
+pub(crate) fn pattern_name(value: u32) -> Option<&'static str> {
+    let name = match value {
+        1 => "solid",
+        2 => "mediumGray",
+        3 => "darkGray",
+        4 => "lightGray",
+        5 => "darkHorizontal",
+        6 => "darkVertical",
+        7 => "darkDown",
+        8 => "darkUp",
+        9 => "darkGrid",
+        10 => "darkTrellis",
+        11 => "lightHorizontal",
+        12 => "lightVertical",
+        13 => "lightDown",
+        14 => "lightUp",
+        15 => "lightGrid",
+        16 => "lightTrellis",
+        17 => "gray125",
+        18 => "gray0625",
+        _ => return None,
+    };
+    Some(name)
+}
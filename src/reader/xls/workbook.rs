@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use super::biff::*;
+use super::formula::{quote_sheet_name, FormulaContext};
+use super::XlsxError;
+
+/// FONT record.
+#[derive(Default, Debug)]
+pub(crate) struct FontRecord {
+    pub(crate) height: u16,
+    pub(crate) is_italic: bool,
+    pub(crate) is_strike: bool,
+    pub(crate) color: u16,
+    pub(crate) weight: u16,
+    pub(crate) underline: u8,
+    pub(crate) family: u8,
+    pub(crate) charset: u8,
+    pub(crate) name: String,
+}
+
+/// XF record.
+#[derive(Default, Debug)]
+pub(crate) struct XfRecord {
+    pub(crate) font_index: u16,
+    pub(crate) format_index: u16,
+    pub(crate) is_locked: bool,
+    pub(crate) is_hidden: bool,
+    pub(crate) alignment: u8,
+    pub(crate) rotation: u8,
+    pub(crate) border1: u32,
+    pub(crate) border2: u32,
+    pub(crate) fill: u16,
+}
+
+/// BOUNDSHEET8 record.
+#[derive(Default, Debug)]
+pub(crate) struct SheetRecord {
+    pub(crate) name: String,
+    pub(crate) position: usize,
+    pub(crate) state: u8,
+    pub(crate) is_worksheet: bool,
+}
+
+/// NAME record. The formula is decoded after all the globals are read.
+#[derive(Default, Debug)]
+pub(crate) struct NameRecord {
+    pub(crate) name: String,
+    pub(crate) is_hidden: bool,
+    pub(crate) sheet_index: u16,
+    pub(crate) rgce: Vec<u8>,
+    pub(crate) rgcb: Vec<u8>,
+}
+
+/// Workbook globals substream.
+#[derive(Default, Debug)]
+pub(crate) struct Globals {
+    pub(crate) fonts: Vec<FontRecord>,
+    pub(crate) formats: HashMap<u16, String>,
+    pub(crate) xfs: Vec<XfRecord>,
+    pub(crate) palette: HashMap<u16, String>,
+    pub(crate) sheets: Vec<SheetRecord>,
+    pub(crate) shared_strings: Vec<String>,
+    pub(crate) names: Vec<NameRecord>,
+    pub(crate) context: FormulaContext,
+}
+
+pub(crate) fn read(stream: &[u8]) -> Result<Globals, XlsxError> {
+    let mut globals = Globals::default();
+    let mut supbooks: Vec<u16> = Vec::new();
+    let mut xti_list: Vec<(u16, u16, u16)> = Vec::new();
+
+    for (index, record) in RecordReader::new(stream, 0).enumerate() {
+        let record = record?;
+        let mut reader = ByteReader::new(&record);
+        match record.record_type {
+            RECORD_BOF => {
+                let version = reader.read_u16()?;
+                if index == 0 && version != BIFF8_VERSION {
+                    return Err(make_error("only BIFF8 (Excel 97-2003) files are supported"));
+                }
+            }
+            RECORD_FILEPASS => {
+                return Err(XlsxError::Decryption(
+                    "encrypted xls files are not supported".into(),
+                ));
+            }
+            RECORD_FONT => {
+                let mut font = FontRecord {
+                    height: reader.read_u16()?,
+                    ..Default::default()
+                };
+                let flags = reader.read_u16()?;
+                font.is_italic = flags & 0x02 != 0;
+                font.is_strike = flags & 0x08 != 0;
+                font.color = reader.read_u16()?;
+                font.weight = reader.read_u16()?;
+                reader.skip(2)?;
+                font.underline = reader.read_u8()?;
+                font.family = reader.read_u8()?;
+                font.charset = reader.read_u8()?;
+                reader.skip(1)?;
+                font.name = reader.read_short_unicode_string()?;
+                globals.fonts.push(font);
+            }
+            RECORD_FORMAT => {
+                let index = reader.read_u16()?;
+                let code = reader.read_unicode_string()?;
+                globals.formats.insert(index, code);
+            }
+            RECORD_XF => {
+                let mut xf = XfRecord {
+                    font_index: reader.read_u16()?,
+                    format_index: reader.read_u16()?,
+                    ..Default::default()
+                };
+                let flags = reader.read_u16()?;
+                xf.is_locked = flags & 0x01 != 0;
+                xf.is_hidden = flags & 0x02 != 0;
+                xf.alignment = reader.read_u8()?;
+                xf.rotation = reader.read_u8()?;
+                reader.skip(2)?;
+                xf.border1 = reader.read_u32()?;
+                xf.border2 = reader.read_u32()?;
+                xf.fill = reader.read_u16()?;
+                globals.xfs.push(xf);
+            }
+            RECORD_PALETTE => {
+                let count = reader.read_u16()?;
+                for index in 0..count {
+                    let rgb = reader.read_bytes(4)?;
+                    globals.palette.insert(
+                        index + 8,
+                        format!("FF{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2]),
+                    );
+                }
+            }
+            RECORD_BOUNDSHEET => {
+                let position = reader.read_u32()? as usize;
+                let state = reader.read_u8()? & 0x03;
+                let sheet_type = reader.read_u8()?;
+                let name = reader.read_short_unicode_string()?;
+                globals.sheets.push(SheetRecord {
+                    name,
+                    position,
+                    state,
+                    is_worksheet: sheet_type == 0x00,
+                });
+            }
+            RECORD_SST => {
+                reader.skip(4)?;
+                let count = reader.read_u32()?;
+                for _ in 0..count {
+                    if reader.remaining() == 0 {
+                        break;
+                    }
+                    let value = reader.read_rich_extended_string()?;
+                    globals.shared_strings.push(value);
+                }
+            }
+            RECORD_SUPBOOK => {
+                reader.skip(2)?;
+                supbooks.push(reader.read_u16()?);
+                globals.context.extern_names.push(Vec::new());
+            }
+            RECORD_EXTERNNAME => {
+                reader.skip(6)?;
+                let name = reader.read_short_unicode_string()?;
+                if let Some(v) = globals.context.extern_names.last_mut() {
+                    v.push(name);
+                }
+            }
+            RECORD_EXTERNSHEET => {
+                let count = reader.read_u16()?;
+                for _ in 0..count {
+                    let supbook = reader.read_u16()?;
+                    let first = reader.read_u16()?;
+                    let last = reader.read_u16()?;
+                    xti_list.push((supbook, first, last));
+                }
+            }
+            RECORD_NAME => {
+                let flags = reader.read_u16()?;
+                reader.skip(1)?;
+                let cch = reader.read_u8()? as usize;
+                let cce = reader.read_u16()? as usize;
+                reader.skip(2)?;
+                let sheet_index = reader.read_u16()?;
+                reader.skip(4)?;
+                let is_high_byte = reader.read_u8()? & 0x01 != 0;
+                let mut name = reader.read_chars(cch, is_high_byte)?;
+                if flags & 0x20 != 0 {
+                    name = builtin_name(&name);
+                }
+                let rgce = reader.read_bytes(cce)?.to_vec();
+                let rgcb = reader.read_bytes(reader.remaining())?.to_vec();
+                globals.names.push(NameRecord {
+                    name,
+                    is_hidden: flags & 0x01 != 0,
+                    sheet_index,
+                    rgce,
+                    rgcb,
+                });
+            }
+            RECORD_EOF => break,
+            _ => {}
+        }
+    }
+
+    globals.context.self_supbooks = supbooks.iter().map(|v| *v == 0x0401).collect();
+    globals.context.names = globals.names.iter().map(|v| v.name.clone()).collect();
+    for (supbook, first, last) in xti_list {
+        let is_self = globals
+            .context
+            .self_supbooks
+            .get(supbook as usize)
+            .copied()
+            .unwrap_or(false);
+        let sheet_text = match is_self {
+            true => {
+                let first_name = globals.sheets.get(first as usize).map(|v| v.name.as_str());
+                let last_name = globals.sheets.get(last as usize).map(|v| v.name.as_str());
+                match (first_name, last_name) {
+                    (Some(f), Some(l)) if f == l => Some(quote_sheet_name(f)),
+                    (Some(f), Some(l)) => Some(quote_sheet_name(&format!("{}:{}", f, l))),
+                    _ => None,
+                }
+            }
+            false => None,
+        };
+        globals
+            .context
+            .extern_sheets
+            .push((sheet_text, supbook as usize));
+    }
+
+    Ok(globals)
+}
+
+fn builtin_name(value: &str) -> String {
+    let name = match value.chars().next().map(|c| c as u32) {
+        Some(0x00) => "Consolidate_Area",
+        Some(0x01) => "Auto_Open",
+        Some(0x02) => "Auto_Close",
+        Some(0x03) => "Extract",
+        Some(0x04) => "Database",
+        Some(0x05) => "Criteria",
+        Some(0x06) => "Print_Area",
+        Some(0x07) => "Print_Titles",
+        Some(0x08) => "Recorder",
+        Some(0x09) => "Data_Form",
+        Some(0x0A) => "Auto_Activate",
+        Some(0x0B) => "Auto_Deactivate",
+        Some(0x0C) => "Sheet_Title",
+        Some(0x0D) => "_FilterDatabase",
+        _ => return value.to_string(),
+    };
+    format!("_xlnm.{}", name)
+}
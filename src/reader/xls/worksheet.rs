@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+use super::biff::*;
+use super::formula::{decode, error_text};
+use super::workbook::Globals;
+use super::XlsxError;
+use crate::helper::coordinate::coordinate_from_index;
+use crate::structs::Cell;
+use crate::structs::CellFormula;
+use crate::structs::CellFormulaValues;
+use crate::structs::Style;
+use crate::structs::Worksheet;
+
+/// SHRFMLA or ARRAY record, keyed by its first cell.
+struct SharedFormula {
+    rgce: Vec<u8>,
+    rgcb: Vec<u8>,
+    range: (u32, u32, u32, u32),
+    is_array: bool,
+}
+
+pub(crate) fn read(
+    stream: &[u8],
+    position: usize,
+    globals: &Globals,
+    styles: &[Style],
+    worksheet: &mut Worksheet,
+) -> Result<(), XlsxError> {
+    let mut shared_formulas: HashMap<(u32, u32), SharedFormula> = HashMap::new();
+    // cells holding a ptgExp token and the first cell of the formula they refer to.
+    let mut exp_cells: Vec<((u32, u32), (u32, u32))> = Vec::new();
+    let mut string_cell: Option<(u32, u32)> = None;
+    // depth of the embedded substreams (charts), which are skipped.
+    let mut depth = 0;
+
+    for record in RecordReader::new(stream, position).skip(1) {
+        let record = record?;
+        let mut reader = ByteReader::new(&record);
+        if depth > 0 {
+            match record.record_type {
+                RECORD_BOF => depth += 1,
+                RECORD_EOF => depth -= 1,
+                _ => {}
+            }
+            continue;
+        }
+        match record.record_type {
+            RECORD_NUMBER => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_f64()?;
+                get_cell(worksheet, row, col, xf, styles).set_value_number(value);
+            }
+            RECORD_RK => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                let value = decode_rk(reader.read_u32()?);
+                get_cell(worksheet, row, col, xf, styles).set_value_number(value);
+            }
+            RECORD_MULRK => {
+                let row = reader.read_u16()? as u32;
+                let mut col = reader.read_u16()? as u32;
+                while reader.remaining() >= 8 {
+                    let xf = reader.read_u16()?;
+                    let value = decode_rk(reader.read_u32()?);
+                    get_cell(worksheet, row, col, xf, styles).set_value_number(value);
+                    col += 1;
+                }
+            }
+            RECORD_LABELSST => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                let index = reader.read_u32()? as usize;
+                let value = globals
+                    .shared_strings
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_default();
+                get_cell(worksheet, row, col, xf, styles).set_value_string(value);
+            }
+            RECORD_LABEL | RECORD_RSTRING => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_unicode_string()?;
+                get_cell(worksheet, row, col, xf, styles).set_value_string(value);
+            }
+            RECORD_BOOLERR => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_u8()?;
+                let is_error = reader.read_u8()? != 0;
+                let cell = get_cell(worksheet, row, col, xf, styles);
+                match is_error {
+                    true => cell.set_error(error_text(value)),
+                    false => cell.set_value_bool(value != 0),
+                };
+            }
+            RECORD_BLANK => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                get_cell(worksheet, row, col, xf, styles);
+            }
+            RECORD_MULBLANK => {
+                let row = reader.read_u16()? as u32;
+                let mut col = reader.read_u16()? as u32;
+                while reader.remaining() >= 4 {
+                    let xf = reader.read_u16()?;
+                    get_cell(worksheet, row, col, xf, styles);
+                    col += 1;
+                }
+            }
+            RECORD_FORMULA => {
+                let (row, col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_bytes(8)?;
+                reader.skip(6)?;
+                let cce = reader.read_u16()? as usize;
+                let rgce = reader.read_bytes(cce)?;
+                let rgcb = reader.read_bytes(reader.remaining())?;
+
+                let cell = get_cell(worksheet, row, col, xf, styles);
+                string_cell = None;
+                if value[6] == 0xFF && value[7] == 0xFF {
+                    match value[0] {
+                        0x00 => string_cell = Some((row, col)),
+                        0x01 => {
+                            cell.set_value_bool(value[2] != 0);
+                        }
+                        0x02 => {
+                            cell.set_error(error_text(value[2]));
+                        }
+                        _ => {
+                            cell.set_value_string("");
+                        }
+                    }
+                } else {
+                    cell.set_value_number(f64::from_le_bytes(value.try_into().unwrap()));
+                }
+
+                if rgce.len() == 5 && rgce[0] == 0x01 {
+                    let mut exp = ByteReader::from_slice(&rgce[1..]);
+                    let first_row = exp.read_u16()? as u32;
+                    let first_col = exp.read_u16()? as u32;
+                    exp_cells.push(((row, col), (first_row, first_col)));
+                } else if let Some(v) = decode(rgce, rgcb, (row, col), &globals.context) {
+                    cell.set_formula(v);
+                }
+            }
+            RECORD_STRING => {
+                if let Some((row, col)) = string_cell.take() {
+                    let value = reader.read_unicode_string()?;
+                    worksheet
+                        .get_cell_mut((col + 1, row + 1))
+                        .set_value_string_crate(value);
+                }
+            }
+            RECORD_SHRFMLA | RECORD_ARRAY => {
+                let row_first = reader.read_u16()? as u32;
+                let row_last = reader.read_u16()? as u32;
+                let col_first = reader.read_u8()? as u32;
+                let col_last = reader.read_u8()? as u32;
+                let is_array = record.record_type == RECORD_ARRAY;
+                match is_array {
+                    true => reader.skip(6)?,
+                    false => reader.skip(2)?,
+                }
+                let cce = reader.read_u16()? as usize;
+                let rgce = reader.read_bytes(cce)?.to_vec();
+                let rgcb = reader.read_bytes(reader.remaining())?.to_vec();
+                shared_formulas.insert(
+                    (row_first, col_first),
+                    SharedFormula {
+                        rgce,
+                        rgcb,
+                        range: (row_first, row_last, col_first, col_last),
+                        is_array,
+                    },
+                );
+            }
+            RECORD_ROW => {
+                let row = reader.read_u16()? as u32;
+                reader.skip(4)?;
+                let height = reader.read_u16()? & 0x7FFF;
+                reader.skip(4)?;
+                let flags = reader.read_u32()?;
+                let is_hidden = flags & 0x20 != 0;
+                let is_custom_height = flags & 0x40 != 0;
+                if is_hidden || is_custom_height {
+                    let row_dimension = worksheet.get_row_dimension_mut(&(row + 1));
+                    if is_custom_height {
+                        row_dimension.set_height(height as f64 / 20f64);
+                        row_dimension.set_custom_height(true);
+                    }
+                    if is_hidden {
+                        row_dimension.set_hidden(true);
+                    }
+                }
+            }
+            RECORD_COLINFO => {
+                let col_first = reader.read_u16()? as u32;
+                let col_last = reader.read_u16()? as u32;
+                let width = reader.read_u16()? as f64 / 256f64;
+                reader.skip(2)?;
+                let is_hidden = reader.read_u16()? & 0x01 != 0;
+                // the last entry may cover the columns up to 256.
+                for col in col_first..=col_last.min(255) {
+                    let column = worksheet.get_column_dimension_by_number_mut(&(col + 1));
+                    column.set_width(width);
+                    if is_hidden {
+                        column.set_hidden(true);
+                    }
+                }
+            }
+            RECORD_MERGECELLS => {
+                let count = reader.read_u16()?;
+                for _ in 0..count {
+                    let row_first = reader.read_u16()? as u32;
+                    let row_last = reader.read_u16()? as u32;
+                    let col_first = reader.read_u16()? as u32;
+                    let col_last = reader.read_u16()? as u32;
+                    worksheet.add_merge_cells(range_text(row_first, row_last, col_first, col_last));
+                }
+            }
+            RECORD_BOF => depth += 1,
+            RECORD_EOF => break,
+            _ => {}
+        }
+    }
+
+    for ((row, col), first) in exp_cells {
+        let shared_formula = match shared_formulas.get(&first) {
+            Some(v) => v,
+            None => continue,
+        };
+        if shared_formula.is_array {
+            if (row, col) != first {
+                continue;
+            }
+            if let Some(v) = decode(
+                &shared_formula.rgce,
+                &shared_formula.rgcb,
+                (row, col),
+                &globals.context,
+            ) {
+                let (row_first, row_last, col_first, col_last) = shared_formula.range;
+                let mut formula = CellFormula::default();
+                formula.set_formula_type(CellFormulaValues::Array);
+                formula.set_reference(range_text(row_first, row_last, col_first, col_last));
+                formula.set_text(v);
+                worksheet
+                    .get_cell_mut((col + 1, row + 1))
+                    .get_cell_value_mut()
+                    .set_formula_obj(formula);
+            }
+        } else if let Some(v) = decode(
+            &shared_formula.rgce,
+            &shared_formula.rgcb,
+            (row, col),
+            &globals.context,
+        ) {
+            worksheet.get_cell_mut((col + 1, row + 1)).set_formula(v);
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn read_cell_header(reader: &mut ByteReader) -> Result<(u32, u32, u16), XlsxError> {
+    let row = reader.read_u16()? as u32;
+    let col = reader.read_u16()? as u32;
+    let xf = reader.read_u16()?;
+    Ok((row, col, xf))
+}
+
+fn get_cell<'a>(
+    worksheet: &'a mut Worksheet,
+    row: u32,
+    col: u32,
+    xf: u16,
+    styles: &[Style],
+) -> &'a mut Cell {
+    let cell = worksheet.get_cell_mut((col + 1, row + 1));
+    if let Some(v) = styles.get(xf as usize) {
+        cell.set_style(v.clone());
+    }
+    cell
+}
+
+fn range_text(row_first: u32, row_last: u32, col_first: u32, col_last: u32) -> String {
+    let first = coordinate_from_index(&(col_first + 1), &(row_first + 1));
+    let last = coordinate_from_index(&(col_last + 1), &(row_last + 1));
+    match first == last {
+        true => first,
+        false => format!("{}:{}", first, last),
+    }
+}
@@ -0,0 +1,223 @@
+//! Reader for the Excel binary workbook format (BIFF12, .xlsb).
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use super::driver::{get_attribute, xml_read_loop};
+use crate::helper::const_str::*;
+use crate::structs::drawing::Theme;
+use crate::structs::DefinedName;
+use crate::structs::Spreadsheet;
+use crate::XlsxError;
+
+mod formula;
+mod record;
+mod shared_strings;
+mod styles;
+mod workbook;
+mod worksheet;
+
+/// read xlsb spreadsheet from arbitrary reader.
+/// Cell values, cached results and text of formulas, shared strings, cell styles
+/// (fonts, number formats, borders, fills, alignment), merged cells, column widths,
+/// row heights, sheet visibility and defined names are loaded.
+/// Charts, images and other drawing objects are ignored.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader<R: io::Read + io::Seek>(reader: R) -> Result<Spreadsheet, XlsxError> {
+    let mut arv = zip::read::ZipArchive::new(reader)?;
+
+    let workbook_path = read_relationships(&mut arv, "", "_rels/.rels")?
+        .into_iter()
+        .find(|(_, type_value, _)| type_value == OFCDOC_NS)
+        .map(|(_, _, target)| target)
+        .unwrap_or_else(|| XLSB_WORKBOOK.to_string());
+    let stream = record::read_part(&mut arv, &workbook_path)?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "workbook part not found"))?;
+    let globals = workbook::read(&stream)?;
+
+    let (directory, file_name) = match workbook_path.rfind('/') {
+        Some(v) => (&workbook_path[..=v], &workbook_path[v + 1..]),
+        None => ("", workbook_path.as_str()),
+    };
+    let workbook_rel = read_relationships(
+        &mut arv,
+        directory,
+        &format!("{}_rels/{}.rels", directory, file_name),
+    )?;
+    let get_part = |type_value: &str| {
+        workbook_rel
+            .iter()
+            .find(|(_, v, _)| v == type_value)
+            .map(|(_, _, target)| target.clone())
+    };
+
+    let mut book = crate::new_file_empty_worksheet();
+    book.set_theme(Theme::get_default_value());
+    if let Some(target) = get_part(THEME_NS) {
+        if let Some(v) = target.strip_prefix("xl/") {
+            book.set_theme(super::xlsx::theme::read(&mut arv, v)?);
+        }
+    }
+
+    let shared_strings = match get_part(SHARED_STRINGS_NS) {
+        Some(v) => match record::read_part(&mut arv, &v)? {
+            Some(stream) => shared_strings::read(&stream)?,
+            None => Vec::new(),
+        },
+        None => Vec::new(),
+    };
+    let styles = match get_part(STYLES_NS) {
+        Some(v) => match record::read_part(&mut arv, &v)? {
+            Some(stream) => styles::read(&stream)?,
+            None => Vec::new(),
+        },
+        None => Vec::new(),
+    };
+
+    // index of the worksheet in the book for each BrtBundleSh record.
+    let mut sheet_indexes: Vec<Option<usize>> = Vec::with_capacity(globals.sheets.len());
+    for sheet in &globals.sheets {
+        let target = workbook_rel
+            .iter()
+            .find(|(id, type_value, _)| {
+                Some(id) == sheet.rel_id.as_ref() && type_value == WORKSHEET_NS
+            })
+            .map(|(_, _, target)| target.as_str());
+        let stream = match target {
+            Some(v) => record::read_part(&mut arv, v)?,
+            None => None,
+        };
+        let stream = match stream {
+            Some(v) => v,
+            None => {
+                // chart sheets and dialog sheets are skipped.
+                sheet_indexes.push(None);
+                continue;
+            }
+        };
+        sheet_indexes.push(Some(book.get_sheet_collection_no_check().len()));
+        let worksheet = book
+            .new_sheet(sheet.name.as_str())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        match sheet.state {
+            1 => {
+                worksheet.set_state_str("hidden");
+            }
+            2 => {
+                worksheet.set_state_str("veryHidden");
+            }
+            _ => {}
+        }
+        worksheet::read(
+            &stream,
+            &shared_strings,
+            &styles,
+            &globals.context,
+            worksheet,
+        )?;
+    }
+
+    for name in &globals.names {
+        let address = match formula::decode(&name.rgce, &name.rgcb, (0, 0), &globals.context) {
+            Some(v) => v,
+            None => continue,
+        };
+        let mut defined_name = DefinedName::default();
+        defined_name.set_name(name.name.as_str());
+        defined_name.set_address(address);
+        if name.is_hidden {
+            defined_name.set_hidden(true);
+        }
+        let local_sheet_index = name
+            .sheet_index
+            .and_then(|v| sheet_indexes.get(v as usize).copied().flatten());
+        match local_sheet_index {
+            Some(v) => {
+                defined_name.set_local_sheet_id(v as u32);
+                book.get_sheet_mut(&v)
+                    .unwrap()
+                    .add_defined_names(defined_name);
+            }
+            None => {
+                let sheet_name = defined_name.get_sheet_name_crate();
+                match book.get_sheet_by_name_mut(&sheet_name) {
+                    Some(v) => v.add_defined_names(defined_name),
+                    None => book.add_defined_names(defined_name),
+                }
+            }
+        }
+    }
+
+    Ok(book)
+}
+
+/// read xlsb spreadsheet file.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsb");
+/// let mut book = umya_spreadsheet::reader::xlsb::read(path).unwrap();
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(io::BufReader::new(file))
+}
+
+/// Read a relationships part. The targets are resolved to paths in the archive.
+fn read_relationships<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    directory: &str,
+    path: &str,
+) -> Result<Vec<(String, String, String)>, XlsxError> {
+    let mut result: Vec<(String, String, String)> = Vec::new();
+    let file = match arv.by_name(path) {
+        Ok(v) => v,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(result),
+        Err(e) => return Err(e.into()),
+    };
+    let mut reader = Reader::from_reader(io::BufReader::new(file));
+    reader.config_mut().trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"Relationship" {
+                let id_value = get_attribute(e, b"Id").unwrap_or_default();
+                let type_value = get_attribute(e, b"Type").unwrap_or_default();
+                let target_value = get_attribute(e, b"Target").unwrap_or_default();
+                result.push((id_value, type_value, resolve_target(directory, &target_value)));
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(result)
+}
+
+/// Resolve a relationship target against the directory of its source part.
+fn resolve_target(directory: &str, target: &str) -> String {
+    if let Some(v) = target.strip_prefix('/') {
+        return v.to_string();
+    }
+    let mut parts: Vec<&str> = directory.split('/').filter(|v| !v.is_empty()).collect();
+    for part in target.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            v => parts.push(v),
+        }
+    }
+    parts.join("/")
+}
@@ -0,0 +1,365 @@
+use crate::helper::coordinate::string_from_column_index;
+use crate::reader::xls::biff::ByteReader;
+use crate::reader::xls::formula::{
+    bool_text, error_text, get_function, push_function, FormulaContext,
+};
+
+const MAX_ROW: u32 = 0xF_FFFF;
+const MAX_COL: u16 = 0x3FFF;
+
+/// Decode a BIFF12 parsed formula (rgce) into formula text without the leading "=".
+/// # Arguments
+/// * `rgce` - formula tokens.
+/// * `rgcb` - extra data of the array constants.
+/// * `base` - cell the formula belongs to (0-based row and column), used by relative tokens of shared formulas.
+/// * `context` - workbook level data.
+/// # Return value
+/// * `Option<String>` - None if the formula holds a token that is not supported.
+pub(crate) fn decode(
+    rgce: &[u8],
+    rgcb: &[u8],
+    base: (u32, u32),
+    context: &FormulaContext,
+) -> Option<String> {
+    let mut reader = ByteReader::from_slice(rgce);
+    let mut extra = ByteReader::from_slice(rgcb);
+    let mut stack: Vec<String> = Vec::new();
+
+    while reader.remaining() > 0 {
+        let ptg = reader.read_u8().ok()?;
+        let token = match ptg < 0x20 {
+            true => ptg,
+            false => (ptg & 0x1F) | 0x20,
+        };
+        match token {
+            0x03..=0x11 => {
+                let right = stack.pop()?;
+                let left = stack.pop()?;
+                let operator = match token {
+                    0x03 => "+",
+                    0x04 => "-",
+                    0x05 => "*",
+                    0x06 => "/",
+                    0x07 => "^",
+                    0x08 => "&",
+                    0x09 => "<",
+                    0x0A => "<=",
+                    0x0B => "=",
+                    0x0C => ">=",
+                    0x0D => ">",
+                    0x0E => "<>",
+                    0x0F => " ",
+                    0x10 => ",",
+                    _ => ":",
+                };
+                stack.push(format!("{}{}{}", left, operator, right));
+            }
+            0x12 => {
+                let value = stack.pop()?;
+                stack.push(format!("+{}", value));
+            }
+            0x13 => {
+                let value = stack.pop()?;
+                stack.push(format!("-{}", value));
+            }
+            0x14 => {
+                let value = stack.pop()?;
+                stack.push(format!("{}%", value));
+            }
+            0x15 => {
+                let value = stack.pop()?;
+                stack.push(format!("({})", value));
+            }
+            0x16 => stack.push(String::new()),
+            0x17 => {
+                let cch = reader.read_u16().ok()? as usize;
+                let value = reader.read_chars(cch, true).ok()?;
+                stack.push(format!("\"{}\"", value.replace('"', "\"\"")));
+            }
+            0x19 => {
+                let flags = reader.read_u8().ok()?;
+                let data = reader.read_u16().ok()?;
+                if flags & 0x04 != 0 {
+                    // jump table of CHOOSE.
+                    reader.skip((data as usize + 1) * 2).ok()?;
+                }
+                if flags & 0x10 != 0 {
+                    let value = stack.pop()?;
+                    stack.push(format!("SUM({})", value));
+                }
+            }
+            0x1C => {
+                let value = reader.read_u8().ok()?;
+                stack.push(error_text(value).to_string());
+            }
+            0x1D => {
+                let value = reader.read_u8().ok()?;
+                stack.push(bool_text(value != 0).to_string());
+            }
+            0x1E => {
+                let value = reader.read_u16().ok()?;
+                stack.push(value.to_string());
+            }
+            0x1F => {
+                let value = reader.read_f64().ok()?;
+                stack.push(value.to_string());
+            }
+            0x20 => {
+                reader.skip(14).ok()?;
+                stack.push(read_array(&mut extra)?);
+            }
+            0x21 => {
+                let index = reader.read_u16().ok()?;
+                let (name, argc) = get_function(index)?;
+                let argc = argc?;
+                push_function(&mut stack, name, argc as usize)?;
+            }
+            0x22 => {
+                let argc = (reader.read_u8().ok()? & 0x7F) as usize;
+                let index = reader.read_u16().ok()? & 0x7FFF;
+                if index == 255 {
+                    // add-in or future function, the name is the first argument.
+                    if stack.len() < argc || argc == 0 {
+                        return None;
+                    }
+                    // the "_xlfn." prefix is kept like in xlsx files.
+                    let args = stack.split_off(stack.len() - argc);
+                    stack.push(format!("{}({})", args[0], args[1..].join(",")));
+                } else {
+                    let (name, _) = get_function(index)?;
+                    push_function(&mut stack, name, argc)?;
+                }
+            }
+            0x23 => {
+                let index = reader.read_u32().ok()? as usize;
+                stack.push(context.names.get(index.checked_sub(1)?)?.clone());
+            }
+            0x24 => {
+                let row = reader.read_u32().ok()?;
+                let col = reader.read_u16().ok()?;
+                stack.push(cell_text(row, col));
+            }
+            0x25 => {
+                let row_first = reader.read_u32().ok()?;
+                let row_last = reader.read_u32().ok()?;
+                let col_first = reader.read_u16().ok()?;
+                let col_last = reader.read_u16().ok()?;
+                stack.push(area_text(row_first, row_last, col_first, col_last));
+            }
+            0x26 => {
+                reader.skip(6).ok()?;
+                // the cached area list of the sub expression.
+                let count = extra.read_u32().ok()? as usize;
+                extra.skip(count * 12).ok()?;
+            }
+            0x27 | 0x28 => reader.skip(6).ok()?,
+            0x29 => reader.skip(2).ok()?,
+            0x2A => {
+                reader.skip(6).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            0x2B => {
+                reader.skip(12).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            0x2C => {
+                let row = reader.read_u32().ok()?;
+                let col = reader.read_u16().ok()?;
+                let (row, col) = relative_cell(row, col, base);
+                stack.push(cell_text(row, col));
+            }
+            0x2D => {
+                let row_first = reader.read_u32().ok()?;
+                let row_last = reader.read_u32().ok()?;
+                let col_first = reader.read_u16().ok()?;
+                let col_last = reader.read_u16().ok()?;
+                let (row_first, col_first) = relative_cell(row_first, col_first, base);
+                let (row_last, col_last) = relative_cell(row_last, col_last, base);
+                stack.push(area_text(row_first, row_last, col_first, col_last));
+            }
+            0x39 => {
+                let ixti = reader.read_u16().ok()? as usize;
+                let index = reader.read_u32().ok()? as usize;
+                let (_, supbook) = context.extern_sheets.get(ixti)?;
+                let name = match context.self_supbooks.get(*supbook) {
+                    Some(true) => context.names.get(index.checked_sub(1)?)?,
+                    _ => context
+                        .extern_names
+                        .get(*supbook)?
+                        .get(index.checked_sub(1)?)?,
+                };
+                stack.push(name.clone());
+            }
+            0x3A => {
+                let sheet = extern_sheet_text(reader.read_u16().ok()?, context);
+                let row = reader.read_u32().ok()?;
+                let col = reader.read_u16().ok()?;
+                stack.push(match sheet {
+                    Some(v) => format!("{}!{}", v, cell_text(row, col)),
+                    None => "#REF!".to_string(),
+                });
+            }
+            0x3B => {
+                let sheet = extern_sheet_text(reader.read_u16().ok()?, context);
+                let row_first = reader.read_u32().ok()?;
+                let row_last = reader.read_u32().ok()?;
+                let col_first = reader.read_u16().ok()?;
+                let col_last = reader.read_u16().ok()?;
+                stack.push(match sheet {
+                    Some(v) => format!(
+                        "{}!{}",
+                        v,
+                        area_text(row_first, row_last, col_first, col_last)
+                    ),
+                    None => "#REF!".to_string(),
+                });
+            }
+            0x3C => {
+                reader.skip(8).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            0x3D => {
+                reader.skip(14).ok()?;
+                stack.push("#REF!".to_string());
+            }
+            _ => return None,
+        }
+    }
+
+    match stack.len() {
+        1 => stack.pop(),
+        _ => None,
+    }
+}
+
+fn extern_sheet_text(ixti: u16, context: &FormulaContext) -> Option<String> {
+    context
+        .extern_sheets
+        .get(ixti as usize)
+        .and_then(|(v, _)| v.clone())
+}
+
+fn relative_cell(row: u32, col: u16, base: (u32, u32)) -> (u32, u16) {
+    let row = match col & 0x8000 != 0 {
+        true => (base.0 as i64 + row as i32 as i64).rem_euclid(MAX_ROW as i64 + 1) as u32,
+        false => row,
+    };
+    let col_value = match col & 0x4000 != 0 {
+        // sign extend the 14-bit offset.
+        true => {
+            let offset = (((col & MAX_COL) << 2) as i16 >> 2) as i64;
+            (base.1 as i64 + offset).rem_euclid(MAX_COL as i64 + 1) as u16
+        }
+        false => col & MAX_COL,
+    };
+    (row, (col & 0xC000) | col_value)
+}
+
+/// A1 text of a cell. The column field holds the relative flags in its high bits.
+fn cell_text(row: u32, col: u16) -> String {
+    format!(
+        "{}{}{}{}",
+        abs_mark(col & 0x4000 != 0),
+        string_from_column_index(&((col & MAX_COL) as u32 + 1)),
+        abs_mark(col & 0x8000 != 0),
+        row + 1
+    )
+}
+
+#[inline]
+fn abs_mark(is_relative: bool) -> &'static str {
+    match is_relative {
+        true => "",
+        false => "$",
+    }
+}
+
+fn area_text(row_first: u32, row_last: u32, col_first: u16, col_last: u16) -> String {
+    if row_first == 0 && row_last == MAX_ROW {
+        let col_text = |col: u16| {
+            format!(
+                "{}{}",
+                abs_mark(col & 0x4000 != 0),
+                string_from_column_index(&((col & MAX_COL) as u32 + 1))
+            )
+        };
+        return format!("{}:{}", col_text(col_first), col_text(col_last));
+    }
+    if (col_first & MAX_COL) == 0 && (col_last & MAX_COL) == MAX_COL {
+        let row_text = |row: u32, col: u16| format!("{}{}", abs_mark(col & 0x8000 != 0), row + 1);
+        return format!(
+            "{}:{}",
+            row_text(row_first, col_first),
+            row_text(row_last, col_last)
+        );
+    }
+    format!(
+        "{}:{}",
+        cell_text(row_first, col_first),
+        cell_text(row_last, col_last)
+    )
+}
+
+fn read_array(extra: &mut ByteReader) -> Option<String> {
+    let rows = extra.read_u32().ok()? as usize;
+    let cols = extra.read_u32().ok()? as usize;
+    let mut row_list: Vec<String> = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let mut col_list: Vec<String> = Vec::with_capacity(cols);
+        for _ in 0..cols {
+            let value = match extra.read_u8().ok()? {
+                0x00 => extra.read_f64().ok()?.to_string(),
+                0x01 => {
+                    let cch = extra.read_u16().ok()? as usize;
+                    let value = extra.read_chars(cch, true).ok()?;
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                }
+                0x02 => bool_text(extra.read_u8().ok()? != 0).to_string(),
+                0x04 => {
+                    let value = extra.read_u8().ok()?;
+                    extra.skip(3).ok()?;
+                    error_text(value).to_string()
+                }
+                _ => return None,
+            };
+            col_list.push(value);
+        }
+        row_list.push(col_list.join(","));
+    }
+    Some(format!("{{{}}}", row_list.join(";")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_tokens() {
+        let context = FormulaContext::default();
+        // =$A$1+B2*2
+        let rgce = [
+            0x24, 0, 0, 0, 0, 0, 0, // $A$1
+            0x24, 1, 0, 0, 0, 1, 0xC0, // B2
+            0x1E, 2, 0, // 2
+            0x05, 0x03,
+        ];
+        assert_eq!(
+            decode(&rgce, &[], (0, 0), &context),
+            Some("$A$1+B2*2".to_string())
+        );
+
+        // =SUM(A:A)
+        let rgce = [
+            0x25, 0, 0, 0, 0, 0xFF, 0xFF, 0x0F, 0, 0, 0xC0, 0, 0xC0, // A:A
+            0x22, 1, 4, 0,
+        ];
+        assert_eq!(
+            decode(&rgce, &[], (0, 0), &context),
+            Some("SUM(A:A)".to_string())
+        );
+
+        // shared formula: one row up and one column left of C3.
+        let rgce = [0x2C, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(decode(&rgce, &[], (2, 2), &context), Some("B2".to_string()));
+    }
+}
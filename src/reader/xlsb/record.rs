@@ -0,0 +1,147 @@
+use super::XlsxError;
+use crate::reader::xls::biff::{make_error, ByteReader};
+
+// record types.
+pub(crate) const BRT_ROW_HDR: u16 = 0x0000;
+pub(crate) const BRT_CELL_BLANK: u16 = 0x0001;
+pub(crate) const BRT_CELL_RK: u16 = 0x0002;
+pub(crate) const BRT_CELL_ERROR: u16 = 0x0003;
+pub(crate) const BRT_CELL_BOOL: u16 = 0x0004;
+pub(crate) const BRT_CELL_REAL: u16 = 0x0005;
+pub(crate) const BRT_CELL_ST: u16 = 0x0006;
+pub(crate) const BRT_CELL_ISST: u16 = 0x0007;
+pub(crate) const BRT_FMLA_STRING: u16 = 0x0008;
+pub(crate) const BRT_FMLA_NUM: u16 = 0x0009;
+pub(crate) const BRT_FMLA_BOOL: u16 = 0x000A;
+pub(crate) const BRT_FMLA_ERROR: u16 = 0x000B;
+pub(crate) const BRT_SST_ITEM: u16 = 0x0013;
+pub(crate) const BRT_NAME: u16 = 0x0027;
+pub(crate) const BRT_FONT: u16 = 0x002B;
+pub(crate) const BRT_FMT: u16 = 0x002C;
+pub(crate) const BRT_FILL: u16 = 0x002D;
+pub(crate) const BRT_BORDER: u16 = 0x002E;
+pub(crate) const BRT_XF: u16 = 0x002F;
+pub(crate) const BRT_COL_INFO: u16 = 0x003C;
+pub(crate) const BRT_CELL_RSTRING: u16 = 0x003E;
+pub(crate) const BRT_BUNDLE_SH: u16 = 0x009C;
+pub(crate) const BRT_MERGE_CELL: u16 = 0x00B0;
+pub(crate) const BRT_SUP_BOOK_SRC: u16 = 0x0163;
+pub(crate) const BRT_SUP_SELF: u16 = 0x0165;
+pub(crate) const BRT_SUP_SAME: u16 = 0x0166;
+pub(crate) const BRT_EXTERN_SHEET: u16 = 0x016A;
+pub(crate) const BRT_ARR_FMLA: u16 = 0x01AA;
+pub(crate) const BRT_SHR_FMLA: u16 = 0x01AB;
+pub(crate) const BRT_BEGIN_CELL_XFS: u16 = 0x0269;
+pub(crate) const BRT_END_CELL_XFS: u16 = 0x026A;
+pub(crate) const BRT_SUP_ADDIN: u16 = 0x029B;
+
+/// A BIFF12 record.
+pub(crate) struct Record<'a> {
+    pub(crate) record_type: u16,
+    pub(crate) data: &'a [u8],
+}
+
+/// Iterate over the records of a part.
+pub(crate) struct RecordReader<'a> {
+    stream: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    #[inline]
+    pub(crate) fn new(stream: &'a [u8]) -> Self {
+        Self { stream, pos: 0 }
+    }
+
+    /// Read a variable length number holding 7 bits in each byte.
+    fn read_var(&mut self, max_bytes: usize) -> Option<u32> {
+        let mut value = 0u32;
+        for index in 0..max_bytes {
+            let byte = *self.stream.get(self.pos)?;
+            self.pos += 1;
+            value |= ((byte & 0x7F) as u32) << (7 * index);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        Some(value)
+    }
+}
+
+impl<'a> Iterator for RecordReader<'a> {
+    type Item = Result<Record<'a>, XlsxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.stream.len() {
+            return None;
+        }
+        let record_type = match self.read_var(2) {
+            Some(v) => v as u16,
+            None => return Some(Err(make_error("unexpected end of record"))),
+        };
+        let size = match self.read_var(4) {
+            Some(v) => v as usize,
+            None => return Some(Err(make_error("unexpected end of record"))),
+        };
+        let data = match self.stream.get(self.pos..self.pos + size) {
+            Some(v) => v,
+            None => return Some(Err(make_error("unexpected end of record"))),
+        };
+        self.pos += size;
+        Some(Ok(Record { record_type, data }))
+    }
+}
+
+/// XLWideString (32-bit character count).
+#[inline]
+pub(crate) fn read_wide_string(reader: &mut ByteReader) -> Result<String, XlsxError> {
+    let cch = reader.read_u32()? as usize;
+    reader.read_chars(cch, true)
+}
+
+/// XLNullableWideString. None if the string is null.
+#[inline]
+pub(crate) fn read_nullable_wide_string(
+    reader: &mut ByteReader,
+) -> Result<Option<String>, XlsxError> {
+    let cch = reader.read_u32()?;
+    if cch == 0xFFFF_FFFF {
+        return Ok(None);
+    }
+    reader.read_chars(cch as usize, true).map(Some)
+}
+
+/// RichStr. Formatting runs and phonetic data are skipped.
+#[inline]
+pub(crate) fn read_rich_string(reader: &mut ByteReader) -> Result<String, XlsxError> {
+    reader.skip(1)?;
+    read_wide_string(reader)
+}
+
+/// Formula tokens and extra data (CellParsedFormula and its variants).
+#[inline]
+pub(crate) fn read_formula<'a>(
+    reader: &mut ByteReader<'a>,
+) -> Result<(&'a [u8], &'a [u8]), XlsxError> {
+    let cce = reader.read_u32()? as usize;
+    let rgce = reader.read_bytes(cce)?;
+    let cb = reader.read_u32()? as usize;
+    let rgcb = reader.read_bytes(cb.min(reader.remaining()))?;
+    Ok((rgce, rgcb))
+}
+
+/// Read a whole part of the archive.
+pub(crate) fn read_part<R: std::io::Read + std::io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    path: &str,
+) -> Result<Option<Vec<u8>>, XlsxError> {
+    use std::io::Read;
+    let mut file = match arv.by_name(path) {
+        Ok(v) => v,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut data = Vec::with_capacity(file.size() as usize);
+    file.read_to_end(&mut data)?;
+    Ok(Some(data))
+}
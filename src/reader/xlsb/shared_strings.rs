@@ -0,0 +1,15 @@
+use super::record::*;
+use super::XlsxError;
+use crate::reader::xls::biff::ByteReader;
+
+pub(crate) fn read(stream: &[u8]) -> Result<Vec<String>, XlsxError> {
+    let mut result: Vec<String> = Vec::new();
+    for record in RecordReader::new(stream) {
+        let record = record?;
+        if record.record_type == BRT_SST_ITEM {
+            let mut reader = ByteReader::from_slice(record.data);
+            result.push(read_rich_string(&mut reader)?);
+        }
+    }
+    Ok(result)
+}
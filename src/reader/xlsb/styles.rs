@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::record::*;
+use super::XlsxError;
+use crate::reader::xls::biff::ByteReader;
+use crate::reader::xls::style::{border_style, pattern_name};
+use crate::structs::Borders;
+use crate::structs::Color;
+use crate::structs::Fill;
+use crate::structs::Font;
+use crate::structs::HorizontalAlignmentValues;
+use crate::structs::PatternValues;
+use crate::structs::Style;
+use crate::structs::VerticalAlignmentValues;
+use crate::structs::FILL_BUILT_IN_FORMAT_CODES;
+
+/// BrtXF record.
+#[derive(Default, Debug)]
+struct XfRecord {
+    format_index: u16,
+    font_index: u16,
+    fill_index: u16,
+    border_index: u16,
+    rotation: u8,
+    flags: u16,
+}
+
+/// Read the styles part and build the style of each cell XF.
+pub(crate) fn read(stream: &[u8]) -> Result<Vec<Style>, XlsxError> {
+    let mut fonts: Vec<Font> = Vec::new();
+    let mut fills: Vec<Option<Fill>> = Vec::new();
+    let mut borders: Vec<Option<Borders>> = Vec::new();
+    let mut formats: HashMap<u16, String> = HashMap::new();
+    let mut xfs: Vec<XfRecord> = Vec::new();
+    let mut is_cell_xfs = false;
+
+    for record in RecordReader::new(stream) {
+        let record = record?;
+        let mut reader = ByteReader::from_slice(record.data);
+        match record.record_type {
+            BRT_FMT => {
+                let index = reader.read_u16()?;
+                let code = read_wide_string(&mut reader)?;
+                formats.insert(index, code);
+            }
+            BRT_FONT => fonts.push(read_font(&mut reader)?),
+            BRT_FILL => fills.push(read_fill(&mut reader)?),
+            BRT_BORDER => borders.push(read_borders(&mut reader)?),
+            BRT_BEGIN_CELL_XFS => is_cell_xfs = true,
+            BRT_END_CELL_XFS => is_cell_xfs = false,
+            BRT_XF if is_cell_xfs => {
+                reader.skip(2)?;
+                let format_index = reader.read_u16()?;
+                let font_index = reader.read_u16()?;
+                let fill_index = reader.read_u16()?;
+                let border_index = reader.read_u16()?;
+                let rotation = reader.read_u8()?;
+                reader.skip(1)?;
+                let flags = reader.read_u16()?;
+                xfs.push(XfRecord {
+                    format_index,
+                    font_index,
+                    fill_index,
+                    border_index,
+                    rotation,
+                    flags,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(xfs
+        .iter()
+        .map(|xf| build_style(xf, &fonts, &fills, &borders, &formats))
+        .collect())
+}
+
+fn build_style(
+    xf: &XfRecord,
+    fonts: &[Font],
+    fills: &[Option<Fill>],
+    borders: &[Option<Borders>],
+    formats: &HashMap<u16, String>,
+) -> Style {
+    let mut style = Style::default();
+
+    if let Some(font) = fonts.get(xf.font_index as usize) {
+        style.set_font(font.clone());
+    }
+    if let Some(Some(fill)) = fills.get(xf.fill_index as usize) {
+        style.set_fill(fill.clone());
+    }
+    if let Some(Some(v)) = borders.get(xf.border_index as usize) {
+        style.set_borders(v.clone());
+    }
+
+    let format_index = xf.format_index;
+    if let Some(code) = formats.get(&format_index) {
+        style.get_number_format_mut().set_format_code(code.as_str());
+    } else if format_index != 0 && FILL_BUILT_IN_FORMAT_CODES.contains_key(&(format_index as u32)) {
+        style
+            .get_number_format_mut()
+            .set_number_format_id(format_index as u32);
+    }
+
+    let horizontal = match xf.flags & 0x07 {
+        1 => Some("left"),
+        2 => Some("center"),
+        3 => Some("right"),
+        4 => Some("fill"),
+        5 => Some("justify"),
+        6 => Some("centerContinuous"),
+        7 => Some("distributed"),
+        _ => None,
+    };
+    let vertical = match (xf.flags >> 3) & 0x07 {
+        0 => Some("top"),
+        1 => Some("center"),
+        3 => Some("justify"),
+        4 => Some("distributed"),
+        _ => None,
+    };
+    let is_wrap = xf.flags & 0x40 != 0;
+    if horizontal.is_some() || vertical.is_some() || is_wrap || xf.rotation != 0 {
+        let alignment = style.get_alignment_mut();
+        if let Some(v) = horizontal {
+            alignment.set_horizontal(HorizontalAlignmentValues::from_str(v).unwrap());
+        }
+        if let Some(v) = vertical {
+            alignment.set_vertical(VerticalAlignmentValues::from_str(v).unwrap());
+        }
+        if is_wrap {
+            alignment.set_wrap_text(true);
+        }
+        if xf.rotation != 0 {
+            alignment.set_text_rotation(xf.rotation as u32);
+        }
+    }
+
+    let is_locked = xf.flags & 0x1000 != 0;
+    let is_hidden = xf.flags & 0x2000 != 0;
+    if !is_locked || is_hidden {
+        let protection = style.get_protection_mut();
+        protection.set_locked(is_locked);
+        protection.set_hidden(is_hidden);
+    }
+
+    style
+}
+
+fn read_font(reader: &mut ByteReader) -> Result<Font, XlsxError> {
+    let mut font = Font::default();
+    font.set_size(reader.read_u16()? as f64 / 20f64);
+    let flags = reader.read_u16()?;
+    if flags & 0x02 != 0 {
+        font.set_italic(true);
+    }
+    if flags & 0x08 != 0 {
+        font.set_strikethrough(true);
+    }
+    if reader.read_u16()? >= 700 {
+        font.set_bold(true);
+    }
+    reader.skip(2)?;
+    match reader.read_u8()? {
+        0x01 => {
+            font.set_underline("single");
+        }
+        0x02 => {
+            font.set_underline("double");
+        }
+        0x21 => {
+            font.set_underline("singleAccounting");
+        }
+        0x22 => {
+            font.set_underline("doubleAccounting");
+        }
+        _ => {}
+    }
+    let family = reader.read_u8()?;
+    if family != 0 {
+        font.set_family(family as i32);
+    }
+    let charset = reader.read_u8()?;
+    if charset != 0 {
+        font.set_charset(charset as i32);
+    }
+    reader.skip(1)?;
+    read_color(reader, font.get_color_mut())?;
+    match reader.read_u8()? {
+        1 => {
+            font.set_scheme("major");
+        }
+        2 => {
+            font.set_scheme("minor");
+        }
+        _ => {}
+    }
+    font.set_name(read_wide_string(reader)?);
+    Ok(font)
+}
+
+/// None if the fill has no pattern.
+fn read_fill(reader: &mut ByteReader) -> Result<Option<Fill>, XlsxError> {
+    let pattern = reader.read_u32()?;
+    let name = match pattern_name(pattern) {
+        Some(v) => v,
+        None => return Ok(None),
+    };
+    let mut fill = Fill::default();
+    let pattern_fill = fill.get_pattern_fill_mut();
+    pattern_fill.set_pattern_type(PatternValues::from_str(name).unwrap());
+    read_color(reader, pattern_fill.get_foreground_color_mut())?;
+    read_color(reader, pattern_fill.get_background_color_mut())?;
+    Ok(Some(fill))
+}
+
+/// None if the borders have no line.
+fn read_borders(reader: &mut ByteReader) -> Result<Option<Borders>, XlsxError> {
+    let flags = reader.read_u8()?;
+    let mut borders = Borders::default();
+    let mut has_line = false;
+    for index in 0..5 {
+        let line = reader.read_u8()?;
+        reader.skip(1)?;
+        let border = match index {
+            0 => borders.get_top_mut(),
+            1 => borders.get_bottom_mut(),
+            2 => borders.get_left_mut(),
+            3 => borders.get_right_mut(),
+            _ => borders.get_diagonal_mut(),
+        };
+        read_color(reader, border.get_color_mut())?;
+        if let Some(v) = border_style(line as u32) {
+            border.set_border_style(v);
+            has_line = true;
+        }
+    }
+    if !has_line {
+        return Ok(None);
+    }
+    if flags & 0x01 != 0 {
+        borders.set_diagonal_down(true);
+    }
+    if flags & 0x02 != 0 {
+        borders.set_diagonal_up(true);
+    }
+    Ok(Some(borders))
+}
+
+/// BrtColor. Automatic colors are left as they are.
+fn read_color(reader: &mut ByteReader, color: &mut Color) -> Result<(), XlsxError> {
+    let color_type = reader.read_u8()? >> 1;
+    let index = reader.read_u8()?;
+    let tint = reader.read_u16()? as i16;
+    let rgba = reader.read_bytes(4)?;
+    match color_type {
+        1 if index < 64 => {
+            color.set_indexed(index as u32);
+        }
+        2 => {
+            color.set_argb(format!("FF{:02X}{:02X}{:02X}", rgba[0], rgba[1], rgba[2]));
+        }
+        3 => {
+            color.set_theme_index(index as u32);
+        }
+        _ => {}
+    }
+    if tint != 0 {
+        color.set_tint(tint as f64 / 32767f64);
+    }
+    Ok(())
+}
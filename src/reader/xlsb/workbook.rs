@@ -0,0 +1,117 @@
+use super::record::*;
+use super::XlsxError;
+use crate::reader::xls::biff::ByteReader;
+use crate::reader::xls::formula::{quote_sheet_name, FormulaContext};
+
+/// BrtBundleSh record.
+#[derive(Default, Debug)]
+pub(crate) struct SheetRecord {
+    pub(crate) name: String,
+    pub(crate) rel_id: Option<String>,
+    pub(crate) state: u32,
+}
+
+/// BrtName record. The formula is decoded after the sheets are read.
+#[derive(Default, Debug)]
+pub(crate) struct NameRecord {
+    pub(crate) name: String,
+    pub(crate) is_hidden: bool,
+    /// index of the sheet for a local name.
+    pub(crate) sheet_index: Option<u32>,
+    pub(crate) rgce: Vec<u8>,
+    pub(crate) rgcb: Vec<u8>,
+}
+
+/// Workbook part.
+#[derive(Default, Debug)]
+pub(crate) struct Globals {
+    pub(crate) sheets: Vec<SheetRecord>,
+    pub(crate) names: Vec<NameRecord>,
+    pub(crate) context: FormulaContext,
+}
+
+pub(crate) fn read(stream: &[u8]) -> Result<Globals, XlsxError> {
+    let mut globals = Globals::default();
+    let mut xti_list: Vec<(u32, i32, i32)> = Vec::new();
+
+    for record in RecordReader::new(stream) {
+        let record = record?;
+        let mut reader = ByteReader::from_slice(record.data);
+        match record.record_type {
+            BRT_BUNDLE_SH => {
+                let state = reader.read_u32()?;
+                reader.skip(4)?;
+                let rel_id = read_nullable_wide_string(&mut reader)?;
+                let name = read_wide_string(&mut reader)?;
+                globals.sheets.push(SheetRecord {
+                    name,
+                    rel_id,
+                    state,
+                });
+            }
+            BRT_SUP_SELF | BRT_SUP_SAME => {
+                globals.context.self_supbooks.push(true);
+                globals.context.extern_names.push(Vec::new());
+            }
+            BRT_SUP_BOOK_SRC | BRT_SUP_ADDIN => {
+                globals.context.self_supbooks.push(false);
+                globals.context.extern_names.push(Vec::new());
+            }
+            BRT_EXTERN_SHEET => {
+                let count = reader.read_u32()?;
+                for _ in 0..count {
+                    let supbook = reader.read_u32()?;
+                    let first = reader.read_u32()? as i32;
+                    let last = reader.read_u32()? as i32;
+                    xti_list.push((supbook, first, last));
+                }
+            }
+            BRT_NAME => {
+                let flags = reader.read_u32()?;
+                reader.skip(1)?;
+                let sheet_index = match reader.read_u32()? {
+                    0xFFFF_FFFF => None,
+                    v => Some(v),
+                };
+                let name = read_wide_string(&mut reader)?;
+                let (rgce, rgcb) = read_formula(&mut reader)?;
+                globals.names.push(NameRecord {
+                    name,
+                    is_hidden: flags & 0x01 != 0,
+                    sheet_index,
+                    rgce: rgce.to_vec(),
+                    rgcb: rgcb.to_vec(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    globals.context.names = globals.names.iter().map(|v| v.name.clone()).collect();
+    for (supbook, first, last) in xti_list {
+        let is_self = globals
+            .context
+            .self_supbooks
+            .get(supbook as usize)
+            .copied()
+            .unwrap_or(false);
+        let sheet_text = match is_self && first >= 0 && last >= 0 {
+            true => {
+                let first_name = globals.sheets.get(first as usize).map(|v| v.name.as_str());
+                let last_name = globals.sheets.get(last as usize).map(|v| v.name.as_str());
+                match (first_name, last_name) {
+                    (Some(f), Some(l)) if f == l => Some(quote_sheet_name(f)),
+                    (Some(f), Some(l)) => Some(quote_sheet_name(&format!("{}:{}", f, l))),
+                    _ => None,
+                }
+            }
+            false => None,
+        };
+        globals
+            .context
+            .extern_sheets
+            .push((sheet_text, supbook as usize));
+    }
+
+    Ok(globals)
+}
@@ -0,0 +1,237 @@
+use super::formula::decode;
+use super::record::*;
+use super::XlsxError;
+use crate::helper::coordinate::coordinate_from_index;
+use crate::reader::xls::biff::{decode_rk, ByteReader};
+use crate::reader::xls::formula::{error_text, FormulaContext};
+use crate::structs::Cell;
+use crate::structs::CellFormula;
+use crate::structs::CellFormulaValues;
+use crate::structs::Style;
+use crate::structs::Worksheet;
+
+/// BrtShrFmla or BrtArrFmla record.
+struct SharedFormula {
+    rgce: Vec<u8>,
+    rgcb: Vec<u8>,
+    range: (u32, u32, u32, u32),
+    is_array: bool,
+}
+
+pub(crate) fn read(
+    stream: &[u8],
+    shared_strings: &[String],
+    styles: &[Style],
+    context: &FormulaContext,
+    worksheet: &mut Worksheet,
+) -> Result<(), XlsxError> {
+    let mut shared_formulas: Vec<SharedFormula> = Vec::new();
+    // cells holding a ptgExp token and the first row of the formula they refer to.
+    let mut exp_cells: Vec<((u32, u32), u32)> = Vec::new();
+    let mut row = 0u32;
+
+    for record in RecordReader::new(stream) {
+        let record = record?;
+        let mut reader = ByteReader::from_slice(record.data);
+        match record.record_type {
+            BRT_ROW_HDR => {
+                row = reader.read_u32()?;
+                reader.skip(4)?;
+                let height = reader.read_u16()?;
+                let flags = reader.read_u16()?;
+                let is_hidden = flags & 0x1000 != 0;
+                let is_custom_height = flags & 0x2000 != 0;
+                if is_hidden || is_custom_height {
+                    let row_dimension = worksheet.get_row_dimension_mut(&(row + 1));
+                    if is_custom_height {
+                        row_dimension.set_height(height as f64 / 20f64);
+                        row_dimension.set_custom_height(true);
+                    }
+                    if is_hidden {
+                        row_dimension.set_hidden(true);
+                    }
+                }
+            }
+            BRT_CELL_BLANK => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                get_cell(worksheet, row, col, xf, styles);
+            }
+            BRT_CELL_RK => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let value = decode_rk(reader.read_u32()?);
+                get_cell(worksheet, row, col, xf, styles).set_value_number(value);
+            }
+            BRT_CELL_ERROR => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_u8()?;
+                get_cell(worksheet, row, col, xf, styles).set_error(error_text(value));
+            }
+            BRT_CELL_BOOL => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_u8()? != 0;
+                get_cell(worksheet, row, col, xf, styles).set_value_bool(value);
+            }
+            BRT_CELL_REAL => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let value = reader.read_f64()?;
+                get_cell(worksheet, row, col, xf, styles).set_value_number(value);
+            }
+            BRT_CELL_ST => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let value = read_wide_string(&mut reader)?;
+                get_cell(worksheet, row, col, xf, styles).set_value_string(value);
+            }
+            BRT_CELL_RSTRING => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let value = read_rich_string(&mut reader)?;
+                get_cell(worksheet, row, col, xf, styles).set_value_string(value);
+            }
+            BRT_CELL_ISST => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let index = reader.read_u32()? as usize;
+                let value = shared_strings.get(index).cloned().unwrap_or_default();
+                get_cell(worksheet, row, col, xf, styles).set_value_string(value);
+            }
+            BRT_FMLA_STRING | BRT_FMLA_NUM | BRT_FMLA_BOOL | BRT_FMLA_ERROR => {
+                let (col, xf) = read_cell_header(&mut reader)?;
+                let cell = get_cell(worksheet, row, col, xf, styles);
+                match record.record_type {
+                    BRT_FMLA_STRING => {
+                        cell.set_value_string(read_wide_string(&mut reader)?);
+                    }
+                    BRT_FMLA_NUM => {
+                        cell.set_value_number(reader.read_f64()?);
+                    }
+                    BRT_FMLA_BOOL => {
+                        cell.set_value_bool(reader.read_u8()? != 0);
+                    }
+                    _ => {
+                        cell.set_error(error_text(reader.read_u8()?));
+                    }
+                }
+                reader.skip(2)?;
+                let (rgce, rgcb) = read_formula(&mut reader)?;
+
+                if rgce.len() == 5 && rgce[0] == 0x01 {
+                    let first_row = u32::from_le_bytes([rgce[1], rgce[2], rgce[3], rgce[4]]);
+                    exp_cells.push(((row, col), first_row));
+                } else if let Some(v) = decode(rgce, rgcb, (row, col), context) {
+                    cell.set_formula(v);
+                }
+            }
+            BRT_SHR_FMLA | BRT_ARR_FMLA => {
+                let row_first = reader.read_u32()?;
+                let row_last = reader.read_u32()?;
+                let col_first = reader.read_u32()?;
+                let col_last = reader.read_u32()?;
+                let is_array = record.record_type == BRT_ARR_FMLA;
+                if is_array {
+                    reader.skip(1)?;
+                }
+                let (rgce, rgcb) = read_formula(&mut reader)?;
+                shared_formulas.push(SharedFormula {
+                    rgce: rgce.to_vec(),
+                    rgcb: rgcb.to_vec(),
+                    range: (row_first, row_last, col_first, col_last),
+                    is_array,
+                });
+            }
+            BRT_COL_INFO => {
+                let col_first = reader.read_u32()?;
+                let col_last = reader.read_u32()?;
+                let width = reader.read_u32()? as f64 / 256f64;
+                reader.skip(4)?;
+                let is_hidden = reader.read_u16()? & 0x01 != 0;
+                // the last entry may cover the columns up to 16384.
+                for col in col_first..=col_last.min(16383) {
+                    let column = worksheet.get_column_dimension_by_number_mut(&(col + 1));
+                    column.set_width(width);
+                    if is_hidden {
+                        column.set_hidden(true);
+                    }
+                }
+            }
+            BRT_MERGE_CELL => {
+                let row_first = reader.read_u32()?;
+                let row_last = reader.read_u32()?;
+                let col_first = reader.read_u32()?;
+                let col_last = reader.read_u32()?;
+                worksheet.add_merge_cells(range_text(row_first, row_last, col_first, col_last));
+            }
+            _ => {}
+        }
+    }
+
+    for ((row, col), first_row) in exp_cells {
+        let shared_formula = match shared_formulas.iter().find(|v| {
+            let (row_first, row_last, col_first, col_last) = v.range;
+            row_first == first_row
+                && (row_first..=row_last).contains(&row)
+                && (col_first..=col_last).contains(&col)
+        }) {
+            Some(v) => v,
+            None => continue,
+        };
+        let (row_first, row_last, col_first, col_last) = shared_formula.range;
+        if shared_formula.is_array {
+            if (row, col) != (row_first, col_first) {
+                continue;
+            }
+            if let Some(v) = decode(
+                &shared_formula.rgce,
+                &shared_formula.rgcb,
+                (row, col),
+                context,
+            ) {
+                let mut formula = CellFormula::default();
+                formula.set_formula_type(CellFormulaValues::Array);
+                formula.set_reference(range_text(row_first, row_last, col_first, col_last));
+                formula.set_text(v);
+                worksheet
+                    .get_cell_mut((col + 1, row + 1))
+                    .get_cell_value_mut()
+                    .set_formula_obj(formula);
+            }
+        } else if let Some(v) = decode(
+            &shared_formula.rgce,
+            &shared_formula.rgcb,
+            (row, col),
+            context,
+        ) {
+            worksheet.get_cell_mut((col + 1, row + 1)).set_formula(v);
+        }
+    }
+
+    Ok(())
+}
+
+/// Column and style index of a cell record.
+#[inline]
+fn read_cell_header(reader: &mut ByteReader) -> Result<(u32, u32), XlsxError> {
+    let col = reader.read_u32()?;
+    let xf = reader.read_u32()? & 0x00FF_FFFF;
+    Ok((col, xf))
+}
+
+fn get_cell<'a>(
+    worksheet: &'a mut Worksheet,
+    row: u32,
+    col: u32,
+    xf: u32,
+    styles: &[Style],
+) -> &'a mut Cell {
+    let cell = worksheet.get_cell_mut((col + 1, row + 1));
+    if let Some(v) = styles.get(xf as usize) {
+        cell.set_style(v.clone());
+    }
+    cell
+}
+
+fn range_text(row_first: u32, row_last: u32, col_first: u32, col_last: u32) -> String {
+    let first = coordinate_from_index(&(col_first + 1), &(row_first + 1));
+    let last = coordinate_from_index(&(col_last + 1), &(row_last + 1));
+    match first == last {
+        true => first,
+        false => format!("{}:{}", first, last),
+    }
+}
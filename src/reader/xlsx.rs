@@ -8,6 +8,7 @@ use std::sync::RwLock;
 
 use super::driver;
 use crate::helper::const_str::*;
+use crate::helper::crypt::*;
 use crate::structs::drawing::Theme;
 use crate::structs::raw::RawWorksheet;
 use crate::structs::SharedStringTable;
@@ -17,24 +18,30 @@ use crate::structs::Worksheet;
 use crate::XlsxError;
 
 pub(crate) mod chart;
+pub(crate) mod chart_ex;
 pub(crate) mod comment;
 mod content_types;
 mod doc_props_app;
 mod doc_props_core;
 mod doc_props_custom;
 pub(crate) mod drawing;
+mod person;
 mod pivot_table;
 mod rels;
 mod shared_strings;
+mod streaming_reader;
 mod styles;
 pub(crate) mod table;
-mod theme;
+pub(crate) mod theme;
+mod threaded_comment;
 mod vba_project_bin;
 pub(crate) mod vml_drawing;
 mod workbook;
 mod workbook_rels;
 pub(crate) mod worksheet;
 
+pub use self::streaming_reader::{StreamingReader, StreamingRows};
+
 /// read spreadsheet from arbitrary reader.
 /// # Arguments
 /// * `reader` - reader to read from.
@@ -60,6 +67,9 @@ pub fn read_reader<R: io::Read + io::Seek>(
             let theme = theme::read(&mut arv, rel_target)?;
             book.set_theme(theme);
         }
+        if type_value == PERSON_NS {
+            person::read(&mut arv, rel_target, &mut book)?;
+        }
     }
 
     shared_strings::read(&mut arv, &mut book)?;
@@ -117,6 +127,78 @@ pub fn lazy_read(path: &Path) -> Result<Spreadsheet, XlsxError> {
     read_reader(file, false)
 }
 
+/// read password-protected spreadsheet from arbitrary reader.
+/// Files saved with "Encrypt with Password" are decrypted first, other files are read as they are.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `password` - password of the file.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message. `XlsxError::WrongPassword` is returned when the password does not match.
+pub fn read_reader_with_password<R: io::Read + io::Seek>(
+    mut reader: R,
+    password: &str,
+    with_sheet_read: bool,
+) -> Result<Spreadsheet, XlsxError> {
+    if !is_encrypted_file(&mut reader)? {
+        return read_reader(reader, with_sheet_read);
+    }
+    let data = decrypt(reader, password)?;
+    read_reader(io::Cursor::new(data), with_sheet_read)
+}
+
+/// read password-protected spreadsheet file.
+/// Both Agile encryption and Standard encryption are supported.
+/// Agile encrypted files are checked against their `dataIntegrity` HMAC.
+/// Standard encryption has no integrity data, so tampering with those files is not detected.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `password` - password of the file.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message. `XlsxError::WrongPassword` is returned when the password does not match.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa_password_standard.xlsx");
+/// let mut book = umya_spreadsheet::reader::xlsx::read_with_password(path, "password").unwrap();
+/// ```
+#[inline]
+pub fn read_with_password<P: AsRef<Path>>(
+    path: P,
+    password: &str,
+) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_with_password(file, password, true)
+}
+
+/// open spreadsheet from arbitrary reader for row-by-row reading.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is StreamingReader. Err is error message.
+#[inline]
+pub fn open_reader<R: io::Read + io::Seek>(reader: R) -> Result<StreamingReader<R>, XlsxError> {
+    StreamingReader::new(reader)
+}
+
+/// open spreadsheet file for row-by-row reading.
+/// Worksheets are not loaded; their rows are read from the file on demand.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is StreamingReader. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut book = umya_spreadsheet::reader::xlsx::open(path).unwrap();
+/// for row in book.rows("Sheet1").unwrap() {
+///     let (row, cells) = row.unwrap();
+/// }
+/// ```
+#[inline]
+pub fn open<P: AsRef<Path>>(path: P) -> Result<StreamingReader<io::BufReader<File>>, XlsxError> {
+    let file = File::open(path)?;
+    open_reader(io::BufReader::new(file))
+}
+
 pub(crate) fn raw_to_deserialize_by_worksheet(
     worksheet: &mut Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
@@ -152,10 +234,23 @@ pub(crate) fn raw_to_deserialize_by_worksheet(
                 COMMENTS_NS => {
                     comment::read(worksheet, relationship.get_raw_file()).unwrap();
                 }
+                // threaded comment
+                THREADED_COMMENT_NS => {
+                    threaded_comment::read(worksheet, relationship.get_raw_file()).unwrap();
+                }
                 // table
                 TABLE_NS => {
                     table::read(worksheet, relationship.get_raw_file()).unwrap();
                 }
+                // pivot table
+                PIVOT_TABLE_NS => {
+                    pivot_table::read(
+                        worksheet,
+                        relationship.get_raw_file(),
+                        &raw_data_of_worksheet,
+                    )
+                    .unwrap();
+                }
                 _ => {}
             }
         }
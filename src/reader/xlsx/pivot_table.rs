@@ -1,6 +1,10 @@
 use super::driver::*;
 use super::XlsxError;
+use crate::helper::const_str::*;
 use crate::structs::raw::RawFile;
+use crate::structs::raw::RawWorksheet;
+use crate::structs::PivotCacheDefinition;
+use crate::structs::PivotCacheRecords;
 use crate::structs::PivotTable;
 use crate::structs::PivotTableDefinition;
 use crate::structs::Worksheet;
@@ -11,34 +15,72 @@ use std::result;
 pub(crate) fn read(
     worksheet: &mut Worksheet,
     pivot_table_file: &RawFile,
+    raw_data_of_worksheet: &RawWorksheet,
 ) -> result::Result<(), XlsxError> {
-    let data = std::io::Cursor::new(pivot_table_file.get_file_data());
+    let mut pivot_table = PivotTable::default();
+    read_file(pivot_table_file, |reader, e| {
+        if e.name().into_inner() == b"pivotTableDefinition" {
+            let mut obj = PivotTableDefinition::default();
+            obj.set_attributes(reader, e);
+            pivot_table.set_pivot_table_definition(obj);
+        }
+    });
+
+    let cache_definition_file = raw_data_of_worksheet
+        .get_relationships_of_file(pivot_table_file)
+        .and_then(|v| {
+            v.get_relationship_list()
+                .iter()
+                .find(|relationship| relationship.get_type() == PIVOT_CACHE_DEF_NS)
+        })
+        .map(|relationship| relationship.get_raw_file());
+    if let Some(file) = cache_definition_file {
+        read_file(file, |reader, e| {
+            if e.name().into_inner() == b"pivotCacheDefinition" {
+                let mut obj = PivotCacheDefinition::default();
+                obj.set_attributes(reader, e);
+                pivot_table.set_pivot_cache_definition(obj);
+            }
+        });
+
+        let cache_records_file = raw_data_of_worksheet
+            .get_relationships_of_file(file)
+            .and_then(|v| {
+                v.get_relationship_list()
+                    .iter()
+                    .find(|relationship| relationship.get_type() == PIVOT_CACHE_RECORDS_NS)
+            })
+            .map(|relationship| relationship.get_raw_file());
+        if let Some(file) = cache_records_file {
+            read_file(file, |reader, e| {
+                if e.name().into_inner() == b"pivotCacheRecords" {
+                    let mut obj = PivotCacheRecords::default();
+                    obj.set_attributes(reader, e);
+                    pivot_table.set_pivot_cache_records(obj);
+                }
+            });
+        }
+    }
+
+    worksheet.add_pivot_table(pivot_table);
+    Ok(())
+}
+
+fn read_file<F>(file: &RawFile, mut f: F)
+where
+    F: FnMut(&mut Reader<std::io::Cursor<&[u8]>>, &quick_xml::events::BytesStart),
+{
+    let data = std::io::Cursor::new(file.get_file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
     let mut buf = Vec::new();
-    let mut pivot_table = PivotTable::default();
     loop {
         match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) => match e.name().into_inner() {
-                b"pivotTableDefinition" => {
-                    let mut obj = PivotTableDefinition::default();
-                    obj.set_attributes(&mut reader, e);
-                    pivot_table.set_pivot_table_definition(obj);
-                }
-                _ => (),
-            },
-            Ok(Event::End(ref e)) => match e.name().into_inner() {
-                b"pivotTableDefinition" => {
-                    break;
-                }
-                _ => (),
-            },
+            Ok(Event::Start(ref e)) => f(&mut reader, e),
             Ok(Event::Eof) => break,
             Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
             _ => (),
         }
         buf.clear();
     }
-    worksheet.add_pivot_table(pivot_table);
-    Ok(())
 }
@@ -0,0 +1,167 @@
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use super::driver::*;
+use super::XlsxError;
+use super::{shared_strings, styles, workbook, workbook_rels};
+use crate::helper::const_str::*;
+use crate::helper::formula::FormulaToken;
+use crate::structs::Cell;
+use crate::structs::Cells;
+use crate::structs::Row;
+use crate::structs::SharedStringTable;
+use crate::structs::Spreadsheet;
+use crate::structs::Stylesheet;
+
+/// Pull-style reader that scans worksheets row by row.
+///
+/// Only the workbook, the shared strings and the styles are loaded up front.
+/// The rows are parsed from the worksheet stream on demand, so a huge sheet
+/// can be scanned in constant memory.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut book = umya_spreadsheet::reader::xlsx::open(path).unwrap();
+/// for row in book.rows("Sheet1").unwrap() {
+///     let (row, cells) = row.unwrap();
+///     for cell in cells {
+///         let _ = cell.get_formatted_value();
+///     }
+/// }
+/// ```
+pub struct StreamingReader<R: io::Read + io::Seek> {
+    arv: zip::read::ZipArchive<R>,
+    spreadsheet: Spreadsheet,
+    sheet_targets: Vec<(String, String)>,
+}
+
+impl<R: io::Read + io::Seek> StreamingReader<R> {
+    pub(crate) fn new(reader: R) -> Result<Self, XlsxError> {
+        let mut arv = zip::read::ZipArchive::new(reader)?;
+
+        let mut spreadsheet = workbook::read(&mut arv)?;
+        let workbook_rel = workbook_rels::read(&mut arv, &mut spreadsheet)?;
+        shared_strings::read(&mut arv, &mut spreadsheet)?;
+        styles::read(&mut arv, &mut spreadsheet)?;
+
+        let mut sheet_targets: Vec<(String, String)> = Vec::new();
+        for sheet in spreadsheet.get_sheet_collection_no_check() {
+            for (rel_id, _, rel_target) in &workbook_rel {
+                if sheet.get_r_id() == rel_id {
+                    sheet_targets
+                        .push((sheet.get_name().to_string(), join_paths("xl", rel_target)));
+                }
+            }
+        }
+
+        Ok(Self {
+            arv,
+            spreadsheet,
+            sheet_targets,
+        })
+    }
+
+    /// Get the sheet names in workbook order.
+    #[inline]
+    pub fn get_sheet_names(&self) -> Vec<&str> {
+        self.sheet_targets
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Iterate over the rows of a worksheet.
+    /// Each item is the row dimension and the cells of the row, sorted by column.
+    /// Shared strings and number formats are resolved, so `Cell::get_value`
+    /// and `Cell::get_formatted_value` can be used directly.
+    /// # Arguments
+    /// * `sheet_name` - sheet name.
+    /// # Return value
+    /// * `Result` - OK is iterator of rows. Err is error message.
+    pub fn rows(&mut self, sheet_name: &str) -> Result<StreamingRows<'_>, XlsxError> {
+        let target = self
+            .sheet_targets
+            .iter()
+            .find(|(name, _)| name == sheet_name)
+            .map(|(_, target)| target.clone())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "worksheet not found"))?;
+        let file: Box<dyn io::Read + '_> = Box::new(self.arv.by_name(&target)?);
+        let mut reader = Reader::from_reader(io::BufReader::new(file));
+        reader.config_mut().trim_text(true);
+        Ok(StreamingRows {
+            reader,
+            shared_string_table: self.spreadsheet.get_shared_string_table(),
+            stylesheet: self.spreadsheet.get_stylesheet(),
+            formula_shared_list: HashMap::new(),
+            is_finished: false,
+        })
+    }
+
+    /// Iterate over the rows of a worksheet.
+    /// # Arguments
+    /// * `index` - sheet index.
+    /// # Return value
+    /// * `Result` - OK is iterator of rows. Err is error message.
+    #[inline]
+    pub fn rows_by_index(&mut self, index: &usize) -> Result<StreamingRows<'_>, XlsxError> {
+        let sheet_name = self
+            .sheet_targets
+            .get(*index)
+            .map(|(name, _)| name.clone())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "worksheet not found"))?;
+        self.rows(&sheet_name)
+    }
+}
+
+/// Iterator over the rows of a worksheet. See `StreamingReader::rows`.
+pub struct StreamingRows<'a> {
+    reader: Reader<io::BufReader<Box<dyn io::Read + 'a>>>,
+    shared_string_table: Arc<RwLock<SharedStringTable>>,
+    stylesheet: &'a Stylesheet,
+    formula_shared_list: HashMap<u32, (String, Vec<FormulaToken>)>,
+    is_finished: bool,
+}
+
+impl Iterator for StreamingRows<'_> {
+    type Item = Result<(Row, Vec<Cell>), XlsxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let (e, empty_flag) = match self.reader.read_event_into(&mut buf) {
+                Ok(Event::Start(e)) if e.name().into_inner() == b"row" => (e, false),
+                Ok(Event::Empty(e)) if e.name().into_inner() == b"row" => (e, true),
+                Ok(Event::End(ref e)) if e.name().into_inner() == b"sheetData" => break,
+                Ok(Event::Eof) => break,
+                Err(e) => {
+                    self.is_finished = true;
+                    return Some(Err(e.into()));
+                }
+                _ => continue,
+            };
+            let mut row = Row::default();
+            let mut cells = Cells::default();
+            row.set_attributes(
+                &mut self.reader,
+                &e,
+                &mut cells,
+                &self.shared_string_table.read().unwrap(),
+                self.stylesheet,
+                &mut self.formula_shared_list,
+                empty_flag,
+            );
+            let cells = cells.get_collection_sorted().into_iter().cloned().collect();
+            return Some(Ok((row, cells)));
+        }
+        self.is_finished = true;
+        None
+    }
+}
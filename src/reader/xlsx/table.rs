@@ -117,6 +117,9 @@ pub(crate) fn read(
                                     b"totalsRowShown" => {
                                         table.set_totals_row_shown_str(&attr_val);
                                     }
+                                    b"headerRowCount" => {
+                                        table.set_header_row_count_str(&attr_val);
+                                    }
                                     b"totalsRowCount" => {
                                         table.set_totals_row_count_str(&attr_val);
                                     }
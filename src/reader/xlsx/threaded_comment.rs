@@ -0,0 +1,54 @@
+use super::XlsxError;
+use crate::structs::raw::RawFile;
+use crate::structs::ThreadedComment;
+use crate::structs::Worksheet;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::result;
+
+pub(crate) fn read(
+    worksheet: &mut Worksheet,
+    threaded_comment_file: &RawFile,
+) -> result::Result<(), XlsxError> {
+    let data = std::io::Cursor::new(threaded_comment_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(false);
+
+    let mut list: Vec<ThreadedComment> = Vec::new();
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"threadedComment" {
+                let mut obj = ThreadedComment::default();
+                obj.set_attributes(&mut reader, e, true);
+                list.push(obj);
+            }
+        },
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"threadedComment" {
+                let mut obj = ThreadedComment::default();
+                obj.set_attributes(&mut reader, e, false);
+                list.push(obj);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    // replies are stored in the comment that starts the thread.
+    let (replies, threads): (Vec<_>, Vec<_>) = list
+        .into_iter()
+        .partition(|v| !v.get_parent_id().is_empty());
+    worksheet.get_threaded_comments_mut().extend(threads);
+    for reply in replies {
+        let parent = worksheet
+            .get_threaded_comments_mut()
+            .iter_mut()
+            .find(|v| v.get_id() == reply.get_parent_id());
+        if let Some(v) = parent {
+            v.get_replies_mut().push(reply);
+        }
+    }
+
+    Ok(())
+}
@@ -8,6 +8,7 @@ use crate::helper::formula::*;
 use crate::structs::office2010::excel::DataValidations as DataValidations2010;
 use crate::structs::raw::RawRelationships;
 use crate::structs::raw::RawWorksheet;
+use crate::structs::AutoFilter;
 use crate::structs::Cells;
 use crate::structs::Columns;
 use crate::structs::ConditionalFormatting;
@@ -17,6 +18,7 @@ use crate::structs::OleObjects;
 use crate::structs::Row;
 use crate::structs::SharedStringTable;
 use crate::structs::SheetProtection;
+use crate::structs::SortState;
 use crate::structs::Stylesheet;
 use crate::structs::Worksheet;
 
@@ -79,7 +81,14 @@ pub(crate) fn read(
                 worksheet.set_row_dimension(obj);
             }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), false);
+                worksheet.set_auto_filter_crate(obj);
+            }
+            b"sortState" => {
+                let mut obj = SortState::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), false);
+                worksheet.set_sort_state(obj);
             }
             b"cols" => {
                 let mut obj = Columns::default();
@@ -106,6 +115,11 @@ pub(crate) fn read(
                 obj.set_attributes(&mut reader, e);
                 worksheet.set_data_validations_2010(obj);
             }
+            b"x14:sparklineGroups" => {
+                worksheet
+                    .get_sparkline_groups_mut()
+                    .set_attributes(&mut reader, e);
+            }
             b"oleObjects" => {
                 let mut obj = OleObjects::default();
                 obj.set_attributes(
@@ -149,6 +163,11 @@ pub(crate) fn read(
                     .get_tab_color_mut()
                     .set_attributes(&mut reader, e, true);
             }
+            b"outlinePr" => {
+                worksheet
+                    .get_outline_properties_mut()
+                    .set_attributes(&mut reader, e);
+            }
             b"sheetFormatPr" => {
                 worksheet
                     .get_sheet_format_properties_mut()
@@ -179,7 +198,14 @@ pub(crate) fn read(
                 worksheet.set_row_dimension(obj);
             }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), true);
+                worksheet.set_auto_filter_crate(obj);
+            }
+            b"sortState" => {
+                let mut obj = SortState::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), true);
+                worksheet.set_sort_state(obj);
             }
             b"pageMargins" => {
                 worksheet
@@ -322,6 +322,9 @@ pub use self::chart::*;
 mod chart_type;
 pub use self::chart_type::*;
 
+mod chart_ex_type;
+pub use self::chart_ex_type::*;
+
 mod merge_cells;
 pub(crate) use self::merge_cells::*;
 
@@ -364,9 +367,18 @@ pub(crate) use self::media_object::*;
 mod csv_writer_option;
 pub use self::csv_writer_option::*;
 
+mod html_writer_option;
+pub use self::html_writer_option::*;
+
+mod csv_reader_option;
+pub use self::csv_reader_option::*;
+
 mod csv_encode_values;
 pub use self::csv_encode_values::*;
 
+mod csv_quote_values;
+pub use self::csv_quote_values::*;
+
 mod cell_raw_value;
 pub use self::cell_raw_value::*;
 
@@ -498,3 +510,90 @@ pub use self::cache_field::*;
 
 mod shared_items;
 pub use self::shared_items::*;
+
+mod sort_by_values;
+pub use self::sort_by_values::*;
+
+mod sort_condition;
+pub use self::sort_condition::*;
+
+mod sort_state;
+pub use self::sort_state::*;
+
+mod sort_key;
+pub use self::sort_key::*;
+
+mod sort_option;
+pub use self::sort_option::*;
+
+mod filter_operator_values;
+pub use self::filter_operator_values::*;
+
+mod date_time_grouping_values;
+pub use self::date_time_grouping_values::*;
+
+mod dynamic_filter_values;
+pub use self::dynamic_filter_values::*;
+
+mod filter;
+pub use self::filter::*;
+
+mod date_group_item;
+pub use self::date_group_item::*;
+
+mod filters;
+pub use self::filters::*;
+
+mod custom_filter;
+pub use self::custom_filter::*;
+
+mod custom_filters;
+pub use self::custom_filters::*;
+
+mod top10;
+pub use self::top10::*;
+
+mod dynamic_filter;
+pub use self::dynamic_filter::*;
+
+mod color_filter;
+pub use self::color_filter::*;
+
+mod filter_column;
+pub use self::filter_column::*;
+
+mod outline_properties;
+pub use self::outline_properties::*;
+
+mod person;
+pub use self::person::*;
+
+mod threaded_comment;
+pub use self::threaded_comment::*;
+
+mod threaded_comment_mention;
+pub use self::threaded_comment_mention::*;
+
+mod data_consolidate_function_values;
+pub use self::data_consolidate_function_values::*;
+
+mod pivot_table_axis_values;
+pub use self::pivot_table_axis_values::*;
+
+mod pivot_cache_value;
+pub use self::pivot_cache_value::*;
+
+mod pivot_item;
+pub use self::pivot_item::*;
+
+mod row_fields;
+pub use self::row_fields::*;
+
+mod pivot_cache_record;
+pub use self::pivot_cache_record::*;
+
+mod pivot_cache_records;
+pub use self::pivot_cache_records::*;
+
+mod pivot_table_builder;
+pub use self::pivot_table_builder::*;
@@ -1,9 +1,23 @@
+// autoFilter
+use super::DifferentialFormats;
+use super::FilterColumn;
 use super::Range;
+use super::SortState;
+use crate::reader::driver::*;
 use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct AutoFilter {
     range: Range,
+    filter_column: ThinVec<FilterColumn>,
+    sort_state: Option<SortState>,
 }
 
 impl AutoFilter {
@@ -23,6 +37,198 @@ impl AutoFilter {
         range.set_range(value.into());
         self.range = range;
     }
+
+    #[inline]
+    pub fn get_filter_column_list(&self) -> &[FilterColumn] {
+        &self.filter_column
+    }
+
+    #[inline]
+    pub fn get_filter_column_list_mut(&mut self) -> &mut ThinVec<FilterColumn> {
+        &mut self.filter_column
+    }
+
+    /// Get the criteria of a column.
+    /// # Arguments
+    /// * `column_id` - Zero based index of the column inside the range.
+    #[inline]
+    pub fn get_filter_column(&self, column_id: &u32) -> Option<&FilterColumn> {
+        self.filter_column
+            .iter()
+            .find(|v| v.get_column_id() == column_id)
+    }
+
+    /// Get the criteria of a column, creating it when missing.
+    /// # Arguments
+    /// * `column_id` - Zero based index of the column inside the range.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_auto_filter("A1:C10");
+    /// let mut filters = Filters::default();
+    /// filters.add_value("Apple");
+    /// worksheet
+    ///     .get_auto_filter_mut()
+    ///     .unwrap()
+    ///     .get_filter_column_mut(&1)
+    ///     .set_filters(filters);
+    /// ```
+    pub fn get_filter_column_mut(&mut self, column_id: &u32) -> &mut FilterColumn {
+        match self
+            .filter_column
+            .iter()
+            .position(|v| v.get_column_id() == column_id)
+        {
+            Some(index) => &mut self.filter_column[index],
+            None => {
+                let mut obj = FilterColumn::default();
+                obj.set_column_id(*column_id);
+                self.filter_column.push(obj);
+                self.filter_column.last_mut().unwrap()
+            }
+        }
+    }
+
+    #[inline]
+    pub fn add_filter_column(&mut self, value: FilterColumn) -> &mut Self {
+        self.filter_column.push(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_filter_column(&mut self, column_id: &u32) -> &mut Self {
+        self.filter_column
+            .retain(|v| v.get_column_id() != column_id);
+        self
+    }
+
+    /// Sort state of the filtered data.
+    #[inline]
+    pub fn get_sort_state(&self) -> Option<&SortState> {
+        self.sort_state.as_ref()
+    }
+
+    #[inline]
+    pub fn get_sort_state_mut(&mut self) -> Option<&mut SortState> {
+        self.sort_state.as_mut()
+    }
+
+    #[inline]
+    pub fn set_sort_state(&mut self, value: SortState) -> &mut Self {
+        self.sort_state = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_sort_state(&mut self) -> &mut Self {
+        self.sort_state = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) {
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.set_range(v);
+        }
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"filterColumn" => {
+                        let mut obj = FilterColumn::default();
+                        obj.set_attributes(reader, e, differential_formats, false);
+                        self.filter_column.push(obj);
+                    }
+                    b"sortState" => {
+                        let mut obj = SortState::default();
+                        obj.set_attributes(reader, e, differential_formats, false);
+                        self.sort_state = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filterColumn" => {
+                        let mut obj = FilterColumn::default();
+                        obj.set_attributes(reader, e, differential_formats, true);
+                        self.filter_column.push(obj);
+                    }
+                    b"sortState" => {
+                        let mut obj = SortState::default();
+                        obj.set_attributes(reader, e, differential_formats, true);
+                        self.sort_state = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"autoFilter" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "autoFilter")
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        let is_inner = !self.filter_column.is_empty() || self.sort_state.is_some();
+
+        // autoFilter
+        let reference = self.range.get_range();
+        write_start_tag(writer, "autoFilter", vec![("ref", &reference)], !is_inner);
+
+        if is_inner {
+            // filterColumn
+            for v in &self.filter_column {
+                v.write_to(writer, differential_formats);
+            }
+
+            // sortState
+            if let Some(v) = &self.sort_state {
+                v.write_to(writer, differential_formats);
+            }
+
+            write_end_tag(writer, "autoFilter");
+        }
+    }
+
+    #[inline]
+    fn get_start_col_num(&self) -> u32 {
+        self.range
+            .get_coordinate_start_col()
+            .map_or(1, |v| *v.get_num())
+    }
+
+    /// Keep the column ids pointing at the same columns after the range moved.
+    /// `adjust` maps an absolute column number to its new one, or None when the column is gone.
+    fn adjustment_column_id<F: Fn(u32) -> Option<u32>>(&mut self, old_start_col: u32, adjust: F) {
+        let new_start_col = self.get_start_col_num();
+        self.filter_column
+            .retain_mut(|v| match adjust(old_start_col + v.get_column_id()) {
+                Some(num) if num >= new_start_col => {
+                    v.set_column_id(num - new_start_col);
+                    true
+                }
+                _ => false,
+            });
+    }
 }
 impl AdjustmentCoordinate for AutoFilter {
     #[inline]
@@ -33,12 +239,27 @@ impl AdjustmentCoordinate for AutoFilter {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
+        let old_start_col = self.get_start_col_num();
         self.range.adjustment_insert_coordinate(
             root_col_num,
             offset_col_num,
             root_row_num,
             offset_row_num,
         );
+        if offset_col_num != &0 {
+            self.adjustment_column_id(old_start_col, |num| match num >= *root_col_num {
+                true => Some(num + offset_col_num),
+                false => Some(num),
+            });
+        }
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     #[inline]
@@ -49,11 +270,31 @@ impl AdjustmentCoordinate for AutoFilter {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
+        let old_start_col = self.get_start_col_num();
         self.range.adjustment_remove_coordinate(
             root_col_num,
             offset_col_num,
             root_row_num,
             offset_row_num,
         );
+        if offset_col_num != &0 {
+            self.adjustment_column_id(old_start_col, |num| {
+                if num >= root_col_num + offset_col_num {
+                    Some(num - offset_col_num)
+                } else if num >= *root_col_num {
+                    None
+                } else {
+                    Some(num)
+                }
+            });
+        }
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
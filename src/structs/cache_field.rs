@@ -60,7 +60,14 @@ impl CacheField {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"sharedItems" {
                     let mut obj = SharedItems::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e, true);
+                    self.set_shared_items(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"sharedItems" {
+                    let mut obj = SharedItems::default();
+                    obj.set_attributes(reader, e, false);
                     self.set_shared_items(obj);
                 }
             },
@@ -74,10 +81,10 @@ impl CacheField {
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // pivotField
+        // cacheField
         write_start_tag(
             writer,
-            "pivotField",
+            "cacheField",
             vec![
                 ("name", self.name.get_value_str()),
                 ("numFmtId", &self.number_format_id.get_value_string()),
@@ -88,6 +95,6 @@ impl CacheField {
         // sharedItems
         self.shared_items.write_to(writer);
 
-        write_end_tag(writer, "pivotField");
+        write_end_tag(writer, "cacheField");
     }
 }
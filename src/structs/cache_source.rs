@@ -74,7 +74,7 @@ impl CacheSource {
         // cacheSource
         let empty_flg = self.worksheet_source.is_none();
         let mut attributes: Vec<(&str, &str)> = Vec::new();
-        attributes.push(("type", self.r#type.get_hash_string()));
+        attributes.push(("type", self.r#type.get_value_string()));
         write_start_tag(writer, "cacheSource", attributes, empty_flg);
 
         if !empty_flg {
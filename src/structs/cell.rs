@@ -1,5 +1,6 @@
 use crate::helper::coordinate::*;
 use crate::helper::formula::*;
+use crate::helper::html::{richtext_to_html, text_to_html};
 use crate::helper::number_format::*;
 use crate::reader::driver::*;
 use crate::structs::CellFormula;
@@ -326,6 +327,24 @@ impl Cell {
         result
     }
 
+    /// Get the value as HTML.
+    /// Rich text runs become `<span>` elements styled with their font,
+    /// other values are the escaped formatted value.
+    /// # Return value
+    /// * `String`
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    /// let html = sheet.get_cell_mut("A1").get_value_html();
+    /// ```
+    pub fn get_value_html(&self) -> String {
+        match self.get_raw_value() {
+            CellRawValue::RichText(rich_text) => richtext_to_html(rich_text),
+            _ => text_to_html(&self.get_formatted_value()),
+        }
+    }
+
     // When opened in software such as Excel, it is visually blank.
     #[inline]
     pub(crate) fn is_visually_empty(&self) -> bool {
@@ -450,10 +469,12 @@ impl Cell {
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
-        shared_string_table: &RwLock<SharedStringTable>,
+        shared_string_table: Option<&RwLock<SharedStringTable>>,
         stylesheet: &mut Stylesheet,
         formula_shared_list: &HashMap<&u32, (String, Option<String>)>,
     ) {
+        // without a shared string table, strings are written inline.
+        let is_inline_string = shared_string_table.is_none() && self.get_data_type_crate() == "s";
         let empty_flag_value = self.cell_value.is_empty();
         let empty_flag_style = self.style.is_empty();
         if empty_flag_value && empty_flag_style {
@@ -464,7 +485,9 @@ impl Cell {
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let coordinate = self.coordinate.to_string();
         attributes.push(("r", &coordinate));
-        if self.get_data_type_crate() == "s"
+        if is_inline_string {
+            attributes.push(("t", "inlineStr"));
+        } else if self.get_data_type_crate() == "s"
             || self.get_data_type_crate() == "b"
             || self.get_data_type_crate() == "str"
             || self.get_data_type_crate() == "e"
@@ -498,8 +521,17 @@ impl Cell {
             None => {}
         }
 
-        // v
-        if self.cell_value.is_value_empty() {
+        // is / v
+        if is_inline_string {
+            write_start_tag(writer, "is", vec![], false);
+            if let Some(v) = self.cell_value.get_text() {
+                v.write_to(writer);
+            }
+            if let Some(v) = self.cell_value.get_rich_text() {
+                v.write_to_none(writer);
+            }
+            write_end_tag(writer, "is");
+        } else if self.cell_value.is_value_empty() {
             write_start_tag(writer, "v", vec![], true);
         } else {
             write_start_tag(writer, "v", vec![], false);
@@ -508,6 +540,7 @@ impl Cell {
             match self.get_data_type_crate() {
                 "s" => {
                     let val_index = shared_string_table
+                        .unwrap()
                         .write()
                         .unwrap()
                         .set_cell(self.get_cell_value());
@@ -522,8 +555,7 @@ impl Cell {
                     write_text_node(writer, prm);
                 }
                 "e" => {
-                    let prm = "#VALUE!";
-                    write_text_node(writer, prm);
+                    write_text_node(writer, self.get_value());
                 }
                 _ => write_text_node_conversion(writer, self.get_value()),
             }
@@ -28,7 +28,12 @@ impl CellValue {
     #[inline]
     pub(crate) fn get_data_type_crate(&self) -> &str {
         match &self.formula {
-            Some(_) => "str",
+            Some(_) => match self.raw_value {
+                CellRawValue::Numeric(_) => "n",
+                CellRawValue::Bool(_) => "b",
+                CellRawValue::Error(_) => "e",
+                _ => "str",
+            },
             None => self.raw_value.get_data_type(),
         }
     }
@@ -83,6 +88,12 @@ impl CellValue {
         self
     }
 
+    #[inline]
+    pub(crate) fn set_raw_value_crate(&mut self, value: CellRawValue) -> &mut Self {
+        self.raw_value = value;
+        self
+    }
+
     #[inline]
     pub fn set_value_lazy<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.raw_value = CellRawValue::Lazy(value.into().into_boxed_str());
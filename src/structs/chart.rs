@@ -4,29 +4,43 @@ use crate::structs::drawing::charts::AreaChartSeries;
 use crate::structs::drawing::charts::AreaChartSeriesList;
 use crate::structs::drawing::charts::AxisId;
 use crate::structs::drawing::charts::AxisPositionValues;
+use crate::structs::drawing::charts::BandFormats;
 use crate::structs::drawing::charts::Bar3DChart;
 use crate::structs::drawing::charts::BarChart;
 use crate::structs::drawing::charts::BarDirectionValues;
 use crate::structs::drawing::charts::Bubble3D;
 use crate::structs::drawing::charts::BubbleChart;
 use crate::structs::drawing::charts::BubbleSize;
+use crate::structs::drawing::charts::BuiltInUnit;
+use crate::structs::drawing::charts::BuiltInUnitValues;
 use crate::structs::drawing::charts::CategoryAxis;
 use crate::structs::drawing::charts::CategoryAxisData;
 use crate::structs::drawing::charts::ChartSpace;
 use crate::structs::drawing::charts::ChartText;
 use crate::structs::drawing::charts::CrossBetweenValues;
+use crate::structs::drawing::charts::CrossesAt;
+use crate::structs::drawing::charts::CrossesValues;
+use crate::structs::drawing::charts::DateAxis;
 use crate::structs::drawing::charts::DisplayBlanksAsValues;
+use crate::structs::drawing::charts::DisplayUnits;
+use crate::structs::drawing::charts::DisplayUnitsLabel;
 use crate::structs::drawing::charts::DoughnutChart;
+use crate::structs::drawing::charts::DownBars;
+use crate::structs::drawing::charts::GapWidth;
 use crate::structs::drawing::charts::GroupingValues;
+use crate::structs::drawing::charts::HighLowLines;
 use crate::structs::drawing::charts::InvertIfNegative;
 use crate::structs::drawing::charts::Layout;
 use crate::structs::drawing::charts::LegendPositionValues;
 use crate::structs::drawing::charts::Line3DChart;
 use crate::structs::drawing::charts::LineChart;
 use crate::structs::drawing::charts::MajorGridlines;
+use crate::structs::drawing::charts::Marker;
+use crate::structs::drawing::charts::MarkerStyleValues;
 use crate::structs::drawing::charts::OfPieChart;
 use crate::structs::drawing::charts::OfPieValues;
 use crate::structs::drawing::charts::OrientationValues;
+use crate::structs::drawing::charts::Perspective;
 use crate::structs::drawing::charts::Pie3DChart;
 use crate::structs::drawing::charts::PieChart;
 use crate::structs::drawing::charts::PlotArea;
@@ -36,31 +50,47 @@ use crate::structs::drawing::charts::RightAngleAxes;
 use crate::structs::drawing::charts::RotateX;
 use crate::structs::drawing::charts::RotateY;
 use crate::structs::drawing::charts::ScatterChart;
+use crate::structs::drawing::charts::SeriesAxis;
 use crate::structs::drawing::charts::SeriesText;
+use crate::structs::drawing::charts::ShapeProperties;
 use crate::structs::drawing::charts::ShapeValues;
 use crate::structs::drawing::charts::ShowLeaderLines;
 use crate::structs::drawing::charts::Smooth;
+use crate::structs::drawing::charts::StockChart;
 use crate::structs::drawing::charts::StringLiteral;
 use crate::structs::drawing::charts::StringPoint;
 use crate::structs::drawing::charts::StringReference;
+use crate::structs::drawing::charts::Surface3DChart;
+use crate::structs::drawing::charts::SurfaceChart;
+use crate::structs::drawing::charts::Symbol;
 use crate::structs::drawing::charts::TextProperties;
 use crate::structs::drawing::charts::TickLabelPositionValues;
 use crate::structs::drawing::charts::TickMarkValues;
+use crate::structs::drawing::charts::TimeUnitValues;
 use crate::structs::drawing::charts::Title;
+use crate::structs::drawing::charts::UpBars;
+use crate::structs::drawing::charts::UpDownBars;
 use crate::structs::drawing::charts::ValueAxis;
 use crate::structs::drawing::charts::Values;
 use crate::structs::drawing::charts::View3D;
+use crate::structs::drawing::charts::Wireframe;
 use crate::structs::drawing::charts::XValues;
 use crate::structs::drawing::charts::YValues;
 use crate::structs::drawing::spreadsheet::GraphicFrame;
 use crate::structs::drawing::spreadsheet::MarkerType;
 use crate::structs::drawing::spreadsheet::TwoCellAnchor;
+use crate::structs::drawing::NoFill;
+use crate::structs::drawing::Outline;
 use crate::structs::drawing::Paragraph;
 use crate::structs::drawing::Run;
 use crate::structs::drawing::RunProperties;
+use crate::structs::drawing::SchemeColor;
+use crate::structs::drawing::SchemeColorValues;
+use crate::structs::drawing::SolidFill;
 use crate::structs::ChartType;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWithSheet;
+use std::collections::HashSet;
 
 /// ## Supported chart types
 /// * AreaChart
@@ -75,6 +105,9 @@ use crate::traits::AdjustmentCoordinateWithSheet;
 /// * PieChart
 /// * RadarChart
 /// * ScatterChart
+/// * StockChart
+/// * SurfaceChart
+/// * Surface3DChart
 ///
 /// Other types will be supported sequentially.
 ///
@@ -162,18 +195,17 @@ impl Chart {
     pub fn set_vertical_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let title = self.make_title(value);
         let plot_area = self.get_plot_area_mut();
-        match plot_area.get_value_axis_mut().len() {
-            1 => {
-                if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
-                    v.set_title(title);
-                }
-            }
-            2 => {
-                if let Some(v) = plot_area.get_value_axis_mut().get_mut(1) {
-                    v.set_title(title);
-                }
-            }
-            _ => {}
+        let index = match (
+            plot_area.get_category_axis().is_empty() && plot_area.get_date_axis().is_empty(),
+            plot_area.get_value_axis().len(),
+        ) {
+            (_, 1) => 0,
+            (true, 2) => 1,
+            (false, _) => 0,
+            _ => return self,
+        };
+        if let Some(v) = plot_area.get_value_axis_mut().get_mut(index) {
+            v.set_title(title);
         }
         self
     }
@@ -181,28 +213,23 @@ impl Chart {
     pub fn set_horizontal_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let title = self.make_title(value);
         let plot_area = self.get_plot_area_mut();
-        match plot_area.get_value_axis_mut().len() {
-            1 => {
-                if let Some(v) = plot_area.get_category_axis_mut().get_mut(0) {
-                    v.set_title(title);
-                }
+        if let Some(v) = plot_area.get_date_axis_mut().get_mut(0) {
+            v.set_title(title);
+        } else if !plot_area.get_category_axis().is_empty() {
+            if let Some(v) = plot_area.get_category_axis_mut().get_mut(0) {
+                v.set_title(title);
             }
-            2 => {
-                if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
-                    v.set_title(title);
-                }
+        } else if plot_area.get_value_axis().len() == 2 {
+            if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
+                v.set_title(title);
             }
-            _ => {}
         }
         self
     }
 
     pub fn set_series_title<S: Into<String>>(&mut self, value: Vec<S>) -> &mut Self {
         let mut value_iter = value.into_iter().map(Into::into);
-        for series in self
-            .get_area_chart_series_list_mut()
-            .get_area_chart_series_mut()
-        {
+        for series in self.get_area_chart_series_all_mut() {
             let value_raw = value_iter.next();
             if let Some(v) = value_raw {
                 let mut series_text = SeriesText::default();
@@ -220,10 +247,7 @@ impl Chart {
             string_point.get_numeric_value_mut().set_text(v);
             string_literal.add_string_point_list(string_point);
         }
-        for series in self
-            .get_area_chart_series_list_mut()
-            .get_area_chart_series_mut()
-        {
+        for series in self.get_area_chart_series_all_mut() {
             match series.get_category_axis_data_mut() {
                 Some(v) => {
                     v.remove_string_reference();
@@ -239,6 +263,362 @@ impl Chart {
         self
     }
 
+    /// Add a chart group to the plot area of a chart made by `new_chart`.
+    /// This makes combination charts such as bars with a line on a secondary axis.
+    /// Supported chart types are LineChart, BarChart and AreaChart,
+    /// and each chart type can only appear once in a plot area.
+    /// The series are numbered after the series already in the plot area.
+    /// # Arguments
+    /// * `chart_type` - chart type of the group.
+    /// * `area_chart_series_list` - series of the group.
+    /// * `secondary_axis` - plot the group on the secondary value axis.
+    /// # Return value
+    /// * `Result<&mut Self, &'static str>` - Err when the chart type is not supported,
+    /// the chart type is already in the plot area, or the plot area has no axes.
+    /// # Examples
+    /// ```
+    /// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("J15");
+    /// let mut chart = umya_spreadsheet::structs::Chart::default();
+    /// chart
+    ///     .new_chart(
+    ///         umya_spreadsheet::structs::ChartType::BarChart,
+    ///         from_marker,
+    ///         to_marker,
+    ///         vec!["Sheet1!$A$1:$A$10"],
+    ///     )
+    ///     .add_chart_group(
+    ///         umya_spreadsheet::structs::ChartType::LineChart,
+    ///         vec!["Sheet1!$B$1:$B$10"],
+    ///         true,
+    ///     )
+    ///     .unwrap()
+    ///     .set_series_title(vec!["Revenue", "Margin %"])
+    ///     .set_secondary_vertical_title("Margin %");
+    /// ```
+    pub fn add_chart_group(
+        &mut self,
+        chart_type: ChartType,
+        area_chart_series_list: Vec<&str>,
+        secondary_axis: bool,
+    ) -> Result<&mut Self, &'static str> {
+        let plot_area = self.get_chart_space().get_chart().get_plot_area();
+        let is_exists = match chart_type {
+            ChartType::LineChart => plot_area.get_line_chart().is_some(),
+            ChartType::BarChart => plot_area.get_bar_chart().is_some(),
+            ChartType::AreaChart => plot_area.get_area_chart().is_some(),
+            _ => return Err("Unsupported chart group."),
+        };
+        if is_exists {
+            return Err("The chart group exists.");
+        }
+        let (category_axis_id, value_axis_id) = if secondary_axis {
+            self.make_secondary_axis()
+        } else {
+            let category_axis_id = match plot_area.get_date_axis().first() {
+                Some(v) => v.get_axis_id(),
+                None => plot_area
+                    .get_category_axis()
+                    .first()
+                    .ok_or("Non-CategoryAxis.")?
+                    .get_axis_id(),
+            };
+            (
+                *category_axis_id.get_val(),
+                *plot_area
+                    .get_value_axis()
+                    .first()
+                    .ok_or("Non-ValueAxis.")?
+                    .get_axis_id()
+                    .get_val(),
+            )
+        };
+        let mut axis_id1 = AxisId::default();
+        axis_id1.set_val(category_axis_id);
+        let mut axis_id2 = AxisId::default();
+        axis_id2.set_val(value_axis_id);
+
+        let mut index = self
+            .get_area_chart_series_all_mut()
+            .iter()
+            .map(|v| *v.get_index().get_val() + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut chart = Chart::default();
+        chart.default_language.clone_from(&self.default_language);
+        match chart_type {
+            ChartType::LineChart => chart.new_chart_line_chart(area_chart_series_list),
+            ChartType::BarChart => chart.new_chart_bar_chart(area_chart_series_list),
+            ChartType::AreaChart => chart.new_chart_area_chart(area_chart_series_list),
+            _ => unreachable!(),
+        }
+        for series in chart
+            .get_area_chart_series_list_mut()
+            .get_area_chart_series_mut()
+        {
+            series.get_index_mut().set_val(index);
+            series.get_order_mut().set_val(index);
+            index += 1;
+        }
+
+        let source = chart.get_chart_space().get_chart().get_plot_area();
+        let plot_area = self.get_plot_area_mut();
+        match chart_type {
+            ChartType::LineChart => {
+                let mut obj = source.get_line_chart().unwrap().clone();
+                obj.get_grouping_mut().set_val(GroupingValues::Standard);
+                obj.set_axis_id(vec![axis_id1, axis_id2]);
+                plot_area.set_line_chart(obj);
+            }
+            ChartType::BarChart => {
+                let mut obj = source.get_bar_chart().unwrap().clone();
+                obj.set_axis_id(vec![axis_id1, axis_id2]);
+                plot_area.set_bar_chart(obj);
+            }
+            ChartType::AreaChart => {
+                let mut obj = source.get_area_chart().unwrap().clone();
+                obj.set_axis_id(vec![axis_id1, axis_id2]);
+                plot_area.set_area_chart(obj);
+            }
+            _ => unreachable!(),
+        }
+        Ok(self)
+    }
+
+    /// Get the secondary value axis made by `add_chart_group`.
+    pub fn get_secondary_value_axis_mut(&mut self) -> Option<&mut ValueAxis> {
+        let plot_area = self.get_plot_area_mut();
+        if plot_area.get_category_axis().len() + plot_area.get_date_axis().len() < 2 {
+            return None;
+        }
+        plot_area.get_value_axis_mut().get_mut(1)
+    }
+
+    /// Set the position of the secondary value axis.
+    /// # Arguments
+    /// * `value` - position. Right by default.
+    pub fn set_secondary_axis_position(&mut self, value: AxisPositionValues) -> &mut Self {
+        if let Some(v) = self.get_secondary_value_axis_mut() {
+            v.get_axis_position_mut().set_val(value);
+        }
+        self
+    }
+
+    /// Set where the secondary value axis crosses the category axis.
+    /// # Arguments
+    /// * `value` - crossing point. Maximum by default.
+    pub fn set_secondary_axis_crosses(&mut self, value: CrossesValues) -> &mut Self {
+        if let Some(v) = self.get_secondary_value_axis_mut() {
+            v.remove_crosses_at();
+            v.get_crosses_mut().set_val(value);
+        }
+        self
+    }
+
+    /// Set where the primary category axis crosses the primary value axis.
+    /// # Arguments
+    /// * `value` - value on the primary value axis.
+    pub fn set_axis_crosses_at(&mut self, value: f64) -> &mut Self {
+        let mut crosses_at = CrossesAt::default();
+        crosses_at.set_val(value);
+        let plot_area = self.get_plot_area_mut();
+        if let Some(v) = plot_area.get_date_axis_mut().get_mut(0) {
+            v.set_crosses_at(crosses_at);
+        } else if let Some(v) = plot_area.get_category_axis_mut().get_mut(0) {
+            v.set_crosses_at(crosses_at);
+        }
+        self
+    }
+
+    pub fn set_secondary_vertical_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let title = self.make_title(value);
+        if let Some(v) = self.get_secondary_value_axis_mut() {
+            v.set_title(title);
+        }
+        self
+    }
+
+    /// Set the category cells of every series.
+    /// When the cells hold dates, the category axis is laid out as a date axis on save.
+    /// # Arguments
+    /// * `value` - address of the category cells. ex) "Sheet1!$A$2:$A$13"
+    pub fn set_category_address<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut string_reference = StringReference::default();
+        string_reference
+            .get_formula_mut()
+            .set_address_str(&value.into());
+        for series in self.get_area_chart_series_all_mut() {
+            let mut obj = CategoryAxisData::default();
+            obj.set_string_reference(string_reference.clone());
+            series.set_category_axis_data(obj);
+        }
+        self
+    }
+
+    /// Turn the primary category axis into a date axis.
+    /// # Arguments
+    /// * `base_time_unit` - the smallest time unit the category dates are grouped by.
+    /// # Examples
+    /// ```
+    /// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("J15");
+    /// let mut chart = umya_spreadsheet::structs::Chart::default();
+    /// chart
+    ///     .new_chart(
+    ///         umya_spreadsheet::structs::ChartType::LineChart,
+    ///         from_marker,
+    ///         to_marker,
+    ///         vec!["Sheet1!$B$2:$B$13"],
+    ///     )
+    ///     .set_category_address("Sheet1!$A$2:$A$13")
+    ///     .set_date_axis(umya_spreadsheet::drawing::charts::TimeUnitValues::Months);
+    /// ```
+    pub fn set_date_axis(&mut self, base_time_unit: TimeUnitValues) -> &mut Self {
+        let plot_area = self.get_plot_area_mut();
+        if plot_area.get_category_axis().is_empty() || !plot_area.get_date_axis().is_empty() {
+            return self;
+        }
+        let category_axis = plot_area.get_category_axis_mut().remove(0);
+        let date_axis = DateAxis::from_category_axis(&category_axis, base_time_unit);
+        plot_area.get_date_axis_mut().insert(0, date_axis);
+        self
+    }
+
+    /// Display the values of the primary value axis in a unit such as thousands or millions.
+    /// The unit name is shown as a label next to the axis.
+    /// # Arguments
+    /// * `value` - display unit.
+    pub fn set_vertical_display_units(&mut self, value: BuiltInUnitValues) -> &mut Self {
+        let mut built_in_unit = BuiltInUnit::default();
+        built_in_unit.set_val(value);
+        let mut display_units = DisplayUnits::default();
+        display_units.set_built_in_unit(built_in_unit);
+        display_units.set_display_units_label(DisplayUnitsLabel::default());
+        if let Some(v) = self.get_plot_area_mut().get_value_axis_mut().get_mut(0) {
+            v.set_display_units(display_units);
+        }
+        self
+    }
+
+    fn get_area_chart_series_all_mut(&mut self) -> Vec<&mut AreaChartSeries> {
+        let mut result: Vec<&mut AreaChartSeries> = self
+            .get_plot_area_mut()
+            .get_area_chart_series_lists_mut()
+            .into_iter()
+            .flat_map(|v| v.get_area_chart_series_mut().iter_mut())
+            .collect();
+        result.sort_by_key(|v| *v.get_index().get_val());
+        result
+    }
+
+    /// The secondary category axis is deleted so that the groups share the categories.
+    fn make_secondary_axis(&mut self) -> (u32, u32) {
+        let plot_area = self.get_plot_area_mut();
+        if plot_area.get_category_axis().len() + plot_area.get_date_axis().len() > 1 {
+            if let (Some(category_axis), Some(value_axis)) = (
+                plot_area.get_category_axis().last(),
+                plot_area.get_value_axis().get(1),
+            ) {
+                return (
+                    *category_axis.get_axis_id().get_val(),
+                    *value_axis.get_axis_id().get_val(),
+                );
+            }
+        }
+        let axis_id_list: HashSet<u32> = plot_area
+            .get_category_axis()
+            .iter()
+            .map(|v| *v.get_axis_id().get_val())
+            .chain(
+                plot_area
+                    .get_date_axis()
+                    .iter()
+                    .map(|v| *v.get_axis_id().get_val()),
+            )
+            .chain(
+                plot_area
+                    .get_value_axis()
+                    .iter()
+                    .map(|v| *v.get_axis_id().get_val()),
+            )
+            .chain(
+                plot_area
+                    .get_series_axis()
+                    .iter()
+                    .map(|v| *v.get_axis_id().get_val()),
+            )
+            .collect();
+        let mut unused_ids = (508154624..).filter(|v| !axis_id_list.contains(v));
+        let value_axis_id = unused_ids.next().unwrap();
+        let category_axis_id = unused_ids.next().unwrap();
+
+        let mut category_axis = CategoryAxis::default();
+        category_axis.get_axis_id_mut().set_val(category_axis_id);
+        category_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        category_axis.get_delete_mut().set_val(true);
+        category_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Bottom);
+        category_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        category_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        category_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        category_axis
+            .get_tick_crossing_axis_mut()
+            .set_val(value_axis_id);
+        category_axis.get_auto_labeled_mut().set_val(true);
+        category_axis.get_label_offset_mut().set_val(100);
+
+        let mut value_axis = ValueAxis::default();
+        value_axis.get_axis_id_mut().set_val(value_axis_id);
+        value_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        value_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Right);
+        value_axis
+            .get_numbering_format_mut()
+            .set_format_code("General");
+        value_axis
+            .get_numbering_format_mut()
+            .set_source_linked(true);
+        value_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        value_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        value_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        value_axis
+            .get_tick_crossing_axis_mut()
+            .set_val(category_axis_id);
+        value_axis.get_crosses_mut().set_val(CrossesValues::Maximum);
+        value_axis
+            .get_cross_between_mut()
+            .set_val(CrossBetweenValues::Between);
+
+        plot_area.add_category_axis(category_axis);
+        plot_area.add_value_axis(value_axis);
+        (category_axis_id, value_axis_id)
+    }
+
     #[inline]
     pub fn get_plot_area_mut(&mut self) -> &mut PlotArea {
         self.get_chart_space_mut()
@@ -344,6 +724,15 @@ impl Chart {
             ChartType::ScatterChart => {
                 self.new_chart_scatter_chart(area_chart_series_list);
             }
+            ChartType::StockChart => {
+                self.new_chart_stock_chart(area_chart_series_list);
+            }
+            ChartType::SurfaceChart => {
+                self.new_chart_surface_chart(area_chart_series_list, false);
+            }
+            ChartType::Surface3DChart => {
+                self.new_chart_surface_chart(area_chart_series_list, true);
+            }
         }
 
         self
@@ -2160,6 +2549,396 @@ impl Chart {
     }
 
     #[inline]
+    /// Series of a stock chart are drawn by the high-low lines and the up-down bars only.
+    /// Four series make an open-high-low-close chart with up-down bars (candlestick),
+    /// otherwise a high-low-close chart.
+    pub(crate) fn new_chart_stock_chart(&mut self, area_chart_series_list: Vec<&str>) {
+        let is_open_high_low_close = area_chart_series_list.len() == 4;
+        let mut acsl_obj = self.convert_series(area_chart_series_list, true);
+        let series_count = acsl_obj.get_area_chart_series().len();
+        for (idx, acs_obj) in acsl_obj.get_area_chart_series_mut().iter_mut().enumerate() {
+            let mut outline = Outline::default();
+            outline.set_width(19050);
+            outline.set_no_fill(NoFill::default());
+            let mut shape_properties = ShapeProperties::default();
+            shape_properties.set_outline(outline);
+            acs_obj.set_shape_properties(shape_properties);
+
+            // high-low-close charts mark the close
+            let mut symbol = Symbol::default();
+            if !is_open_high_low_close && idx + 1 == series_count {
+                symbol.set_val(MarkerStyleValues::Dot);
+            } else {
+                symbol.set_val(MarkerStyleValues::None);
+            }
+            let mut marker = Marker::default();
+            marker.set_symbol(symbol);
+            acs_obj.set_marker(marker);
+        }
+
+        let mut axis_id1 = AxisId::default();
+        axis_id1.set_val(213468160);
+
+        let mut axis_id2 = AxisId::default();
+        axis_id2.set_val(169590080);
+
+        let mut stock_chart = StockChart::default();
+        stock_chart.set_area_chart_series_list(acsl_obj);
+        stock_chart.set_high_low_lines(HighLowLines::default());
+        if is_open_high_low_close {
+            let mut up_bars = UpBars::default();
+            up_bars.set_shape_properties(Self::make_solid_fill(SchemeColorValues::Light1));
+            let mut down_bars = DownBars::default();
+            down_bars.set_shape_properties(Self::make_solid_fill(SchemeColorValues::Dark1));
+            let mut gap_width = GapWidth::default();
+            gap_width.set_val(150);
+            let mut up_down_bars = UpDownBars::default();
+            up_down_bars.set_gap_width(gap_width);
+            up_down_bars.set_up_bars(up_bars);
+            up_down_bars.set_down_bars(down_bars);
+            stock_chart.set_up_down_bars(up_down_bars);
+        }
+        stock_chart.add_axis_id(axis_id1);
+        stock_chart.add_axis_id(axis_id2);
+
+        let mut category_axis = CategoryAxis::default();
+        category_axis.get_axis_id_mut().set_val(213468160);
+        category_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        category_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Bottom);
+        category_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        category_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        category_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::Low);
+        category_axis
+            .get_tick_crossing_axis_mut()
+            .set_val(169590080);
+        category_axis.get_auto_labeled_mut().set_val(true);
+        category_axis.get_label_offset_mut().set_val(100);
+
+        let major_gridlines = MajorGridlines::default();
+
+        let mut value_axis = ValueAxis::default();
+        value_axis.get_axis_id_mut().set_val(169590080);
+        value_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        value_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Left);
+        value_axis.set_major_gridlines(major_gridlines);
+        value_axis
+            .get_numbering_format_mut()
+            .set_format_code("General");
+        value_axis
+            .get_numbering_format_mut()
+            .set_source_linked(true);
+        value_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        value_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        value_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        value_axis.get_tick_crossing_axis_mut().set_val(213468160);
+        value_axis
+            .get_cross_between_mut()
+            .set_val(CrossBetweenValues::Between);
+
+        let mut graphic_frame = GraphicFrame::default();
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_editing_language_mut()
+            .set_val(&self.default_language);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .set_stock_chart(stock_chart);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_category_axis(category_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_value_axis(value_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_legend_mut()
+            .get_legend_position_mut()
+            .set_val(LegendPositionValues::Right);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_visible_only_mut()
+            .set_val(true);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_display_blanks_as_mut()
+            .set_val(DisplayBlanksAsValues::Gap);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .set_print_settings(self.make_print_settings());
+        self.two_cell_anchor.set_graphic_frame(graphic_frame);
+    }
+
+    /// A surface chart seen from above is a contour chart.
+    pub(crate) fn new_chart_surface_chart(
+        &mut self,
+        area_chart_series_list: Vec<&str>,
+        is_3d: bool,
+    ) {
+        let mut rotate_x = RotateX::default();
+        let mut rotate_y = RotateY::default();
+        let mut right_angle_axes = RightAngleAxes::default();
+        let mut perspective = Perspective::default();
+        if is_3d {
+            rotate_x.set_val(15);
+            rotate_y.set_val(20);
+            perspective.set_val(30);
+        } else {
+            rotate_x.set_val(90);
+            rotate_y.set_val(0);
+            perspective.set_val(0);
+        }
+        right_angle_axes.set_val(false);
+        let mut view_3d = View3D::default();
+        view_3d.set_rotate_x(rotate_x);
+        view_3d.set_rotate_y(rotate_y);
+        view_3d.set_right_angle_axes(right_angle_axes);
+        view_3d.set_perspective(perspective);
+
+        let mut acsl_obj = self.convert_series(area_chart_series_list, false);
+        for acs_obj in acsl_obj.get_area_chart_series_mut() {
+            acs_obj.remove_invert_if_negative();
+        }
+
+        let mut axis_id1 = AxisId::default();
+        axis_id1.set_val(213468160);
+
+        let mut axis_id2 = AxisId::default();
+        axis_id2.set_val(169590080);
+
+        let mut axis_id3 = AxisId::default();
+        axis_id3.set_val(207618944);
+
+        let mut wireframe = Wireframe::default();
+        wireframe.set_val(false);
+
+        let mut category_axis = CategoryAxis::default();
+        category_axis.get_axis_id_mut().set_val(213468160);
+        category_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        category_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Bottom);
+        category_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        category_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        category_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        category_axis
+            .get_tick_crossing_axis_mut()
+            .set_val(169590080);
+        category_axis.get_auto_labeled_mut().set_val(true);
+        category_axis.get_label_offset_mut().set_val(100);
+
+        let major_gridlines = MajorGridlines::default();
+
+        let mut value_axis = ValueAxis::default();
+        value_axis.get_axis_id_mut().set_val(169590080);
+        value_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        value_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Left);
+        value_axis.set_major_gridlines(major_gridlines);
+        value_axis
+            .get_numbering_format_mut()
+            .set_format_code("General");
+        value_axis
+            .get_numbering_format_mut()
+            .set_source_linked(true);
+        value_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        value_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        value_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        value_axis.get_tick_crossing_axis_mut().set_val(213468160);
+        value_axis
+            .get_cross_between_mut()
+            .set_val(CrossBetweenValues::MidpointCategory);
+
+        let mut series_axis = SeriesAxis::default();
+        series_axis.get_axis_id_mut().set_val(207618944);
+        series_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        series_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Bottom);
+        series_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        series_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        series_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        series_axis.get_tick_crossing_axis_mut().set_val(169590080);
+
+        let mut graphic_frame = GraphicFrame::default();
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_editing_language_mut()
+            .set_val(&self.default_language);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .set_view_3d(view_3d);
+        if is_3d {
+            let mut surface_3d_chart = Surface3DChart::default();
+            surface_3d_chart.set_wireframe(wireframe);
+            surface_3d_chart.set_area_chart_series_list(acsl_obj);
+            surface_3d_chart.set_band_formats(BandFormats::default());
+            surface_3d_chart.add_axis_id(axis_id1);
+            surface_3d_chart.add_axis_id(axis_id2);
+            surface_3d_chart.add_axis_id(axis_id3);
+            graphic_frame
+                .get_graphic_mut()
+                .get_graphic_data_mut()
+                .get_chart_space_mut()
+                .get_chart_mut()
+                .get_plot_area_mut()
+                .set_surface_3d_chart(surface_3d_chart);
+        } else {
+            let mut surface_chart = SurfaceChart::default();
+            surface_chart.set_wireframe(wireframe);
+            surface_chart.set_area_chart_series_list(acsl_obj);
+            surface_chart.set_band_formats(BandFormats::default());
+            surface_chart.add_axis_id(axis_id1);
+            surface_chart.add_axis_id(axis_id2);
+            surface_chart.add_axis_id(axis_id3);
+            graphic_frame
+                .get_graphic_mut()
+                .get_graphic_data_mut()
+                .get_chart_space_mut()
+                .get_chart_mut()
+                .get_plot_area_mut()
+                .set_surface_chart(surface_chart);
+        }
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_category_axis(category_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_value_axis(value_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_series_axis(series_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_legend_mut()
+            .get_legend_position_mut()
+            .set_val(LegendPositionValues::Right);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_visible_only_mut()
+            .set_val(true);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_display_blanks_as_mut()
+            .set_val(DisplayBlanksAsValues::Zero);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .set_print_settings(self.make_print_settings());
+        self.two_cell_anchor.set_graphic_frame(graphic_frame);
+    }
+
+    fn make_solid_fill(color: SchemeColorValues) -> ShapeProperties {
+        let mut scheme_color = SchemeColor::default();
+        scheme_color.set_val(color);
+        let mut solid_fill = SolidFill::default();
+        solid_fill.set_scheme_color(scheme_color);
+        let mut shape_properties = ShapeProperties::default();
+        shape_properties.set_solid_fill(solid_fill);
+        shape_properties
+    }
+
     pub(crate) fn make_print_settings(&self) -> PrintSettings {
         let mut obj = PrintSettings::default();
         obj.get_page_margins_mut()
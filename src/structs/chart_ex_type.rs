@@ -0,0 +1,48 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChartExType {
+    Waterfall,
+    Histogram,
+    Pareto,
+    Treemap,
+    Sunburst,
+    Funnel,
+    BoxWhisker,
+}
+impl Default for ChartExType {
+    fn default() -> Self {
+        Self::Waterfall
+    }
+}
+impl EnumTrait for ChartExType {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Waterfall => "waterfall",
+            Self::Histogram => "histogram",
+            Self::Pareto => "pareto",
+            Self::Treemap => "treemap",
+            Self::Sunburst => "sunburst",
+            Self::Funnel => "funnel",
+            Self::BoxWhisker => "box_whisker",
+        }
+    }
+}
+impl FromStr for ChartExType {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "waterfall" => Ok(Self::Waterfall),
+            "histogram" => Ok(Self::Histogram),
+            "pareto" => Ok(Self::Pareto),
+            "treemap" => Ok(Self::Treemap),
+            "sunburst" => Ok(Self::Sunburst),
+            "funnel" => Ok(Self::Funnel),
+            "box_whisker" => Ok(Self::BoxWhisker),
+            _ => Err(()),
+        }
+    }
+}
@@ -310,7 +310,7 @@ impl Color {
         self.write_to(writer, "tabColor");
     }
 
-    fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str) {
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let theme_index = self.theme_index.get_value_string();
         let indexed = self.indexed.get_value_string();
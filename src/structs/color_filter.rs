@@ -0,0 +1,85 @@
+// colorFilter
+use super::BooleanValue;
+use super::DifferentialFormats;
+use super::Style;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ColorFilter {
+    cell_color: BooleanValue,
+    style: Option<Box<Style>>,
+}
+
+impl ColorFilter {
+    /// Filter by the cell color. When false, the font color is used.
+    #[inline]
+    pub fn get_cell_color(&self) -> &bool {
+        match self.cell_color.has_value() {
+            true => self.cell_color.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_cell_color(&mut self, value: bool) -> &mut Self {
+        self.cell_color.set_value(value);
+        self
+    }
+
+    /// Style holding the color to show.
+    /// The cell color is the background color of the pattern fill, the font color is the color of the font.
+    #[inline]
+    pub fn get_style(&self) -> Option<&Style> {
+        self.style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_style(&mut self) -> &mut Self {
+        self.style = None;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+    ) {
+        set_string_from_xml!(self, e, cell_color, "cellColor");
+
+        if let Some(v) = get_attribute(e, b"dxfId") {
+            let dxf_id = v.parse::<usize>().unwrap();
+            self.set_style(differential_formats.get_style(dxf_id));
+        }
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // colorFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let dxf_id_str: String;
+        if let Some(v) = &self.style {
+            dxf_id_str = differential_formats.set_style(v).to_string();
+            attributes.push(("dxfId", &dxf_id_str));
+        }
+        if self.cell_color.has_value() {
+            attributes.push(("cellColor", self.cell_color.get_value_string()));
+        }
+        write_start_tag(writer, "colorFilter", attributes, true);
+    }
+}
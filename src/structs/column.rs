@@ -1,4 +1,5 @@
 use super::BooleanValue;
+use super::ByteValue;
 use super::DoubleValue;
 use super::Style;
 use super::Stylesheet;
@@ -31,6 +32,8 @@ pub struct Column {
     pub(crate) width: DoubleValue,
     pub(crate) hidden: BooleanValue,
     pub(crate) best_fit: BooleanValue,
+    pub(crate) outline_level: ByteValue,
+    pub(crate) collapsed: BooleanValue,
     style: Box<Style>,
     auto_width: BooleanValue,
 }
@@ -45,6 +48,8 @@ impl Default for Column {
             width,
             hidden: BooleanValue::default(),
             best_fit: BooleanValue::default(),
+            outline_level: ByteValue::default(),
+            collapsed: BooleanValue::default(),
             style: Box::new(Style::default()),
             auto_width: BooleanValue::default(),
         }
@@ -96,6 +101,30 @@ impl Column {
         self
     }
 
+    /// Outline level of the column. (0 to 7)
+    #[inline]
+    pub fn get_outline_level(&self) -> &u8 {
+        self.outline_level.get_value()
+    }
+
+    #[inline]
+    pub fn set_outline_level(&mut self, value: u8) -> &mut Self {
+        self.outline_level.set_value(value.min(7));
+        self
+    }
+
+    /// The outline group this column summarizes is collapsed.
+    #[inline]
+    pub fn get_collapsed(&self) -> &bool {
+        self.collapsed.get_value()
+    }
+
+    #[inline]
+    pub fn set_collapsed(&mut self, value: bool) -> &mut Self {
+        self.collapsed.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_style(&self) -> &Style {
         &self.style
@@ -163,10 +192,12 @@ impl Column {
         format!(
             "{:x}",
             md5::Md5::digest(format!(
-                "{}{}{}",
+                "{}{}{}{}{}",
                 &self.width.get_value_string(),
                 &self.hidden.get_value_string(),
                 &self.best_fit.get_value_string(),
+                &self.outline_level.get_value_string(),
+                &self.collapsed.get_value_string(),
             ))
         )
     }
@@ -180,6 +211,8 @@ impl Column {
         set_string_from_xml!(self, e, width, "width");
         set_string_from_xml!(self, e, hidden, "hidden");
         set_string_from_xml!(self, e, best_fit, "bestFit");
+        set_string_from_xml!(self, e, outline_level, "outlineLevel");
+        set_string_from_xml!(self, e, collapsed, "collapsed");
 
         if let Some(v) = get_attribute(e, b"style") {
             let style = stylesheet.get_style(v.parse::<usize>().unwrap());
@@ -169,6 +169,13 @@ impl Columns {
         if *column.best_fit.get_value() {
             attributes.push(("bestFit", column.best_fit.get_value_string()));
         }
+        let outline_level = column.outline_level.get_value_string();
+        if column.outline_level.get_value() > &0 {
+            attributes.push(("outlineLevel", &outline_level));
+        }
+        if *column.collapsed.get_value() {
+            attributes.push(("collapsed", column.collapsed.get_value_string()));
+        }
         attributes.push(("customWidth", "1"));
         let xf_index_str: String;
         let xf_index = stylesheet.set_style(column.get_style());
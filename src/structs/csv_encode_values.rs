@@ -19,6 +19,22 @@ impl Default for CsvEncodeValues {
         Self::Utf8
     }
 }
+impl CsvEncodeValues {
+    pub(crate) fn get_encoding(&self) -> &'static encoding_rs::Encoding {
+        match &self {
+            Self::Utf8 => encoding_rs::UTF_8,
+            Self::ShiftJis => encoding_rs::SHIFT_JIS,
+            Self::Koi8u => encoding_rs::KOI8_U,
+            Self::Koi8r => encoding_rs::KOI8_R,
+            Self::Iso88598i => encoding_rs::ISO_8859_8_I,
+            Self::Gbk => encoding_rs::GBK,
+            Self::EucKr => encoding_rs::EUC_KR,
+            Self::Big5 => encoding_rs::BIG5,
+            Self::Utf16Le => encoding_rs::UTF_16LE,
+            Self::Utf16Be => encoding_rs::UTF_16BE,
+        }
+    }
+}
 impl EnumTrait for CsvEncodeValues {
     #[inline]
     fn get_value_string(&self) -> &str {
@@ -0,0 +1,37 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CsvQuoteValues {
+    Minimal,
+    All,
+    NonNumeric,
+}
+impl Default for CsvQuoteValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Minimal
+    }
+}
+impl EnumTrait for CsvQuoteValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Minimal => "minimal",
+            Self::All => "all",
+            Self::NonNumeric => "non_numeric",
+        }
+    }
+}
+impl FromStr for CsvQuoteValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "minimal" => Ok(Self::Minimal),
+            "all" => Ok(Self::All),
+            "non_numeric" => Ok(Self::NonNumeric),
+            _ => Err(()),
+        }
+    }
+}
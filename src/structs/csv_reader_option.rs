@@ -0,0 +1,82 @@
+use super::EnumValue;
+use crate::structs::CsvEncodeValues;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CsvReaderOption {
+    pub(crate) csv_encode_values: EnumValue<CsvEncodeValues>,
+    pub(crate) delimiter: char,
+    pub(crate) quote_char: char,
+    pub(crate) do_trim: bool,
+    pub(crate) guess_type: bool,
+}
+impl Default for CsvReaderOption {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            csv_encode_values: EnumValue::default(),
+            delimiter: ',',
+            quote_char: '"',
+            do_trim: false,
+            guess_type: true,
+        }
+    }
+}
+impl CsvReaderOption {
+    /// Encoding of the file. A byte order mark in the file takes precedence.
+    #[inline]
+    pub fn get_csv_encode_value(&self) -> &CsvEncodeValues {
+        self.csv_encode_values.get_value()
+    }
+
+    #[inline]
+    pub fn set_csv_encode_value(&mut self, value: CsvEncodeValues) -> &mut Self {
+        self.csv_encode_values.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_delimiter(&self) -> &char {
+        &self.delimiter
+    }
+
+    #[inline]
+    pub fn set_delimiter(&mut self, value: char) -> &mut Self {
+        self.delimiter = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_quote_char(&self) -> &char {
+        &self.quote_char
+    }
+
+    #[inline]
+    pub fn set_quote_char(&mut self, value: char) -> &mut Self {
+        self.quote_char = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_do_trim(&self) -> &bool {
+        &self.do_trim
+    }
+
+    #[inline]
+    pub fn set_do_trim(&mut self, value: bool) -> &mut Self {
+        self.do_trim = value;
+        self
+    }
+
+    /// Convert numbers, logical values and errors into typed values.
+    /// When false, every value is stored as a string.
+    #[inline]
+    pub fn get_guess_type(&self) -> &bool {
+        &self.guess_type
+    }
+
+    #[inline]
+    pub fn set_guess_type(&mut self, value: bool) -> &mut Self {
+        self.guess_type = value;
+        self
+    }
+}
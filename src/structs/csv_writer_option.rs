@@ -1,11 +1,38 @@
 use super::EnumValue;
 use crate::structs::CsvEncodeValues;
+use crate::structs::CsvQuoteValues;
 
-#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct CsvWriterOption {
     pub(crate) csv_encode_values: EnumValue<CsvEncodeValues>,
     pub(crate) wrap_with_char: Box<str>,
     pub(crate) do_trim: bool,
+    pub(crate) delimiter: char,
+    pub(crate) line_terminator: Box<str>,
+    pub(crate) csv_quote_values: EnumValue<CsvQuoteValues>,
+    pub(crate) sheet_name: Option<Box<str>>,
+    pub(crate) sheet_index: Option<usize>,
+    pub(crate) range: Option<Box<str>>,
+    pub(crate) include_bom: bool,
+    pub(crate) use_formatted_value: bool,
+}
+impl Default for CsvWriterOption {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            csv_encode_values: EnumValue::default(),
+            wrap_with_char: "".into(),
+            do_trim: false,
+            delimiter: ',',
+            line_terminator: "\r\n".into(),
+            csv_quote_values: EnumValue::default(),
+            sheet_name: None,
+            sheet_index: None,
+            range: None,
+            include_bom: false,
+            use_formatted_value: false,
+        }
+    }
 }
 impl CsvWriterOption {
     #[inline]
@@ -19,6 +46,8 @@ impl CsvWriterOption {
         self
     }
 
+    /// Character wrapped around every field.
+    /// When set, it is used instead of `"` and every field is quoted regardless of the quoting policy.
     #[inline]
     pub fn get_wrap_with_char(&self) -> &str {
         &self.wrap_with_char
@@ -40,4 +69,109 @@ impl CsvWriterOption {
         self.do_trim = value;
         self
     }
+
+    #[inline]
+    pub fn get_delimiter(&self) -> &char {
+        &self.delimiter
+    }
+
+    #[inline]
+    pub fn set_delimiter(&mut self, value: char) -> &mut Self {
+        self.delimiter = value;
+        self
+    }
+
+    /// Line terminator written after each row. Default is `\r\n`.
+    #[inline]
+    pub fn get_line_terminator(&self) -> &str {
+        &self.line_terminator
+    }
+
+    #[inline]
+    pub fn set_line_terminator<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.line_terminator = value.into().into_boxed_str();
+        self
+    }
+
+    #[inline]
+    pub fn get_csv_quote_value(&self) -> &CsvQuoteValues {
+        self.csv_quote_values.get_value()
+    }
+
+    #[inline]
+    pub fn set_csv_quote_value(&mut self, value: CsvQuoteValues) -> &mut Self {
+        self.csv_quote_values.set_value(value);
+        self
+    }
+
+    /// Name of the worksheet to export.
+    /// If neither a name nor an index is set, the active sheet is exported.
+    #[inline]
+    pub fn get_sheet_name(&self) -> Option<&str> {
+        self.sheet_name.as_deref()
+    }
+
+    #[inline]
+    pub fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_name = Some(value.into().into_boxed_str());
+        self.sheet_index = None;
+        self
+    }
+
+    /// Index of the worksheet to export.
+    /// If neither a name nor an index is set, the active sheet is exported.
+    #[inline]
+    pub fn get_sheet_index(&self) -> Option<&usize> {
+        self.sheet_index.as_ref()
+    }
+
+    #[inline]
+    pub fn set_sheet_index(&mut self, value: usize) -> &mut Self {
+        self.sheet_index = Some(value);
+        self.sheet_name = None;
+        self
+    }
+
+    /// Range to export. (ex. "A1:C10", "B:D", "2:5")
+    /// If not set, everything up to the highest column and row is exported.
+    #[inline]
+    pub fn get_range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+
+    #[inline]
+    pub fn set_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.range = Some(value.into().into_boxed_str());
+        self
+    }
+
+    #[inline]
+    pub fn remove_range(&mut self) -> &mut Self {
+        self.range = None;
+        self
+    }
+
+    /// Write a byte order mark. Only applies to UTF-8 and UTF-16 encodings.
+    #[inline]
+    pub fn get_include_bom(&self) -> &bool {
+        &self.include_bom
+    }
+
+    #[inline]
+    pub fn set_include_bom(&mut self, value: bool) -> &mut Self {
+        self.include_bom = value;
+        self
+    }
+
+    /// Write the values as displayed, applying the number format of each cell.
+    #[inline]
+    pub fn get_use_formatted_value(&self) -> &bool {
+        &self.use_formatted_value
+    }
+
+    #[inline]
+    pub fn set_use_formatted_value(&mut self, value: bool) -> &mut Self {
+        self.use_formatted_value = value;
+        self
+    }
 }
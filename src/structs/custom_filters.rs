@@ -0,0 +1,98 @@
+// customFilters
+use super::BooleanValue;
+use super::CustomFilter;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomFilters {
+    and: BooleanValue,
+    custom_filter_list: ThinVec<CustomFilter>,
+}
+
+impl CustomFilters {
+    /// Both filters have to match. When false, one of them has to match.
+    #[inline]
+    pub fn get_and(&self) -> &bool {
+        self.and.get_value()
+    }
+
+    #[inline]
+    pub fn set_and(&mut self, value: bool) -> &mut Self {
+        self.and.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_custom_filter_list(&self) -> &[CustomFilter] {
+        &self.custom_filter_list
+    }
+
+    #[inline]
+    pub fn get_custom_filter_list_mut(&mut self) -> &mut ThinVec<CustomFilter> {
+        &mut self.custom_filter_list
+    }
+
+    /// Excel supports up to two custom filters per column.
+    #[inline]
+    pub fn add_custom_filter(&mut self, value: CustomFilter) -> &mut Self {
+        self.custom_filter_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, and, "and");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"customFilter" {
+                    let mut obj = CustomFilter::default();
+                    obj.set_attributes(reader, e);
+                    self.custom_filter_list.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customFilters" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "customFilters")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let is_inner = !self.custom_filter_list.is_empty();
+
+        // customFilters
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.and.has_value() {
+            attributes.push(("and", self.and.get_value_string()));
+        }
+        write_start_tag(writer, "customFilters", attributes, !is_inner);
+
+        if is_inner {
+            // customFilter
+            for v in &self.custom_filter_list {
+                v.write_to(writer);
+            }
+
+            write_end_tag(writer, "customFilters");
+        }
+    }
+}
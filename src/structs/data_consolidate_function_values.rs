@@ -0,0 +1,61 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DataConsolidateFunctionValues {
+    Average,
+    Count,
+    CountNumbers,
+    Maximum,
+    Minimum,
+    Product,
+    StandardDeviation,
+    StandardDeviationP,
+    Sum,
+    Variance,
+    VarianceP,
+}
+impl Default for DataConsolidateFunctionValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Sum
+    }
+}
+impl EnumTrait for DataConsolidateFunctionValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Average => "average",
+            Self::Count => "count",
+            Self::CountNumbers => "countNums",
+            Self::Maximum => "max",
+            Self::Minimum => "min",
+            Self::Product => "product",
+            Self::StandardDeviation => "stdDev",
+            Self::StandardDeviationP => "stdDevp",
+            Self::Sum => "sum",
+            Self::Variance => "var",
+            Self::VarianceP => "varp",
+        }
+    }
+}
+impl FromStr for DataConsolidateFunctionValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "average" => Ok(Self::Average),
+            "count" => Ok(Self::Count),
+            "countNums" => Ok(Self::CountNumbers),
+            "max" => Ok(Self::Maximum),
+            "min" => Ok(Self::Minimum),
+            "product" => Ok(Self::Product),
+            "stdDev" => Ok(Self::StandardDeviation),
+            "stdDevp" => Ok(Self::StandardDeviationP),
+            "sum" => Ok(Self::Sum),
+            "var" => Ok(Self::Variance),
+            "varp" => Ok(Self::VarianceP),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,12 +1,12 @@
 // dataField
 use crate::reader::driver::*;
-use crate::structs::BooleanValue;
+use crate::structs::DataConsolidateFunctionValues;
+use crate::structs::EnumValue;
 use crate::structs::Int32Value;
-use crate::structs::Location;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
-use quick_xml::events::{BytesStart, Event};
+use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
@@ -15,8 +15,10 @@ use std::io::Cursor;
 pub struct DataField {
     name: StringValue,
     fie_id: UInt32Value,
+    subtotal: EnumValue<DataConsolidateFunctionValues>,
     base_fie_id: Int32Value,
     base_item: UInt32Value,
+    number_format_id: UInt32Value,
 }
 impl DataField {
     #[inline]
@@ -41,6 +43,18 @@ impl DataField {
         self
     }
 
+    /// Aggregation applied to the values of the field.
+    #[inline]
+    pub fn get_subtotal(&self) -> &DataConsolidateFunctionValues {
+        self.subtotal.get_value()
+    }
+
+    #[inline]
+    pub fn set_subtotal(&mut self, value: DataConsolidateFunctionValues) -> &mut Self {
+        self.subtotal.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_base_fie_id(&self) -> &i32 {
         self.base_fie_id.get_value()
@@ -63,6 +77,17 @@ impl DataField {
         self
     }
 
+    #[inline]
+    pub fn get_number_format_id(&self) -> &u32 {
+        self.number_format_id.get_value()
+    }
+
+    #[inline]
+    pub fn set_number_format_id(&mut self, value: u32) -> &mut Self {
+        self.number_format_id.set_value(value);
+        self
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
@@ -73,21 +98,28 @@ impl DataField {
         set_string_from_xml!(self, e, fie_id, "fld");
         set_string_from_xml!(self, e, base_fie_id, "baseField");
         set_string_from_xml!(self, e, base_item, "baseItem");
+        set_string_from_xml!(self, e, subtotal, "subtotal");
+        set_string_from_xml!(self, e, number_format_id, "numFmtId");
     }
 
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // dataField
-        write_start_tag(
-            writer,
-            "dataField",
-            vec![
-                ("name", self.name.get_value_str()),
-                ("fld", &self.fie_id.get_value_string()),
-                ("baseField", &self.base_fie_id.get_value_string()),
-                ("baseItem", &self.base_item.get_value_string()),
-            ],
-            true,
-        );
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("name", self.name.get_value_str()));
+        let fie_id_str = self.fie_id.get_value_string();
+        attributes.push(("fld", &fie_id_str));
+        if self.subtotal.has_value() && self.get_subtotal() != &DataConsolidateFunctionValues::Sum {
+            attributes.push(("subtotal", self.subtotal.get_value_string()));
+        }
+        let base_fie_id_str = self.base_fie_id.get_value_string();
+        attributes.push(("baseField", &base_fie_id_str));
+        let base_item_str = self.base_item.get_value_string();
+        attributes.push(("baseItem", &base_item_str));
+        let number_format_id_str = self.number_format_id.get_value_string();
+        if self.number_format_id.has_value() {
+            attributes.push(("numFmtId", &number_format_id_str));
+        }
+        write_start_tag(writer, "dataField", attributes, true);
     }
 }
@@ -1,5 +1,6 @@
 //! structs of drawing.
 
+pub mod chartex;
 pub mod charts;
 pub mod spreadsheet;
 
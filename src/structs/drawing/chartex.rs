@@ -0,0 +1,131 @@
+mod aggregation;
+pub use self::aggregation::*;
+
+mod axis;
+pub use self::axis::*;
+
+mod axis_id;
+pub use self::axis_id::*;
+
+mod binning;
+pub use self::binning::*;
+
+mod category_scaling;
+pub use self::category_scaling::*;
+
+mod chart;
+pub use self::chart::*;
+
+mod chart_data;
+pub use self::chart_data::*;
+
+mod chart_space;
+pub use self::chart_space::*;
+
+mod chart_text;
+pub use self::chart_text::*;
+
+mod data;
+pub use self::data::*;
+
+mod data_id;
+pub use self::data_id::*;
+
+mod data_label_position_values;
+pub use self::data_label_position_values::*;
+
+mod data_label_visibilities;
+pub use self::data_label_visibilities::*;
+
+mod data_labels;
+pub use self::data_labels::*;
+
+mod formula;
+pub use self::formula::*;
+
+mod formula_direction_values;
+pub use self::formula_direction_values::*;
+
+mod interval_closed_side_values;
+pub use self::interval_closed_side_values::*;
+
+mod layout_properties;
+pub use self::layout_properties::*;
+
+mod legend;
+pub use self::legend::*;
+
+mod level;
+pub use self::level::*;
+
+mod major_gridlines;
+pub use self::major_gridlines::*;
+
+mod minor_gridlines;
+pub use self::minor_gridlines::*;
+
+mod number_format;
+pub use self::number_format::*;
+
+mod numeric_dimension;
+pub use self::numeric_dimension::*;
+
+mod numeric_dimension_values;
+pub use self::numeric_dimension_values::*;
+
+mod parent_label_layout;
+pub use self::parent_label_layout::*;
+
+mod parent_label_layout_values;
+pub use self::parent_label_layout_values::*;
+
+mod plot_area;
+pub use self::plot_area::*;
+
+mod plot_area_region;
+pub use self::plot_area_region::*;
+
+mod point;
+pub use self::point::*;
+
+mod position_alignment_values;
+pub use self::position_alignment_values::*;
+
+mod quartile_method_values;
+pub use self::quartile_method_values::*;
+
+mod series;
+pub use self::series::*;
+
+mod series_element_visibilities;
+pub use self::series_element_visibilities::*;
+
+mod series_layout_values;
+pub use self::series_layout_values::*;
+
+mod side_position_values;
+pub use self::side_position_values::*;
+
+mod statistics;
+pub use self::statistics::*;
+
+mod string_dimension;
+pub use self::string_dimension::*;
+
+mod string_dimension_values;
+pub use self::string_dimension_values::*;
+
+mod subtotals;
+pub use self::subtotals::*;
+
+mod text_data;
+pub use self::text_data::*;
+
+mod tick_labels;
+pub use self::tick_labels::*;
+
+mod title;
+pub use self::title::*;
+
+mod value_scaling;
+pub use self::value_scaling::*;
@@ -0,0 +1,269 @@
+// cx:axis
+use super::super::super::BooleanValue;
+use super::super::super::UInt32Value;
+use super::CategoryScaling;
+use super::MajorGridlines;
+use super::MinorGridlines;
+use super::NumberFormat;
+use super::TickLabels;
+use super::Title;
+use super::ValueScaling;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Axis {
+    id: UInt32Value,
+    hidden: BooleanValue,
+    category_scaling: Option<CategoryScaling>,
+    value_scaling: Option<ValueScaling>,
+    title: Option<Title>,
+    major_gridlines: Option<MajorGridlines>,
+    minor_gridlines: Option<MinorGridlines>,
+    tick_labels: Option<TickLabels>,
+    number_format: Option<NumberFormat>,
+}
+
+impl Axis {
+    pub fn get_id(&self) -> &u32 {
+        self.id.get_value()
+    }
+
+    pub fn set_id(&mut self, value: u32) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    pub fn get_category_scaling(&self) -> Option<&CategoryScaling> {
+        self.category_scaling.as_ref()
+    }
+
+    pub fn get_category_scaling_mut(&mut self) -> Option<&mut CategoryScaling> {
+        self.category_scaling.as_mut()
+    }
+
+    /// Make this a category axis. Clears the value scaling.
+    pub fn set_category_scaling(&mut self, value: CategoryScaling) -> &mut Self {
+        self.category_scaling = Some(value);
+        self.value_scaling = None;
+        self
+    }
+
+    pub fn get_value_scaling(&self) -> Option<&ValueScaling> {
+        self.value_scaling.as_ref()
+    }
+
+    pub fn get_value_scaling_mut(&mut self) -> Option<&mut ValueScaling> {
+        self.value_scaling.as_mut()
+    }
+
+    /// Make this a value axis. Clears the category scaling.
+    pub fn set_value_scaling(&mut self, value: ValueScaling) -> &mut Self {
+        self.value_scaling = Some(value);
+        self.category_scaling = None;
+        self
+    }
+
+    pub fn get_title(&self) -> Option<&Title> {
+        self.title.as_ref()
+    }
+
+    pub fn get_title_mut(&mut self) -> Option<&mut Title> {
+        self.title.as_mut()
+    }
+
+    pub fn set_title(&mut self, value: Title) -> &mut Self {
+        self.title = Some(value);
+        self
+    }
+
+    pub fn get_major_gridlines(&self) -> Option<&MajorGridlines> {
+        self.major_gridlines.as_ref()
+    }
+
+    pub fn set_major_gridlines(&mut self, value: MajorGridlines) -> &mut Self {
+        self.major_gridlines = Some(value);
+        self
+    }
+
+    pub fn remove_major_gridlines(&mut self) -> &mut Self {
+        self.major_gridlines = None;
+        self
+    }
+
+    pub fn get_minor_gridlines(&self) -> Option<&MinorGridlines> {
+        self.minor_gridlines.as_ref()
+    }
+
+    pub fn set_minor_gridlines(&mut self, value: MinorGridlines) -> &mut Self {
+        self.minor_gridlines = Some(value);
+        self
+    }
+
+    pub fn remove_minor_gridlines(&mut self) -> &mut Self {
+        self.minor_gridlines = None;
+        self
+    }
+
+    pub fn get_tick_labels(&self) -> Option<&TickLabels> {
+        self.tick_labels.as_ref()
+    }
+
+    pub fn set_tick_labels(&mut self, value: TickLabels) -> &mut Self {
+        self.tick_labels = Some(value);
+        self
+    }
+
+    pub fn remove_tick_labels(&mut self) -> &mut Self {
+        self.tick_labels = None;
+        self
+    }
+
+    pub fn get_number_format(&self) -> Option<&NumberFormat> {
+        self.number_format.as_ref()
+    }
+
+    pub fn get_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        self.number_format.as_mut()
+    }
+
+    pub fn set_number_format(&mut self, value: NumberFormat) -> &mut Self {
+        self.number_format = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, hidden, "hidden");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_title(obj);
+                }
+                b"cx:majorGridlines" => {
+                    self.set_major_gridlines(MajorGridlines::default());
+                }
+                b"cx:minorGridlines" => {
+                    self.set_minor_gridlines(MinorGridlines::default());
+                }
+                b"cx:tickLabels" => {
+                    self.set_tick_labels(TickLabels::default());
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:catScaling" => {
+                    let mut obj = CategoryScaling::default();
+                    obj.set_attributes(reader, e);
+                    self.set_category_scaling(obj);
+                }
+                b"cx:valScaling" => {
+                    let mut obj = ValueScaling::default();
+                    obj.set_attributes(reader, e);
+                    self.set_value_scaling(obj);
+                }
+                b"cx:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_title(obj);
+                }
+                b"cx:majorGridlines" => {
+                    let mut obj = MajorGridlines::default();
+                    obj.set_attributes(reader, e);
+                    self.set_major_gridlines(obj);
+                }
+                b"cx:minorGridlines" => {
+                    let mut obj = MinorGridlines::default();
+                    obj.set_attributes(reader, e);
+                    self.set_minor_gridlines(obj);
+                }
+                b"cx:tickLabels" => {
+                    let mut obj = TickLabels::default();
+                    obj.set_attributes(reader, e);
+                    self.set_tick_labels(obj);
+                }
+                b"cx:numFmt" => {
+                    let mut obj = NumberFormat::default();
+                    obj.set_attributes(reader, e);
+                    self.set_number_format(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:axis" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:axis"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:axis
+        let id = self.id.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = vec![("id", &id)];
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.get_value_string()));
+        }
+        write_start_tag(writer, "cx:axis", attributes, false);
+
+        // cx:catScaling
+        if let Some(v) = &self.category_scaling {
+            v.write_to(writer);
+        }
+
+        // cx:valScaling
+        if let Some(v) = &self.value_scaling {
+            v.write_to(writer);
+        }
+
+        // cx:title
+        if let Some(v) = &self.title {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:majorGridlines
+        if let Some(v) = &self.major_gridlines {
+            v.write_to(writer);
+        }
+
+        // cx:minorGridlines
+        if let Some(v) = &self.minor_gridlines {
+            v.write_to(writer);
+        }
+
+        // cx:tickLabels
+        if let Some(v) = &self.tick_labels {
+            v.write_to(writer);
+        }
+
+        // cx:numFmt
+        if let Some(v) = &self.number_format {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:axis");
+    }
+}
@@ -0,0 +1,149 @@
+// cx:binning
+use super::super::super::DoubleValue;
+use super::super::super::EnumValue;
+use super::super::super::StringValue;
+use super::super::super::UInt32Value;
+use super::IntervalClosedSideValues;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Binning {
+    interval_closed: EnumValue<IntervalClosedSideValues>,
+    underflow: StringValue,
+    overflow: StringValue,
+    bin_size: DoubleValue,
+    bin_count: UInt32Value,
+}
+
+impl Binning {
+    pub fn get_interval_closed(&self) -> &IntervalClosedSideValues {
+        self.interval_closed.get_value()
+    }
+
+    pub fn set_interval_closed(&mut self, value: IntervalClosedSideValues) -> &mut Self {
+        self.interval_closed.set_value(value);
+        self
+    }
+
+    /// Get the underflow bin limit. `"auto"` or a number.
+    pub fn get_underflow(&self) -> Option<&str> {
+        self.underflow.get_value()
+    }
+
+    pub fn set_underflow<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.underflow.set_value(value);
+        self
+    }
+
+    /// Get the overflow bin limit. `"auto"` or a number.
+    pub fn get_overflow(&self) -> Option<&str> {
+        self.overflow.get_value()
+    }
+
+    pub fn set_overflow<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.overflow.set_value(value);
+        self
+    }
+
+    pub fn get_bin_size(&self) -> Option<&f64> {
+        self.bin_size.has_value().then(|| self.bin_size.get_value())
+    }
+
+    /// Set the bin width. Clears the bin count.
+    pub fn set_bin_size(&mut self, value: f64) -> &mut Self {
+        self.bin_size.set_value(value);
+        self.bin_count.remove_value();
+        self
+    }
+
+    pub fn get_bin_count(&self) -> Option<&u32> {
+        self.bin_count
+            .has_value()
+            .then(|| self.bin_count.get_value())
+    }
+
+    /// Set the number of bins. Clears the bin size.
+    pub fn set_bin_count(&mut self, value: u32) -> &mut Self {
+        self.bin_count.set_value(value);
+        self.bin_size = DoubleValue::default();
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, interval_closed, "intervalClosed");
+        set_string_from_xml!(self, e, underflow, "underflow");
+        set_string_from_xml!(self, e, overflow, "overflow");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:binSize" => {
+                    set_string_from_xml!(self, e, bin_size, "val");
+                }
+                b"cx:binCount" => {
+                    set_string_from_xml!(self, e, bin_count, "val");
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:binning" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:binning"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:binning
+        let mut attributes: Vec<(&str, &str)> =
+            vec![("intervalClosed", self.interval_closed.get_value_string())];
+        if self.underflow.has_value() {
+            attributes.push(("underflow", self.underflow.get_value_str()));
+        }
+        if self.overflow.has_value() {
+            attributes.push(("overflow", self.overflow.get_value_str()));
+        }
+        let is_empty = !self.bin_size.has_value() && !self.bin_count.has_value();
+        write_start_tag(writer, "cx:binning", attributes, is_empty);
+        if is_empty {
+            return;
+        }
+
+        // cx:binSize
+        if self.bin_size.has_value() {
+            write_start_tag(
+                writer,
+                "cx:binSize",
+                vec![("val", &self.bin_size.get_value_string())],
+                true,
+            );
+        }
+
+        // cx:binCount
+        if self.bin_count.has_value() {
+            write_start_tag(
+                writer,
+                "cx:binCount",
+                vec![("val", &self.bin_count.get_value_string())],
+                true,
+            );
+        }
+
+        write_end_tag(writer, "cx:binning");
+    }
+}
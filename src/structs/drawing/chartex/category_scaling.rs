@@ -0,0 +1,42 @@
+// cx:catScaling
+use super::super::super::StringValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct CategoryScaling {
+    gap_width: StringValue,
+}
+
+impl CategoryScaling {
+    /// Get the gap between categories. `"auto"` or a ratio of the column width.
+    pub fn get_gap_width(&self) -> Option<&str> {
+        self.gap_width.get_value()
+    }
+
+    pub fn set_gap_width<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.gap_width.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, gap_width, "gapWidth");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:catScaling
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.gap_width.has_value() {
+            attributes.push(("gapWidth", self.gap_width.get_value_str()));
+        }
+        write_start_tag(writer, "cx:catScaling", attributes, true);
+    }
+}
@@ -0,0 +1,188 @@
+// cx:chart
+use super::Legend;
+use super::PlotArea;
+use super::Title;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Chart {
+    title: Option<Title>,
+    plot_area: PlotArea,
+    legend: Option<Legend>,
+}
+
+impl Chart {
+    pub fn get_title(&self) -> Option<&Title> {
+        self.title.as_ref()
+    }
+
+    pub fn get_title_mut(&mut self) -> Option<&mut Title> {
+        self.title.as_mut()
+    }
+
+    pub fn set_title(&mut self, value: Title) -> &mut Self {
+        self.title = Some(value);
+        self
+    }
+
+    pub fn remove_title(&mut self) -> &mut Self {
+        self.title = None;
+        self
+    }
+
+    pub fn get_plot_area(&self) -> &PlotArea {
+        &self.plot_area
+    }
+
+    pub fn get_plot_area_mut(&mut self) -> &mut PlotArea {
+        &mut self.plot_area
+    }
+
+    pub fn set_plot_area(&mut self, value: PlotArea) -> &mut Self {
+        self.plot_area = value;
+        self
+    }
+
+    pub fn get_legend(&self) -> Option<&Legend> {
+        self.legend.as_ref()
+    }
+
+    pub fn get_legend_mut(&mut self) -> Option<&mut Legend> {
+        self.legend.as_mut()
+    }
+
+    pub fn set_legend(&mut self, value: Legend) -> &mut Self {
+        self.legend = Some(value);
+        self
+    }
+
+    pub fn remove_legend(&mut self) -> &mut Self {
+        self.legend = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_title(obj);
+                }
+                b"cx:plotArea" => {
+                    self.plot_area.set_attributes(reader, e);
+                }
+                b"cx:legend" => {
+                    let mut obj = Legend::default();
+                    obj.set_attributes(reader, e);
+                    self.set_legend(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_title(obj);
+                }
+                b"cx:legend" => {
+                    let mut obj = Legend::default();
+                    obj.set_attributes(reader, e);
+                    self.set_legend(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:chart" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:chart"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:chart
+        write_start_tag(writer, "cx:chart", vec![], false);
+
+        // cx:title
+        if let Some(v) = &self.title {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:plotArea
+        self.plot_area.write_to(writer, spreadsheet);
+
+        // cx:legend
+        if let Some(v) = &self.legend {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:chart");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Chart {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.title {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        self.plot_area.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.title {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        self.plot_area.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
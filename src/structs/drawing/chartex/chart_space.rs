@@ -0,0 +1,498 @@
+// cx:chartSpace
+use super::super::super::ChartExType;
+use super::Aggregation;
+use super::Axis;
+use super::AxisId;
+use super::Binning;
+use super::CategoryScaling;
+use super::Chart;
+use super::ChartData;
+use super::ChartText;
+use super::Data;
+use super::DataId;
+use super::DataLabelPositionValues;
+use super::DataLabelVisibilities;
+use super::DataLabels;
+use super::Formula;
+use super::FormulaDirectionValues;
+use super::IntervalClosedSideValues;
+use super::LayoutProperties;
+use super::Legend;
+use super::MajorGridlines;
+use super::NumberFormat;
+use super::NumericDimension;
+use super::NumericDimensionValues;
+use super::ParentLabelLayout;
+use super::ParentLabelLayoutValues;
+use super::PositionAlignmentValues;
+use super::QuartileMethodValues;
+use super::Series;
+use super::SeriesElementVisibilities;
+use super::SeriesLayoutValues;
+use super::SidePositionValues;
+use super::Statistics;
+use super::StringDimension;
+use super::StringDimensionValues;
+use super::Subtotals;
+use super::TickLabels;
+use super::Title;
+use super::ValueScaling;
+use crate::helper::const_str::*;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ChartSpace {
+    chart_data: ChartData,
+    chart: Chart,
+}
+
+impl ChartSpace {
+    pub fn get_chart_data(&self) -> &ChartData {
+        &self.chart_data
+    }
+
+    pub fn get_chart_data_mut(&mut self) -> &mut ChartData {
+        &mut self.chart_data
+    }
+
+    pub fn set_chart_data(&mut self, value: ChartData) -> &mut Self {
+        self.chart_data = value;
+        self
+    }
+
+    pub fn get_chart(&self) -> &Chart {
+        &self.chart
+    }
+
+    pub fn get_chart_mut(&mut self) -> &mut Chart {
+        &mut self.chart
+    }
+
+    pub fn set_chart(&mut self, value: Chart) -> &mut Self {
+        self.chart = value;
+        self
+    }
+
+    /// Build a chart of the given type.
+    /// # Arguments
+    /// * `chart_type` - Type of chart.
+    /// * `category_address` - Category range, e.g. `"Sheet1!$A$2:$A$6"`. Several columns make a hierarchy (treemap, sunburst).
+    /// * `value_address_list` - One value range per series.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// use umya_spreadsheet::drawing::chartex::ChartSpace;
+    /// let mut chart_space = ChartSpace::default();
+    /// chart_space.new_chart(
+    ///     ChartExType::Waterfall,
+    ///     Some("Sheet1!$A$1:$A$5"),
+    ///     vec!["Sheet1!$B$1:$B$5"],
+    /// );
+    /// ```
+    pub fn new_chart(
+        &mut self,
+        chart_type: ChartExType,
+        category_address: Option<&str>,
+        value_address_list: Vec<&str>,
+    ) -> &mut Self {
+        let value_type = match chart_type {
+            ChartExType::Treemap | ChartExType::Sunburst => NumericDimensionValues::Size,
+            _ => NumericDimensionValues::Value,
+        };
+
+        let mut chart_data = ChartData::default();
+        let mut chart = Chart::default();
+        for (index, value_address) in value_address_list.into_iter().enumerate() {
+            let mut data = Data::default();
+            data.set_id(index as u32);
+            if let Some(address) = category_address {
+                let mut formula = Formula::default();
+                formula
+                    .set_address_str(address)
+                    .set_direction(FormulaDirectionValues::Column);
+                let mut string_dimension = StringDimension::default();
+                string_dimension
+                    .set_type(StringDimensionValues::Category)
+                    .set_formula(formula);
+                data.add_string_dimension(string_dimension);
+            }
+            let mut formula = Formula::default();
+            formula
+                .set_address_str(value_address)
+                .set_direction(FormulaDirectionValues::Column);
+            let mut numeric_dimension = NumericDimension::default();
+            numeric_dimension
+                .set_type(value_type.clone())
+                .set_formula(formula);
+            data.add_numeric_dimension(numeric_dimension);
+            chart_data.add_data(data);
+
+            let mut data_id = DataId::default();
+            data_id.set_val(index as u32);
+            let mut series = Series::default();
+            series.set_format_index(index as u32).set_data_id(data_id);
+            Self::make_series(&chart_type, &mut series, category_address.is_some());
+            chart
+                .get_plot_area_mut()
+                .get_plot_area_region_mut()
+                .add_series(series);
+        }
+
+        match chart_type {
+            ChartExType::Waterfall => {
+                chart
+                    .get_plot_area_mut()
+                    .add_axis(Self::make_category_axis(0, "0.5"))
+                    .add_axis(Self::make_value_axis(1));
+                chart.set_legend(Self::make_legend());
+            }
+            ChartExType::Histogram => {
+                chart
+                    .get_plot_area_mut()
+                    .add_axis(Self::make_category_axis(0, "0"))
+                    .add_axis(Self::make_value_axis(1));
+            }
+            ChartExType::Pareto => {
+                let series_list = chart
+                    .get_plot_area_mut()
+                    .get_plot_area_region_mut()
+                    .get_series_mut();
+                let count = series_list.len();
+                for index in 0..count {
+                    let mut axis_id = AxisId::default();
+                    axis_id.set_val(1);
+                    series_list[index].add_axis_id(axis_id);
+
+                    let mut axis_id = AxisId::default();
+                    axis_id.set_val(2);
+                    let mut pareto = Series::default();
+                    pareto
+                        .set_layout_id(SeriesLayoutValues::ParetoLine)
+                        .set_owner_index(index as u32)
+                        .set_format_index((count + index) as u32)
+                        .add_axis_id(axis_id);
+                    series_list.push(pareto);
+                }
+
+                let mut value_scaling = ValueScaling::default();
+                value_scaling.set_max("1").set_min("0");
+                let mut number_format = NumberFormat::default();
+                number_format.set_format_code("0%").set_source_linked(false);
+                let mut axis = Axis::default();
+                axis.set_id(2)
+                    .set_value_scaling(value_scaling)
+                    .set_tick_labels(TickLabels::default())
+                    .set_number_format(number_format);
+                chart
+                    .get_plot_area_mut()
+                    .add_axis(Self::make_category_axis(0, "0"))
+                    .add_axis(Self::make_value_axis(1))
+                    .add_axis(axis);
+                chart.set_legend(Self::make_legend());
+            }
+            ChartExType::Treemap => {
+                chart.set_legend(Self::make_legend());
+            }
+            ChartExType::Sunburst => {}
+            ChartExType::Funnel => {
+                chart
+                    .get_plot_area_mut()
+                    .add_axis(Self::make_category_axis(0, "0.06"));
+            }
+            ChartExType::BoxWhisker => {
+                chart
+                    .get_plot_area_mut()
+                    .add_axis(Self::make_category_axis(0, "1"))
+                    .add_axis(Self::make_value_axis(1));
+                chart.set_legend(Self::make_legend());
+            }
+        }
+
+        self.chart_data = chart_data;
+        self.chart = chart;
+        self
+    }
+
+    /// Set the chart title.
+    pub fn set_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut title = Title::default();
+        title
+            .set_position(SidePositionValues::Top)
+            .set_alignment(PositionAlignmentValues::Center)
+            .set_overlay(false)
+            .set_text(value);
+        self.chart.set_title(title);
+        self
+    }
+
+    /// Set the series names, in series order. Derived series (e.g. pareto lines) are skipped.
+    pub fn set_series_title<S: Into<String>>(&mut self, value: Vec<S>) -> &mut Self {
+        let series_list = self
+            .chart
+            .get_plot_area_mut()
+            .get_plot_area_region_mut()
+            .get_series_mut()
+            .iter_mut()
+            .filter(|v| v.get_owner_index().is_none());
+        for (series, title) in series_list.zip(value) {
+            let mut chart_text = ChartText::default();
+            chart_text.get_text_data_mut().set_value(title);
+            series.set_chart_text(chart_text);
+        }
+        self
+    }
+
+    /// Get the source ranges of all dimensions.
+    pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
+        let mut result = Vec::new();
+        for data in self.chart_data.get_data_mut() {
+            result.extend(data.get_formula_mut());
+        }
+        result
+    }
+
+    /// Namespace prefix and uri an application must understand to open the chart.
+    pub(crate) fn get_requires(&self) -> (&str, &str) {
+        let is_funnel = self
+            .chart
+            .get_plot_area()
+            .get_plot_area_region()
+            .get_series()
+            .iter()
+            .any(|v| v.get_layout_id() == &SeriesLayoutValues::Funnel);
+        if is_funnel {
+            return ("cx2", DRAWINGML_CHARTEX_CX2_NS);
+        }
+        ("cx1", DRAWINGML_CHARTEX_CX1_NS)
+    }
+
+    fn make_series(chart_type: &ChartExType, series: &mut Series, has_category: bool) {
+        match chart_type {
+            ChartExType::Waterfall => {
+                let mut visibility = DataLabelVisibilities::default();
+                visibility
+                    .set_series_name(false)
+                    .set_category_name(false)
+                    .set_value(true);
+                let mut data_labels = DataLabels::default();
+                data_labels
+                    .set_position(DataLabelPositionValues::OutsideEnd)
+                    .set_visibility(visibility);
+                let mut layout_properties = LayoutProperties::default();
+                layout_properties.set_subtotals(Subtotals::default());
+                series
+                    .set_layout_id(SeriesLayoutValues::Waterfall)
+                    .set_data_labels(data_labels)
+                    .set_layout_properties(layout_properties);
+            }
+            ChartExType::Histogram | ChartExType::Pareto => {
+                let mut layout_properties = LayoutProperties::default();
+                if has_category {
+                    layout_properties.set_aggregation(Aggregation::default());
+                } else {
+                    let mut binning = Binning::default();
+                    binning.set_interval_closed(IntervalClosedSideValues::Right);
+                    layout_properties.set_binning(binning);
+                }
+                series
+                    .set_layout_id(SeriesLayoutValues::ClusteredColumn)
+                    .set_layout_properties(layout_properties);
+            }
+            ChartExType::Treemap => {
+                let mut visibility = DataLabelVisibilities::default();
+                visibility
+                    .set_series_name(false)
+                    .set_category_name(true)
+                    .set_value(false);
+                let mut data_labels = DataLabels::default();
+                data_labels
+                    .set_position(DataLabelPositionValues::InsideEnd)
+                    .set_visibility(visibility);
+                let mut parent_label_layout = ParentLabelLayout::default();
+                parent_label_layout.set_val(ParentLabelLayoutValues::Overlapping);
+                let mut layout_properties = LayoutProperties::default();
+                layout_properties.set_parent_label_layout(parent_label_layout);
+                series
+                    .set_layout_id(SeriesLayoutValues::Treemap)
+                    .set_data_labels(data_labels)
+                    .set_layout_properties(layout_properties);
+            }
+            ChartExType::Sunburst => {
+                let mut visibility = DataLabelVisibilities::default();
+                visibility
+                    .set_series_name(false)
+                    .set_category_name(true)
+                    .set_value(false);
+                let mut data_labels = DataLabels::default();
+                data_labels
+                    .set_position(DataLabelPositionValues::Center)
+                    .set_visibility(visibility);
+                series
+                    .set_layout_id(SeriesLayoutValues::Sunburst)
+                    .set_data_labels(data_labels);
+            }
+            ChartExType::Funnel => {
+                let mut visibility = DataLabelVisibilities::default();
+                visibility
+                    .set_series_name(false)
+                    .set_category_name(false)
+                    .set_value(true);
+                let mut data_labels = DataLabels::default();
+                data_labels
+                    .set_position(DataLabelPositionValues::InsideEnd)
+                    .set_visibility(visibility);
+                series
+                    .set_layout_id(SeriesLayoutValues::Funnel)
+                    .set_data_labels(data_labels);
+            }
+            ChartExType::BoxWhisker => {
+                let mut visibility = SeriesElementVisibilities::default();
+                visibility
+                    .set_mean_line(false)
+                    .set_mean_marker(true)
+                    .set_nonoutliers(false)
+                    .set_outliers(true);
+                let mut statistics = Statistics::default();
+                statistics.set_quartile_method(QuartileMethodValues::Exclusive);
+                let mut layout_properties = LayoutProperties::default();
+                layout_properties
+                    .set_visibility(visibility)
+                    .set_statistics(statistics);
+                series
+                    .set_layout_id(SeriesLayoutValues::BoxWhisker)
+                    .set_layout_properties(layout_properties);
+            }
+        }
+    }
+
+    fn make_category_axis(id: u32, gap_width: &str) -> Axis {
+        let mut category_scaling = CategoryScaling::default();
+        category_scaling.set_gap_width(gap_width);
+        let mut axis = Axis::default();
+        axis.set_id(id)
+            .set_category_scaling(category_scaling)
+            .set_tick_labels(TickLabels::default());
+        axis
+    }
+
+    fn make_value_axis(id: u32) -> Axis {
+        let mut axis = Axis::default();
+        axis.set_id(id)
+            .set_value_scaling(ValueScaling::default())
+            .set_major_gridlines(MajorGridlines::default())
+            .set_tick_labels(TickLabels::default());
+        axis
+    }
+
+    fn make_legend() -> Legend {
+        let mut legend = Legend::default();
+        legend
+            .set_position(SidePositionValues::Top)
+            .set_alignment(PositionAlignmentValues::Center)
+            .set_overlay(false);
+        legend
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:chartData" => {
+                    self.chart_data.set_attributes(reader, e);
+                }
+                b"cx:chart" => {
+                    self.chart.set_attributes(reader, e);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:chartSpace" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:chartSpace"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:chartSpace
+        write_start_tag(
+            writer,
+            "cx:chartSpace",
+            vec![
+                ("xmlns:a", DRAWINGML_MAIN_NS),
+                ("xmlns:r", REL_OFC_NS),
+                ("xmlns:cx", DRAWINGML_CHARTEX_NS),
+            ],
+            false,
+        );
+
+        // cx:chartData
+        self.chart_data.write_to(writer, spreadsheet);
+
+        // cx:chart
+        self.chart.write_to(writer, spreadsheet);
+
+        write_end_tag(writer, "cx:chartSpace");
+    }
+}
+impl AdjustmentCoordinateWithSheet for ChartSpace {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.chart_data.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.chart.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.chart_data.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.chart.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
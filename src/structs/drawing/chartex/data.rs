@@ -0,0 +1,183 @@
+// cx:data
+use super::super::super::UInt32Value;
+use super::Formula;
+use super::NumericDimension;
+use super::StringDimension;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct Data {
+    id: UInt32Value,
+    string_dimension: ThinVec<StringDimension>,
+    numeric_dimension: ThinVec<NumericDimension>,
+}
+
+impl Data {
+    pub fn get_id(&self) -> &u32 {
+        self.id.get_value()
+    }
+
+    pub fn set_id(&mut self, value: u32) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    pub fn get_string_dimension(&self) -> &[StringDimension] {
+        &self.string_dimension
+    }
+
+    pub fn get_string_dimension_mut(&mut self) -> &mut ThinVec<StringDimension> {
+        &mut self.string_dimension
+    }
+
+    pub fn add_string_dimension(&mut self, value: StringDimension) -> &mut Self {
+        self.string_dimension.push(value);
+        self
+    }
+
+    pub fn get_numeric_dimension(&self) -> &[NumericDimension] {
+        &self.numeric_dimension
+    }
+
+    pub fn get_numeric_dimension_mut(&mut self) -> &mut ThinVec<NumericDimension> {
+        &mut self.numeric_dimension
+    }
+
+    pub fn add_numeric_dimension(&mut self, value: NumericDimension) -> &mut Self {
+        self.numeric_dimension.push(value);
+        self
+    }
+
+    pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
+        let mut result: Vec<&mut Formula> = Vec::new();
+        for v in &mut self.string_dimension {
+            if let Some(formula) = v.get_formula_mut() {
+                result.push(formula);
+            }
+        }
+        for v in &mut self.numeric_dimension {
+            if let Some(formula) = v.get_formula_mut() {
+                result.push(formula);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, id, "id");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:strDim" => {
+                    let mut obj = StringDimension::default();
+                    obj.set_attributes(reader, e);
+                    self.add_string_dimension(obj);
+                }
+                b"cx:numDim" => {
+                    let mut obj = NumericDimension::default();
+                    obj.set_attributes(reader, e);
+                    self.add_numeric_dimension(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:data" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:data"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:data
+        write_start_tag(
+            writer,
+            "cx:data",
+            vec![("id", &self.id.get_value_string())],
+            false,
+        );
+
+        // cx:strDim
+        for v in &self.string_dimension {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:numDim
+        for v in &self.numeric_dimension {
+            v.write_to(writer, spreadsheet);
+        }
+
+        write_end_tag(writer, "cx:data");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Data {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.string_dimension {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        for v in &mut self.numeric_dimension {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.string_dimension {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        for v in &mut self.numeric_dimension {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
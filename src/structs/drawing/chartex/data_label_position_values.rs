@@ -0,0 +1,51 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataLabelPositionValues {
+    BestFit,
+    Bottom,
+    Center,
+    InsideBase,
+    InsideEnd,
+    Left,
+    OutsideEnd,
+    Right,
+    Top,
+}
+impl Default for DataLabelPositionValues {
+    fn default() -> Self {
+        Self::OutsideEnd
+    }
+}
+impl EnumTrait for DataLabelPositionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::BestFit => "bestFit",
+            Self::Bottom => "b",
+            Self::Center => "ctr",
+            Self::InsideBase => "inBase",
+            Self::InsideEnd => "inEnd",
+            Self::Left => "l",
+            Self::OutsideEnd => "outEnd",
+            Self::Right => "r",
+            Self::Top => "t",
+        }
+    }
+}
+impl FromStr for DataLabelPositionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "bestFit" => Ok(Self::BestFit),
+            "b" => Ok(Self::Bottom),
+            "ctr" => Ok(Self::Center),
+            "inBase" => Ok(Self::InsideBase),
+            "inEnd" => Ok(Self::InsideEnd),
+            "l" => Ok(Self::Left),
+            "outEnd" => Ok(Self::OutsideEnd),
+            "r" => Ok(Self::Right),
+            "t" => Ok(Self::Top),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// cx:dataLabels
+use super::super::super::EnumValue;
+use super::DataLabelPositionValues;
+use super::DataLabelVisibilities;
+use super::NumberFormat;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DataLabels {
+    position: EnumValue<DataLabelPositionValues>,
+    number_format: Option<NumberFormat>,
+    visibility: Option<DataLabelVisibilities>,
+}
+
+impl DataLabels {
+    pub fn get_position(&self) -> &DataLabelPositionValues {
+        self.position.get_value()
+    }
+
+    pub fn set_position(&mut self, value: DataLabelPositionValues) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    pub fn get_number_format(&self) -> Option<&NumberFormat> {
+        self.number_format.as_ref()
+    }
+
+    pub fn get_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        self.number_format.as_mut()
+    }
+
+    pub fn set_number_format(&mut self, value: NumberFormat) -> &mut Self {
+        self.number_format = Some(value);
+        self
+    }
+
+    pub fn get_visibility(&self) -> Option<&DataLabelVisibilities> {
+        self.visibility.as_ref()
+    }
+
+    pub fn get_visibility_mut(&mut self) -> Option<&mut DataLabelVisibilities> {
+        self.visibility.as_mut()
+    }
+
+    pub fn set_visibility(&mut self, value: DataLabelVisibilities) -> &mut Self {
+        self.visibility = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, position, "pos");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:numFmt" => {
+                    let mut obj = NumberFormat::default();
+                    obj.set_attributes(reader, e);
+                    self.set_number_format(obj);
+                }
+                b"cx:visibility" => {
+                    let mut obj = DataLabelVisibilities::default();
+                    obj.set_attributes(reader, e);
+                    self.set_visibility(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:dataLabels" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:dataLabels"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:dataLabels
+        let is_empty = self.number_format.is_none() && self.visibility.is_none();
+        write_start_tag(
+            writer,
+            "cx:dataLabels",
+            vec![("pos", self.position.get_value_string())],
+            is_empty,
+        );
+        if is_empty {
+            return;
+        }
+
+        // cx:numFmt
+        if let Some(v) = &self.number_format {
+            v.write_to(writer);
+        }
+
+        // cx:visibility
+        if let Some(v) = &self.visibility {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:dataLabels");
+    }
+}
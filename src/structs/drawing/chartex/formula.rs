@@ -0,0 +1,160 @@
+// cx:f
+use super::super::super::Address;
+use super::super::super::EnumValue;
+use super::super::super::StringValue;
+use super::FormulaDirectionValues;
+use crate::helper::address::*;
+use crate::helper::range::*;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Formula {
+    address: Address,
+    string_value: StringValue,
+    direction: EnumValue<FormulaDirectionValues>,
+}
+
+impl Formula {
+    pub fn get_address(&self) -> &Address {
+        &self.address
+    }
+
+    pub fn get_address_mut(&mut self) -> &mut Address {
+        &mut self.address
+    }
+
+    pub fn get_address_str(&self) -> String {
+        if self.string_value.has_value() {
+            return self.string_value.get_value_str().to_string();
+        }
+        self.address.get_address()
+    }
+
+    pub fn set_address(&mut self, value: Address) -> &mut Self {
+        self.address = value;
+        self.string_value.remove_value();
+        self
+    }
+
+    pub fn set_string_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.address = Address::default();
+        self.string_value.set_value(value);
+        self
+    }
+
+    pub fn set_address_str<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let value = value.into();
+        if is_address(&value) {
+            self.address.set_address(value);
+        } else {
+            self.set_string_value(value);
+        }
+        self
+    }
+
+    pub fn get_direction(&self) -> &FormulaDirectionValues {
+        self.direction.get_value()
+    }
+
+    pub fn set_direction(&mut self, value: FormulaDirectionValues) -> &mut Self {
+        self.direction.set_value(value);
+        self
+    }
+
+    /// Cell values of the referenced range, one list per level.
+    /// The last column of the range is the first level.
+    pub(crate) fn get_level_value_list(
+        &self,
+        spreadsheet: &Spreadsheet,
+    ) -> Option<Vec<Vec<String>>> {
+        if self.string_value.has_value() || self.get_direction() == &FormulaDirectionValues::Row {
+            return None;
+        }
+        let worksheet = spreadsheet.get_sheet_by_name(self.address.get_sheet_name())?;
+        let (row_start, row_end, col_start, col_end) =
+            get_start_and_end_point(&self.address.get_range().get_range());
+        let result = (col_start..=col_end)
+            .rev()
+            .map(|col| {
+                (row_start..=row_end)
+                    .map(|row| worksheet.get_value((col, row)))
+                    .collect()
+            })
+            .collect();
+        Some(result)
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, direction, "dir");
+
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.set_address_str(e.unescape().unwrap());
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:f" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:f"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:f
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.direction.has_value() {
+            attributes.push(("dir", self.direction.get_value_string()));
+        }
+        write_start_tag(writer, "cx:f", attributes, false);
+        write_text_node_no_escape(writer, self.get_address_str());
+        write_end_tag(writer, "cx:f");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Formula {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.address.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.address.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormulaDirectionValues {
+    Column,
+    Row,
+}
+impl Default for FormulaDirectionValues {
+    fn default() -> Self {
+        Self::Column
+    }
+}
+impl EnumTrait for FormulaDirectionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Column => "col",
+            Self::Row => "row",
+        }
+    }
+}
+impl FromStr for FormulaDirectionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "col" => Ok(Self::Column),
+            "row" => Ok(Self::Row),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum IntervalClosedSideValues {
+    Left,
+    Right,
+}
+impl Default for IntervalClosedSideValues {
+    fn default() -> Self {
+        Self::Right
+    }
+}
+impl EnumTrait for IntervalClosedSideValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Left => "l",
+            Self::Right => "r",
+        }
+    }
+}
+impl FromStr for IntervalClosedSideValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "l" => Ok(Self::Left),
+            "r" => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
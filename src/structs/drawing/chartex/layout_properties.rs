@@ -0,0 +1,207 @@
+// cx:layoutPr
+use super::Aggregation;
+use super::Binning;
+use super::ParentLabelLayout;
+use super::SeriesElementVisibilities;
+use super::Statistics;
+use super::Subtotals;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct LayoutProperties {
+    parent_label_layout: Option<ParentLabelLayout>,
+    visibility: Option<SeriesElementVisibilities>,
+    aggregation: Option<Aggregation>,
+    binning: Option<Binning>,
+    statistics: Option<Statistics>,
+    subtotals: Option<Subtotals>,
+}
+
+impl LayoutProperties {
+    pub fn get_parent_label_layout(&self) -> Option<&ParentLabelLayout> {
+        self.parent_label_layout.as_ref()
+    }
+
+    pub fn get_parent_label_layout_mut(&mut self) -> Option<&mut ParentLabelLayout> {
+        self.parent_label_layout.as_mut()
+    }
+
+    pub fn set_parent_label_layout(&mut self, value: ParentLabelLayout) -> &mut Self {
+        self.parent_label_layout = Some(value);
+        self
+    }
+
+    pub fn get_visibility(&self) -> Option<&SeriesElementVisibilities> {
+        self.visibility.as_ref()
+    }
+
+    pub fn get_visibility_mut(&mut self) -> Option<&mut SeriesElementVisibilities> {
+        self.visibility.as_mut()
+    }
+
+    pub fn set_visibility(&mut self, value: SeriesElementVisibilities) -> &mut Self {
+        self.visibility = Some(value);
+        self
+    }
+
+    pub fn get_aggregation(&self) -> Option<&Aggregation> {
+        self.aggregation.as_ref()
+    }
+
+    /// Group the values by category. Clears the binning.
+    pub fn set_aggregation(&mut self, value: Aggregation) -> &mut Self {
+        self.aggregation = Some(value);
+        self.binning = None;
+        self
+    }
+
+    pub fn get_binning(&self) -> Option<&Binning> {
+        self.binning.as_ref()
+    }
+
+    pub fn get_binning_mut(&mut self) -> Option<&mut Binning> {
+        self.binning.as_mut()
+    }
+
+    /// Group the values into bins. Clears the aggregation.
+    pub fn set_binning(&mut self, value: Binning) -> &mut Self {
+        self.binning = Some(value);
+        self.aggregation = None;
+        self
+    }
+
+    pub fn get_statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    pub fn get_statistics_mut(&mut self) -> Option<&mut Statistics> {
+        self.statistics.as_mut()
+    }
+
+    pub fn set_statistics(&mut self, value: Statistics) -> &mut Self {
+        self.statistics = Some(value);
+        self
+    }
+
+    pub fn get_subtotals(&self) -> Option<&Subtotals> {
+        self.subtotals.as_ref()
+    }
+
+    pub fn get_subtotals_mut(&mut self) -> Option<&mut Subtotals> {
+        self.subtotals.as_mut()
+    }
+
+    pub fn set_subtotals(&mut self, value: Subtotals) -> &mut Self {
+        self.subtotals = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:binning" => {
+                    let mut obj = Binning::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_binning(obj);
+                }
+                b"cx:subtotals" => {
+                    let mut obj = Subtotals::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_subtotals(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:parentLabelLayout" => {
+                    let mut obj = ParentLabelLayout::default();
+                    obj.set_attributes(reader, e);
+                    self.set_parent_label_layout(obj);
+                }
+                b"cx:visibility" => {
+                    let mut obj = SeriesElementVisibilities::default();
+                    obj.set_attributes(reader, e);
+                    self.set_visibility(obj);
+                }
+                b"cx:aggregation" => {
+                    let mut obj = Aggregation::default();
+                    obj.set_attributes(reader, e);
+                    self.set_aggregation(obj);
+                }
+                b"cx:binning" => {
+                    let mut obj = Binning::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_binning(obj);
+                }
+                b"cx:statistics" => {
+                    let mut obj = Statistics::default();
+                    obj.set_attributes(reader, e);
+                    self.set_statistics(obj);
+                }
+                b"cx:subtotals" => {
+                    let mut obj = Subtotals::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_subtotals(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:layoutPr" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:layoutPr"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:layoutPr
+        write_start_tag(writer, "cx:layoutPr", vec![], false);
+
+        // cx:parentLabelLayout
+        if let Some(v) = &self.parent_label_layout {
+            v.write_to(writer);
+        }
+
+        // cx:visibility
+        if let Some(v) = &self.visibility {
+            v.write_to(writer);
+        }
+
+        // cx:aggregation
+        if let Some(v) = &self.aggregation {
+            v.write_to(writer);
+        }
+
+        // cx:binning
+        if let Some(v) = &self.binning {
+            v.write_to(writer);
+        }
+
+        // cx:statistics
+        if let Some(v) = &self.statistics {
+            v.write_to(writer);
+        }
+
+        // cx:subtotals
+        if let Some(v) = &self.subtotals {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:layoutPr");
+    }
+}
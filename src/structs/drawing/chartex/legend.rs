@@ -0,0 +1,71 @@
+// cx:legend
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::PositionAlignmentValues;
+use super::SidePositionValues;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Legend {
+    position: EnumValue<SidePositionValues>,
+    alignment: EnumValue<PositionAlignmentValues>,
+    overlay: BooleanValue,
+}
+
+impl Legend {
+    pub fn get_position(&self) -> &SidePositionValues {
+        self.position.get_value()
+    }
+
+    pub fn set_position(&mut self, value: SidePositionValues) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    pub fn get_alignment(&self) -> &PositionAlignmentValues {
+        self.alignment.get_value()
+    }
+
+    pub fn set_alignment(&mut self, value: PositionAlignmentValues) -> &mut Self {
+        self.alignment.set_value(value);
+        self
+    }
+
+    pub fn get_overlay(&self) -> &bool {
+        self.overlay.get_value()
+    }
+
+    pub fn set_overlay(&mut self, value: bool) -> &mut Self {
+        self.overlay.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, position, "pos");
+        set_string_from_xml!(self, e, alignment, "align");
+        set_string_from_xml!(self, e, overlay, "overlay");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:legend
+        write_start_tag(
+            writer,
+            "cx:legend",
+            vec![
+                ("pos", self.position.get_value_string()),
+                ("align", self.alignment.get_value_string()),
+                ("overlay", self.overlay.get_value_string()),
+            ],
+            true,
+        );
+    }
+}
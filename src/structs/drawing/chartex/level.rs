@@ -0,0 +1,132 @@
+// cx:lvl
+use super::super::super::StringValue;
+use super::super::super::UInt32Value;
+use super::Point;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct Level {
+    point_count: UInt32Value,
+    format_code: StringValue,
+    name: StringValue,
+    point: ThinVec<Point>,
+}
+
+impl Level {
+    pub fn get_point_count(&self) -> &u32 {
+        self.point_count.get_value()
+    }
+
+    pub fn set_point_count(&mut self, value: u32) -> &mut Self {
+        self.point_count.set_value(value);
+        self
+    }
+
+    pub fn get_format_code(&self) -> Option<&str> {
+        self.format_code.get_value()
+    }
+
+    pub fn set_format_code<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.format_code.set_value(value);
+        self
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.get_value()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    pub fn get_point(&self) -> &[Point] {
+        &self.point
+    }
+
+    pub fn get_point_mut(&mut self) -> &mut ThinVec<Point> {
+        &mut self.point
+    }
+
+    pub fn add_point(&mut self, value: Point) -> &mut Self {
+        self.point.push(value);
+        self
+    }
+
+    /// Make a level from cell values. Empty cells have no point.
+    pub(crate) fn from_value_list(value_list: &[String]) -> Self {
+        let mut level = Self::default();
+        level.set_point_count(value_list.len() as u32);
+        for (index, value) in value_list.iter().enumerate() {
+            if value.is_empty() {
+                continue;
+            }
+            let mut point = Point::default();
+            point.set_index(index as u32);
+            point.set_value(value);
+            level.add_point(point);
+        }
+        level
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, point_count, "ptCount");
+        set_string_from_xml!(self, e, format_code, "formatCode");
+        set_string_from_xml!(self, e, name, "name");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:pt" {
+                    let mut obj = Point::default();
+                    obj.set_attributes(reader, e);
+                    self.add_point(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:lvl" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:lvl"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:lvl
+        let point_count = self.point_count.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = vec![("ptCount", &point_count)];
+        if self.format_code.has_value() {
+            attributes.push(("formatCode", self.format_code.get_value_str()));
+        }
+        if self.name.has_value() {
+            attributes.push(("name", self.name.get_value_str()));
+        }
+        write_start_tag(writer, "cx:lvl", attributes, self.point.is_empty());
+        if self.point.is_empty() {
+            return;
+        }
+
+        // cx:pt
+        for v in &self.point {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:lvl");
+    }
+}
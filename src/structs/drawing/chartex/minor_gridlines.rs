@@ -0,0 +1,23 @@
+// cx:minorGridlines
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct MinorGridlines {}
+
+impl MinorGridlines {
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:minorGridlines
+        write_start_tag(writer, "cx:minorGridlines", vec![], true);
+    }
+}
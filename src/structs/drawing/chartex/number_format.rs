@@ -0,0 +1,54 @@
+// cx:numFmt
+use super::super::super::BooleanValue;
+use super::super::super::StringValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct NumberFormat {
+    format_code: StringValue,
+    source_linked: BooleanValue,
+}
+
+impl NumberFormat {
+    pub fn get_format_code(&self) -> &str {
+        self.format_code.get_value_str()
+    }
+
+    pub fn set_format_code<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.format_code.set_value(value);
+        self
+    }
+
+    pub fn get_source_linked(&self) -> &bool {
+        self.source_linked.get_value()
+    }
+
+    pub fn set_source_linked(&mut self, value: bool) -> &mut Self {
+        self.source_linked.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, format_code, "formatCode");
+        set_string_from_xml!(self, e, source_linked, "sourceLinked");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:numFmt
+        let mut attributes: Vec<(&str, &str)> =
+            vec![("formatCode", self.format_code.get_value_str())];
+        if self.source_linked.has_value() {
+            attributes.push(("sourceLinked", self.source_linked.get_value_string()));
+        }
+        write_start_tag(writer, "cx:numFmt", attributes, true);
+    }
+}
@@ -0,0 +1,39 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum NumericDimensionValues {
+    Value,
+    X,
+    Y,
+    Size,
+    ColorValue,
+}
+impl Default for NumericDimensionValues {
+    fn default() -> Self {
+        Self::Value
+    }
+}
+impl EnumTrait for NumericDimensionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Value => "val",
+            Self::X => "x",
+            Self::Y => "y",
+            Self::Size => "size",
+            Self::ColorValue => "colorVal",
+        }
+    }
+}
+impl FromStr for NumericDimensionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "val" => Ok(Self::Value),
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            "size" => Ok(Self::Size),
+            "colorVal" => Ok(Self::ColorValue),
+            _ => Err(()),
+        }
+    }
+}
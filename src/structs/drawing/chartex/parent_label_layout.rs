@@ -0,0 +1,43 @@
+// cx:parentLabelLayout
+use super::super::super::EnumValue;
+use super::ParentLabelLayoutValues;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ParentLabelLayout {
+    val: EnumValue<ParentLabelLayoutValues>,
+}
+
+impl ParentLabelLayout {
+    pub fn get_val(&self) -> &ParentLabelLayoutValues {
+        self.val.get_value()
+    }
+
+    pub fn set_val(&mut self, value: ParentLabelLayoutValues) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, val, "val");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:parentLabelLayout
+        write_start_tag(
+            writer,
+            "cx:parentLabelLayout",
+            vec![("val", self.val.get_value_string())],
+            true,
+        );
+    }
+}
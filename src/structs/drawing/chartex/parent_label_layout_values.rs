@@ -0,0 +1,33 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParentLabelLayoutValues {
+    None,
+    Banner,
+    Overlapping,
+}
+impl Default for ParentLabelLayoutValues {
+    fn default() -> Self {
+        Self::Overlapping
+    }
+}
+impl EnumTrait for ParentLabelLayoutValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::None => "none",
+            Self::Banner => "banner",
+            Self::Overlapping => "overlapping",
+        }
+    }
+}
+impl FromStr for ParentLabelLayoutValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(Self::None),
+            "banner" => Ok(Self::Banner),
+            "overlapping" => Ok(Self::Overlapping),
+            _ => Err(()),
+        }
+    }
+}
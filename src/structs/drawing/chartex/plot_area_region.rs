@@ -0,0 +1,105 @@
+// cx:plotAreaRegion
+use super::Series;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct PlotAreaRegion {
+    series: ThinVec<Series>,
+}
+
+impl PlotAreaRegion {
+    pub fn get_series(&self) -> &[Series] {
+        &self.series
+    }
+
+    pub fn get_series_mut(&mut self) -> &mut ThinVec<Series> {
+        &mut self.series
+    }
+
+    pub fn add_series(&mut self, value: Series) -> &mut Self {
+        self.series.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:series" {
+                    let mut obj = Series::default();
+                    obj.set_attributes(reader, e);
+                    self.add_series(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:plotAreaRegion" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:plotAreaRegion"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:plotAreaRegion
+        write_start_tag(writer, "cx:plotAreaRegion", vec![], false);
+
+        // cx:series
+        for v in &self.series {
+            v.write_to(writer, spreadsheet);
+        }
+
+        write_end_tag(writer, "cx:plotAreaRegion");
+    }
+}
+impl AdjustmentCoordinateWithSheet for PlotAreaRegion {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.series {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.series {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
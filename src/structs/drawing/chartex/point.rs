@@ -0,0 +1,67 @@
+// cx:pt
+use super::super::super::UInt32Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Point {
+    index: UInt32Value,
+    value: Box<str>,
+}
+
+impl Point {
+    pub fn get_index(&self) -> &u32 {
+        self.index.get_value()
+    }
+
+    pub fn set_index(&mut self, value: u32) -> &mut Self {
+        self.index.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value = value.into().into_boxed_str();
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, index, "idx");
+
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.set_value(e.unescape().unwrap());
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:pt" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:pt"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:pt
+        write_start_tag(
+            writer,
+            "cx:pt",
+            vec![("idx", &self.index.get_value_string())],
+            false,
+        );
+        write_text_node(writer, &*self.value);
+        write_end_tag(writer, "cx:pt");
+    }
+}
@@ -0,0 +1,33 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum PositionAlignmentValues {
+    Minimum,
+    Center,
+    Maximum,
+}
+impl Default for PositionAlignmentValues {
+    fn default() -> Self {
+        Self::Center
+    }
+}
+impl EnumTrait for PositionAlignmentValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Minimum => "min",
+            Self::Center => "ctr",
+            Self::Maximum => "max",
+        }
+    }
+}
+impl FromStr for PositionAlignmentValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "min" => Ok(Self::Minimum),
+            "ctr" => Ok(Self::Center),
+            "max" => Ok(Self::Maximum),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuartileMethodValues {
+    Inclusive,
+    Exclusive,
+}
+impl Default for QuartileMethodValues {
+    fn default() -> Self {
+        Self::Exclusive
+    }
+}
+impl EnumTrait for QuartileMethodValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Inclusive => "inclusive",
+            Self::Exclusive => "exclusive",
+        }
+    }
+}
+impl FromStr for QuartileMethodValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "inclusive" => Ok(Self::Inclusive),
+            "exclusive" => Ok(Self::Exclusive),
+            _ => Err(()),
+        }
+    }
+}
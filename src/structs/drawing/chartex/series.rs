@@ -0,0 +1,301 @@
+// cx:series
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::super::super::StringValue;
+use super::super::super::UInt32Value;
+use super::AxisId;
+use super::ChartText;
+use super::DataId;
+use super::DataLabels;
+use super::LayoutProperties;
+use super::SeriesLayoutValues;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct Series {
+    layout_id: EnumValue<SeriesLayoutValues>,
+    hidden: BooleanValue,
+    owner_index: UInt32Value,
+    unique_id: StringValue,
+    format_index: UInt32Value,
+    chart_text: Option<ChartText>,
+    data_labels: Option<DataLabels>,
+    data_id: Option<DataId>,
+    layout_properties: Option<LayoutProperties>,
+    axis_id: ThinVec<AxisId>,
+}
+
+impl Series {
+    pub fn get_layout_id(&self) -> &SeriesLayoutValues {
+        self.layout_id.get_value()
+    }
+
+    pub fn set_layout_id(&mut self, value: SeriesLayoutValues) -> &mut Self {
+        self.layout_id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    /// Index of the series this series is derived from (e.g. the pareto line).
+    pub fn get_owner_index(&self) -> Option<&u32> {
+        self.owner_index
+            .has_value()
+            .then(|| self.owner_index.get_value())
+    }
+
+    pub fn set_owner_index(&mut self, value: u32) -> &mut Self {
+        self.owner_index.set_value(value);
+        self
+    }
+
+    pub fn get_unique_id(&self) -> Option<&str> {
+        self.unique_id.get_value()
+    }
+
+    pub fn set_unique_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.unique_id.set_value(value);
+        self
+    }
+
+    pub fn get_format_index(&self) -> Option<&u32> {
+        self.format_index
+            .has_value()
+            .then(|| self.format_index.get_value())
+    }
+
+    pub fn set_format_index(&mut self, value: u32) -> &mut Self {
+        self.format_index.set_value(value);
+        self
+    }
+
+    pub fn get_chart_text(&self) -> Option<&ChartText> {
+        self.chart_text.as_ref()
+    }
+
+    pub fn get_chart_text_mut(&mut self) -> Option<&mut ChartText> {
+        self.chart_text.as_mut()
+    }
+
+    pub fn set_chart_text(&mut self, value: ChartText) -> &mut Self {
+        self.chart_text = Some(value);
+        self
+    }
+
+    pub fn get_data_labels(&self) -> Option<&DataLabels> {
+        self.data_labels.as_ref()
+    }
+
+    pub fn get_data_labels_mut(&mut self) -> Option<&mut DataLabels> {
+        self.data_labels.as_mut()
+    }
+
+    pub fn set_data_labels(&mut self, value: DataLabels) -> &mut Self {
+        self.data_labels = Some(value);
+        self
+    }
+
+    pub fn get_data_id(&self) -> Option<&DataId> {
+        self.data_id.as_ref()
+    }
+
+    pub fn get_data_id_mut(&mut self) -> Option<&mut DataId> {
+        self.data_id.as_mut()
+    }
+
+    pub fn set_data_id(&mut self, value: DataId) -> &mut Self {
+        self.data_id = Some(value);
+        self
+    }
+
+    pub fn get_layout_properties(&self) -> Option<&LayoutProperties> {
+        self.layout_properties.as_ref()
+    }
+
+    pub fn get_layout_properties_mut(&mut self) -> Option<&mut LayoutProperties> {
+        self.layout_properties.as_mut()
+    }
+
+    pub fn set_layout_properties(&mut self, value: LayoutProperties) -> &mut Self {
+        self.layout_properties = Some(value);
+        self
+    }
+
+    pub fn get_axis_id(&self) -> &[AxisId] {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self) -> &mut ThinVec<AxisId> {
+        &mut self.axis_id
+    }
+
+    pub fn add_axis_id(&mut self, value: AxisId) -> &mut Self {
+        self.axis_id.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, layout_id, "layoutId");
+        set_string_from_xml!(self, e, hidden, "hidden");
+        set_string_from_xml!(self, e, owner_index, "ownerIdx");
+        set_string_from_xml!(self, e, unique_id, "uniqueId");
+        set_string_from_xml!(self, e, format_index, "formatIdx");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:tx" => {
+                    let mut obj = ChartText::default();
+                    obj.set_attributes(reader, e);
+                    self.set_chart_text(obj);
+                }
+                b"cx:dataLabels" => {
+                    let mut obj = DataLabels::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_data_labels(obj);
+                }
+                b"cx:layoutPr" => {
+                    let mut obj = LayoutProperties::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_layout_properties(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:dataLabels" => {
+                    let mut obj = DataLabels::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_data_labels(obj);
+                }
+                b"cx:dataId" => {
+                    let mut obj = DataId::default();
+                    obj.set_attributes(reader, e);
+                    self.set_data_id(obj);
+                }
+                b"cx:layoutPr" => {
+                    let mut obj = LayoutProperties::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_layout_properties(obj);
+                }
+                b"cx:axisId" => {
+                    let mut obj = AxisId::default();
+                    obj.set_attributes(reader, e);
+                    self.add_axis_id(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:series" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:series"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:series
+        let owner_index = self.owner_index.get_value_string();
+        let format_index = self.format_index.get_value_string();
+        let mut attributes: Vec<(&str, &str)> =
+            vec![("layoutId", self.layout_id.get_value_string())];
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.get_value_string()));
+        }
+        if self.owner_index.has_value() {
+            attributes.push(("ownerIdx", &owner_index));
+        }
+        if self.unique_id.has_value() {
+            attributes.push(("uniqueId", self.unique_id.get_value_str()));
+        }
+        if self.format_index.has_value() {
+            attributes.push(("formatIdx", &format_index));
+        }
+        write_start_tag(writer, "cx:series", attributes, false);
+
+        // cx:tx
+        if let Some(v) = &self.chart_text {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:dataLabels
+        if let Some(v) = &self.data_labels {
+            v.write_to(writer);
+        }
+
+        // cx:dataId
+        if let Some(v) = &self.data_id {
+            v.write_to(writer);
+        }
+
+        // cx:layoutPr
+        if let Some(v) = &self.layout_properties {
+            v.write_to(writer);
+        }
+
+        // cx:axisId
+        for v in &self.axis_id {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:series");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Series {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.chart_text {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.chart_text {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
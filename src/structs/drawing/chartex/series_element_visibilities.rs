@@ -0,0 +1,97 @@
+// cx:visibility
+use super::super::super::BooleanValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct SeriesElementVisibilities {
+    connector_lines: BooleanValue,
+    mean_line: BooleanValue,
+    mean_marker: BooleanValue,
+    nonoutliers: BooleanValue,
+    outliers: BooleanValue,
+}
+
+impl SeriesElementVisibilities {
+    pub fn get_connector_lines(&self) -> &bool {
+        self.connector_lines.get_value()
+    }
+
+    pub fn set_connector_lines(&mut self, value: bool) -> &mut Self {
+        self.connector_lines.set_value(value);
+        self
+    }
+
+    pub fn get_mean_line(&self) -> &bool {
+        self.mean_line.get_value()
+    }
+
+    pub fn set_mean_line(&mut self, value: bool) -> &mut Self {
+        self.mean_line.set_value(value);
+        self
+    }
+
+    pub fn get_mean_marker(&self) -> &bool {
+        self.mean_marker.get_value()
+    }
+
+    pub fn set_mean_marker(&mut self, value: bool) -> &mut Self {
+        self.mean_marker.set_value(value);
+        self
+    }
+
+    pub fn get_nonoutliers(&self) -> &bool {
+        self.nonoutliers.get_value()
+    }
+
+    pub fn set_nonoutliers(&mut self, value: bool) -> &mut Self {
+        self.nonoutliers.set_value(value);
+        self
+    }
+
+    pub fn get_outliers(&self) -> &bool {
+        self.outliers.get_value()
+    }
+
+    pub fn set_outliers(&mut self, value: bool) -> &mut Self {
+        self.outliers.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, connector_lines, "connectorLines");
+        set_string_from_xml!(self, e, mean_line, "meanLine");
+        set_string_from_xml!(self, e, mean_marker, "meanMarker");
+        set_string_from_xml!(self, e, nonoutliers, "nonoutliers");
+        set_string_from_xml!(self, e, outliers, "outliers");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:visibility
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.connector_lines.has_value() {
+            attributes.push(("connectorLines", self.connector_lines.get_value_string()));
+        }
+        if self.mean_line.has_value() {
+            attributes.push(("meanLine", self.mean_line.get_value_string()));
+        }
+        if self.mean_marker.has_value() {
+            attributes.push(("meanMarker", self.mean_marker.get_value_string()));
+        }
+        if self.nonoutliers.has_value() {
+            attributes.push(("nonoutliers", self.nonoutliers.get_value_string()));
+        }
+        if self.outliers.has_value() {
+            attributes.push(("outliers", self.outliers.get_value_string()));
+        }
+        write_start_tag(writer, "cx:visibility", attributes, true);
+    }
+}
@@ -0,0 +1,36 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum SidePositionValues {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+impl Default for SidePositionValues {
+    fn default() -> Self {
+        Self::Top
+    }
+}
+impl EnumTrait for SidePositionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Left => "l",
+            Self::Top => "t",
+            Self::Right => "r",
+            Self::Bottom => "b",
+        }
+    }
+}
+impl FromStr for SidePositionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "l" => Ok(Self::Left),
+            "t" => Ok(Self::Top),
+            "r" => Ok(Self::Right),
+            "b" => Ok(Self::Bottom),
+            _ => Err(()),
+        }
+    }
+}
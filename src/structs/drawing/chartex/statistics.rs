@@ -0,0 +1,43 @@
+// cx:statistics
+use super::super::super::EnumValue;
+use super::QuartileMethodValues;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Statistics {
+    quartile_method: EnumValue<QuartileMethodValues>,
+}
+
+impl Statistics {
+    pub fn get_quartile_method(&self) -> &QuartileMethodValues {
+        self.quartile_method.get_value()
+    }
+
+    pub fn set_quartile_method(&mut self, value: QuartileMethodValues) -> &mut Self {
+        self.quartile_method.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, quartile_method, "quartileMethod");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:statistics
+        write_start_tag(
+            writer,
+            "cx:statistics",
+            vec![("quartileMethod", self.quartile_method.get_value_string())],
+            true,
+        );
+    }
+}
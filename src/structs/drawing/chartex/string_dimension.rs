@@ -0,0 +1,176 @@
+// cx:strDim
+use super::super::super::EnumValue;
+use super::Formula;
+use super::Level;
+use super::StringDimensionValues;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct StringDimension {
+    r#type: EnumValue<StringDimensionValues>,
+    formula: Option<Formula>,
+    level: ThinVec<Level>,
+}
+
+impl StringDimension {
+    pub fn get_type(&self) -> &StringDimensionValues {
+        self.r#type.get_value()
+    }
+
+    pub fn set_type(&mut self, value: StringDimensionValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    pub fn get_formula(&self) -> Option<&Formula> {
+        self.formula.as_ref()
+    }
+
+    pub fn get_formula_mut(&mut self) -> Option<&mut Formula> {
+        self.formula.as_mut()
+    }
+
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = Some(value);
+        self
+    }
+
+    /// Levels hold the cached values of the formula.
+    /// They are rebuilt from the worksheet when the file is written.
+    pub fn get_level(&self) -> &[Level] {
+        &self.level
+    }
+
+    pub fn get_level_mut(&mut self) -> &mut ThinVec<Level> {
+        &mut self.level
+    }
+
+    pub fn add_level(&mut self, value: Level) -> &mut Self {
+        self.level.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, r#type, "type");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:f" => {
+                    let mut obj = Formula::default();
+                    obj.set_attributes(reader, e);
+                    self.set_formula(obj);
+                }
+                b"cx:lvl" => {
+                    let mut obj = Level::default();
+                    obj.set_attributes(reader, e, false);
+                    self.add_level(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"cx:lvl" {
+                    let mut obj = Level::default();
+                    obj.set_attributes(reader, e, true);
+                    self.add_level(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:strDim" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:strDim"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:strDim
+        write_start_tag(
+            writer,
+            "cx:strDim",
+            vec![("type", self.r#type.get_value_string())],
+            false,
+        );
+
+        // cx:f
+        let value_list = match &self.formula {
+            Some(v) => {
+                v.write_to(writer);
+                v.get_level_value_list(spreadsheet)
+            }
+            None => None,
+        };
+
+        // cx:lvl
+        match value_list {
+            Some(value_list) => {
+                for (index, values) in value_list.iter().enumerate() {
+                    let mut level = Level::from_value_list(values);
+                    if let Some(name) = self.level.get(index).and_then(|v| v.get_name()) {
+                        level.set_name(name);
+                    }
+                    level.write_to(writer);
+                }
+            }
+            None => {
+                for v in &self.level {
+                    v.write_to(writer);
+                }
+            }
+        }
+
+        write_end_tag(writer, "cx:strDim");
+    }
+}
+impl AdjustmentCoordinateWithSheet for StringDimension {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
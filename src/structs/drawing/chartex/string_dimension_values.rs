@@ -0,0 +1,33 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum StringDimensionValues {
+    Category,
+    ColorString,
+    EntityId,
+}
+impl Default for StringDimensionValues {
+    fn default() -> Self {
+        Self::Category
+    }
+}
+impl EnumTrait for StringDimensionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Category => "cat",
+            Self::ColorString => "colorStr",
+            Self::EntityId => "entityId",
+        }
+    }
+}
+impl FromStr for StringDimensionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "cat" => Ok(Self::Category),
+            "colorStr" => Ok(Self::ColorString),
+            "entityId" => Ok(Self::EntityId),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// cx:subtotals
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct Subtotals {
+    index: ThinVec<u32>,
+}
+
+impl Subtotals {
+    /// Indexes of the data points drawn as totals.
+    pub fn get_index(&self) -> &[u32] {
+        &self.index
+    }
+
+    pub fn get_index_mut(&mut self) -> &mut ThinVec<u32> {
+        &mut self.index
+    }
+
+    pub fn add_index(&mut self, value: u32) -> &mut Self {
+        self.index.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().0 == b"cx:idx" {
+                    if let Some(v) = get_attribute(e, b"val").and_then(|v| v.parse().ok()) {
+                        self.add_index(v);
+                    }
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:subtotals" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:subtotals"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:subtotals
+        write_start_tag(writer, "cx:subtotals", vec![], self.index.is_empty());
+        if self.index.is_empty() {
+            return;
+        }
+
+        // cx:idx
+        for v in &self.index {
+            write_start_tag(writer, "cx:idx", vec![("val", &v.to_string())], true);
+        }
+
+        write_end_tag(writer, "cx:subtotals");
+    }
+}
@@ -0,0 +1,23 @@
+// cx:tickLabels
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct TickLabels {}
+
+impl TickLabels {
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:tickLabels
+        write_start_tag(writer, "cx:tickLabels", vec![], true);
+    }
+}
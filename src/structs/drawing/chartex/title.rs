@@ -0,0 +1,170 @@
+// cx:title
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::ChartText;
+use super::PositionAlignmentValues;
+use super::SidePositionValues;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Title {
+    position: EnumValue<SidePositionValues>,
+    alignment: EnumValue<PositionAlignmentValues>,
+    overlay: BooleanValue,
+    chart_text: Option<ChartText>,
+}
+
+impl Title {
+    pub fn get_position(&self) -> &SidePositionValues {
+        self.position.get_value()
+    }
+
+    pub fn set_position(&mut self, value: SidePositionValues) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    pub fn get_alignment(&self) -> &PositionAlignmentValues {
+        self.alignment.get_value()
+    }
+
+    pub fn set_alignment(&mut self, value: PositionAlignmentValues) -> &mut Self {
+        self.alignment.set_value(value);
+        self
+    }
+
+    pub fn get_overlay(&self) -> &bool {
+        self.overlay.get_value()
+    }
+
+    pub fn set_overlay(&mut self, value: bool) -> &mut Self {
+        self.overlay.set_value(value);
+        self
+    }
+
+    pub fn get_chart_text(&self) -> Option<&ChartText> {
+        self.chart_text.as_ref()
+    }
+
+    pub fn get_chart_text_mut(&mut self) -> Option<&mut ChartText> {
+        self.chart_text.as_mut()
+    }
+
+    pub fn set_chart_text(&mut self, value: ChartText) -> &mut Self {
+        self.chart_text = Some(value);
+        self
+    }
+
+    /// Get the plain text of the title.
+    pub fn get_text(&self) -> &str {
+        match &self.chart_text {
+            Some(v) => v.get_text_data().get_value(),
+            None => "",
+        }
+    }
+
+    /// Set the title to a plain text.
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut chart_text = ChartText::default();
+        chart_text.get_text_data_mut().set_value(value);
+        self.set_chart_text(chart_text)
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, position, "pos");
+        set_string_from_xml!(self, e, alignment, "align");
+        set_string_from_xml!(self, e, overlay, "overlay");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:tx" {
+                    let mut obj = ChartText::default();
+                    obj.set_attributes(reader, e);
+                    self.set_chart_text(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:title" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "cx:title"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:title
+        write_start_tag(
+            writer,
+            "cx:title",
+            vec![
+                ("pos", self.position.get_value_string()),
+                ("align", self.alignment.get_value_string()),
+                ("overlay", self.overlay.get_value_string()),
+            ],
+            self.chart_text.is_none(),
+        );
+
+        // cx:tx
+        if let Some(v) = &self.chart_text {
+            v.write_to(writer, spreadsheet);
+            write_end_tag(writer, "cx:title");
+        }
+    }
+}
+impl AdjustmentCoordinateWithSheet for Title {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.chart_text {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.chart_text {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// cx:valScaling
+use super::super::super::StringValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// Axis bounds and units. Each value is `"auto"` or a number.
+#[derive(Clone, Default, Debug)]
+pub struct ValueScaling {
+    max: StringValue,
+    min: StringValue,
+    major_unit: StringValue,
+    minor_unit: StringValue,
+}
+
+impl ValueScaling {
+    pub fn get_max(&self) -> Option<&str> {
+        self.max.get_value()
+    }
+
+    pub fn set_max<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.max.set_value(value);
+        self
+    }
+
+    pub fn get_min(&self) -> Option<&str> {
+        self.min.get_value()
+    }
+
+    pub fn set_min<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.min.set_value(value);
+        self
+    }
+
+    pub fn get_major_unit(&self) -> Option<&str> {
+        self.major_unit.get_value()
+    }
+
+    pub fn set_major_unit<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.major_unit.set_value(value);
+        self
+    }
+
+    pub fn get_minor_unit(&self) -> Option<&str> {
+        self.minor_unit.get_value()
+    }
+
+    pub fn set_minor_unit<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.minor_unit.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, max, "max");
+        set_string_from_xml!(self, e, min, "min");
+        set_string_from_xml!(self, e, major_unit, "majorUnit");
+        set_string_from_xml!(self, e, minor_unit, "minorUnit");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:valScaling
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.max.has_value() {
+            attributes.push(("max", self.max.get_value_str()));
+        }
+        if self.min.has_value() {
+            attributes.push(("min", self.min.get_value_str()));
+        }
+        if self.major_unit.has_value() {
+            attributes.push(("majorUnit", self.major_unit.get_value_str()));
+        }
+        if self.minor_unit.has_value() {
+            attributes.push(("minorUnit", self.minor_unit.get_value_str()));
+        }
+        write_start_tag(writer, "cx:valScaling", attributes, true);
+    }
+}
@@ -426,3 +426,159 @@ pub use self::area_chart_series_list::*;
 
 mod series_text;
 pub use self::series_text::*;
+
+mod high_low_lines;
+pub use self::high_low_lines::*;
+
+mod drop_lines;
+pub use self::drop_lines::*;
+
+mod up_bars;
+pub use self::up_bars::*;
+
+mod down_bars;
+pub use self::down_bars::*;
+
+mod up_down_bars;
+pub use self::up_down_bars::*;
+
+mod stock_chart;
+pub use self::stock_chart::*;
+
+mod wireframe;
+pub use self::wireframe::*;
+
+mod band_format;
+pub use self::band_format::*;
+
+mod band_formats;
+pub use self::band_formats::*;
+
+mod surface_chart;
+pub use self::surface_chart::*;
+
+mod surface_3d_chart;
+pub use self::surface_3d_chart::*;
+
+mod crosses_at;
+pub use self::crosses_at::*;
+
+mod trendline;
+pub use self::trendline::*;
+
+mod trendline_label;
+pub use self::trendline_label::*;
+
+mod trendline_name;
+pub use self::trendline_name::*;
+
+mod trendline_type;
+pub use self::trendline_type::*;
+
+mod trendline_values;
+pub use self::trendline_values::*;
+
+mod polynomial_order;
+pub use self::polynomial_order::*;
+
+mod period;
+pub use self::period::*;
+
+mod forward;
+pub use self::forward::*;
+
+mod backward;
+pub use self::backward::*;
+
+mod intercept;
+pub use self::intercept::*;
+
+mod display_r_squared_value;
+pub use self::display_r_squared_value::*;
+
+mod display_equation;
+pub use self::display_equation::*;
+
+mod error_bars;
+pub use self::error_bars::*;
+
+mod error_direction;
+pub use self::error_direction::*;
+
+mod error_bar_direction_values;
+pub use self::error_bar_direction_values::*;
+
+mod error_bar_type;
+pub use self::error_bar_type::*;
+
+mod error_bar_values;
+pub use self::error_bar_values::*;
+
+mod error_bar_value_type;
+pub use self::error_bar_value_type::*;
+
+mod error_values;
+pub use self::error_values::*;
+
+mod error_bar_value;
+pub use self::error_bar_value::*;
+
+mod no_end_cap;
+pub use self::no_end_cap::*;
+
+mod plus;
+pub use self::plus::*;
+
+mod minus;
+pub use self::minus::*;
+
+mod data_table;
+pub use self::data_table::*;
+
+mod show_horizontal_border;
+pub use self::show_horizontal_border::*;
+
+mod show_vertical_border;
+pub use self::show_vertical_border::*;
+
+mod show_outline_border;
+pub use self::show_outline_border::*;
+
+mod show_keys;
+pub use self::show_keys::*;
+
+mod time_unit_values;
+pub use self::time_unit_values::*;
+
+mod base_time_unit;
+pub use self::base_time_unit::*;
+
+mod major_time_unit;
+pub use self::major_time_unit::*;
+
+mod minor_time_unit;
+pub use self::minor_time_unit::*;
+
+mod major_unit;
+pub use self::major_unit::*;
+
+mod minor_unit;
+pub use self::minor_unit::*;
+
+mod date_axis;
+pub use self::date_axis::*;
+
+mod built_in_unit_values;
+pub use self::built_in_unit_values::*;
+
+mod built_in_unit;
+pub use self::built_in_unit::*;
+
+mod custom_unit;
+pub use self::custom_unit::*;
+
+mod display_units_label;
+pub use self::display_units_label::*;
+
+mod display_units;
+pub use self::display_units::*;
@@ -3,6 +3,7 @@ use super::Bubble3D;
 use super::BubbleSize;
 use super::CategoryAxisData;
 use super::DataLabels;
+use super::ErrorBars;
 use super::Explosion;
 use super::Formula;
 use super::Index;
@@ -12,6 +13,7 @@ use super::Order;
 use super::SeriesText;
 use super::ShapeProperties;
 use super::Smooth;
+use super::Trendline;
 use super::Values;
 use super::XValues;
 use super::YValues;
@@ -22,6 +24,7 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct AreaChartSeries {
@@ -40,6 +43,8 @@ pub struct AreaChartSeries {
     bubble_3d: Option<Bubble3D>,
     smooth: Option<Smooth>,
     data_labels: Option<DataLabels>,
+    trendline: ThinVec<Trendline>,
+    error_bars: ThinVec<ErrorBars>,
 }
 
 impl AreaChartSeries {
@@ -108,6 +113,11 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn remove_invert_if_negative(&mut self) -> &mut Self {
+        self.invert_if_negative = None;
+        self
+    }
+
     pub fn get_marker(&self) -> Option<&Marker> {
         self.marker.as_ref()
     }
@@ -238,13 +248,47 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_trendline(&self) -> &[Trendline] {
+        &self.trendline
+    }
+
+    pub fn get_trendline_mut(&mut self) -> &mut ThinVec<Trendline> {
+        &mut self.trendline
+    }
+
+    pub fn set_trendline(&mut self, value: impl Into<ThinVec<Trendline>>) -> &mut Self {
+        self.trendline = value.into();
+        self
+    }
+
+    pub fn add_trendline(&mut self, value: Trendline) -> &mut Self {
+        self.trendline.push(value);
+        self
+    }
+
+    pub fn get_error_bars(&self) -> &[ErrorBars] {
+        &self.error_bars
+    }
+
+    pub fn get_error_bars_mut(&mut self) -> &mut ThinVec<ErrorBars> {
+        &mut self.error_bars
+    }
+
+    pub fn set_error_bars(&mut self, value: impl Into<ThinVec<ErrorBars>>) -> &mut Self {
+        self.error_bars = value.into();
+        self
+    }
+
+    pub fn add_error_bars(&mut self, value: ErrorBars) -> &mut Self {
+        self.error_bars.push(value);
+        self
+    }
+
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
 
         if let Some(v) = &mut self.category_axis_data {
-            if let Some(h) = v.get_string_reference_mut() {
-                result.push(h.get_formula_mut());
-            }
+            result.extend(v.get_formula_mut());
         }
         if let Some(v) = &mut self.values {
             result.push(v.get_number_reference_mut().get_formula_mut());
@@ -258,6 +302,9 @@ impl AreaChartSeries {
         if let Some(v) = &mut self.bubble_size {
             result.push(v.get_number_reference_mut().get_formula_mut());
         }
+        for v in &mut self.error_bars {
+            result.extend(v.get_formula_mut());
+        }
         result
     }
 
@@ -314,6 +361,16 @@ impl AreaChartSeries {
                     obj.set_attributes(reader, e);
                     self.set_data_labels(obj);
                 }
+                b"c:trendline" => {
+                    let mut obj = Trendline::default();
+                    obj.set_attributes(reader, e);
+                    self.add_trendline(obj);
+                }
+                b"c:errBars" => {
+                    let mut obj = ErrorBars::default();
+                    obj.set_attributes(reader, e);
+                    self.add_error_bars(obj);
+                }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
@@ -369,6 +426,11 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
         // c:explosion
         if let Some(v) = &self.explosion {
             v.write_to(writer);
@@ -384,16 +446,21 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
-        // c:spPr
-        if let Some(v) = &self.shape_properties {
+        // c:dLbls
+        if let Some(v) = &self.data_labels {
             v.write_to(writer);
         }
 
-        // c:dLbls
-        if let Some(v) = &self.data_labels {
+        // c:trendline
+        for v in &self.trendline {
             v.write_to(writer);
         }
 
+        // c:errBars
+        for v in &self.error_bars {
+            v.write_to(writer, spreadsheet);
+        }
+
         // c:cat
         if let Some(v) = &self.category_axis_data {
             v.write_to(writer, spreadsheet);
@@ -0,0 +1,73 @@
+// c:bandFmts
+use super::BandFormat;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct BandFormats {
+    band_format: ThinVec<BandFormat>,
+}
+
+impl BandFormats {
+    pub fn get_band_format(&self) -> &[BandFormat] {
+        &self.band_format
+    }
+
+    pub fn get_band_format_mut(&mut self) -> &mut ThinVec<BandFormat> {
+        &mut self.band_format
+    }
+
+    pub fn set_band_format(&mut self, value: impl Into<ThinVec<BandFormat>>) -> &mut Self {
+        self.band_format = value.into();
+        self
+    }
+
+    pub fn add_band_format(&mut self, value: BandFormat) -> &mut Self {
+        self.band_format.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"c:bandFmt" {
+                    let mut obj = BandFormat::default();
+                    obj.set_attributes(reader, e);
+                    self.add_band_format(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"c:bandFmts" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:bandFmts"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:bandFmts
+        if self.band_format.is_empty() {
+            write_start_tag(writer, "c:bandFmts", vec![], true);
+            return;
+        }
+        write_start_tag(writer, "c:bandFmts", vec![], false);
+
+        // c:bandFmt
+        for v in &self.band_format {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:bandFmts");
+    }
+}
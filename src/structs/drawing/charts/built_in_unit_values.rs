@@ -0,0 +1,51 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuiltInUnitValues {
+    Hundreds,
+    Thousands,
+    TenThousands,
+    HundredThousands,
+    Millions,
+    TenMillions,
+    HundredMillions,
+    Billions,
+    Trillions,
+}
+impl Default for BuiltInUnitValues {
+    fn default() -> Self {
+        Self::Thousands
+    }
+}
+impl EnumTrait for BuiltInUnitValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Hundreds => "hundreds",
+            Self::Thousands => "thousands",
+            Self::TenThousands => "tenThousands",
+            Self::HundredThousands => "hundredThousands",
+            Self::Millions => "millions",
+            Self::TenMillions => "tenMillions",
+            Self::HundredMillions => "hundredMillions",
+            Self::Billions => "billions",
+            Self::Trillions => "trillions",
+        }
+    }
+}
+impl FromStr for BuiltInUnitValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "hundreds" => Ok(Self::Hundreds),
+            "thousands" => Ok(Self::Thousands),
+            "tenThousands" => Ok(Self::TenThousands),
+            "hundredThousands" => Ok(Self::HundredThousands),
+            "millions" => Ok(Self::Millions),
+            "tenMillions" => Ok(Self::TenMillions),
+            "hundredMillions" => Ok(Self::HundredMillions),
+            "billions" => Ok(Self::Billions),
+            "trillions" => Ok(Self::Trillions),
+            _ => Err(()),
+        }
+    }
+}
@@ -5,6 +5,7 @@ use super::AutoLabeled;
 use super::AxisId;
 use super::AxisPosition;
 use super::Crosses;
+use super::CrossesAt;
 use super::CrossingAxis;
 use super::Delete;
 use super::LabelAlignment;
@@ -37,6 +38,7 @@ pub struct CategoryAxis {
     tick_label_position: TickLabelPosition,
     crossing_axis: CrossingAxis,
     crosses: Crosses,
+    crosses_at: Option<CrossesAt>,
     auto_labeled: AutoLabeled,
     label_alignment: LabelAlignment,
     label_offset: LabelOffset,
@@ -189,6 +191,26 @@ impl CategoryAxis {
         self
     }
 
+    pub fn get_crosses_at(&self) -> Option<&CrossesAt> {
+        self.crosses_at.as_ref()
+    }
+
+    pub fn get_crosses_at_mut(&mut self) -> Option<&mut CrossesAt> {
+        self.crosses_at.as_mut()
+    }
+
+    /// Set the value on the crossing axis where this axis crosses.
+    /// Takes precedence over `crosses` when written.
+    pub fn set_crosses_at(&mut self, value: CrossesAt) -> &mut Self {
+        self.crosses_at = Some(value);
+        self
+    }
+
+    pub fn remove_crosses_at(&mut self) -> &mut Self {
+        self.crosses_at = None;
+        self
+    }
+
     pub fn get_auto_labeled(&self) -> &AutoLabeled {
         &self.auto_labeled
     }
@@ -330,6 +352,11 @@ impl CategoryAxis {
                 b"c:crosses" => {
                     self.crosses.set_attributes(reader, e);
                 }
+                b"c:crossesAt" => {
+                    let mut obj = CrossesAt::default();
+                    obj.set_attributes(reader, e);
+                    self.set_crosses_at(obj);
+                }
                 b"c:auto" => {
                     self.auto_labeled.set_attributes(reader, e);
                 }
@@ -401,8 +428,11 @@ impl CategoryAxis {
         // c:crossAx
         self.crossing_axis.write_to(writer);
 
-        // c:crosses
-        self.crosses.write_to(writer);
+        // c:crosses or c:crossesAt
+        match &self.crosses_at {
+            Some(v) => v.write_to(writer),
+            None => self.crosses.write_to(writer),
+        }
 
         // c:auto
         self.auto_labeled.write_to(writer);
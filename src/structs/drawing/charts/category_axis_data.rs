@@ -1,4 +1,6 @@
 // c:cat
+use super::Formula;
+use super::NumberReference;
 use super::StringLiteral;
 use super::StringReference;
 use crate::reader::driver::*;
@@ -12,6 +14,7 @@ use std::io::Cursor;
 #[derive(Clone, Default, Debug)]
 pub struct CategoryAxisData {
     string_reference: Option<StringReference>,
+    number_reference: Option<NumberReference>,
     string_literal: Option<StringLiteral>,
 }
 
@@ -34,6 +37,25 @@ impl CategoryAxisData {
         self
     }
 
+    pub fn get_number_reference(&self) -> Option<&NumberReference> {
+        self.number_reference.as_ref()
+    }
+
+    pub fn get_number_reference_mut(&mut self) -> Option<&mut NumberReference> {
+        self.number_reference.as_mut()
+    }
+
+    /// Set numeric categories, e.g. the dates of a date axis.
+    pub fn set_number_reference(&mut self, value: NumberReference) -> &mut Self {
+        self.number_reference = Some(value);
+        self
+    }
+
+    pub fn remove_number_reference(&mut self) -> &mut Self {
+        self.number_reference = None;
+        self
+    }
+
     pub fn get_string_literal(&self) -> Option<&StringLiteral> {
         self.string_literal.as_ref()
     }
@@ -66,6 +88,11 @@ impl CategoryAxisData {
                         obj.set_attributes(reader, e);
                         self.set_string_reference(obj);
                     }
+                    b"c:numRef" => {
+                        let mut obj = NumberReference::default();
+                        obj.set_attributes(reader, e);
+                        self.set_number_reference(obj);
+                    }
                     b"c:strLit" => {
                         let mut obj = StringLiteral::default();
                         obj.set_attributes(reader, e);
@@ -83,12 +110,52 @@ impl CategoryAxisData {
         );
     }
 
+    pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
+        let mut result: Vec<&mut Formula> = Vec::default();
+        if let Some(v) = &mut self.string_reference {
+            result.push(v.get_formula_mut());
+        }
+        if let Some(v) = &mut self.number_reference {
+            result.push(v.get_formula_mut());
+        }
+        result
+    }
+
+    /// (This method is crate only.)
+    /// Get the date format code and serial values of the categories
+    /// when every referenced cell holds a date.
+    pub(crate) fn get_date_value(&self, spreadsheet: &Spreadsheet) -> Option<(String, Vec<f64>)> {
+        let address = match (&self.string_reference, &self.number_reference) {
+            (Some(v), _) => v.get_formula().get_address(),
+            (None, Some(v)) => v.get_formula().get_address(),
+            (None, None) => return None,
+        };
+        spreadsheet.get_date_value_by_address_crate(address)
+    }
+
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
         // c:cat
         write_start_tag(writer, "c:cat", vec![], false);
 
         // c:strRef
+        // Categories referencing date cells are written as c:numRef,
+        // so that the spreadsheet application can lay them out on a date axis.
         if let Some(v) = &self.string_reference {
+            match spreadsheet.get_date_value_by_address_crate(v.get_formula().get_address()) {
+                Some((format_code, _)) => {
+                    let mut obj = NumberReference::default();
+                    obj.set_formula(v.get_formula().clone());
+                    obj.get_numbering_cache_mut()
+                        .get_format_code_mut()
+                        .set_text(format_code);
+                    obj.write_to(writer, spreadsheet);
+                }
+                None => v.write_to(writer, spreadsheet),
+            }
+        }
+
+        // c:numRef
+        if let Some(v) = &self.number_reference {
             v.write_to(writer, spreadsheet);
         }
 
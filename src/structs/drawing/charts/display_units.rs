@@ -0,0 +1,135 @@
+// c:dispUnits
+use super::BuiltInUnit;
+use super::CustomUnit;
+use super::DisplayUnitsLabel;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DisplayUnits {
+    built_in_unit: Option<BuiltInUnit>,
+    custom_unit: Option<CustomUnit>,
+    display_units_label: Option<DisplayUnitsLabel>,
+}
+
+impl DisplayUnits {
+    pub fn get_built_in_unit(&self) -> Option<&BuiltInUnit> {
+        self.built_in_unit.as_ref()
+    }
+
+    pub fn get_built_in_unit_mut(&mut self) -> Option<&mut BuiltInUnit> {
+        self.built_in_unit.as_mut()
+    }
+
+    /// Set a built-in unit. Clears any custom unit.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::charts::*;
+    /// let mut display_units = DisplayUnits::default();
+    /// let mut built_in_unit = BuiltInUnit::default();
+    /// built_in_unit.set_val(BuiltInUnitValues::Millions);
+    /// display_units.set_built_in_unit(built_in_unit);
+    /// ```
+    pub fn set_built_in_unit(&mut self, value: BuiltInUnit) -> &mut Self {
+        self.built_in_unit = Some(value);
+        self.custom_unit = None;
+        self
+    }
+
+    pub fn get_custom_unit(&self) -> Option<&CustomUnit> {
+        self.custom_unit.as_ref()
+    }
+
+    pub fn get_custom_unit_mut(&mut self) -> Option<&mut CustomUnit> {
+        self.custom_unit.as_mut()
+    }
+
+    /// Set a custom divisor for the axis values. Clears any built-in unit.
+    pub fn set_custom_unit(&mut self, value: CustomUnit) -> &mut Self {
+        self.custom_unit = Some(value);
+        self.built_in_unit = None;
+        self
+    }
+
+    pub fn get_display_units_label(&self) -> Option<&DisplayUnitsLabel> {
+        self.display_units_label.as_ref()
+    }
+
+    pub fn get_display_units_label_mut(&mut self) -> Option<&mut DisplayUnitsLabel> {
+        self.display_units_label.as_mut()
+    }
+
+    pub fn set_display_units_label(&mut self, value: DisplayUnitsLabel) -> &mut Self {
+        self.display_units_label = Some(value);
+        self
+    }
+
+    pub fn remove_display_units_label(&mut self) -> &mut Self {
+        self.display_units_label = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"c:dispUnitsLbl" {
+                    let mut obj = DisplayUnitsLabel::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_display_units_label(obj);
+                }
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:builtInUnit" => {
+                    let mut obj = BuiltInUnit::default();
+                    obj.set_attributes(reader, e);
+                    self.set_built_in_unit(obj);
+                }
+                b"c:custUnit" => {
+                    let mut obj = CustomUnit::default();
+                    obj.set_attributes(reader, e);
+                    self.set_custom_unit(obj);
+                }
+                b"c:dispUnitsLbl" => {
+                    let mut obj = DisplayUnitsLabel::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_display_units_label(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:dispUnits" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:dispUnits"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dispUnits
+        write_start_tag(writer, "c:dispUnits", vec![], false);
+
+        // c:custUnit or c:builtInUnit
+        match (&self.custom_unit, &self.built_in_unit) {
+            (Some(v), _) => v.write_to(writer),
+            (None, Some(v)) => v.write_to(writer),
+            (None, None) => BuiltInUnit::default().write_to(writer),
+        }
+
+        // c:dispUnitsLbl
+        if let Some(v) = &self.display_units_label {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:dispUnits");
+    }
+}
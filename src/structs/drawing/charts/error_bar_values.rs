@@ -0,0 +1,33 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum ErrorBarValues {
+    Both,
+    Minus,
+    Plus,
+}
+impl Default for ErrorBarValues {
+    fn default() -> Self {
+        Self::Both
+    }
+}
+impl EnumTrait for ErrorBarValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Both => "both",
+            Self::Minus => "minus",
+            Self::Plus => "plus",
+        }
+    }
+}
+impl FromStr for ErrorBarValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "both" => Ok(Self::Both),
+            "minus" => Ok(Self::Minus),
+            "plus" => Ok(Self::Plus),
+            _ => Err(()),
+        }
+    }
+}
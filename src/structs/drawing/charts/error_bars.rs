@@ -0,0 +1,247 @@
+// c:errBars
+use super::ErrorBarType;
+use super::ErrorBarValue;
+use super::ErrorBarValueType;
+use super::ErrorDirection;
+use super::Formula;
+use super::Minus;
+use super::NoEndCap;
+use super::Plus;
+use super::ShapeProperties;
+use crate::structs::Spreadsheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct ErrorBars {
+    error_direction: Option<ErrorDirection>,
+    error_bar_type: ErrorBarType,
+    error_bar_value_type: ErrorBarValueType,
+    no_end_cap: Option<NoEndCap>,
+    plus: Option<Plus>,
+    minus: Option<Minus>,
+    error_bar_value: Option<ErrorBarValue>,
+    shape_properties: Option<ShapeProperties>,
+}
+
+impl ErrorBars {
+    pub fn get_error_direction(&self) -> Option<&ErrorDirection> {
+        self.error_direction.as_ref()
+    }
+
+    pub fn get_error_direction_mut(&mut self) -> Option<&mut ErrorDirection> {
+        self.error_direction.as_mut()
+    }
+
+    pub fn set_error_direction(&mut self, value: ErrorDirection) -> &mut Self {
+        self.error_direction = Some(value);
+        self
+    }
+
+    pub fn get_error_bar_type(&self) -> &ErrorBarType {
+        &self.error_bar_type
+    }
+
+    pub fn get_error_bar_type_mut(&mut self) -> &mut ErrorBarType {
+        &mut self.error_bar_type
+    }
+
+    pub fn set_error_bar_type(&mut self, value: ErrorBarType) -> &mut Self {
+        self.error_bar_type = value;
+        self
+    }
+
+    pub fn get_error_bar_value_type(&self) -> &ErrorBarValueType {
+        &self.error_bar_value_type
+    }
+
+    pub fn get_error_bar_value_type_mut(&mut self) -> &mut ErrorBarValueType {
+        &mut self.error_bar_value_type
+    }
+
+    pub fn set_error_bar_value_type(&mut self, value: ErrorBarValueType) -> &mut Self {
+        self.error_bar_value_type = value;
+        self
+    }
+
+    pub fn get_no_end_cap(&self) -> Option<&NoEndCap> {
+        self.no_end_cap.as_ref()
+    }
+
+    pub fn get_no_end_cap_mut(&mut self) -> Option<&mut NoEndCap> {
+        self.no_end_cap.as_mut()
+    }
+
+    pub fn set_no_end_cap(&mut self, value: NoEndCap) -> &mut Self {
+        self.no_end_cap = Some(value);
+        self
+    }
+
+    pub fn get_plus(&self) -> Option<&Plus> {
+        self.plus.as_ref()
+    }
+
+    pub fn get_plus_mut(&mut self) -> Option<&mut Plus> {
+        self.plus.as_mut()
+    }
+
+    pub fn set_plus(&mut self, value: Plus) -> &mut Self {
+        self.plus = Some(value);
+        self
+    }
+
+    pub fn get_minus(&self) -> Option<&Minus> {
+        self.minus.as_ref()
+    }
+
+    pub fn get_minus_mut(&mut self) -> Option<&mut Minus> {
+        self.minus.as_mut()
+    }
+
+    pub fn set_minus(&mut self, value: Minus) -> &mut Self {
+        self.minus = Some(value);
+        self
+    }
+
+    pub fn get_error_bar_value(&self) -> Option<&ErrorBarValue> {
+        self.error_bar_value.as_ref()
+    }
+
+    pub fn get_error_bar_value_mut(&mut self) -> Option<&mut ErrorBarValue> {
+        self.error_bar_value.as_mut()
+    }
+
+    pub fn set_error_bar_value(&mut self, value: ErrorBarValue) -> &mut Self {
+        self.error_bar_value = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
+        let mut result: Vec<&mut Formula> = Vec::default();
+        if let Some(v) = &mut self.plus {
+            result.push(v.get_number_reference_mut().get_formula_mut());
+        }
+        if let Some(v) = &mut self.minus {
+            result.push(v.get_number_reference_mut().get_formula_mut());
+        }
+        result
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:plus" => {
+                    let mut obj = Plus::default();
+                    obj.set_attributes(reader, e);
+                    self.set_plus(obj);
+                }
+                b"c:minus" => {
+                    let mut obj = Minus::default();
+                    obj.set_attributes(reader, e);
+                    self.set_minus(obj);
+                }
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e);
+                    self.set_shape_properties(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:errDir" => {
+                    let mut obj = ErrorDirection::default();
+                    obj.set_attributes(reader, e);
+                    self.set_error_direction(obj);
+                }
+                b"c:errBarType" => {
+                    self.error_bar_type.set_attributes(reader, e);
+                }
+                b"c:errValType" => {
+                    self.error_bar_value_type.set_attributes(reader, e);
+                }
+                b"c:noEndCap" => {
+                    let mut obj = NoEndCap::default();
+                    obj.set_attributes(reader, e);
+                    self.set_no_end_cap(obj);
+                }
+                b"c:val" => {
+                    let mut obj = ErrorBarValue::default();
+                    obj.set_attributes(reader, e);
+                    self.set_error_bar_value(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:errBars" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:errBars"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // c:errBars
+        write_start_tag(writer, "c:errBars", vec![], false);
+
+        // c:errDir
+        if let Some(v) = &self.error_direction {
+            v.write_to(writer);
+        }
+
+        // c:errBarType
+        self.error_bar_type.write_to(writer);
+
+        // c:errValType
+        self.error_bar_value_type.write_to(writer);
+
+        // c:noEndCap
+        if let Some(v) = &self.no_end_cap {
+            v.write_to(writer);
+        }
+
+        // c:plus
+        if let Some(v) = &self.plus {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // c:minus
+        if let Some(v) = &self.minus {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // c:val
+        if let Some(v) = &self.error_bar_value {
+            v.write_to(writer);
+        }
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:errBars");
+    }
+}
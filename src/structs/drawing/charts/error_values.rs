@@ -0,0 +1,39 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum ErrorValues {
+    Custom,
+    FixedValue,
+    Percentage,
+    StandardDeviation,
+    StandardError,
+}
+impl Default for ErrorValues {
+    fn default() -> Self {
+        Self::FixedValue
+    }
+}
+impl EnumTrait for ErrorValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Custom => "cust",
+            Self::FixedValue => "fixedVal",
+            Self::Percentage => "percentage",
+            Self::StandardDeviation => "stdDev",
+            Self::StandardError => "stdErr",
+        }
+    }
+}
+impl FromStr for ErrorValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "cust" => Ok(Self::Custom),
+            "fixedVal" => Ok(Self::FixedValue),
+            "percentage" => Ok(Self::Percentage),
+            "stdDev" => Ok(Self::StandardDeviation),
+            "stdErr" => Ok(Self::StandardError),
+            _ => Err(()),
+        }
+    }
+}
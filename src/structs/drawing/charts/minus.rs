@@ -0,0 +1,61 @@
+use crate::xml_read_loop;
+
+// c:minus
+use super::NumberReference;
+use crate::structs::Spreadsheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Minus {
+    number_reference: NumberReference,
+}
+
+impl Minus {
+    pub fn get_number_reference(&self) -> &NumberReference {
+        &self.number_reference
+    }
+
+    pub fn get_number_reference_mut(&mut self) -> &mut NumberReference {
+        &mut self.number_reference
+    }
+
+    pub fn set_number_reference(&mut self, value: NumberReference) -> &mut Minus {
+        self.number_reference = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"c:numRef" {
+                    self.number_reference.set_attributes(reader, e);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:minus" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:minus"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // c:minus
+        write_start_tag(writer, "c:minus", vec![], false);
+
+        // c:numRef
+        self.number_reference.write_to(writer, spreadsheet);
+
+        write_end_tag(writer, "c:minus");
+    }
+}
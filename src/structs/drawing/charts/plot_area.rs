@@ -6,12 +6,15 @@ use super::Bar3DChart;
 use super::BarChart;
 use super::BubbleChart;
 use super::CategoryAxis;
+use super::DataTable;
+use super::DateAxis;
 use super::DoughnutChart;
 use super::Formula;
 use super::GroupingValues;
 use super::Layout;
 use super::Line3DChart;
 use super::LineChart;
+use super::NumberingFormat;
 use super::OfPieChart;
 use super::Pie3DChart;
 use super::PieChart;
@@ -19,6 +22,9 @@ use super::RadarChart;
 use super::ScatterChart;
 use super::SeriesAxis;
 use super::ShapeProperties;
+use super::StockChart;
+use super::Surface3DChart;
+use super::SurfaceChart;
 use super::ValueAxis;
 use crate::structs::Spreadsheet;
 use crate::traits::AdjustmentCoordinateWithSheet;
@@ -46,9 +52,14 @@ pub struct PlotArea {
     area_chart: Option<AreaChart>,
     area_3d_chart: Option<Area3DChart>,
     of_pie_chart: Option<OfPieChart>,
+    stock_chart: Option<StockChart>,
+    surface_chart: Option<SurfaceChart>,
+    surface_3d_chart: Option<Surface3DChart>,
     category_axis: ThinVec<CategoryAxis>,
+    date_axis: ThinVec<DateAxis>,
     value_axis: ThinVec<ValueAxis>,
     series_axis: ThinVec<SeriesAxis>,
+    data_table: Option<DataTable>,
     shape_properties: Option<ShapeProperties>,
 }
 
@@ -235,6 +246,45 @@ impl PlotArea {
         self
     }
 
+    pub fn get_stock_chart(&self) -> Option<&StockChart> {
+        self.stock_chart.as_ref()
+    }
+
+    pub fn get_stock_chart_mut(&mut self) -> Option<&mut StockChart> {
+        self.stock_chart.as_mut()
+    }
+
+    pub fn set_stock_chart(&mut self, value: StockChart) -> &mut Self {
+        self.stock_chart = Some(value);
+        self
+    }
+
+    pub fn get_surface_chart(&self) -> Option<&SurfaceChart> {
+        self.surface_chart.as_ref()
+    }
+
+    pub fn get_surface_chart_mut(&mut self) -> Option<&mut SurfaceChart> {
+        self.surface_chart.as_mut()
+    }
+
+    pub fn set_surface_chart(&mut self, value: SurfaceChart) -> &mut Self {
+        self.surface_chart = Some(value);
+        self
+    }
+
+    pub fn get_surface_3d_chart(&self) -> Option<&Surface3DChart> {
+        self.surface_3d_chart.as_ref()
+    }
+
+    pub fn get_surface_3d_chart_mut(&mut self) -> Option<&mut Surface3DChart> {
+        self.surface_3d_chart.as_mut()
+    }
+
+    pub fn set_surface_3d_chart(&mut self, value: Surface3DChart) -> &mut Self {
+        self.surface_3d_chart = Some(value);
+        self
+    }
+
     pub fn get_category_axis(&self) -> &[CategoryAxis] {
         &self.category_axis
     }
@@ -253,6 +303,24 @@ impl PlotArea {
         self
     }
 
+    pub fn get_date_axis(&self) -> &[DateAxis] {
+        &self.date_axis
+    }
+
+    pub fn get_date_axis_mut(&mut self) -> &mut ThinVec<DateAxis> {
+        &mut self.date_axis
+    }
+
+    pub fn set_date_axis(&mut self, value: impl Into<ThinVec<DateAxis>>) -> &mut Self {
+        self.date_axis = value.into();
+        self
+    }
+
+    pub fn add_date_axis(&mut self, value: DateAxis) -> &mut Self {
+        self.date_axis.push(value);
+        self
+    }
+
     pub fn get_value_axis(&self) -> &[ValueAxis] {
         &self.value_axis
     }
@@ -289,6 +357,19 @@ impl PlotArea {
         self
     }
 
+    pub fn get_data_table(&self) -> Option<&DataTable> {
+        self.data_table.as_ref()
+    }
+
+    pub fn get_data_table_mut(&mut self) -> Option<&mut DataTable> {
+        self.data_table.as_mut()
+    }
+
+    pub fn set_data_table(&mut self, value: DataTable) -> &mut Self {
+        self.data_table = Some(value);
+        self
+    }
+
     pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
         self.shape_properties.as_ref()
     }
@@ -370,9 +451,72 @@ impl PlotArea {
         if let Some(chart) = &mut self.of_pie_chart {
             return chart.get_area_chart_series_list_mut();
         }
+        if let Some(chart) = &mut self.stock_chart {
+            return chart.get_area_chart_series_list_mut();
+        }
+        if let Some(chart) = &mut self.surface_chart {
+            return chart.get_area_chart_series_list_mut();
+        }
+        if let Some(chart) = &mut self.surface_3d_chart {
+            return chart.get_area_chart_series_list_mut();
+        }
         panic! {"Non-ChartSeriesList."};
     }
 
+    /// Get the series lists of all chart groups in the plot area.
+    pub fn get_area_chart_series_lists_mut(&mut self) -> Vec<&mut AreaChartSeriesList> {
+        let mut result: Vec<&mut AreaChartSeriesList> = Vec::default();
+        if let Some(v) = &mut self.line_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.line_3d_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.pie_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.pie_3d_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.doughnut_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.scatter_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.bar_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.bar_3d_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.radar_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.bubble_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.area_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.area_3d_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.of_pie_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.stock_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.surface_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        if let Some(v) = &mut self.surface_3d_chart {
+            result.push(v.get_area_chart_series_list_mut());
+        }
+        result
+    }
+
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
         if let Some(v) = &mut self.line_chart {
@@ -505,9 +649,69 @@ impl PlotArea {
                 }
             }
         }
+        if let Some(v) = &mut self.stock_chart {
+            for ser in v
+                .get_area_chart_series_list_mut()
+                .get_area_chart_series_mut()
+            {
+                for formula in ser.get_formula_mut() {
+                    result.push(formula);
+                }
+            }
+        }
+        if let Some(v) = &mut self.surface_chart {
+            for ser in v
+                .get_area_chart_series_list_mut()
+                .get_area_chart_series_mut()
+            {
+                for formula in ser.get_formula_mut() {
+                    result.push(formula);
+                }
+            }
+        }
+        if let Some(v) = &mut self.surface_3d_chart {
+            for ser in v
+                .get_area_chart_series_list_mut()
+                .get_area_chart_series_mut()
+            {
+                for formula in ser.get_formula_mut() {
+                    result.push(formula);
+                }
+            }
+        }
         result
     }
 
+    /// Get the date format code and serial values of the categories of the first series,
+    /// when the chart type can be laid out on a date axis and every category cell holds a date.
+    pub(crate) fn get_date_category_value(
+        &self,
+        spreadsheet: &Spreadsheet,
+    ) -> Option<(String, Vec<f64>)> {
+        let area_chart_series_list = if let Some(v) = &self.line_chart {
+            v.get_area_chart_series_list()
+        } else if let Some(v) = &self.line_3d_chart {
+            v.get_area_chart_series_list()
+        } else if let Some(v) = &self.bar_chart {
+            v.get_area_chart_series_list()
+        } else if let Some(v) = &self.bar_3d_chart {
+            v.get_area_chart_series_list()
+        } else if let Some(v) = &self.area_chart {
+            v.get_area_chart_series_list()
+        } else if let Some(v) = &self.area_3d_chart {
+            v.get_area_chart_series_list()
+        } else if let Some(v) = &self.stock_chart {
+            v.get_area_chart_series_list()
+        } else {
+            return None;
+        };
+        area_chart_series_list
+            .get_area_chart_series()
+            .first()?
+            .get_category_axis_data()?
+            .get_date_value(spreadsheet)
+    }
+
     pub(crate) fn is_support(&self) -> bool {
         self.line_chart.is_some()
             || self.line_3d_chart.is_some()
@@ -522,6 +726,9 @@ impl PlotArea {
             || self.area_chart.is_some()
             || self.area_3d_chart.is_some()
             || self.of_pie_chart.is_some()
+            || self.stock_chart.is_some()
+            || self.surface_chart.is_some()
+            || self.surface_3d_chart.is_some()
     }
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
@@ -600,11 +807,31 @@ impl PlotArea {
                     obj.set_attributes(reader, e);
                     self.set_of_pie_chart(obj);
                 }
+                b"c:stockChart" => {
+                    let mut obj = StockChart::default();
+                    obj.set_attributes(reader, e);
+                    self.set_stock_chart(obj);
+                }
+                b"c:surfaceChart" => {
+                    let mut obj = SurfaceChart::default();
+                    obj.set_attributes(reader, e);
+                    self.set_surface_chart(obj);
+                }
+                b"c:surface3DChart" => {
+                    let mut obj = Surface3DChart::default();
+                    obj.set_attributes(reader, e);
+                    self.set_surface_3d_chart(obj);
+                }
                 b"c:catAx" => {
                     let mut obj = CategoryAxis::default();
                     obj.set_attributes(reader, e);
                     self.add_category_axis(obj);
                 }
+                b"c:dateAx" => {
+                    let mut obj = DateAxis::default();
+                    obj.set_attributes(reader, e);
+                    self.add_date_axis(obj);
+                }
                 b"c:valAx" => {
                     let mut obj = ValueAxis::default();
                     obj.set_attributes(reader, e);
@@ -615,6 +842,11 @@ impl PlotArea {
                     obj.set_attributes(reader, e);
                     self.add_series_axis(obj);
                 }
+                b"c:dTable" => {
+                    let mut obj = DataTable::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_data_table(obj);
+                }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
                     obj.set_attributes(reader, e);
@@ -622,6 +854,13 @@ impl PlotArea {
                 }
                 _ => (),
             },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"c:dTable" {
+                    let mut obj = DataTable::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_data_table(obj);
+                }
+            },
             Event::End(ref e) => {
                 if e.name().0 == b"c:plotArea" {
                     return;
@@ -703,8 +942,46 @@ impl PlotArea {
             v.write_to(writer, spreadsheet);
         }
 
+        // c:stockChart
+        if let Some(v) = &self.stock_chart {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // c:surfaceChart
+        if let Some(v) = &self.surface_chart {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // c:surface3DChart
+        if let Some(v) = &self.surface_3d_chart {
+            v.write_to(writer, spreadsheet);
+        }
+
         // c:catAx
+        // An automatic category axis over date cells is written as c:dateAx.
+        // Serial values out of the date range stay on c:catAx.
+        let date_category_value =
+            self.get_date_category_value(spreadsheet)
+                .and_then(|(format_code, serial_list)| {
+                    Some((format_code, DateAxis::detect_base_time_unit(&serial_list)?))
+                });
         for v in &self.category_axis {
+            match &date_category_value {
+                Some((format_code, base_time_unit)) if *v.get_auto_labeled().get_val() => {
+                    let mut numbering_format = NumberingFormat::default();
+                    numbering_format
+                        .set_format_code(format_code)
+                        .set_source_linked(true);
+                    DateAxis::from_category_axis(v, base_time_unit.clone())
+                        .set_numbering_format(numbering_format)
+                        .write_to(writer);
+                }
+                _ => v.write_to(writer),
+            }
+        }
+
+        // c:dateAx
+        for v in &self.date_axis {
             v.write_to(writer);
         }
 
@@ -718,6 +995,11 @@ impl PlotArea {
             v.write_to(writer);
         }
 
+        // c:dTable
+        if let Some(v) = &self.data_table {
+            v.write_to(writer);
+        }
+
         // c:spPr
         if let Some(v) = &self.shape_properties {
             v.write_to(writer);
@@ -0,0 +1,210 @@
+use crate::xml_read_loop;
+
+// c:stockChart
+use super::AreaChartSeries;
+use super::AreaChartSeriesList;
+use super::AxisId;
+use super::DataLabels;
+use super::DropLines;
+use super::HighLowLines;
+use super::UpDownBars;
+use crate::structs::Spreadsheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// Stock chart.
+/// Holds the high, low and close series, preceded by the open series for open-high-low-close.
+#[derive(Clone, Default, Debug)]
+pub struct StockChart {
+    area_chart_series_list: AreaChartSeriesList,
+    data_labels: DataLabels,
+    drop_lines: Option<DropLines>,
+    high_low_lines: Option<HighLowLines>,
+    up_down_bars: Option<UpDownBars>,
+    axis_id: ThinVec<AxisId>,
+}
+
+impl StockChart {
+    pub fn get_area_chart_series_list(&self) -> &AreaChartSeriesList {
+        &self.area_chart_series_list
+    }
+
+    pub fn get_area_chart_series_list_mut(&mut self) -> &mut AreaChartSeriesList {
+        &mut self.area_chart_series_list
+    }
+
+    pub fn set_area_chart_series_list(&mut self, value: AreaChartSeriesList) -> &mut Self {
+        self.area_chart_series_list = value;
+        self
+    }
+
+    pub fn get_data_labels(&self) -> &DataLabels {
+        &self.data_labels
+    }
+
+    pub fn get_data_labels_mut(&mut self) -> &mut DataLabels {
+        &mut self.data_labels
+    }
+
+    pub fn set_data_labels(&mut self, value: DataLabels) -> &mut Self {
+        self.data_labels = value;
+        self
+    }
+
+    pub fn get_drop_lines(&self) -> Option<&DropLines> {
+        self.drop_lines.as_ref()
+    }
+
+    pub fn get_drop_lines_mut(&mut self) -> Option<&mut DropLines> {
+        self.drop_lines.as_mut()
+    }
+
+    pub fn set_drop_lines(&mut self, value: DropLines) -> &mut Self {
+        self.drop_lines = Some(value);
+        self
+    }
+
+    pub fn get_high_low_lines(&self) -> Option<&HighLowLines> {
+        self.high_low_lines.as_ref()
+    }
+
+    pub fn get_high_low_lines_mut(&mut self) -> Option<&mut HighLowLines> {
+        self.high_low_lines.as_mut()
+    }
+
+    pub fn set_high_low_lines(&mut self, value: HighLowLines) -> &mut Self {
+        self.high_low_lines = Some(value);
+        self
+    }
+
+    pub fn get_up_down_bars(&self) -> Option<&UpDownBars> {
+        self.up_down_bars.as_ref()
+    }
+
+    pub fn get_up_down_bars_mut(&mut self) -> Option<&mut UpDownBars> {
+        self.up_down_bars.as_mut()
+    }
+
+    pub fn set_up_down_bars(&mut self, value: UpDownBars) -> &mut Self {
+        self.up_down_bars = Some(value);
+        self
+    }
+
+    pub fn get_axis_id(&self) -> &[AxisId] {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self) -> &mut ThinVec<AxisId> {
+        &mut self.axis_id
+    }
+
+    pub fn set_axis_id(&mut self, value: impl Into<ThinVec<AxisId>>) -> &mut Self {
+        self.axis_id = value.into();
+        self
+    }
+
+    pub fn add_axis_id(&mut self, value: AxisId) -> &mut Self {
+        self.axis_id.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"c:ser" => {
+                    let mut obj = AreaChartSeries::default();
+                    obj.set_attributes(reader, e);
+                    self.get_area_chart_series_list_mut()
+                        .add_area_chart_series(obj);
+                }
+                b"c:dLbls" => {
+                    self.data_labels.set_attributes(reader, e);
+                }
+                b"c:dropLines" => {
+                    let mut obj = DropLines::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_drop_lines(obj);
+                }
+                b"c:hiLowLines" => {
+                    let mut obj = HighLowLines::default();
+                    obj.set_attributes(reader, e, false);
+                    self.set_high_low_lines(obj);
+                }
+                b"c:upDownBars" => {
+                    let mut obj = UpDownBars::default();
+                    obj.set_attributes(reader, e);
+                    self.set_up_down_bars(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"c:dropLines" => {
+                    let mut obj = DropLines::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_drop_lines(obj);
+                }
+                b"c:hiLowLines" => {
+                    let mut obj = HighLowLines::default();
+                    obj.set_attributes(reader, e, true);
+                    self.set_high_low_lines(obj);
+                }
+                b"c:axId" => {
+                    let mut obj = AxisId::default();
+                    obj.set_attributes(reader, e);
+                    self.add_axis_id(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"c:stockChart" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:stockChart"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // c:stockChart
+        write_start_tag(writer, "c:stockChart", vec![], false);
+
+        // c:ser
+        for v in self.area_chart_series_list.get_area_chart_series() {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // c:dLbls
+        self.data_labels.write_to(writer);
+
+        // c:dropLines
+        if let Some(v) = &self.drop_lines {
+            v.write_to(writer);
+        }
+
+        // c:hiLowLines
+        if let Some(v) = &self.high_low_lines {
+            v.write_to(writer);
+        }
+
+        // c:upDownBars
+        if let Some(v) = &self.up_down_bars {
+            v.write_to(writer);
+        }
+
+        // c:axId
+        for v in &self.axis_id {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:stockChart");
+    }
+}
@@ -0,0 +1,155 @@
+use crate::xml_read_loop;
+
+// c:surfaceChart
+use super::AreaChartSeries;
+use super::AreaChartSeriesList;
+use super::AxisId;
+use super::BandFormats;
+use super::Wireframe;
+use crate::structs::Spreadsheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct SurfaceChart {
+    wireframe: Option<Wireframe>,
+    area_chart_series_list: AreaChartSeriesList,
+    band_formats: Option<BandFormats>,
+    axis_id: ThinVec<AxisId>,
+}
+
+impl SurfaceChart {
+    pub fn get_wireframe(&self) -> Option<&Wireframe> {
+        self.wireframe.as_ref()
+    }
+
+    pub fn get_wireframe_mut(&mut self) -> Option<&mut Wireframe> {
+        self.wireframe.as_mut()
+    }
+
+    pub fn set_wireframe(&mut self, value: Wireframe) -> &mut Self {
+        self.wireframe = Some(value);
+        self
+    }
+
+    pub fn get_area_chart_series_list(&self) -> &AreaChartSeriesList {
+        &self.area_chart_series_list
+    }
+
+    pub fn get_area_chart_series_list_mut(&mut self) -> &mut AreaChartSeriesList {
+        &mut self.area_chart_series_list
+    }
+
+    pub fn set_area_chart_series_list(&mut self, value: AreaChartSeriesList) -> &mut Self {
+        self.area_chart_series_list = value;
+        self
+    }
+
+    pub fn get_band_formats(&self) -> Option<&BandFormats> {
+        self.band_formats.as_ref()
+    }
+
+    pub fn get_band_formats_mut(&mut self) -> Option<&mut BandFormats> {
+        self.band_formats.as_mut()
+    }
+
+    pub fn set_band_formats(&mut self, value: BandFormats) -> &mut Self {
+        self.band_formats = Some(value);
+        self
+    }
+
+    pub fn get_axis_id(&self) -> &[AxisId] {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self) -> &mut ThinVec<AxisId> {
+        &mut self.axis_id
+    }
+
+    pub fn set_axis_id(&mut self, value: impl Into<ThinVec<AxisId>>) -> &mut Self {
+        self.axis_id = value.into();
+        self
+    }
+
+    pub fn add_axis_id(&mut self, value: AxisId) -> &mut Self {
+        self.axis_id.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"c:ser" => {
+                    let mut obj = AreaChartSeries::default();
+                    obj.set_attributes(reader, e);
+                    self.get_area_chart_series_list_mut()
+                        .add_area_chart_series(obj);
+                }
+                b"c:bandFmts" => {
+                    let mut obj = BandFormats::default();
+                    obj.set_attributes(reader, e);
+                    self.set_band_formats(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"c:wireframe" => {
+                    let mut obj = Wireframe::default();
+                    obj.set_attributes(reader, e);
+                    self.set_wireframe(obj);
+                }
+                b"c:bandFmts" => {
+                    self.set_band_formats(BandFormats::default());
+                }
+                b"c:axId" => {
+                    let mut obj = AxisId::default();
+                    obj.set_attributes(reader, e);
+                    self.add_axis_id(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"c:surfaceChart" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:surfaceChart"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // c:surfaceChart
+        write_start_tag(writer, "c:surfaceChart", vec![], false);
+
+        // c:wireframe
+        if let Some(v) = &self.wireframe {
+            v.write_to(writer);
+        }
+
+        // c:ser
+        for v in self.area_chart_series_list.get_area_chart_series() {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // c:bandFmts
+        if let Some(v) = &self.band_formats {
+            v.write_to(writer);
+        }
+
+        // c:axId
+        for v in &self.axis_id {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:surfaceChart");
+    }
+}
@@ -0,0 +1,50 @@
+// c:name
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+use crate::xml_read_loop;
+
+#[derive(Clone, Default, Debug)]
+pub struct TrendlineName {
+    text: Box<str>,
+}
+
+impl TrendlineName {
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut TrendlineName {
+        self.text = value.into().into_boxed_str();
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.set_text(e.unescape().unwrap());
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:name" {
+                    return;
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "c:name"),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:name
+        write_start_tag(writer, "c:name", vec![], false);
+        write_text_node(writer, &*self.text);
+        write_end_tag(writer, "c:name");
+    }
+}
@@ -0,0 +1,42 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum TrendlineValues {
+    Exponential,
+    Linear,
+    Logarithmic,
+    MovingAverage,
+    Polynomial,
+    Power,
+}
+impl Default for TrendlineValues {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+impl EnumTrait for TrendlineValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Exponential => "exp",
+            Self::Linear => "linear",
+            Self::Logarithmic => "log",
+            Self::MovingAverage => "movingAvg",
+            Self::Polynomial => "poly",
+            Self::Power => "power",
+        }
+    }
+}
+impl FromStr for TrendlineValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "exp" => Ok(Self::Exponential),
+            "linear" => Ok(Self::Linear),
+            "log" => Ok(Self::Logarithmic),
+            "movingAvg" => Ok(Self::MovingAverage),
+            "poly" => Ok(Self::Polynomial),
+            "power" => Ok(Self::Power),
+            _ => Err(()),
+        }
+    }
+}
@@ -3,8 +3,10 @@ use super::AxisId;
 use super::AxisPosition;
 use super::CrossBetween;
 use super::Crosses;
+use super::CrossesAt;
 use super::CrossingAxis;
 use super::Delete;
+use super::DisplayUnits;
 use super::MajorGridlines;
 use super::MajorTickMark;
 use super::MinorTickMark;
@@ -35,7 +37,9 @@ pub struct ValueAxis {
     tick_label_position: TickLabelPosition,
     crossing_axis: CrossingAxis,
     crosses: Crosses,
+    crosses_at: Option<CrossesAt>,
     cross_between: CrossBetween,
+    display_units: Option<DisplayUnits>,
     shape_properties: Option<ShapeProperties>,
     text_properties: Option<TextProperties>,
 }
@@ -197,6 +201,26 @@ impl ValueAxis {
         self
     }
 
+    pub fn get_crosses_at(&self) -> Option<&CrossesAt> {
+        self.crosses_at.as_ref()
+    }
+
+    pub fn get_crosses_at_mut(&mut self) -> Option<&mut CrossesAt> {
+        self.crosses_at.as_mut()
+    }
+
+    /// Set the value on the crossing axis where this axis crosses.
+    /// Takes precedence over `crosses` when written.
+    pub fn set_crosses_at(&mut self, value: CrossesAt) -> &mut Self {
+        self.crosses_at = Some(value);
+        self
+    }
+
+    pub fn remove_crosses_at(&mut self) -> &mut Self {
+        self.crosses_at = None;
+        self
+    }
+
     pub fn get_cross_between(&self) -> &CrossBetween {
         &self.cross_between
     }
@@ -210,6 +234,36 @@ impl ValueAxis {
         self
     }
 
+    pub fn get_display_units(&self) -> Option<&DisplayUnits> {
+        self.display_units.as_ref()
+    }
+
+    pub fn get_display_units_mut(&mut self) -> Option<&mut DisplayUnits> {
+        self.display_units.as_mut()
+    }
+
+    /// Set the units the axis values are displayed in, e.g. thousands or millions.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::charts::*;
+    /// let mut value_axis = ValueAxis::default();
+    /// let mut built_in_unit = BuiltInUnit::default();
+    /// built_in_unit.set_val(BuiltInUnitValues::Thousands);
+    /// let mut display_units = DisplayUnits::default();
+    /// display_units.set_built_in_unit(built_in_unit);
+    /// display_units.set_display_units_label(DisplayUnitsLabel::default());
+    /// value_axis.set_display_units(display_units);
+    /// ```
+    pub fn set_display_units(&mut self, value: DisplayUnits) -> &mut Self {
+        self.display_units = Some(value);
+        self
+    }
+
+    pub fn remove_display_units(&mut self) -> &mut Self {
+        self.display_units = None;
+        self
+    }
+
     pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
         self.shape_properties.as_ref()
     }
@@ -267,6 +321,11 @@ impl ValueAxis {
                     obj.set_attributes(reader, e, false);
                     self.set_major_gridlines(obj);
                 }
+                b"c:dispUnits" => {
+                    let mut obj = DisplayUnits::default();
+                    obj.set_attributes(reader, e);
+                    self.set_display_units(obj);
+                }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
@@ -302,6 +361,11 @@ impl ValueAxis {
                 b"c:crosses" => {
                     self.crosses.set_attributes(reader, e);
                 }
+                b"c:crossesAt" => {
+                    let mut obj = CrossesAt::default();
+                    obj.set_attributes(reader, e);
+                    self.set_crosses_at(obj);
+                }
                 b"c:crossBetween" => {
                     self.cross_between.set_attributes(reader, e);
                 }
@@ -367,12 +431,20 @@ impl ValueAxis {
         // c:crossAx
         self.crossing_axis.write_to(writer);
 
-        // c:crosses
-        self.crosses.write_to(writer);
+        // c:crosses or c:crossesAt
+        match &self.crosses_at {
+            Some(v) => v.write_to(writer),
+            None => self.crosses.write_to(writer),
+        }
 
         // c:crossBetween
         self.cross_between.write_to(writer);
 
+        // c:dispUnits
+        if let Some(v) = &self.display_units {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "c:valAx");
     }
 }
@@ -1,8 +1,10 @@
 // *:graphicData
+use super::chartex;
 use super::charts::ChartSpace;
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::reader::xlsx::chart;
+use crate::reader::xlsx::chart_ex;
 use crate::structs::raw::RawRelationships;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
@@ -14,6 +16,7 @@ use std::io::Cursor;
 #[derive(Clone, Default, Debug)]
 pub struct GraphicData {
     chart_space: ChartSpace,
+    chart_ex_space: Option<Box<chartex::ChartSpace>>,
 }
 
 impl GraphicData {
@@ -33,6 +36,22 @@ impl GraphicData {
         self
     }
 
+    #[inline]
+    pub fn get_chart_ex_space(&self) -> Option<&chartex::ChartSpace> {
+        self.chart_ex_space.as_deref()
+    }
+
+    #[inline]
+    pub fn get_chart_ex_space_mut(&mut self) -> Option<&mut chartex::ChartSpace> {
+        self.chart_ex_space.as_deref_mut()
+    }
+
+    #[inline]
+    pub fn set_chart_ex_space(&mut self, value: chartex::ChartSpace) -> &mut Self {
+        self.chart_ex_space = Some(Box::new(value));
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
@@ -41,14 +60,24 @@ impl GraphicData {
     ) {
         xml_read_loop!(
             reader,
-            Event::Empty(ref e) => {
-                if e.name().into_inner() == b"c:chart" {
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"c:chart" => {
                     let chart_id = get_attribute(e, b"r:id").unwrap();
                     let relationship = drawing_relationships
                         .unwrap()
                         .get_relationship_by_rid(&chart_id);
                     chart::read(relationship.get_raw_file(), &mut self.chart_space);
                 }
+                b"cx:chart" => {
+                    let chart_id = get_attribute(e, b"r:id").unwrap();
+                    let relationship = drawing_relationships
+                        .unwrap()
+                        .get_relationship_by_rid(&chart_id);
+                    let mut obj = chartex::ChartSpace::default();
+                    chart_ex::read(relationship.get_raw_file(), &mut obj);
+                    self.set_chart_ex_space(obj);
+                }
+                _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
@@ -64,6 +93,11 @@ impl GraphicData {
         writer: &mut Writer<Cursor<Vec<u8>>>,
         rel_list: &mut Vec<(String, String)>,
     ) {
+        if self.chart_ex_space.is_some() {
+            self.write_to_chart_ex(writer, rel_list);
+            return;
+        }
+
         // a:graphicData
         write_start_tag(
             writer,
@@ -87,6 +121,35 @@ impl GraphicData {
 
         write_end_tag(writer, "a:graphicData");
     }
+
+    fn write_to_chart_ex(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
+        // a:graphicData
+        write_start_tag(
+            writer,
+            "a:graphicData",
+            vec![("uri", DRAWINGML_CHARTEX_NS)],
+            false,
+        );
+
+        // cx:chart
+        rel_list.push((String::from("CHART_EX"), String::new()));
+        write_start_tag(
+            writer,
+            "cx:chart",
+            vec![
+                ("xmlns:cx", DRAWINGML_CHARTEX_NS),
+                ("xmlns:r", REL_OFC_NS),
+                ("r:id", format!("rId{}", rel_list.len()).as_str()),
+            ],
+            true,
+        );
+
+        write_end_tag(writer, "a:graphicData");
+    }
 }
 impl AdjustmentCoordinateWithSheet for GraphicData {
     #[inline]
@@ -105,6 +168,15 @@ impl AdjustmentCoordinateWithSheet for GraphicData {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.chart_ex_space {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     #[inline]
@@ -123,5 +195,14 @@ impl AdjustmentCoordinateWithSheet for GraphicData {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.chart_ex_space {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
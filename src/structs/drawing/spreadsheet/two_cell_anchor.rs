@@ -1,5 +1,6 @@
 // xdr:twoCellAnchor
 use super::super::super::EnumValue;
+use super::super::chartex;
 use super::ConnectionShape;
 use super::EditAsValues;
 use super::GraphicFrame;
@@ -171,18 +172,40 @@ impl TwoCellAnchor {
     #[inline]
     pub(crate) fn is_support(&self) -> bool {
         self.graphic_frame.as_ref().map_or(true, |v| {
-            v.get_graphic()
-                .get_graphic_data()
-                .get_chart_space()
-                .get_chart()
-                .get_plot_area()
-                .is_support()
+            let graphic_data = v.get_graphic().get_graphic_data();
+            graphic_data.get_chart_ex_space().is_some()
+                || graphic_data
+                    .get_chart_space()
+                    .get_chart()
+                    .get_plot_area()
+                    .is_support()
         })
     }
 
     #[inline]
     pub(crate) fn is_chart(&self) -> bool {
-        self.graphic_frame.is_some()
+        self.graphic_frame.is_some() && !self.is_chart_ex()
+    }
+
+    #[inline]
+    pub(crate) fn is_chart_ex(&self) -> bool {
+        self.get_chart_ex_space().is_some()
+    }
+
+    #[inline]
+    pub(crate) fn get_chart_ex_space(&self) -> Option<&chartex::ChartSpace> {
+        self.graphic_frame
+            .as_ref()
+            .and_then(|v| v.get_graphic().get_graphic_data().get_chart_ex_space())
+    }
+
+    #[inline]
+    pub(crate) fn get_chart_ex_space_mut(&mut self) -> Option<&mut chartex::ChartSpace> {
+        self.graphic_frame.as_mut().and_then(|v| {
+            v.get_graphic_mut()
+                .get_graphic_data_mut()
+                .get_chart_ex_space_mut()
+        })
     }
 
     #[inline]
@@ -233,6 +256,10 @@ impl TwoCellAnchor {
                     obj.set_attributes(reader, e, drawing_relationships);
                     self.set_picture(obj);
                 }
+                b"mc:Fallback" => {
+                    // placeholder shape for readers without chartex support.
+                    let _ = reader.read_to_end_into(e.name(), &mut Vec::new());
+                }
                 _ => (),
                 }
             },
@@ -289,7 +316,32 @@ impl TwoCellAnchor {
 
         // xdr:graphicFrame
         if let Some(v) = &self.graphic_frame {
-            v.write_to(writer, rel_list);
+            match self.get_chart_ex_space() {
+                Some(chart_space) => {
+                    // mc:AlternateContent
+                    write_start_tag(
+                        writer,
+                        "mc:AlternateContent",
+                        vec![("xmlns:mc", MC_NS)],
+                        false,
+                    );
+
+                    // mc:Choice
+                    let (prefix, namespace) = chart_space.get_requires();
+                    let xmlns = format!("xmlns:{}", prefix);
+                    write_start_tag(
+                        writer,
+                        "mc:Choice",
+                        vec![(&xmlns, namespace), ("Requires", prefix)],
+                        false,
+                    );
+                    v.write_to(writer, rel_list);
+                    write_end_tag(writer, "mc:Choice");
+
+                    write_end_tag(writer, "mc:AlternateContent");
+                }
+                None => v.write_to(writer, rel_list),
+            }
         }
 
         // xdr:sp
@@ -1,6 +1,8 @@
 // xdr:wsDr
+use super::super::chartex;
 use super::ConnectionShape;
 use super::GraphicFrame;
+use super::MarkerType;
 use super::OneCellAnchor;
 use super::Picture;
 use super::Shape;
@@ -117,6 +119,45 @@ impl WorksheetDrawing {
             .collect()
     }
 
+    /// Get the chartex (waterfall, histogram, treemap, ...) charts.
+    pub fn get_chart_ex_collection(&self) -> Vec<&chartex::ChartSpace> {
+        self.two_cell_anchor_collection
+            .iter()
+            .filter_map(|v| v.get_chart_ex_space())
+            .collect()
+    }
+
+    pub fn get_chart_ex_collection_mut(&mut self) -> Vec<&mut chartex::ChartSpace> {
+        self.two_cell_anchor_collection
+            .iter_mut()
+            .filter_map(|v| v.get_chart_ex_space_mut())
+            .collect()
+    }
+
+    /// Add a chartex chart anchored between two cells.
+    /// # Arguments
+    /// * `from_marker` - Top left cell.
+    /// * `to_marker` - Bottom right cell.
+    /// * `chart_space` - Chart built with [`chartex::ChartSpace::new_chart`].
+    pub fn add_chart_ex(
+        &mut self,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+        chart_space: chartex::ChartSpace,
+    ) -> &mut Self {
+        let mut graphic_frame = GraphicFrame::default();
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .set_chart_ex_space(chart_space);
+        let mut two_cell_anchor = TwoCellAnchor::default();
+        two_cell_anchor
+            .set_from_marker(from_marker)
+            .set_to_marker(to_marker)
+            .set_graphic_frame(graphic_frame);
+        self.add_two_cell_anchor_collection(two_cell_anchor)
+    }
+
     #[inline]
     pub fn get_one_cell_anchor_collection(&self) -> &[OneCellAnchor] {
         &self.one_cell_anchor_collection
@@ -478,6 +519,17 @@ impl AdjustmentCoordinateWithSheet for WorksheetDrawing {
                 offset_row_num,
             );
         }
+
+        // chartex
+        for anchor in &mut self.two_cell_anchor_collection {
+            anchor.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     fn adjustment_remove_coordinate_with_sheet(
@@ -498,5 +550,16 @@ impl AdjustmentCoordinateWithSheet for WorksheetDrawing {
                 offset_row_num,
             );
         }
+
+        // chartex
+        for anchor in &mut self.two_cell_anchor_collection {
+            anchor.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
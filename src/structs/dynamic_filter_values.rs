@@ -0,0 +1,132 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DynamicFilterValues {
+    Null,
+    AboveAverage,
+    BelowAverage,
+    Tomorrow,
+    Today,
+    Yesterday,
+    NextWeek,
+    ThisWeek,
+    LastWeek,
+    NextMonth,
+    ThisMonth,
+    LastMonth,
+    NextQuarter,
+    ThisQuarter,
+    LastQuarter,
+    NextYear,
+    ThisYear,
+    LastYear,
+    YearToDate,
+    Quarter1,
+    Quarter2,
+    Quarter3,
+    Quarter4,
+    Month1,
+    Month2,
+    Month3,
+    Month4,
+    Month5,
+    Month6,
+    Month7,
+    Month8,
+    Month9,
+    Month10,
+    Month11,
+    Month12,
+}
+impl Default for DynamicFilterValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Null
+    }
+}
+impl EnumTrait for DynamicFilterValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Null => "null",
+            Self::AboveAverage => "aboveAverage",
+            Self::BelowAverage => "belowAverage",
+            Self::Tomorrow => "tomorrow",
+            Self::Today => "today",
+            Self::Yesterday => "yesterday",
+            Self::NextWeek => "nextWeek",
+            Self::ThisWeek => "thisWeek",
+            Self::LastWeek => "lastWeek",
+            Self::NextMonth => "nextMonth",
+            Self::ThisMonth => "thisMonth",
+            Self::LastMonth => "lastMonth",
+            Self::NextQuarter => "nextQuarter",
+            Self::ThisQuarter => "thisQuarter",
+            Self::LastQuarter => "lastQuarter",
+            Self::NextYear => "nextYear",
+            Self::ThisYear => "thisYear",
+            Self::LastYear => "lastYear",
+            Self::YearToDate => "yearToDate",
+            Self::Quarter1 => "Q1",
+            Self::Quarter2 => "Q2",
+            Self::Quarter3 => "Q3",
+            Self::Quarter4 => "Q4",
+            Self::Month1 => "M1",
+            Self::Month2 => "M2",
+            Self::Month3 => "M3",
+            Self::Month4 => "M4",
+            Self::Month5 => "M5",
+            Self::Month6 => "M6",
+            Self::Month7 => "M7",
+            Self::Month8 => "M8",
+            Self::Month9 => "M9",
+            Self::Month10 => "M10",
+            Self::Month11 => "M11",
+            Self::Month12 => "M12",
+        }
+    }
+}
+impl FromStr for DynamicFilterValues {
+    type Err = ();
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "null" => Ok(Self::Null),
+            "aboveAverage" => Ok(Self::AboveAverage),
+            "belowAverage" => Ok(Self::BelowAverage),
+            "tomorrow" => Ok(Self::Tomorrow),
+            "today" => Ok(Self::Today),
+            "yesterday" => Ok(Self::Yesterday),
+            "nextWeek" => Ok(Self::NextWeek),
+            "thisWeek" => Ok(Self::ThisWeek),
+            "lastWeek" => Ok(Self::LastWeek),
+            "nextMonth" => Ok(Self::NextMonth),
+            "thisMonth" => Ok(Self::ThisMonth),
+            "lastMonth" => Ok(Self::LastMonth),
+            "nextQuarter" => Ok(Self::NextQuarter),
+            "thisQuarter" => Ok(Self::ThisQuarter),
+            "lastQuarter" => Ok(Self::LastQuarter),
+            "nextYear" => Ok(Self::NextYear),
+            "thisYear" => Ok(Self::ThisYear),
+            "lastYear" => Ok(Self::LastYear),
+            "yearToDate" => Ok(Self::YearToDate),
+            "Q1" => Ok(Self::Quarter1),
+            "Q2" => Ok(Self::Quarter2),
+            "Q3" => Ok(Self::Quarter3),
+            "Q4" => Ok(Self::Quarter4),
+            "M1" => Ok(Self::Month1),
+            "M2" => Ok(Self::Month2),
+            "M3" => Ok(Self::Month3),
+            "M4" => Ok(Self::Month4),
+            "M5" => Ok(Self::Month5),
+            "M6" => Ok(Self::Month6),
+            "M7" => Ok(Self::Month7),
+            "M8" => Ok(Self::Month8),
+            "M9" => Ok(Self::Month9),
+            "M10" => Ok(Self::Month10),
+            "M11" => Ok(Self::Month11),
+            "M12" => Ok(Self::Month12),
+            _ => Err(()),
+        }
+    }
+}
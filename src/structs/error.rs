@@ -59,6 +59,7 @@ impl FromStr for CellErrorType {
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum XlsxError {
     /// IO error
     Io(std::io::Error),
@@ -70,6 +71,12 @@ pub enum XlsxError {
     Uft8(std::string::FromUtf8Error),
     /// Cell error
     CellError(String),
+    /// Password of an encrypted file is wrong
+    WrongPassword,
+    /// Encrypted file could not be decrypted
+    Decryption(String),
+    /// Pivot table could not be generated
+    PivotTable(String),
 }
 
 from_err!(std::io::Error, XlsxError, Io);
@@ -87,6 +94,9 @@ impl fmt::Display for XlsxError {
             Zip(s) => write!(f, "ZipError: {}", s),
             Uft8(s) => write!(f, "Uft8Error: {}", s),
             CellError(e) => write!(f, "Unsupported cell error value '{e}'"),
+            WrongPassword => write!(f, "The password is incorrect"),
+            Decryption(s) => write!(f, "DecryptionError: {}", s),
+            PivotTable(s) => write!(f, "PivotTableError: {}", s),
         }
     }
 }
@@ -0,0 +1,282 @@
+// filterColumn
+use super::BooleanValue;
+use super::ColorFilter;
+use super::CustomFilters;
+use super::DifferentialFormats;
+use super::DynamicFilter;
+use super::Filters;
+use super::Top10;
+use super::UInt32Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct FilterColumn {
+    column_id: UInt32Value,
+    hidden_button: BooleanValue,
+    show_button: BooleanValue,
+    filters: Option<Filters>,
+    top10: Option<Top10>,
+    custom_filters: Option<CustomFilters>,
+    dynamic_filter: Option<DynamicFilter>,
+    color_filter: Option<ColorFilter>,
+}
+
+impl FilterColumn {
+    /// Zero based index of the column inside the auto filter range.
+    #[inline]
+    pub fn get_column_id(&self) -> &u32 {
+        self.column_id.get_value()
+    }
+
+    #[inline]
+    pub fn set_column_id(&mut self, value: u32) -> &mut Self {
+        self.column_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_hidden_button(&self) -> &bool {
+        self.hidden_button.get_value()
+    }
+
+    #[inline]
+    pub fn set_hidden_button(&mut self, value: bool) -> &mut Self {
+        self.hidden_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_show_button(&self) -> &bool {
+        match self.show_button.has_value() {
+            true => self.show_button.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_show_button(&mut self, value: bool) -> &mut Self {
+        self.show_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_filters(&self) -> Option<&Filters> {
+        self.filters.as_ref()
+    }
+
+    #[inline]
+    pub fn get_filters_mut(&mut self) -> Option<&mut Filters> {
+        self.filters.as_mut()
+    }
+
+    /// Show the cells matching a list of values. Replaces the other criteria.
+    #[inline]
+    pub fn set_filters(&mut self, value: Filters) -> &mut Self {
+        self.remove_criteria();
+        self.filters = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_top10(&self) -> Option<&Top10> {
+        self.top10.as_ref()
+    }
+
+    #[inline]
+    pub fn get_top10_mut(&mut self) -> Option<&mut Top10> {
+        self.top10.as_mut()
+    }
+
+    /// Show the largest or smallest items. Replaces the other criteria.
+    #[inline]
+    pub fn set_top10(&mut self, value: Top10) -> &mut Self {
+        self.remove_criteria();
+        self.top10 = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_custom_filters(&self) -> Option<&CustomFilters> {
+        self.custom_filters.as_ref()
+    }
+
+    #[inline]
+    pub fn get_custom_filters_mut(&mut self) -> Option<&mut CustomFilters> {
+        self.custom_filters.as_mut()
+    }
+
+    /// Show the cells matching comparisons. Replaces the other criteria.
+    #[inline]
+    pub fn set_custom_filters(&mut self, value: CustomFilters) -> &mut Self {
+        self.remove_criteria();
+        self.custom_filters = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_dynamic_filter(&self) -> Option<&DynamicFilter> {
+        self.dynamic_filter.as_ref()
+    }
+
+    #[inline]
+    pub fn get_dynamic_filter_mut(&mut self) -> Option<&mut DynamicFilter> {
+        self.dynamic_filter.as_mut()
+    }
+
+    /// Show the cells matching a date period or the average. Replaces the other criteria.
+    #[inline]
+    pub fn set_dynamic_filter(&mut self, value: DynamicFilter) -> &mut Self {
+        self.remove_criteria();
+        self.dynamic_filter = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_color_filter(&self) -> Option<&ColorFilter> {
+        self.color_filter.as_ref()
+    }
+
+    #[inline]
+    pub fn get_color_filter_mut(&mut self) -> Option<&mut ColorFilter> {
+        self.color_filter.as_mut()
+    }
+
+    /// Show the cells with a cell or font color. Replaces the other criteria.
+    #[inline]
+    pub fn set_color_filter(&mut self, value: ColorFilter) -> &mut Self {
+        self.remove_criteria();
+        self.color_filter = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_criteria(&mut self) -> &mut Self {
+        self.filters = None;
+        self.top10 = None;
+        self.custom_filters = None;
+        self.dynamic_filter = None;
+        self.color_filter = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, column_id, "colId");
+        set_string_from_xml!(self, e, hidden_button, "hiddenButton");
+        set_string_from_xml!(self, e, show_button, "showButton");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"filters" => {
+                        let mut obj = Filters::default();
+                        obj.set_attributes(reader, e, false);
+                        self.filters = Some(obj);
+                    }
+                    b"customFilters" => {
+                        let mut obj = CustomFilters::default();
+                        obj.set_attributes(reader, e, false);
+                        self.custom_filters = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filters" => {
+                        let mut obj = Filters::default();
+                        obj.set_attributes(reader, e, true);
+                        self.filters = Some(obj);
+                    }
+                    b"customFilters" => {
+                        let mut obj = CustomFilters::default();
+                        obj.set_attributes(reader, e, true);
+                        self.custom_filters = Some(obj);
+                    }
+                    b"top10" => {
+                        let mut obj = Top10::default();
+                        obj.set_attributes(reader, e);
+                        self.top10 = Some(obj);
+                    }
+                    b"dynamicFilter" => {
+                        let mut obj = DynamicFilter::default();
+                        obj.set_attributes(reader, e);
+                        self.dynamic_filter = Some(obj);
+                    }
+                    b"colorFilter" => {
+                        let mut obj = ColorFilter::default();
+                        obj.set_attributes(reader, e, differential_formats);
+                        self.color_filter = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "filterColumn")
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        let is_inner = self.filters.is_some()
+            || self.top10.is_some()
+            || self.custom_filters.is_some()
+            || self.dynamic_filter.is_some()
+            || self.color_filter.is_some();
+
+        // filterColumn
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let column_id = self.column_id.get_value_string();
+        attributes.push(("colId", &column_id));
+        if self.hidden_button.has_value() {
+            attributes.push(("hiddenButton", self.hidden_button.get_value_string()));
+        }
+        if self.show_button.has_value() {
+            attributes.push(("showButton", self.show_button.get_value_string()));
+        }
+        write_start_tag(writer, "filterColumn", attributes, !is_inner);
+
+        if is_inner {
+            if let Some(v) = &self.filters {
+                v.write_to(writer);
+            }
+            if let Some(v) = &self.top10 {
+                v.write_to(writer);
+            }
+            if let Some(v) = &self.custom_filters {
+                v.write_to(writer);
+            }
+            if let Some(v) = &self.dynamic_filter {
+                v.write_to(writer);
+            }
+            if let Some(v) = &self.color_filter {
+                v.write_to(writer, differential_formats);
+            }
+
+            write_end_tag(writer, "filterColumn");
+        }
+    }
+}
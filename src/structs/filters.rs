@@ -0,0 +1,136 @@
+// filters
+use super::BooleanValue;
+use super::DateGroupItem;
+use super::Filter;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct Filters {
+    blank: BooleanValue,
+    filter_list: ThinVec<Filter>,
+    date_group_item_list: ThinVec<DateGroupItem>,
+}
+
+impl Filters {
+    /// Show blank cells.
+    #[inline]
+    pub fn get_blank(&self) -> &bool {
+        self.blank.get_value()
+    }
+
+    #[inline]
+    pub fn set_blank(&mut self, value: bool) -> &mut Self {
+        self.blank.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_filter_list(&self) -> &[Filter] {
+        &self.filter_list
+    }
+
+    #[inline]
+    pub fn get_filter_list_mut(&mut self) -> &mut ThinVec<Filter> {
+        &mut self.filter_list
+    }
+
+    #[inline]
+    pub fn add_filter(&mut self, value: Filter) -> &mut Self {
+        self.filter_list.push(value);
+        self
+    }
+
+    /// Add a filter showing the cells displayed as `value`.
+    #[inline]
+    pub fn add_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut filter = Filter::default();
+        filter.set_val(value);
+        self.add_filter(filter)
+    }
+
+    #[inline]
+    pub fn get_date_group_item_list(&self) -> &[DateGroupItem] {
+        &self.date_group_item_list
+    }
+
+    #[inline]
+    pub fn get_date_group_item_list_mut(&mut self) -> &mut ThinVec<DateGroupItem> {
+        &mut self.date_group_item_list
+    }
+
+    #[inline]
+    pub fn add_date_group_item(&mut self, value: DateGroupItem) -> &mut Self {
+        self.date_group_item_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, blank, "blank");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filter" => {
+                        let mut obj = Filter::default();
+                        obj.set_attributes(reader, e);
+                        self.filter_list.push(obj);
+                    }
+                    b"dateGroupItem" => {
+                        let mut obj = DateGroupItem::default();
+                        obj.set_attributes(reader, e);
+                        self.date_group_item_list.push(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filters" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "filters")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let is_inner = !self.filter_list.is_empty() || !self.date_group_item_list.is_empty();
+
+        // filters
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.blank.has_value() {
+            attributes.push(("blank", self.blank.get_value_string()));
+        }
+        write_start_tag(writer, "filters", attributes, !is_inner);
+
+        if is_inner {
+            // filter
+            for v in &self.filter_list {
+                v.write_to(writer);
+            }
+
+            // dateGroupItem
+            for v in &self.date_group_item_list {
+                v.write_to(writer);
+            }
+
+            write_end_tag(writer, "filters");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct HtmlWriterOption {
+    pub(crate) sheet_name: Option<Box<str>>,
+    pub(crate) sheet_index: Option<usize>,
+    pub(crate) range: Option<Box<str>>,
+    pub(crate) include_hidden: bool,
+    pub(crate) full_document: bool,
+}
+impl Default for HtmlWriterOption {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            sheet_name: None,
+            sheet_index: None,
+            range: None,
+            include_hidden: false,
+            full_document: true,
+        }
+    }
+}
+impl HtmlWriterOption {
+    /// Name of the worksheet to export.
+    /// If neither a name nor an index is set, the active sheet is exported.
+    #[inline]
+    pub fn get_sheet_name(&self) -> Option<&str> {
+        self.sheet_name.as_deref()
+    }
+
+    #[inline]
+    pub fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_name = Some(value.into().into_boxed_str());
+        self.sheet_index = None;
+        self
+    }
+
+    /// Index of the worksheet to export.
+    /// If neither a name nor an index is set, the active sheet is exported.
+    #[inline]
+    pub fn get_sheet_index(&self) -> Option<&usize> {
+        self.sheet_index.as_ref()
+    }
+
+    #[inline]
+    pub fn set_sheet_index(&mut self, value: usize) -> &mut Self {
+        self.sheet_index = Some(value);
+        self.sheet_name = None;
+        self
+    }
+
+    /// Range to export. (ex. "A1:C10", "B:D", "2:5")
+    /// If not set, everything up to the highest column and row is exported.
+    #[inline]
+    pub fn get_range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+
+    #[inline]
+    pub fn set_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.range = Some(value.into().into_boxed_str());
+        self
+    }
+
+    #[inline]
+    pub fn remove_range(&mut self) -> &mut Self {
+        self.range = None;
+        self
+    }
+
+    /// Export hidden rows and columns. By default they are left out.
+    #[inline]
+    pub fn get_include_hidden(&self) -> &bool {
+        &self.include_hidden
+    }
+
+    #[inline]
+    pub fn set_include_hidden(&mut self, value: bool) -> &mut Self {
+        self.include_hidden = value;
+        self
+    }
+
+    /// Wrap the table in a complete HTML document.
+    /// When false, only the `<table>` element is written so it can be embedded in a page.
+    #[inline]
+    pub fn get_full_document(&self) -> &bool {
+        &self.full_document
+    }
+
+    #[inline]
+    pub fn set_full_document(&mut self, value: bool) -> &mut Self {
+        self.full_document = value;
+        self
+    }
+}
@@ -27,16 +27,16 @@ impl MemberPropertyIndex {
         _reader: &mut Reader<R>,
         e: &BytesStart,
     ) {
-        set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, val, "v");
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // x
-        if self.val.has_value() {
-            let mut attributes: Vec<(&str, &str)> = Vec::new();
-            let val = self.val.get_value_string();
-            attributes.push(("val", &val));
-            write_start_tag(writer, "x", attributes, true);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let val = self.val.get_value_string();
+        if self.val.has_value() && self.get_val() != &0 {
+            attributes.push(("v", &val));
         }
+        write_start_tag(writer, "x", attributes, true);
     }
 }
@@ -0,0 +1,225 @@
+// x14:sparkline
+use crate::reader::driver::*;
+use crate::structs::office::excel::Formula;
+use crate::structs::office::excel::ReferenceSequence;
+use crate::structs::Address;
+use crate::traits::AdjustmentCoordinate;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::traits::AdjustmentValue;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug, Clone)]
+pub struct Sparkline {
+    formula: Formula,
+    reference_sequence: ReferenceSequence,
+}
+impl Sparkline {
+    /// Data of the sparkline.
+    #[inline]
+    pub fn get_formula(&self) -> &Formula {
+        &self.formula
+    }
+
+    #[inline]
+    pub fn get_formula_mut(&mut self) -> &mut Formula {
+        &mut self.formula
+    }
+
+    #[inline]
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = value;
+        self
+    }
+
+    /// Cell the sparkline is drawn in.
+    #[inline]
+    pub fn get_reference_sequence(&self) -> &ReferenceSequence {
+        &self.reference_sequence
+    }
+
+    #[inline]
+    pub fn get_reference_sequence_mut(&mut self) -> &mut ReferenceSequence {
+        &mut self.reference_sequence
+    }
+
+    #[inline]
+    pub fn set_reference_sequence(&mut self, value: ReferenceSequence) -> &mut Self {
+        self.reference_sequence = value;
+        self
+    }
+
+    /// Get the data range. ex) "Sheet1!A1:E1"
+    #[inline]
+    pub fn get_data_range(&self) -> String {
+        self.formula.get_value().get_address()
+    }
+
+    /// Set the data range.
+    /// # Arguments
+    /// * `value` - Range with the sheet name. ex) "Sheet1!A1:E1"
+    #[inline]
+    pub fn set_data_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut address = Address::default();
+        address.set_address(value);
+        self.formula.set_value(address);
+        self
+    }
+
+    /// Get the cell the sparkline is drawn in. ex) "F1"
+    #[inline]
+    pub fn get_location(&self) -> String {
+        self.reference_sequence.get_sqref()
+    }
+
+    /// Set the cell the sparkline is drawn in.
+    /// # Arguments
+    /// * `value` - Coordinate. ex) "F1"
+    #[inline]
+    pub fn set_location<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.reference_sequence.remove_value().set_sqref(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"xm:f" => {
+                        self.formula.set_attributes(reader, e);
+                    }
+                    b"xm:sqref" => {
+                        self.reference_sequence.set_attributes(reader, e);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:sparkline" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "x14:sparkline")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparkline
+        write_start_tag(writer, "x14:sparkline", vec![], false);
+
+        // xm:f
+        self.formula.write_to(writer);
+
+        // xm:sqref
+        self.reference_sequence.write_to(writer);
+
+        write_end_tag(writer, "x14:sparkline");
+    }
+}
+impl AdjustmentCoordinate for Sparkline {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for range in self.reference_sequence.get_value_mut() {
+            range.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for range in self.reference_sequence.get_value_mut() {
+            range.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        // a sparkline location is a single cell.
+        self.reference_sequence.get_value().iter().all(|range| {
+            range
+                .get_coordinate_start_col()
+                .map(|v| v.is_remove_value(root_col_num, offset_col_num))
+                .unwrap_or(false)
+                || range
+                    .get_coordinate_start_row()
+                    .map(|v| v.is_remove_value(root_row_num, offset_row_num))
+                    .unwrap_or(false)
+        })
+    }
+}
+impl AdjustmentCoordinateWithSheet for Sparkline {
+    #[inline]
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.formula
+            .get_value_mut()
+            .adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.formula
+            .get_value_mut()
+            .adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::structs::EnumTrait;
+use std::str::FromStr;
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SparklineAxisMinMaxValues {
+    Custom,
+    Group,
+    Individual,
+}
+impl Default for SparklineAxisMinMaxValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Individual
+    }
+}
+impl EnumTrait for SparklineAxisMinMaxValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Custom => "custom",
+            Self::Group => "group",
+            Self::Individual => "individual",
+        }
+    }
+}
+impl FromStr for SparklineAxisMinMaxValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "custom" => Ok(Self::Custom),
+            "group" => Ok(Self::Group),
+            "individual" => Ok(Self::Individual),
+            _ => Err(()),
+        }
+    }
+}
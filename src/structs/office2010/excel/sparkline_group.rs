@@ -0,0 +1,755 @@
+// x14:sparklineGroup
+use crate::reader::driver::*;
+use crate::structs::drawing::charts::DisplayBlanksAsValues;
+use crate::structs::office::excel::Formula;
+use crate::structs::office2010::excel::Sparkline;
+use crate::structs::office2010::excel::SparklineAxisMinMaxValues;
+use crate::structs::office2010::excel::SparklineTypeValues;
+use crate::structs::Address;
+use crate::structs::BooleanValue;
+use crate::structs::Color;
+use crate::structs::DoubleValue;
+use crate::structs::EnumValue;
+use crate::traits::AdjustmentCoordinate;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// # Examples
+/// ```rust
+/// use umya_spreadsheet::*;
+/// use umya_spreadsheet::structs::office2010::excel::*;
+/// let mut book = new_file();
+/// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+/// let mut sparkline = Sparkline::default();
+/// sparkline.set_data_range("Sheet1!A1:E1").set_location("F1");
+/// let mut sparkline_group = SparklineGroup::default();
+/// sparkline_group
+///     .set_type(SparklineTypeValues::Column)
+///     .set_high(true)
+///     .add_sparkline(sparkline);
+/// worksheet.add_sparkline_group(sparkline_group);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparklineGroup {
+    manual_max: DoubleValue,
+    manual_min: DoubleValue,
+    line_weight: DoubleValue,
+    r#type: EnumValue<SparklineTypeValues>,
+    date_axis: BooleanValue,
+    display_empty_cells_as: EnumValue<DisplayBlanksAsValues>,
+    markers: BooleanValue,
+    high: BooleanValue,
+    low: BooleanValue,
+    first: BooleanValue,
+    last: BooleanValue,
+    negative: BooleanValue,
+    display_x_axis: BooleanValue,
+    display_hidden: BooleanValue,
+    min_axis_type: EnumValue<SparklineAxisMinMaxValues>,
+    max_axis_type: EnumValue<SparklineAxisMinMaxValues>,
+    right_to_left: BooleanValue,
+    color_series: Color,
+    color_negative: Color,
+    color_axis: Color,
+    color_markers: Color,
+    color_first: Color,
+    color_last: Color,
+    color_high: Color,
+    color_low: Color,
+    formula: Option<Formula>,
+    sparkline_list: ThinVec<Sparkline>,
+}
+impl Default for SparklineGroup {
+    /// Colors are the ones of the default sparkline style of Excel.
+    fn default() -> Self {
+        let theme_color = |index: u32, tint: Option<f64>| {
+            let mut color = Color::default();
+            color.set_theme_index(index);
+            if let Some(v) = tint {
+                color.set_tint(v);
+            }
+            color
+        };
+        let mut color_axis = Color::default();
+        color_axis.set_argb(Color::COLOR_BLACK);
+        Self {
+            manual_max: DoubleValue::default(),
+            manual_min: DoubleValue::default(),
+            line_weight: DoubleValue::default(),
+            r#type: EnumValue::default(),
+            date_axis: BooleanValue::default(),
+            display_empty_cells_as: EnumValue::default(),
+            markers: BooleanValue::default(),
+            high: BooleanValue::default(),
+            low: BooleanValue::default(),
+            first: BooleanValue::default(),
+            last: BooleanValue::default(),
+            negative: BooleanValue::default(),
+            display_x_axis: BooleanValue::default(),
+            display_hidden: BooleanValue::default(),
+            min_axis_type: EnumValue::default(),
+            max_axis_type: EnumValue::default(),
+            right_to_left: BooleanValue::default(),
+            color_series: theme_color(4, Some(-0.499984740745262)),
+            color_negative: theme_color(5, None),
+            color_axis,
+            color_markers: theme_color(4, Some(-0.499984740745262)),
+            color_first: theme_color(4, Some(0.39997558519241921)),
+            color_last: theme_color(4, Some(0.39997558519241921)),
+            color_high: theme_color(4, None),
+            color_low: theme_color(4, None),
+            formula: None,
+            sparkline_list: ThinVec::new(),
+        }
+    }
+}
+impl SparklineGroup {
+    /// Maximum of the vertical axis when `max_axis_type` is custom.
+    #[inline]
+    pub fn get_manual_max(&self) -> &f64 {
+        self.manual_max.get_value()
+    }
+
+    #[inline]
+    pub fn set_manual_max(&mut self, value: f64) -> &mut Self {
+        self.manual_max.set_value(value);
+        self.max_axis_type
+            .set_value(SparklineAxisMinMaxValues::Custom);
+        self
+    }
+
+    /// Minimum of the vertical axis when `min_axis_type` is custom.
+    #[inline]
+    pub fn get_manual_min(&self) -> &f64 {
+        self.manual_min.get_value()
+    }
+
+    #[inline]
+    pub fn set_manual_min(&mut self, value: f64) -> &mut Self {
+        self.manual_min.set_value(value);
+        self.min_axis_type
+            .set_value(SparklineAxisMinMaxValues::Custom);
+        self
+    }
+
+    /// Weight of the line in points.
+    #[inline]
+    pub fn get_line_weight(&self) -> &f64 {
+        match self.line_weight.has_value() {
+            true => self.line_weight.get_value(),
+            false => &0.75,
+        }
+    }
+
+    #[inline]
+    pub fn set_line_weight(&mut self, value: f64) -> &mut Self {
+        self.line_weight.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_type(&self) -> &SparklineTypeValues {
+        self.r#type.get_value()
+    }
+
+    #[inline]
+    pub fn set_type(&mut self, value: SparklineTypeValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// The horizontal axis is a date axis given by `get_date_axis_range`.
+    #[inline]
+    pub fn get_date_axis(&self) -> &bool {
+        self.date_axis.get_value()
+    }
+
+    #[inline]
+    pub fn set_date_axis(&mut self, value: bool) -> &mut Self {
+        self.date_axis.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_display_empty_cells_as(&self) -> &DisplayBlanksAsValues {
+        match self.display_empty_cells_as.has_value() {
+            true => self.display_empty_cells_as.get_value(),
+            false => &DisplayBlanksAsValues::Zero,
+        }
+    }
+
+    #[inline]
+    pub fn set_display_empty_cells_as(&mut self, value: DisplayBlanksAsValues) -> &mut Self {
+        self.display_empty_cells_as.set_value(value);
+        self
+    }
+
+    /// Show markers on the line.
+    #[inline]
+    pub fn get_markers(&self) -> &bool {
+        self.markers.get_value()
+    }
+
+    #[inline]
+    pub fn set_markers(&mut self, value: bool) -> &mut Self {
+        self.markers.set_value(value);
+        self
+    }
+
+    /// Highlight the highest point.
+    #[inline]
+    pub fn get_high(&self) -> &bool {
+        self.high.get_value()
+    }
+
+    #[inline]
+    pub fn set_high(&mut self, value: bool) -> &mut Self {
+        self.high.set_value(value);
+        self
+    }
+
+    /// Highlight the lowest point.
+    #[inline]
+    pub fn get_low(&self) -> &bool {
+        self.low.get_value()
+    }
+
+    #[inline]
+    pub fn set_low(&mut self, value: bool) -> &mut Self {
+        self.low.set_value(value);
+        self
+    }
+
+    /// Highlight the first point.
+    #[inline]
+    pub fn get_first(&self) -> &bool {
+        self.first.get_value()
+    }
+
+    #[inline]
+    pub fn set_first(&mut self, value: bool) -> &mut Self {
+        self.first.set_value(value);
+        self
+    }
+
+    /// Highlight the last point.
+    #[inline]
+    pub fn get_last(&self) -> &bool {
+        self.last.get_value()
+    }
+
+    #[inline]
+    pub fn set_last(&mut self, value: bool) -> &mut Self {
+        self.last.set_value(value);
+        self
+    }
+
+    /// Highlight the negative points.
+    #[inline]
+    pub fn get_negative(&self) -> &bool {
+        self.negative.get_value()
+    }
+
+    #[inline]
+    pub fn set_negative(&mut self, value: bool) -> &mut Self {
+        self.negative.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_display_x_axis(&self) -> &bool {
+        self.display_x_axis.get_value()
+    }
+
+    #[inline]
+    pub fn set_display_x_axis(&mut self, value: bool) -> &mut Self {
+        self.display_x_axis.set_value(value);
+        self
+    }
+
+    /// Plot the data of hidden rows and columns.
+    #[inline]
+    pub fn get_display_hidden(&self) -> &bool {
+        self.display_hidden.get_value()
+    }
+
+    #[inline]
+    pub fn set_display_hidden(&mut self, value: bool) -> &mut Self {
+        self.display_hidden.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_min_axis_type(&self) -> &SparklineAxisMinMaxValues {
+        self.min_axis_type.get_value()
+    }
+
+    #[inline]
+    pub fn set_min_axis_type(&mut self, value: SparklineAxisMinMaxValues) -> &mut Self {
+        self.min_axis_type.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_max_axis_type(&self) -> &SparklineAxisMinMaxValues {
+        self.max_axis_type.get_value()
+    }
+
+    #[inline]
+    pub fn set_max_axis_type(&mut self, value: SparklineAxisMinMaxValues) -> &mut Self {
+        self.max_axis_type.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_right_to_left(&self) -> &bool {
+        self.right_to_left.get_value()
+    }
+
+    #[inline]
+    pub fn set_right_to_left(&mut self, value: bool) -> &mut Self {
+        self.right_to_left.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_color_series(&self) -> &Color {
+        &self.color_series
+    }
+
+    #[inline]
+    pub fn get_color_series_mut(&mut self) -> &mut Color {
+        &mut self.color_series
+    }
+
+    #[inline]
+    pub fn set_color_series(&mut self, value: Color) -> &mut Self {
+        self.color_series = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_negative(&self) -> &Color {
+        &self.color_negative
+    }
+
+    #[inline]
+    pub fn get_color_negative_mut(&mut self) -> &mut Color {
+        &mut self.color_negative
+    }
+
+    #[inline]
+    pub fn set_color_negative(&mut self, value: Color) -> &mut Self {
+        self.color_negative = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_axis(&self) -> &Color {
+        &self.color_axis
+    }
+
+    #[inline]
+    pub fn get_color_axis_mut(&mut self) -> &mut Color {
+        &mut self.color_axis
+    }
+
+    #[inline]
+    pub fn set_color_axis(&mut self, value: Color) -> &mut Self {
+        self.color_axis = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_markers(&self) -> &Color {
+        &self.color_markers
+    }
+
+    #[inline]
+    pub fn get_color_markers_mut(&mut self) -> &mut Color {
+        &mut self.color_markers
+    }
+
+    #[inline]
+    pub fn set_color_markers(&mut self, value: Color) -> &mut Self {
+        self.color_markers = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_first(&self) -> &Color {
+        &self.color_first
+    }
+
+    #[inline]
+    pub fn get_color_first_mut(&mut self) -> &mut Color {
+        &mut self.color_first
+    }
+
+    #[inline]
+    pub fn set_color_first(&mut self, value: Color) -> &mut Self {
+        self.color_first = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_last(&self) -> &Color {
+        &self.color_last
+    }
+
+    #[inline]
+    pub fn get_color_last_mut(&mut self) -> &mut Color {
+        &mut self.color_last
+    }
+
+    #[inline]
+    pub fn set_color_last(&mut self, value: Color) -> &mut Self {
+        self.color_last = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_high(&self) -> &Color {
+        &self.color_high
+    }
+
+    #[inline]
+    pub fn get_color_high_mut(&mut self) -> &mut Color {
+        &mut self.color_high
+    }
+
+    #[inline]
+    pub fn set_color_high(&mut self, value: Color) -> &mut Self {
+        self.color_high = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_low(&self) -> &Color {
+        &self.color_low
+    }
+
+    #[inline]
+    pub fn get_color_low_mut(&mut self) -> &mut Color {
+        &mut self.color_low
+    }
+
+    #[inline]
+    pub fn set_color_low(&mut self, value: Color) -> &mut Self {
+        self.color_low = value;
+        self
+    }
+
+    /// Dates of the horizontal axis when `date_axis` is set.
+    #[inline]
+    pub fn get_formula(&self) -> Option<&Formula> {
+        self.formula.as_ref()
+    }
+
+    #[inline]
+    pub fn get_formula_mut(&mut self) -> Option<&mut Formula> {
+        self.formula.as_mut()
+    }
+
+    #[inline]
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula(&mut self) -> &mut Self {
+        self.formula = None;
+        self
+    }
+
+    /// Use a date axis.
+    /// # Arguments
+    /// * `value` - Range of the dates with the sheet name. ex) "Sheet1!A1:E1"
+    pub fn set_date_axis_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut address = Address::default();
+        address.set_address(value);
+        let mut formula = Formula::default();
+        formula.set_value(address);
+        self.formula = Some(formula);
+        self.date_axis.set_value(true);
+        self
+    }
+
+    #[inline]
+    pub fn get_sparkline_list(&self) -> &[Sparkline] {
+        &self.sparkline_list
+    }
+
+    #[inline]
+    pub fn get_sparkline_list_mut(&mut self) -> &mut ThinVec<Sparkline> {
+        &mut self.sparkline_list
+    }
+
+    #[inline]
+    pub fn add_sparkline(&mut self, value: Sparkline) -> &mut Self {
+        self.sparkline_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, manual_max, "manualMax");
+        set_string_from_xml!(self, e, manual_min, "manualMin");
+        set_string_from_xml!(self, e, line_weight, "lineWeight");
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, date_axis, "dateAxis");
+        set_string_from_xml!(self, e, display_empty_cells_as, "displayEmptyCellsAs");
+        set_string_from_xml!(self, e, markers, "markers");
+        set_string_from_xml!(self, e, high, "high");
+        set_string_from_xml!(self, e, low, "low");
+        set_string_from_xml!(self, e, first, "first");
+        set_string_from_xml!(self, e, last, "last");
+        set_string_from_xml!(self, e, negative, "negative");
+        set_string_from_xml!(self, e, display_x_axis, "displayXAxis");
+        set_string_from_xml!(self, e, display_hidden, "displayHidden");
+        set_string_from_xml!(self, e, min_axis_type, "minAxisType");
+        set_string_from_xml!(self, e, max_axis_type, "maxAxisType");
+        set_string_from_xml!(self, e, right_to_left, "rightToLeft");
+
+        // only the colors in the file are kept.
+        for color in self.get_colors_mut() {
+            *color = Color::default();
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                let color = match e.name().into_inner() {
+                    b"x14:colorSeries" => Some(&mut self.color_series),
+                    b"x14:colorNegative" => Some(&mut self.color_negative),
+                    b"x14:colorAxis" => Some(&mut self.color_axis),
+                    b"x14:colorMarkers" => Some(&mut self.color_markers),
+                    b"x14:colorFirst" => Some(&mut self.color_first),
+                    b"x14:colorLast" => Some(&mut self.color_last),
+                    b"x14:colorHigh" => Some(&mut self.color_high),
+                    b"x14:colorLow" => Some(&mut self.color_low),
+                    _ => None,
+                };
+                if let Some(v) = color {
+                    v.set_attributes(reader, e, true);
+                }
+            },
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"xm:f" => {
+                        let mut obj = Formula::default();
+                        obj.set_attributes(reader, e);
+                        self.formula = Some(obj);
+                    }
+                    b"x14:sparkline" => {
+                        let mut obj = Sparkline::default();
+                        obj.set_attributes(reader, e);
+                        self.sparkline_list.push(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:sparklineGroup" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "x14:sparklineGroup")
+        );
+    }
+
+    #[inline]
+    fn get_colors_mut(&mut self) -> [&mut Color; 8] {
+        [
+            &mut self.color_series,
+            &mut self.color_negative,
+            &mut self.color_axis,
+            &mut self.color_markers,
+            &mut self.color_first,
+            &mut self.color_last,
+            &mut self.color_high,
+            &mut self.color_low,
+        ]
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparklineGroup
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let manual_max = self.manual_max.get_value_string();
+        if self.manual_max.has_value() {
+            attributes.push(("manualMax", &manual_max));
+        }
+        let manual_min = self.manual_min.get_value_string();
+        if self.manual_min.has_value() {
+            attributes.push(("manualMin", &manual_min));
+        }
+        let line_weight = self.line_weight.get_value_string();
+        if self.line_weight.has_value() {
+            attributes.push(("lineWeight", &line_weight));
+        }
+        if self.r#type.has_value() {
+            attributes.push(("type", self.r#type.get_value_string()));
+        }
+        if self.date_axis.has_value() {
+            attributes.push(("dateAxis", self.date_axis.get_value_string()));
+        }
+        if self.display_empty_cells_as.has_value() {
+            attributes.push((
+                "displayEmptyCellsAs",
+                self.display_empty_cells_as.get_value_string(),
+            ));
+        }
+        for (name, value) in [
+            ("markers", &self.markers),
+            ("high", &self.high),
+            ("low", &self.low),
+            ("first", &self.first),
+            ("last", &self.last),
+            ("negative", &self.negative),
+            ("displayXAxis", &self.display_x_axis),
+            ("displayHidden", &self.display_hidden),
+        ] {
+            if value.has_value() {
+                attributes.push((name, value.get_value_string()));
+            }
+        }
+        if self.min_axis_type.has_value() {
+            attributes.push(("minAxisType", self.min_axis_type.get_value_string()));
+        }
+        if self.max_axis_type.has_value() {
+            attributes.push(("maxAxisType", self.max_axis_type.get_value_string()));
+        }
+        if self.right_to_left.has_value() {
+            attributes.push(("rightToLeft", self.right_to_left.get_value_string()));
+        }
+        write_start_tag(writer, "x14:sparklineGroup", attributes, false);
+
+        // colors
+        self.color_series.write_to(writer, "x14:colorSeries");
+        self.color_negative.write_to(writer, "x14:colorNegative");
+        self.color_axis.write_to(writer, "x14:colorAxis");
+        self.color_markers.write_to(writer, "x14:colorMarkers");
+        self.color_first.write_to(writer, "x14:colorFirst");
+        self.color_last.write_to(writer, "x14:colorLast");
+        self.color_high.write_to(writer, "x14:colorHigh");
+        self.color_low.write_to(writer, "x14:colorLow");
+
+        // xm:f
+        if let Some(v) = &self.formula {
+            v.write_to(writer);
+        }
+
+        // x14:sparklines
+        write_start_tag(writer, "x14:sparklines", vec![], false);
+        for obj in &self.sparkline_list {
+            obj.write_to(writer);
+        }
+        write_end_tag(writer, "x14:sparklines");
+
+        write_end_tag(writer, "x14:sparklineGroup");
+    }
+}
+impl AdjustmentCoordinate for SparklineGroup {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.sparkline_list.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
+impl AdjustmentCoordinateWithSheet for SparklineGroup {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.get_value_mut().adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.get_value_mut().adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// outlinePr
+use super::BooleanValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct OutlineProperties {
+    apply_styles: BooleanValue,
+    summary_below: BooleanValue,
+    summary_right: BooleanValue,
+    show_outline_symbols: BooleanValue,
+}
+
+impl OutlineProperties {
+    #[inline]
+    pub fn get_apply_styles(&self) -> &bool {
+        self.apply_styles.get_value()
+    }
+
+    #[inline]
+    pub fn set_apply_styles(&mut self, value: bool) -> &mut Self {
+        self.apply_styles.set_value(value);
+        self
+    }
+
+    /// Summary rows are below the detail rows. When false, they are above.
+    #[inline]
+    pub fn get_summary_below(&self) -> &bool {
+        match self.summary_below.has_value() {
+            true => self.summary_below.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_summary_below(&mut self, value: bool) -> &mut Self {
+        self.summary_below.set_value(value);
+        self
+    }
+
+    /// Summary columns are right of the detail columns. When false, they are left.
+    #[inline]
+    pub fn get_summary_right(&self) -> &bool {
+        match self.summary_right.has_value() {
+            true => self.summary_right.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_summary_right(&mut self, value: bool) -> &mut Self {
+        self.summary_right.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_show_outline_symbols(&self) -> &bool {
+        match self.show_outline_symbols.has_value() {
+            true => self.show_outline_symbols.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_show_outline_symbols(&mut self, value: bool) -> &mut Self {
+        self.show_outline_symbols.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn has_param(&self) -> bool {
+        self.apply_styles.has_value()
+            || self.summary_below.has_value()
+            || self.summary_right.has_value()
+            || self.show_outline_symbols.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, apply_styles, "applyStyles");
+        set_string_from_xml!(self, e, summary_below, "summaryBelow");
+        set_string_from_xml!(self, e, summary_right, "summaryRight");
+        set_string_from_xml!(self, e, show_outline_symbols, "showOutlineSymbols");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // outlinePr
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.apply_styles.has_value() {
+            attributes.push(("applyStyles", self.apply_styles.get_value_string()));
+        }
+        if self.summary_below.has_value() {
+            attributes.push(("summaryBelow", self.summary_below.get_value_string()));
+        }
+        if self.summary_right.has_value() {
+            attributes.push(("summaryRight", self.summary_right.get_value_string()));
+        }
+        if self.show_outline_symbols.has_value() {
+            attributes.push((
+                "showOutlineSymbols",
+                self.show_outline_symbols.get_value_string(),
+            ));
+        }
+        write_start_tag(writer, "outlinePr", attributes, true);
+    }
+}
@@ -0,0 +1,110 @@
+// person
+use super::StringValue;
+use crate::helper::string_helper::make_guid;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// An author of threaded comments.
+#[derive(Clone, Default, Debug)]
+pub struct Person {
+    display_name: StringValue,
+    id: StringValue,
+    user_id: StringValue,
+    provider_id: StringValue,
+}
+
+impl Person {
+    /// Create a new person with a generated id.
+    /// # Arguments
+    /// * `display_name` - Name shown as the author of the comments.
+    #[inline]
+    pub fn new<S: Into<String>>(display_name: S) -> Self {
+        let mut obj = Self::default();
+        obj.set_display_name(display_name).set_id(make_guid());
+        obj
+    }
+
+    #[inline]
+    pub fn get_display_name(&self) -> &str {
+        self.display_name.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_display_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.display_name.set_value(value);
+        self
+    }
+
+    /// GUID of the person. ex) `{8B1A4E5C-2F3D-4A6B-9C7E-1D2F3A4B5C6D}`
+    #[inline]
+    pub fn get_id(&self) -> &str {
+        self.id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    /// Identity of the person at the provider. ex) e-mail address
+    #[inline]
+    pub fn get_user_id(&self) -> &str {
+        self.user_id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_user_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.user_id.set_value(value);
+        self
+    }
+
+    /// Identity provider. ex) `"AD"`, `"Windows Live"`, `"None"`
+    #[inline]
+    pub fn get_provider_id(&self) -> &str {
+        self.provider_id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_provider_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.provider_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, display_name, "displayName");
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, user_id, "userId");
+        set_string_from_xml!(self, e, provider_id, "providerId");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // person
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("displayName", self.display_name.get_value_str()));
+        attributes.push(("id", self.id.get_value_str()));
+        if self.user_id.has_value() {
+            attributes.push(("userId", self.user_id.get_value_str()));
+        }
+        attributes.push(("providerId", self.get_provider_id_or_default()));
+        write_start_tag(writer, "person", attributes, true);
+    }
+
+    #[inline]
+    fn get_provider_id_or_default(&self) -> &str {
+        match self.provider_id.has_value() {
+            true => self.provider_id.get_value_str(),
+            false => "None",
+        }
+    }
+}
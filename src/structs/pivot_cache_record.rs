@@ -0,0 +1,67 @@
+// r
+use crate::reader::driver::*;
+use crate::structs::PivotCacheValue;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// One row of the source data, with a value for each cache field.
+#[derive(Clone, Default, Debug)]
+pub struct PivotCacheRecord {
+    values: ThinVec<PivotCacheValue>,
+}
+impl PivotCacheRecord {
+    #[inline]
+    pub fn get_values(&self) -> &[PivotCacheValue] {
+        &self.values
+    }
+
+    #[inline]
+    pub fn get_values_mut(&mut self) -> &mut ThinVec<PivotCacheValue> {
+        &mut self.values
+    }
+
+    #[inline]
+    pub fn add_value(&mut self, value: PivotCacheValue) -> &mut Self {
+        self.values.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if let Some(v) = PivotCacheValue::from_xml(e) {
+                    self.values.push(v);
+                }
+            },
+            Event::Start(ref e) => {
+                if let Some(v) = PivotCacheValue::from_xml(e) {
+                    self.values.push(v);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"r" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "r")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // r
+        write_start_tag(writer, "r", vec![], false);
+        for value in &self.values {
+            value.write_to(writer);
+        }
+        write_end_tag(writer, "r");
+    }
+}
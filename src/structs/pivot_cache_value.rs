@@ -0,0 +1,100 @@
+// s, n, b, e, d, m, x
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use std::fmt;
+use std::io::Cursor;
+
+/// A value held by the pivot cache.
+/// Used for the shared items of a cache field and for the cache records.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PivotCacheValue {
+    String(Box<str>),
+    Number(f64),
+    Boolean(bool),
+    Error(Box<str>),
+    DateTime(Box<str>),
+    Missing,
+    /// Index of a shared item. (cache records only)
+    Index(u32),
+}
+
+impl Default for PivotCacheValue {
+    #[inline]
+    fn default() -> Self {
+        Self::Missing
+    }
+}
+
+impl fmt::Display for PivotCacheValue {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::String(v) => write!(f, "{v}"),
+            Self::Number(v) => write!(f, "{v}"),
+            Self::Boolean(v) => write!(f, "{}", if *v { "TRUE" } else { "FALSE" }),
+            Self::Error(v) => write!(f, "{v}"),
+            Self::DateTime(v) => write!(f, "{v}"),
+            Self::Missing => write!(f, ""),
+            Self::Index(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl PivotCacheValue {
+    #[inline]
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Self::Missing)
+    }
+
+    #[inline]
+    pub fn get_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Tag name of the value. ex) `"s"`
+    #[inline]
+    pub(crate) fn get_tag(&self) -> &str {
+        match self {
+            Self::String(_) => "s",
+            Self::Number(_) => "n",
+            Self::Boolean(_) => "b",
+            Self::Error(_) => "e",
+            Self::DateTime(_) => "d",
+            Self::Missing => "m",
+            Self::Index(_) => "x",
+        }
+    }
+
+    pub(crate) fn from_xml(e: &BytesStart) -> Option<Self> {
+        let v = get_attribute(e, b"v").unwrap_or_default();
+        let result = match e.name().into_inner() {
+            b"s" => Self::String(v.into_boxed_str()),
+            b"n" => Self::Number(v.parse::<f64>().unwrap_or(0f64)),
+            b"b" => Self::Boolean(v == "1" || v == "true"),
+            b"e" => Self::Error(v.into_boxed_str()),
+            b"d" => Self::DateTime(v.into_boxed_str()),
+            b"m" => Self::Missing,
+            b"x" => Self::Index(v.parse::<u32>().unwrap_or(0)),
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let value = match self {
+            Self::Missing => None,
+            Self::Boolean(v) => Some(if *v { "1".to_string() } else { "0".to_string() }),
+            _ => Some(self.to_string()),
+        };
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if let Some(v) = &value {
+            attributes.push(("v", v));
+        }
+        write_start_tag(writer, self.get_tag(), attributes, true);
+    }
+}
@@ -1,22 +1,39 @@
 // pivotField
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
-use crate::structs::ByteValue;
-use crate::structs::Location;
-use crate::structs::StringValue;
-use crate::structs::UInt32Value;
+use crate::structs::EnumValue;
+use crate::structs::PivotItem;
+use crate::structs::PivotTableAxisValues;
 use crate::writer::driver::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct PivotField {
+    axis: EnumValue<PivotTableAxisValues>,
     data_field: BooleanValue,
     show_all: BooleanValue,
+    items: ThinVec<PivotItem>,
 }
 impl PivotField {
+    /// Area of the pivot table the field is placed on.
+    #[inline]
+    pub fn get_axis(&self) -> Option<&PivotTableAxisValues> {
+        match self.axis.has_value() {
+            true => Some(self.axis.get_value()),
+            false => None,
+        }
+    }
+
+    #[inline]
+    pub fn set_axis(&mut self, value: PivotTableAxisValues) -> &mut Self {
+        self.axis.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_data_field(&self) -> &bool {
         self.data_field.get_value()
@@ -39,27 +56,82 @@ impl PivotField {
         self
     }
 
+    /// Items of the field in display order.
+    #[inline]
+    pub fn get_items(&self) -> &[PivotItem] {
+        &self.items
+    }
+
     #[inline]
+    pub fn get_items_mut(&mut self) -> &mut ThinVec<PivotItem> {
+        &mut self.items
+    }
+
+    #[inline]
+    pub fn add_item(&mut self, value: PivotItem) -> &mut Self {
+        self.items.push(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
+        empty_flag: bool,
     ) {
+        set_string_from_xml!(self, e, axis, "axis");
         set_string_from_xml!(self, e, data_field, "dataField");
         set_string_from_xml!(self, e, show_all, "showAll");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"item" {
+                    let mut obj = PivotItem::default();
+                    obj.set_attributes(reader, e);
+                    self.items.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"pivotField" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "pivotField")
+        );
     }
 
-    #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // pivotField
-        write_start_tag(
-            writer,
-            "pivotField",
-            vec![
-                ("dataField", self.data_field.get_value_string()),
-                ("showAll", self.show_all.get_value_string()),
-            ],
-            true,
-        );
+        let empty_flag = self.items.is_empty();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.axis.has_value() {
+            attributes.push(("axis", self.axis.get_value_string()));
+        }
+        if self.data_field.has_value() {
+            attributes.push(("dataField", self.data_field.get_value_string()));
+        }
+        attributes.push(("showAll", self.show_all.get_value_string()));
+        write_start_tag(writer, "pivotField", attributes, empty_flag);
+
+        if !empty_flag {
+            // items
+            write_start_tag(
+                writer,
+                "items",
+                vec![("count", &self.items.len().to_string())],
+                false,
+            );
+            for item in &self.items {
+                item.write_to(writer);
+            }
+            write_end_tag(writer, "items");
+
+            write_end_tag(writer, "pivotField");
+        }
     }
 }
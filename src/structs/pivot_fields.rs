@@ -44,7 +44,14 @@ impl PivotFields {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"pivotField" {
                     let mut obj = PivotField::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e, true);
+                    self.add_list_mut(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"pivotField" {
+                    let mut obj = PivotField::default();
+                    obj.set_attributes(reader, e, false);
                     self.add_list_mut(obj);
                 }
             },
@@ -0,0 +1,86 @@
+// item
+use crate::reader::driver::*;
+use crate::structs::BooleanValue;
+use crate::structs::EnumValue;
+use crate::structs::ItemValues;
+use crate::structs::UInt32Value;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct PivotItem {
+    index: UInt32Value,
+    item_type: EnumValue<ItemValues>,
+    hidden: BooleanValue,
+}
+impl PivotItem {
+    /// Index of the shared item in the cache field.
+    #[inline]
+    pub fn get_index(&self) -> &u32 {
+        self.index.get_value()
+    }
+
+    #[inline]
+    pub fn set_index(&mut self, value: u32) -> &mut Self {
+        self.index.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn has_index(&self) -> bool {
+        self.index.has_value()
+    }
+
+    #[inline]
+    pub fn get_item_type(&self) -> &ItemValues {
+        self.item_type.get_value()
+    }
+
+    #[inline]
+    pub fn set_item_type(&mut self, value: ItemValues) -> &mut Self {
+        self.item_type.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    #[inline]
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, index, "x");
+        set_string_from_xml!(self, e, item_type, "t");
+        set_string_from_xml!(self, e, hidden, "h");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // item
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let index_str = self.index.get_value_string();
+        if self.index.has_value() {
+            attributes.push(("x", &index_str));
+        }
+        if self.item_type.has_value() {
+            attributes.push(("t", self.item_type.get_value_string()));
+        }
+        if self.hidden.has_value() {
+            attributes.push(("h", self.hidden.get_value_string()));
+        }
+        write_start_tag(writer, "item", attributes, true);
+    }
+}
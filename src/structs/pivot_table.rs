@@ -1,17 +1,27 @@
-use super::SheetView;
-use crate::reader::driver::*;
+use crate::helper::pivot::*;
+use crate::structs::ColumnFields;
+use crate::structs::DataField;
+use crate::structs::DataFields;
+use crate::structs::Field;
 use crate::structs::PivotCacheDefinition;
+use crate::structs::PivotCacheRecords;
+use crate::structs::PivotCacheValue;
+use crate::structs::PivotField;
+use crate::structs::PivotFields;
+use crate::structs::PivotTableBuilder;
 use crate::structs::PivotTableDefinition;
-use crate::writer::driver::*;
-use quick_xml::events::{BytesStart, Event};
-use quick_xml::Reader;
-use quick_xml::Writer;
-use std::io::Cursor;
+use crate::structs::RowFields;
+use crate::structs::SourceValues;
+use crate::structs::Spreadsheet;
+use crate::structs::WorksheetSource;
+use crate::structs::XlsxError;
 
 #[derive(Clone, Default, Debug)]
 pub struct PivotTable {
     pivot_table_definition: PivotTableDefinition,
     pivot_cache_definition: PivotCacheDefinition,
+    pivot_cache_records: PivotCacheRecords,
+    builder: Option<Box<PivotTableBuilder>>,
 }
 
 impl PivotTable {
@@ -46,4 +56,279 @@ impl PivotTable {
         self.pivot_cache_definition = value;
         self
     }
+
+    #[inline]
+    pub fn get_pivot_cache_records(&self) -> &PivotCacheRecords {
+        &self.pivot_cache_records
+    }
+
+    #[inline]
+    pub fn get_pivot_cache_records_mut(&mut self) -> &mut PivotCacheRecords {
+        &mut self.pivot_cache_records
+    }
+
+    #[inline]
+    pub fn set_pivot_cache_records(&mut self, value: PivotCacheRecords) -> &mut Self {
+        self.pivot_cache_records = value;
+        self
+    }
+
+    /// Whether the cache and the layout are still to be generated from the builder.
+    #[inline]
+    pub(crate) fn has_pending_layout(&self) -> bool {
+        self.builder.is_some()
+    }
+
+    /// Refresh the pivot table from its source data.
+    /// The source range is read again, the cache fields and the cache records are rebuilt,
+    /// and the row and column items and the location are recalculated.
+    /// Use `Spreadsheet::refresh_pivot_tables` to also rewrite the output cells.
+    /// # Arguments
+    /// * `spreadsheet` - Spreadsheet holding the source data.
+    /// # Return value
+    /// * `Result` - Err when the source range or one of the fields is not found.
+    /// # Examples
+    /// ```
+    /// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    /// let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    /// let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    /// let mut pivot_table = sheet.get_pivot_tables()[0].clone();
+    /// pivot_table.refresh(&book).unwrap();
+    /// ```
+    pub fn refresh(&mut self, spreadsheet: &Spreadsheet) -> Result<(), XlsxError> {
+        let address = match &self.builder {
+            Some(v) => v.get_source().clone(),
+            None => match self
+                .pivot_cache_definition
+                .get_cache_source()
+                .get_worksheet_source()
+            {
+                Some(v) => get_source_address(spreadsheet, v)?,
+                None => return Err(XlsxError::PivotTable("no worksheet source".into())),
+            },
+        };
+        let (names, rows) = read_source(spreadsheet, &address)?;
+        match self.builder.clone() {
+            Some(v) => self.apply_builder(&v, &names)?,
+            None => self.remap_fields(&names)?,
+        }
+
+        let definition = &self.pivot_table_definition;
+        let axis_fields: Vec<i32> = definition
+            .get_row_fields()
+            .get_list()
+            .iter()
+            .chain(definition.get_column_fields().get_list())
+            .map(|v| *v.get_data_field())
+            .collect();
+        let axis: Vec<bool> = (0..names.len())
+            .map(|index| {
+                axis_fields.contains(&(index as i32))
+                    || definition
+                        .get_pivot_fields()
+                        .get_list()
+                        .get(index)
+                        .and_then(|v| v.get_axis())
+                        .is_some()
+            })
+            .collect();
+        let (cache_fields, records) = make_cache(&names, &rows, &axis);
+
+        self.pivot_cache_definition
+            .set_record_count(rows.len() as u32)
+            .set_cache_fields(cache_fields);
+        self.pivot_cache_records = records;
+        make_layout(
+            &mut self.pivot_table_definition,
+            self.pivot_cache_definition.get_cache_fields(),
+            &self.pivot_cache_records,
+        );
+        self.builder = None;
+        Ok(())
+    }
+
+    /// Labels and values of the output range, as `(col, row, value)`.
+    #[inline]
+    pub(crate) fn make_output(&self) -> Vec<(u32, u32, PivotCacheValue)> {
+        make_output(
+            &self.pivot_table_definition,
+            self.pivot_cache_definition.get_cache_fields(),
+            &self.pivot_cache_records,
+        )
+    }
+
+    /// Sets the row, column and data fields of the builder.
+    fn apply_builder(
+        &mut self,
+        builder: &PivotTableBuilder,
+        names: &[String],
+    ) -> Result<(), XlsxError> {
+        let find_field = |name: &str| {
+            names
+                .iter()
+                .position(|v| v.to_lowercase() == name.to_lowercase())
+                .ok_or_else(|| XlsxError::PivotTable(format!("field not found: {}", name)))
+        };
+        let row_fields = builder
+            .get_rows()
+            .iter()
+            .map(|v| find_field(v))
+            .collect::<Result<Vec<usize>, XlsxError>>()?;
+        let column_fields = builder
+            .get_columns()
+            .iter()
+            .map(|v| find_field(v))
+            .collect::<Result<Vec<usize>, XlsxError>>()?;
+        let mut data_fields: Vec<DataField> = Vec::new();
+        for (name, function) in builder.get_values() {
+            let index = find_field(name)?;
+            let mut caption = make_data_field_name(function, &names[index]);
+            let mut suffix = 2;
+            while data_fields.iter().any(|v| v.get_name() == caption) {
+                caption = format!(
+                    "{}{}",
+                    make_data_field_name(function, &names[index]),
+                    suffix
+                );
+                suffix += 1;
+            }
+            let mut data_field = DataField::default();
+            data_field
+                .set_name(caption)
+                .set_fie_id(index as u32)
+                .set_subtotal(function.clone())
+                .set_base_fie_id(0)
+                .set_base_item(0);
+            data_fields.push(data_field);
+        }
+        if data_fields.is_empty() {
+            return Err(XlsxError::PivotTable("no value fields".into()));
+        }
+
+        let definition = &mut self.pivot_table_definition;
+        definition.set_row_fields(RowFields::default());
+        definition.set_column_fields(ColumnFields::default());
+        definition.set_data_fields(DataFields::default());
+        for index in &row_fields {
+            let mut field = Field::default();
+            field.set_data_field(*index as i32);
+            definition.get_row_fields_mut().add_list_mut(field);
+        }
+        for index in &column_fields {
+            let mut field = Field::default();
+            field.set_data_field(*index as i32);
+            definition.get_column_fields_mut().add_list_mut(field);
+        }
+        if data_fields.len() > 1 {
+            let mut field = Field::default();
+            field.set_data_field(DATA_FIELD_INDEX);
+            definition.get_column_fields_mut().add_list_mut(field);
+        }
+        for data_field in data_fields {
+            definition.get_data_fields_mut().add_list_mut(data_field);
+        }
+        Ok(())
+    }
+
+    /// Moves the field indexes of the definition to the fields of the same name in the source.
+    fn remap_fields(&mut self, names: &[String]) -> Result<(), XlsxError> {
+        let old_names: Vec<String> = self
+            .pivot_cache_definition
+            .get_cache_fields()
+            .get_list()
+            .iter()
+            .map(|v| v.get_name().to_lowercase())
+            .collect();
+        let find_field = |old_index: usize| -> Result<usize, XlsxError> {
+            let name = old_names.get(old_index).ok_or_else(|| {
+                XlsxError::PivotTable(format!("field index out of range: {}", old_index))
+            })?;
+            names
+                .iter()
+                .position(|v| &v.to_lowercase() == name)
+                .ok_or_else(|| XlsxError::PivotTable(format!("field not found: {}", name)))
+        };
+
+        let definition = &mut self.pivot_table_definition;
+        for field in definition.get_row_fields_mut().get_list_mut() {
+            if *field.get_data_field() != DATA_FIELD_INDEX {
+                let index = find_field(*field.get_data_field() as usize)?;
+                field.set_data_field(index as i32);
+            }
+        }
+        for field in definition.get_column_fields_mut().get_list_mut() {
+            if *field.get_data_field() != DATA_FIELD_INDEX {
+                let index = find_field(*field.get_data_field() as usize)?;
+                field.set_data_field(index as i32);
+            }
+        }
+        for data_field in definition.get_data_fields_mut().get_list_mut() {
+            let index = find_field(*data_field.get_fie_id() as usize)?;
+            data_field.set_fie_id(index as u32);
+        }
+
+        let mut pivot_fields: Vec<PivotField> = vec![PivotField::default(); names.len()];
+        for (old_index, pivot_field) in definition.get_pivot_fields().get_list().iter().enumerate()
+        {
+            if let Ok(index) = find_field(old_index) {
+                pivot_fields[index] = pivot_field.clone();
+            }
+        }
+        let mut obj = PivotFields::default();
+        for pivot_field in pivot_fields {
+            obj.add_list_mut(pivot_field);
+        }
+        definition.set_pivot_fields(obj);
+        Ok(())
+    }
+}
+
+impl From<PivotTableBuilder> for PivotTable {
+    fn from(builder: PivotTableBuilder) -> Self {
+        let mut obj = Self::default();
+
+        let definition = &mut obj.pivot_table_definition;
+        definition
+            .set_name(builder.get_name())
+            .set_data_caption("Values")
+            .set_apply_number_formats(false)
+            .set_apply_border_formats(false)
+            .set_apply_font_formats(false)
+            .set_apply_pattern_formats(false)
+            .set_apply_alignment_formats(false)
+            .set_apply_width_height_formats(true)
+            .set_updated_version(6)
+            .set_min_refreshable_version(3)
+            .set_use_auto_formatting(true)
+            .set_item_print_titles(true)
+            .set_created_version(6)
+            .set_indent(0)
+            .set_outline(true)
+            .set_outline_data(true)
+            .set_multiple_field_filters(false);
+        definition
+            .get_location_mut()
+            .set_reference(builder.get_location());
+        definition
+            .get_pivot_table_style_mut()
+            .set_name(builder.get_style())
+            .set_show_row_headers(true)
+            .set_show_column_headers(true)
+            .set_show_row_stripes(false)
+            .set_show_column_stripes(false)
+            .set_show_last_column(true);
+
+        let mut worksheet_source = WorksheetSource::default();
+        worksheet_source.set_address(builder.get_source().clone());
+        obj.pivot_cache_definition
+            .set_created_version(6)
+            .set_refreshed_version(6)
+            .set_min_refreshable_version(3)
+            .get_cache_source_mut()
+            .set_type(SourceValues::Worksheet)
+            .set_worksheet_source_mut(worksheet_source);
+
+        obj.builder = Some(Box::new(builder));
+        obj
+    }
 }
@@ -0,0 +1,126 @@
+use crate::structs::Address;
+use crate::structs::DataConsolidateFunctionValues;
+
+/// Describes a pivot table to be generated from a source range.
+/// The first row of the source range holds the field names.
+/// The cache and the layout are generated from the source data when the file is written.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let mut book = new_file();
+/// book.new_sheet("Report").unwrap().add_pivot_table(
+///     PivotTableBuilder::new("Sheet1!A1:F500")
+///         .rows(["Region"])
+///         .columns(["Year"])
+///         .values([("Sales", DataConsolidateFunctionValues::Sum)]),
+/// );
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct PivotTableBuilder {
+    source: Address,
+    name: String,
+    location: String,
+    style: String,
+    rows: Vec<String>,
+    columns: Vec<String>,
+    values: Vec<(String, DataConsolidateFunctionValues)>,
+}
+
+impl PivotTableBuilder {
+    /// # Arguments
+    /// * `source` - Source range including the header row. ex) `"Sheet1!A1:F500"`
+    pub fn new<S: Into<String>>(source: S) -> Self {
+        let mut address = Address::default();
+        address.set_address(source);
+        Self {
+            source: address,
+            location: String::from("A3"),
+            style: String::from("PivotStyleLight16"),
+            ..Default::default()
+        }
+    }
+
+    /// Name of the pivot table. ex) `"PivotTable1"`
+    pub fn name<S: Into<String>>(mut self, value: S) -> Self {
+        self.name = value.into();
+        self
+    }
+
+    /// Top left cell of the pivot table. (default `"A3"`)
+    pub fn location<S: Into<String>>(mut self, value: S) -> Self {
+        self.location = value.into();
+        self
+    }
+
+    /// Name of the pivot table style. (default `"PivotStyleLight16"`)
+    pub fn style<S: Into<String>>(mut self, value: S) -> Self {
+        self.style = value.into();
+        self
+    }
+
+    /// Fields placed on the rows, outermost first.
+    pub fn rows<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rows.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Fields placed on the columns, outermost first.
+    pub fn columns<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.columns.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Fields summarized in the value area, with their aggregation.
+    pub fn values<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = (S, DataConsolidateFunctionValues)>,
+        S: Into<String>,
+    {
+        self.values
+            .extend(fields.into_iter().map(|(name, func)| (name.into(), func)));
+        self
+    }
+
+    #[inline]
+    pub fn get_source(&self) -> &Address {
+        &self.source
+    }
+
+    #[inline]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn get_location(&self) -> &str {
+        &self.location
+    }
+
+    #[inline]
+    pub fn get_style(&self) -> &str {
+        &self.style
+    }
+
+    #[inline]
+    pub fn get_rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    #[inline]
+    pub fn get_columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    #[inline]
+    pub fn get_values(&self) -> &[(String, DataConsolidateFunctionValues)] {
+        &self.values
+    }
+}
@@ -9,6 +9,7 @@ use crate::structs::DataFields;
 use crate::structs::Location;
 use crate::structs::PivotFields;
 use crate::structs::PivotTableStyle;
+use crate::structs::RowFields;
 use crate::structs::RowItems;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
@@ -41,6 +42,7 @@ pub struct PivotTableDefinition {
     created_version: ByteValue,
     location: Location,
     pivot_fields: PivotFields,
+    row_fields: RowFields,
     row_items: RowItems,
     column_fields: ColumnFields,
     column_items: ColumnItems,
@@ -289,6 +291,22 @@ impl PivotTableDefinition {
         self
     }
 
+    #[inline]
+    pub fn get_row_fields(&self) -> &RowFields {
+        &self.row_fields
+    }
+
+    #[inline]
+    pub fn get_row_fields_mut(&mut self) -> &mut RowFields {
+        &mut self.row_fields
+    }
+
+    #[inline]
+    pub fn set_row_fields(&mut self, value: RowFields) -> &mut Self {
+        self.row_fields = value;
+        self
+    }
+
     #[inline]
     pub fn get_row_items(&self) -> &RowItems {
         &self.row_items
@@ -419,6 +437,11 @@ impl PivotTableDefinition {
                     obj.set_attributes(reader, e);
                     self.set_pivot_fields(obj);
                 }
+                if e.name().into_inner() == b"rowFields" {
+                    let mut obj = RowFields::default();
+                    obj.set_attributes(reader, e);
+                    self.set_row_fields(obj);
+                }
                 if e.name().into_inner() == b"rowItems" {
                     let mut obj = RowItems::default();
                     obj.set_attributes(reader, e);
@@ -546,22 +569,39 @@ impl PivotTableDefinition {
         self.location.write_to(writer);
 
         // pivotFields
-        self.pivot_fields.write_to(writer);
+        if !self.pivot_fields.get_list().is_empty() {
+            self.pivot_fields.write_to(writer);
+        }
+
+        // rowFields
+        if !self.row_fields.get_list().is_empty() {
+            self.row_fields.write_to(writer);
+        }
 
         // rowItems
-        self.row_items.write_to(writer);
+        if !self.row_items.get_list().is_empty() {
+            self.row_items.write_to(writer);
+        }
 
         // colFields
-        self.column_fields.write_to(writer);
+        if !self.column_fields.get_list().is_empty() {
+            self.column_fields.write_to(writer);
+        }
 
         // colItems
-        self.column_items.write_to(writer);
+        if !self.column_items.get_list().is_empty() {
+            self.column_items.write_to(writer);
+        }
 
         // dataFields
-        self.data_fields.write_to(writer);
+        if !self.data_fields.get_list().is_empty() {
+            self.data_fields.write_to(writer);
+        }
 
         // pivotTableStyleInfo
-        self.pivot_table_style.write_to(writer);
+        if !self.pivot_table_style.get_name().is_empty() {
+            self.pivot_table_style.write_to(writer);
+        }
 
         write_end_tag(writer, "pivotTableDefinition");
     }
@@ -45,6 +45,14 @@ impl RawWorksheet {
             .find(|&relationships| relationships.get_file_target().starts_with(PKG_SHEET_RELS))
     }
 
+    /// Relationships of a file referenced from the worksheet. ex) pivot table
+    pub(crate) fn get_relationships_of_file(&self, file: &RawFile) -> Option<&RawRelationships> {
+        let target = format!("{}/{}", file.get_path(), file.make_rel_name());
+        self.get_relationships_list()
+            .iter()
+            .find(|&relationships| relationships.get_file_target() == target)
+    }
+
     pub(crate) fn get_drawing_relationships(&self) -> Option<&RawRelationships> {
         self.get_relationships_list().iter().find(|&relationships| {
             relationships
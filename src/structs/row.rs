@@ -1,4 +1,5 @@
 use super::BooleanValue;
+use super::ByteValue;
 use super::Cell;
 use super::Cells;
 use super::DoubleValue;
@@ -24,6 +25,8 @@ pub struct Row {
     thick_bot: BooleanValue,
     custom_height: BooleanValue,
     hidden: BooleanValue,
+    outline_level: ByteValue,
+    collapsed: BooleanValue,
     style: Box<Style>,
 }
 impl Default for Row {
@@ -36,6 +39,8 @@ impl Default for Row {
             thick_bot: BooleanValue::default(),
             custom_height: BooleanValue::default(),
             hidden: BooleanValue::default(),
+            outline_level: ByteValue::default(),
+            collapsed: BooleanValue::default(),
             style: Box::new(Style::default()),
         }
     }
@@ -108,6 +113,30 @@ impl Row {
         self
     }
 
+    /// Outline level of the row. (0 to 7)
+    #[inline]
+    pub fn get_outline_level(&self) -> &u8 {
+        self.outline_level.get_value()
+    }
+
+    #[inline]
+    pub fn set_outline_level(&mut self, value: u8) -> &mut Self {
+        self.outline_level.set_value(value.min(7));
+        self
+    }
+
+    /// The outline group this row summarizes is collapsed.
+    #[inline]
+    pub fn get_collapsed(&self) -> &bool {
+        self.collapsed.get_value()
+    }
+
+    #[inline]
+    pub fn set_collapsed(&mut self, value: bool) -> &mut Self {
+        self.collapsed.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_style(&self) -> &Style {
         &self.style
@@ -144,6 +173,8 @@ impl Row {
         set_string_from_xml!(self, e, thick_bot, "thickBot");
         set_string_from_xml!(self, e, custom_height, "customHeight");
         set_string_from_xml!(self, e, hidden, "hidden");
+        set_string_from_xml!(self, e, outline_level, "outlineLevel");
+        set_string_from_xml!(self, e, collapsed, "collapsed");
 
         if let Some(v) = get_attribute(e, b"x14ac:dyDescent") {
             if !v.is_empty() {
@@ -218,6 +249,13 @@ impl Row {
         if *self.hidden.get_value() {
             attributes.push(("hidden", self.hidden.get_value_string()));
         }
+        let outline_level = self.outline_level.get_value_string();
+        if self.outline_level.get_value() > &0 {
+            attributes.push(("outlineLevel", &outline_level));
+        }
+        if *self.collapsed.get_value() {
+            attributes.push(("collapsed", self.collapsed.get_value_string()));
+        }
         let descent = self.descent.get_value_string();
         if self.descent.has_value() {
             attributes.push(("x14ac:dyDescent", &descent));
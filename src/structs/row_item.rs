@@ -9,13 +9,14 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct RowItem {
     index: UInt32Value,
     item_type: EnumValue<ItemValues>,
     repeated_item_count: UInt32Value,
-    member_property_index: Option<MemberPropertyIndex>,
+    member_property_index: ThinVec<MemberPropertyIndex>,
 }
 impl RowItem {
     #[inline]
@@ -34,6 +35,11 @@ impl RowItem {
         self.item_type.get_value()
     }
 
+    #[inline]
+    pub fn has_item_type(&self) -> bool {
+        self.item_type.has_value()
+    }
+
     #[inline]
     pub fn set_item_type(&mut self, value: ItemValues) -> &mut Self {
         self.item_type.set_value(value);
@@ -53,17 +59,35 @@ impl RowItem {
 
     #[inline]
     pub fn get_member_property_index(&self) -> Option<&MemberPropertyIndex> {
-        self.member_property_index.as_ref()
+        self.member_property_index.first()
     }
 
     #[inline]
     pub fn get_member_property_index_mut(&mut self) -> Option<&mut MemberPropertyIndex> {
-        self.member_property_index.as_mut()
+        self.member_property_index.first_mut()
     }
 
     #[inline]
     pub fn set_member_property_index_color(&mut self, value: MemberPropertyIndex) -> &mut Self {
-        self.member_property_index = Some(value);
+        self.member_property_index.clear();
+        self.member_property_index.push(value);
+        self
+    }
+
+    /// Item indexes of each field from `repeated_item_count` onward.
+    #[inline]
+    pub fn get_member_property_index_list(&self) -> &[MemberPropertyIndex] {
+        &self.member_property_index
+    }
+
+    #[inline]
+    pub fn get_member_property_index_list_mut(&mut self) -> &mut ThinVec<MemberPropertyIndex> {
+        &mut self.member_property_index
+    }
+
+    #[inline]
+    pub fn add_member_property_index(&mut self, value: MemberPropertyIndex) -> &mut Self {
+        self.member_property_index.push(value);
         self
     }
 
@@ -88,7 +112,7 @@ impl RowItem {
                 if e.name().into_inner() == b"x" {
                     let mut obj = MemberPropertyIndex::default();
                     obj.set_attributes(reader, e);
-                    self.set_member_property_index_color(obj);
+                    self.add_member_property_index(obj);
                 }
             },
             Event::End(ref e) => {
@@ -102,7 +126,7 @@ impl RowItem {
 
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        let empty_flg = self.member_property_index.is_some();
+        let empty_flg = self.member_property_index.is_empty();
         // i
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let index_str = self.index.get_value_string();
@@ -117,8 +141,8 @@ impl RowItem {
             attributes.push(("r", &repeated_item_count_str));
         }
         write_start_tag(writer, "i", attributes, empty_flg);
-        if empty_flg == false {
-            if let Some(v) = &self.member_property_index {
+        if !empty_flg {
+            for v in &self.member_property_index {
                 v.write_to(writer);
             }
             write_end_tag(writer, "i");
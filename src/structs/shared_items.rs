@@ -2,20 +2,27 @@
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
 use crate::structs::DoubleValue;
+use crate::structs::PivotCacheValue;
 use crate::writer::driver::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct SharedItems {
     contains_semi_mixed_types: BooleanValue,
+    contains_non_date: BooleanValue,
+    contains_date: BooleanValue,
     contains_string: BooleanValue,
+    contains_blank: BooleanValue,
+    contains_mixed_types: BooleanValue,
     contains_number: BooleanValue,
     contains_integer: BooleanValue,
     min_value: DoubleValue,
     max_value: DoubleValue,
+    items: ThinVec<PivotCacheValue>,
 }
 impl SharedItems {
     pub fn get_contains_semi_mixed_types(&self) -> &bool {
@@ -27,6 +34,24 @@ impl SharedItems {
         self
     }
 
+    pub fn get_contains_non_date(&self) -> &bool {
+        self.contains_non_date.get_value()
+    }
+
+    pub fn set_contains_non_date(&mut self, value: bool) -> &mut Self {
+        self.contains_non_date.set_value(value);
+        self
+    }
+
+    pub fn get_contains_date(&self) -> &bool {
+        self.contains_date.get_value()
+    }
+
+    pub fn set_contains_date(&mut self, value: bool) -> &mut Self {
+        self.contains_date.set_value(value);
+        self
+    }
+
     pub fn get_contains_string(&self) -> &bool {
         self.contains_string.get_value()
     }
@@ -36,6 +61,24 @@ impl SharedItems {
         self
     }
 
+    pub fn get_contains_blank(&self) -> &bool {
+        self.contains_blank.get_value()
+    }
+
+    pub fn set_contains_blank(&mut self, value: bool) -> &mut Self {
+        self.contains_blank.set_value(value);
+        self
+    }
+
+    pub fn get_contains_mixed_types(&self) -> &bool {
+        self.contains_mixed_types.get_value()
+    }
+
+    pub fn set_contains_mixed_types(&mut self, value: bool) -> &mut Self {
+        self.contains_mixed_types.set_value(value);
+        self
+    }
+
     pub fn get_contains_number(&self) -> &bool {
         self.contains_number.get_value()
     }
@@ -72,36 +115,116 @@ impl SharedItems {
         self
     }
 
+    /// Distinct values of the field.
+    /// Cache records refer to them by index.
+    pub fn get_items(&self) -> &[PivotCacheValue] {
+        &self.items
+    }
+
+    pub fn get_items_mut(&mut self) -> &mut ThinVec<PivotCacheValue> {
+        &mut self.items
+    }
+
+    pub fn add_item(&mut self, value: PivotCacheValue) -> &mut Self {
+        self.items.push(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
+        empty_flag: bool,
     ) {
         set_string_from_xml!(self, e, contains_semi_mixed_types, "containsSemiMixedTypes");
+        set_string_from_xml!(self, e, contains_non_date, "containsNonDate");
+        set_string_from_xml!(self, e, contains_date, "containsDate");
         set_string_from_xml!(self, e, contains_string, "containsString");
+        set_string_from_xml!(self, e, contains_blank, "containsBlank");
+        set_string_from_xml!(self, e, contains_mixed_types, "containsMixedTypes");
         set_string_from_xml!(self, e, contains_number, "containsNumber");
         set_string_from_xml!(self, e, contains_integer, "containsInteger");
         set_string_from_xml!(self, e, min_value, "minValue");
         set_string_from_xml!(self, e, max_value, "maxValue");
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if let Some(v) = PivotCacheValue::from_xml(e) {
+                    self.items.push(v);
+                }
+            },
+            Event::Start(ref e) => {
+                if let Some(v) = PivotCacheValue::from_xml(e) {
+                    self.items.push(v);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sharedItems" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "sharedItems")
+        );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // sharedItems
-        write_start_tag(
-            writer,
-            "sharedItems",
-            vec![
-                (
-                    "containsSemiMixedTypes",
-                    self.contains_semi_mixed_types.get_value_string(),
-                ),
-                ("containsString", self.contains_string.get_value_string()),
-                ("containsNumber", self.contains_number.get_value_string()),
-                ("containsInteger", self.contains_integer.get_value_string()),
-                ("minValue", &self.min_value.get_value_string()),
-                ("maxValue", &self.max_value.get_value_string()),
-            ],
-            true,
-        );
+        let empty_flag = self.items.is_empty();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.contains_semi_mixed_types.has_value() {
+            attributes.push((
+                "containsSemiMixedTypes",
+                self.contains_semi_mixed_types.get_value_string(),
+            ));
+        }
+        if self.contains_non_date.has_value() {
+            attributes.push(("containsNonDate", self.contains_non_date.get_value_string()));
+        }
+        if self.contains_date.has_value() {
+            attributes.push(("containsDate", self.contains_date.get_value_string()));
+        }
+        if self.contains_string.has_value() {
+            attributes.push(("containsString", self.contains_string.get_value_string()));
+        }
+        if self.contains_blank.has_value() {
+            attributes.push(("containsBlank", self.contains_blank.get_value_string()));
+        }
+        if self.contains_mixed_types.has_value() {
+            attributes.push((
+                "containsMixedTypes",
+                self.contains_mixed_types.get_value_string(),
+            ));
+        }
+        if self.contains_number.has_value() {
+            attributes.push(("containsNumber", self.contains_number.get_value_string()));
+        }
+        if self.contains_integer.has_value() {
+            attributes.push(("containsInteger", self.contains_integer.get_value_string()));
+        }
+        let min_value_str = self.min_value.get_value_string();
+        if self.min_value.has_value() {
+            attributes.push(("minValue", &min_value_str));
+        }
+        let max_value_str = self.max_value.get_value_string();
+        if self.max_value.has_value() {
+            attributes.push(("maxValue", &max_value_str));
+        }
+        let count_str = self.items.len().to_string();
+        if !empty_flag {
+            attributes.push(("count", &count_str));
+        }
+        write_start_tag(writer, "sharedItems", attributes, empty_flag);
+
+        if !empty_flag {
+            for item in &self.items {
+                item.write_to(writer);
+            }
+            write_end_tag(writer, "sharedItems");
+        }
     }
 }
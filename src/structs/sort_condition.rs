@@ -0,0 +1,198 @@
+// sortCondition
+use super::BooleanValue;
+use super::DifferentialFormats;
+use super::EnumValue;
+use super::Range;
+use super::SortByValues;
+use super::StringValue;
+use super::Style;
+use crate::reader::driver::*;
+use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct SortCondition {
+    descending: BooleanValue,
+    sort_by: EnumValue<SortByValues>,
+    reference: Range,
+    custom_list: StringValue,
+    style: Option<Box<Style>>,
+}
+
+impl SortCondition {
+    #[inline]
+    pub fn get_descending(&self) -> &bool {
+        self.descending.get_value()
+    }
+
+    #[inline]
+    pub fn set_descending(&mut self, value: bool) -> &mut Self {
+        self.descending.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_sort_by(&self) -> &SortByValues {
+        self.sort_by.get_value()
+    }
+
+    #[inline]
+    pub fn set_sort_by(&mut self, value: SortByValues) -> &mut Self {
+        self.sort_by.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_reference(&self) -> &Range {
+        &self.reference
+    }
+
+    #[inline]
+    pub fn get_reference_mut(&mut self) -> &mut Range {
+        &mut self.reference
+    }
+
+    #[inline]
+    pub fn set_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut range = Range::default();
+        range.set_range(value);
+        self.reference = range;
+        self
+    }
+
+    /// Comma separated custom sort list. (ex. "Low,Medium,High")
+    #[inline]
+    pub fn get_custom_list(&self) -> &str {
+        self.custom_list.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_custom_list<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.custom_list.set_value(value);
+        self
+    }
+
+    /// Cell color or font color the condition sorts by.
+    #[inline]
+    pub fn get_style(&self) -> Option<&Style> {
+        self.style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_style(&mut self) -> &mut Self {
+        self.style = None;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+    ) {
+        set_string_from_xml!(self, e, descending, "descending");
+        set_string_from_xml!(self, e, sort_by, "sortBy");
+        set_string_from_xml!(self, e, custom_list, "customList");
+
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.set_reference(v);
+        }
+
+        if let Some(v) = get_attribute(e, b"dxfId") {
+            let dxf_id = v.parse::<usize>().unwrap();
+            self.set_style(differential_formats.get_style(dxf_id));
+        }
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // sortCondition
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+
+        if self.descending.has_value() {
+            attributes.push(("descending", self.descending.get_value_string()));
+        }
+
+        if self.sort_by.has_value() {
+            attributes.push(("sortBy", self.sort_by.get_value_string()));
+        }
+
+        let reference = self.reference.get_range();
+        attributes.push(("ref", &reference));
+
+        if self.custom_list.has_value() {
+            attributes.push(("customList", self.custom_list.get_value_str()));
+        }
+
+        let dxf_id_str: String;
+        if let Some(v) = &self.style {
+            dxf_id_str = differential_formats.set_style(v).to_string();
+            attributes.push(("dxfId", &dxf_id_str));
+        }
+
+        write_start_tag(writer, "sortCondition", attributes, true);
+    }
+}
+impl AdjustmentCoordinate for SortCondition {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.reference.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.reference.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.reference.is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
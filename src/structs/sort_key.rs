@@ -0,0 +1,95 @@
+use super::Color;
+use super::SortByValues;
+use thin_vec::ThinVec;
+
+/// A key of `Worksheet::sort_range`.
+#[derive(Clone, Default, Debug)]
+pub struct SortKey {
+    column: u32,
+    descending: bool,
+    case_sensitive: bool,
+    custom_list: ThinVec<String>,
+    sort_by: SortByValues,
+    color: Option<Box<Color>>,
+}
+impl SortKey {
+    /// Column number of the key. (ex. 1 for column A)
+    /// The column has to be inside the sorted range.
+    #[inline]
+    pub fn get_column(&self) -> &u32 {
+        &self.column
+    }
+
+    #[inline]
+    pub fn set_column(&mut self, value: u32) -> &mut Self {
+        self.column = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_descending(&self) -> &bool {
+        &self.descending
+    }
+
+    #[inline]
+    pub fn set_descending(&mut self, value: bool) -> &mut Self {
+        self.descending = value;
+        self
+    }
+
+    /// Compare text case sensitively. Lowercase sorts before uppercase.
+    #[inline]
+    pub fn get_case_sensitive(&self) -> &bool {
+        &self.case_sensitive
+    }
+
+    #[inline]
+    pub fn set_case_sensitive(&mut self, value: bool) -> &mut Self {
+        self.case_sensitive = value;
+        self
+    }
+
+    /// Values sorted in the order of the list. (ex. ["Low", "Medium", "High"])
+    /// Values that are not in the list follow them.
+    #[inline]
+    pub fn get_custom_list(&self) -> &[String] {
+        &self.custom_list
+    }
+
+    #[inline]
+    pub fn set_custom_list<S: Into<String>>(&mut self, value: Vec<S>) -> &mut Self {
+        self.custom_list = value.into_iter().map(|v| v.into()).collect();
+        self
+    }
+
+    /// Sort by the cell value, the cell color or the font color.
+    #[inline]
+    pub fn get_sort_by(&self) -> &SortByValues {
+        &self.sort_by
+    }
+
+    #[inline]
+    pub fn set_sort_by(&mut self, value: SortByValues) -> &mut Self {
+        self.sort_by = value;
+        self
+    }
+
+    /// Color moved to the top (to the bottom when descending) when sorting by color.
+    /// If not set, cells without a color are moved.
+    #[inline]
+    pub fn get_color(&self) -> Option<&Color> {
+        self.color.as_deref()
+    }
+
+    #[inline]
+    pub fn set_color(&mut self, value: Color) -> &mut Self {
+        self.color = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_color(&mut self) -> &mut Self {
+        self.color = None;
+        self
+    }
+}
@@ -0,0 +1,38 @@
+/// Options of `Worksheet::sort_range_with_option`.
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SortOption {
+    pub(crate) has_header: Option<bool>,
+    pub(crate) save_sort_state: bool,
+}
+impl SortOption {
+    /// Whether the first row of the range is a header that stays in place.
+    /// If not set, it is detected from the values and fonts of the first two rows.
+    #[inline]
+    pub fn get_has_header(&self) -> Option<&bool> {
+        self.has_header.as_ref()
+    }
+
+    #[inline]
+    pub fn set_has_header(&mut self, value: bool) -> &mut Self {
+        self.has_header = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_has_header(&mut self) -> &mut Self {
+        self.has_header = None;
+        self
+    }
+
+    /// Save the sort as `sortState` so Excel shows it in the sort dialog.
+    #[inline]
+    pub fn get_save_sort_state(&self) -> &bool {
+        &self.save_sort_state
+    }
+
+    #[inline]
+    pub fn set_save_sort_state(&mut self, value: bool) -> &mut Self {
+        self.save_sort_state = value;
+        self
+    }
+}
@@ -0,0 +1,217 @@
+// sortState
+use super::BooleanValue;
+use super::DifferentialFormats;
+use super::Range;
+use super::SortCondition;
+use crate::reader::driver::*;
+use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct SortState {
+    column_sort: BooleanValue,
+    case_sensitive: BooleanValue,
+    reference: Range,
+    sort_condition_list: ThinVec<SortCondition>,
+}
+
+impl SortState {
+    /// Sort left to right instead of top to bottom.
+    #[inline]
+    pub fn get_column_sort(&self) -> &bool {
+        self.column_sort.get_value()
+    }
+
+    #[inline]
+    pub fn set_column_sort(&mut self, value: bool) -> &mut Self {
+        self.column_sort.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_case_sensitive(&self) -> &bool {
+        self.case_sensitive.get_value()
+    }
+
+    #[inline]
+    pub fn set_case_sensitive(&mut self, value: bool) -> &mut Self {
+        self.case_sensitive.set_value(value);
+        self
+    }
+
+    /// Sorted range without the header row.
+    #[inline]
+    pub fn get_reference(&self) -> &Range {
+        &self.reference
+    }
+
+    #[inline]
+    pub fn get_reference_mut(&mut self) -> &mut Range {
+        &mut self.reference
+    }
+
+    #[inline]
+    pub fn set_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut range = Range::default();
+        range.set_range(value);
+        self.reference = range;
+        self
+    }
+
+    #[inline]
+    pub fn get_sort_condition_list(&self) -> &[SortCondition] {
+        &self.sort_condition_list
+    }
+
+    #[inline]
+    pub fn get_sort_condition_list_mut(&mut self) -> &mut ThinVec<SortCondition> {
+        &mut self.sort_condition_list
+    }
+
+    #[inline]
+    pub fn add_sort_condition(&mut self, value: SortCondition) -> &mut Self {
+        self.sort_condition_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) {
+        set_string_from_xml!(self, e, column_sort, "columnSort");
+        set_string_from_xml!(self, e, case_sensitive, "caseSensitive");
+
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.set_reference(v);
+        }
+
+        if empty_flag {
+            return;
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"sortCondition" {
+                    let mut obj = SortCondition::default();
+                    obj.set_attributes(reader, e, differential_formats);
+                    self.sort_condition_list.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sortState" {
+                    return
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "sortState")
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        let is_inner = !self.sort_condition_list.is_empty();
+
+        // sortState
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+
+        if self.column_sort.has_value() {
+            attributes.push(("columnSort", self.column_sort.get_value_string()));
+        }
+
+        if self.case_sensitive.has_value() {
+            attributes.push(("caseSensitive", self.case_sensitive.get_value_string()));
+        }
+
+        let reference = self.reference.get_range();
+        attributes.push(("ref", &reference));
+
+        write_start_tag(writer, "sortState", attributes, !is_inner);
+
+        if is_inner {
+            // sortCondition
+            for v in &self.sort_condition_list {
+                v.write_to(writer, differential_formats);
+            }
+
+            write_end_tag(writer, "sortState");
+        }
+    }
+}
+impl AdjustmentCoordinate for SortState {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.reference.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for sort_condition in &mut self.sort_condition_list {
+            sort_condition.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.sort_condition_list.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        self.reference.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for sort_condition in &mut self.sort_condition_list {
+            sort_condition.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.reference.is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
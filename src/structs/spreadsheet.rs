@@ -1,20 +1,26 @@
 use crate::helper::address::*;
+use crate::helper::calculation::*;
 use crate::helper::coordinate::*;
+use crate::helper::number_format::DATE_TIME_REGEX;
+use crate::helper::range::get_start_and_end_point_checked;
 use crate::reader::xlsx::*;
 use crate::structs::drawing::Theme;
 use crate::structs::Address;
 use crate::structs::CellValue;
 use crate::structs::Cells;
 use crate::structs::DefinedName;
+use crate::structs::Person;
 use crate::structs::Properties;
 use crate::structs::SharedStringTable;
 use crate::structs::Stylesheet;
 use crate::structs::WorkbookProtection;
 use crate::structs::WorkbookView;
 use crate::structs::Worksheet;
+use crate::structs::XlsxError;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::StringValue;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::RwLock;
 use thin_vec::ThinVec;
@@ -36,6 +42,8 @@ pub struct Spreadsheet {
     pivot_caches: ThinVec<(Box<str>, Box<str>, Box<str>)>,
     workbook_protection: Option<Box<WorkbookProtection>>,
     defined_names: ThinVec<DefinedName>,
+    persons: ThinVec<Person>,
+    dependency_graph: Option<Box<DependencyGraph>>,
 }
 
 impl Spreadsheet {
@@ -173,6 +181,203 @@ impl Spreadsheet {
             .get_cell_value_by_range(&address.get_range().get_range())
     }
 
+    /// (This method is crate only.)
+    /// Get the serial values of a range whose every cell holds a date,
+    /// together with the number format code of the first cell.
+    /// Returns `None` as soon as one cell is missing, not numeric or not date formatted.
+    pub(crate) fn get_date_value_by_address_crate(
+        &self,
+        address: &Address,
+    ) -> Option<(String, Vec<f64>)> {
+        let worksheet = self.get_sheet_by_name(address.get_sheet_name())?;
+        let (row_start, row_end, col_start, col_end) =
+            get_start_and_end_point_checked(&address.get_range().get_range()).ok()?;
+        let mut format_code: Option<String> = None;
+        let mut serial_list: Vec<f64> = Vec::new();
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                let cell = worksheet.get_cell((col, row))?;
+                let value = cell.get_value_number()?;
+                let code = cell.get_style().get_number_format()?.get_format_code();
+                if !DATE_TIME_REGEX.is_match(code).unwrap_or(false) {
+                    return None;
+                }
+                if format_code.is_none() {
+                    format_code = Some(code.to_string());
+                }
+                serial_list.push(value);
+            }
+        }
+        Some((format_code?, serial_list))
+    }
+
+    /// Calculate every formula in the workbook and store the results as cell values.
+    /// The stored values are what other applications read without recalculating.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(1);
+    /// worksheet.get_cell_mut("A2").set_formula("SUM(A1,2)");
+    /// book.calculate();
+    /// assert_eq!(book.get_sheet(&0).unwrap().get_value("A2"), "3");
+    /// ```
+    pub fn calculate(&mut self) -> &mut Self {
+        self.read_sheet_collection();
+        let mut calculator = Calculator::from_spreadsheet(self);
+        calculator.calculate_all();
+        let results = calculator.into_results();
+        self.set_calculated_results(results)
+    }
+
+    /// Recalculate only the formulas affected by a change of a cell, directly or indirectly.
+    /// Other formula cells keep their cached value.
+    /// # Arguments
+    /// * `sheet_name` - Name of the worksheet holding the changed cell.
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(1);
+    /// worksheet.get_cell_mut("A2").set_formula("A1*10");
+    /// book.calculate();
+    /// book.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_value_number(2);
+    /// book.calculate_dependents("Sheet1", "A1");
+    /// assert_eq!(book.get_sheet(&0).unwrap().get_value("A2"), "20");
+    /// ```
+    pub fn calculate_dependents<T>(&mut self, sheet_name: &str, coordinate: T) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let sheet_index = match self.find_sheet_index_by_name(sheet_name) {
+            Some(v) => v,
+            None => return self,
+        };
+        let graph = self.get_dependency_graph();
+        let mut targets = graph.get_all_dependents(sheet_index, col, row);
+        // the changed cell itself may hold a new formula
+        if graph.has_formula(sheet_index, col, row) {
+            targets.insert((sheet_index, col, row));
+        }
+        let mut keys: Vec<(usize, u32, u32)> = targets.iter().cloned().collect();
+        keys.sort_by_key(|(sheet_index, col, row)| (*sheet_index, *row, *col));
+        let mut calculator = Calculator::from_spreadsheet(self);
+        calculator.set_targets(targets);
+        for (sheet_index, col, row) in keys {
+            calculator.calculate_cell(sheet_index, col, row);
+        }
+        let results = calculator.into_results();
+        self.set_calculated_results(results)
+    }
+
+    /// Get the dependency graph of the workbook formulas.
+    /// The graph is kept until a worksheet or a defined name is borrowed mutably.
+    fn get_dependency_graph(&mut self) -> &DependencyGraph {
+        self.read_sheet_collection();
+        if self.dependency_graph.is_none() {
+            let graph = DependencyGraph::new(&Calculator::from_spreadsheet(self));
+            self.dependency_graph = Some(Box::new(graph));
+        }
+        self.dependency_graph.as_ref().unwrap()
+    }
+
+    #[inline]
+    fn clear_dependency_graph(&mut self) {
+        self.dependency_graph = None;
+    }
+
+    fn to_address(&self, sheet_index: usize, range: &str) -> Address {
+        let mut address = Address::default();
+        address.set_sheet_name(self.work_sheet_collection[sheet_index].get_name());
+        address.get_range_mut().set_range(range);
+        address
+    }
+
+    fn set_calculated_results(
+        &mut self,
+        results: HashMap<(usize, u32, u32), CalculatedValue>,
+    ) -> &mut Self {
+        let mut values: Vec<Vec<((u32, u32), CalculatedValue)>> =
+            vec![Vec::new(); self.work_sheet_collection.len()];
+        for ((sheet_index, col, row), value) in results {
+            values[sheet_index].push(((col, row), value));
+        }
+        for (worksheet, values) in self.work_sheet_collection.iter_mut().zip(values) {
+            worksheet.set_calculated_values(values.into_iter());
+        }
+        self
+    }
+
+    /// Gets the cells and ranges the formula of a cell refers to.
+    /// Defined names are resolved to what they refer to.
+    /// # Arguments
+    /// * `sheet_name` - Name of the worksheet holding the cell.
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// *`Vec<Address>` - Referenced ranges. Empty when the cell has no formula.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("C1").set_formula("SUM(A1:B2)");
+    /// let precedents = book.get_precedents("Sheet1", "C1");
+    /// assert_eq!(precedents[0].get_address(), "Sheet1!A1:B2");
+    /// ```
+    pub fn get_precedents<T>(&mut self, sheet_name: &str, coordinate: T) -> Vec<Address>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let sheet_index = match self.find_sheet_index_by_name(sheet_name) {
+            Some(v) => v,
+            None => return Vec::new(),
+        };
+        let precedents = self
+            .get_dependency_graph()
+            .get_precedents(sheet_index, col, row)
+            .to_vec();
+        precedents
+            .iter()
+            .map(|v| self.to_address(v.sheet_index, &v.get_range()))
+            .collect()
+    }
+
+    /// Gets the formula cells referring to a cell directly.
+    /// # Arguments
+    /// * `sheet_name` - Name of the worksheet holding the cell.
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// *`Vec<Address>` - Formula cells, sorted by sheet, row and column.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("C1").set_formula("SUM(A1:B2)");
+    /// let dependents = book.get_dependents("Sheet1", "B2");
+    /// assert_eq!(dependents[0].get_address(), "Sheet1!C1");
+    /// ```
+    pub fn get_dependents<T>(&mut self, sheet_name: &str, coordinate: T) -> Vec<Address>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let sheet_index = match self.find_sheet_index_by_name(sheet_name) {
+            Some(v) => v,
+            None => return Vec::new(),
+        };
+        let dependents = self
+            .get_dependency_graph()
+            .get_dependents(sheet_index, col, row);
+        dependents
+            .into_iter()
+            .map(|(sheet_index, col, row)| {
+                self.to_address(sheet_index, &coordinate_from_index(&col, &row))
+            })
+            .collect()
+    }
+
     /// Get Theme.
     #[inline]
     pub fn get_theme(&self) -> &Theme {
@@ -329,6 +534,7 @@ impl Spreadsheet {
     #[inline]
     pub fn get_sheet_collection_mut(&mut self) -> &mut ThinVec<Worksheet> {
         self.read_sheet_collection();
+        self.clear_dependency_graph();
         &mut self.work_sheet_collection
     }
 
@@ -351,6 +557,35 @@ impl Spreadsheet {
         self
     }
 
+    /// Refresh all pivot tables from their source data.
+    /// The pivot caches and layouts are rebuilt, and the output ranges are rewritten
+    /// with the labels, values, subtotals and grand totals.
+    /// # Return value
+    /// * `Result` - Err when the source range or one of the fields of a pivot table is not found,
+    /// or when an output range grows over other cells.
+    /// # Examples
+    /// ```
+    /// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    /// let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    /// book.get_sheet_by_name_mut("Sheet1").unwrap().get_cell_mut("D40").set_value("test3");
+    /// book.refresh_pivot_tables().unwrap();
+    /// ```
+    pub fn refresh_pivot_tables(&mut self) -> Result<(), XlsxError> {
+        self.read_sheet_collection();
+        self.clear_dependency_graph();
+        for sheet_index in 0..self.work_sheet_collection.len() {
+            if !self.work_sheet_collection[sheet_index].has_pivot_table() {
+                continue;
+            }
+            let mut worksheet = self.work_sheet_collection[sheet_index].clone();
+            for index in 0..worksheet.get_pivot_tables().len() {
+                worksheet.refresh_pivot_table(index, self)?;
+            }
+            self.work_sheet_collection[sheet_index] = worksheet;
+        }
+        Ok(())
+    }
+
     /// deserialize a worksheet.
     #[inline]
     pub fn read_sheet(&mut self, index: usize) -> &mut Self {
@@ -420,6 +655,7 @@ impl Spreadsheet {
     /// # Return value
     /// * `Option<&mut Worksheet>`.
     pub fn get_sheet_mut(&mut self, index: &usize) -> Option<&mut Worksheet> {
+        self.clear_dependency_graph();
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
         self.work_sheet_collection.get_mut(*index).map(|v| {
@@ -472,6 +708,7 @@ impl Spreadsheet {
     pub fn add_sheet(&mut self, value: Worksheet) -> Result<&mut Worksheet, &'static str> {
         let title = value.get_name();
         Spreadsheet::check_sheet_name(self, title)?;
+        self.clear_dependency_graph();
         self.work_sheet_collection.push(value);
         Ok(self.work_sheet_collection.last_mut().unwrap())
     }
@@ -486,6 +723,7 @@ impl Spreadsheet {
         if self.work_sheet_collection.len() <= index {
             return Err("out of index.");
         }
+        self.clear_dependency_graph();
         self.work_sheet_collection.remove(index);
         Ok(())
     }
@@ -496,6 +734,7 @@ impl Spreadsheet {
     /// # Return value
     /// * `Result<(), &'static str>` - OK:removed worksheet. Err:Error.
     pub fn remove_sheet_by_name(&mut self, sheet_name: &str) -> Result<(), &'static str> {
+        self.clear_dependency_graph();
         let cnt_before = self.work_sheet_collection.len();
         self.work_sheet_collection
             .retain(|x| x.get_name() != sheet_name);
@@ -705,6 +944,7 @@ impl Spreadsheet {
     /// Get Defined Name (Vec) in mutable.
     #[inline]
     pub fn get_defined_names_mut(&mut self) -> &mut ThinVec<DefinedName> {
+        self.clear_dependency_graph();
         &mut self.defined_names
     }
 
@@ -713,6 +953,7 @@ impl Spreadsheet {
     /// * `value` - Vec<DefinedName>.
     #[inline]
     pub fn set_defined_names(&mut self, value: impl Into<ThinVec<DefinedName>>) {
+        self.clear_dependency_graph();
         self.defined_names = value.into();
     }
 
@@ -721,8 +962,45 @@ impl Spreadsheet {
     /// * `value` - DefinedName.
     #[inline]
     pub fn add_defined_names(&mut self, value: DefinedName) {
+        self.clear_dependency_graph();
         self.defined_names.push(value);
     }
+
+    /// Get Persons (authors of threaded comments).
+    #[inline]
+    pub fn get_persons(&self) -> &[Person] {
+        &self.persons
+    }
+
+    /// Get Persons in mutable.
+    #[inline]
+    pub fn get_persons_mut(&mut self) -> &mut ThinVec<Person> {
+        &mut self.persons
+    }
+
+    /// Get Person by id.
+    /// # Arguments
+    /// * `id` - Id of the person. see `ThreadedComment::get_person_id()`.
+    #[inline]
+    pub fn get_person(&self, id: &str) -> Option<&Person> {
+        self.persons.iter().find(|v| v.get_id() == id)
+    }
+
+    /// Set Persons.
+    /// # Arguments
+    /// * `value` - Vec<Person>.
+    #[inline]
+    pub fn set_persons(&mut self, value: impl Into<ThinVec<Person>>) {
+        self.persons = value.into();
+    }
+
+    /// Add Person.
+    /// # Arguments
+    /// * `value` - Person.
+    #[inline]
+    pub fn add_person(&mut self, value: Person) {
+        self.persons.push(value);
+    }
 }
 impl AdjustmentCoordinateWithSheet for Spreadsheet {
     fn adjustment_insert_coordinate_with_sheet(
@@ -733,6 +1011,7 @@ impl AdjustmentCoordinateWithSheet for Spreadsheet {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
+        self.clear_dependency_graph();
         self.read_sheet_collection();
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_insert_coordinate(
@@ -759,6 +1038,7 @@ impl AdjustmentCoordinateWithSheet for Spreadsheet {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
+        self.clear_dependency_graph();
         self.read_sheet_collection();
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_remove_coordinate(
@@ -6,9 +6,10 @@ use quick_xml::{
 };
 
 use super::{
-    coordinate::*, BooleanValue, EnumValue, StringValue, TotalsRowFunctionValues, UInt32Value,
+    coordinate::*, BooleanValue, Cell, EnumValue, StringValue, TotalsRowFunctionValues, UInt32Value,
 };
 use crate::helper::coordinate::*;
+use crate::traits::AdjustmentCoordinate;
 use thin_vec::ThinVec;
 //use reader::driver::*;
 
@@ -20,6 +21,7 @@ pub struct Table {
     columns: ThinVec<TableColumn>,
     style_info: Option<Box<TableStyleInfo>>,
     totals_row_shown: BooleanValue,
+    header_row_count: UInt32Value,
     totals_row_count: UInt32Value,
 }
 impl Table {
@@ -38,6 +40,7 @@ impl Table {
             columns: ThinVec::<TableColumn>::default(),
             style_info: None,
             totals_row_shown: BooleanValue::default(),
+            header_row_count: UInt32Value::default(),
             totals_row_count: UInt32Value::default(),
         }
     }
@@ -142,6 +145,35 @@ impl Table {
         self.totals_row_shown.set_value_string(value);
     }
 
+    #[inline]
+    pub(crate) fn has_header_row_count(&self) -> bool {
+        self.header_row_count.has_value()
+    }
+
+    /// Number of header rows. 0 when the table is shown without its header row.
+    #[inline]
+    pub fn get_header_row_count(&self) -> u32 {
+        match self.header_row_count.has_value() {
+            true => *self.header_row_count.get_value(),
+            false => 1,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_header_row_count_str(&self) -> String {
+        self.header_row_count.get_value_string()
+    }
+
+    #[inline]
+    pub fn set_header_row_count(&mut self, value: u32) {
+        self.header_row_count.set_value(value);
+    }
+
+    #[inline]
+    pub(crate) fn set_header_row_count_str(&mut self, value: &str) {
+        self.header_row_count.set_value_string(value);
+    }
+
     #[inline]
     pub(crate) fn has_totals_row_count(&self) -> bool {
         self.totals_row_count.has_value()
@@ -167,6 +199,96 @@ impl Table {
         self.totals_row_count.set_value_string(value);
     }
 
+    /// Append a data row to the table, above the totals row.
+    /// The area is extended by one row and the cells of the new row are returned.
+    /// Calculated columns hold their formula, the other columns take the values in order.
+    /// The cells are not added to a worksheet; use `Worksheet::set_cell`,
+    /// or `Worksheet::append_table_row` which also moves the totals row down.
+    /// # Arguments
+    /// * `values` - Values of the columns in order. Values of calculated columns are ignored.
+    /// # Return value
+    /// * `Vec<Cell>` - Cells of the new row.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut table = umya_spreadsheet::structs::Table::new("Table1", ("A1", "B2"));
+    /// table.add_column(umya_spreadsheet::structs::TableColumn::new("Item"));
+    /// table.add_column(umya_spreadsheet::structs::TableColumn::new("Amount"));
+    /// for cell in table.append_row(["Apple", "120"]) {
+    ///     worksheet.set_cell(cell);
+    /// }
+    /// worksheet.add_table(table);
+    /// assert_eq!(worksheet.get_value("B3"), "120");
+    /// ```
+    pub fn append_row<I, S>(&mut self, values: I) -> Vec<Cell>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let start_col_num = *self.area.0.get_col_num();
+        let end_row_num = *self.area.1.get_row_num();
+        self.area.1.set_row_num(end_row_num + 1);
+        let row_num = end_row_num + 1 - self.totals_row_count.get_value();
+
+        let mut values = values.into_iter();
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let mut cell = Cell::default();
+                cell.set_coordinate((start_col_num + index as u32, row_num));
+                let value = values.next();
+                match (column.get_calculated_column_formula(), value) {
+                    (Some(v), _) => {
+                        cell.set_formula(v);
+                    }
+                    (None, Some(v)) => {
+                        cell.set_value(v);
+                    }
+                    (None, None) => {}
+                }
+                cell
+            })
+            .collect()
+    }
+
+    // Name for a column inserted into the table. ex) "Column3"
+    fn make_column_name(&self) -> String {
+        (1..)
+            .map(|n| format!("Column{}", n))
+            .find(|name| {
+                !self
+                    .columns
+                    .iter()
+                    .any(|v| v.get_name().eq_ignore_ascii_case(name))
+            })
+            .unwrap()
+    }
+
+    // Bounds of the area after removing `offset_num` lines from `root_num`.
+    fn adjustment_remove_bounds(
+        start_num: &u32,
+        end_num: &u32,
+        root_num: &u32,
+        offset_num: &u32,
+    ) -> (u32, u32) {
+        if root_num == &0 || offset_num == &0 {
+            return (*start_num, *end_num);
+        }
+        let last_num = root_num + offset_num - 1;
+        let adjust = |num: &u32, inner_num: u32| {
+            if num > &last_num {
+                num - offset_num
+            } else if num >= root_num {
+                inner_num
+            } else {
+                *num
+            }
+        };
+        (adjust(start_num, *root_num), adjust(end_num, root_num - 1))
+    }
+
     #[inline]
     fn cell_coord_to_coord<T>(cc: T) -> Coordinate
     where
@@ -180,6 +302,111 @@ impl Table {
     }
 }
 
+impl AdjustmentCoordinate for Table {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        // columns inserted inside the area become new table columns
+        let start_col_num = *self.area.0.get_col_num();
+        if offset_col_num != &0
+            && root_col_num > &start_col_num
+            && root_col_num <= self.area.1.get_col_num()
+        {
+            let index = (root_col_num - start_col_num) as usize;
+            for i in 0..*offset_col_num as usize {
+                let column = TableColumn::new(&self.make_column_name());
+                self.columns.insert(index + i, column);
+            }
+        }
+        self.area.0.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.area.1.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        let (start_col_num, end_col_num) = Self::adjustment_remove_bounds(
+            self.area.0.get_col_num(),
+            self.area.1.get_col_num(),
+            root_col_num,
+            offset_col_num,
+        );
+        if offset_col_num != &0 && root_col_num != &0 {
+            let area_col_num = *self.area.0.get_col_num();
+            let mut index = 0;
+            self.columns.retain(|_| {
+                let col_num = area_col_num + index;
+                index += 1;
+                !is_remove_coordinate(&col_num, root_col_num, offset_col_num)
+            });
+        }
+        // the first remaining data row moves up, the table is kept without its header row
+        if self.get_header_row_count() > 0
+            && is_remove_coordinate(self.area.0.get_row_num(), root_row_num, offset_row_num)
+        {
+            self.set_header_row_count(0);
+        }
+        let (start_row_num, end_row_num) = Self::adjustment_remove_bounds(
+            self.area.0.get_row_num(),
+            self.area.1.get_row_num(),
+            root_row_num,
+            offset_row_num,
+        );
+        self.area
+            .0
+            .set_col_num(start_col_num)
+            .set_row_num(start_row_num);
+        self.area
+            .1
+            .set_col_num(end_col_num)
+            .set_row_num(end_row_num);
+    }
+
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        let (start, end) = &self.area;
+        let is_remove_cols = |start_num: &u32, end_num: &u32| {
+            is_remove_coordinate(start_num, root_col_num, offset_col_num)
+                && is_remove_coordinate(end_num, root_col_num, offset_col_num)
+        };
+        let is_remove_rows = |start_num: &u32, end_num: &u32| {
+            is_remove_coordinate(start_num, root_row_num, offset_row_num)
+                && is_remove_coordinate(end_num, root_row_num, offset_row_num)
+        };
+        // the table is gone with all of its data rows or all of its columns
+        let data_start_row_num = start.get_row_num() + self.get_header_row_count();
+        let data_end_row_num = end
+            .get_row_num()
+            .saturating_sub(*self.totals_row_count.get_value());
+        (data_start_row_num <= data_end_row_num
+            && is_remove_rows(&data_start_row_num, &data_end_row_num))
+            || is_remove_cols(start.get_col_num(), end.get_col_num())
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct TableColumn {
     name: String,
@@ -263,9 +490,13 @@ impl TableColumn {
         self.calculated_column_formula.as_ref()
     }
 
+    /// Set the formula filled into each row of the column.
+    /// # Arguments
+    /// * `value` - Formula without the leading `=`. ex) `"Table1[[#This Row],[Qty]]*2"`
     #[inline]
-    pub(crate) fn set_calculated_column_formula(&mut self, value: String) {
-        self.calculated_column_formula = Some(value);
+    pub fn set_calculated_column_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.calculated_column_formula = Some(value.into());
+        self
     }
 }
 
@@ -0,0 +1,338 @@
+// threadedComment
+use super::BooleanValue;
+use super::Coordinate;
+use super::StringValue;
+use super::ThreadedCommentMention;
+use crate::helper::coordinate::*;
+use crate::helper::string_helper::make_guid;
+use crate::reader::driver::*;
+use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// A modern (threaded) comment.
+/// Replies are kept in the comment that starts the thread.
+#[derive(Clone, Default, Debug)]
+pub struct ThreadedComment {
+    coordinate: Coordinate,
+    date_time: StringValue,
+    person_id: StringValue,
+    id: StringValue,
+    parent_id: StringValue,
+    done: BooleanValue,
+    text: StringValue,
+    mentions: ThinVec<ThreadedCommentMention>,
+    replies: ThinVec<ThreadedComment>,
+}
+
+impl ThreadedComment {
+    /// Create a new comment with a generated id and the current time.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `person_id` - Id of the author. see `Person`.
+    /// * `text` - Comment text.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let person = umya_spreadsheet::Person::new("John Doe");
+    /// let mut comment = umya_spreadsheet::ThreadedComment::new("A1", person.get_id(), "Please check.");
+    /// comment.add_reply(umya_spreadsheet::ThreadedComment::new("A1", person.get_id(), "Done."));
+    /// book.add_person(person);
+    /// book.get_sheet_mut(&0).unwrap().add_threaded_comment(comment);
+    /// ```
+    pub fn new<T, S1, S2>(coordinate: T, person_id: S1, text: S2) -> Self
+    where
+        T: Into<CellCoordinates>,
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let mut obj = Self::default();
+        obj.get_coordinate_mut().set_col_num(col).set_row_num(row);
+        obj.set_person_id(person_id)
+            .set_text(text)
+            .set_id(make_guid())
+            .set_date_time(
+                chrono::Utc::now()
+                    .format("%Y-%m-%dT%H:%M:%S%.3f")
+                    .to_string(),
+            );
+        obj
+    }
+
+    #[inline]
+    pub fn get_coordinate(&self) -> &Coordinate {
+        &self.coordinate
+    }
+
+    #[inline]
+    pub fn get_coordinate_mut(&mut self) -> &mut Coordinate {
+        &mut self.coordinate
+    }
+
+    /// Creation time. ex) `2024-01-31T09:15:00.000`
+    #[inline]
+    pub fn get_date_time(&self) -> &str {
+        self.date_time.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_date_time<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.date_time.set_value(value);
+        self
+    }
+
+    /// Id of the author. see `Person`.
+    #[inline]
+    pub fn get_person_id(&self) -> &str {
+        self.person_id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_person_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.person_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_id(&self) -> &str {
+        self.id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn get_parent_id(&self) -> &str {
+        self.parent_id.get_value_str()
+    }
+
+    /// The thread is resolved.
+    #[inline]
+    pub fn get_done(&self) -> &bool {
+        self.done.get_value()
+    }
+
+    #[inline]
+    pub fn set_done(&mut self, value: bool) -> &mut Self {
+        self.done.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_text(&self) -> &str {
+        self.text.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_mentions(&self) -> &[ThreadedCommentMention] {
+        &self.mentions
+    }
+
+    #[inline]
+    pub fn get_mentions_mut(&mut self) -> &mut ThinVec<ThreadedCommentMention> {
+        &mut self.mentions
+    }
+
+    #[inline]
+    pub fn add_mention(&mut self, value: ThreadedCommentMention) -> &mut Self {
+        self.mentions.push(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_replies(&self) -> &[ThreadedComment] {
+        &self.replies
+    }
+
+    #[inline]
+    pub fn get_replies_mut(&mut self) -> &mut ThinVec<ThreadedComment> {
+        &mut self.replies
+    }
+
+    /// Add a reply to the thread.
+    /// The reply is moved to the coordinate of this comment.
+    #[inline]
+    pub fn add_reply(&mut self, mut value: ThreadedComment) -> &mut Self {
+        value.coordinate = self.coordinate.clone();
+        self.replies.push(value);
+        self
+    }
+
+    /// Text of the legacy note that older versions of Excel show instead of the thread.
+    pub(crate) fn get_legacy_text(&self) -> String {
+        let mut result = String::from("[Threaded comment]\n\nYour version of Excel allows you to read this threaded comment; however, any edits to it will get removed if the file is opened in a newer version of Excel. Learn more: https://go.microsoft.com/fwlink/?linkid=870924\n\nComment:\n    ");
+        result.push_str(self.get_text());
+        for reply in self.get_replies() {
+            result.push_str("\nReply:\n    ");
+            result.push_str(reply.get_text());
+        }
+        result
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) {
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.coordinate.set_coordinate(v);
+        }
+        set_string_from_xml!(self, e, date_time, "dT");
+        set_string_from_xml!(self, e, person_id, "personId");
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, parent_id, "parentId");
+        set_string_from_xml!(self, e, done, "done");
+
+        if empty_flag {
+            return;
+        }
+
+        let mut value = String::new();
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"mention" {
+                    let mut obj = ThreadedCommentMention::default();
+                    obj.set_attributes(reader, e);
+                    self.mentions.push(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"text" {
+                    value.clear();
+                }
+            },
+            Event::Text(e) => {
+                match e.unescape() {
+                    Ok(v) => value.push_str(&v),
+                    Err(_) => value.push_str(&String::from_utf8_lossy(&e)),
+                }
+            },
+            Event::End(ref e) => {
+                match e.name().into_inner() {
+                    b"text" => {
+                        self.text.set_value(std::mem::take(&mut value));
+                    }
+                    b"threadedComment" => return,
+                    _ => value.clear(),
+                }
+            },
+            Event::Eof => panic!("Error: Could not find {} end element", "threadedComment")
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, parent_id: Option<&str>) {
+        // threadedComment
+        let coordinate = self.coordinate.to_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("ref", &coordinate));
+        if self.date_time.has_value() {
+            attributes.push(("dT", self.date_time.get_value_str()));
+        }
+        attributes.push(("personId", self.person_id.get_value_str()));
+        attributes.push(("id", self.id.get_value_str()));
+        if let Some(v) = parent_id {
+            attributes.push(("parentId", v));
+        }
+        if parent_id.is_none() && self.done.has_value() {
+            attributes.push(("done", self.done.get_value_string()));
+        }
+        write_start_tag(writer, "threadedComment", attributes, false);
+
+        // text
+        write_start_tag(writer, "text", vec![], false);
+        write_text_node(writer, self.text.get_value_str());
+        write_end_tag(writer, "text");
+
+        // mentions
+        if !self.mentions.is_empty() {
+            write_start_tag(writer, "mentions", vec![], false);
+            for mention in &self.mentions {
+                mention.write_to(writer);
+            }
+            write_end_tag(writer, "mentions");
+        }
+
+        write_end_tag(writer, "threadedComment");
+    }
+}
+impl AdjustmentCoordinate for ThreadedComment {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.coordinate.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for reply in &mut self.replies {
+            reply.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.coordinate.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for reply in &mut self.replies {
+            reply.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.coordinate.is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
@@ -0,0 +1,97 @@
+// mention
+use super::StringValue;
+use super::UInt32Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// A mention of a person (`@name`) inside the text of a threaded comment.
+#[derive(Clone, Default, Debug)]
+pub struct ThreadedCommentMention {
+    mention_person_id: StringValue,
+    mention_id: StringValue,
+    start_index: UInt32Value,
+    length: UInt32Value,
+}
+
+impl ThreadedCommentMention {
+    /// Id of the mentioned `Person`.
+    #[inline]
+    pub fn get_mention_person_id(&self) -> &str {
+        self.mention_person_id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_mention_person_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.mention_person_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_mention_id(&self) -> &str {
+        self.mention_id.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_mention_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.mention_id.set_value(value);
+        self
+    }
+
+    /// Character position of the mention in the comment text.
+    #[inline]
+    pub fn get_start_index(&self) -> &u32 {
+        self.start_index.get_value()
+    }
+
+    #[inline]
+    pub fn set_start_index(&mut self, value: u32) -> &mut Self {
+        self.start_index.set_value(value);
+        self
+    }
+
+    /// Number of characters of the mention, including the leading `@`.
+    #[inline]
+    pub fn get_length(&self) -> &u32 {
+        self.length.get_value()
+    }
+
+    #[inline]
+    pub fn set_length(&mut self, value: u32) -> &mut Self {
+        self.length.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) {
+        set_string_from_xml!(self, e, mention_person_id, "mentionpersonId");
+        set_string_from_xml!(self, e, mention_id, "mentionId");
+        set_string_from_xml!(self, e, start_index, "startIndex");
+        set_string_from_xml!(self, e, length, "length");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // mention
+        let start_index = self.start_index.get_value_string();
+        let length = self.length.get_value_string();
+        write_start_tag(
+            writer,
+            "mention",
+            vec![
+                ("mentionpersonId", self.mention_person_id.get_value_str()),
+                ("mentionId", self.mention_id.get_value_str()),
+                ("startIndex", &start_index),
+                ("length", &length),
+            ],
+            true,
+        );
+    }
+}
@@ -1,10 +1,19 @@
+use crate::helper::auto_filter::*;
+use crate::helper::calculation::*;
 use crate::helper::const_str::*;
 use crate::helper::coordinate::*;
+use crate::helper::outline::*;
 use crate::helper::range::*;
+use crate::helper::sort::*;
 use crate::reader::xlsx::worksheet::*;
+use crate::structs::drawing::chartex;
+use crate::structs::drawing::spreadsheet::MarkerType;
 use crate::structs::drawing::spreadsheet::WorksheetDrawing;
 use crate::structs::office2010::excel::DataValidations as DataValidations2010;
+use crate::structs::office2010::excel::SparklineGroup;
+use crate::structs::office2010::excel::SparklineGroups;
 use crate::structs::raw::RawWorksheet;
+use crate::structs::Address;
 use crate::structs::AutoFilter;
 use crate::structs::Cell;
 use crate::structs::CellValue;
@@ -19,14 +28,18 @@ use crate::structs::ConditionalFormatting;
 use crate::structs::DataValidations;
 use crate::structs::DefinedName;
 use crate::structs::EnumValue;
+use crate::structs::Font;
 use crate::structs::HeaderFooter;
 use crate::structs::Hyperlink;
 use crate::structs::Image;
 use crate::structs::MediaObject;
 use crate::structs::MergeCells;
 use crate::structs::OleObjects;
+use crate::structs::OutlineProperties;
 use crate::structs::PageMargins;
 use crate::structs::PageSetup;
+use crate::structs::PatternValues;
+use crate::structs::PivotCacheValue;
 use crate::structs::PivotTable;
 use crate::structs::PrintOptions;
 use crate::structs::Range;
@@ -38,16 +51,24 @@ use crate::structs::SheetFormatProperties;
 use crate::structs::SheetProtection;
 use crate::structs::SheetStateValues;
 use crate::structs::SheetViews;
+use crate::structs::SortByValues;
+use crate::structs::SortCondition;
+use crate::structs::SortKey;
+use crate::structs::SortOption;
+use crate::structs::SortState;
+use crate::structs::Spreadsheet;
 use crate::structs::Style;
 use crate::structs::Stylesheet;
 use crate::structs::Table;
+use crate::structs::ThreadedComment;
+use crate::structs::XlsxError;
 use crate::traits;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWith2Sheet;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::traits::AdjustmentValue;
 use crate::StringValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use thin_vec::ThinVec;
 
 use super::EnumTrait;
@@ -72,7 +93,9 @@ pub struct Worksheet {
     conditional_formatting_collection: ThinVec<ConditionalFormatting>,
     merge_cells: MergeCells,
     auto_filter: Option<AutoFilter>,
+    sort_state: Option<SortState>,
     comments: ThinVec<Comment>,
+    threaded_comments: ThinVec<ThreadedComment>,
     active_cell: Box<str>,
     tab_color: Option<Color>,
     code_name: StringValue,
@@ -86,6 +109,8 @@ pub struct Worksheet {
     data_validations: Option<DataValidations>,
     data_validations_2010: Option<DataValidations2010>,
     sheet_format_properties: SheetFormatProperties,
+    outline_properties: OutlineProperties,
+    sparkline_groups: SparklineGroups,
     sheet_protection: Option<SheetProtection>,
 }
 
@@ -371,6 +396,101 @@ impl Worksheet {
         self.get_cell_mut(coordinate).get_cell_value_mut()
     }
 
+    /// Calculate the formula of a cell and store the result as its value.
+    /// Formula cells it depends on are calculated and updated as well.
+    /// References to other worksheets cannot be resolved from a single worksheet and result in `#REF!`;
+    /// use `Spreadsheet::calculate()` for those.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `&CellValue` - CellValue holding the result.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(2);
+    /// worksheet.get_cell_mut("A2").set_formula("A1*3");
+    /// let cell_value = worksheet.calculate_cell("A2");
+    /// assert_eq!(cell_value.get_value(), "6");
+    /// ```
+    pub fn calculate_cell<T>(&mut self, coordinate: T) -> &CellValue
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let mut calculator = Calculator::from_worksheet(self);
+        calculator.calculate_cell(0, col, row);
+        let results = calculator.into_results();
+        self.set_calculated_values(results.into_iter().map(|((_, c, r), v)| ((c, r), v)));
+        self.get_cell_value((col, row))
+    }
+
+    /// Calculate every formula of the worksheet and store the results as cell values.
+    /// References to other worksheets result in `#REF!`; use `Spreadsheet::calculate()` for those.
+    pub fn calculate(&mut self) -> &mut Self {
+        let mut calculator = Calculator::from_worksheet(self);
+        calculator.calculate_worksheet(0);
+        let results = calculator.into_results();
+        self.set_calculated_values(results.into_iter().map(|((_, c, r), v)| ((c, r), v)));
+        self
+    }
+
+    /// Gets the cells and ranges the formula of a cell refers to.
+    /// References to other worksheets cannot be resolved from a single worksheet and are omitted;
+    /// use `Spreadsheet::get_precedents()` for those.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// *`Vec<Address>` - Referenced ranges. Empty when the cell has no formula.
+    pub fn get_precedents<T>(&self, coordinate: T) -> Vec<Address>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let calculator = Calculator::from_worksheet(self);
+        DependencyGraph::new(&calculator)
+            .get_precedents(0, col, row)
+            .iter()
+            .map(|v| to_address(&calculator, v))
+            .collect()
+    }
+
+    /// Gets the formula cells of the worksheet referring to a cell directly.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// *`Vec<Address>` - Formula cells, sorted by row and column.
+    pub fn get_dependents<T>(&self, coordinate: T) -> Vec<Address>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let calculator = Calculator::from_worksheet(self);
+        DependencyGraph::new(&calculator)
+            .get_dependents(0, col, row)
+            .into_iter()
+            .map(|(_, col, row)| {
+                let mut address = Address::default();
+                address.set_sheet_name(self.get_name());
+                address
+                    .get_range_mut()
+                    .set_range(coordinate_from_index(&col, &row));
+                address
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_calculated_values(
+        &mut self,
+        values: impl Iterator<Item = ((u32, u32), CalculatedValue)>,
+    ) -> &mut Self {
+        for (coordinate, value) in values {
+            self.get_cell_value_mut(coordinate)
+                .set_raw_value_crate(value.to_raw_value());
+        }
+        self
+    }
+
     /// Gets the cell value by specifying an range.
     /// # Arguments
     /// * `range` - range. ex) "A1:C5"
@@ -528,6 +648,84 @@ impl Worksheet {
         !self.comments.is_empty()
     }
 
+    // ************************
+    // Threaded Comment
+    // ************************
+    /// Get Threaded Comments.
+    /// Replies are stored in the comment that starts the thread.
+    #[inline]
+    pub fn get_threaded_comments(&self) -> &[ThreadedComment] {
+        &self.threaded_comments
+    }
+
+    /// Get Threaded Comments in mutable.
+    #[inline]
+    pub fn get_threaded_comments_mut(&mut self) -> &mut ThinVec<ThreadedComment> {
+        &mut self.threaded_comments
+    }
+
+    /// Get Threaded Comment.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    pub fn get_threaded_comment<T>(&self, coordinate: T) -> Option<&ThreadedComment>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.threaded_comments.iter().find(|v| {
+            v.get_coordinate().get_col_num() == &col && v.get_coordinate().get_row_num() == &row
+        })
+    }
+
+    /// Get Threaded Comment in mutable.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    pub fn get_threaded_comment_mut<T>(&mut self, coordinate: T) -> Option<&mut ThreadedComment>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.threaded_comments.iter_mut().find(|v| {
+            v.get_coordinate().get_col_num() == &col && v.get_coordinate().get_row_num() == &row
+        })
+    }
+
+    /// Set Threaded Comments.
+    /// # Arguments
+    /// * `value` - ThreadedComment List (Vec)
+    #[inline]
+    pub fn set_threaded_comments(&mut self, value: impl Into<ThinVec<ThreadedComment>>) {
+        self.threaded_comments = value.into();
+    }
+
+    /// Add Threaded Comment.
+    /// Excel expects a legacy note in the same cell for older versions,
+    /// so one is added when the cell has no note yet.
+    /// Its text is rebuilt from the thread when the file is written.
+    /// # Arguments
+    /// * `value` - ThreadedComment
+    pub fn add_threaded_comment(&mut self, value: ThreadedComment) {
+        let col = *value.get_coordinate().get_col_num();
+        let row = *value.get_coordinate().get_row_num();
+        let has_note = self.comments.iter().any(|v| {
+            v.get_coordinate().get_col_num() == &col && v.get_coordinate().get_row_num() == &row
+        });
+        if !has_note {
+            let mut comment = Comment::default();
+            comment.new_comment((col, row));
+            comment.set_author(format!("tc={}", value.get_id()));
+            comment.set_text_string(value.get_legacy_text());
+            self.comments.push(comment);
+        }
+        self.threaded_comments.push(value);
+    }
+
+    /// Has Threaded Comments.
+    #[inline]
+    pub fn has_threaded_comments(&self) -> bool {
+        !self.threaded_comments.is_empty()
+    }
+
     // ************************
     // Conditional
     // ************************
@@ -657,12 +855,114 @@ impl Worksheet {
         self.auto_filter = Some(auto_filter);
     }
 
+    #[inline]
+    pub(crate) fn set_auto_filter_crate(&mut self, value: AutoFilter) {
+        self.auto_filter = Some(value);
+    }
+
     // Remove Auto Filter.
     #[inline]
     pub fn remove_auto_filter(&mut self) {
         self.auto_filter = None;
     }
 
+    /// Apply the criteria of the auto filter, hiding the rows that do not match them.
+    /// The first row of the range is the header row.
+    /// When the range only holds the header row, the data down to the last used row is filtered.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value("Fruit");
+    /// worksheet.get_cell_mut("A2").set_value("Apple");
+    /// worksheet.get_cell_mut("A3").set_value("Banana");
+    /// worksheet.set_auto_filter("A1:A3");
+    /// let mut filters = Filters::default();
+    /// filters.add_value("Apple");
+    /// worksheet
+    ///     .get_auto_filter_mut()
+    ///     .unwrap()
+    ///     .get_filter_column_mut(&0)
+    ///     .set_filters(filters);
+    /// worksheet.apply_auto_filter();
+    /// assert!(*worksheet.get_row_dimension(&3).unwrap().get_hidden());
+    /// ```
+    pub fn apply_auto_filter(&mut self) -> &mut Self {
+        let auto_filter = match &self.auto_filter {
+            Some(v) => v,
+            None => return self,
+        };
+        let (row_start, mut row_end, mut col_start, mut col_end) =
+            get_start_and_end_point(&auto_filter.get_range().get_range());
+        let (max_col, max_row) = self.get_highest_column_and_row();
+        if row_end <= row_start {
+            row_end = max_row;
+        }
+        if col_start == 0 {
+            col_start = 1;
+            col_end = max_col;
+        }
+        if row_start == 0 || row_start >= row_end {
+            return self;
+        }
+
+        let today = chrono::Local::now().date_naive();
+        let rows: Vec<u32> = (row_start + 1..=row_end).collect();
+        let mut visible = vec![true; rows.len()];
+        for filter_column in auto_filter.get_filter_column_list() {
+            let col = match col_start.checked_add(*filter_column.get_column_id()) {
+                Some(v) if v <= col_end => v,
+                _ => continue,
+            };
+            let cells: Vec<Option<&Cell>> = rows
+                .iter()
+                .map(|row| self.cell_collection.get((col, *row)))
+                .collect();
+            for (result, is_match) in visible
+                .iter_mut()
+                .zip(evaluate(filter_column, &cells, today))
+            {
+                *result &= is_match;
+            }
+        }
+
+        for (row, is_visible) in rows.iter().zip(visible) {
+            if !is_visible {
+                self.get_row_dimension_mut(row).set_hidden(true);
+            } else if self.get_row_dimension(row).is_some() {
+                self.get_row_dimension_mut(row).set_hidden(false);
+            }
+        }
+        self
+    }
+
+    // ************************
+    // Sort State
+    // ************************
+    /// Get the sort state saved by `sort_range_with_option`.
+    #[inline]
+    pub fn get_sort_state(&self) -> Option<&SortState> {
+        self.sort_state.as_ref()
+    }
+
+    #[inline]
+    pub fn get_sort_state_mut(&mut self) -> Option<&mut SortState> {
+        self.sort_state.as_mut()
+    }
+
+    #[inline]
+    pub fn set_sort_state(&mut self, value: SortState) -> &mut Self {
+        self.sort_state = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_sort_state(&mut self) -> &mut Self {
+        self.sort_state = None;
+        self
+    }
+
     // ************************
     // Column Dimensions
     // ************************
@@ -807,6 +1107,281 @@ impl Worksheet {
         &self.row_dimensions
     }
 
+    // ************************
+    // Outline
+    // ************************
+    /// Get the position of the summary rows and columns of the outline.
+    #[inline]
+    pub fn get_outline_properties(&self) -> &OutlineProperties {
+        &self.outline_properties
+    }
+
+    /// Get the position of the summary rows and columns of the outline in mutable.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_outline_properties_mut().set_summary_below(false);
+    /// ```
+    #[inline]
+    pub fn get_outline_properties_mut(&mut self) -> &mut OutlineProperties {
+        &mut self.outline_properties
+    }
+
+    #[inline]
+    pub fn set_outline_properties(&mut self, value: OutlineProperties) -> &mut Self {
+        self.outline_properties = value;
+        self
+    }
+
+    /// Group rows, one outline level deeper.
+    /// # Arguments
+    /// * `row_start` - First row of the group. ex) 2
+    /// * `row_end` - Last row of the group. ex) 5
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.group_rows(&2, &5);
+    /// worksheet.collapse_rows(&2, &5);
+    /// ```
+    pub fn group_rows(&mut self, row_start: &u32, row_end: &u32) -> &mut Self {
+        for row in *row_start..=*row_end {
+            let row_dimension = self.get_row_dimension_mut(&row);
+            let level = *row_dimension.get_outline_level();
+            row_dimension.set_outline_level(level + 1);
+        }
+        self.update_outline_level();
+        self
+    }
+
+    /// Ungroup rows, one outline level shallower.
+    /// # Arguments
+    /// * `row_start` - First row of the group. ex) 2
+    /// * `row_end` - Last row of the group. ex) 5
+    pub fn ungroup_rows(&mut self, row_start: &u32, row_end: &u32) -> &mut Self {
+        for row in *row_start..=*row_end {
+            if self.get_row_dimension(&row).is_none() {
+                continue;
+            }
+            let row_dimension = self.get_row_dimension_mut(&row);
+            let level = *row_dimension.get_outline_level();
+            row_dimension.set_outline_level(level.saturating_sub(1));
+        }
+        self.update_outline_level();
+        self
+    }
+
+    /// Collapse a group of rows, hiding them.
+    /// # Arguments
+    /// * `row_start` - First row of the group. ex) 2
+    /// * `row_end` - Last row of the group. ex) 5
+    pub fn collapse_rows(&mut self, row_start: &u32, row_end: &u32) -> &mut Self {
+        for row in *row_start..=*row_end {
+            self.get_row_dimension_mut(&row).set_hidden(true);
+        }
+        let summary_below = *self.outline_properties.get_summary_below();
+        if let Some(row) = get_summary_number(*row_start, *row_end, summary_below) {
+            self.get_row_dimension_mut(&row).set_collapsed(true);
+        }
+        self
+    }
+
+    /// Expand a group of rows, showing them.
+    /// Nested groups that are collapsed stay hidden.
+    /// # Arguments
+    /// * `row_start` - First row of the group. ex) 2
+    /// * `row_end` - Last row of the group. ex) 5
+    pub fn expand_rows(&mut self, row_start: &u32, row_end: &u32) -> &mut Self {
+        let summary_below = *self.outline_properties.get_summary_below();
+        let shown = get_expanded_numbers(
+            *row_start,
+            *row_end,
+            summary_below,
+            &|row| {
+                self.get_row_dimension(&row)
+                    .map_or(0, |v| *v.get_outline_level())
+            },
+            &|row| {
+                self.get_row_dimension(&row)
+                    .map_or(false, |v| *v.get_collapsed())
+            },
+        );
+        for row in shown {
+            if self.get_row_dimension(&row).is_some() {
+                self.get_row_dimension_mut(&row).set_hidden(false);
+            }
+        }
+        if let Some(row) = get_summary_number(*row_start, *row_end, summary_below) {
+            if self.get_row_dimension(&row).is_some() {
+                self.get_row_dimension_mut(&row).set_collapsed(false);
+            }
+        }
+        self
+    }
+
+    /// Group columns, one outline level deeper.
+    /// # Arguments
+    /// * `column_start` - First column of the group. ex) "B"
+    /// * `column_end` - Last column of the group. ex) "D"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.group_columns("B", "D");
+    /// worksheet.collapse_columns("B", "D");
+    /// ```
+    #[inline]
+    pub fn group_columns(&mut self, column_start: &str, column_end: &str) -> &mut Self {
+        self.group_columns_by_index(
+            &column_index_from_string(column_start),
+            &column_index_from_string(column_end),
+        )
+    }
+
+    /// Group columns, one outline level deeper.
+    /// # Arguments
+    /// * `column_start` - First column number of the group. ex) 2
+    /// * `column_end` - Last column number of the group. ex) 4
+    pub fn group_columns_by_index(&mut self, column_start: &u32, column_end: &u32) -> &mut Self {
+        for col in *column_start..=*column_end {
+            let column_dimension = self.get_column_dimension_by_number_mut(&col);
+            let level = *column_dimension.get_outline_level();
+            column_dimension.set_outline_level(level + 1);
+        }
+        self.update_outline_level();
+        self
+    }
+
+    /// Ungroup columns, one outline level shallower.
+    /// # Arguments
+    /// * `column_start` - First column of the group. ex) "B"
+    /// * `column_end` - Last column of the group. ex) "D"
+    #[inline]
+    pub fn ungroup_columns(&mut self, column_start: &str, column_end: &str) -> &mut Self {
+        self.ungroup_columns_by_index(
+            &column_index_from_string(column_start),
+            &column_index_from_string(column_end),
+        )
+    }
+
+    /// Ungroup columns, one outline level shallower.
+    /// # Arguments
+    /// * `column_start` - First column number of the group. ex) 2
+    /// * `column_end` - Last column number of the group. ex) 4
+    pub fn ungroup_columns_by_index(&mut self, column_start: &u32, column_end: &u32) -> &mut Self {
+        for col in *column_start..=*column_end {
+            if self.get_column_dimension_by_number(&col).is_none() {
+                continue;
+            }
+            let column_dimension = self.get_column_dimension_by_number_mut(&col);
+            let level = *column_dimension.get_outline_level();
+            column_dimension.set_outline_level(level.saturating_sub(1));
+        }
+        self.update_outline_level();
+        self
+    }
+
+    /// Collapse a group of columns, hiding them.
+    /// # Arguments
+    /// * `column_start` - First column of the group. ex) "B"
+    /// * `column_end` - Last column of the group. ex) "D"
+    #[inline]
+    pub fn collapse_columns(&mut self, column_start: &str, column_end: &str) -> &mut Self {
+        self.collapse_columns_by_index(
+            &column_index_from_string(column_start),
+            &column_index_from_string(column_end),
+        )
+    }
+
+    /// Collapse a group of columns, hiding them.
+    /// # Arguments
+    /// * `column_start` - First column number of the group. ex) 2
+    /// * `column_end` - Last column number of the group. ex) 4
+    pub fn collapse_columns_by_index(&mut self, column_start: &u32, column_end: &u32) -> &mut Self {
+        for col in *column_start..=*column_end {
+            self.get_column_dimension_by_number_mut(&col)
+                .set_hidden(true);
+        }
+        let summary_right = *self.outline_properties.get_summary_right();
+        if let Some(col) = get_summary_number(*column_start, *column_end, summary_right) {
+            self.get_column_dimension_by_number_mut(&col)
+                .set_collapsed(true);
+        }
+        self
+    }
+
+    /// Expand a group of columns, showing them.
+    /// Nested groups that are collapsed stay hidden.
+    /// # Arguments
+    /// * `column_start` - First column of the group. ex) "B"
+    /// * `column_end` - Last column of the group. ex) "D"
+    #[inline]
+    pub fn expand_columns(&mut self, column_start: &str, column_end: &str) -> &mut Self {
+        self.expand_columns_by_index(
+            &column_index_from_string(column_start),
+            &column_index_from_string(column_end),
+        )
+    }
+
+    /// Expand a group of columns, showing them.
+    /// Nested groups that are collapsed stay hidden.
+    /// # Arguments
+    /// * `column_start` - First column number of the group. ex) 2
+    /// * `column_end` - Last column number of the group. ex) 4
+    pub fn expand_columns_by_index(&mut self, column_start: &u32, column_end: &u32) -> &mut Self {
+        let summary_right = *self.outline_properties.get_summary_right();
+        let shown = get_expanded_numbers(
+            *column_start,
+            *column_end,
+            summary_right,
+            &|col| {
+                self.get_column_dimension_by_number(&col)
+                    .map_or(0, |v| *v.get_outline_level())
+            },
+            &|col| {
+                self.get_column_dimension_by_number(&col)
+                    .map_or(false, |v| *v.get_collapsed())
+            },
+        );
+        for col in shown {
+            if self.get_column_dimension_by_number(&col).is_some() {
+                self.get_column_dimension_by_number_mut(&col)
+                    .set_hidden(false);
+            }
+        }
+        if let Some(col) = get_summary_number(*column_start, *column_end, summary_right) {
+            if self.get_column_dimension_by_number(&col).is_some() {
+                self.get_column_dimension_by_number_mut(&col)
+                    .set_collapsed(false);
+            }
+        }
+        self
+    }
+
+    /// Keep the deepest outline levels of the sheet format in sync with the rows and columns.
+    fn update_outline_level(&mut self) {
+        let row_level = self
+            .get_row_dimensions()
+            .iter()
+            .map(|v| *v.get_outline_level())
+            .max()
+            .unwrap_or(0);
+        let column_level = self
+            .get_column_dimensions()
+            .iter()
+            .map(|v| *v.get_outline_level())
+            .max()
+            .unwrap_or(0);
+        let properties = &mut self.sheet_format_properties;
+        if row_level > 0 || *properties.get_outline_level_row() > 0 {
+            properties.set_outline_level_row(row_level);
+        }
+        if column_level > 0 || *properties.get_outline_level_column() > 0 {
+            properties.set_outline_level_column(column_level);
+        }
+    }
+
     // ************************
     // WorksheetDrawing
     // ************************
@@ -1428,16 +2003,190 @@ impl Worksheet {
         &mut self.tables
     }
 
+    /// Append a data row to the table.
+    /// The area is extended by one row and the totals row moves down.
+    /// Calculated columns are filled with their formula.
+    /// # Arguments
+    /// * `table_name` - Name of the table. ex) "Table1"
+    /// * `values` - Values of the columns in order. Values of calculated columns are ignored.
+    /// # Return value
+    /// * `Option<u32>` - Row number of the new row. `None` when the table is not found.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut table = umya_spreadsheet::structs::Table::new("Table1", ("A1", "B2"));
+    /// table.add_column(umya_spreadsheet::structs::TableColumn::new("Item"));
+    /// table.add_column(umya_spreadsheet::structs::TableColumn::new("Amount"));
+    /// worksheet.add_table(table);
+    /// let row_num = worksheet.append_table_row("Table1", ["Apple", "120"]);
+    /// assert_eq!(row_num, Some(3));
+    /// ```
+    pub fn append_table_row<I, S>(&mut self, table_name: &str, values: I) -> Option<u32>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let table = self.tables.iter_mut().find(|v| {
+            v.get_name().eq_ignore_ascii_case(table_name)
+                || v.get_display_name().eq_ignore_ascii_case(table_name)
+        })?;
+        let (start, end) = table.get_area();
+        let start_col_num = *start.get_col_num();
+        let end_col_num = *end.get_col_num();
+        let data_start_row_num = start.get_row_num() + table.get_header_row_count();
+        let end_row_num = *end.get_row_num();
+        let cells = table.append_row(values);
+        let row_num = end_row_num + 1 - table.get_totals_row_count();
+
+        // totals row
+        if row_num <= end_row_num {
+            let range = format!(
+                "{}:{}",
+                coordinate_from_index(&start_col_num, &row_num),
+                coordinate_from_index(&end_col_num, &end_row_num)
+            );
+            self.move_range(&range, &1, &0);
+        }
+
+        for mut cell in cells {
+            let col_num = *cell.get_coordinate().get_col_num();
+            if row_num > data_start_row_num {
+                if let Some(v) = self.get_cell((col_num, row_num - 1)) {
+                    cell.set_style(v.get_style().clone());
+                }
+            }
+            self.set_cell(cell);
+        }
+        Some(row_num)
+    }
+
     #[inline]
     pub fn has_pivot_table(&self) -> bool {
         !self.pivot_tables.is_empty()
     }
 
-    #[inline]
-    pub fn add_pivot_table(&mut self, pivot_table: PivotTable) {
+    /// Add a pivot table.
+    /// A `PivotTableBuilder` can be passed to generate the pivot table from a source range.
+    /// The cache and the layout are generated from the source data when the file is written.
+    /// # Arguments
+    /// * `pivot_table` - PivotTable or PivotTableBuilder.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value("Region");
+    /// worksheet.get_cell_mut("B1").set_value("Sales");
+    /// worksheet.get_cell_mut("A2").set_value("East");
+    /// worksheet.get_cell_mut("B2").set_value_number(10);
+    /// worksheet.add_pivot_table(
+    ///     PivotTableBuilder::new("Sheet1!A1:B2")
+    ///         .location("D3")
+    ///         .rows(["Region"])
+    ///         .values([("Sales", DataConsolidateFunctionValues::Sum)]),
+    /// );
+    /// ```
+    pub fn add_pivot_table<P: Into<PivotTable>>(&mut self, pivot_table: P) {
+        let mut pivot_table = pivot_table.into();
+        if pivot_table
+            .get_pivot_table_definition()
+            .get_name()
+            .is_empty()
+        {
+            let name = format!("PivotTable{}", self.pivot_tables.len() + 1);
+            pivot_table.get_pivot_table_definition_mut().set_name(name);
+        }
         self.pivot_tables.push(pivot_table);
     }
 
+    /// Whether a pivot table added from a builder is still to be generated.
+    #[inline]
+    pub(crate) fn has_pending_pivot_table(&self) -> bool {
+        self.pivot_tables.iter().any(|v| v.has_pending_layout())
+    }
+
+    /// Generates the pivot tables added from a builder.
+    pub(crate) fn make_pending_pivot_tables(
+        &mut self,
+        spreadsheet: &Spreadsheet,
+    ) -> Result<(), XlsxError> {
+        for index in 0..self.pivot_tables.len() {
+            if self.pivot_tables[index].has_pending_layout() {
+                self.refresh_pivot_table(index, spreadsheet)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Refreshes a pivot table and rewrites its output range.
+    /// Err when the new output range covers cells that are not part of the pivot table.
+    pub(crate) fn refresh_pivot_table(
+        &mut self,
+        index: usize,
+        spreadsheet: &Spreadsheet,
+    ) -> Result<(), XlsxError> {
+        let pivot_table = &mut self.pivot_tables[index];
+        let old_range = match pivot_table.has_pending_layout() {
+            true => None,
+            false => Some(
+                pivot_table
+                    .get_pivot_table_definition()
+                    .get_location()
+                    .get_reference()
+                    .to_string(),
+            ),
+        };
+        pivot_table.refresh(spreadsheet)?;
+        let new_range = pivot_table
+            .get_pivot_table_definition()
+            .get_location()
+            .get_reference()
+            .to_string();
+        let output = pivot_table.make_output();
+
+        let old_point = old_range.as_deref().map(get_start_and_end_point);
+        let new_point = get_start_and_end_point(&new_range);
+        let contains = |point: &(u32, u32, u32, u32), col: u32, row: u32| -> bool {
+            let (row_start, row_end, col_start, col_end) = *point;
+            (row_start..=row_end).contains(&row) && (col_start..=col_end).contains(&col)
+        };
+        let is_overlap = self.get_cell_collection().iter().any(|cell| {
+            let col = *cell.get_coordinate().get_col_num();
+            let row = *cell.get_coordinate().get_row_num();
+            contains(&new_point, col, row)
+                && !old_point.is_some_and(|v| contains(&v, col, row))
+                && !cell.get_value().is_empty()
+        });
+        if is_overlap {
+            return Err(XlsxError::PivotTable(format!(
+                "output range {} overlaps other cells",
+                new_range
+            )));
+        }
+
+        for point in old_point.iter().chain([&new_point]) {
+            for cell in self.get_cell_collection_mut() {
+                let coordinate = cell.get_coordinate();
+                let col = *coordinate.get_col_num();
+                let row = *coordinate.get_row_num();
+                if contains(point, col, row) {
+                    cell.set_blank();
+                }
+            }
+        }
+        for (col, row, value) in output {
+            let cell = self.get_cell_mut((col, row));
+            match value {
+                PivotCacheValue::Number(v) => cell.set_value_number(v),
+                PivotCacheValue::Boolean(v) => cell.set_value_bool(v),
+                PivotCacheValue::Error(v) => cell.set_error(v),
+                v => cell.set_value_string(v.to_string()),
+            };
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn get_pivot_tables(&self) -> &[PivotTable] {
         &self.pivot_tables
@@ -1492,6 +2241,33 @@ impl Worksheet {
         self
     }
 
+    /// Get the sparkline groups.
+    #[inline]
+    pub fn get_sparkline_groups(&self) -> &SparklineGroups {
+        &self.sparkline_groups
+    }
+
+    /// Get the sparkline groups in mutable.
+    #[inline]
+    pub fn get_sparkline_groups_mut(&mut self) -> &mut SparklineGroups {
+        &mut self.sparkline_groups
+    }
+
+    #[inline]
+    pub fn set_sparkline_groups(&mut self, value: SparklineGroups) -> &mut Self {
+        self.sparkline_groups = value;
+        self
+    }
+
+    /// Add a sparkline group.
+    /// # Arguments
+    /// * `value` - SparklineGroup
+    #[inline]
+    pub fn add_sparkline_group(&mut self, value: SparklineGroup) -> &mut Self {
+        self.sparkline_groups.add_sparkline_group(value);
+        self
+    }
+
     #[inline]
     pub fn get_sheet_format_properties(&self) -> &SheetFormatProperties {
         &self.sheet_format_properties
@@ -1629,6 +2405,58 @@ impl Worksheet {
         self.get_worksheet_drawing_mut().get_charts_mut(&col, &row)
     }
 
+    /// Outputs all chartex charts (waterfall, histogram, treemap, ...) contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&chartex::ChartSpace>` - Chart Object List.
+    #[inline]
+    pub fn get_chart_ex_collection(&self) -> Vec<&chartex::ChartSpace> {
+        self.get_worksheet_drawing().get_chart_ex_collection()
+    }
+
+    /// Outputs all chartex charts (waterfall, histogram, treemap, ...) contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&mut chartex::ChartSpace>` - Chart Object List.
+    #[inline]
+    pub fn get_chart_ex_collection_mut(&mut self) -> Vec<&mut chartex::ChartSpace> {
+        self.get_worksheet_drawing_mut()
+            .get_chart_ex_collection_mut()
+    }
+
+    /// Add a chartex chart.
+    /// # Arguments
+    /// * `from_marker` - Top left cell.
+    /// * `to_marker` - Bottom right cell.
+    /// * `chart_space` - Chart.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// use umya_spreadsheet::drawing::chartex::ChartSpace;
+    /// use umya_spreadsheet::drawing::spreadsheet::MarkerType;
+    /// let mut book = new_file();
+    /// let mut from_marker = MarkerType::default();
+    /// from_marker.set_coordinate("D2");
+    /// let mut to_marker = MarkerType::default();
+    /// to_marker.set_coordinate("K16");
+    /// let mut chart_space = ChartSpace::default();
+    /// chart_space.new_chart(
+    ///     ChartExType::Waterfall,
+    ///     Some("Sheet1!$A$1:$A$5"),
+    ///     vec!["Sheet1!$B$1:$B$5"],
+    /// );
+    /// book.get_sheet_mut(&0).unwrap().add_chart_ex(from_marker, to_marker, chart_space);
+    /// ```
+    #[inline]
+    pub fn add_chart_ex(
+        &mut self,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+        chart_space: chartex::ChartSpace,
+    ) -> &mut Self {
+        self.get_worksheet_drawing_mut()
+            .add_chart_ex(from_marker, to_marker, chart_space);
+        self
+    }
+
     /// Outputs all media contained in the worksheet.
     /// # Return value
     /// * `Vec<&MediaObject>` - Media Object List.
@@ -1783,6 +2611,258 @@ impl Worksheet {
         self.move_or_copy_range(&range, &row, &column, false)
     }
 
+    /// Sort the rows of a section of the sheet.
+    /// Whether the first row is a header is detected automatically.
+    /// # Arguments
+    /// * `range` - Specify like "A1:G8", "A:G"
+    /// * `keys` - Sort keys in order of priority. The columns have to be inside the range.
+    /// # Return value
+    /// * `Result<&mut Self, &'static str>` - Err when the range is not standard or a key is out of the range.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut key = umya_spreadsheet::SortKey::default();
+    /// key.set_column(2).set_descending(true);
+    /// worksheet.sort_range("A1:C10", &[key]).unwrap();
+    /// ```
+    #[inline]
+    pub fn sort_range(&mut self, range: &str, keys: &[SortKey]) -> Result<&mut Self, &'static str> {
+        self.sort_range_with_option(range, keys, &SortOption::default())
+    }
+
+    /// Sort the rows of a section of the sheet.
+    /// Values, styles, hyperlinks and comments move with their rows,
+    /// and relative references of formulas follow the new row.
+    /// # Arguments
+    /// * `range` - Specify like "A1:G8", "A:G"
+    /// * `keys` - Sort keys in order of priority. The columns have to be inside the range.
+    /// * `option` - Header row and sort state settings.
+    /// # Return value
+    /// * `Result<&mut Self, &'static str>` - Err when the range is not standard or a key is out of the range.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut key = umya_spreadsheet::SortKey::default();
+    /// key.set_column(1);
+    /// let mut option = umya_spreadsheet::SortOption::default();
+    /// option.set_has_header(true).set_save_sort_state(true);
+    /// worksheet.sort_range_with_option("A1:C10", &[key], &option).unwrap();
+    /// ```
+    pub fn sort_range_with_option(
+        &mut self,
+        range: &str,
+        keys: &[SortKey],
+        option: &SortOption,
+    ) -> Result<&mut Self, &'static str> {
+        let (mut row_start, mut row_end, mut col_start, mut col_end) =
+            get_start_and_end_point_checked(range)?;
+        let (max_col, max_row) = self.get_highest_column_and_row();
+        if row_start == 0 {
+            row_start = 1;
+            row_end = max_row;
+        }
+        if col_start == 0 {
+            col_start = 1;
+            col_end = max_col;
+        }
+        if !keys
+            .iter()
+            .all(|key| (col_start..=col_end).contains(key.get_column()))
+        {
+            return Err("Sort key is out of range.");
+        }
+        if keys.is_empty() || row_start >= row_end {
+            return Ok(self);
+        }
+
+        let has_header = option.get_has_header().copied().unwrap_or_else(|| {
+            let get_row = |row: u32| -> Vec<Option<&Cell>> {
+                (col_start..=col_end)
+                    .map(|col| self.cell_collection.get((col, row)))
+                    .collect()
+            };
+            is_header_row(&get_row(row_start), &get_row(row_start + 1))
+        });
+        let data_start = row_start + has_header as u32;
+
+        if option.save_sort_state {
+            self.sort_state = Some(Self::make_sort_state(
+                keys, col_start, col_end, data_start, row_end,
+            ));
+        }
+        if data_start >= row_end {
+            return Ok(self);
+        }
+
+        // order of the rows.
+        let rows: Vec<u32> = (data_start..=row_end).collect();
+        let values: Vec<Vec<SortValue>> = rows
+            .iter()
+            .map(|row| {
+                keys.iter()
+                    .map(|key| {
+                        SortValue::new(self.cell_collection.get((*key.get_column(), *row)), key)
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by(|a, b| {
+            keys.iter()
+                .enumerate()
+                .map(|(i, key)| compare(&values[*a][i], &values[*b][i], key))
+                .find(|v| v.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let row_map: HashMap<u32, u32> = order
+            .iter()
+            .enumerate()
+            .filter(|(i, index)| i != *index)
+            .map(|(i, index)| (rows[*index], rows[i]))
+            .collect();
+        if row_map.is_empty() {
+            return Ok(self);
+        }
+
+        // shared formulas are split, their cells no longer line up.
+        let shared_index_list: HashSet<u32> = self
+            .cell_collection
+            .iter_cells_by_range_sorted_by_row(data_start, row_end, col_start, col_end)
+            .filter_map(|cell| cell.get_formula_shared_index().copied())
+            .collect();
+        if !shared_index_list.is_empty() {
+            for cell in self.cell_collection.get_collection_mut() {
+                if let Some(v) = cell.get_formula_shared_index() {
+                    if shared_index_list.contains(v) {
+                        let formula = cell.get_formula().to_string();
+                        cell.set_formula(formula);
+                    }
+                }
+            }
+        }
+
+        // move the cells.
+        let mut move_cells: Vec<Cell> = Vec::new();
+        for row in row_map.keys() {
+            let col_list: Vec<u32> = self
+                .cell_collection
+                .iter_columns_with_cells_by_row(*row)
+                .filter(|col| (col_start..=col_end).contains(col))
+                .collect();
+            for col in col_list {
+                if let Some(cell) = self.cell_collection.get((col, *row)) {
+                    move_cells.push(cell.clone());
+                }
+                self.cell_collection.remove(&col, row);
+            }
+        }
+        for mut cell in move_cells {
+            let col = *cell.get_coordinate().get_col_num();
+            let row = row_map[cell.get_coordinate().get_row_num()];
+            cell.set_coordinate((col, row));
+            self.set_cell(cell);
+        }
+
+        // move the comments.
+        let columns = col_start..=col_end;
+        for comment in &mut self.comments {
+            let coordinate = comment.get_coordinate();
+            let (col, row) = (*coordinate.get_col_num(), *coordinate.get_row_num());
+            Self::move_sorted_row(comment, col, row, &columns, &row_map);
+        }
+        for threaded_comment in &mut self.threaded_comments {
+            let coordinate = threaded_comment.get_coordinate();
+            let (col, row) = (*coordinate.get_col_num(), *coordinate.get_row_num());
+            Self::move_sorted_row(threaded_comment, col, row, &columns, &row_map);
+        }
+
+        Ok(self)
+    }
+
+    fn move_sorted_row<T: AdjustmentCoordinate>(
+        item: &mut T,
+        col: u32,
+        row: u32,
+        columns: &std::ops::RangeInclusive<u32>,
+        row_map: &HashMap<u32, u32>,
+    ) {
+        if !columns.contains(&col) {
+            return;
+        }
+        match row_map.get(&row) {
+            Some(v) if *v > row => {
+                item.adjustment_insert_coordinate(&0, &0, &1, &(v - row));
+            }
+            Some(v) => {
+                let offset = row - v;
+                item.adjustment_remove_coordinate(&0, &0, &(offset + 1), &offset);
+            }
+            None => {}
+        }
+    }
+
+    fn make_sort_state(
+        keys: &[SortKey],
+        col_start: u32,
+        col_end: u32,
+        row_start: u32,
+        row_end: u32,
+    ) -> SortState {
+        let mut sort_state = SortState::default();
+        sort_state.set_reference(format!(
+            "{}:{}",
+            coordinate_from_index(&col_start, &row_start),
+            coordinate_from_index(&col_end, &row_end)
+        ));
+        if keys.iter().any(|key| *key.get_case_sensitive()) {
+            sort_state.set_case_sensitive(true);
+        }
+        for key in keys {
+            let mut sort_condition = SortCondition::default();
+            sort_condition.set_reference(format!(
+                "{}:{}",
+                coordinate_from_index(key.get_column(), &row_start),
+                coordinate_from_index(key.get_column(), &row_end)
+            ));
+            if *key.get_descending() {
+                sort_condition.set_descending(true);
+            }
+            match key.get_sort_by() {
+                SortByValues::CellColor => {
+                    let mut style = Style::default();
+                    if let Some(v) = key.get_color() {
+                        style
+                            .get_fill_mut()
+                            .get_pattern_fill_mut()
+                            .set_pattern_type(PatternValues::Solid)
+                            .set_background_color(v.clone());
+                    }
+                    sort_condition.set_sort_by(SortByValues::CellColor);
+                    sort_condition.set_style(style);
+                }
+                SortByValues::FontColor => {
+                    let mut style = Style::default();
+                    if let Some(v) = key.get_color() {
+                        let mut font = Font::default();
+                        font.set_color(v.clone());
+                        style.set_font(font);
+                    }
+                    sort_condition.set_sort_by(SortByValues::FontColor);
+                    sort_condition.set_style(style);
+                }
+                _ => {
+                    if !key.get_custom_list().is_empty() {
+                        sort_condition.set_custom_list(key.get_custom_list().join(","));
+                    }
+                }
+            }
+            sort_state.add_sort_condition(sort_condition);
+        }
+        sort_state
+    }
+
     // Moving or copying a section of the sheet
     #[inline]
     fn move_or_copy_range(
@@ -2001,6 +3081,24 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // threaded comments
+        for threaded_comment in &mut self.threaded_comments {
+            threaded_comment.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
+        // sparkline locations
+        self.sparkline_groups.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+
         // conditional styles
         for conditional_styles in &mut self.conditional_formatting_collection {
             conditional_styles.adjustment_insert_coordinate(
@@ -2030,6 +3128,45 @@ impl AdjustmentCoordinate for Worksheet {
                 offset_row_num,
             );
         };
+
+        // sort state
+        if let Some(v) = self.get_sort_state_mut() {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        };
+
+        // tables
+        for table in &mut self.tables {
+            table.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        if offset_col_num != &0 {
+            // header cells of inserted table columns
+            let mut headers: Vec<(u32, u32, String)> = Vec::new();
+            for table in self.tables.iter().filter(|v| v.get_header_row_count() > 0) {
+                let start = &table.get_area().0;
+                for (index, column) in table.get_columns().iter().enumerate() {
+                    headers.push((
+                        start.get_col_num() + index as u32,
+                        *start.get_row_num(),
+                        column.get_name().to_string(),
+                    ));
+                }
+            }
+            for (col_num, row_num, name) in headers {
+                if self.get_cell((col_num, row_num)).is_none() {
+                    self.get_cell_mut((col_num, row_num)).set_value_string(name);
+                }
+            }
+        }
     }
 
     fn adjustment_remove_coordinate(
@@ -2105,6 +3242,27 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // threaded comments
+        self.threaded_comments.retain(|x| {
+            !(x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num))
+        });
+        for threaded_comment in &mut self.threaded_comments {
+            threaded_comment.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
+        // sparkline locations
+        self.sparkline_groups.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+
         // conditional styles
         self.conditional_formatting_collection.retain(|x| {
             !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
@@ -2152,6 +3310,38 @@ impl AdjustmentCoordinate for Worksheet {
                 offset_row_num,
             );
         };
+
+        // sort state
+        let is_remove = match self.get_sort_state() {
+            Some(v) => {
+                v.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+            }
+            None => false,
+        };
+        if is_remove {
+            self.remove_sort_state();
+        }
+        if let Some(v) = self.get_sort_state_mut() {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        };
+
+        // tables
+        self.tables.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for table in &mut self.tables {
+            table.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
 impl AdjustmentCoordinateWithSheet for Worksheet {
@@ -2188,6 +3378,16 @@ impl AdjustmentCoordinateWithSheet for Worksheet {
                 root_row_num,
                 offset_row_num,
             );
+
+        // sparkline data
+        self.sparkline_groups
+            .adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
     }
 
     fn adjustment_remove_coordinate_with_sheet(
@@ -2223,5 +3423,15 @@ impl AdjustmentCoordinateWithSheet for Worksheet {
                 root_row_num,
                 offset_row_num,
             );
+
+        // sparkline data
+        self.sparkline_groups
+            .adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
     }
 }
@@ -2,6 +2,7 @@
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::structs::Address;
+use crate::structs::StringValue;
 use crate::writer::driver::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -11,6 +12,7 @@ use std::io::Cursor;
 #[derive(Clone, Default, Debug)]
 pub struct WorksheetSource {
     address: Address,
+    name: StringValue,
 }
 
 impl WorksheetSource {
@@ -27,6 +29,16 @@ impl WorksheetSource {
         self
     }
 
+    /// Name of a table or a defined name used as the source instead of a range.
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
@@ -40,16 +52,22 @@ impl WorksheetSource {
             address.set_sheet_name(v);
         }
         self.set_address(address);
+        set_string_from_xml!(self, e, name, "name");
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // worksheetSource
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let ref_str = self.address.get_range().get_range();
-        attributes.push(("ref", &ref_str));
+        if !self.name.has_value() {
+            attributes.push(("ref", &ref_str));
+        }
         if self.address.get_sheet_name() != "" {
             attributes.push(("sheet", self.address.get_sheet_name()));
         }
+        if self.name.has_value() {
+            attributes.push(("name", self.name.get_value_str()));
+        }
         write_start_tag(writer, "worksheetSource", attributes, true);
     }
 }
@@ -10,6 +10,7 @@ pub struct WriterManager<'a, W: io::Seek + io::Write> {
     arv: &'a mut zip::ZipWriter<W>,
     is_light: bool,
     table_no: i32,
+    pivot_caches: Vec<(String, String)>,
 }
 
 impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
@@ -20,6 +21,7 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
             arv,
             is_light: false,
             table_no: 0,
+            pivot_caches: Vec::new(),
         }
     }
 
@@ -45,6 +47,18 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         self.table_no
     }
 
+    /// Pivot caches written by this manager, as `(cacheId, target)`.
+    #[inline]
+    pub(crate) fn get_pivot_caches(&self) -> &[(String, String)] {
+        &self.pivot_caches
+    }
+
+    #[inline]
+    pub(crate) fn add_pivot_cache(&mut self, cache_id: String, target: String) -> &mut Self {
+        self.pivot_caches.push((cache_id, target));
+        self
+    }
+
     #[inline]
     pub(crate) fn add_writer(
         &mut self,
@@ -58,6 +72,15 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         Ok(())
     }
 
+    /// Register a file that was written to the archive without going through this manager.
+    #[inline]
+    pub(crate) fn add_file_name(&mut self, target: &str) -> &mut Self {
+        if !self.check_file_exist(target) {
+            self.files.push(target.to_string());
+        }
+        self
+    }
+
     #[inline]
     pub(crate) fn add_bin(&mut self, target: &str, data: &[u8]) -> Result<(), XlsxError> {
         if !self.check_file_exist(target) {
@@ -129,6 +152,21 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         }
     }
 
+    pub(crate) fn add_file_at_threaded_comment(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/threadedComment{}.xml", PKG_THREADED_COMMENTS, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_chart(
         &mut self,
         writer: Writer<Cursor<Vec<u8>>>,
@@ -144,6 +182,21 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         }
     }
 
+    pub(crate) fn add_file_at_chart_ex(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/chartEx{}.xml", PKG_CHARTS, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_ole_object(&mut self, writer: &[u8]) -> Result<i32, XlsxError> {
         let mut index = 0;
         loop {
@@ -191,6 +244,51 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         return Ok(table_no);
     }
 
+    pub(crate) fn add_file_at_pivot_cache_definition(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/pivotCacheDefinition{}.xml", PKG_PIVOT_CACHE, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    pub(crate) fn add_file_at_pivot_cache_records(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/pivotCacheRecords{}.xml", PKG_PIVOT_CACHE, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    pub(crate) fn add_file_at_pivot_table(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/pivotTable{}.xml", PKG_PIVOT_TABLES, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     #[inline]
     pub(crate) fn has_extension(&self, extension: &str) -> bool {
         let extension = format!(".{}", extension);
@@ -226,11 +324,36 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
                 content_type = TABLE_TYPE;
             }
 
+            // Override pivotTable
+            if file.starts_with("/xl/pivotTables/pivotTable") {
+                content_type = PIVOT_TABLE_TYPE;
+            }
+
+            // Override pivotCacheDefinition
+            if file.starts_with("/xl/pivotCache/pivotCacheDefinition") {
+                content_type = PIVOT_CACHE_DEF_TYPE;
+            }
+
+            // Override pivotCacheRecords
+            if file.starts_with("/xl/pivotCache/pivotCacheRecords") {
+                content_type = PIVOT_CACHE_RECORDS_TYPE;
+            }
+
             // Override comments
             if file.starts_with("/xl/comments") {
                 content_type = COMMENTS_TYPE;
             }
 
+            // Override threadedComments
+            if file.starts_with("/xl/threadedComments/threadedComment") {
+                content_type = THREADED_COMMENTS_TYPE;
+            }
+
+            // Override persons
+            if file.starts_with("/xl/persons/person") {
+                content_type = PERSON_TYPE;
+            }
+
             // Override theme
             if file.starts_with("/xl/theme/theme") {
                 content_type = THEME_TYPE;
@@ -256,6 +379,11 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
                 content_type = CHART_TYPE;
             }
 
+            // Override chartEx
+            if file.starts_with("/xl/charts/chartEx") {
+                content_type = CHART_EX_TYPE;
+            }
+
             // Override embeddings
             if file.starts_with("/xl/embeddings/oleObject") {
                 content_type = OLE_OBJECT_TYPE;
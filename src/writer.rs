@@ -2,4 +2,6 @@
 
 pub mod csv;
 pub(crate) mod driver;
+pub mod html;
+pub mod ods;
 pub mod xlsx;
@@ -3,7 +3,9 @@ use std::io;
 use std::path::Path;
 use std::string::FromUtf8Error;
 
+use crate::helper::range::get_start_and_end_point_checked;
 use crate::structs::CsvEncodeValues;
+use crate::structs::CsvQuoteValues;
 use crate::structs::CsvWriterOption;
 use crate::structs::Spreadsheet;
 use crate::structs::XlsxError;
@@ -13,6 +15,7 @@ use std::fmt::Write;
 /// # Arguments
 /// * `spreadsheet` - Spreadsheet structs object.
 /// * `writer` - writer to write to.
+/// * `option` - options.
 /// # Return value
 /// * `Result` - OK is void. Err is error message.
 pub fn write_writer<W: io::Seek + io::Write>(
@@ -21,17 +24,45 @@ pub fn write_writer<W: io::Seek + io::Write>(
     option: &CsvWriterOption,
 ) -> Result<(), XlsxError> {
     // get worksheet.
-    let worksheet = spreadsheet.get_active_sheet();
+    let worksheet = match (option.get_sheet_name(), option.get_sheet_index()) {
+        (Some(name), _) => spreadsheet.get_sheet_by_name(name),
+        (None, Some(index)) => spreadsheet.get_sheet(index),
+        (None, None) => Some(spreadsheet.get_active_sheet()),
+    }
+    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "worksheet not found"))?;
 
-    // get max column and row.
+    // get target range.
     let (max_column, max_row) = worksheet.get_highest_column_and_row();
+    let (mut row_start, mut row_end, mut col_start, mut col_end) = (1, max_row, 1, max_column);
+    if let Some(range) = option.get_range() {
+        let (r_start, r_end, c_start, c_end) = get_start_and_end_point_checked(range)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        if r_start != 0 {
+            (row_start, row_end) = (r_start, r_end);
+        }
+        if c_start != 0 {
+            (col_start, col_end) = (c_start, c_end);
+        }
+    }
+
+    let quote = match option.get_wrap_with_char() {
+        "" => "\"",
+        v => v,
+    };
+    let quote_policy = match option.get_wrap_with_char() {
+        "" => option.get_csv_quote_value().clone(),
+        _ => CsvQuoteValues::All,
+    };
+    let delimiter = option.get_delimiter().to_string();
 
     let mut data = String::new();
-    for row in 0u32..max_row {
+    for row in row_start..=row_end {
         let mut row_vec: Vec<String> = Vec::new();
-        for column in 0u32..max_column {
+        for column in col_start..=col_end {
             // get value.
-            let mut value = match worksheet.get_cell((column + 1, row + 1)) {
+            let cell = worksheet.get_cell((column, row));
+            let mut value = match cell {
+                Some(cell) if *option.get_use_formatted_value() => cell.get_formatted_value(),
                 Some(cell) => cell.get_cell_value().get_value().into(),
                 None => String::new(),
             };
@@ -39,32 +70,66 @@ pub fn write_writer<W: io::Seek + io::Write>(
             if *option.get_do_trim() {
                 value = value.trim().to_string();
             }
-            // wrap_with_char.
-            if option.get_wrap_with_char() != "" {
-                value = format! {"{}{}{}", option.get_wrap_with_char(), value, option.get_wrap_with_char()};
+            // quote.
+            // values holding the quote, the delimiter or a line break are quoted under every policy.
+            let is_quote = value.contains(quote)
+                || value.contains(&delimiter)
+                || value.contains(['\r', '\n'])
+                || match quote_policy {
+                    CsvQuoteValues::All => true,
+                    CsvQuoteValues::NonNumeric => {
+                        !value.is_empty() && cell.map_or(true, |v| v.get_data_type() != "n")
+                    }
+                    CsvQuoteValues::Minimal => false,
+                };
+            if is_quote {
+                value = format!(
+                    "{}{}{}",
+                    quote,
+                    value.replace(quote, &format!("{}{}", quote, quote)),
+                    quote
+                );
             }
             row_vec.push(value);
         }
-        write!(data, "{}", row_vec.join(",")).unwrap();
-        write!(data, "\r\n").unwrap();
+        write!(data, "{}", row_vec.join(&delimiter)).unwrap();
+        write!(data, "{}", option.get_line_terminator()).unwrap();
     }
 
     // encoding.
+    let is_bom = *option.get_include_bom();
     let data_bytes = match *option.get_csv_encode_value() {
-        CsvEncodeValues::ShiftJis => encoding_rs::SHIFT_JIS.encode(&data).0.into_owned(),
-        CsvEncodeValues::Koi8u => encoding_rs::KOI8_U.encode(&data).0.into_owned(),
-        CsvEncodeValues::Koi8r => encoding_rs::KOI8_R.encode(&data).0.into_owned(),
-        CsvEncodeValues::Iso88598i => encoding_rs::ISO_8859_8_I.encode(&data).0.into_owned(),
-        CsvEncodeValues::Gbk => encoding_rs::GBK.encode(&data).0.into_owned(),
-        CsvEncodeValues::EucKr => encoding_rs::EUC_KR.encode(&data).0.into_owned(),
-        CsvEncodeValues::Big5 => encoding_rs::BIG5.encode(&data).0.into_owned(),
-        CsvEncodeValues::Utf16Le => encoding_rs::UTF_16LE.encode(&data).0.into_owned(),
-        CsvEncodeValues::Utf16Be => encoding_rs::UTF_16BE.encode(&data).0.into_owned(),
-        _ => data.into_bytes(),
+        CsvEncodeValues::Utf8 => {
+            let mut bytes = Vec::new();
+            if is_bom {
+                bytes.extend_from_slice(b"\xEF\xBB\xBF");
+            }
+            bytes.extend_from_slice(data.as_bytes());
+            bytes
+        }
+        CsvEncodeValues::Utf16Le => {
+            let mut bytes = Vec::new();
+            if is_bom {
+                bytes.extend_from_slice(b"\xFF\xFE");
+            }
+            data.encode_utf16()
+                .for_each(|v| bytes.extend_from_slice(&v.to_le_bytes()));
+            bytes
+        }
+        CsvEncodeValues::Utf16Be => {
+            let mut bytes = Vec::new();
+            if is_bom {
+                bytes.extend_from_slice(b"\xFE\xFF");
+            }
+            data.encode_utf16()
+                .for_each(|v| bytes.extend_from_slice(&v.to_be_bytes()));
+            bytes
+        }
+        ref v => v.get_encoding().encode(&data).0.into_owned(),
     };
 
     // output.
-    writer.write_all(&data_bytes).unwrap();
+    writer.write_all(&data_bytes)?;
     Ok(())
 }
 
@@ -83,7 +148,10 @@ pub fn write_writer<W: io::Seek + io::Write>(
 /// let mut option = structs::CsvWriterOption::default();
 /// option.set_csv_encode_value(structs::CsvEncodeValues::ShiftJis);
 /// option.set_do_trim(true);
-/// option.set_wrap_with_char("\"");
+/// option.set_sheet_name("Sheet1");
+/// option.set_range("A1:C10");
+/// option.set_csv_quote_value(structs::CsvQuoteValues::NonNumeric);
+/// option.set_use_formatted_value(true);
 /// let _ = writer::csv::write(&book, path, Some(&option));
 /// ```
 pub fn write<P: AsRef<Path>>(
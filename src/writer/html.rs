@@ -0,0 +1,373 @@
+//! Writer rendering a worksheet as an HTML table.
+
+use quick_xml::escape::escape;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::helper::html::{color_to_css, font_to_css, richtext_to_html_with_theme, text_to_html};
+use crate::helper::range::{get_start_and_end_point, get_start_and_end_point_checked};
+use crate::structs::drawing::Theme;
+use crate::structs::Border;
+use crate::structs::Cell;
+use crate::structs::CellRawValue;
+use crate::structs::HorizontalAlignmentValues;
+use crate::structs::HtmlWriterOption;
+use crate::structs::PatternValues;
+use crate::structs::Spreadsheet;
+use crate::structs::Style;
+use crate::structs::VerticalAlignmentValues;
+use crate::structs::Worksheet;
+use crate::structs::XlsxError;
+
+/// write a worksheet as an HTML table to arbitrary writer.
+/// Merged cells, fonts, fills, borders, alignment, column widths, row heights,
+/// hidden rows and columns, rich text runs and number formats are rendered with inline CSS.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer to write to.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn write_writer<W: io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer: &mut W,
+    option: &HtmlWriterOption,
+) -> Result<(), XlsxError> {
+    // get worksheet.
+    let worksheet = match (option.get_sheet_name(), option.get_sheet_index()) {
+        (Some(name), _) => spreadsheet.get_sheet_by_name(name),
+        (None, Some(index)) => spreadsheet.get_sheet(index),
+        (None, None) => Some(spreadsheet.get_active_sheet()),
+    }
+    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "worksheet not found"))?;
+
+    // get target range.
+    let (max_column, max_row) = worksheet.get_highest_column_and_row();
+    let (mut row_start, mut row_end, mut col_start, mut col_end) =
+        (1, max_row.max(1), 1, max_column.max(1));
+    if let Some(range) = option.get_range() {
+        let (r_start, r_end, c_start, c_end) = get_start_and_end_point_checked(range)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        if r_start != 0 {
+            (row_start, row_end) = (r_start, r_end);
+        }
+        if c_start != 0 {
+            (col_start, col_end) = (c_start, c_end);
+        }
+    }
+
+    let include_hidden = *option.get_include_hidden();
+    let rows: Vec<u32> = (row_start..=row_end)
+        .filter(|row| {
+            include_hidden
+                || !worksheet
+                    .get_row_dimension(row)
+                    .map_or(false, |v| *v.get_hidden())
+        })
+        .collect();
+    let columns: Vec<u32> = (col_start..=col_end)
+        .filter(|col| {
+            include_hidden
+                || !worksheet
+                    .get_column_dimension_by_number(col)
+                    .map_or(false, |v| *v.get_hidden())
+        })
+        .collect();
+
+    // merged cells: the first visible cell of each range holds the spans.
+    let mut spans: HashMap<(u32, u32), (usize, usize, (u32, u32))> = HashMap::new();
+    let mut covered: HashSet<(u32, u32)> = HashSet::new();
+    for range in worksheet.get_merge_cells() {
+        let (r_start, r_end, c_start, c_end) = get_start_and_end_point(&range.get_range());
+        let merged_rows: Vec<u32> = rows
+            .iter()
+            .copied()
+            .filter(|v| (r_start..=r_end).contains(v))
+            .collect();
+        let merged_columns: Vec<u32> = columns
+            .iter()
+            .copied()
+            .filter(|v| (c_start..=c_end).contains(v))
+            .collect();
+        if merged_rows.is_empty() || merged_columns.is_empty() {
+            continue;
+        }
+        let anchor = (merged_columns[0], merged_rows[0]);
+        for row in &merged_rows {
+            for col in &merged_columns {
+                if (*col, *row) != anchor {
+                    covered.insert((*col, *row));
+                }
+            }
+        }
+        spans.insert(
+            anchor,
+            (merged_rows.len(), merged_columns.len(), (c_start, r_start)),
+        );
+    }
+
+    let theme = spreadsheet.get_theme();
+    let mut html = String::new();
+    if *option.get_full_document() {
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        writeln!(html, "<title>{}</title>", escape(worksheet.get_name())).unwrap();
+        html.push_str("</head>\n<body>\n");
+    }
+
+    // table and column widths.
+    let widths: Vec<u32> = columns
+        .iter()
+        .map(|col| column_width_to_pixel(get_column_width(worksheet, col)))
+        .collect();
+    let mut table_css = vec![
+        "border-collapse:collapse".to_string(),
+        "table-layout:fixed".to_string(),
+        format!("width:{}px", widths.iter().sum::<u32>()),
+    ];
+    if let Some(font) = spreadsheet.get_stylesheet()._get_fonts().get_font().first() {
+        if !font.get_name().is_empty() {
+            table_css.push(format!("font-family:'{}'", font.get_name()));
+        }
+        if *font.get_size() > 0f64 {
+            table_css.push(format!("font-size:{}pt", font.get_size()));
+        }
+    }
+    writeln!(html, "<table style=\"{}\">", escape(&table_css.join(";"))).unwrap();
+    html.push_str("<colgroup>\n");
+    for width in &widths {
+        writeln!(html, "<col style=\"width:{}px\">", width).unwrap();
+    }
+    html.push_str("</colgroup>\n");
+
+    // rows and cells.
+    for row in &rows {
+        writeln!(
+            html,
+            "<tr style=\"height:{}pt\">",
+            get_row_height(worksheet, row)
+        )
+        .unwrap();
+        for col in &columns {
+            if covered.contains(&(*col, *row)) {
+                continue;
+            }
+            let (source, rowspan, colspan) = match spans.get(&(*col, *row)) {
+                Some((rowspan, colspan, source)) => (*source, *rowspan, *colspan),
+                None => ((*col, *row), 1, 1),
+            };
+            html.push_str("<td");
+            if rowspan > 1 {
+                write!(html, " rowspan=\"{}\"", rowspan).unwrap();
+            }
+            if colspan > 1 {
+                write!(html, " colspan=\"{}\"", colspan).unwrap();
+            }
+            let cell = worksheet.get_cell(source);
+            let style = worksheet.get_style(source);
+            let css = make_cell_css(style, cell, theme);
+            if !css.is_empty() {
+                write!(html, " style=\"{}\"", escape(&css.join(";"))).unwrap();
+            }
+            html.push('>');
+            if let Some(v) = cell {
+                html.push_str(&make_cell_text(v, theme));
+            }
+            html.push_str("</td>\n");
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+
+    if *option.get_full_document() {
+        html.push_str("</body>\n</html>\n");
+    }
+
+    // output.
+    writer.write_all(html.as_bytes())?;
+    Ok(())
+}
+
+/// write a worksheet as an HTML file.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let mut book = new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz.html");
+/// let mut option = structs::HtmlWriterOption::default();
+/// option.set_sheet_name("Sheet1");
+/// option.set_range("A1:C10");
+/// let _ = writer::html::write(&book, path, Some(&option));
+/// ```
+pub fn write<P: AsRef<Path>>(
+    spreadsheet: &Spreadsheet,
+    path: P,
+    option: Option<&HtmlWriterOption>,
+) -> Result<(), XlsxError> {
+    let extension = path
+        .as_ref()
+        .extension()
+        .and_then(|v| v.to_str())
+        .unwrap_or_default();
+    let path_tmp = path
+        .as_ref()
+        .with_extension(format!("{}{}", extension, "tmp"));
+    let def_option = HtmlWriterOption::default();
+    let option = match option {
+        Some(v) => v,
+        None => &def_option,
+    };
+    if let Err(v) = write_writer(
+        spreadsheet,
+        &mut io::BufWriter::new(fs::File::create(path_tmp.as_ref() as &Path)?),
+        option,
+    ) {
+        fs::remove_file(path_tmp)?;
+        return Err(v);
+    }
+    fs::rename(path_tmp, path)?;
+    Ok(())
+}
+
+fn get_column_width(worksheet: &Worksheet, col: &u32) -> f64 {
+    match worksheet.get_column_dimension_by_number(col) {
+        Some(v) if *v.get_width() > 0f64 => *v.get_width(),
+        _ => match *worksheet
+            .get_sheet_format_properties()
+            .get_default_column_width()
+        {
+            v if v > 0f64 => v,
+            _ => 8.43,
+        },
+    }
+}
+
+fn get_row_height(worksheet: &Worksheet, row: &u32) -> f64 {
+    match worksheet.get_row_dimension(row) {
+        Some(v) if *v.get_custom_height() && *v.get_height() > 0f64 => *v.get_height(),
+        _ => match *worksheet
+            .get_sheet_format_properties()
+            .get_default_row_height()
+        {
+            v if v > 0f64 => v,
+            _ => 15f64,
+        },
+    }
+}
+
+/// Convert a column width (in characters of the default font) into pixels.
+#[inline]
+fn column_width_to_pixel(width: f64) -> u32 {
+    (width * 7f64).round() as u32
+}
+
+/// CSS declarations of a cell.
+fn make_cell_css(style: &Style, cell: Option<&Cell>, theme: &Theme) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+
+    if let Some(font) = style.get_font() {
+        result.extend(font_to_css(font, theme));
+    }
+
+    if let Some(color) = style
+        .get_fill()
+        .and_then(|v| v.get_pattern_fill())
+        .and_then(|v| match v.get_pattern_type() {
+            PatternValues::None => None,
+            _ => v.get_foreground_color(),
+        })
+    {
+        if let Some(v) = color_to_css(color, theme) {
+            result.push(format!("background-color:{}", v));
+        }
+    }
+
+    if let Some(borders) = style.get_borders() {
+        let border_list = [
+            ("border-top", borders.get_top()),
+            ("border-right", borders.get_right()),
+            ("border-bottom", borders.get_bottom()),
+            ("border-left", borders.get_left()),
+        ];
+        for (key, border) in border_list {
+            if let Some(v) = make_border(border, theme) {
+                result.push(format!("{}:{}", key, v));
+            }
+        }
+    }
+
+    let alignment = style.get_alignment();
+    let text_align = match alignment.map(|v| v.get_horizontal()) {
+        Some(HorizontalAlignmentValues::Left) | Some(HorizontalAlignmentValues::Fill) => {
+            Some("left")
+        }
+        Some(HorizontalAlignmentValues::Center)
+        | Some(HorizontalAlignmentValues::CenterContinuous) => Some("center"),
+        Some(HorizontalAlignmentValues::Right) => Some("right"),
+        Some(HorizontalAlignmentValues::Justify) | Some(HorizontalAlignmentValues::Distributed) => {
+            Some("justify")
+        }
+        // general alignment depends on the value.
+        _ => match cell.map(|v| v.get_raw_value()) {
+            Some(CellRawValue::Numeric(_)) => Some("right"),
+            Some(CellRawValue::Bool(_)) | Some(CellRawValue::Error(_)) => Some("center"),
+            _ => None,
+        },
+    };
+    if let Some(v) = text_align {
+        result.push(format!("text-align:{}", v));
+    }
+    let vertical_align = match alignment.map(|v| v.get_vertical()) {
+        Some(VerticalAlignmentValues::Top) => "top",
+        Some(VerticalAlignmentValues::Center)
+        | Some(VerticalAlignmentValues::Justify)
+        | Some(VerticalAlignmentValues::Distributed) => "middle",
+        _ => "bottom",
+    };
+    result.push(format!("vertical-align:{}", vertical_align));
+    match alignment.map_or(false, |v| *v.get_wrap_text()) {
+        true => result.push("white-space:pre-wrap".to_string()),
+        false => result.push("white-space:nowrap".to_string()),
+    }
+    result.push("overflow:hidden".to_string());
+
+    result
+}
+
+/// Convert a border into the value of `border-*`.
+fn make_border(border: &Border, theme: &Theme) -> Option<String> {
+    let (width, line) = match border.get_border_style() {
+        Border::BORDER_HAIR => ("1px", "dotted"),
+        Border::BORDER_THIN => ("1px", "solid"),
+        Border::BORDER_MEDIUM => ("2px", "solid"),
+        Border::BORDER_THICK => ("3px", "solid"),
+        Border::BORDER_DOUBLE => ("3px", "double"),
+        Border::BORDER_DOTTED => ("1px", "dotted"),
+        Border::BORDER_DASHED | Border::BORDER_DASHDOT | Border::BORDER_DASHDOTDOT => {
+            ("1px", "dashed")
+        }
+        Border::BORDER_MEDIUMDASHED
+        | Border::BORDER_MEDIUMDASHDOT
+        | Border::BORDER_MEDIUMDASHDOTDOT
+        | Border::BORDER_SLANTDASHDOT => ("2px", "dashed"),
+        _ => return None,
+    };
+    let color = color_to_css(border.get_color(), theme).unwrap_or_else(|| "#000000".to_string());
+    Some(format!("{} {} {}", width, line, color))
+}
+
+/// HTML of a cell value. Rich text runs become spans.
+fn make_cell_text(cell: &Cell, theme: &Theme) -> String {
+    match cell.get_raw_value() {
+        CellRawValue::RichText(rich_text) => richtext_to_html_with_theme(rich_text, theme),
+        _ => text_to_html(&cell.get_formatted_value()),
+    }
+}
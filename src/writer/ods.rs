@@ -0,0 +1,96 @@
+//! Writer for the OpenDocument Spreadsheet format (.ods).
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::helper::const_str::*;
+use crate::structs::Spreadsheet;
+use crate::structs::WriterManager;
+use crate::XlsxError;
+
+mod content;
+mod manifest;
+mod meta;
+mod number_format;
+mod styles;
+
+fn make_buffer(spreadsheet: &Spreadsheet) -> Result<std::vec::Vec<u8>, XlsxError> {
+    for worksheet in spreadsheet.get_sheet_collection_no_check() {
+        if !worksheet.is_deserialized() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "worksheets read lazily must be deserialized before writing ods",
+            )
+            .into());
+        }
+    }
+
+    let mut arv = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let mut writer_manager = WriterManager::new(&mut arv);
+
+    // the mimetype must be the first entry and must not be compressed.
+    writer_manager.set_is_light(true);
+    writer_manager.add_bin(ODS_MIMETYPE_FILE, ODS_MIMETYPE.as_bytes())?;
+    writer_manager.set_is_light(false);
+
+    // Add content
+    content::write(spreadsheet, &mut writer_manager)?;
+
+    // Add styles
+    styles::write(spreadsheet, &mut writer_manager)?;
+
+    // Add meta
+    meta::write(spreadsheet, &mut writer_manager)?;
+
+    // Add manifest
+    manifest::write(&mut writer_manager)?;
+
+    Ok(arv.finish()?.into_inner())
+}
+
+/// write spreadsheet file to arbitrary writer as OpenDocument Spreadsheet.
+/// Cell values, formulas, cell styles, merged cells, column widths, row heights,
+/// sheet visibility and defined names are written.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer to write to.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+#[inline]
+pub fn write_writer<W: io::Write>(
+    spreadsheet: &Spreadsheet,
+    mut writer: W,
+) -> Result<(), XlsxError> {
+    let buffer = make_buffer(spreadsheet)?;
+    writer.write_all(&buffer)?;
+    Ok(())
+}
+
+/// write spreadsheet file as OpenDocument Spreadsheet.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz.ods");
+/// let _ = umya_spreadsheet::writer::ods::write(&book, path);
+/// ```
+pub fn write<P: AsRef<Path>>(spreadsheet: &Spreadsheet, path: P) -> Result<(), XlsxError> {
+    let extension = path.as_ref().extension().unwrap().to_str().unwrap();
+    let path_tmp = path
+        .as_ref()
+        .with_extension(format!("{}{}", extension, "tmp"));
+    if let Err(v) = write_writer(
+        spreadsheet,
+        &mut io::BufWriter::new(fs::File::create(&path_tmp)?),
+    ) {
+        fs::remove_file(path_tmp)?;
+        return Err(v);
+    }
+    fs::rename(path_tmp, path)?;
+    Ok(())
+}
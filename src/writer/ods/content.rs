@@ -0,0 +1,643 @@
+use chrono::{Duration, NaiveDate};
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::io::Cursor;
+use std::io::Write;
+
+use super::number_format::{self, NumberStyleKind};
+use super::styles::*;
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::helper::ods::*;
+use crate::structs::drawing::Theme;
+use crate::structs::Cell;
+use crate::structs::CellRawValue;
+use crate::structs::DefinedName;
+use crate::structs::SheetStateValues;
+use crate::structs::Spreadsheet;
+use crate::structs::Style;
+use crate::structs::Worksheet;
+use crate::structs::WriterManager;
+use crate::writer::driver::*;
+
+const PRINT_AREA: &str = "_xlnm.Print_Area";
+
+/// Automatic styles collected while the tables are written.
+#[derive(Default)]
+struct StyleRegistry {
+    default_text: Vec<(&'static str, String)>,
+    number_styles: Vec<(String, String)>,
+    cell_styles: Vec<(String, CellStyleProperties)>,
+    cell_style_map: HashMap<String, String>,
+    column_styles: Vec<(String, String)>,
+    row_styles: Vec<(String, String)>,
+}
+impl StyleRegistry {
+    fn get_number_style_name(&mut self, format_code: &str) -> String {
+        if let Some((name, _)) = self.number_styles.iter().find(|(_, v)| v == format_code) {
+            return name.clone();
+        }
+        let name = format!("N{}", self.number_styles.len() + 1);
+        self.number_styles
+            .push((name.clone(), format_code.to_string()));
+        name
+    }
+
+    fn get_cell_style_name(&mut self, style: &Style, theme: &Theme) -> Option<String> {
+        let mut properties = make_cell_style_properties(style, &self.default_text, theme);
+        if let Some(v) = get_format_code(style) {
+            properties.data_style_name = Some(self.get_number_style_name(v));
+        }
+        if properties.is_empty() {
+            return None;
+        }
+        let hash_code = properties.get_hash_code();
+        if let Some(v) = self.cell_style_map.get(&hash_code) {
+            return Some(v.clone());
+        }
+        let name = format!("ce{}", self.cell_styles.len() + 1);
+        self.cell_style_map.insert(hash_code, name.clone());
+        self.cell_styles.push((name.clone(), properties));
+        Some(name)
+    }
+
+    fn get_column_style_name(&mut self, width: &f64) -> String {
+        let length = column_width_to_length(width);
+        if let Some((name, _)) = self.column_styles.iter().find(|(_, v)| *v == length) {
+            return name.clone();
+        }
+        let name = format!("co{}", self.column_styles.len() + 1);
+        self.column_styles.push((name.clone(), length));
+        name
+    }
+
+    fn get_row_style_name(&mut self, height: &f64, is_custom: bool) -> String {
+        let key = format!("{}pt{}", height, if is_custom { "" } else { "*" });
+        if let Some((name, _)) = self.row_styles.iter().find(|(_, v)| *v == key) {
+            return name.clone();
+        }
+        let name = format!("ro{}", self.row_styles.len() + 1);
+        self.row_styles.push((name.clone(), key));
+        name
+    }
+}
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let theme = spreadsheet.get_theme();
+    let mut registry = StyleRegistry {
+        default_text: make_text_properties(&get_default_font(spreadsheet), theme),
+        ..Default::default()
+    };
+
+    // tables are written first to collect the automatic styles.
+    let mut body = Writer::new(io::Cursor::new(Vec::new()));
+    for (index, worksheet) in spreadsheet
+        .get_sheet_collection_no_check()
+        .iter()
+        .enumerate()
+    {
+        write_table(worksheet, index as u32, theme, &mut registry, &mut body);
+    }
+    let global_names: Vec<&DefinedName> = spreadsheet
+        .get_defined_names()
+        .iter()
+        .chain(
+            spreadsheet
+                .get_sheet_collection_no_check()
+                .iter()
+                .flat_map(|v| v.get_defined_names())
+                .filter(|v| !v.has_local_sheet_id()),
+        )
+        .collect();
+    write_named_expressions(&global_names, &mut body);
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // office:document-content
+    write_start_tag(
+        &mut writer,
+        "office:document-content",
+        vec![
+            ("xmlns:office", ODS_OFFICE_NS),
+            ("xmlns:style", ODS_STYLE_NS),
+            ("xmlns:text", ODS_TEXT_NS),
+            ("xmlns:table", ODS_TABLE_NS),
+            ("xmlns:fo", ODS_FO_NS),
+            ("xmlns:number", ODS_NUMBER_NS),
+            ("xmlns:svg", ODS_SVG_NS),
+            ("xmlns:of", ODS_OF_NS),
+            ("office:version", ODS_VERSION),
+        ],
+        false,
+    );
+
+    // office:automatic-styles
+    write_start_tag(&mut writer, "office:automatic-styles", vec![], false);
+    for (name, width) in &registry.column_styles {
+        write_start_tag(
+            &mut writer,
+            "style:style",
+            vec![("style:name", name), ("style:family", "table-column")],
+            false,
+        );
+        write_start_tag(
+            &mut writer,
+            "style:table-column-properties",
+            vec![("fo:break-before", "auto"), ("style:column-width", width)],
+            true,
+        );
+        write_end_tag(&mut writer, "style:style");
+    }
+    for (name, key) in &registry.row_styles {
+        let (height, is_optimal) = match key.strip_suffix('*') {
+            Some(v) => (v, "true"),
+            None => (key.as_str(), "false"),
+        };
+        write_start_tag(
+            &mut writer,
+            "style:style",
+            vec![("style:name", name), ("style:family", "table-row")],
+            false,
+        );
+        write_start_tag(
+            &mut writer,
+            "style:table-row-properties",
+            vec![
+                ("style:row-height", height),
+                ("fo:break-before", "auto"),
+                ("style:use-optimal-row-height", is_optimal),
+            ],
+            true,
+        );
+        write_end_tag(&mut writer, "style:style");
+    }
+    for (name, display) in [("ta1", "true"), ("ta2", "false")] {
+        write_start_tag(
+            &mut writer,
+            "style:style",
+            vec![
+                ("style:name", name),
+                ("style:family", "table"),
+                ("style:master-page-name", "Default"),
+            ],
+            false,
+        );
+        write_start_tag(
+            &mut writer,
+            "style:table-properties",
+            vec![("table:display", display)],
+            true,
+        );
+        write_end_tag(&mut writer, "style:style");
+    }
+    for (name, format_code) in &registry.number_styles {
+        number_format::write(name, format_code, &mut writer);
+    }
+    for (name, properties) in &registry.cell_styles {
+        write_cell_style(&mut writer, name, properties);
+    }
+    write_end_tag(&mut writer, "office:automatic-styles");
+
+    // office:body
+    write_start_tag(&mut writer, "office:body", vec![], false);
+    write_start_tag(&mut writer, "office:spreadsheet", vec![], false);
+    writer
+        .get_mut()
+        .write_all(&body.into_inner().into_inner())?;
+    write_end_tag(&mut writer, "office:spreadsheet");
+    write_end_tag(&mut writer, "office:body");
+
+    write_end_tag(&mut writer, "office:document-content");
+    writer_mng.add_writer(ODS_CONTENT, writer)
+}
+
+fn write_table(
+    worksheet: &Worksheet,
+    sheet_index: u32,
+    theme: &Theme,
+    registry: &mut StyleRegistry,
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+) {
+    // the range to write.
+    let (mut max_column, mut max_row) = worksheet.get_highest_column_and_row();
+    let mut merge_map: HashMap<(u32, u32), (u32, u32)> = HashMap::new();
+    let mut covered: HashSet<(u32, u32)> = HashSet::new();
+    for range in worksheet.get_merge_cells() {
+        let (col_start, row_start, col_end, row_end) = match (
+            range.get_coordinate_start_col(),
+            range.get_coordinate_start_row(),
+            range.get_coordinate_end_col(),
+            range.get_coordinate_end_row(),
+        ) {
+            (Some(a), Some(b), Some(c), Some(d)) => {
+                (*a.get_num(), *b.get_num(), *c.get_num(), *d.get_num())
+            }
+            _ => continue,
+        };
+        merge_map.insert(
+            (col_start, row_start),
+            (col_end - col_start + 1, row_end - row_start + 1),
+        );
+        for col in col_start..=col_end {
+            for row in row_start..=row_end {
+                if (col, row) != (col_start, row_start) {
+                    covered.insert((col, row));
+                }
+            }
+        }
+        max_column = max_column.max(col_end);
+        max_row = max_row.max(row_end);
+    }
+    for column in worksheet.get_column_dimensions() {
+        max_column = max_column.max(*column.get_col_num());
+    }
+    for row in worksheet.get_row_dimensions() {
+        if *row.get_custom_height() || *row.get_hidden() {
+            max_row = max_row.max(*row.get_row_num());
+        }
+    }
+
+    let table_style = match worksheet.get_state() {
+        SheetStateValues::Visible => "ta1",
+        _ => "ta2",
+    };
+    let print_range = worksheet
+        .get_defined_names()
+        .iter()
+        .find(|v| v.get_name() == PRINT_AREA)
+        .and_then(|v| {
+            v.get_address()
+                .split(',')
+                .map(to_ods_range_address)
+                .collect::<Option<Vec<String>>>()
+        })
+        .map(|v| v.join(" "));
+    let mut attributes = vec![
+        ("table:name", worksheet.get_name()),
+        ("table:style-name", table_style),
+    ];
+    if let Some(v) = &print_range {
+        attributes.push(("table:print-ranges", v));
+    }
+    write_start_tag(writer, "table:table", attributes, false);
+
+    // table:table-column
+    let default_width = match *worksheet
+        .get_sheet_format_properties()
+        .get_default_column_width()
+    {
+        v if v > 0f64 => v,
+        _ => 8.43,
+    };
+    let mut column_list: Vec<(String, bool)> = Vec::new();
+    for col in 1..=max_column.max(1) {
+        let (width, is_hidden) = match worksheet.get_column_dimension_by_number(&col) {
+            Some(v) if *v.get_width() > 0f64 => (*v.get_width(), *v.get_hidden()),
+            Some(v) => (default_width, *v.get_hidden()),
+            None => (default_width, false),
+        };
+        column_list.push((registry.get_column_style_name(&width), is_hidden));
+    }
+    for (count, (style_name, is_hidden)) in group_runs(&column_list) {
+        let count = count.to_string();
+        let mut attributes = vec![("table:style-name", style_name.as_str())];
+        if count != "1" {
+            attributes.push(("table:number-columns-repeated", &count));
+        }
+        if *is_hidden {
+            attributes.push(("table:visibility", "collapse"));
+        }
+        attributes.push(("table:default-cell-style-name", "Default"));
+        write_start_tag(writer, "table:table-column", attributes, true);
+    }
+
+    // table:table-row
+    let mut row_cells: HashMap<u32, Vec<&Cell>> = HashMap::new();
+    for cell in worksheet.get_cell_collection() {
+        row_cells
+            .entry(*cell.get_coordinate().get_row_num())
+            .or_default()
+            .push(cell);
+    }
+    let default_height = match *worksheet
+        .get_sheet_format_properties()
+        .get_default_row_height()
+    {
+        v if v > 0f64 => v,
+        _ => 15f64,
+    };
+    let mut empty_rows: Vec<(String, bool)> = Vec::new();
+    for row in 1..=max_row.max(1) {
+        let (row_style, is_hidden) = match worksheet.get_row_dimension(&row) {
+            Some(v) if *v.get_custom_height() && *v.get_height() > 0f64 => (
+                registry.get_row_style_name(v.get_height(), true),
+                *v.get_hidden(),
+            ),
+            Some(v) => (
+                registry.get_row_style_name(&default_height, false),
+                *v.get_hidden(),
+            ),
+            None => (registry.get_row_style_name(&default_height, false), false),
+        };
+        let has_content = row_cells.contains_key(&row)
+            || (1..=max_column).any(|col| covered.contains(&(col, row)));
+        if !has_content {
+            empty_rows.push((row_style, is_hidden));
+            continue;
+        }
+        write_empty_rows(&mut empty_rows, max_column, writer);
+        write_row_start(&row_style, is_hidden, 1, writer);
+
+        let mut empty_cells: Vec<Option<String>> = Vec::new();
+        for col in 1..=max_column.max(1) {
+            if covered.contains(&(col, row)) {
+                write_empty_cells(&mut empty_cells, writer);
+                write_start_tag(writer, "table:covered-table-cell", vec![], true);
+                continue;
+            }
+            let cell = match worksheet.get_cell((col, row)) {
+                Some(v) => v,
+                None => {
+                    empty_cells.push(None);
+                    continue;
+                }
+            };
+            let style_name = registry.get_cell_style_name(cell.get_style(), theme);
+            let span = merge_map.get(&(col, row));
+            if span.is_none() && cell.get_value().is_empty() && !cell.is_formula() {
+                empty_cells.push(style_name);
+                continue;
+            }
+            write_empty_cells(&mut empty_cells, writer);
+            write_cell(cell, style_name.as_deref(), span, writer);
+        }
+        write_empty_cells(&mut empty_cells, writer);
+        write_end_tag(writer, "table:table-row");
+    }
+    write_empty_rows(&mut empty_rows, max_column, writer);
+
+    // local names
+    let local_names: Vec<&DefinedName> = worksheet
+        .get_defined_names()
+        .iter()
+        .filter(|v| v.has_local_sheet_id() && *v.get_local_sheet_id() == sheet_index)
+        .collect();
+    write_named_expressions(&local_names, writer);
+
+    write_end_tag(writer, "table:table");
+}
+
+fn write_row_start(
+    style_name: &str,
+    is_hidden: bool,
+    count: usize,
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+) {
+    let count = count.to_string();
+    let mut attributes = vec![("table:style-name", style_name)];
+    if count != "1" {
+        attributes.push(("table:number-rows-repeated", &count));
+    }
+    if is_hidden {
+        attributes.push(("table:visibility", "collapse"));
+    }
+    write_start_tag(writer, "table:table-row", attributes, false);
+}
+
+fn write_empty_rows(
+    empty_rows: &mut Vec<(String, bool)>,
+    max_column: u32,
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+) {
+    for (count, (style_name, is_hidden)) in group_runs(empty_rows) {
+        write_row_start(style_name, *is_hidden, count, writer);
+        let count = max_column.max(1).to_string();
+        let mut attributes = vec![];
+        if count != "1" {
+            attributes.push(("table:number-columns-repeated", count.as_str()));
+        }
+        write_start_tag(writer, "table:table-cell", attributes, true);
+        write_end_tag(writer, "table:table-row");
+    }
+    empty_rows.clear();
+}
+
+fn write_empty_cells(empty_cells: &mut Vec<Option<String>>, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    for (count, style_name) in group_runs(empty_cells) {
+        let count = count.to_string();
+        let mut attributes = vec![];
+        if let Some(v) = style_name {
+            attributes.push(("table:style-name", v.as_str()));
+        }
+        if count != "1" {
+            attributes.push(("table:number-columns-repeated", count.as_str()));
+        }
+        write_start_tag(writer, "table:table-cell", attributes, true);
+    }
+    empty_cells.clear();
+}
+
+fn write_cell(
+    cell: &Cell,
+    style_name: Option<&str>,
+    span: Option<&(u32, u32)>,
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+) {
+    let kind = get_format_code(cell.get_style()).map(number_format::get_kind);
+    let mut attributes: Vec<(&str, String)> = Vec::new();
+    if let Some(v) = style_name {
+        attributes.push(("table:style-name", v.to_string()));
+    }
+    if cell.is_formula() {
+        attributes.push(("table:formula", to_ods_formula(cell.get_formula())));
+    }
+    let mut text: Option<String> = None;
+    match cell.get_raw_value() {
+        CellRawValue::Numeric(v) => match kind {
+            Some(NumberStyleKind::Date) => {
+                attributes.push(("office:value-type", "date".to_string()));
+                attributes.push(("office:date-value", make_date_value(v)));
+            }
+            Some(NumberStyleKind::Time) => {
+                attributes.push(("office:value-type", "time".to_string()));
+                attributes.push(("office:time-value", make_time_value(v)));
+            }
+            Some(NumberStyleKind::Percentage) => {
+                attributes.push(("office:value-type", "percentage".to_string()));
+                attributes.push(("office:value", v.to_string()));
+            }
+            _ => {
+                attributes.push(("office:value-type", "float".to_string()));
+                attributes.push(("office:value", v.to_string()));
+            }
+        },
+        CellRawValue::Bool(v) => {
+            attributes.push(("office:value-type", "boolean".to_string()));
+            attributes.push(("office:boolean-value", v.to_string()));
+            text = Some(cell.get_value().to_string());
+        }
+        CellRawValue::Empty => {}
+        _ => {
+            attributes.push(("office:value-type", "string".to_string()));
+            text = Some(cell.get_value().to_string());
+        }
+    }
+    if text.is_none() && matches!(cell.get_raw_value(), CellRawValue::Numeric(_)) {
+        text = Some(cell.get_formatted_value());
+    }
+    if let Some((cols, rows)) = span {
+        attributes.push(("table:number-columns-spanned", cols.to_string()));
+        attributes.push(("table:number-rows-spanned", rows.to_string()));
+    }
+
+    let attributes: Vec<(&str, &str)> = attributes.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    match &text {
+        Some(v) => {
+            write_start_tag(writer, "table:table-cell", attributes, false);
+            write_paragraphs(v, writer);
+            write_end_tag(writer, "table:table-cell");
+        }
+        None => write_start_tag(writer, "table:table-cell", attributes, true),
+    }
+}
+
+/// Write text as `text:p` elements, keeping spaces, tabs and line breaks.
+fn write_paragraphs(value: &str, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    for line in value.split('\n') {
+        write_start_tag(writer, "text:p", vec![], false);
+        let chars: Vec<char> = line.trim_end_matches('\r').chars().collect();
+        let mut buffer = String::new();
+        let mut index = 0;
+        while index < chars.len() {
+            match chars[index] {
+                ' ' => {
+                    let mut end = index;
+                    while end < chars.len() && chars[end] == ' ' {
+                        end += 1;
+                    }
+                    let mut count = end - index;
+                    if index > 0 {
+                        buffer.push(' ');
+                        count -= 1;
+                    }
+                    if count > 0 {
+                        write_text_node(writer, std::mem::take(&mut buffer));
+                        let count = count.to_string();
+                        let attributes = match count.as_str() {
+                            "1" => vec![],
+                            v => vec![("text:c", v)],
+                        };
+                        write_start_tag(writer, "text:s", attributes, true);
+                    }
+                    index = end;
+                }
+                '\t' => {
+                    write_text_node(writer, std::mem::take(&mut buffer));
+                    write_start_tag(writer, "text:tab", vec![], true);
+                    index += 1;
+                }
+                c => {
+                    buffer.push(c);
+                    index += 1;
+                }
+            }
+        }
+        if !buffer.is_empty() {
+            write_text_node(writer, buffer);
+        }
+        write_end_tag(writer, "text:p");
+    }
+}
+
+fn write_named_expressions(names: &[&DefinedName], writer: &mut Writer<Cursor<Vec<u8>>>) {
+    let names: Vec<&&DefinedName> = names
+        .iter()
+        .filter(|v| !v.get_name().starts_with("_xlnm."))
+        .collect();
+    if names.is_empty() {
+        return;
+    }
+    write_start_tag(writer, "table:named-expressions", vec![], false);
+    for defined_name in names {
+        let address = defined_name.get_address();
+        match to_ods_range_address(&address) {
+            Some(range) => {
+                let base = range.split(':').next().unwrap_or(&range).to_string();
+                write_start_tag(
+                    writer,
+                    "table:named-range",
+                    vec![
+                        ("table:name", defined_name.get_name()),
+                        ("table:cell-range-address", &range),
+                        ("table:base-cell-address", &base),
+                    ],
+                    true,
+                );
+            }
+            None => {
+                let expression = to_ods_formula(&address);
+                write_start_tag(
+                    writer,
+                    "table:named-expression",
+                    vec![
+                        ("table:name", defined_name.get_name()),
+                        ("table:expression", &expression),
+                    ],
+                    true,
+                );
+            }
+        }
+    }
+    write_end_tag(writer, "table:named-expressions");
+}
+
+/// The format code of a style, if it is not "General".
+fn get_format_code(style: &Style) -> Option<&str> {
+    style
+        .get_number_format()
+        .map(|v| v.get_format_code())
+        .filter(|v| !v.is_empty() && *v != "General")
+}
+
+/// Group consecutive equal items into (count, item).
+fn group_runs<T: PartialEq>(list: &[T]) -> Vec<(usize, &T)> {
+    let mut result: Vec<(usize, &T)> = Vec::new();
+    for item in list {
+        match result.last_mut() {
+            Some((count, last)) if *last == item => *count += 1,
+            _ => result.push((1, item)),
+        }
+    }
+    result
+}
+
+/// Convert a serial date into `office:date-value`.
+fn make_date_value(value: &f64) -> String {
+    let base = NaiveDate::from_ymd_opt(1899, 12, 30)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let date_time = base + Duration::milliseconds((value * 86_400_000f64).round() as i64);
+    match value.fract() == 0f64 {
+        true => date_time.format("%Y-%m-%d").to_string(),
+        false => date_time.format("%Y-%m-%dT%H:%M:%S%.f").to_string(),
+    }
+}
+
+/// Convert a serial time into `office:time-value`.
+fn make_time_value(value: &f64) -> String {
+    let milliseconds = (value.abs() * 86_400_000f64).round() as u64;
+    let hours = milliseconds / 3_600_000;
+    let minutes = milliseconds % 3_600_000 / 60_000;
+    let seconds = (milliseconds % 60_000) as f64 / 1000f64;
+    let sign = if *value < 0f64 { "-" } else { "" };
+    format!("{}PT{:02}H{:02}M{:02}S", sign, hours, minutes, seconds)
+}
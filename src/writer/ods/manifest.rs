@@ -0,0 +1,48 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::structs::WriterManager;
+use crate::writer::driver::*;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // manifest:manifest
+    write_start_tag(
+        &mut writer,
+        "manifest:manifest",
+        vec![
+            ("xmlns:manifest", ODS_MANIFEST_NS),
+            ("manifest:version", ODS_VERSION),
+        ],
+        false,
+    );
+
+    let entry_list = [
+        ("/", ODS_MIMETYPE),
+        (ODS_CONTENT, "text/xml"),
+        (ODS_STYLES, "text/xml"),
+        (ODS_META, "text/xml"),
+    ];
+    for (path, media_type) in entry_list {
+        let mut attributes = vec![
+            ("manifest:full-path", path),
+            ("manifest:media-type", media_type),
+        ];
+        if path == "/" {
+            attributes.push(("manifest:version", ODS_VERSION));
+        }
+        write_start_tag(&mut writer, "manifest:file-entry", attributes, true);
+    }
+
+    write_end_tag(&mut writer, "manifest:manifest");
+    writer_mng.add_writer(ODS_MANIFEST, writer)
+}
@@ -0,0 +1,55 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::structs::Spreadsheet;
+use crate::structs::WriterManager;
+use crate::writer::driver::*;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // office:document-meta
+    write_start_tag(
+        &mut writer,
+        "office:document-meta",
+        vec![
+            ("xmlns:office", ODS_OFFICE_NS),
+            ("xmlns:meta", ODS_META_NS),
+            ("xmlns:dc", DCORE_NS),
+            ("office:version", ODS_VERSION),
+        ],
+        false,
+    );
+    write_start_tag(&mut writer, "office:meta", vec![], false);
+
+    let properties = spreadsheet.get_properties();
+    let element_list = [
+        ("meta:generator", "umya-spreadsheet"),
+        ("dc:title", properties.get_title()),
+        ("meta:initial-creator", properties.get_creator()),
+        ("meta:creation-date", properties.get_created()),
+        ("dc:creator", properties.get_last_modified_by()),
+        ("dc:date", properties.get_modified()),
+    ];
+    for (tag, value) in element_list {
+        if value.is_empty() {
+            continue;
+        }
+        write_start_tag(&mut writer, tag, vec![], false);
+        write_text_node(&mut writer, value);
+        write_end_tag(&mut writer, tag);
+    }
+
+    write_end_tag(&mut writer, "office:meta");
+    write_end_tag(&mut writer, "office:document-meta");
+    writer_mng.add_writer(ODS_META, writer)
+}
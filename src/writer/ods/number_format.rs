@@ -0,0 +1,542 @@
+//! Conversion of format codes into ODS number styles.
+//! Only the first section of a format code is converted. Colors and conditions are dropped.
+
+use quick_xml::Writer;
+use std::io::Cursor;
+
+use crate::writer::driver::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum NumberStyleKind {
+    Number,
+    Percentage,
+    Scientific,
+    Fraction,
+    Date,
+    Time,
+    Text,
+}
+
+#[derive(Debug, PartialEq)]
+enum DateToken {
+    Literal(String),
+    Year(usize),
+    Month(usize),
+    Day(usize),
+    Hour(usize, bool),
+    Minute(usize, bool),
+    Second(usize, bool, usize),
+    AmPm,
+}
+
+/// Classify a format code.
+pub(crate) fn get_kind(format_code: &str) -> NumberStyleKind {
+    let section = get_first_section(format_code);
+    if contains_unquoted(&section, |c| c == '@') {
+        return NumberStyleKind::Text;
+    }
+    let tokens = parse_date_tokens(&section);
+    let has_date = tokens.iter().any(|v| {
+        matches!(
+            v,
+            DateToken::Year(_) | DateToken::Month(_) | DateToken::Day(_)
+        )
+    });
+    let has_time = tokens.iter().any(|v| {
+        matches!(
+            v,
+            DateToken::Hour(..) | DateToken::Minute(..) | DateToken::Second(..)
+        )
+    });
+    if has_date {
+        return NumberStyleKind::Date;
+    }
+    if has_time {
+        return NumberStyleKind::Time;
+    }
+    let plain = remove_literals(&section);
+    if plain.contains('%') {
+        NumberStyleKind::Percentage
+    } else if plain.contains("E+") || plain.contains("E-") || plain.contains("e+") {
+        NumberStyleKind::Scientific
+    } else if plain.contains('/') {
+        NumberStyleKind::Fraction
+    } else {
+        NumberStyleKind::Number
+    }
+}
+
+/// Write a number style element converted from a format code.
+pub(crate) fn write(name: &str, format_code: &str, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    let section = get_first_section(format_code);
+    match get_kind(format_code) {
+        NumberStyleKind::Date | NumberStyleKind::Time => write_date(name, &section, writer),
+        NumberStyleKind::Text => write_text(name, &section, writer),
+        kind => write_number(name, &section, kind, writer),
+    }
+}
+
+fn write_date(name: &str, section: &str, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    let tokens = parse_date_tokens(section);
+    let is_date = tokens.iter().any(|v| {
+        matches!(
+            v,
+            DateToken::Year(_) | DateToken::Month(_) | DateToken::Day(_)
+        )
+    });
+    let is_elapsed = tokens.iter().any(|v| {
+        matches!(
+            v,
+            DateToken::Hour(_, true) | DateToken::Minute(_, true) | DateToken::Second(_, true, _)
+        )
+    });
+    let tag = match is_date {
+        true => "number:date-style",
+        false => "number:time-style",
+    };
+    let mut attributes = vec![("style:name", name)];
+    if !is_date && is_elapsed {
+        attributes.push(("number:truncate-on-overflow", "false"));
+    }
+    write_start_tag(writer, tag, attributes, false);
+    for token in &tokens {
+        match token {
+            DateToken::Literal(v) => write_literal(v, writer),
+            DateToken::Year(len) => write_part("number:year", *len >= 4, vec![], writer),
+            DateToken::Month(len) => {
+                let attributes = match *len >= 3 {
+                    true => vec![("number:textual", "true")],
+                    false => vec![],
+                };
+                write_part("number:month", *len == 2 || *len == 4, attributes, writer)
+            }
+            DateToken::Day(len) if *len >= 3 => {
+                write_part("number:day-of-week", *len >= 4, vec![], writer)
+            }
+            DateToken::Day(len) => write_part("number:day", *len >= 2, vec![], writer),
+            DateToken::Hour(len, _) => write_part("number:hours", *len >= 2, vec![], writer),
+            DateToken::Minute(len, _) => write_part("number:minutes", *len >= 2, vec![], writer),
+            DateToken::Second(len, _, decimal) => {
+                let decimal = decimal.to_string();
+                let attributes = match *decimal != *"0" {
+                    true => vec![("number:decimal-places", decimal.as_str())],
+                    false => vec![],
+                };
+                write_part("number:seconds", *len >= 2, attributes, writer)
+            }
+            DateToken::AmPm => write_start_tag(writer, "number:am-pm", vec![], true),
+        }
+    }
+    write_end_tag(writer, tag);
+}
+
+fn write_part(
+    tag: &str,
+    is_long: bool,
+    mut attributes: Vec<(&str, &str)>,
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+) {
+    if is_long {
+        attributes.push(("number:style", "long"));
+    }
+    write_start_tag(writer, tag, attributes, true);
+}
+
+fn write_text(name: &str, section: &str, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    write_start_tag(
+        writer,
+        "number:text-style",
+        vec![("style:name", name)],
+        false,
+    );
+    let mut literal = String::new();
+    for part in split_literals(section) {
+        match part {
+            (true, v) => literal.push_str(&v),
+            (false, v) => {
+                for c in v.chars() {
+                    if c == '@' {
+                        write_literal(&std::mem::take(&mut literal), writer);
+                        write_start_tag(writer, "number:text-content", vec![], true);
+                    } else {
+                        literal.push(c);
+                    }
+                }
+            }
+        }
+    }
+    write_literal(&literal, writer);
+    write_end_tag(writer, "number:text-style");
+}
+
+fn write_number(
+    name: &str,
+    section: &str,
+    kind: NumberStyleKind,
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+) {
+    let tag = match kind {
+        NumberStyleKind::Percentage => "number:percentage-style",
+        _ => "number:number-style",
+    };
+    write_start_tag(writer, tag, vec![("style:name", name)], false);
+
+    // split into prefix, number and suffix.
+    let mut prefix = String::new();
+    let mut body = String::new();
+    let mut suffix = String::new();
+    for (is_literal, value) in split_literals(section) {
+        if is_literal {
+            let is_fraction_space = kind == NumberStyleKind::Fraction
+                && value.trim().is_empty()
+                && !body.is_empty()
+                && suffix.is_empty();
+            match (is_fraction_space, body.is_empty()) {
+                (true, _) => body.push_str(&value),
+                (false, true) => prefix.push_str(&value),
+                (false, false) => suffix.push_str(&value),
+            }
+            continue;
+        }
+        for c in value.chars() {
+            let is_body_char = matches!(c, '0' | '#' | '?' | '.' | ',' | '/' | 'E' | 'e')
+                || (matches!(c, '+' | '-') && body.ends_with(['E', 'e']))
+                || (c.is_ascii_digit() && !body.is_empty());
+            if is_body_char && suffix.is_empty() {
+                body.push(c);
+            } else if body.is_empty() {
+                prefix.push(c);
+            } else {
+                suffix.push(c);
+            }
+        }
+    }
+
+    write_literal(&prefix, writer);
+    let (mantissa, exponent) = body.split_once(['E', 'e']).unwrap_or((&body, ""));
+    let (integer, decimal) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let min_integer = integer.chars().filter(|c| *c == '0').count().to_string();
+    let decimal_places = count_digit_placeholders(decimal).to_string();
+    match kind {
+        NumberStyleKind::Scientific => {
+            let exponent_digits = count_digit_placeholders(exponent).to_string();
+            write_start_tag(
+                writer,
+                "number:scientific-number",
+                vec![
+                    ("number:decimal-places", decimal_places.as_str()),
+                    ("number:min-integer-digits", min_integer.as_str()),
+                    ("number:min-exponent-digits", exponent_digits.as_str()),
+                ],
+                true,
+            );
+        }
+        NumberStyleKind::Fraction => {
+            let (integer, fraction) = body.trim().rsplit_once(' ').unwrap_or(("", body.trim()));
+            let (numerator, denominator) = fraction.split_once('/').unwrap_or((fraction, ""));
+            let min_integer = integer.chars().filter(|c| *c == '0').count().to_string();
+            let numerator_digits = count_digit_placeholders(numerator).max(1).to_string();
+            let denominator_digits = count_digit_placeholders(denominator).max(1).to_string();
+            let mut attributes = vec![
+                ("number:min-integer-digits", min_integer.as_str()),
+                ("number:min-numerator-digits", numerator_digits.as_str()),
+            ];
+            match !denominator.is_empty() && denominator.chars().all(|c| c.is_ascii_digit()) {
+                true => attributes.push(("number:denominator-value", denominator)),
+                false => {
+                    attributes.push(("number:min-denominator-digits", denominator_digits.as_str()))
+                }
+            }
+            write_start_tag(writer, "number:fraction", attributes, true);
+        }
+        _ => {
+            let mut attributes = vec![
+                ("number:decimal-places", decimal_places.as_str()),
+                ("number:min-integer-digits", min_integer.as_str()),
+            ];
+            if integer.contains(',') {
+                attributes.push(("number:grouping", "true"));
+            }
+            write_start_tag(writer, "number:number", attributes, true);
+        }
+    }
+    write_literal(&suffix, writer);
+    write_end_tag(writer, tag);
+}
+
+fn write_literal(value: &str, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    if value.is_empty() {
+        return;
+    }
+    write_start_tag(writer, "number:text", vec![], false);
+    write_text_node(writer, value);
+    write_end_tag(writer, "number:text");
+}
+
+#[inline]
+fn count_digit_placeholders(value: &str) -> usize {
+    value
+        .chars()
+        .filter(|c| matches!(c, '0' | '#' | '?'))
+        .count()
+}
+
+fn get_first_section(format_code: &str) -> String {
+    let mut result = String::new();
+    let mut in_quote = false;
+    let mut chars = format_code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quote = !in_quote,
+            '\\' if !in_quote => {
+                result.push(c);
+                if let Some(v) = chars.next() {
+                    result.push(v);
+                }
+                continue;
+            }
+            ';' if !in_quote => break,
+            _ => {}
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn contains_unquoted<F: Fn(char) -> bool>(value: &str, f: F) -> bool {
+    split_literals(value)
+        .iter()
+        .any(|(is_literal, v)| !is_literal && v.chars().any(&f))
+}
+
+#[inline]
+fn remove_literals(value: &str) -> String {
+    split_literals(value)
+        .into_iter()
+        .filter(|(is_literal, _)| !is_literal)
+        .map(|(_, v)| v)
+        .collect()
+}
+
+/// Split a format section into literal parts and code parts.
+/// Quoted strings, escaped characters and currency symbols become literals.
+/// Colors, conditions and padding characters are dropped.
+fn split_literals(section: &str) -> Vec<(bool, String)> {
+    let mut result: Vec<(bool, String)> = Vec::new();
+    let mut push = |is_literal: bool, value: &str| {
+        if value.is_empty() {
+            return;
+        }
+        match result.last_mut() {
+            Some((last, v)) if *last == is_literal => v.push_str(value),
+            _ => result.push((is_literal, value.to_string())),
+        }
+    };
+    let chars: Vec<char> = section.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '"' => {
+                let end = chars[index + 1..]
+                    .iter()
+                    .position(|v| *v == '"')
+                    .map(|v| index + 1 + v)
+                    .unwrap_or(chars.len());
+                let value: String = chars[index + 1..end].iter().collect();
+                push(true, &value);
+                index = end + 1;
+            }
+            '\\' => {
+                if let Some(v) = chars.get(index + 1) {
+                    push(true, &v.to_string());
+                }
+                index += 2;
+            }
+            '_' => {
+                push(true, " ");
+                index += 2;
+            }
+            '*' => index += 2,
+            '[' => {
+                let end = chars[index..]
+                    .iter()
+                    .position(|v| *v == ']')
+                    .map(|v| index + v)
+                    .unwrap_or(chars.len() - 1);
+                let value: String = chars[index + 1..end].iter().collect();
+                if let Some(v) = value.strip_prefix('$') {
+                    push(true, v.split('-').next().unwrap_or(""));
+                } else if !value.is_empty()
+                    && value
+                        .chars()
+                        .all(|v| matches!(v, 'h' | 'H' | 'm' | 'M' | 's' | 'S'))
+                {
+                    push(false, &format!("[{}]", value));
+                }
+                index = end + 1;
+            }
+            '-' | '+' if index > 0 && matches!(chars[index - 1], 'E' | 'e') => {
+                push(false, &c.to_string());
+                index += 1;
+            }
+            '$' | '-' | '+' | '(' | ')' | ':' | '!' | '^' | '&' | '\'' | '~' | '{' | '}' | '<'
+            | '>' | '=' | ' ' => {
+                push(true, &c.to_string());
+                index += 1;
+            }
+            _ => {
+                push(false, &c.to_string());
+                index += 1;
+            }
+        }
+    }
+    result
+}
+
+fn parse_date_tokens(section: &str) -> Vec<DateToken> {
+    let mut tokens: Vec<DateToken> = Vec::new();
+    let push_literal = |tokens: &mut Vec<DateToken>, value: &str| match tokens.last_mut() {
+        Some(DateToken::Literal(v)) => v.push_str(value),
+        _ => tokens.push(DateToken::Literal(value.to_string())),
+    };
+    for (is_literal, value) in split_literals(section) {
+        if is_literal {
+            push_literal(&mut tokens, &value);
+            continue;
+        }
+        let chars: Vec<char> = value.chars().collect();
+        let mut index = 0;
+        while index < chars.len() {
+            let rest: String = chars[index..].iter().collect::<String>().to_lowercase();
+            if rest.starts_with("am/pm") {
+                tokens.push(DateToken::AmPm);
+                index += 5;
+                continue;
+            }
+            if rest.starts_with("a/p") {
+                tokens.push(DateToken::AmPm);
+                index += 3;
+                continue;
+            }
+            let c = chars[index].to_ascii_lowercase();
+            let is_elapsed = c == '[';
+            let (c, start) = match is_elapsed {
+                true => (
+                    chars
+                        .get(index + 1)
+                        .map(|v| v.to_ascii_lowercase())
+                        .unwrap_or(' '),
+                    index + 1,
+                ),
+                false => (c, index),
+            };
+            if chars[index] == '.'
+                && chars.get(index + 1) == Some(&'0')
+                && matches!(tokens.last(), Some(DateToken::Second(..)))
+            {
+                let mut end = index + 1;
+                while chars.get(end) == Some(&'0') {
+                    end += 1;
+                }
+                if let Some(DateToken::Second(_, _, decimal)) = tokens.last_mut() {
+                    *decimal = end - index - 1;
+                }
+                index = end;
+                continue;
+            }
+            if !matches!(c, 'y' | 'm' | 'd' | 'h' | 's') {
+                push_literal(&mut tokens, &chars[index].to_string());
+                index += 1;
+                continue;
+            }
+            let mut end = start;
+            while end < chars.len() && chars[end].to_ascii_lowercase() == c {
+                end += 1;
+            }
+            let len = end - start;
+            if is_elapsed && chars.get(end) == Some(&']') {
+                end += 1;
+            }
+            match c {
+                'y' => tokens.push(DateToken::Year(len)),
+                'm' => tokens.push(DateToken::Month(len)),
+                'd' => tokens.push(DateToken::Day(len)),
+                'h' => tokens.push(DateToken::Hour(len, is_elapsed)),
+                _ => tokens.push(DateToken::Second(len, is_elapsed, 0)),
+            }
+            if is_elapsed {
+                if let Some(DateToken::Month(len)) = tokens.last() {
+                    let len = *len;
+                    *tokens.last_mut().unwrap() = DateToken::Minute(len, true);
+                }
+            }
+            index = end;
+        }
+    }
+
+    // "m" next to hours or seconds means minutes.
+    for index in 0..tokens.len() {
+        let len = match tokens[index] {
+            DateToken::Month(v) if v <= 2 => v,
+            _ => continue,
+        };
+        let previous = tokens[..index]
+            .iter()
+            .rev()
+            .find(|v| !matches!(v, DateToken::Literal(_)));
+        let next = tokens[index + 1..]
+            .iter()
+            .find(|v| !matches!(v, DateToken::Literal(_)));
+        if matches!(previous, Some(DateToken::Hour(..)))
+            || matches!(next, Some(DateToken::Second(..)))
+        {
+            tokens[index] = DateToken::Minute(len, false);
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind() {
+        assert_eq!(get_kind("yyyy-mm-dd"), NumberStyleKind::Date);
+        assert_eq!(get_kind("hh:mm:ss"), NumberStyleKind::Time);
+        assert_eq!(get_kind("[h]:mm"), NumberStyleKind::Time);
+        assert_eq!(get_kind("0.00%"), NumberStyleKind::Percentage);
+        assert_eq!(get_kind("0.00E+00"), NumberStyleKind::Scientific);
+        assert_eq!(get_kind("# ?/?"), NumberStyleKind::Fraction);
+        assert_eq!(get_kind("#,##0.00;[Red]-#,##0.00"), NumberStyleKind::Number);
+        assert_eq!(get_kind("\"day\" 0"), NumberStyleKind::Number);
+        assert_eq!(get_kind("@"), NumberStyleKind::Text);
+    }
+
+    #[test]
+    fn date_tokens() {
+        assert_eq!(
+            parse_date_tokens("yyyy/m/d h:mm"),
+            vec![
+                DateToken::Year(4),
+                DateToken::Literal("/".into()),
+                DateToken::Month(1),
+                DateToken::Literal("/".into()),
+                DateToken::Day(1),
+                DateToken::Literal(" ".into()),
+                DateToken::Hour(1, false),
+                DateToken::Literal(":".into()),
+                DateToken::Minute(2, false),
+            ]
+        );
+        assert_eq!(
+            parse_date_tokens("mm:ss.00"),
+            vec![
+                DateToken::Minute(2, false),
+                DateToken::Literal(":".into()),
+                DateToken::Second(2, false, 2),
+            ]
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+use std::io::Cursor;
+
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::structs::drawing::Theme;
+use crate::structs::Border;
+use crate::structs::Color;
+use crate::structs::Font;
+use crate::structs::HorizontalAlignmentValues;
+use crate::structs::PatternValues;
+use crate::structs::Spreadsheet;
+use crate::structs::Style;
+use crate::structs::UnderlineValues;
+use crate::structs::VerticalAlignmentValues;
+use crate::structs::WriterManager;
+use crate::writer::driver::*;
+
+/// Properties of an automatic cell style.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct CellStyleProperties {
+    pub(crate) data_style_name: Option<String>,
+    pub(crate) cell: Vec<(&'static str, String)>,
+    pub(crate) paragraph: Vec<(&'static str, String)>,
+    pub(crate) text: Vec<(&'static str, String)>,
+}
+impl CellStyleProperties {
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data_style_name.is_none()
+            && self.cell.is_empty()
+            && self.paragraph.is_empty()
+            && self.text.is_empty()
+    }
+
+    #[inline]
+    pub(crate) fn get_hash_code(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)));
+    write_new_line(&mut writer);
+
+    // office:document-styles
+    write_start_tag(
+        &mut writer,
+        "office:document-styles",
+        vec![
+            ("xmlns:office", ODS_OFFICE_NS),
+            ("xmlns:style", ODS_STYLE_NS),
+            ("xmlns:text", ODS_TEXT_NS),
+            ("xmlns:table", ODS_TABLE_NS),
+            ("xmlns:fo", ODS_FO_NS),
+            ("xmlns:number", ODS_NUMBER_NS),
+            ("xmlns:svg", ODS_SVG_NS),
+            ("office:version", ODS_VERSION),
+        ],
+        false,
+    );
+
+    // office:styles
+    write_start_tag(&mut writer, "office:styles", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "style:default-style",
+        vec![("style:family", "table-cell")],
+        false,
+    );
+    let text = make_text_properties(&get_default_font(spreadsheet), spreadsheet.get_theme());
+    write_properties(&mut writer, "style:text-properties", &text);
+    write_end_tag(&mut writer, "style:default-style");
+    write_start_tag(
+        &mut writer,
+        "style:style",
+        vec![("style:name", "Default"), ("style:family", "table-cell")],
+        true,
+    );
+    write_end_tag(&mut writer, "office:styles");
+
+    // office:automatic-styles
+    write_start_tag(&mut writer, "office:automatic-styles", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "style:page-layout",
+        vec![("style:name", "pm1")],
+        true,
+    );
+    write_end_tag(&mut writer, "office:automatic-styles");
+
+    // office:master-styles
+    write_start_tag(&mut writer, "office:master-styles", vec![], false);
+    write_start_tag(
+        &mut writer,
+        "style:master-page",
+        vec![("style:name", "Default"), ("style:page-layout-name", "pm1")],
+        true,
+    );
+    write_end_tag(&mut writer, "office:master-styles");
+
+    write_end_tag(&mut writer, "office:document-styles");
+    writer_mng.add_writer(ODS_STYLES, writer)
+}
+
+/// The font of the default cell style.
+#[inline]
+pub(crate) fn get_default_font(spreadsheet: &Spreadsheet) -> Font {
+    spreadsheet
+        .get_stylesheet()
+        ._get_fonts()
+        .get_font()
+        .first()
+        .cloned()
+        .unwrap_or_else(Font::get_default_value)
+}
+
+/// Convert a style into the properties of an automatic cell style.
+/// Font properties equal to the default font are omitted.
+pub(crate) fn make_cell_style_properties(
+    style: &Style,
+    default_text: &[(&'static str, String)],
+    theme: &Theme,
+) -> CellStyleProperties {
+    let mut properties = CellStyleProperties::default();
+
+    if let Some(color) = style
+        .get_fill()
+        .and_then(|v| v.get_pattern_fill())
+        .and_then(|v| match v.get_pattern_type() {
+            PatternValues::None => None,
+            _ => v.get_foreground_color(),
+        })
+    {
+        if let Some(v) = make_color(color, theme) {
+            properties.cell.push(("fo:background-color", v));
+        }
+    }
+
+    if let Some(borders) = style.get_borders() {
+        let border_list = [
+            ("fo:border-bottom", borders.get_bottom()),
+            ("fo:border-left", borders.get_left()),
+            ("fo:border-right", borders.get_right()),
+            ("fo:border-top", borders.get_top()),
+        ];
+        for (key, border) in border_list {
+            if let Some(v) = make_border(border, theme) {
+                properties.cell.push((key, v));
+            }
+        }
+    }
+
+    if let Some(alignment) = style.get_alignment() {
+        let text_align = match alignment.get_horizontal() {
+            HorizontalAlignmentValues::Left | HorizontalAlignmentValues::Fill => Some("start"),
+            HorizontalAlignmentValues::Center | HorizontalAlignmentValues::CenterContinuous => {
+                Some("center")
+            }
+            HorizontalAlignmentValues::Right => Some("end"),
+            HorizontalAlignmentValues::Justify | HorizontalAlignmentValues::Distributed => {
+                Some("justify")
+            }
+            HorizontalAlignmentValues::General => None,
+        };
+        let vertical_align = match alignment.get_vertical() {
+            VerticalAlignmentValues::Top => Some("top"),
+            VerticalAlignmentValues::Center
+            | VerticalAlignmentValues::Justify
+            | VerticalAlignmentValues::Distributed => Some("middle"),
+            VerticalAlignmentValues::Bottom => None,
+        };
+        if let Some(v) = vertical_align {
+            properties
+                .cell
+                .push(("style:vertical-align", v.to_string()));
+        }
+        if *alignment.get_wrap_text() {
+            properties.cell.push(("fo:wrap-option", "wrap".to_string()));
+        }
+        let rotation = *alignment.get_text_rotation();
+        if (1..=180).contains(&rotation) {
+            let angle = match rotation > 90 {
+                true => 360 - (rotation - 90),
+                false => rotation,
+            };
+            properties
+                .cell
+                .push(("style:rotation-angle", angle.to_string()));
+        }
+        if let Some(v) = text_align {
+            properties
+                .cell
+                .push(("style:text-align-source", "fix".to_string()));
+            properties.paragraph.push(("fo:text-align", v.to_string()));
+        }
+    }
+
+    if let Some(protection) = style.get_protection() {
+        if !*protection.get_locked() {
+            properties
+                .cell
+                .push(("style:cell-protect", "none".to_string()));
+        }
+    }
+
+    if let Some(font) = style.get_font() {
+        for property in make_text_properties(font, theme) {
+            if !default_text.contains(&property) {
+                properties.text.push(property);
+            }
+        }
+    }
+
+    properties
+}
+
+/// Convert a font into text properties.
+pub(crate) fn make_text_properties(font: &Font, theme: &Theme) -> Vec<(&'static str, String)> {
+    let mut result: Vec<(&'static str, String)> = Vec::new();
+    if let Some(v) = make_color(font.get_color(), theme) {
+        result.push(("fo:color", v));
+    }
+    if !font.get_name().is_empty() {
+        result.push(("fo:font-family", font.get_name().to_string()));
+    }
+    if *font.get_size() > 0f64 {
+        result.push(("fo:font-size", format!("{}pt", font.get_size())));
+    }
+    let font_style = match *font.get_italic() {
+        true => "italic",
+        false => "normal",
+    };
+    result.push(("fo:font-style", font_style.to_string()));
+    let font_weight = match *font.get_bold() {
+        true => "bold",
+        false => "normal",
+    };
+    result.push(("fo:font-weight", font_weight.to_string()));
+    if *font.get_strikethrough() {
+        result.push(("style:text-line-through-style", "solid".to_string()));
+    }
+    match font.get_font_underline().get_val() {
+        UnderlineValues::Single | UnderlineValues::SingleAccounting => {
+            result.push(("style:text-underline-style", "solid".to_string()));
+        }
+        UnderlineValues::Double | UnderlineValues::DoubleAccounting => {
+            result.push(("style:text-underline-style", "solid".to_string()));
+            result.push(("style:text-underline-type", "double".to_string()));
+        }
+        _ => {}
+    }
+    result
+}
+
+/// Convert a border into the value of `fo:border-*`.
+fn make_border(border: &Border, theme: &Theme) -> Option<String> {
+    let (width, line) = match border.get_border_style() {
+        Border::BORDER_HAIR => ("0.26pt", "solid"),
+        Border::BORDER_THIN => ("0.74pt", "solid"),
+        Border::BORDER_MEDIUM => ("1.76pt", "solid"),
+        Border::BORDER_THICK => ("2.49pt", "solid"),
+        Border::BORDER_DOUBLE => ("2.01pt", "double"),
+        Border::BORDER_DASHED => ("0.74pt", "dashed"),
+        Border::BORDER_MEDIUMDASHED => ("1.76pt", "dashed"),
+        Border::BORDER_DOTTED => ("0.74pt", "dotted"),
+        Border::BORDER_DASHDOT => ("0.74pt", "dash-dot"),
+        Border::BORDER_MEDIUMDASHDOT | Border::BORDER_SLANTDASHDOT => ("1.76pt", "dash-dot"),
+        Border::BORDER_DASHDOTDOT => ("0.74pt", "dash-dot-dot"),
+        Border::BORDER_MEDIUMDASHDOTDOT => ("1.76pt", "dash-dot-dot"),
+        _ => return None,
+    };
+    let color = make_color(border.get_color(), theme).unwrap_or_else(|| "#000000".to_string());
+    Some(format!("{} {} {}", width, line, color))
+}
+
+/// Convert a color into `#RRGGBB`.
+pub(crate) fn make_color(color: &Color, theme: &Theme) -> Option<String> {
+    let argb = color.get_argb_with_theme(theme);
+    match argb.len() {
+        8 => Some(format!("#{}", &argb[2..])),
+        6 => Some(format!("#{}", argb)),
+        _ => None,
+    }
+}
+
+/// Write an automatic cell style.
+pub(crate) fn write_cell_style(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    name: &str,
+    properties: &CellStyleProperties,
+) {
+    let mut attributes = vec![
+        ("style:name", name),
+        ("style:family", "table-cell"),
+        ("style:parent-style-name", "Default"),
+    ];
+    if let Some(v) = &properties.data_style_name {
+        attributes.push(("style:data-style-name", v.as_str()));
+    }
+    write_start_tag(writer, "style:style", attributes, false);
+    write_properties(writer, "style:table-cell-properties", &properties.cell);
+    write_properties(writer, "style:paragraph-properties", &properties.paragraph);
+    write_properties(writer, "style:text-properties", &properties.text);
+    write_end_tag(writer, "style:style");
+}
+
+pub(crate) fn write_properties(
+    writer: &mut Writer<Cursor<Vec<u8>>>,
+    tag: &str,
+    properties: &[(&'static str, String)],
+) {
+    if properties.is_empty() {
+        return;
+    }
+    let attributes: Vec<(&str, &str)> = properties.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    write_start_tag(writer, tag, attributes, true);
+}
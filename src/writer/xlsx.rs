@@ -1,6 +1,7 @@
 use super::driver;
 use crate::helper::crypt::*;
 use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
 use crate::structs::WriterManager;
 use crate::XlsxError;
 use std::fmt;
@@ -13,6 +14,7 @@ use std::path::Path;
 use std::string::FromUtf8Error;
 
 mod chart;
+mod chart_ex;
 mod comment;
 mod content_types;
 mod doc_props_app;
@@ -22,12 +24,16 @@ mod drawing;
 mod drawing_rels;
 mod embeddings;
 mod media;
+mod person;
+mod pivot_table;
 mod printer_settings;
 mod rels;
 mod shared_strings;
+mod streaming_writer;
 mod styles;
 mod table;
 mod theme;
+mod threaded_comment;
 mod vba_project_bin;
 mod vml_drawing;
 mod vml_drawing_rels;
@@ -36,6 +42,8 @@ mod workbook_rels;
 mod worksheet;
 mod worksheet_rels;
 
+pub use self::streaming_writer::StreamingWriter;
+
 fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Vec<u8>, XlsxError> {
     let mut arv = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
     let mut writer_manager = WriterManager::new(&mut arv);
@@ -59,11 +67,29 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     // Add theme
     theme::write(spreadsheet.get_theme(), &mut writer_manager)?;
 
+    // generate the pivot tables added from a builder
+    let mut prepared_worksheets: Vec<Option<Worksheet>> = Vec::new();
+    for worksheet in spreadsheet.get_sheet_collection_no_check() {
+        if worksheet.is_deserialized() && worksheet.has_pending_pivot_table() {
+            let mut worksheet = worksheet.clone();
+            worksheet.make_pending_pivot_tables(spreadsheet)?;
+            prepared_worksheets.push(Some(worksheet));
+        } else {
+            prepared_worksheets.push(None);
+        }
+    }
+    let worksheets: Vec<&Worksheet> = spreadsheet
+        .get_sheet_collection_no_check()
+        .iter()
+        .zip(&prepared_worksheets)
+        .map(|(worksheet, prepared)| prepared.as_ref().unwrap_or(worksheet))
+        .collect();
+
     // worksheet
     let shared_string_table = spreadsheet.get_shared_string_table();
     let mut stylesheet = spreadsheet.get_stylesheet().clone();
     let mut worksheet_no = 1;
-    for worksheet in spreadsheet.get_sheet_collection_no_check() {
+    for worksheet in &worksheets {
         if worksheet.is_deserialized() {
             // from deserialized.
             worksheet::write(
@@ -85,7 +111,7 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
 
     // Objects associated with worksheets
     let mut worksheet_no = 0;
-    for worksheet in spreadsheet.get_sheet_collection_no_check() {
+    for worksheet in &worksheets {
         worksheet_no += 1;
         if !worksheet.is_deserialized() {
             continue;
@@ -99,6 +125,11 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
             let chart_no = chart::write(chart_space, spreadsheet, &mut writer_manager)?;
             chart_no_list.push(chart_no);
         }
+        let mut chart_ex_no_list: Vec<String> = Vec::new();
+        for chart_space in worksheet.get_chart_ex_collection() {
+            let chart_ex_no = chart_ex::write(chart_space, spreadsheet, &mut writer_manager)?;
+            chart_ex_no_list.push(chart_ex_no);
+        }
 
         // Add drawing
         let (drawing_no, rel_list) = drawing::write(worksheet, &mut writer_manager)?;
@@ -108,6 +139,7 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
             worksheet,
             &drawing_no,
             &chart_no_list,
+            &chart_ex_no_list,
             &rel_list,
             &mut writer_manager,
         )?;
@@ -121,6 +153,9 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         // Add comment
         let comment_no = comment::write(worksheet, &mut writer_manager)?;
 
+        // Add threaded comment
+        let threaded_comment_no = threaded_comment::write(worksheet, &mut writer_manager)?;
+
         // Add ole_object and excel
         let (ole_object_no_list, excel_no_list) =
             embeddings::write(worksheet, &mut writer_manager)?;
@@ -137,6 +172,9 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         // Add tables
         let table_no_list = table::write(worksheet, &mut writer_manager)?;
 
+        // Add pivot tables
+        let pivot_table_no_list = pivot_table::write(worksheet, spreadsheet, &mut writer_manager)?;
+
         // Add worksheet rels
         worksheet_rels::write(
             worksheet,
@@ -144,10 +182,12 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
             &drawing_no,
             &vml_drawing_no,
             &comment_no,
+            &threaded_comment_no,
             &ole_object_no_list,
             &excel_no_list,
             &printer_settings_no,
             &table_no_list,
+            &pivot_table_no_list,
             &mut writer_manager,
         )?;
     }
@@ -161,6 +201,9 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     // Add Styles
     styles::write(&stylesheet, &mut writer_manager)?;
 
+    // Add persons
+    person::write(spreadsheet, &mut writer_manager)?;
+
     // Add workbook
     workbook::write(spreadsheet, &mut writer_manager)?;
 
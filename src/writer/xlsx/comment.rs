@@ -1,6 +1,7 @@
 use super::driver::*;
 use super::XlsxError;
 use crate::helper::const_str::*;
+use crate::structs::RichText;
 use crate::structs::Worksheet;
 use crate::structs::WriterManager;
 use quick_xml::events::{BytesDecl, Event};
@@ -57,7 +58,21 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
 
         // text
-        comment.get_text().write_to_text(&mut writer);
+        // the note of a threaded comment follows the current thread.
+        let threaded_comment = comment.get_author().strip_prefix("tc=").and_then(|id| {
+            worksheet
+                .get_threaded_comments()
+                .iter()
+                .find(|v| v.get_id() == id)
+        });
+        match threaded_comment {
+            Some(v) => {
+                let mut text = RichText::default();
+                text.set_text(v.get_legacy_text());
+                text.write_to_text(&mut writer);
+            }
+            None => comment.get_text().write_to_text(&mut writer),
+        }
 
         write_end_tag(&mut writer, "comment");
     }
@@ -12,6 +12,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
     drawing_no: &str,
     chart_no_list: &[String],
+    chart_ex_no_list: &[String],
     rel_list: &[(String, String)],
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
@@ -42,6 +43,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     }
 
     let mut r_id = 1;
+    let mut chart_ex_no_list = chart_ex_no_list.iter();
     for (key, value) in rel_list {
         if key == "IMAGE" {
             is_write = write_relationship(
@@ -52,6 +54,17 @@ pub(crate) fn write<W: io::Seek + io::Write>(
                 "",
             );
         }
+        if key == "CHART_EX" {
+            if let Some(chart_ex_no) = chart_ex_no_list.next() {
+                is_write = write_relationship(
+                    &mut writer,
+                    &r_id,
+                    CHART_EX_NS,
+                    format!("../charts/chartEx{}.xml", chart_ex_no).as_str(),
+                    "",
+                );
+            }
+        }
         r_id += 1;
     }
     write_end_tag(&mut writer, "Relationships");
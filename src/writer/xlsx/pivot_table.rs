@@ -0,0 +1,128 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+use super::driver::*;
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::structs::PivotTable;
+use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
+use crate::structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<Vec<String>, XlsxError> {
+    let mut pivot_table_no_list: Vec<String> = Vec::new();
+    for pivot_table in worksheet.get_pivot_tables() {
+        let cache_id = make_cache_id(spreadsheet, writer_mng);
+
+        // pivotCacheRecords
+        let mut writer = new_writer();
+        pivot_table.get_pivot_cache_records().write_to(&mut writer);
+        let records_no = writer_mng.add_file_at_pivot_cache_records(writer)?;
+
+        // pivotCacheDefinition
+        let mut pivot_cache_definition = pivot_table.get_pivot_cache_definition().clone();
+        pivot_cache_definition.set_id("rId1");
+        let mut writer = new_writer();
+        pivot_cache_definition.write_to(&mut writer);
+        let definition_no = writer_mng.add_file_at_pivot_cache_definition(writer)?;
+
+        // pivotCacheDefinition rels
+        let mut writer = new_writer();
+        write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
+        write_relationship(
+            &mut writer,
+            PIVOT_CACHE_RECORDS_NS,
+            &format!("pivotCacheRecords{}.xml", records_no),
+        );
+        write_end_tag(&mut writer, "Relationships");
+        let file_path = format!(
+            "{}/_rels/pivotCacheDefinition{}.xml.rels",
+            PKG_PIVOT_CACHE, definition_no
+        );
+        writer_mng.add_writer(&file_path, writer)?;
+        writer_mng.add_pivot_cache(
+            cache_id.to_string(),
+            format!("pivotCache/pivotCacheDefinition{}.xml", definition_no),
+        );
+
+        // pivotTableDefinition
+        let pivot_table_no = write_pivot_table(pivot_table, cache_id, writer_mng)?;
+
+        // pivotTableDefinition rels
+        let mut writer = new_writer();
+        write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
+        write_relationship(
+            &mut writer,
+            PIVOT_CACHE_DEF_NS,
+            &format!("../pivotCache/pivotCacheDefinition{}.xml", definition_no),
+        );
+        write_end_tag(&mut writer, "Relationships");
+        let file_path = format!(
+            "{}/_rels/pivotTable{}.xml.rels",
+            PKG_PIVOT_TABLES, pivot_table_no
+        );
+        writer_mng.add_writer(&file_path, writer)?;
+
+        pivot_table_no_list.push(pivot_table_no.to_string());
+    }
+    Ok(pivot_table_no_list)
+}
+
+fn write_pivot_table<W: io::Seek + io::Write>(
+    pivot_table: &PivotTable,
+    cache_id: u32,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<i32, XlsxError> {
+    let mut pivot_table_definition = pivot_table.get_pivot_table_definition().clone();
+    pivot_table_definition.set_cache_id(cache_id);
+    let mut writer = new_writer();
+    pivot_table_definition.write_to(&mut writer);
+    writer_mng.add_file_at_pivot_table(writer)
+}
+
+/// Next cacheId, after the caches carried through from the loaded file.
+fn make_cache_id<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &WriterManager<W>,
+) -> u32 {
+    let existing = spreadsheet
+        .get_pivot_caches()
+        .into_iter()
+        .map(|(_, cache_id, _)| cache_id);
+    let written = writer_mng
+        .get_pivot_caches()
+        .iter()
+        .map(|(cache_id, _)| cache_id.clone());
+    existing
+        .chain(written)
+        .filter_map(|v| v.parse::<u32>().ok())
+        .max()
+        .map(|v| v + 1)
+        .unwrap_or(0)
+}
+
+fn new_writer() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+    writer
+}
+
+fn write_relationship(writer: &mut Writer<io::Cursor<Vec<u8>>>, p_type: &str, p_target: &str) {
+    write_start_tag(
+        writer,
+        "Relationship",
+        vec![("Id", "rId1"), ("Type", p_type), ("Target", p_target)],
+        true,
+    );
+}
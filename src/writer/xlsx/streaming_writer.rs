@@ -0,0 +1,401 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+use super::driver::*;
+use super::XlsxError;
+use super::{
+    content_types, doc_props_app, doc_props_core, doc_props_custom, rels, shared_strings, styles,
+    theme, workbook, workbook_rels, worksheet, worksheet_rels,
+};
+use crate::helper::const_str::*;
+use crate::structs::Cell;
+use crate::structs::Row;
+use crate::structs::Spreadsheet;
+use crate::structs::Stylesheet;
+use crate::structs::Worksheet;
+use crate::structs::WriterManager;
+
+/// Row-by-row xlsx writer with bounded memory.
+///
+/// Worksheet settings (column widths, merged cells, freeze panes, row heights, ...)
+/// are declared up front with a template `Worksheet`. Rows are then appended in order
+/// and flushed straight into the zip entry, so the cells are never held in memory.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let path = std::path::Path::new("./tests/result_files/zzz_streaming.xlsx");
+/// let mut writer = writer::xlsx::StreamingWriter::create(path).unwrap();
+///
+/// let mut sheet = Worksheet::default();
+/// sheet.set_name("Data");
+/// sheet.get_column_dimension_mut("A").set_width(20.0);
+/// sheet.add_merge_cells("A1:C1");
+/// writer.add_sheet(sheet).unwrap();
+///
+/// writer.append_row_values(["Title"]).unwrap();
+/// for i in 0..1000 {
+///     writer.append_row_values([i.to_string(), "value".to_string()]).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// ```
+pub struct StreamingWriter<W: io::Seek + io::Write> {
+    arv: zip::ZipWriter<W>,
+    spreadsheet: Spreadsheet,
+    stylesheet: Stylesheet,
+    use_shared_string: bool,
+    is_light: bool,
+    sheet_files: Vec<String>,
+    row_num: u32,
+    is_sheet_open: bool,
+}
+
+impl StreamingWriter<io::BufWriter<File>> {
+    /// Create a new file to stream into.
+    /// # Arguments
+    /// * `path` - file path to save.
+    /// # Return value
+    /// * `Result` - OK is StreamingWriter. Err is error message.
+    #[inline]
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, XlsxError> {
+        Ok(Self::new(io::BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: io::Seek + io::Write> StreamingWriter<W> {
+    /// Stream into an arbitrary writer.
+    /// # Arguments
+    /// * `writer` - writer to write to.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        let spreadsheet = crate::new_file_empty_worksheet();
+        let stylesheet = spreadsheet.get_stylesheet().clone();
+        Self {
+            arv: zip::ZipWriter::new(writer),
+            spreadsheet,
+            stylesheet,
+            use_shared_string: false,
+            is_light: false,
+            sheet_files: Vec::new(),
+            row_num: 0,
+            is_sheet_open: false,
+        }
+    }
+
+    /// Whether strings go to the shared string table.
+    /// Default is false: strings are written inline so that no table has to be kept in memory.
+    #[inline]
+    pub fn get_use_shared_string(&self) -> &bool {
+        &self.use_shared_string
+    }
+
+    #[inline]
+    pub fn set_use_shared_string(&mut self, value: bool) -> &mut Self {
+        self.use_shared_string = value;
+        self
+    }
+
+    /// Whether to store the parts without compression. (like `write_light`)
+    #[inline]
+    pub fn get_is_light(&self) -> &bool {
+        &self.is_light
+    }
+
+    #[inline]
+    pub fn set_is_light(&mut self, value: bool) -> &mut Self {
+        self.is_light = value;
+        self
+    }
+
+    /// Spreadsheet used for the workbook level parts (properties, theme, defined names, ...).
+    #[inline]
+    pub fn get_spreadsheet_mut(&mut self) -> &mut Spreadsheet {
+        &mut self.spreadsheet
+    }
+
+    /// Start a new worksheet. The previous worksheet is closed.
+    /// # Arguments
+    /// * `worksheet` - template holding the sheet name and settings.
+    /// Cells of the template are not written; use `append_row` instead.
+    /// Templates holding parts that need a relationship (external hyperlinks, drawings,
+    /// comments, ole objects, tables or printer settings) are rejected.
+    /// # Return value
+    /// * `Result` - OK is void. Err is error message.
+    pub fn add_sheet(&mut self, mut worksheet: Worksheet) -> Result<&mut Self, XlsxError> {
+        if let Some(part) = get_unsupported_part(&worksheet) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not supported by the streaming writer", part),
+            )
+            .into());
+        }
+        self.close_sheet()?;
+
+        let sheet_id = (self.sheet_files.len() + 1).to_string();
+        worksheet.set_sheet_id(&sheet_id);
+        if *worksheet
+            .get_sheet_format_properties()
+            .get_default_row_height()
+            == 0f64
+        {
+            worksheet
+                .get_sheet_format_properties_mut()
+                .set_defalut_value();
+        }
+        let worksheet = self
+            .spreadsheet
+            .add_sheet(worksheet)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+        // XML header
+        writer.write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )));
+        write_new_line(&mut writer);
+        worksheet::write_head(&mut writer, worksheet, &mut self.stylesheet, false, false);
+
+        // sheetData
+        write_start_tag(&mut writer, "sheetData", vec![], false);
+
+        let target = format!("{PKG_SHEET}{}.xml", sheet_id);
+        self.arv
+            .start_file(target.as_str(), self.get_file_options())?;
+        self.arv.write_all(&writer.into_inner().into_inner())?;
+        self.sheet_files.push(target);
+        self.row_num = 0;
+        self.is_sheet_open = true;
+        Ok(self)
+    }
+
+    /// Start a new worksheet with default settings.
+    /// # Arguments
+    /// * `sheet_name` - sheet name.
+    /// # Return value
+    /// * `Result` - OK is void. Err is error message.
+    #[inline]
+    pub fn new_sheet<S: Into<String>>(&mut self, sheet_name: S) -> Result<&mut Self, XlsxError> {
+        let mut worksheet = Worksheet::default();
+        worksheet.set_name(sheet_name);
+        self.add_sheet(worksheet)
+    }
+
+    /// Number of rows written to the current worksheet.
+    #[inline]
+    pub fn get_row_num(&self) -> &u32 {
+        &self.row_num
+    }
+
+    /// Append the next row to the current worksheet.
+    /// The cells are placed from column A in order; their coordinates are overwritten
+    /// while formulas are written as they are.
+    /// If the template has a row dimension for this row, its height and style are used.
+    /// # Arguments
+    /// * `cells` - cells of the row.
+    /// # Return value
+    /// * `Result` - OK is void. Err is error message.
+    pub fn append_row(&mut self, mut cells: Vec<Cell>) -> Result<&mut Self, XlsxError> {
+        if !self.is_sheet_open {
+            return Err(
+                io::Error::new(io::ErrorKind::InvalidInput, "no worksheet is started").into(),
+            );
+        }
+        self.row_num += 1;
+        let row_num = self.row_num;
+
+        let worksheet = self
+            .spreadsheet
+            .get_sheet_collection_no_check()
+            .last()
+            .unwrap();
+        let row = match worksheet.get_row_dimension(&row_num) {
+            Some(v) => v.clone(),
+            None => {
+                let mut row = Row::default();
+                row.set_row_num(row_num);
+                row
+            }
+        };
+
+        for (index, cell) in cells.iter_mut().enumerate() {
+            // formulas are kept as given.
+            let coordinate = cell.get_coordinate_mut();
+            coordinate.set_col_num(index as u32 + 1);
+            coordinate.set_row_num(row_num);
+        }
+        let shared_string_table = self.spreadsheet.get_shared_string_table();
+        let shared_string_table = match self.use_shared_string {
+            true => Some(&*shared_string_table),
+            false => None,
+        };
+        let formula_shared_list = HashMap::new();
+
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+        let is_empty = cells.is_empty();
+        let spans = format!("1:{}", cells.len());
+        row.write_to(&mut writer, &mut self.stylesheet, &spans, is_empty);
+        if !is_empty {
+            for cell in &cells {
+                cell.write_to(
+                    &mut writer,
+                    shared_string_table,
+                    &mut self.stylesheet,
+                    &formula_shared_list,
+                );
+            }
+            write_end_tag(&mut writer, "row");
+        }
+        self.arv.write_all(&writer.into_inner().into_inner())?;
+        Ok(self)
+    }
+
+    /// Append the next row to the current worksheet from values.
+    /// The data type of each value is guessed like `Cell::set_value`.
+    /// # Arguments
+    /// * `values` - values of the row.
+    /// # Return value
+    /// * `Result` - OK is void. Err is error message.
+    #[inline]
+    pub fn append_row_values<I, S>(&mut self, values: I) -> Result<&mut Self, XlsxError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let cells = values
+            .into_iter()
+            .map(|value| {
+                let mut cell = Cell::default();
+                cell.set_value(value);
+                cell
+            })
+            .collect();
+        self.append_row(cells)
+    }
+
+    /// Close the current worksheet, write the workbook level parts and finish the zip.
+    /// # Return value
+    /// * `Result` - OK is the inner writer. Err is error message.
+    pub fn finish(mut self) -> Result<W, XlsxError> {
+        self.close_sheet()?;
+        if self.sheet_files.is_empty() {
+            self.new_sheet("Sheet1")?;
+            self.close_sheet()?;
+        }
+
+        let spreadsheet = &self.spreadsheet;
+        let mut writer_manager = WriterManager::new(&mut self.arv);
+        writer_manager.set_is_light(self.is_light);
+        for file in &self.sheet_files {
+            writer_manager.add_file_name(file);
+        }
+
+        // Add docProps App
+        doc_props_app::write(spreadsheet, &mut writer_manager)?;
+
+        // Add docProps Core
+        doc_props_core::write(spreadsheet, &mut writer_manager)?;
+
+        // Add docProps Custom
+        doc_props_custom::write(spreadsheet, &mut writer_manager)?;
+
+        // Add relationships
+        rels::write(spreadsheet, &mut writer_manager)?;
+
+        // Add theme
+        theme::write(spreadsheet.get_theme(), &mut writer_manager)?;
+
+        // Add worksheet rels
+        let mut worksheet_no = 0;
+        for worksheet in spreadsheet.get_sheet_collection_no_check() {
+            worksheet_no += 1;
+            worksheet_rels::write(
+                worksheet,
+                &worksheet_no.to_string(),
+                "",
+                "",
+                "",
+                "",
+                &[],
+                &[],
+                "",
+                &[],
+                &[],
+                &mut writer_manager,
+            )?;
+        }
+
+        // Add SharedStrings
+        let shared_string_table = spreadsheet.get_shared_string_table();
+        shared_strings::write(&shared_string_table, &mut writer_manager)?;
+
+        // Add Styles
+        styles::write(&self.stylesheet, &mut writer_manager)?;
+
+        // Add workbook
+        workbook::write(spreadsheet, &mut writer_manager)?;
+
+        // Add workbook relationships
+        let has_shared_string_table = shared_string_table.read().unwrap().has_value();
+        workbook_rels::write(spreadsheet, has_shared_string_table, &mut writer_manager)?;
+
+        // Add Content_Types
+        content_types::write(spreadsheet, &mut writer_manager)?;
+
+        Ok(self.arv.finish()?)
+    }
+
+    fn close_sheet(&mut self) -> Result<(), XlsxError> {
+        if !self.is_sheet_open {
+            return Ok(());
+        }
+        let worksheet = self
+            .spreadsheet
+            .get_sheet_collection_no_check()
+            .last()
+            .unwrap();
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+        write_end_tag(&mut writer, "sheetData");
+        worksheet::write_tail(&mut writer, worksheet, &mut self.stylesheet);
+        self.arv.write_all(&writer.into_inner().into_inner())?;
+        self.is_sheet_open = false;
+        Ok(())
+    }
+
+    #[inline]
+    fn get_file_options(&self) -> zip::write::SimpleFileOptions {
+        let compression_method = match self.is_light {
+            true => zip::CompressionMethod::Stored,
+            false => zip::CompressionMethod::DEFLATE,
+        };
+        zip::write::SimpleFileOptions::default().compression_method(compression_method)
+    }
+}
+
+fn get_unsupported_part(worksheet: &Worksheet) -> Option<&'static str> {
+    if worksheet
+        .get_hyperlink_collection_to_hashmap()
+        .values()
+        .any(|hyperlink| !*hyperlink.get_location())
+    {
+        return Some("external hyperlink");
+    }
+    if worksheet.has_drawing_object() {
+        return Some("drawing");
+    }
+    if worksheet.has_legacy_drawing() {
+        return Some("comment or ole object");
+    }
+    if worksheet.has_table() {
+        return Some("table");
+    }
+    if worksheet.get_page_setup().get_object_data().is_some() {
+        return Some("printer settings");
+    }
+    None
+}
@@ -44,6 +44,10 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         if table.has_totals_row_shown() {
             attributes.push(("totalsRowShown", &table.get_totals_row_shown_str()));
         }
+        let header_row_count_str = table.get_header_row_count_str();
+        if table.has_header_row_count() {
+            attributes.push(("headerRowCount", &header_row_count_str));
+        }
         let totals_row_count_str = table.get_totals_row_count_str();
         if table.has_totals_row_count() {
             attributes.push(("totalsRowCount", &totals_row_count_str));
@@ -51,7 +55,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         write_start_tag(&mut writer, "table", attributes, false);
 
         // autoFilter
-        write_start_tag(&mut writer, "autoFilter", vec![("ref", &area)], true);
+        if table.get_header_row_count() > 0 {
+            write_start_tag(&mut writer, "autoFilter", vec![("ref", &area)], true);
+        }
 
         // tableColumns
         let cols = table.get_columns();
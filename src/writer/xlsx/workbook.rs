@@ -121,10 +121,17 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     );
 
     // pivotCaches
-    let pivot_cache_definition_collection = spreadsheet.get_pivot_caches();
-    if !pivot_cache_definition_collection.is_empty() {
+    let mut pivot_cache_id_list: Vec<String> = spreadsheet
+        .get_pivot_caches()
+        .into_iter()
+        .map(|(_, val2, _)| val2)
+        .collect();
+    for (cache_id, _) in writer_mng.get_pivot_caches() {
+        pivot_cache_id_list.push(cache_id.clone());
+    }
+    if !pivot_cache_id_list.is_empty() {
         write_start_tag(&mut writer, "pivotCaches", vec![], false);
-        for (_, val2, _) in pivot_cache_definition_collection {
+        for val2 in pivot_cache_id_list {
             let r_id = format!("rId{}", index);
             write_start_tag(
                 &mut writer,
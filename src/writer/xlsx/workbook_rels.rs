@@ -48,6 +48,16 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
         index += 1;
     }
+    for (_, pivot_cache_definition) in writer_mng.get_pivot_caches() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            PIVOT_CACHE_DEF_NS,
+            pivot_cache_definition,
+            "",
+        );
+        index += 1;
+    }
 
     // relationship styles.xml
     write_relationship(&mut writer, &index.to_string(), STYLES_NS, "styles.xml", "");
@@ -84,6 +94,18 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             "vbaProject.bin",
             "",
         );
+        index += 1;
+    }
+
+    // relationship persons/person.xml
+    if !spreadsheet.get_persons().is_empty() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            PERSON_NS,
+            "persons/person.xml",
+            "",
+        );
     }
 
     write_end_tag(&mut writer, root_tag_name);
@@ -31,75 +31,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     )));
     write_new_line(&mut writer);
 
-    // worksheet
-    write_start_tag(
-        &mut writer,
-        "worksheet",
-        vec![
-            ("xmlns", SHEET_MAIN_NS),
-            ("xmlns:r", REL_OFC_NS),
-            ("xmlns:xdr", SHEET_DRAWING_NS),
-            ("xmlns:x14", SHEET_MS_MAIN_NS),
-            ("xmlns:mc", MC_NS),
-            ("mc:Ignorable", "x14ac"),
-            ("xmlns:x14ac", SHEETML_AC_NS),
-        ],
-        false,
-    );
-
-    // sheetPr
-    let mut attributes: Vec<(&str, &str)> = Vec::new();
-    if has_macros {
-        let code_name = match worksheet.has_code_name() {
-            true => worksheet.get_code_name().as_ref().unwrap(),
-            false => worksheet.get_name(),
-        };
-        attributes.push(("codeName", code_name));
-    }
-
-    // tabColor
-    match worksheet.get_tab_color() {
-        Some(v) => {
-            write_start_tag(&mut writer, "sheetPr", attributes, false);
-            v.write_to_tab_color(&mut writer);
-            write_end_tag(&mut writer, "sheetPr");
-        }
-        None => {
-            if !attributes.is_empty() {
-                write_start_tag(&mut writer, "sheetPr", attributes, true);
-            }
-        }
-    }
-
-    // outlinePr
-    //write_start_tag(&mut writer, "outlinePr", vec![
-    //    ("summaryBelow", if worksheet.show_summary_below {"1"} else {"0"}),
-    //    ("summaryRight", if worksheet.show_summary_right {"1"} else {"0"}),
-    //], true);
-
-    // dimension
-    write_start_tag(
-        &mut writer,
-        "dimension",
-        vec![("ref", &worksheet.calculate_worksheet_dimension())],
-        true,
-    );
-
-    // sheetViews
-    worksheet.get_sheets_views().write_to(&mut writer);
-
-    // sheetFormatPr
-    worksheet
-        .get_sheet_format_properties()
-        .write_to(&mut writer);
-
-    // cols
-    let mut column_dimensions = worksheet.get_column_dimensions_crate().clone();
-    column_dimensions.calculation_auto_width(
-        worksheet.get_cell_collection_crate(),
-        worksheet.get_merge_cells_crate(),
-    );
-    column_dimensions.write_to(&mut writer, stylesheet);
+    write_head(&mut writer, worksheet, stylesheet, has_macros, true);
 
     // sheetData
     let has_sheet_data = worksheet.has_sheet_data();
@@ -164,7 +96,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             for cell in cells_in_row {
                 cell.write_to(
                     &mut writer,
-                    &shared_string_table,
+                    Some(shared_string_table),
                     stylesheet,
                     &formula_shared_list,
                 );
@@ -178,42 +110,133 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         write_end_tag(&mut writer, "sheetData");
     }
 
+    write_tail(&mut writer, worksheet, stylesheet);
+
+    let target = format!("{PKG_SHEET}{}.xml", sheet_no);
+    writer_mng.add_writer(&target, writer)
+}
+
+/// Write the part of the worksheet that precedes `sheetData`.
+pub(crate) fn write_head(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    worksheet: &Worksheet,
+    stylesheet: &mut Stylesheet,
+    has_macros: bool,
+    with_dimension: bool,
+) {
+    // worksheet
+    write_start_tag(
+        writer,
+        "worksheet",
+        vec![
+            ("xmlns", SHEET_MAIN_NS),
+            ("xmlns:r", REL_OFC_NS),
+            ("xmlns:xdr", SHEET_DRAWING_NS),
+            ("xmlns:x14", SHEET_MS_MAIN_NS),
+            ("xmlns:mc", MC_NS),
+            ("mc:Ignorable", "x14ac"),
+            ("xmlns:x14ac", SHEETML_AC_NS),
+        ],
+        false,
+    );
+
+    // sheetPr
+    let mut attributes: Vec<(&str, &str)> = Vec::new();
+    if has_macros {
+        let code_name = match worksheet.has_code_name() {
+            true => worksheet.get_code_name().as_ref().unwrap(),
+            false => worksheet.get_name(),
+        };
+        attributes.push(("codeName", code_name));
+    }
+
+    let has_inner =
+        worksheet.get_tab_color().is_some() || worksheet.get_outline_properties().has_param();
+    if has_inner {
+        write_start_tag(writer, "sheetPr", attributes, false);
+
+        // tabColor
+        if let Some(v) = worksheet.get_tab_color() {
+            v.write_to_tab_color(writer);
+        }
+
+        // outlinePr
+        if worksheet.get_outline_properties().has_param() {
+            worksheet.get_outline_properties().write_to(writer);
+        }
+
+        write_end_tag(writer, "sheetPr");
+    } else if !attributes.is_empty() {
+        write_start_tag(writer, "sheetPr", attributes, true);
+    }
+
+    // dimension
+    if with_dimension {
+        write_start_tag(
+            writer,
+            "dimension",
+            vec![("ref", &worksheet.calculate_worksheet_dimension())],
+            true,
+        );
+    }
+
+    // sheetViews
+    worksheet.get_sheets_views().write_to(writer);
+
+    // sheetFormatPr
+    worksheet.get_sheet_format_properties().write_to(writer);
+
+    // cols
+    let mut column_dimensions = worksheet.get_column_dimensions_crate().clone();
+    column_dimensions.calculation_auto_width(
+        worksheet.get_cell_collection_crate(),
+        worksheet.get_merge_cells_crate(),
+    );
+    column_dimensions.write_to(writer, stylesheet);
+}
+
+/// Write the part of the worksheet that follows `sheetData`.
+pub(crate) fn write_tail(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    worksheet: &Worksheet,
+    stylesheet: &mut Stylesheet,
+) {
     // sheetProtection
     if let Some(v) = worksheet.get_sheet_protection() {
-        v.write_to(&mut writer);
+        v.write_to(writer);
     }
 
     // autoFilter
     if let Some(v) = worksheet.get_auto_filter() {
-        write_start_tag(
-            &mut writer,
-            "autoFilter",
-            vec![("ref", &v.get_range().get_range())],
-            true,
-        );
+        v.write_to(writer, stylesheet.get_differential_formats_mut());
+    }
+
+    // sortState
+    if let Some(v) = worksheet.get_sort_state() {
+        v.write_to(writer, stylesheet.get_differential_formats_mut());
     }
 
     // mergeCells
-    worksheet.get_merge_cells_crate().write_to(&mut writer);
+    worksheet.get_merge_cells_crate().write_to(writer);
 
     // phoneticPr
-    write_start_tag(&mut writer, "phoneticPr", vec![("fontId", "1")], true);
+    write_start_tag(writer, "phoneticPr", vec![("fontId", "1")], true);
 
     // conditionalFormatting
     for conditional_formatting in worksheet.get_conditional_formatting_collection() {
-        conditional_formatting.write_to(&mut writer, stylesheet.get_differential_formats_mut());
+        conditional_formatting.write_to(writer, stylesheet.get_differential_formats_mut());
     }
 
     // dataValidations
     if let Some(v) = worksheet.get_data_validations() {
-        v.write_to(&mut writer);
+        v.write_to(writer);
     }
 
     let mut r_id = 1;
 
     // hyperlinks
     if worksheet.has_hyperlink() {
-        write_start_tag(&mut writer, "hyperlinks", vec![], false);
+        write_start_tag(writer, "hyperlinks", vec![], false);
 
         // hyperlink
         for (coordition, hyperlink) in worksheet.get_hyperlink_collection_to_hashmap() {
@@ -226,48 +249,43 @@ pub(crate) fn write<W: io::Seek + io::Write>(
                 attributes.push(("r:id", &r_id_str));
                 r_id += 1;
             }
-            write_start_tag(&mut writer, "hyperlink", attributes, true);
+            write_start_tag(writer, "hyperlink", attributes, true);
         }
 
-        write_end_tag(&mut writer, "hyperlinks");
+        write_end_tag(writer, "hyperlinks");
     }
 
     // printOptions
-    worksheet.get_print_options().write_to(&mut writer);
+    worksheet.get_print_options().write_to(writer);
 
     // pageMargins
-    worksheet.get_page_margins().write_to(&mut writer);
+    worksheet.get_page_margins().write_to(writer);
 
     // pageSetup
     if worksheet.get_page_setup().has_param() {
-        worksheet.get_page_setup().write_to(&mut writer, &mut r_id);
+        worksheet.get_page_setup().write_to(writer, &mut r_id);
     }
 
     // headerFooter
-    worksheet.get_header_footer().write_to(&mut writer);
+    worksheet.get_header_footer().write_to(writer);
 
     // rowBreaks
-    worksheet.get_row_breaks().write_to(&mut writer);
+    worksheet.get_row_breaks().write_to(writer);
 
     // colBreaks
-    worksheet.get_column_breaks().write_to(&mut writer);
+    worksheet.get_column_breaks().write_to(writer);
 
     if worksheet.has_drawing_object() {
         // drawing
         let r_id_str = format!("rId{}", &r_id);
-        write_start_tag(&mut writer, "drawing", vec![("r:id", &r_id_str)], true);
+        write_start_tag(writer, "drawing", vec![("r:id", &r_id_str)], true);
         r_id += 1;
     }
 
     // legacyDrawing
     if worksheet.has_legacy_drawing() {
         let r_id_str = format!("rId{}", &r_id);
-        write_start_tag(
-            &mut writer,
-            "legacyDrawing",
-            vec![("r:id", &r_id_str)],
-            true,
-        );
+        write_start_tag(writer, "legacyDrawing", vec![("r:id", &r_id_str)], true);
         r_id += 1;
     }
 
@@ -275,39 +293,39 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     if worksheet.has_table() {
         let tables = worksheet.get_tables();
         write_start_tag(
-            &mut writer,
+            writer,
             "tableParts",
             vec![("count", &tables.len().to_string())],
             false,
         );
         for table in worksheet.get_tables().iter() {
             let r_id_str = format!("rId{}", &r_id);
-            write_start_tag(&mut writer, "tablePart", vec![("r:id", &r_id_str)], true);
+            write_start_tag(writer, "tablePart", vec![("r:id", &r_id_str)], true);
             r_id += 1;
         }
-        write_end_tag(&mut writer, "tableParts");
+        write_end_tag(writer, "tableParts");
     }
 
     // oleObjects
     let ole_id = 1000 + 25;
-    worksheet
-        .get_ole_objects()
-        .write_to(&mut writer, &r_id, &ole_id);
+    worksheet.get_ole_objects().write_to(writer, &r_id, &ole_id);
 
     // extLst
-    if worksheet.get_data_validations_2010().is_some() {
-        write_start_tag(&mut writer, "extLst", vec![], false);
+    if worksheet.get_data_validations_2010().is_some()
+        || worksheet.get_sparkline_groups().has_param()
+    {
+        write_start_tag(writer, "extLst", vec![], false);
         match worksheet.get_data_validations_2010() {
             Some(v) => {
-                v.write_to(&mut writer);
+                v.write_to(writer);
             }
             None => {}
         }
-        write_end_tag(&mut writer, "extLst");
+        if worksheet.get_sparkline_groups().has_param() {
+            worksheet.get_sparkline_groups().write_to(writer);
+        }
+        write_end_tag(writer, "extLst");
     }
 
-    write_end_tag(&mut writer, "worksheet");
-
-    let target = format!("{PKG_SHEET}{}.xml", sheet_no);
-    writer_mng.add_writer(&target, writer)
+    write_end_tag(writer, "worksheet");
 }
@@ -15,10 +15,12 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     drawing_no: &str,
     vml_drawing_no: &str,
     comment_no: &str,
+    threaded_comment_no: &str,
     ole_object_no_list: &[String],
     excel_no_list: &[String],
     printer_settings_no: &str,
     table_no_list: &[String],
+    pivot_table_no_list: &[String],
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let mut is_write = false;
@@ -152,6 +154,35 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             format!("../comments{}.xml", comment_no).as_str(),
             "",
         );
+        r_id += 1;
+    }
+
+    // Write threaded comments relationship
+    if worksheet.has_threaded_comments() {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id.to_string(),
+            THREADED_COMMENT_NS,
+            format!(
+                "../threadedComments/threadedComment{}.xml",
+                threaded_comment_no
+            )
+            .as_str(),
+            "",
+        );
+        r_id += 1;
+    }
+
+    // write pivot table relationships (not referenced from the sheet xml)
+    for pivot_table_no in pivot_table_no_list.iter() {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id.to_string(),
+            PIVOT_TABLE_NS,
+            format!("../pivotTables/pivotTable{}.xml", pivot_table_no).as_str(),
+            "",
+        );
+        r_id += 1;
     }
 
     write_end_tag(&mut writer, "Relationships");
@@ -52,6 +52,45 @@ fn wite_with_password() {
     let _ = umya_spreadsheet::writer::xlsx::set_password(&from_path, &to_path, "password");
 }
 
+#[test]
+fn read_with_password() {
+    // agile encryption
+    let from_path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let path = std::path::Path::new("./tests/result_files/bbb_password3.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::set_password(&from_path, &path, "password");
+    let book = umya_spreadsheet::reader::xlsx::read_with_password(path, "password").unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("B5"), " ");
+    let result = umya_spreadsheet::reader::xlsx::read_with_password(path, "wrong");
+    assert!(matches!(result, Err(XlsxError::WrongPassword)));
+
+    // tampered package fails the data integrity check
+    let tampered_path = std::path::Path::new("./tests/result_files/bbb_password3_tampered.xlsx");
+    std::fs::copy(path, tampered_path).unwrap();
+    {
+        use std::io::{Read, Seek, SeekFrom, Write};
+        let mut comp = cfb::open_rw(tampered_path).unwrap();
+        let mut stream = comp.open_stream("EncryptedPackage").unwrap();
+        let mut byte = [0u8; 1];
+        stream.seek(SeekFrom::End(-1)).unwrap();
+        stream.read_exact(&mut byte).unwrap();
+        stream.seek(SeekFrom::End(-1)).unwrap();
+        stream.write_all(&[byte[0] ^ 0xff]).unwrap();
+    }
+    let result = umya_spreadsheet::reader::xlsx::read_with_password(tampered_path, "password");
+    assert!(matches!(result, Err(XlsxError::Decryption(_))));
+
+    // standard encryption
+    let path = std::path::Path::new("./tests/test_files/aaa_password_standard.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read_with_password(path, "password").unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("B5"), " ");
+    let result = umya_spreadsheet::reader::xlsx::read_with_password(path, "wrong");
+    assert!(matches!(result, Err(XlsxError::WrongPassword)));
+
+    // not encrypted
+    let book = umya_spreadsheet::reader::xlsx::read_with_password(from_path, "password").unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("B5"), " ");
+}
+
 #[test]
 fn lazy_read_and_wite() {
     // reader
@@ -1156,6 +1195,908 @@ fn move_range_test() {
     let _ = umya_spreadsheet::writer::xlsx::write_light(&book, path);
 }
 
+#[test]
+fn sort_range_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+
+    // header
+    sheet.get_cell_mut("A1").set_value("Name");
+    sheet.get_cell_mut("B1").set_value("Group");
+    sheet.get_cell_mut("C1").set_value("Score");
+    sheet.get_cell_mut("D1").set_value("Double");
+    for (row, (name, group, score)) in [
+        ("d", "Low", 3.0),
+        ("a", "High", 5.0),
+        ("c", "Low", 1.0),
+        ("b", "High", 2.0),
+        ("e", "Medium", 4.0),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let row = row as u32 + 2;
+        sheet.get_cell_mut((1, row)).set_value(*name);
+        sheet.get_cell_mut((2, row)).set_value(*group);
+        sheet.get_cell_mut((3, row)).set_value_number(*score);
+        sheet
+            .get_cell_mut((4, row))
+            .set_formula(format!("C{}*2", row));
+    }
+    sheet
+        .get_style_mut("A3")
+        .set_background_color(umya_spreadsheet::Color::COLOR_YELLOW);
+    sheet
+        .get_cell_mut("A3")
+        .get_hyperlink_mut()
+        .set_url("https://example.com/a");
+    let mut comment = Comment::default();
+    comment.new_comment("A3");
+    comment.set_text_string("note of a");
+    sheet.add_comments(comment);
+    // outside of the range.
+    sheet.get_cell_mut("F2").set_value("fixed");
+
+    // group by custom list, then score descending.
+    let mut group = umya_spreadsheet::SortKey::default();
+    group
+        .set_column(2)
+        .set_custom_list(vec!["High", "Medium", "Low"]);
+    let mut score = umya_spreadsheet::SortKey::default();
+    score.set_column(3).set_descending(true);
+    let mut option = umya_spreadsheet::SortOption::default();
+    option.set_save_sort_state(true);
+    sheet
+        .sort_range_with_option("A1:D6", &[group, score], &option)
+        .unwrap();
+
+    let names: Vec<String> = (1..=6).map(|row| sheet.get_value((1, row))).collect();
+    assert_eq!(names, vec!["Name", "a", "b", "e", "d", "c"]);
+    assert_eq!(sheet.get_value("F2"), "fixed");
+    assert_eq!(sheet.get_cell("D2").unwrap().get_formula(), "C2*2");
+    assert_eq!(sheet.get_cell("D5").unwrap().get_formula(), "C5*2");
+    assert_eq!(
+        sheet
+            .get_style("A2")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_YELLOW
+    );
+    assert_eq!(
+        sheet
+            .get_cell("A2")
+            .unwrap()
+            .get_hyperlink()
+            .unwrap()
+            .get_url(),
+        "https://example.com/a"
+    );
+    assert!(sheet.get_cell("A3").unwrap().get_hyperlink().is_none());
+    assert_eq!(
+        sheet.get_comments()[0].get_coordinate().get_coordinate(),
+        "A2"
+    );
+
+    let sort_state = sheet.get_sort_state().unwrap();
+    assert_eq!(sort_state.get_reference().get_range(), "A2:D6");
+    assert_eq!(sort_state.get_sort_condition_list().len(), 2);
+    assert_eq!(
+        sort_state.get_sort_condition_list()[0].get_custom_list(),
+        "High,Medium,Low"
+    );
+    assert!(*sort_state.get_sort_condition_list()[1].get_descending());
+
+    // cell color on top, the header is given explicitly.
+    let mut color = umya_spreadsheet::SortKey::default();
+    let mut yellow = umya_spreadsheet::Color::default();
+    yellow.set_argb(umya_spreadsheet::Color::COLOR_YELLOW);
+    color
+        .set_column(1)
+        .set_sort_by(umya_spreadsheet::SortByValues::CellColor)
+        .set_color(yellow);
+    let mut name = umya_spreadsheet::SortKey::default();
+    name.set_column(1).set_descending(true);
+    let mut option = umya_spreadsheet::SortOption::default();
+    option.set_has_header(true).set_save_sort_state(true);
+    sheet
+        .sort_range_with_option("A:D", &[color, name], &option)
+        .unwrap();
+    let names: Vec<String> = (1..=6).map(|row| sheet.get_value((1, row))).collect();
+    assert_eq!(names, vec!["Name", "a", "e", "d", "c", "b"]);
+
+    // case sensitive values without a header.
+    let sheet = book.new_sheet("Sheet2").unwrap();
+    sheet.get_cell_mut("A1").set_value("b");
+    sheet.get_cell_mut("A2").set_value("B");
+    sheet.get_cell_mut("A3").set_value_number(1);
+    sheet.get_cell_mut("A5").set_value("a");
+    let mut key = umya_spreadsheet::SortKey::default();
+    key.set_column(1).set_case_sensitive(true);
+    sheet.sort_range("A1:A5", &[key]).unwrap();
+    let values: Vec<String> = (1..=5).map(|row| sheet.get_value((1, row))).collect();
+    assert_eq!(values, vec!["1", "a", "b", "B", ""]);
+
+    // invalid ranges and keys outside the range are rejected.
+    let mut key = umya_spreadsheet::SortKey::default();
+    key.set_column(3);
+    assert!(sheet.sort_range("A1:B5", &[key.clone()]).is_err());
+    assert!(sheet.sort_range("A1:C", &[key]).is_err());
+
+    let path = std::path::Path::new("./tests/result_files/sort_range.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sort_state = book.get_sheet(&0).unwrap().get_sort_state().unwrap();
+    assert_eq!(sort_state.get_reference().get_range(), "A2:D6");
+    let condition = &sort_state.get_sort_condition_list()[0];
+    assert_eq!(
+        condition.get_sort_by(),
+        &umya_spreadsheet::SortByValues::CellColor
+    );
+    assert_eq!(condition.get_reference().get_range(), "A2:A6");
+    assert_eq!(
+        condition
+            .get_style()
+            .unwrap()
+            .get_fill()
+            .unwrap()
+            .get_pattern_fill()
+            .unwrap()
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_YELLOW
+    );
+}
+
+#[test]
+fn auto_filter_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+
+    sheet.get_cell_mut("A1").set_value("Fruit");
+    sheet.get_cell_mut("B1").set_value("Price");
+    sheet.get_cell_mut("C1").set_value("Note");
+    for (row, (fruit, price, note)) in [
+        ("Apple", 120.0, "fresh"),
+        ("Banana", 80.0, "ripe"),
+        ("apple", 300.0, "imported"),
+        ("Cherry", 500.0, "fresh"),
+        ("Melon", 1000.0, ""),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let row = row as u32 + 2;
+        sheet.get_cell_mut((1, row)).set_value(*fruit);
+        sheet.get_cell_mut((2, row)).set_value_number(*price);
+        if !note.is_empty() {
+            sheet.get_cell_mut((3, row)).set_value(*note);
+        }
+    }
+    sheet
+        .get_style_mut("C5")
+        .set_background_color(umya_spreadsheet::Color::COLOR_YELLOW);
+    sheet.set_auto_filter("A1:C6");
+
+    let hidden_rows = |sheet: &umya_spreadsheet::Worksheet| -> Vec<u32> {
+        (2..=6)
+            .filter(|row| {
+                sheet
+                    .get_row_dimension(row)
+                    .map_or(false, |v| *v.get_hidden())
+            })
+            .collect()
+    };
+
+    // value list, without case.
+    let mut filters = umya_spreadsheet::Filters::default();
+    filters.add_value("Apple").add_value("Cherry");
+    sheet
+        .get_auto_filter_mut()
+        .unwrap()
+        .get_filter_column_mut(&0)
+        .set_filters(filters);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![3, 6]);
+
+    // custom filter on a second column, combined with the first one.
+    let mut custom_filters = umya_spreadsheet::CustomFilters::default();
+    let mut custom_filter = umya_spreadsheet::CustomFilter::default();
+    custom_filter
+        .set_operator(umya_spreadsheet::FilterOperatorValues::GreaterThanOrEqual)
+        .set_val("200");
+    custom_filters.add_custom_filter(custom_filter);
+    sheet
+        .get_auto_filter_mut()
+        .unwrap()
+        .get_filter_column_mut(&1)
+        .set_custom_filters(custom_filters);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 6]);
+
+    // top 2 items replace the custom filter.
+    let mut top10 = umya_spreadsheet::Top10::default();
+    top10.set_val(2.0);
+    sheet
+        .get_auto_filter_mut()
+        .unwrap()
+        .remove_filter_column(&0)
+        .get_filter_column_mut(&1)
+        .set_top10(top10);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 4]);
+
+    // wildcards and blanks.
+    let mut custom_filters = umya_spreadsheet::CustomFilters::default();
+    let mut custom_filter = umya_spreadsheet::CustomFilter::default();
+    custom_filter.set_val("fr*");
+    custom_filters.add_custom_filter(custom_filter);
+    let mut custom_filter = umya_spreadsheet::CustomFilter::default();
+    custom_filter.set_val("");
+    custom_filters.add_custom_filter(custom_filter);
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.remove_filter_column(&1);
+    auto_filter
+        .get_filter_column_mut(&2)
+        .set_custom_filters(custom_filters);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![3, 4]);
+
+    // above average.
+    let mut dynamic_filter = umya_spreadsheet::DynamicFilter::default();
+    dynamic_filter.set_type(umya_spreadsheet::DynamicFilterValues::AboveAverage);
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.remove_filter_column(&2);
+    auto_filter
+        .get_filter_column_mut(&1)
+        .set_dynamic_filter(dynamic_filter);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 4]);
+
+    // cell color.
+    let mut style = umya_spreadsheet::Style::default();
+    style.set_background_color(umya_spreadsheet::Color::COLOR_YELLOW);
+    let mut color_filter = umya_spreadsheet::ColorFilter::default();
+    color_filter.set_style(style);
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.remove_filter_column(&1);
+    auto_filter
+        .get_filter_column_mut(&2)
+        .set_color_filter(color_filter);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 4, 6]);
+
+    let mut filters = umya_spreadsheet::Filters::default();
+    filters.add_value("Banana").set_blank(true);
+    sheet
+        .get_auto_filter_mut()
+        .unwrap()
+        .get_filter_column_mut(&0)
+        .set_filters(filters);
+
+    let path = std::path::Path::new("./tests/result_files/auto_filter.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    let auto_filter = sheet.get_auto_filter().unwrap();
+    assert_eq!(auto_filter.get_range().get_range(), "A1:C6");
+    assert_eq!(auto_filter.get_filter_column_list().len(), 2);
+    let filters = auto_filter
+        .get_filter_column(&0)
+        .unwrap()
+        .get_filters()
+        .unwrap();
+    assert!(*filters.get_blank());
+    assert_eq!(filters.get_filter_list()[0].get_val(), "Banana");
+    assert!(auto_filter
+        .get_filter_column(&2)
+        .unwrap()
+        .get_color_filter()
+        .unwrap()
+        .get_style()
+        .is_some());
+
+    // the color filter still matches after the dxf round trip.
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 4, 5, 6]);
+
+    // the column ids follow an inserted column.
+    sheet.insert_new_column("B", &1);
+    let auto_filter = sheet.get_auto_filter().unwrap();
+    assert_eq!(auto_filter.get_range().get_range(), "A1:D6");
+    assert!(auto_filter.get_filter_column(&0).is_some());
+    assert!(auto_filter.get_filter_column(&3).is_some());
+}
+
+#[test]
+fn auto_filter_date_serial_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value("Date");
+    // 2023-03-15, then serials outside the range of Excel dates.
+    sheet.get_cell_mut("A2").set_value_number(45000);
+    sheet.get_cell_mut("A3").set_value_number(1e12);
+    sheet.get_cell_mut("A4").set_value_number(-1);
+    sheet.set_auto_filter("A1:A4");
+
+    let hidden_rows = |sheet: &umya_spreadsheet::Worksheet| -> Vec<u32> {
+        (2..=4)
+            .filter(|row| {
+                sheet
+                    .get_row_dimension(row)
+                    .map_or(false, |v| *v.get_hidden())
+            })
+            .collect()
+    };
+
+    let mut date_group_item = umya_spreadsheet::DateGroupItem::default();
+    date_group_item
+        .set_year(2023)
+        .set_date_time_grouping(umya_spreadsheet::DateTimeGroupingValues::Year);
+    let mut filters = umya_spreadsheet::Filters::default();
+    filters.add_date_group_item(date_group_item);
+    sheet
+        .get_auto_filter_mut()
+        .unwrap()
+        .get_filter_column_mut(&0)
+        .set_filters(filters);
+    sheet.apply_auto_filter();
+    assert_eq!(hidden_rows(sheet), vec![3, 4]);
+
+    let mut dynamic_filter = umya_spreadsheet::DynamicFilter::default();
+    dynamic_filter.set_type(umya_spreadsheet::DynamicFilterValues::ThisYear);
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.remove_filter_column(&0);
+    auto_filter
+        .get_filter_column_mut(&0)
+        .set_dynamic_filter(dynamic_filter);
+    sheet.apply_auto_filter();
+    assert!(hidden_rows(sheet).ends_with(&[3, 4]));
+
+    // a column id past the last column is ignored.
+    let mut filters = umya_spreadsheet::Filters::default();
+    filters.add_value("none");
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.remove_filter_column(&0);
+    auto_filter
+        .get_filter_column_mut(&u32::MAX)
+        .set_filters(filters);
+    sheet.apply_auto_filter();
+    assert!(hidden_rows(sheet).is_empty());
+}
+
+#[test]
+fn outline_group_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for row in 1..=8 {
+        sheet.get_cell_mut((1, row)).set_value_number(row);
+    }
+
+    // rows 2..=6 summarized by 7, rows 3..=4 summarized by 5.
+    sheet.group_rows(&2, &6).group_rows(&3, &4);
+    let levels: Vec<u8> = (1..=7)
+        .map(|row| {
+            sheet
+                .get_row_dimension(&row)
+                .map_or(0, |v| *v.get_outline_level())
+        })
+        .collect();
+    assert_eq!(levels, vec![0, 1, 2, 2, 1, 1, 0]);
+    assert_eq!(
+        sheet.get_sheet_format_properties().get_outline_level_row(),
+        &2
+    );
+
+    let hidden_rows = |sheet: &umya_spreadsheet::Worksheet| -> Vec<u32> {
+        (1..=8)
+            .filter(|row| {
+                sheet
+                    .get_row_dimension(row)
+                    .map_or(false, |v| *v.get_hidden())
+            })
+            .collect()
+    };
+    sheet.collapse_rows(&3, &4);
+    assert!(*sheet.get_row_dimension(&5).unwrap().get_collapsed());
+    sheet.collapse_rows(&2, &6);
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 4, 5, 6]);
+    // the nested group stays collapsed.
+    sheet.expand_rows(&2, &6);
+    assert_eq!(hidden_rows(sheet), vec![3, 4]);
+    assert!(!*sheet.get_row_dimension(&7).unwrap().get_collapsed());
+    sheet.expand_rows(&3, &4);
+    assert!(hidden_rows(sheet).is_empty());
+
+    sheet.ungroup_rows(&3, &4);
+    assert_eq!(
+        sheet.get_sheet_format_properties().get_outline_level_row(),
+        &1
+    );
+
+    // columns with the summary on the left.
+    sheet.get_outline_properties_mut().set_summary_right(false);
+    sheet.group_columns("C", "E");
+    sheet.collapse_columns("C", "E");
+    assert!(*sheet.get_column_dimension("B").unwrap().get_collapsed());
+    assert!(*sheet.get_column_dimension("D").unwrap().get_hidden());
+    sheet.collapse_rows(&2, &6);
+
+    let path = std::path::Path::new("./tests/result_files/outline_group.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    assert!(*sheet.get_outline_properties().get_summary_below());
+    assert!(!*sheet.get_outline_properties().get_summary_right());
+    assert_eq!(
+        sheet
+            .get_sheet_format_properties()
+            .get_outline_level_column(),
+        &1
+    );
+    assert_eq!(sheet.get_row_dimension(&4).unwrap().get_outline_level(), &1);
+    assert!(*sheet.get_row_dimension(&7).unwrap().get_collapsed());
+    assert_eq!(hidden_rows(sheet), vec![2, 3, 4, 5, 6]);
+    let column = sheet.get_column_dimension("E").unwrap();
+    assert_eq!(column.get_outline_level(), &1);
+    assert!(*column.get_hidden());
+    assert!(*sheet.get_column_dimension("B").unwrap().get_collapsed());
+
+    sheet.expand_columns("C", "E");
+    assert!(!*sheet.get_column_dimension("C").unwrap().get_hidden());
+    assert!(!*sheet.get_column_dimension("B").unwrap().get_collapsed());
+}
+
+#[test]
+fn sparkline_test() {
+    use umya_spreadsheet::structs::office2010::excel::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for row in 1..=3 {
+        for col in 1..=5 {
+            sheet.get_cell_mut((col, row)).set_value_number(row * col);
+        }
+    }
+
+    let mut group = SparklineGroup::default();
+    group
+        .set_type(SparklineTypeValues::Column)
+        .set_high(true)
+        .set_negative(true)
+        .set_display_empty_cells_as(umya_spreadsheet::drawing::charts::DisplayBlanksAsValues::Gap)
+        .set_manual_max(20.0);
+    group.get_color_high_mut().set_argb("FFFF0000");
+    for row in 1..=3 {
+        let mut sparkline = Sparkline::default();
+        sparkline
+            .set_data_range(format!("Sheet1!A{0}:E{0}", row))
+            .set_location(format!("F{}", row));
+        group.add_sparkline(sparkline);
+    }
+    sheet.add_sparkline_group(group);
+
+    // a sparkline on another sheet pointing at the first one.
+    let sheet = book.new_sheet("Sheet2").unwrap();
+    let mut sparkline = Sparkline::default();
+    sparkline.set_data_range("Sheet1!A1:E1").set_location("B2");
+    let mut group = SparklineGroup::default();
+    group.set_markers(true).add_sparkline(sparkline);
+    sheet.add_sparkline_group(group);
+
+    let path = std::path::Path::new("./tests/result_files/sparkline.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let groups = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_sparkline_groups()
+        .get_sparkline_group_list();
+    assert_eq!(groups.len(), 1);
+    let group = &groups[0];
+    assert_eq!(group.get_type(), &SparklineTypeValues::Column);
+    assert!(*group.get_high());
+    assert!(*group.get_negative());
+    assert!(!*group.get_markers());
+    assert_eq!(group.get_manual_max(), &20.0);
+    assert_eq!(
+        group.get_max_axis_type(),
+        &SparklineAxisMinMaxValues::Custom
+    );
+    assert_eq!(group.get_color_high().get_argb(), "FFFF0000");
+    assert_eq!(group.get_color_negative().get_theme_index(), &5);
+    assert_eq!(group.get_sparkline_list().len(), 3);
+    assert_eq!(
+        group.get_sparkline_list()[1].get_data_range(),
+        "Sheet1!A2:E2"
+    );
+    assert_eq!(group.get_sparkline_list()[1].get_location(), "F2");
+
+    // the ranges follow inserted rows and columns.
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.insert_new_row(&1, &1);
+    sheet.insert_new_column("C", &2);
+    let sparkline =
+        &sheet.get_sparkline_groups().get_sparkline_group_list()[0].get_sparkline_list()[0];
+    assert_eq!(sparkline.get_data_range(), "Sheet1!A2:G2");
+    assert_eq!(sparkline.get_location(), "H2");
+
+    // removing the row of a location removes the sparkline.
+    sheet.remove_row(&2, &1);
+    let group = &sheet.get_sparkline_groups().get_sparkline_group_list()[0];
+    assert_eq!(group.get_sparkline_list().len(), 2);
+    assert_eq!(group.get_sparkline_list()[0].get_location(), "H2");
+}
+
+#[test]
+fn threaded_comment_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut alice = umya_spreadsheet::Person::new("Alice");
+    alice.set_user_id("alice@example.com").set_provider_id("AD");
+    let bob = umya_spreadsheet::Person::new("Bob");
+
+    let mut comment =
+        umya_spreadsheet::ThreadedComment::new("B2", alice.get_id(), "Please check @Bob");
+    let mut mention = umya_spreadsheet::ThreadedCommentMention::default();
+    mention
+        .set_mention_person_id(bob.get_id())
+        .set_mention_id("{00000000-0000-4000-8000-000000000001}")
+        .set_start_index(13)
+        .set_length(4);
+    comment.add_mention(mention).set_done(true);
+    comment.add_reply(umya_spreadsheet::ThreadedComment::new(
+        "A1",
+        bob.get_id(),
+        "Fixed & checked",
+    ));
+    let thread_id = comment.get_id().to_string();
+
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.add_threaded_comment(comment);
+    assert_eq!(sheet.get_comments().len(), 1);
+    assert_eq!(
+        sheet.get_comments()[0].get_author(),
+        format!("tc={}", thread_id)
+    );
+    book.add_person(alice);
+    book.add_person(bob);
+
+    let path = std::path::Path::new("./tests/result_files/threaded_comment.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_persons().len(), 2);
+    let alice = book
+        .get_persons()
+        .iter()
+        .find(|v| v.get_display_name() == "Alice")
+        .unwrap();
+    assert_eq!(alice.get_user_id(), "alice@example.com");
+    assert_eq!(alice.get_provider_id(), "AD");
+    let alice_id = alice.get_id().to_string();
+
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_threaded_comments().len(), 1);
+    let comment = sheet.get_threaded_comment("B2").unwrap();
+    assert_eq!(comment.get_id(), thread_id);
+    assert_eq!(comment.get_person_id(), alice_id);
+    assert_eq!(comment.get_text(), "Please check @Bob");
+    assert!(comment.get_done());
+    assert!(!comment.get_date_time().is_empty());
+    assert_eq!(comment.get_mentions().len(), 1);
+    assert_eq!(comment.get_mentions()[0].get_start_index(), &13);
+    assert_eq!(comment.get_mentions()[0].get_length(), &4);
+    assert_eq!(comment.get_replies().len(), 1);
+    let reply = &comment.get_replies()[0];
+    assert_eq!(reply.get_text(), "Fixed & checked");
+    assert_eq!(reply.get_coordinate().to_string(), "B2");
+    assert_eq!(
+        book.get_person(reply.get_person_id())
+            .unwrap()
+            .get_display_name(),
+        "Bob"
+    );
+
+    // the legacy note carries the whole thread.
+    let note = &sheet.get_comments()[0];
+    assert_eq!(note.get_coordinate().to_string(), "B2");
+    let text = note.get_text().get_text();
+    assert!(text.starts_with("[Threaded comment]"));
+    assert!(text.contains("Comment:\n    Please check @Bob"));
+    assert!(text.contains("Reply:\n    Fixed & checked"));
+
+    // threaded comments move with their cells.
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.insert_new_row(&1, &2);
+    let comment = sheet.get_threaded_comment("B4").unwrap();
+    assert_eq!(comment.get_replies()[0].get_coordinate().to_string(), "B4");
+    sheet.remove_row(&4, &1);
+    assert!(!sheet.has_threaded_comments());
+}
+
+#[test]
+fn pivot_table_builder_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value("Region");
+    sheet.get_cell_mut("B1").set_value("Year");
+    sheet.get_cell_mut("C1").set_value("Sales");
+    let data = [
+        ("West", 2023, 10),
+        ("East", 2023, 20),
+        ("West", 2024, 30),
+        ("east", 2024, 40),
+    ];
+    for (i, (region, year, sales)) in data.iter().enumerate() {
+        let row = i as u32 + 2;
+        sheet.get_cell_mut((1, row)).set_value(*region);
+        sheet.get_cell_mut((2, row)).set_value_number(*year);
+        sheet.get_cell_mut((3, row)).set_value_number(*sales);
+    }
+    book.new_sheet("Report").unwrap().add_pivot_table(
+        PivotTableBuilder::new("Sheet1!A1:C5")
+            .rows(["Region"])
+            .columns(["Year"])
+            .values([("Sales", DataConsolidateFunctionValues::Sum)]),
+    );
+    assert_eq!(
+        book.get_sheet_by_name("Report").unwrap().get_pivot_tables()[0]
+            .get_pivot_table_definition()
+            .get_name(),
+        "PivotTable1"
+    );
+
+    let path = std::path::Path::new("./tests/result_files/pivot_table_builder.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut read_part = |name: &str| {
+        let mut buf = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut buf).unwrap();
+        buf
+    };
+
+    let definition = read_part("xl/pivotCache/pivotCacheDefinition1.xml");
+    assert!(definition.contains(r#"r:id="rId1""#));
+    assert!(definition.contains(r#"recordCount="4""#));
+    assert!(definition.contains(r#"<worksheetSource ref="A1:C5" sheet="Sheet1"/>"#));
+    assert!(
+        definition.contains(r#"<sharedItems count="2"><s v="West"/><s v="East"/></sharedItems>"#)
+    );
+    assert!(definition.contains(r#"minValue="10" maxValue="40"/>"#));
+
+    let records = read_part("xl/pivotCache/pivotCacheRecords1.xml");
+    assert!(records.contains(r#"count="4""#));
+    assert!(records.contains(r#"<r><x v="1"/><x v="1"/><n v="40"/></r>"#));
+
+    let pivot_table = read_part("xl/pivotTables/pivotTable1.xml");
+    assert!(pivot_table.contains(r#"name="PivotTable1" cacheId="0""#));
+    assert!(pivot_table.contains(
+        r#"<location ref="A3:D7" firstHeaderRow="1" firstDataRow="2" firstDataCol="1"/>"#
+    ));
+    assert!(pivot_table.contains(r#"<rowFields count="1"><field x="0"/></rowFields>"#));
+    assert!(pivot_table.contains(r#"<colFields count="1"><field x="1"/></colFields>"#));
+    assert!(pivot_table.contains(r#"<dataField name="Sum of Sales" fld="2""#));
+
+    let pivot_table_rels = read_part("xl/pivotTables/_rels/pivotTable1.xml.rels");
+    assert!(pivot_table_rels.contains("../pivotCache/pivotCacheDefinition1.xml"));
+    let sheet_rels = read_part("xl/worksheets/_rels/sheet2.xml.rels");
+    assert!(sheet_rels.contains("../pivotTables/pivotTable1.xml"));
+    let workbook = read_part("xl/workbook.xml");
+    assert!(workbook.contains(r#"<pivotCache cacheId="0" r:id="#));
+    let content_types = read_part("[Content_Types].xml");
+    assert!(content_types.contains("/xl/pivotCache/pivotCacheRecords1.xml"));
+}
+
+#[test]
+fn pivot_table_with_ole_objects_test() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsm");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let data = book.new_sheet("PivotData").unwrap();
+    data.get_cell_mut("A1").set_value("Region");
+    data.get_cell_mut("B1").set_value("Sales");
+    data.get_cell_mut("A2").set_value("West");
+    data.get_cell_mut("B2").set_value_number(10);
+    data.get_cell_mut("A3").set_value("East");
+    data.get_cell_mut("B3").set_value_number(20);
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .add_pivot_table(
+            PivotTableBuilder::new("PivotData!A1:B3")
+                .location("AA1")
+                .rows(["Region"])
+                .values([("Sales", DataConsolidateFunctionValues::Sum)]),
+        );
+
+    let path = std::path::Path::new("./tests/result_files/pivot_table_with_ole_objects.xlsm");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut read_part = |name: &str| {
+        let mut buf = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut buf).unwrap();
+        buf
+    };
+    let sheet = read_part("xl/worksheets/sheet1.xml");
+    let sheet_rels = read_part("xl/worksheets/_rels/sheet1.xml.rels");
+    assert!(sheet.contains("<oleObject "));
+    assert!(sheet_rels.contains("../pivotTables/pivotTable1.xml"));
+
+    // every r:id in the sheet xml must point at a relationship of the matching type.
+    let mut checked = 0;
+    for (pos, _) in sheet.match_indices(r#"r:id=""#) {
+        let element = &sheet[sheet[..pos].rfind('<').unwrap() + 1..];
+        let element = &element[..element.find(' ').unwrap()];
+        let value = &sheet[pos + 6..];
+        let value = &value[..value.find('"').unwrap()];
+        let relationship = &sheet_rels[sheet_rels.find(&format!(r#"Id="{}""#, value)).unwrap()..];
+        let relationship = &relationship[..relationship.find("/>").unwrap()];
+        let expected = match element {
+            "oleObject" => "/oleObject\"",
+            "objectPr" => "/image\"",
+            "drawing" => "/drawing\"",
+            "legacyDrawing" => "/vmlDrawing\"",
+            "tablePart" => "/table\"",
+            "pageSetup" => "/printerSettings\"",
+            "hyperlink" => "/hyperlink\"",
+            _ => panic!("unexpected r:id on {}", element),
+        };
+        assert!(
+            relationship.contains(expected)
+                || element == "oleObject" && relationship.contains("/package\""),
+            "{} {} -> {}",
+            element,
+            value,
+            relationship
+        );
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn pivot_table_refresh_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value("Region");
+    sheet.get_cell_mut("B1").set_value("Year");
+    sheet.get_cell_mut("C1").set_value("Sales");
+    let data = [
+        ("West", 2023, 10),
+        ("East", 2023, 20),
+        ("West", 2024, 30),
+        ("East", 2024, 40),
+    ];
+    for (i, (region, year, sales)) in data.iter().enumerate() {
+        let row = i as u32 + 2;
+        sheet.get_cell_mut((1, row)).set_value(*region);
+        sheet.get_cell_mut((2, row)).set_value_number(*year);
+        sheet.get_cell_mut((3, row)).set_value_number(*sales);
+    }
+    let report = book.new_sheet("Report").unwrap();
+    report.add_pivot_table(
+        PivotTableBuilder::new("Sheet1!A1:C5")
+            .rows(["Region"])
+            .columns(["Year"])
+            .values([("Sales", DataConsolidateFunctionValues::Sum)]),
+    );
+    report.add_pivot_table(
+        PivotTableBuilder::new("Sheet1!A1:C5")
+            .location("H3")
+            .rows(["Region"])
+            .values([
+                ("Sales", DataConsolidateFunctionValues::Count),
+                ("Sales", DataConsolidateFunctionValues::Average),
+                ("Sales", DataConsolidateFunctionValues::Maximum),
+                ("Sales", DataConsolidateFunctionValues::Minimum),
+                ("Sales", DataConsolidateFunctionValues::Product),
+                ("Sales", DataConsolidateFunctionValues::StandardDeviation),
+                ("Sales", DataConsolidateFunctionValues::Variance),
+            ]),
+    );
+
+    let path = std::path::Path::new("./tests/result_files/pivot_table_refresh.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+
+    // the output written with the generated pivot tables.
+    let report = book.get_sheet_by_name("Report").unwrap();
+    assert_eq!(report.get_pivot_tables().len(), 2);
+    assert_eq!(report.get_value("A3"), "Sum of Sales");
+    assert_eq!(report.get_value("B3"), "Column Labels");
+    assert_eq!(report.get_value("A4"), "Row Labels");
+    assert_eq!(report.get_value("B4"), "2023");
+    assert_eq!(report.get_value("D4"), "Grand Total");
+    assert_eq!(report.get_value("A5"), "East");
+    assert_eq!(report.get_value("B5"), "20");
+    assert_eq!(report.get_value("D5"), "60");
+    assert_eq!(report.get_value("A7"), "Grand Total");
+    assert_eq!(report.get_value("D7"), "100");
+
+    assert_eq!(report.get_value("H3"), "Row Labels");
+    assert_eq!(report.get_value("I3"), "Count of Sales");
+    assert_eq!(report.get_value("N3"), "StdDev of Sales");
+    assert_eq!(report.get_value("H4"), "East");
+    assert_eq!(report.get_value("I4"), "2");
+    assert_eq!(report.get_value("J4"), "30");
+    assert_eq!(report.get_value("K4"), "40");
+    assert_eq!(report.get_value("L4"), "20");
+    assert_eq!(report.get_value("M4"), "800");
+    assert_eq!(
+        report.get_cell("N4").unwrap().get_value_number(),
+        Some(200f64.sqrt())
+    );
+    assert_eq!(report.get_value("O4"), "200");
+    assert_eq!(report.get_value("H6"), "Grand Total");
+    assert_eq!(report.get_value("I6"), "4");
+
+    // the pivot tables follow the changed source data.
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("C2").set_value_number(15);
+    sheet.get_cell_mut("A5").set_value("North");
+    book.refresh_pivot_tables().unwrap();
+
+    let report = book.get_sheet_by_name("Report").unwrap();
+    let pivot_table = &report.get_pivot_tables()[0];
+    assert_eq!(
+        *pivot_table.get_pivot_cache_definition().get_record_count(),
+        4
+    );
+    assert_eq!(
+        pivot_table
+            .get_pivot_table_definition()
+            .get_location()
+            .get_reference(),
+        "A3:D8"
+    );
+    assert_eq!(report.get_value("A5"), "East");
+    assert_eq!(report.get_value("D5"), "20");
+    assert_eq!(report.get_value("A6"), "North");
+    assert_eq!(report.get_value("B6"), "");
+    assert_eq!(report.get_value("C6"), "40");
+    assert_eq!(report.get_value("A7"), "West");
+    assert_eq!(report.get_value("D7"), "45");
+    assert_eq!(report.get_value("A8"), "Grand Total");
+    assert_eq!(report.get_value("D8"), "105");
+
+    // growing over cells of the user is rejected.
+    let report = book.get_sheet_by_name_mut("Report").unwrap();
+    report.get_cell_mut("A9").set_value("Note");
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("A4").set_value("South");
+    assert!(book.refresh_pivot_tables().is_err());
+    let report = book.get_sheet_by_name("Report").unwrap();
+    assert_eq!(report.get_value("A9"), "Note");
+    assert_eq!(report.get_value("A8"), "Grand Total");
+    book.get_sheet_by_name_mut("Report")
+        .unwrap()
+        .get_cell_mut("A9")
+        .set_value("");
+    book.refresh_pivot_tables().unwrap();
+    let report = book.get_sheet_by_name("Report").unwrap();
+    assert_eq!(report.get_value("A8"), "West");
+    assert_eq!(report.get_value("A9"), "Grand Total");
+
+    // a pivot table loaded from a file, with a table as its source.
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("D41").set_value("test0");
+    book.refresh_pivot_tables().unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("D44"), "Row Labels");
+    assert_eq!(sheet.get_value("D45"), "test0");
+    assert_eq!(sheet.get_value("D46"), "test1");
+    assert_eq!(sheet.get_value("D47"), "Grand Total");
+}
+
 #[test]
 fn issue_72() {
     let xlsx_path = std::path::Path::new("./tests/test_files/wps_comment.xlsx");
@@ -1255,6 +2196,88 @@ fn issue_147() {
     let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
 }
 
+#[test]
+fn richtext_to_html_test() {
+    let run = |text: &str, f: &dyn Fn(&mut umya_spreadsheet::Font)| {
+        let mut font = umya_spreadsheet::Font::default();
+        f(&mut font);
+        let mut element = umya_spreadsheet::TextElement::default();
+        element.set_text(text).set_run_properties(font);
+        element
+    };
+    let mut rich_text = umya_spreadsheet::RichText::default();
+    rich_text
+        .add_rich_text_elements(run("bold", &|v| {
+            v.set_bold(true);
+        }))
+        .add_rich_text_elements(run("italic", &|v| {
+            v.set_italic(true);
+        }))
+        .add_rich_text_elements(run("single", &|v| {
+            v.set_underline("single");
+        }))
+        .add_rich_text_elements(run("double", &|v| {
+            v.set_underline("double");
+        }))
+        .add_rich_text_elements(run("strike", &|v| {
+            v.set_strikethrough(true);
+        }))
+        .add_rich_text_elements(run("red", &|v| {
+            v.get_color_mut().set_argb("FFFF0000");
+        }))
+        .add_rich_text_elements(run("big", &|v| {
+            v.set_size(20.0);
+        }))
+        .add_rich_text_elements(run("sup", &|v| {
+            v.get_vertical_text_alignment_mut()
+                .set_val(umya_spreadsheet::VerticalAlignmentRunValues::Superscript);
+        }))
+        .add_rich_text_elements(run("sub", &|v| {
+            v.get_vertical_text_alignment_mut()
+                .set_val(umya_spreadsheet::VerticalAlignmentRunValues::Subscript);
+        }))
+        .add_rich_text_elements(run("quoted", &|v| {
+            v.set_name("A\"B");
+        }));
+    let mut element = umya_spreadsheet::TextElement::default();
+    element.set_text("a < b & \"c\"");
+    rich_text.add_rich_text_elements(element);
+
+    let html = umya_spreadsheet::helper::html::richtext_to_html(&rich_text);
+    assert_eq!(
+        html,
+        concat!(
+            r#"<span style="font-weight:bold">bold</span>"#,
+            r#"<span style="font-style:italic">italic</span>"#,
+            r#"<span style="text-decoration:underline">single</span>"#,
+            r#"<span style="text-decoration:underline double">double</span>"#,
+            r#"<span style="text-decoration:line-through">strike</span>"#,
+            r#"<span style="color:#FF0000">red</span>"#,
+            r#"<span style="font-size:20pt">big</span>"#,
+            r#"<span style="vertical-align:super">sup</span>"#,
+            r#"<span style="vertical-align:sub">sub</span>"#,
+            r#"<span style="font-family:'A&quot;B'">quoted</span>"#,
+            r#"a &lt; b &amp; &quot;c&quot;"#,
+        )
+    );
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_rich_text(rich_text);
+    assert_eq!(sheet.get_cell("A1").unwrap().get_value_html(), html);
+    sheet.get_cell_mut("A2").set_value("x < y & \"z\"\nnext");
+    assert_eq!(
+        sheet.get_cell("A2").unwrap().get_value_html(),
+        "x &lt; y &amp; &quot;z&quot;<br>next"
+    );
+    sheet.get_cell_mut("A3").set_value_number(1234.5);
+    sheet
+        .get_style_mut("A3")
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_NUMBER_COMMA_SEPARATED1);
+    assert_eq!(sheet.get_cell("A3").unwrap().get_value_html(), "1,234.50");
+}
+
 #[test]
 fn html_to_richtext_test() {
     let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
@@ -1983,3 +3006,1889 @@ fn issue_268() {
 
     println!("All sheets copied successfully!");
 }
+
+#[test]
+fn calculate_formula() {
+    let mut book = new_file();
+    let _ = book.new_sheet("Data Sheet");
+    {
+        let sheet = book.get_sheet_by_name_mut("Data Sheet").unwrap();
+        sheet.get_cell_mut("A1").set_value("apple");
+        sheet.get_cell_mut("B1").set_value_number(120);
+        sheet.get_cell_mut("A2").set_value("banana");
+        sheet.get_cell_mut("B2").set_value_number(80);
+        sheet.get_cell_mut("A3").set_value("cherry");
+        sheet.get_cell_mut("B3").set_value_number(300);
+    }
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("A1").set_value_number(10);
+    sheet.get_cell_mut("A2").set_value_number(20);
+    sheet.get_cell_mut("A3").set_value_number(30);
+    sheet.get_cell_mut("B1").set_formula("A1+A2*A3");
+    sheet
+        .get_cell_mut("B2")
+        .set_formula("SUM(A1:A3)/COUNT(A:A)");
+    sheet
+        .get_cell_mut("B3")
+        .set_formula("SUM('Data Sheet'!B1:B3)");
+    sheet
+        .get_cell_mut("B4")
+        .set_formula("VLOOKUP(\"banana\",'Data Sheet'!A1:B3,2,FALSE)");
+    sheet
+        .get_cell_mut("B5")
+        .set_formula("IF(B1>500,\"high\",\"low\")&\"!\"");
+    sheet.get_cell_mut("B6").set_formula("1/0");
+    sheet.get_cell_mut("B7").set_formula("Total*2");
+    sheet.get_cell_mut("B8").set_formula("ROUND(2.675,2)");
+    sheet
+        .get_cell_mut("B9")
+        .set_formula("COUNTIF(A1:A3,\">15\")");
+    sheet.get_cell_mut("B10").set_formula("B11+1");
+    sheet.get_cell_mut("B11").set_formula("UNKNOWNFUNC(1)");
+    sheet.add_defined_name("Total", "Sheet1!$B$3").unwrap();
+
+    book.calculate();
+
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("B1"), "610");
+    assert_eq!(sheet.get_value("B2"), "20");
+    assert_eq!(sheet.get_value("B3"), "500");
+    assert_eq!(sheet.get_value("B4"), "80");
+    assert_eq!(sheet.get_value("B5"), "high!");
+    assert_eq!(sheet.get_value("B6"), "#DIV/0!");
+    assert_eq!(sheet.get_value("B7"), "1000");
+    assert_eq!(sheet.get_value("B8"), "2.68");
+    assert_eq!(sheet.get_value("B9"), "2");
+    assert_eq!(sheet.get_value("B10"), "#NAME?");
+    assert_eq!(sheet.get_cell("B1").unwrap().get_formula(), "A1+A2*A3");
+
+    let path = std::path::Path::new("./tests/result_files/calculate_formula.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("B1"), "610");
+    assert_eq!(sheet.get_cell_value("B1").get_value_number(), Some(610.0));
+    assert_eq!(sheet.get_value("B5"), "high!");
+    assert_eq!(sheet.get_value("B6"), "#DIV/0!");
+
+    let mut book = new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value_number(4);
+    sheet.get_cell_mut("A2").set_formula("A1^2");
+    sheet.get_cell_mut("A3").set_formula("A2+A1");
+    assert_eq!(sheet.calculate_cell("A3").get_value(), "20");
+    assert_eq!(sheet.get_value("A2"), "16");
+}
+
+#[test]
+fn calculate_long_reference_chain() {
+    let mut book = new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let count = 100_000u32;
+    for row in 1..count {
+        sheet
+            .get_cell_mut((1, row))
+            .set_formula(format!("A{}+1", row + 1));
+    }
+    sheet.get_cell_mut((1, count)).set_value_number(1);
+    book.calculate();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("A1"), count.to_string());
+    assert_eq!(sheet.get_value((1, count - 1)), "2");
+}
+
+#[test]
+fn formula_dependencies() {
+    let mut book = new_file();
+    let _ = book.new_sheet("Input");
+    {
+        let sheet = book.get_sheet_by_name_mut("Input").unwrap();
+        sheet.get_cell_mut("A1").set_value_number(100);
+        sheet.get_cell_mut("A2").set_value_number(0.1);
+    }
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.add_defined_name("Rate", "Input!$A$2").unwrap();
+    sheet.get_cell_mut("B1").set_formula("Input!A1*Rate");
+    sheet.get_cell_mut("B2").set_formula("B1+Input!A1");
+    sheet.get_cell_mut("B3").set_formula("SUM(B1:B2)");
+    sheet.get_cell_mut("C1").set_formula("1+1");
+    book.calculate();
+    assert_eq!(
+        book.get_sheet_by_name("Sheet1").unwrap().get_value("B3"),
+        "120"
+    );
+
+    let precedents: Vec<String> = book
+        .get_precedents("Sheet1", "B1")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(precedents, vec!["Input!A1", "Input!A2"]);
+
+    let dependents: Vec<String> = book
+        .get_dependents("Input", "A1")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(dependents, vec!["Sheet1!B1", "Sheet1!B2"]);
+
+    let dependents: Vec<String> = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_dependents("B2")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(dependents, vec!["Sheet1!B3"]);
+
+    // only affected formulas are recalculated
+    {
+        let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+        sheet.get_cell_mut("C1").set_formula_result_default("stale");
+    }
+    book.get_sheet_by_name_mut("Input")
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value_number(200);
+    book.calculate_dependents("Input", "A1");
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("B1"), "20");
+    assert_eq!(sheet.get_value("B2"), "220");
+    assert_eq!(sheet.get_value("B3"), "240");
+    assert_eq!(sheet.get_value("C1"), "stale");
+
+    // the changed cell itself is recalculated
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("B1")
+        .set_formula("Input!A1*2");
+    book.calculate_dependents("Sheet1", "B1");
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("B1"), "400");
+    assert_eq!(sheet.get_value("B3"), "1000");
+
+    // the cached graph follows edits
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("D1")
+        .set_formula("B3*2");
+    let dependents: Vec<String> = book
+        .get_dependents("Sheet1", "B3")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(dependents, vec!["Sheet1!D1"]);
+
+    // lazily read workbooks are deserialized first
+    let path = std::path::Path::new("./tests/result_files/formula_dependencies.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let mut book = umya_spreadsheet::reader::xlsx::lazy_read(path).unwrap();
+    let dependents: Vec<String> = book
+        .get_dependents("Input", "A1")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(dependents, vec!["Sheet1!B1", "Sheet1!B2"]);
+    let precedents: Vec<String> = book
+        .get_precedents("Sheet1", "D1")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(precedents, vec!["Sheet1!B3"]);
+}
+
+#[test]
+fn read_csv() {
+    // RFC 4180 quoting, embedded newlines and type guessing
+    let path = std::path::Path::new("./tests/result_files/csv_read_rfc4180.csv");
+    std::fs::write(
+        path,
+        "name,amount,flag\r\n\"Smith, John\",1200.5,TRUE\r\n\"say \"\"hi\"\"\",\"line1\nline2\",\r\n",
+    )
+    .unwrap();
+    let option = umya_spreadsheet::structs::CsvReaderOption::default();
+    let book = umya_spreadsheet::reader::csv::read(path, &option).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_highest_row(), 3);
+    assert_eq!(sheet.get_value("A2"), "Smith, John");
+    assert_eq!(sheet.get_cell("B2").unwrap().get_data_type(), "n");
+    assert_eq!(
+        sheet.get_cell("B2").unwrap().get_value_number(),
+        Some(1200.5)
+    );
+    assert_eq!(sheet.get_cell("C2").unwrap().get_data_type(), "b");
+    assert_eq!(sheet.get_value("A3"), "say \"hi\"");
+    assert_eq!(sheet.get_value("B3"), "line1\nline2");
+    assert!(sheet.get_cell("C3").is_none());
+
+    // delimiter, trim, no type guessing and UTF-8 BOM
+    let path = std::path::Path::new("./tests/result_files/csv_read_semicolon.csv");
+    std::fs::write(path, "\u{feff}'a;b'; 10 \n").unwrap();
+    let mut option = umya_spreadsheet::structs::CsvReaderOption::default();
+    option.set_delimiter(';');
+    option.set_quote_char('\'');
+    option.set_do_trim(true);
+    option.set_guess_type(false);
+    let book = umya_spreadsheet::reader::csv::read(path, &option).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_value("A1"), "a;b");
+    assert_eq!(sheet.get_value("B1"), "10");
+    assert_eq!(sheet.get_cell("B1").unwrap().get_data_type(), "s");
+
+    // encoding round trip with the csv writer
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value("日本語");
+    sheet.get_cell_mut("B1").set_value("テスト");
+    let path = std::path::Path::new("./tests/result_files/csv_read_shift_jis.csv");
+    let mut write_option = umya_spreadsheet::structs::CsvWriterOption::default();
+    write_option.set_csv_encode_value(umya_spreadsheet::structs::CsvEncodeValues::ShiftJis);
+    umya_spreadsheet::writer::csv::write(&book, path, Some(&write_option)).unwrap();
+    let mut option = umya_spreadsheet::structs::CsvReaderOption::default();
+    option.set_csv_encode_value(umya_spreadsheet::structs::CsvEncodeValues::ShiftJis);
+    let mut sheet = umya_spreadsheet::new_file().get_sheet(&0).unwrap().clone();
+    umya_spreadsheet::reader::csv::read_to_worksheet(path, &mut sheet, &option).unwrap();
+    assert_eq!(sheet.get_value("A1"), "日本語");
+    assert_eq!(sheet.get_value("B1"), "テスト");
+
+    // fixture used by the documentation example
+    let path = std::path::Path::new("./tests/test_files/aaa.csv");
+    let mut option = umya_spreadsheet::structs::CsvReaderOption::default();
+    option.set_delimiter(';');
+    let book = umya_spreadsheet::reader::csv::read(path, &option).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_value("A3"), "Banana; ripe");
+    assert_eq!(sheet.get_value("B2"), "120");
+}
+
+#[test]
+fn write_csv_with_options() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.new_sheet("Data").unwrap();
+    sheet.get_cell_mut("A1").set_value("name");
+    sheet.get_cell_mut("B1").set_value("amount");
+    sheet.get_cell_mut("A2").set_value("Smith, John");
+    sheet.get_cell_mut("B2").set_value_number(1234.5);
+    sheet
+        .get_style_mut("B2")
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_NUMBER_COMMA_SEPARATED1);
+    sheet.get_cell_mut("A3").set_value("say \"hi\"\nbye");
+    sheet.get_cell_mut("B3").set_value_number(7);
+    sheet.get_cell_mut("C3").set_value("out of range");
+
+    let write = |option: &umya_spreadsheet::structs::CsvWriterOption| -> Vec<u8> {
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        umya_spreadsheet::writer::csv::write_writer(&book, &mut cursor, option).unwrap();
+        cursor.into_inner()
+    };
+
+    // minimal quoting
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_name("Data");
+    option.set_range("A1:B3");
+    assert_eq!(
+        String::from_utf8(write(&option)).unwrap(),
+        "name,amount\r\n\"Smith, John\",1234.5\r\n\"say \"\"hi\"\"\nbye\",7\r\n"
+    );
+
+    // non-numeric quoting, delimiter, line terminator, formatted values and BOM
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_index(1);
+    option.set_range("A2:B2");
+    option.set_delimiter(';');
+    option.set_line_terminator("\n");
+    option.set_csv_quote_value(umya_spreadsheet::structs::CsvQuoteValues::NonNumeric);
+    option.set_use_formatted_value(true);
+    option.set_include_bom(true);
+    assert_eq!(
+        String::from_utf8(write(&option)).unwrap(),
+        "\u{feff}\"Smith, John\";1,234.50\n"
+    );
+
+    // numbers holding the delimiter are still quoted
+    option.set_delimiter(',');
+    option.set_include_bom(false);
+    assert_eq!(
+        String::from_utf8(write(&option)).unwrap(),
+        "\"Smith, John\",\"1,234.50\"\n"
+    );
+
+    // quote all
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_name("Data");
+    option.set_range("A1:B1");
+    option.set_csv_quote_value(umya_spreadsheet::structs::CsvQuoteValues::All);
+    assert_eq!(
+        String::from_utf8(write(&option)).unwrap(),
+        "\"name\",\"amount\"\r\n"
+    );
+
+    // unknown sheet
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_name("Missing");
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    assert!(umya_spreadsheet::writer::csv::write_writer(&book, &mut cursor, &option).is_err());
+
+    // malformed range
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_range("A1:B2:C3");
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    assert!(umya_spreadsheet::writer::csv::write_writer(&book, &mut cursor, &option).is_err());
+}
+
+#[test]
+fn write_html() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("A1").set_value("Title <merged>");
+    sheet.add_merge_cells("A1:B1");
+    sheet.get_cell_mut("A2").set_value_number(1234.5);
+    sheet
+        .get_style_mut("A2")
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_NUMBER_COMMA_SEPARATED1);
+    sheet.get_cell_mut("B2").set_value("line1\nline2");
+    sheet.get_cell_mut("C2").set_value("hidden column");
+    sheet.get_cell_mut("A3").set_value("hidden row");
+
+    let style = sheet.get_style_mut("A1");
+    style.get_font_mut().set_bold(true).set_size(14.0);
+    style.get_font_mut().get_color_mut().set_argb("FFFF0000");
+    style.set_background_color("FFFFFF00");
+    style
+        .get_borders_mut()
+        .get_bottom_mut()
+        .set_border_style(umya_spreadsheet::Border::BORDER_MEDIUM);
+    style
+        .get_alignment_mut()
+        .set_horizontal(umya_spreadsheet::HorizontalAlignmentValues::Center);
+    sheet
+        .get_style_mut("B2")
+        .get_alignment_mut()
+        .set_wrap_text(true);
+
+    let mut rich_text = umya_spreadsheet::RichText::default();
+    let mut element = umya_spreadsheet::TextElement::default();
+    element.set_text("bold");
+    element.get_run_properties_mut().set_bold(true);
+    rich_text.add_rich_text_elements(element);
+    let mut element = umya_spreadsheet::TextElement::default();
+    element.set_text(" plain");
+    rich_text.add_rich_text_elements(element);
+    sheet.get_cell_mut("D1").set_rich_text(rich_text);
+
+    sheet.get_column_dimension_mut("A").set_width(20.0);
+    sheet.get_column_dimension_mut("C").set_hidden(true);
+    sheet.get_row_dimension_mut(&2).set_height(30.0);
+    sheet.get_row_dimension_mut(&3).set_hidden(true);
+
+    let write = |option: &umya_spreadsheet::structs::HtmlWriterOption| -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        umya_spreadsheet::writer::html::write_writer(&book, &mut buffer, option).unwrap();
+        String::from_utf8(buffer).unwrap()
+    };
+
+    let mut option = umya_spreadsheet::structs::HtmlWriterOption::default();
+    option.set_full_document(false);
+    let html = write(&option);
+    assert!(html.starts_with("<table "));
+    assert!(html.contains("<col style=\"width:140px\">"));
+    assert!(html.contains("<td colspan=\"2\" style=\""));
+    assert!(html.contains(">Title &lt;merged&gt;</td>"));
+    assert!(html.contains("font-weight:bold"));
+    assert!(html.contains("color:#FF0000"));
+    assert!(html.contains("background-color:#FFFF00"));
+    assert!(html.contains("border-bottom:2px solid #000000"));
+    assert!(html.contains("text-align:center"));
+    assert!(html.contains("<tr style=\"height:30pt\">"));
+    assert!(html.contains(">1,234.50</td>"));
+    assert!(html.contains("white-space:pre-wrap"));
+    assert!(html.contains(">line1<br>line2</td>"));
+    assert!(html.contains("font-weight:bold;color:#000000\">bold</span> plain</td>"));
+    assert!(!html.contains("hidden column"));
+    assert!(!html.contains("hidden row"));
+
+    // hidden rows and columns, range and full document
+    let mut option = umya_spreadsheet::structs::HtmlWriterOption::default();
+    option.set_include_hidden(true);
+    option.set_range("A2:C3");
+    let html = write(&option);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Sheet1</title>"));
+    assert!(html.contains("hidden column"));
+    assert!(html.contains("hidden row"));
+    assert!(!html.contains("Title"));
+
+    let path = std::path::Path::new("./tests/result_files/html_write.html");
+    umya_spreadsheet::writer::html::write(&book, path, None).unwrap();
+
+    // malformed range and a path without extension
+    let mut option = umya_spreadsheet::structs::HtmlWriterOption::default();
+    option.set_range("A1:B2:C3");
+    let mut buf: Vec<u8> = Vec::new();
+    assert!(umya_spreadsheet::writer::html::write_writer(&book, &mut buf, &option).is_err());
+    let path = std::path::Path::new("./tests/result_files/html_write_without_extension");
+    umya_spreadsheet::writer::html::write(&book, path, None).unwrap();
+    assert!(path.exists());
+}
+
+#[test]
+fn streaming_writer() {
+    let path = std::path::Path::new("./tests/result_files/streaming_writer.xlsx");
+    let mut writer = umya_spreadsheet::writer::xlsx::StreamingWriter::create(path).unwrap();
+
+    let mut sheet = umya_spreadsheet::Worksheet::default();
+    sheet.set_name("Data");
+    sheet.get_column_dimension_mut("A").set_width(30.0);
+    sheet.add_merge_cells("A1:C1");
+    let mut pane = umya_spreadsheet::Pane::default();
+    pane.set_horizontal_split(1.0);
+    pane.get_top_left_cell_mut().set_coordinate("A2");
+    pane.set_state(umya_spreadsheet::PaneStateValues::Frozen);
+    let mut sheet_view = umya_spreadsheet::SheetView::default();
+    sheet_view.set_pane(pane);
+    sheet
+        .get_sheet_views_mut()
+        .add_sheet_view_list_mut(sheet_view);
+    sheet.get_row_dimension_mut(&1).set_height(40.0);
+    writer.add_sheet(sheet).unwrap();
+
+    let mut title = umya_spreadsheet::Cell::default();
+    title.set_value("Report");
+    title.get_style_mut().get_font_mut().set_bold(true);
+    writer.append_row(vec![title]).unwrap();
+    for i in 1..=1000 {
+        writer
+            .append_row_values([format!("item {}", i), i.to_string(), "TRUE".to_string()])
+            .unwrap();
+    }
+    assert_eq!(writer.get_row_num(), &1001);
+
+    writer.set_use_shared_string(true);
+    writer.new_sheet("Shared").unwrap();
+    writer.append_row_values(["a", "b", "a"]).unwrap();
+    writer.append_row(vec![]).unwrap();
+    writer.append_row_values(["c"]).unwrap();
+    let mut formula = umya_spreadsheet::Cell::default();
+    formula.set_formula("A3&\"d\"");
+    writer.append_row(vec![formula]).unwrap();
+    assert!(writer.new_sheet("Data").is_err());
+
+    let mut linked = umya_spreadsheet::Worksheet::default();
+    linked.set_name("Linked");
+    linked
+        .get_cell_mut("A1")
+        .get_hyperlink_mut()
+        .set_url("https://example.com");
+    assert!(writer.add_sheet(linked).is_err());
+    let mut tabled = umya_spreadsheet::Worksheet::default();
+    tabled.set_name("Tabled");
+    tabled.add_table(umya_spreadsheet::structs::Table::new(
+        "Table1",
+        ("A1", "B3"),
+    ));
+    assert!(writer.add_sheet(tabled).is_err());
+    writer.finish().unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Data").unwrap();
+    assert_eq!(sheet.get_value("A1"), "Report");
+    assert!(sheet.get_style("A1").get_font().unwrap().get_bold());
+    assert_eq!(sheet.get_value("A1001"), "item 1000");
+    assert_eq!(
+        sheet.get_cell("B1001").unwrap().get_value_number(),
+        Some(1000.0)
+    );
+    assert_eq!(sheet.get_cell("C2").unwrap().get_data_type(), "b");
+    assert_eq!(sheet.get_merge_cells()[0].get_range(), "A1:C1");
+    assert_eq!(sheet.get_column_dimension("A").unwrap().get_width(), &30.0);
+    assert_eq!(sheet.get_row_dimension(&1).unwrap().get_height(), &40.0);
+    let pane = sheet.get_sheets_views().get_sheet_view_list()[0]
+        .get_pane()
+        .unwrap();
+    assert_eq!(pane.get_horizontal_split(), &1.0);
+
+    let sheet = book.get_sheet_by_name("Shared").unwrap();
+    assert_eq!(sheet.get_value("A1"), "a");
+    assert_eq!(sheet.get_value("C1"), "a");
+    assert_eq!(sheet.get_value("A3"), "c");
+    assert_eq!(sheet.get_cell("A4").unwrap().get_formula(), "A3&\"d\"");
+}
+
+#[test]
+fn streaming_reader() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    let mut reader = umya_spreadsheet::reader::xlsx::open(path).unwrap();
+    assert_eq!(
+        reader.get_sheet_names(),
+        book.get_sheet_collection()
+            .iter()
+            .map(|v| v.get_name())
+            .collect::<Vec<_>>()
+    );
+
+    let mut count = 0;
+    for row in reader.rows("Sheet1").unwrap() {
+        let (row, cells) = row.unwrap();
+        for cell in cells {
+            assert_eq!(cell.get_coordinate().get_row_num(), row.get_row_num());
+            let expected = sheet.get_cell(cell.get_coordinate().to_string()).unwrap();
+            assert_eq!(cell.get_value(), expected.get_value());
+            assert_eq!(cell.get_formatted_value(), expected.get_formatted_value());
+            count += 1;
+        }
+    }
+    assert_eq!(count, sheet.get_cell_collection().len());
+
+    let first = reader.rows_by_index(&0).unwrap().next().unwrap().unwrap();
+    assert_eq!(first.0.get_row_num(), &1);
+    assert!(reader.rows("Missing").is_err());
+}
+
+#[test]
+fn read_xls() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xls");
+    let book = umya_spreadsheet::reader::xls::read(path).unwrap();
+    assert_eq!(book.get_sheet_count(), 2);
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    // values
+    assert_eq!(sheet.get_value("A1"), "Hello");
+    assert_eq!(
+        sheet.get_cell("B1").unwrap().get_value_number(),
+        Some(1234.5678)
+    );
+    assert_eq!(sheet.get_value("C1"), "#DIV/0!");
+    assert_eq!(sheet.get_value("D1"), "TRUE");
+    assert_eq!(sheet.get_value("F1"), "World-long-string-split");
+    assert_eq!(sheet.get_value("A2"), "10");
+    assert_eq!(sheet.get_value("B2"), "20");
+    assert_eq!(sheet.get_value("B4"), "日本語");
+
+    // formulas
+    assert_eq!(sheet.get_cell("C2").unwrap().get_formula(), "A2+B2*2");
+    assert_eq!(sheet.get_value("C2"), "50");
+    assert_eq!(sheet.get_cell("D2").unwrap().get_formula(), "A1&\"x\"");
+    assert_eq!(sheet.get_value("D2"), "Hellox");
+    assert_eq!(sheet.get_cell("E2").unwrap().get_formula(), "A2*2");
+    assert_eq!(sheet.get_cell("E3").unwrap().get_formula(), "A3*2");
+    assert_eq!(sheet.get_cell("A3").unwrap().get_formula(), "Sheet2!A1");
+    assert_eq!(sheet.get_value("A3"), "Other");
+    assert_eq!(sheet.get_cell("C3").unwrap().get_formula(), "SUM(A2:B2)");
+
+    // styles
+    let style = sheet.get_style("A1");
+    assert!(*style.get_font().unwrap().get_bold());
+    assert_eq!(sheet.get_formatted_value("B1"), "1234.568");
+    let style = sheet.get_style("B4");
+    assert_eq!(
+        style.get_number_format().unwrap().get_number_format_id(),
+        &14
+    );
+    let borders = style.get_borders().unwrap();
+    assert_eq!(borders.get_left().get_border_style(), "thin");
+    assert_eq!(borders.get_bottom().get_border_style(), "medium");
+    assert_eq!(style.get_background_color().unwrap().get_argb(), "FFFFFF00");
+    assert_eq!(
+        style.get_alignment().unwrap().get_horizontal(),
+        &umya_spreadsheet::HorizontalAlignmentValues::Center
+    );
+
+    // sheet layout
+    assert_eq!(sheet.get_merge_cells()[0].get_range(), "A5:B6");
+    assert_eq!(*sheet.get_column_dimension("A").unwrap().get_width(), 20.0);
+    assert!(*sheet.get_column_dimension("F").unwrap().get_hidden());
+    assert_eq!(*sheet.get_row_dimension(&2).unwrap().get_height(), 30.0);
+
+    // defined names
+    let defined_name = sheet
+        .get_defined_names()
+        .iter()
+        .find(|v| v.get_name() == "MyRange")
+        .unwrap();
+    assert_eq!(defined_name.get_address(), "'Sheet1'!$A$1:$B$2");
+    let defined_name = sheet
+        .get_defined_names()
+        .iter()
+        .find(|v| v.get_name() == "_xlnm.Print_Area")
+        .unwrap();
+    assert_eq!(defined_name.get_local_sheet_id(), &0);
+
+    let sheet = book.get_sheet_by_name("Sheet2").unwrap();
+    assert!(matches!(
+        sheet.get_state(),
+        umya_spreadsheet::SheetStateValues::Hidden
+    ));
+    assert_eq!(sheet.get_value("A1"), "Other");
+
+    // the book can be saved as xlsx
+    let path = std::path::Path::new("./tests/result_files/xls_read.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn read_xlsb() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsb");
+    let book = umya_spreadsheet::reader::xlsb::read(path).unwrap();
+    assert_eq!(book.get_sheet_count(), 2);
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    // values
+    assert_eq!(sheet.get_value("A1"), "Hello");
+    assert_eq!(sheet.get_cell("B1").unwrap().get_value_number(), Some(3.5));
+    assert_eq!(sheet.get_value("C1"), "12");
+    assert_eq!(sheet.get_value("D1"), "Inline");
+    assert_eq!(sheet.get_value("A2"), "TRUE");
+    assert_eq!(sheet.get_value("B2"), "#DIV/0!");
+    assert_eq!(sheet.get_value("A4"), "Merged");
+
+    // formulas and their cached results
+    assert_eq!(sheet.get_cell("C2").unwrap().get_formula(), "B1*2");
+    assert_eq!(sheet.get_value("C2"), "7");
+    assert_eq!(sheet.get_cell("A3").unwrap().get_formula(), "A1&\"!\"");
+    assert_eq!(sheet.get_value("A3"), "Hello!");
+    assert_eq!(
+        sheet.get_cell("B3").unwrap().get_formula(),
+        "SUM(Sheet2!A1:A2)"
+    );
+    assert_eq!(sheet.get_value("B3"), "30");
+    assert_eq!(sheet.get_cell("D2").unwrap().get_formula(), "C2+1");
+    assert_eq!(sheet.get_cell("D3").unwrap().get_formula(), "C3+1");
+
+    // styles
+    let style = sheet.get_style("A1");
+    let font = style.get_font().unwrap();
+    assert!(*font.get_bold());
+    assert!(*font.get_italic());
+    assert_eq!(font.get_name(), "Arial");
+    assert_eq!(*font.get_size(), 14.0);
+    assert_eq!(font.get_color().get_argb(), "FFFF0000");
+    assert_eq!(sheet.get_formatted_value("C2"), "7.00");
+    let style = sheet.get_style("E1");
+    assert_eq!(
+        style.get_number_format().unwrap().get_number_format_id(),
+        &14
+    );
+    let style = sheet.get_style("A4");
+    let borders = style.get_borders().unwrap();
+    assert_eq!(borders.get_left().get_border_style(), "thin");
+    assert_eq!(borders.get_bottom().get_border_style(), "thin");
+    assert_eq!(style.get_background_color().unwrap().get_argb(), "FFFFFF00");
+    let alignment = style.get_alignment().unwrap();
+    assert_eq!(
+        alignment.get_horizontal(),
+        &umya_spreadsheet::HorizontalAlignmentValues::Center
+    );
+    assert!(*alignment.get_wrap_text());
+
+    // sheet layout
+    assert_eq!(sheet.get_merge_cells()[0].get_range(), "A4:B4");
+    assert_eq!(*sheet.get_column_dimension("B").unwrap().get_width(), 20.0);
+    assert!(*sheet.get_column_dimension("C").unwrap().get_hidden());
+    assert_eq!(*sheet.get_row_dimension(&2).unwrap().get_height(), 30.0);
+    assert!(*sheet.get_row_dimension(&5).unwrap().get_hidden());
+
+    // defined names
+    let defined_name = sheet
+        .get_defined_names()
+        .iter()
+        .find(|v| v.get_name() == "MyRange")
+        .unwrap();
+    assert_eq!(defined_name.get_address(), "'Sheet1'!$A$1:$B$2");
+    let defined_name = sheet
+        .get_defined_names()
+        .iter()
+        .find(|v| v.get_name() == "LocalName")
+        .unwrap();
+    assert_eq!(defined_name.get_local_sheet_id(), &0);
+
+    let sheet = book.get_sheet_by_name("Sheet2").unwrap();
+    assert!(matches!(
+        sheet.get_state(),
+        umya_spreadsheet::SheetStateValues::Hidden
+    ));
+    assert_eq!(sheet.get_value("A2"), "20");
+
+    // the book can be saved as xlsx
+    let path = std::path::Path::new("./tests/result_files/xlsb_read.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn ods_write_and_read() {
+    let mut book = new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("A1").set_value_string("Hello  World");
+    sheet.get_cell_mut("B1").set_value_number(1234.5678);
+    sheet.get_cell_mut("C1").set_value_bool(true);
+    sheet.get_cell_mut("D1").set_value_string("line1\nline2");
+    sheet.get_cell_mut("A2").set_value_number(10);
+    sheet.get_cell_mut("B2").set_value_number(20);
+    sheet
+        .get_cell_mut("C2")
+        .set_value_number(30)
+        .set_formula("SUM(A2:B2)");
+    sheet
+        .get_cell_mut("D2")
+        .set_value_string("Hello\"x\"")
+        .set_formula("A1&\"\"\"x\"\"\"");
+    sheet
+        .get_cell_mut("E2")
+        .set_value_string("Other")
+        .set_formula("Sheet2!A1");
+
+    // styles
+    let style = sheet.get_style_mut("A1");
+    style.get_font_mut().set_bold(true).set_size(14.0);
+    style.get_font_mut().get_color_mut().set_argb("FFFF0000");
+    style.set_background_color("FFFFFF00");
+    style
+        .get_borders_mut()
+        .get_left_mut()
+        .set_border_style(Border::BORDER_THIN);
+    style
+        .get_borders_mut()
+        .get_bottom_mut()
+        .set_border_style(Border::BORDER_MEDIUM);
+    style
+        .get_alignment_mut()
+        .set_horizontal(HorizontalAlignmentValues::Center);
+    style.get_alignment_mut().set_wrap_text(true);
+
+    // number formats
+    sheet.get_cell_mut("A3").set_value_number(45292);
+    sheet
+        .get_style_mut("A3")
+        .get_number_format_mut()
+        .set_format_code("yyyy-mm-dd");
+    sheet.get_cell_mut("B3").set_value_number(0.25);
+    sheet
+        .get_style_mut("B3")
+        .get_number_format_mut()
+        .set_format_code("0.00%");
+    sheet.get_cell_mut("C3").set_value_number(1234567.891);
+    sheet
+        .get_style_mut("C3")
+        .get_number_format_mut()
+        .set_format_code("#,##0.00");
+    sheet.get_cell_mut("D3").set_value_number(0.5625);
+    sheet
+        .get_style_mut("D3")
+        .get_number_format_mut()
+        .set_format_code("hh:mm:ss");
+
+    // sheet layout
+    sheet.add_merge_cells("A5:B6");
+    sheet.get_cell_mut("A5").set_value_string("merged");
+    sheet.get_column_dimension_mut("A").set_width(20.0);
+    sheet.get_column_dimension_mut("F").set_hidden(true);
+    sheet.get_row_dimension_mut(&2).set_height(30.0);
+
+    sheet
+        .add_defined_name("MyRange", "Sheet1!$A$1:$B$2")
+        .unwrap();
+
+    let sheet = book.new_sheet("Sheet2").unwrap();
+    sheet.get_cell_mut("A1").set_value_string("Other");
+    sheet.set_state(SheetStateValues::Hidden);
+
+    let path = std::path::Path::new("./tests/result_files/ods_write_and_read.ods");
+    umya_spreadsheet::writer::ods::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::ods::read(path).unwrap();
+    assert_eq!(book.get_sheet_count(), 2);
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    // values
+    assert_eq!(sheet.get_value("A1"), "Hello  World");
+    assert_eq!(
+        sheet.get_cell("B1").unwrap().get_value_number(),
+        Some(1234.5678)
+    );
+    assert_eq!(sheet.get_value("C1"), "TRUE");
+    assert_eq!(sheet.get_value("D1"), "line1\nline2");
+
+    // formulas
+    assert_eq!(sheet.get_cell("C2").unwrap().get_formula(), "SUM(A2:B2)");
+    assert_eq!(sheet.get_value("C2"), "30");
+    assert_eq!(
+        sheet.get_cell("D2").unwrap().get_formula(),
+        "A1&\"\"\"x\"\"\""
+    );
+    assert_eq!(sheet.get_value("D2"), "Hello\"x\"");
+    assert_eq!(sheet.get_cell("E2").unwrap().get_formula(), "Sheet2!A1");
+
+    // styles
+    let style = sheet.get_style("A1");
+    let font = style.get_font().unwrap();
+    assert!(*font.get_bold());
+    assert_eq!(*font.get_size(), 14.0);
+    assert_eq!(font.get_color().get_argb(), "FFFF0000");
+    assert_eq!(
+        font.get_font_underline().get_val(),
+        &umya_spreadsheet::UnderlineValues::None
+    );
+    assert_eq!(style.get_background_color().unwrap().get_argb(), "FFFFFF00");
+    let borders = style.get_borders().unwrap();
+    assert_eq!(borders.get_left().get_border_style(), "thin");
+    assert_eq!(borders.get_bottom().get_border_style(), "medium");
+    let alignment = style.get_alignment().unwrap();
+    assert_eq!(
+        alignment.get_horizontal(),
+        &HorizontalAlignmentValues::Center
+    );
+    assert!(*alignment.get_wrap_text());
+
+    // number formats
+    assert_eq!(
+        sheet.get_cell("A3").unwrap().get_value_number(),
+        Some(45292.0)
+    );
+    assert_eq!(sheet.get_formatted_value("A3"), "2024-01-01");
+    assert_eq!(sheet.get_formatted_value("B3"), "25.00%");
+    assert_eq!(sheet.get_formatted_value("C3"), "1,234,567.89");
+    assert_eq!(
+        sheet.get_cell("D3").unwrap().get_value_number(),
+        Some(0.5625)
+    );
+    assert_eq!(
+        sheet
+            .get_style("D3")
+            .get_number_format()
+            .unwrap()
+            .get_format_code(),
+        "hh:mm:ss"
+    );
+
+    // sheet layout
+    assert_eq!(sheet.get_merge_cells()[0].get_range(), "A5:B6");
+    assert_eq!(sheet.get_value("A5"), "merged");
+    assert_eq!(*sheet.get_column_dimension("A").unwrap().get_width(), 20.0);
+    assert!(*sheet.get_column_dimension("F").unwrap().get_hidden());
+    assert_eq!(*sheet.get_row_dimension(&2).unwrap().get_height(), 30.0);
+
+    // defined names
+    let defined_name = sheet
+        .get_defined_names()
+        .iter()
+        .find(|v| v.get_name() == "MyRange")
+        .unwrap();
+    assert_eq!(defined_name.get_address(), "'Sheet1'!$A$1:$B$2");
+
+    let sheet = book.get_sheet_by_name("Sheet2").unwrap();
+    assert!(matches!(sheet.get_state(), SheetStateValues::Hidden));
+    assert_eq!(sheet.get_value("A1"), "Other");
+
+    // fixture used by the documentation example
+    let path = std::path::Path::new("./tests/test_files/aaa.ods");
+    let book = umya_spreadsheet::reader::ods::read(path).unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("B5"), " ");
+}
+
+#[test]
+fn table_structured_reference_test() {
+    use umya_spreadsheet::helper::formula::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    let headers = ["Item", "Qty", "Price", "Total"];
+    for (i, header) in headers.iter().enumerate() {
+        sheet.get_cell_mut((i as u32 + 1, 1)).set_value(*header);
+    }
+    let data = [("Apple", 2, 100), ("Lemon", 5, 40), ("Melon", 1, 900)];
+    for (i, (item, qty, price)) in data.iter().enumerate() {
+        let row = i as u32 + 2;
+        sheet.get_cell_mut((1, row)).set_value(*item);
+        sheet.get_cell_mut((2, row)).set_value_number(*qty);
+        sheet.get_cell_mut((3, row)).set_value_number(*price);
+        sheet
+            .get_cell_mut((4, row))
+            .set_formula("Table1[[#This Row],[Qty]]*Table1[[#This Row],[Price]]");
+    }
+    sheet.get_cell_mut("A5").set_value("Total");
+    sheet
+        .get_cell_mut("D5")
+        .set_formula("SUBTOTAL(109,Table1[Total])");
+    let mut table = Table::new("Table1", ("A1", "D5"));
+    for header in headers {
+        let mut column = TableColumn::new(header);
+        if header == "Total" {
+            column.set_calculated_column_formula(
+                "Table1[[#This Row],[Qty]]*Table1[[#This Row],[Price]]",
+            );
+        }
+        table.add_column(column);
+    }
+    table.set_totals_row_count(1);
+    sheet.add_table(table);
+
+    // resolve references
+    let range = |sheet: &Worksheet, value: &str, row_num: u32| {
+        parse_structured_reference(value)
+            .unwrap()
+            .get_range(&sheet.get_tables()[0], &row_num)
+    };
+    assert_eq!(range(sheet, "Table1[Qty]", 0).unwrap(), "B2:B4");
+    assert_eq!(range(sheet, "Table1[]", 0).unwrap(), "A2:D4");
+    assert_eq!(range(sheet, "Table1[#All]", 0).unwrap(), "A1:D5");
+    assert_eq!(range(sheet, "Table1[#Totals]", 0).unwrap(), "A5:D5");
+    assert_eq!(range(sheet, "[@Price]", 3).unwrap(), "C3");
+    assert_eq!(range(sheet, "[@Price]", 5), None);
+    assert_eq!(
+        range(sheet, "Table1[[#Headers],[Item]:[Price]]", 0).unwrap(),
+        "A1:C1"
+    );
+    assert_eq!(
+        range(sheet, "Table1[[#Data],[#Totals],[Total]]", 0).unwrap(),
+        "D2:D5"
+    );
+    assert_eq!(range(sheet, "Table1[[#Headers],[#Totals]]", 0), None);
+    assert_eq!(range(sheet, "Table1[Unknown]", 0), None);
+
+    // append a row above the totals row
+    assert_eq!(
+        sheet.append_table_row("Table1", ["Pear", "3", "200", "ignored"]),
+        Some(5)
+    );
+    assert_eq!(sheet.append_table_row("Table2", ["Pear"]), None);
+    let (start, end) = sheet.get_tables()[0].get_area();
+    assert_eq!(
+        format!("{}:{}", start.to_string(), end.to_string()),
+        "A1:D6"
+    );
+    assert_eq!(sheet.get_value("A5"), "Pear");
+    assert_eq!(sheet.get_value_number("B5"), Some(3.0));
+    assert_eq!(
+        sheet.get_cell("D5").unwrap().get_formula(),
+        "Table1[[#This Row],[Qty]]*Table1[[#This Row],[Price]]"
+    );
+    assert_eq!(sheet.get_value("A6"), "Total");
+    assert_eq!(
+        sheet.get_cell("D6").unwrap().get_formula(),
+        "SUBTOTAL(109,Table1[Total])"
+    );
+    assert_eq!(range(sheet, "Table1[Qty]", 0).unwrap(), "B2:B5");
+
+    // rows
+    let area = |sheet: &Worksheet| {
+        let (start, end) = sheet.get_tables()[0].get_area();
+        format!("{}:{}", start.to_string(), end.to_string())
+    };
+    sheet.insert_new_row(&3, &2);
+    assert_eq!(area(sheet), "A1:D8");
+    sheet.insert_new_row(&9, &1);
+    assert_eq!(area(sheet), "A1:D8");
+    sheet.insert_new_row(&1, &1);
+    assert_eq!(area(sheet), "A2:D9");
+    sheet.remove_row(&1, &1);
+    sheet.remove_row(&3, &3);
+    assert_eq!(area(sheet), "A1:D5");
+    assert_eq!(sheet.get_value("A3"), "Melon");
+
+    // columns
+    sheet.insert_new_column("C", &1);
+    assert_eq!(area(sheet), "A1:E5");
+    let names: Vec<&str> = sheet.get_tables()[0]
+        .get_columns()
+        .iter()
+        .map(|v| v.get_name())
+        .collect();
+    assert_eq!(names, ["Item", "Qty", "Column1", "Price", "Total"]);
+    assert_eq!(sheet.get_value("C1"), "Column1");
+    sheet.remove_column("B", &2);
+    assert_eq!(area(sheet), "A1:C5");
+    assert_eq!(sheet.get_tables()[0].get_columns().len(), 3);
+
+    let path = std::path::Path::new("./tests/result_files/table_structured_reference.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    // removing the header row keeps the data rows without a header
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.remove_row(&1, &1);
+    assert_eq!(area(sheet), "A1:C4");
+    assert_eq!(sheet.get_tables()[0].get_header_row_count(), 0);
+    assert_eq!(range(sheet, "Table1[Item]", 0).unwrap(), "A1:A3");
+    assert_eq!(range(sheet, "Table1[#Headers]", 0), None);
+    let path = std::path::Path::new("./tests/result_files/table_without_header.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_tables()[0].get_header_row_count(), 0);
+
+    // removing all of the data rows removes the table
+    let mut book = book;
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.remove_row(&1, &3);
+    assert!(!sheet.has_table());
+
+    // a table outside of a worksheet
+    let mut table = Table::new("Table2", ("A1", "B2"));
+    table.add_column(TableColumn::new("Item"));
+    let mut column = TableColumn::new("Double");
+    column.set_calculated_column_formula("[@Item]*2");
+    table.add_column(column);
+    let cells = table.append_row(["4", "ignored"]);
+    assert_eq!(table.get_area().1.to_string(), "B3");
+    assert_eq!(cells[0].get_coordinate().to_string(), "A3");
+    assert_eq!(cells[0].get_value(), "4");
+    assert_eq!(cells[1].get_formula(), "[@Item]*2");
+}
+
+#[test]
+fn stock_and_surface_chart_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for row in 1..=5u32 {
+        let base = row as f64 * 10.0;
+        sheet.get_cell_mut((1, row)).set_value_number(base);
+        sheet.get_cell_mut((2, row)).set_value_number(base + 5.0);
+        sheet.get_cell_mut((3, row)).set_value_number(base - 5.0);
+        sheet.get_cell_mut((4, row)).set_value_number(base + 2.0);
+    }
+
+    let chart_types = [
+        (umya_spreadsheet::structs::ChartType::StockChart, "A10"),
+        (umya_spreadsheet::structs::ChartType::StockChart, "A30"),
+        (umya_spreadsheet::structs::ChartType::SurfaceChart, "J10"),
+        (umya_spreadsheet::structs::ChartType::Surface3DChart, "J30"),
+    ];
+    for (i, (chart_type, coordinate)) in chart_types.into_iter().enumerate() {
+        let mut from_marker =
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate(coordinate);
+        to_marker.set_coordinate(coordinate.replace("10", "25").replace("30", "45"));
+        // the first stock chart is open-high-low-close, the second high-low-close
+        let area_chart_series_list = if i == 1 {
+            vec!["Sheet1!$B$1:$B$5", "Sheet1!$C$1:$C$5", "Sheet1!$D$1:$D$5"]
+        } else {
+            vec![
+                "Sheet1!$A$1:$A$5",
+                "Sheet1!$B$1:$B$5",
+                "Sheet1!$C$1:$C$5",
+                "Sheet1!$D$1:$D$5",
+            ]
+        };
+        let mut chart = umya_spreadsheet::structs::Chart::default();
+        chart.new_chart(chart_type, from_marker, to_marker, area_chart_series_list);
+        sheet.add_chart(chart);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/stock_and_surface_chart.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    let plot_area = |coordinate: &str| {
+        sheet
+            .get_chart(coordinate)
+            .unwrap()
+            .get_chart_space()
+            .get_chart()
+            .get_plot_area()
+            .clone()
+    };
+
+    let plot_area_ohlc = plot_area("A10");
+    let stock_chart = plot_area_ohlc.get_stock_chart().unwrap();
+    assert_eq!(
+        stock_chart
+            .get_area_chart_series_list()
+            .get_area_chart_series()
+            .len(),
+        4
+    );
+    assert!(stock_chart.get_high_low_lines().is_some());
+    let up_down_bars = stock_chart.get_up_down_bars().unwrap();
+    assert!(up_down_bars.get_up_bars().is_some());
+    assert!(up_down_bars.get_down_bars().is_some());
+
+    let plot_area_hlc = plot_area("A30");
+    let stock_chart = plot_area_hlc.get_stock_chart().unwrap();
+    assert!(stock_chart.get_high_low_lines().is_some());
+    assert!(stock_chart.get_up_down_bars().is_none());
+
+    let plot_area_surface = plot_area("J10");
+    assert!(plot_area_surface.get_surface_chart().is_some());
+    assert_eq!(plot_area_surface.get_series_axis().len(), 1);
+
+    let plot_area_surface_3d = plot_area("J30");
+    let surface_3d_chart = plot_area_surface_3d.get_surface_3d_chart().unwrap();
+    assert!(!surface_3d_chart.get_wireframe().unwrap().get_val());
+}
+
+#[test]
+fn combination_chart_test() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for row in 1..=6u32 {
+        sheet.get_cell_mut((1, row)).set_value_number(row * 1000);
+        sheet
+            .get_cell_mut((2, row))
+            .set_value_number(row as f64 / 10.0);
+        sheet.get_cell_mut((3, row)).set_value_number(row * 500);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("M20");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::BarChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$A$1:$A$6"],
+        )
+        .add_chart_group(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            vec!["Sheet1!$B$1:$B$6"],
+            true,
+        )
+        .unwrap()
+        .add_chart_group(
+            umya_spreadsheet::structs::ChartType::AreaChart,
+            vec!["Sheet1!$C$1:$C$6"],
+            false,
+        )
+        .unwrap()
+        .set_series_title(vec!["Revenue", "Margin %", "Cost"])
+        .set_title("Revenue and Margin")
+        .set_horizontal_title("Month")
+        .set_vertical_title("Amount")
+        .set_secondary_vertical_title("Margin %")
+        .set_secondary_axis_position(
+            umya_spreadsheet::structs::drawing::charts::AxisPositionValues::Right,
+        )
+        .set_axis_crosses_at(0.0);
+    assert!(chart
+        .add_chart_group(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            vec!["Sheet1!$C$1:$C$6"],
+            false,
+        )
+        .is_err());
+    assert!(chart
+        .add_chart_group(
+            umya_spreadsheet::structs::ChartType::PieChart,
+            vec!["Sheet1!$C$1:$C$6"],
+            false,
+        )
+        .is_err());
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/combination_chart.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let plot_area = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_chart("E1")
+        .unwrap()
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area();
+    assert_eq!(plot_area.get_category_axis().len(), 2);
+    assert_eq!(plot_area.get_value_axis().len(), 2);
+
+    let secondary_category_axis = &plot_area.get_category_axis()[1];
+    let secondary_value_axis = &plot_area.get_value_axis()[1];
+    assert!(secondary_category_axis.get_delete().get_val());
+    assert_eq!(
+        secondary_value_axis
+            .get_axis_position()
+            .get_val()
+            .get_value_string(),
+        "r"
+    );
+    assert_eq!(
+        secondary_value_axis
+            .get_crosses()
+            .get_val()
+            .get_value_string(),
+        "max"
+    );
+    assert!(secondary_value_axis.get_title().is_some());
+    assert_eq!(
+        plot_area.get_category_axis()[0]
+            .get_crosses_at()
+            .unwrap()
+            .get_val(),
+        &0.0
+    );
+
+    let line_chart = plot_area.get_line_chart().unwrap();
+    let axis_ids: Vec<u32> = line_chart
+        .get_axis_id()
+        .iter()
+        .map(|v| *v.get_val())
+        .collect();
+    assert_eq!(
+        axis_ids,
+        [
+            *secondary_category_axis.get_axis_id().get_val(),
+            *secondary_value_axis.get_axis_id().get_val()
+        ]
+    );
+    let series = &line_chart
+        .get_area_chart_series_list()
+        .get_area_chart_series()[0];
+    assert_eq!(series.get_index().get_val(), &1);
+    assert_eq!(series.get_series_text().unwrap().get_value(), "Margin %");
+
+    let area_chart = plot_area.get_area_chart().unwrap();
+    assert_eq!(
+        area_chart.get_axis_id()[0].get_val(),
+        plot_area.get_category_axis()[0].get_axis_id().get_val()
+    );
+    assert_eq!(
+        area_chart
+            .get_area_chart_series_list()
+            .get_area_chart_series()[0]
+            .get_index()
+            .get_val(),
+        &2
+    );
+    assert!(plot_area.get_bar_chart().is_some());
+
+    // the secondary axes do not reuse the ids of the primary axes
+    let mut axis_ids: Vec<u32> = plot_area
+        .get_category_axis()
+        .iter()
+        .map(|v| *v.get_axis_id().get_val())
+        .chain(
+            plot_area
+                .get_value_axis()
+                .iter()
+                .map(|v| *v.get_axis_id().get_val()),
+        )
+        .collect();
+    axis_ids.sort();
+    axis_ids.dedup();
+    assert_eq!(axis_ids.len(), 4);
+}
+
+#[test]
+fn chart_trendline_error_bars_data_table_test() {
+    use umya_spreadsheet::structs::drawing::charts::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for row in 1..=6u32 {
+        sheet.get_cell_mut((1, row)).set_value_number(row * row);
+        sheet.get_cell_mut((2, row)).set_value_number(row);
+        sheet.get_cell_mut((3, row)).set_value_number(0.5);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("M20");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$6", "Sheet1!$B$1:$B$6"],
+    );
+
+    let mut polynomial = Trendline::default();
+    polynomial
+        .get_trendline_type_mut()
+        .set_val(TrendlineValues::Polynomial);
+    let mut polynomial_order = PolynomialOrder::default();
+    polynomial_order.set_val(2);
+    polynomial.set_polynomial_order(polynomial_order);
+    let mut display_equation = DisplayEquation::default();
+    display_equation.set_val(true);
+    polynomial.set_display_equation(display_equation);
+    let mut display_r_squared_value = DisplayRSquaredValue::default();
+    display_r_squared_value.set_val(true);
+    polynomial.set_display_r_squared_value(display_r_squared_value);
+    polynomial.set_trendline_label(TrendlineLabel::default());
+
+    let mut moving_average = Trendline::default();
+    moving_average
+        .get_trendline_type_mut()
+        .set_val(TrendlineValues::MovingAverage);
+    let mut period = Period::default();
+    period.set_val(3);
+    moving_average.set_period(period);
+    let mut trendline_name = TrendlineName::default();
+    trendline_name.set_text("3 per. Mov. Avg.");
+    moving_average.set_trendline_name(trendline_name);
+
+    let mut percentage = ErrorBars::default();
+    percentage
+        .get_error_bar_value_type_mut()
+        .set_val(ErrorValues::Percentage);
+    let mut error_bar_value = ErrorBarValue::default();
+    error_bar_value.set_val(5.0);
+    percentage.set_error_bar_value(error_bar_value);
+
+    let mut custom = ErrorBars::default();
+    custom
+        .get_error_bar_type_mut()
+        .set_val(ErrorBarValues::Plus);
+    custom
+        .get_error_bar_value_type_mut()
+        .set_val(ErrorValues::Custom);
+    let mut plus = Plus::default();
+    plus.get_number_reference_mut()
+        .get_formula_mut()
+        .set_address_str("Sheet1!$C$1:$C$6");
+    custom.set_plus(plus);
+
+    let series_list = chart
+        .get_area_chart_series_list_mut()
+        .get_area_chart_series_mut();
+    series_list[0]
+        .add_trendline(polynomial)
+        .add_trendline(moving_average)
+        .add_error_bars(percentage);
+    series_list[1].add_error_bars(custom);
+
+    let mut data_table = DataTable::default();
+    let mut show_keys = ShowKeys::default();
+    show_keys.set_val(true);
+    data_table.set_show_keys(show_keys);
+    chart.get_plot_area_mut().set_data_table(data_table);
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_trendline_error_bars.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    book.insert_new_row("Sheet1", &1, &2);
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    let plot_area = sheet
+        .get_chart_mut("E3")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    assert!(*plot_area
+        .get_data_table()
+        .unwrap()
+        .get_show_keys()
+        .unwrap()
+        .get_val());
+
+    let series_list = plot_area
+        .get_line_chart()
+        .unwrap()
+        .get_area_chart_series_list()
+        .get_area_chart_series();
+    let trendlines = series_list[0].get_trendline();
+    assert_eq!(trendlines.len(), 2);
+    assert_eq!(
+        trendlines[0]
+            .get_trendline_type()
+            .get_val()
+            .get_value_string(),
+        "poly"
+    );
+    assert_eq!(trendlines[0].get_polynomial_order().unwrap().get_val(), &2);
+    assert!(*trendlines[0].get_display_equation().unwrap().get_val());
+    assert!(*trendlines[0]
+        .get_display_r_squared_value()
+        .unwrap()
+        .get_val());
+    assert!(trendlines[0].get_trendline_label().is_some());
+    assert_eq!(trendlines[1].get_period().unwrap().get_val(), &3);
+    assert_eq!(
+        trendlines[1].get_trendline_name().unwrap().get_text(),
+        "3 per. Mov. Avg."
+    );
+
+    let error_bars = series_list[0].get_error_bars();
+    assert_eq!(
+        error_bars[0]
+            .get_error_bar_value_type()
+            .get_val()
+            .get_value_string(),
+        "percentage"
+    );
+    assert_eq!(error_bars[0].get_error_bar_value().unwrap().get_val(), &5.0);
+
+    let error_bars = series_list[1].get_error_bars();
+    assert_eq!(
+        error_bars[0]
+            .get_error_bar_type()
+            .get_val()
+            .get_value_string(),
+        "plus"
+    );
+    assert_eq!(
+        error_bars[0]
+            .get_plus()
+            .unwrap()
+            .get_number_reference()
+            .get_formula()
+            .get_address_str(),
+        "Sheet1!$C$3:$C$8"
+    );
+}
+
+#[test]
+fn chart_ex_test() {
+    use umya_spreadsheet::structs::drawing::chartex::*;
+    use umya_spreadsheet::structs::drawing::spreadsheet::MarkerType;
+    use umya_spreadsheet::structs::ChartExType;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    let values = [120, -30, 45, -20, 60, 175];
+    let groups = ["North", "North", "North", "South", "South", "South"];
+    for row in 1..=6u32 {
+        sheet
+            .get_cell_mut((1, row))
+            .set_value(groups[row as usize - 1]);
+        sheet
+            .get_cell_mut((2, row))
+            .set_value(format!("Item {}", row));
+        sheet
+            .get_cell_mut((3, row))
+            .set_value_number(values[row as usize - 1]);
+    }
+
+    let chart_list = [
+        (ChartExType::Waterfall, Some("Sheet1!$B$1:$B$6"), "E1"),
+        (ChartExType::Pareto, Some("Sheet1!$B$1:$B$6"), "E20"),
+        (ChartExType::Histogram, None, "E40"),
+        (ChartExType::Treemap, Some("Sheet1!$A$1:$B$6"), "O1"),
+        (ChartExType::Sunburst, Some("Sheet1!$A$1:$B$6"), "O20"),
+        (ChartExType::Funnel, Some("Sheet1!$B$1:$B$6"), "O40"),
+        (ChartExType::BoxWhisker, Some("Sheet1!$A$1:$A$6"), "Y1"),
+    ];
+    for (chart_type, category, coordinate) in chart_list {
+        let mut from_marker = MarkerType::default();
+        from_marker.set_coordinate(coordinate);
+        let mut to_marker = MarkerType::default();
+        to_marker.set_col(from_marker.get_col() + 8);
+        to_marker.set_row(from_marker.get_row() + 15);
+        let mut chart_space = ChartSpace::default();
+        chart_space
+            .new_chart(chart_type, category, vec!["Sheet1!$C$1:$C$6"])
+            .set_title("Sales")
+            .set_series_title(vec!["Amount"]);
+        sheet.add_chart_ex(from_marker, to_marker, chart_space);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/chart_ex.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    book.insert_new_row("Sheet1", &1, &1);
+    let sheet = book.get_sheet(&0).unwrap();
+    let chart_list = sheet.get_chart_ex_collection();
+    assert_eq!(chart_list.len(), 7);
+
+    let layout_list: Vec<Vec<&SeriesLayoutValues>> = chart_list
+        .iter()
+        .map(|v| {
+            v.get_chart()
+                .get_plot_area()
+                .get_plot_area_region()
+                .get_series()
+                .iter()
+                .map(|s| s.get_layout_id())
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        layout_list,
+        vec![
+            vec![&SeriesLayoutValues::Waterfall],
+            vec![
+                &SeriesLayoutValues::ClusteredColumn,
+                &SeriesLayoutValues::ParetoLine
+            ],
+            vec![&SeriesLayoutValues::ClusteredColumn],
+            vec![&SeriesLayoutValues::Treemap],
+            vec![&SeriesLayoutValues::Sunburst],
+            vec![&SeriesLayoutValues::Funnel],
+            vec![&SeriesLayoutValues::BoxWhisker],
+        ]
+    );
+
+    // waterfall
+    let waterfall = chart_list[0];
+    assert_eq!(
+        waterfall.get_chart().get_title().unwrap().get_text(),
+        "Sales"
+    );
+    let data = &waterfall.get_chart_data().get_data()[0];
+    let category = &data.get_string_dimension()[0];
+    assert_eq!(
+        category.get_formula().unwrap().get_address_str(),
+        "Sheet1!$B$2:$B$7"
+    );
+    assert_eq!(category.get_level()[0].get_point()[0].get_value(), "Item 1");
+    let value = &data.get_numeric_dimension()[0];
+    assert_eq!(value.get_type(), &NumericDimensionValues::Value);
+    let level = &value.get_level()[0];
+    assert_eq!(level.get_point_count(), &6);
+    assert_eq!(level.get_format_code(), Some("General"));
+    assert_eq!(level.get_point()[1].get_value(), "-30");
+    let series = &waterfall
+        .get_chart()
+        .get_plot_area()
+        .get_plot_area_region()
+        .get_series()[0];
+    assert_eq!(
+        series.get_chart_text().unwrap().get_text_data().get_value(),
+        "Amount"
+    );
+    assert!(series
+        .get_layout_properties()
+        .unwrap()
+        .get_subtotals()
+        .is_some());
+    let axis = waterfall.get_chart().get_plot_area().get_axis();
+    assert_eq!(axis.len(), 2);
+    assert_eq!(
+        axis[0].get_category_scaling().unwrap().get_gap_width(),
+        Some("0.5")
+    );
+    assert!(axis[1].get_value_scaling().is_some());
+
+    // pareto
+    let pareto = chart_list[1];
+    let series = pareto
+        .get_chart()
+        .get_plot_area()
+        .get_plot_area_region()
+        .get_series();
+    assert_eq!(series[1].get_owner_index(), Some(&0));
+    assert_eq!(series[1].get_axis_id()[0].get_val(), &2);
+    let axis = pareto.get_chart().get_plot_area().get_axis();
+    assert_eq!(axis.len(), 3);
+    assert_eq!(axis[2].get_number_format().unwrap().get_format_code(), "0%");
+
+    // histogram
+    let histogram = chart_list[2];
+    let series = &histogram
+        .get_chart()
+        .get_plot_area()
+        .get_plot_area_region()
+        .get_series()[0];
+    assert_eq!(
+        series
+            .get_layout_properties()
+            .unwrap()
+            .get_binning()
+            .unwrap()
+            .get_interval_closed(),
+        &IntervalClosedSideValues::Right
+    );
+    assert!(histogram.get_chart_data().get_data()[0]
+        .get_string_dimension()
+        .is_empty());
+
+    // treemap
+    let treemap = chart_list[3];
+    let data = &treemap.get_chart_data().get_data()[0];
+    let levels = data.get_string_dimension()[0].get_level();
+    assert_eq!(levels.len(), 2);
+    assert_eq!(levels[0].get_point()[0].get_value(), "Item 1");
+    assert_eq!(levels[1].get_point()[0].get_value(), "North");
+    assert_eq!(
+        data.get_numeric_dimension()[0].get_type(),
+        &NumericDimensionValues::Size
+    );
+    assert!(treemap.get_chart().get_plot_area().get_axis().is_empty());
+
+    // box & whisker
+    let box_whisker = chart_list[6];
+    let layout_properties = box_whisker
+        .get_chart()
+        .get_plot_area()
+        .get_plot_area_region()
+        .get_series()[0]
+        .get_layout_properties()
+        .unwrap();
+    assert!(*layout_properties.get_visibility().unwrap().get_outliers());
+    assert_eq!(
+        layout_properties
+            .get_statistics()
+            .unwrap()
+            .get_quartile_method(),
+        &QuartileMethodValues::Exclusive
+    );
+
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn chart_date_axis_display_units_test() {
+    use umya_spreadsheet::structs::drawing::charts::*;
+    use umya_spreadsheet::structs::drawing::spreadsheet::MarkerType;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for month in 1..=12u32 {
+        let serial = umya_spreadsheet::helper::date::convert_date(2024, month as i32, 1, 0, 0, 0);
+        sheet
+            .get_cell_mut((1, month))
+            .set_value_number(serial)
+            .get_style_mut()
+            .get_number_format_mut()
+            .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_DATE_YYYYMMDD);
+        sheet
+            .get_cell_mut((2, month))
+            .set_value_number(month * 125000);
+        sheet
+            .get_cell_mut((3, month))
+            .set_value(format!("Item {}", month));
+        let serial =
+            umya_spreadsheet::helper::date::convert_date(2024, month as i32 + 1, 1, 0, 0, 0);
+        sheet
+            .get_cell_mut((4, month))
+            .set_value_number(serial - 1.0)
+            .get_style_mut()
+            .get_number_format_mut()
+            .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_DATE_YYYYMMDD);
+    }
+    for row in 1..=2u32 {
+        sheet
+            .get_cell_mut((24, row))
+            .set_value_number(1e12 * row as f64)
+            .get_style_mut()
+            .get_number_format_mut()
+            .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_DATE_YYYYMMDD);
+    }
+
+    // date cells as categories
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("M15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$12"],
+        )
+        .set_category_address("Sheet1!$A$1:$A$12")
+        .set_vertical_display_units(BuiltInUnitValues::Thousands);
+    sheet.add_chart(chart);
+
+    // text cells as categories
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("E17");
+    to_marker.set_coordinate("M31");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::BarChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$12"],
+        )
+        .set_category_address("Sheet1!$C$1:$C$12");
+    sheet.add_chart(chart);
+
+    // explicit date axis
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("O1");
+    to_marker.set_coordinate("W15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::AreaChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$12"],
+        )
+        .set_category_address("Sheet1!$A$1:$A$12")
+        .set_date_axis(TimeUnitValues::Days);
+    let mut custom_unit = CustomUnit::default();
+    custom_unit.set_val(50000.0);
+    let mut display_units = DisplayUnits::default();
+    display_units.set_custom_unit(custom_unit);
+    chart.get_plot_area_mut().get_value_axis_mut()[0].set_display_units(display_units);
+    sheet.add_chart(chart);
+
+    // dates at the end of the months
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("E33");
+    to_marker.set_coordinate("M47");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$12"],
+        )
+        .set_category_address("Sheet1!$D$1:$D$12");
+    sheet.add_chart(chart);
+
+    // serial values out of the date range
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("O33");
+    to_marker.set_coordinate("W47");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$2"],
+        )
+        .set_category_address("Sheet1!$X$1:$X$2");
+    sheet.add_chart(chart);
+
+    // date axis with a chart group on the secondary axis
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("O17");
+    to_marker.set_coordinate("W31");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::BarChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$12"],
+        )
+        .set_category_address("Sheet1!$A$1:$A$12")
+        .set_date_axis(TimeUnitValues::Months)
+        .add_chart_group(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            vec!["Sheet1!$B$1:$B$12"],
+            true,
+        )
+        .unwrap()
+        .set_horizontal_title("Month")
+        .set_secondary_vertical_title("Total")
+        .set_secondary_axis_position(AxisPositionValues::Right)
+        .set_axis_crosses_at(0.0);
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_date_axis.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+
+    let plot_area = sheet
+        .get_chart_mut("E1")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    assert!(plot_area.get_category_axis().is_empty());
+    let date_axis = &plot_area.get_date_axis()[0];
+    assert_eq!(
+        date_axis.get_base_time_unit().unwrap().get_val(),
+        &TimeUnitValues::Months
+    );
+    let category_axis_data = plot_area
+        .get_line_chart()
+        .unwrap()
+        .get_area_chart_series_list()
+        .get_area_chart_series()[0]
+        .get_category_axis_data()
+        .unwrap();
+    let number_reference = category_axis_data.get_number_reference().unwrap();
+    assert_eq!(
+        number_reference.get_formula().get_address_str(),
+        "Sheet1!$A$1:$A$12"
+    );
+    assert_eq!(
+        number_reference
+            .get_numbering_cache()
+            .get_format_code()
+            .get_text(),
+        "yyyy-mm-dd"
+    );
+    let display_units = plot_area.get_value_axis()[0].get_display_units().unwrap();
+    assert_eq!(
+        display_units.get_built_in_unit().unwrap().get_val(),
+        &BuiltInUnitValues::Thousands
+    );
+    assert!(display_units.get_display_units_label().is_some());
+
+    let plot_area = sheet
+        .get_chart_mut("E17")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    assert_eq!(plot_area.get_category_axis().len(), 1);
+    assert!(plot_area.get_date_axis().is_empty());
+    assert!(plot_area.get_value_axis()[0].get_display_units().is_none());
+
+    let plot_area = sheet
+        .get_chart_mut("O1")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    assert!(plot_area.get_category_axis().is_empty());
+    assert_eq!(
+        plot_area.get_date_axis()[0]
+            .get_base_time_unit()
+            .unwrap()
+            .get_val(),
+        &TimeUnitValues::Days
+    );
+    let display_units = plot_area.get_value_axis()[0].get_display_units().unwrap();
+    assert_eq!(display_units.get_custom_unit().unwrap().get_val(), &50000.0);
+    assert!(display_units.get_built_in_unit().is_none());
+
+    let plot_area = sheet
+        .get_chart_mut("E33")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    assert_eq!(
+        plot_area.get_date_axis()[0]
+            .get_base_time_unit()
+            .unwrap()
+            .get_val(),
+        &TimeUnitValues::Months
+    );
+
+    let plot_area = sheet
+        .get_chart_mut("O33")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    assert_eq!(plot_area.get_category_axis().len(), 1);
+    assert!(plot_area.get_date_axis().is_empty());
+
+    let plot_area = sheet
+        .get_chart_mut("O17")
+        .unwrap()
+        .get_plot_area_mut()
+        .clone();
+    let date_axis = &plot_area.get_date_axis()[0];
+    assert!(date_axis.get_title().is_some());
+    assert_eq!(date_axis.get_crosses_at().unwrap().get_val(), &0.0);
+    assert_eq!(plot_area.get_category_axis().len(), 1);
+    assert!(plot_area.get_category_axis()[0].get_delete().get_val());
+    assert_eq!(plot_area.get_value_axis().len(), 2);
+    let secondary_value_axis = &plot_area.get_value_axis()[1];
+    assert!(secondary_value_axis.get_title().is_some());
+    assert_eq!(
+        plot_area.get_bar_chart().unwrap().get_axis_id()[0].get_val(),
+        date_axis.get_axis_id().get_val()
+    );
+    assert_eq!(
+        plot_area.get_line_chart().unwrap().get_axis_id()[1].get_val(),
+        secondary_value_axis.get_axis_id().get_val()
+    );
+
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}